@@ -9,8 +9,10 @@ fn main() {
     
     if target.contains("-ios") {
         println!("cargo:rustc-link-lib=framework=UIKit");
+        println!("cargo:rustc-link-lib=framework=MobileCoreServices");
     } else {
         println!("cargo:rustc-link-lib=framework=AppKit");
+        println!("cargo:rustc-link-lib=framework=CoreServices");
     }
 
     println!("cargo:rustc-link-lib=framework=CoreGraphics");
@@ -20,12 +22,56 @@ fn main() {
     #[cfg(feature = "webview")]
     println!("cargo:rustc-link-lib=framework=WebKit");
     
+    #[cfg(feature = "web-authentication")]
+    {
+        println!("cargo:rustc-link-lib=framework=AuthenticationServices");
+
+        if target.contains("-ios") {
+            println!("cargo:rustc-link-lib=framework=SafariServices");
+        }
+    }
+
+    #[cfg(feature = "background-tasks")]
+    {
+        if target.contains("-ios") {
+            println!("cargo:rustc-link-lib=framework=BackgroundTasks");
+        }
+    }
+
+    #[cfg(feature = "camera")]
+    println!("cargo:rustc-link-lib=framework=AVFoundation");
+
     #[cfg(feature = "cloudkit")]
     println!("cargo:rustc-link-lib=framework=CloudKit");
 
     #[cfg(feature = "user-notifications")]
-    println!("cargo:rustc-link-lib=framework=UserNotifications");
+    {
+        println!("cargo:rustc-link-lib=framework=UserNotifications");
+
+        // Location-based notification triggers need `CLCircularRegion`.
+        if target.contains("-ios") {
+            println!("cargo:rustc-link-lib=framework=CoreLocation");
+        }
+    }
     
+    #[cfg(feature = "local-authentication")]
+    println!("cargo:rustc-link-lib=framework=LocalAuthentication");
+
+    #[cfg(feature = "sound")]
+    {
+        if target.contains("-ios") {
+            println!("cargo:rustc-link-lib=framework=AVFoundation");
+            println!("cargo:rustc-link-lib=framework=AudioToolbox");
+        }
+    }
+
+    #[cfg(feature = "photo-picker")]
+    {
+        if target.contains("-ios") {
+            println!("cargo:rustc-link-lib=framework=PhotosUI");
+        }
+    }
+
     #[cfg(feature = "quicklook")]
     println!("cargo:rustc-link-lib=framework=QuickLook");
 }
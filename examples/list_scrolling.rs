@@ -0,0 +1,90 @@
+//! A rough benchmark for list scrolling: fills a `ListView` with a lot of rows, scrolls through
+//! them from top to bottom (laying out each step, so every newly visible row is dequeued), and
+//! prints how long it took. Run it with `--release`, and compare runs before and after a change
+//! to the row dequeue path.
+
+use std::time::Instant;
+
+use objc::{msg_send, sel, sel_impl};
+
+use cacao::listview::{ListView, ListViewDelegate, ListViewRow};
+use cacao::view::ViewDelegate;
+
+use cacao::macos::{App, AppDelegate};
+use cacao::macos::window::{Window, WindowConfig};
+
+/// How many rows the list has.
+const ROWS: usize = 10_000;
+
+/// How many rows each scroll step moves by - roughly a screenful.
+const STEP: usize = 20;
+
+/// The identifier rows are registered and dequeued under.
+const ROW_IDENTIFIER: &str = "BenchmarkRow";
+
+#[derive(Default)]
+struct Row;
+
+impl ViewDelegate for Row {}
+
+#[derive(Default)]
+struct Rows {
+    view: Option<ListView>
+}
+
+impl ListViewDelegate for Rows {
+    fn did_load(&mut self, view: ListView) {
+        view.register(ROW_IDENTIFIER, Row::default);
+        view.set_row_height(24.);
+        self.view = Some(view);
+    }
+
+    fn number_of_items(&self) -> usize {
+        ROWS
+    }
+
+    fn item_for(&self, _row: usize) -> ListViewRow {
+        let view = self.view.as_ref().unwrap();
+        view.dequeue::<Row>(ROW_IDENTIFIER).wut()
+    }
+}
+
+struct BenchmarkApp {
+    window: Window,
+    list: ListView<Rows>
+}
+
+impl AppDelegate for BenchmarkApp {
+    fn did_finish_launching(&self) {
+        self.window.set_title("List Scrolling Benchmark");
+        self.window.set_content_view(&self.list);
+        self.window.show();
+        self.list.reload();
+
+        let started = Instant::now();
+
+        for row in (0..ROWS).step_by(STEP) {
+            unsafe {
+                let _: () = msg_send![&*self.list.objc, scrollRowToVisible:row as isize];
+                let _: () = msg_send![&*self.list.scrollview.objc, layoutSubtreeIfNeeded];
+            }
+        }
+
+        let elapsed = started.elapsed();
+        println!(
+            "Scrolled through {} rows in {:?} ({:.2}us per row)",
+            ROWS,
+            elapsed,
+            elapsed.as_secs_f64() * 1_000_000. / ROWS as f64
+        );
+
+        App::terminate();
+    }
+}
+
+fn main() {
+    App::new("com.test.list-scrolling", BenchmarkApp {
+        window: Window::new(WindowConfig::default()),
+        list: ListView::with(Rows::default())
+    }).run();
+}
@@ -0,0 +1,215 @@
+//! The iOS side of `Alert`, which wraps `UIAlertController`. Each action's handler calls back into
+//! a shared completion, which is only set once the alert is presented.
+
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+
+use block::ConcreteBlock;
+use core_graphics::geometry::{CGPoint, CGRect, CGSize};
+
+use objc_id::ShareId;
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::alert::{AlertActionStyle, AlertResponse};
+use crate::dispatch::assert_main_thread;
+use crate::foundation::{id, nil, YES, NSInteger, NSString, NSUInteger};
+use crate::ios::BarButtonItem;
+use crate::layout::Layout;
+use crate::utils::Controller;
+
+/// `UIAlertControllerStyleActionSheet`.
+const STYLE_ACTION_SHEET: NSInteger = 0;
+
+/// `UIAlertControllerStyleAlert`.
+const STYLE_ALERT: NSInteger = 1;
+
+/// Called with the response once the alert is dismissed; set by `present()`.
+type Completion = Rc<RefCell<Option<Box<dyn Fn(AlertResponse)>>>>;
+
+/// Represents a `UIAlertController`, showing either an alert or an action sheet.
+pub struct Alert {
+    /// The underlying `UIAlertController`.
+    pub objc: ShareId<Object>,
+
+    completion: Completion,
+    actions: Cell<usize>
+}
+
+impl Alert {
+    /// Creates an alert, shown in the middle of the screen. You can show it by calling
+    /// `present()`.
+    ///
+    /// Unlike on macOS, UIKit doesn't add a default button; if you don't add any actions, the user
+    /// can't dismiss the alert.
+    pub fn new(title: &str, message: &str) -> Self {
+        Alert::with_style(title, message, STYLE_ALERT)
+    }
+
+    /// Creates an action sheet, which slides up from the bottom of the screen (or, on iPad, shows
+    /// as a popover - see `set_source_view()`) to offer the user a choice of actions.
+    pub fn action_sheet(title: &str, message: &str) -> Self {
+        Alert::with_style(title, message, STYLE_ACTION_SHEET)
+    }
+
+    fn with_style(title: &str, message: &str, style: NSInteger) -> Self {
+        assert_main_thread("Alert::new");
+
+        let title = NSString::new(title);
+        let message = NSString::new(message);
+
+        Alert {
+            objc: unsafe {
+                let alert: id = msg_send![class!(UIAlertController), alertControllerWithTitle:title
+                    message:message
+                    preferredStyle:style];
+                ShareId::from_ptr(alert)
+            },
+
+            completion: Rc::new(RefCell::new(None)),
+            actions: Cell::new(0)
+        }
+    }
+
+    /// Adds a button with the given title.
+    pub fn add_button(&self, title: &str) {
+        self.add_action(title, AlertActionStyle::Default);
+    }
+
+    /// Adds a button with the given title and style. The response's `button` is its index, in the
+    /// order actions were added - UIKit may display a `Cancel` action apart from the others, but
+    /// that doesn't change its index.
+    pub fn add_action(&self, title: &str, style: AlertActionStyle) {
+        let title = NSString::new(title);
+        let style: NSInteger = style.into();
+
+        let index = self.actions.get();
+        self.actions.set(index + 1);
+
+        let completion = self.completion.clone();
+        let handler = ConcreteBlock::new(move |_action: id| {
+            if let Some(completion) = &*completion.borrow() {
+                completion(AlertResponse {
+                    button: Some(index),
+                    suppressed: false
+                });
+            }
+        });
+        let handler = handler.copy();
+
+        unsafe {
+            let action: id = msg_send![class!(UIAlertAction), actionWithTitle:title style:style handler:handler];
+            let _: () = msg_send![&*self.objc, addAction:action];
+        }
+    }
+
+    /// Adds a text field with the given placeholder. Only alerts (not action sheets) can have text
+    /// fields. Read what was entered with `text_field_value()` once the alert is dismissed.
+    pub fn add_text_field(&self, placeholder: &str) {
+        let placeholder = placeholder.to_string();
+
+        let configure = ConcreteBlock::new(move |field: id| unsafe {
+            let placeholder = NSString::new(&placeholder);
+            let _: () = msg_send![field, setPlaceholder:placeholder];
+        });
+        let configure = configure.copy();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, addTextFieldWithConfigurationHandler:configure];
+        }
+    }
+
+    /// Returns the text in the text field at `index` (in the order they were added), or `None` if
+    /// there's no such field.
+    pub fn text_field_value(&self, index: usize) -> Option<String> {
+        unsafe {
+            let fields: id = msg_send![&*self.objc, textFields];
+            if fields == nil {
+                return None;
+            }
+
+            let count: NSUInteger = msg_send![fields, count];
+            if index as NSUInteger >= count {
+                return None;
+            }
+
+            let field: id = msg_send![fields, objectAtIndex:index as NSUInteger];
+            let text: id = msg_send![field, text];
+
+            Some(match text == nil {
+                true => String::new(),
+                false => NSString::wrap(text).to_string()
+            })
+        }
+    }
+
+    /// On iPad, action sheets are shown as a popover, which has to point at something; this
+    /// anchors it to `view`. Without this (or `set_source_bar_button()`), the popover is shown in
+    /// the middle of the presenting controller's view. Does nothing on iPhone.
+    pub fn set_source_view<L: Layout>(&self, view: &L) {
+        let backing_node = view.get_backing_node();
+
+        unsafe {
+            let popover: id = msg_send![&*self.objc, popoverPresentationController];
+            if popover != nil {
+                let bounds: CGRect = msg_send![&*backing_node, bounds];
+                let _: () = msg_send![popover, setSourceView:&*backing_node];
+                let _: () = msg_send![popover, setSourceRect:bounds];
+            }
+        }
+    }
+
+    /// As with `set_source_view()`, but anchors the popover to a bar button item.
+    pub fn set_source_bar_button(&self, item: &BarButtonItem) {
+        unsafe {
+            let popover: id = msg_send![&*self.objc, popoverPresentationController];
+            if popover != nil {
+                let _: () = msg_send![popover, setBarButtonItem:&*item.objc];
+            }
+        }
+    }
+
+    /// Presents this alert from the given controller. This returns immediately; `completion` is
+    /// called with the response once the user picks an action (which dismisses the alert).
+    pub fn present<VC, F>(&self, controller: &VC, completion: F)
+    where
+        VC: Controller,
+        F: Fn(AlertResponse) + 'static
+    {
+        *self.completion.borrow_mut() = Some(Box::new(completion));
+        let backing_node = controller.get_backing_node();
+
+        unsafe {
+            let popover: id = msg_send![&*self.objc, popoverPresentationController];
+            if popover != nil {
+                let source_view: id = msg_send![popover, sourceView];
+                let bar_button: id = msg_send![popover, barButtonItem];
+
+                if source_view == nil && bar_button == nil {
+                    let view: id = msg_send![&*backing_node, view];
+                    let bounds: CGRect = msg_send![view, bounds];
+                    let center = CGRect::new(
+                        &CGPoint::new(bounds.origin.x + bounds.size.width / 2., bounds.origin.y + bounds.size.height / 2.),
+                        &CGSize::new(0., 0.)
+                    );
+
+                    let _: () = msg_send![popover, setSourceView:view];
+                    let _: () = msg_send![popover, setSourceRect:center];
+                    let _: () = msg_send![popover, setPermittedArrowDirections:0 as NSUInteger];
+                }
+            }
+
+            let _: () = msg_send![&*backing_node, presentViewController:&*self.objc animated:YES completion:nil];
+        }
+    }
+}
+
+impl fmt::Debug for Alert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Alert")
+            .field("objc", &self.objc)
+            .field("actions", &self.actions.get())
+            .finish()
+    }
+}
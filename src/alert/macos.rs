@@ -0,0 +1,242 @@
+//! The macOS side of `Alert`, which wraps `NSAlert`. Text fields are stacked up in a plain
+//! `NSView`, which is set as the alert's accessory view.
+
+use std::cell::RefCell;
+
+use block::ConcreteBlock;
+use core_graphics::base::CGFloat;
+use core_graphics::geometry::{CGPoint, CGRect, CGSize};
+
+use objc_id::ShareId;
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::alert::{AlertActionStyle, AlertResponse, AlertStyle};
+use crate::dispatch::assert_main_thread;
+use crate::foundation::{id, YES, NO, NSInteger, NSString};
+use crate::layout::Layout;
+use crate::macos::window::Window;
+
+/// `NSAlertFirstButtonReturn`. Subsequent buttons increment from here.
+const FIRST_BUTTON_RETURN: NSInteger = 1000;
+
+/// The width of the text field container; about as wide as an alert's text.
+const TEXT_FIELD_WIDTH: CGFloat = 230.;
+
+/// The height of each text field.
+const TEXT_FIELD_HEIGHT: CGFloat = 24.;
+
+/// The space between text fields.
+const TEXT_FIELD_SPACING: CGFloat = 8.;
+
+impl AlertResponse {
+    /// Builds a response for the given alert and `NSModalResponse`.
+    fn new(alert: &Object, response: NSInteger) -> Self {
+        let suppressed = unsafe {
+            let button: id = msg_send![alert, suppressionButton];
+            let state: NSInteger = msg_send![button, state];
+            state == 1
+        };
+
+        AlertResponse {
+            button: match response >= FIRST_BUTTON_RETURN {
+                true => Some((response - FIRST_BUTTON_RETURN) as usize),
+                false => None
+            },
+
+            suppressed: suppressed
+        }
+    }
+}
+
+/// Represents an `NSAlert`. Has no information other than the retained pointer to the Objective C
+/// side (and any text fields you've added), so... don't bother inspecting this.
+pub struct Alert {
+    objc: ShareId<Object>,
+    text_fields: RefCell<Vec<ShareId<Object>>>
+}
+
+impl Alert {
+    /// Creates a basic `NSAlert`, storing a pointer to it in the Objective C runtime.
+    /// You can show this alert by calling `show()`.
+    ///
+    /// If you don't add any buttons via `add_button()`, AppKit will show a single "OK" button.
+    pub fn new(title: &str, message: &str) -> Self {
+        assert_main_thread("Alert::new");
+
+        let title = NSString::new(title);
+        let message = NSString::new(message);
+
+        Alert {
+            objc: unsafe {
+                let alert: id = msg_send![class!(NSAlert), new];
+                let _: () = msg_send![alert, setMessageText:title];
+                let _: () = msg_send![alert, setInformativeText:message];
+                ShareId::from_retained_ptr(alert)
+            },
+
+            text_fields: RefCell::new(vec![])
+        }
+    }
+
+    /// Creates an alert to offer the user a choice of actions. AppKit has no separate action sheet
+    /// style, so on macOS this is the same as `new()`.
+    pub fn action_sheet(title: &str, message: &str) -> Self {
+        Alert::new(title, message)
+    }
+
+    /// Adds a button with the given title. Buttons are displayed right-to-left (or top-to-bottom),
+    /// in the order they're added; the first is the default button, and responds to the Return
+    /// key.
+    pub fn add_button(&self, title: &str) {
+        self.add_action(title, AlertActionStyle::Default);
+    }
+
+    /// Adds a button with the given title and style, ordered as with `add_button()`. A `Cancel`
+    /// button responds to the Escape key, and a `Destructive` one is shown in red (on macOS 11 and
+    /// later).
+    pub fn add_action(&self, title: &str, style: AlertActionStyle) {
+        let title = NSString::new(title);
+
+        unsafe {
+            let button: id = msg_send![&*self.objc, addButtonWithTitle:title];
+
+            match style {
+                AlertActionStyle::Default => {},
+
+                AlertActionStyle::Cancel => {
+                    let escape = NSString::new("\u{1b}");
+                    let _: () = msg_send![button, setKeyEquivalent:escape];
+                },
+
+                AlertActionStyle::Destructive => {
+                    let _: () = msg_send![button, setHasDestructiveAction:YES];
+                }
+            }
+        }
+    }
+
+    /// Adds a text field, below the informative text, with the given placeholder. Fields are
+    /// stacked in the order they're added, and the first one has focus when the alert is shown.
+    /// Read what was entered with `text_field_value()` once the alert is dismissed.
+    ///
+    /// The fields live in the accessory view, so this replaces anything set with
+    /// `set_accessory_view()`.
+    pub fn add_text_field(&self, placeholder: &str) {
+        let placeholder = NSString::new(placeholder);
+        let mut text_fields = self.text_fields.borrow_mut();
+
+        unsafe {
+            let field: id = msg_send![class!(NSTextField), new];
+            let _: () = msg_send![field, setPlaceholderString:placeholder];
+
+            if text_fields.is_empty() {
+                let window: id = msg_send![&*self.objc, window];
+                let _: () = msg_send![window, setInitialFirstResponder:field];
+            }
+
+            text_fields.push(ShareId::from_retained_ptr(field));
+        }
+
+        // NSAlert sizes itself around the accessory view's frame, so lay the fields out by hand:
+        // top to bottom, in a view that isn't flipped.
+        let count = text_fields.len() as CGFloat;
+        let height = count * TEXT_FIELD_HEIGHT + (count - 1.) * TEXT_FIELD_SPACING;
+
+        unsafe {
+            let frame = CGRect::new(&CGPoint::new(0., 0.), &CGSize::new(TEXT_FIELD_WIDTH, height));
+            let container: id = msg_send![class!(NSView), alloc];
+            let container: id = msg_send![container, initWithFrame:frame];
+
+            for (index, field) in text_fields.iter().enumerate() {
+                let y = height - (index as CGFloat + 1.) * TEXT_FIELD_HEIGHT
+                    - index as CGFloat * TEXT_FIELD_SPACING;
+                let frame = CGRect::new(&CGPoint::new(0., y), &CGSize::new(TEXT_FIELD_WIDTH, TEXT_FIELD_HEIGHT));
+
+                let _: () = msg_send![&**field, setFrame:frame];
+                let _: () = msg_send![container, addSubview:&**field];
+            }
+
+            let _: () = msg_send![&*self.objc, setAccessoryView:container];
+            let _: () = msg_send![container, release];
+        }
+    }
+
+    /// Returns the text in the text field at `index` (in the order they were added), or `None` if
+    /// there's no such field.
+    pub fn text_field_value(&self, index: usize) -> Option<String> {
+        self.text_fields.borrow().get(index).map(|field| {
+            let value = NSString::wrap(unsafe { msg_send![&**field, stringValue] });
+            value.to_string()
+        })
+    }
+
+    /// Sets the style of this alert.
+    pub fn set_style(&self, style: AlertStyle) {
+        let style: NSInteger = style.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAlertStyle:style];
+        }
+    }
+
+    /// Sets a custom view to display below the informative text (and above the buttons). This
+    /// replaces any text fields added with `add_text_field()`.
+    pub fn set_accessory_view<L: Layout>(&self, view: &L) {
+        let backing_node = view.get_backing_node();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAccessoryView:&*backing_node];
+        }
+    }
+
+    /// Sets whether this alert shows a suppression ("Don't ask again") checkbox. Whether it was
+    /// checked is available on the `AlertResponse` - it's up to you to persist and honor it.
+    pub fn set_shows_suppression_button(&self, shows: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setShowsSuppressionButton:match shows {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets the title for the suppression checkbox. By default, this is "Do not show this message
+    /// again".
+    pub fn set_suppression_button_title(&self, title: &str) {
+        let title = NSString::new(title);
+
+        unsafe {
+            let button: id = msg_send![&*self.objc, suppressionButton];
+            let _: () = msg_send![button, setTitle:title];
+        }
+    }
+
+    /// Shows this alert as a modal, blocking until the user dismisses it. Returns which button was
+    /// clicked, along with the state of the suppression checkbox.
+    pub fn show(&self) -> AlertResponse {
+        let response: NSInteger = unsafe {
+            msg_send![&*self.objc, runModal]
+        };
+
+        AlertResponse::new(&self.objc, response)
+    }
+
+    /// Presents this alert as a sheet on the given window. This returns immediately; `completion`
+    /// is called with the response once the user dismisses the alert.
+    pub fn begin_sheet<W, F>(&self, window: &Window<W>, completion: F)
+    where
+        F: Fn(AlertResponse) + 'static
+    {
+        let alert = self.objc.clone();
+
+        let block = ConcreteBlock::new(move |response: NSInteger| {
+            completion(AlertResponse::new(&alert, response));
+        });
+        let block = block.copy();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, beginSheetModalForWindow:&*window.objc completionHandler:block];
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Alerts, across platforms: `NSAlert` on macOS, and `UIAlertController` on iOS.
+//!
+//! The basics - a title, a message, some buttons (with styles), and text fields - work the same
+//! on both. How an alert is shown differs, though: on macOS, `show()` blocks until the user
+//! responds, or `begin_sheet()` attaches it to a window; on iOS, `present()` shows it from a view
+//! controller, and calls you back.
+//!
+//! ```rust,no_run
+//! use cacao::alert::{Alert, AlertActionStyle};
+//!
+//! let alert = Alert::new("Delete this item?", "You can't undo this action.");
+//! alert.add_action("Delete", AlertActionStyle::Destructive);
+//! alert.add_action("Cancel", AlertActionStyle::Cancel);
+//!
+//! # #[cfg(target_os = "macos")]
+//! if alert.show().button == Some(0) {
+//!     // Delete it...
+//! }
+//! ```
+//!
+//! If you want to show a complex view in an alert-esque fashion on macOS, you may consider looking
+//! at `Sheet`.
+
+use crate::foundation::NSInteger;
+
+#[cfg(target_os = "macos")]
+mod macos;
+
+#[cfg(target_os = "macos")]
+pub use macos::Alert;
+
+#[cfg(target_os = "ios")]
+mod ios;
+
+#[cfg(target_os = "ios")]
+pub use ios::Alert;
+
+/// Describes the severity of an alert, which affects how it's displayed. This only applies on
+/// macOS.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AlertStyle {
+    /// Warns the user about a current or impending event. This is the default.
+    Warning,
+
+    /// Informs the user about a current or impending event.
+    Informational,
+
+    /// Used for events that may have severe consequences (e.g, data loss). AppKit displays these
+    /// with a caution icon.
+    Critical
+}
+
+impl From<AlertStyle> for NSInteger {
+    fn from(style: AlertStyle) -> Self {
+        match style {
+            AlertStyle::Warning => 0,
+            AlertStyle::Informational => 1,
+            AlertStyle::Critical => 2
+        }
+    }
+}
+
+/// Describes what a button on an alert does, which affects how it's displayed (and, for `Cancel`,
+/// which key triggers it).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AlertActionStyle {
+    /// A standard button.
+    Default,
+
+    /// Dismisses the alert without doing anything. Escape triggers this on macOS; on iOS, it's
+    /// placed apart from the others, and there can be only one.
+    Cancel,
+
+    /// Does something that can't be undone (e.g, deleting data), and is shown in red.
+    Destructive
+}
+
+impl From<AlertActionStyle> for NSInteger {
+    fn from(style: AlertActionStyle) -> Self {
+        match style {
+            AlertActionStyle::Default => 0,
+            AlertActionStyle::Cancel => 1,
+            AlertActionStyle::Destructive => 2
+        }
+    }
+}
+
+/// Returned after an alert has been dismissed, describing how the user responded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AlertResponse {
+    /// The index of the button that was clicked, in the order they were added. If you didn't add
+    /// any buttons, this will be `Some(0)` (for the default "OK" button). This is `None` if the
+    /// alert was dismissed without a button being clicked (e.g, a sheet that was ended by its
+    /// window closing).
+    pub button: Option<usize>,
+
+    /// Whether the suppression ("Don't ask again") checkbox was checked. This is always `false`
+    /// if the suppression button isn't shown (and on iOS, which doesn't have one).
+    pub suppressed: bool
+}
@@ -0,0 +1,80 @@
+//! Light and dark mode. On macOS, views and windows draw using an `NSAppearance`; by default this
+//! follows the system setting, but you can override it for the whole application via
+//! `App::set_appearance()`.
+//!
+//! If you use semantic colors, AppKit handles switching for you. If you're doing custom drawing,
+//! you can check `App::effective_appearance()`, or be notified when the system switches via
+//! `App::observe_system_appearance()`.
+
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, NSArray, NSString};
+
+static AQUA: &str = "NSAppearanceNameAqua";
+static DARK_AQUA: &str = "NSAppearanceNameDarkAqua";
+
+/// Posted (via the distributed notification center) when the system appearance changes.
+pub(crate) static SYSTEM_APPEARANCE_DID_CHANGE: &str = "AppleInterfaceThemeChangedNotification";
+
+/// An appearance for the application, a window, or a view.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Appearance {
+    /// Follow the system setting. When read back (e.g, from `App::effective_appearance()`),
+    /// you'll get `Light` or `Dark` instead.
+    System,
+
+    /// The standard light appearance ("Aqua").
+    Light,
+
+    /// The dark appearance ("Dark Aqua").
+    Dark
+}
+
+impl Appearance {
+    /// Returns an `NSAppearance` for this appearance, or `nil` for `System` (which, when set,
+    /// means "inherit").
+    pub(crate) fn to_objc(&self) -> id {
+        let name = match self {
+            Appearance::System => { return nil; },
+            Appearance::Light => NSString::new(AQUA),
+            Appearance::Dark => NSString::new(DARK_AQUA)
+        };
+
+        unsafe { msg_send![class!(NSAppearance), appearanceNamed:name] }
+    }
+
+    /// Resolves an `NSAppearance` to `Light` or `Dark`. High contrast and vibrant variants are
+    /// matched to whichever of the two they're based on.
+    pub(crate) fn from_objc(appearance: id) -> Self {
+        if appearance == nil {
+            return Appearance::Light;
+        }
+
+        let aqua = NSString::new(AQUA);
+        let dark_aqua = NSString::new(DARK_AQUA);
+        let names = NSArray::from(vec![&*aqua.0, &*dark_aqua.0]);
+
+        let name: id = unsafe {
+            msg_send![appearance, bestMatchFromAppearancesWithNames:&*names.0]
+        };
+
+        match name != nil && NSString::wrap(name).to_str() == DARK_AQUA {
+            true => Appearance::Dark,
+            false => Appearance::Light
+        }
+    }
+
+    /// Returns the appearance the user has chosen in System Preferences.
+    pub(crate) fn system() -> Self {
+        let style: id = unsafe {
+            let defaults: id = msg_send![class!(NSUserDefaults), standardUserDefaults];
+            let key = NSString::new("AppleInterfaceStyle");
+            msg_send![defaults, stringForKey:key]
+        };
+
+        match style != nil && NSString::wrap(style).to_str() == "Dark" {
+            true => Appearance::Dark,
+            false => Appearance::Light
+        }
+    }
+}
@@ -0,0 +1,340 @@
+//! Observable values, and bindings that keep widgets in sync with them.
+//!
+//! An `Observable<T>` holds a value (your model), and tells its subscribers whenever it changes.
+//! Widgets can bind to one - e.g, `Label::bind_text()`, or `Button::bind_checked()` for
+//! checkboxes and switches - after which changing the value updates
+//! the widget, without you having to hold on to (or message pass to) the widget yourself.
+//!
+//! ```rust,no_run
+//! use cacao::binding::Observable;
+//! use cacao::dispatch::{background, QualityOfService};
+//! use cacao::text::Label;
+//!
+//! let status = Observable::new("Idle".to_string());
+//!
+//! let label = Label::new();
+//! let binding = label.bind_text(&status);
+//!
+//! let worker = status.clone();
+//! background(QualityOfService::Utility, move || {
+//!     // Safe from any thread; the label is updated on the main thread.
+//!     worker.set("Syncing...".to_string());
+//! });
+//! ```
+//!
+//! Observables are cheap to clone (clones share the same value), and safe to change from any
+//! thread. Subscribers are called on whichever thread made the change; widget bindings hop over
+//! to the main thread before touching the widget. A binding stays in effect for as long as you
+//! hold on to the `Binding` it returns.
+
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// A subscriber, as stored by an `Observable`.
+type Subscriber<T> = Arc<dyn Fn(&T) + Send + Sync + 'static>;
+
+/// The state shared between clones of an `Observable`.
+struct Shared<T> {
+    value: Mutex<T>,
+    subscribers: Mutex<Vec<(usize, Subscriber<T>)>>,
+    next_id: AtomicUsize
+}
+
+/// A value that notifies its subscribers when it changes. See the module documentation.
+pub struct Observable<T> {
+    shared: Arc<Shared<T>>
+}
+
+impl<T: Clone + Send + 'static> Observable<T> {
+    /// Creates an observable holding `value`.
+    pub fn new(value: T) -> Self {
+        Observable {
+            shared: Arc::new(Shared {
+                value: Mutex::new(value),
+                subscribers: Mutex::new(vec![]),
+                next_id: AtomicUsize::new(0)
+            })
+        }
+    }
+
+    /// Returns a copy of the current value.
+    pub fn get(&self) -> T {
+        self.shared.value.lock().unwrap().clone()
+    }
+
+    /// Calls `handler` with a copy of the current value. The lock isn't held while `handler`
+    /// runs, so it's free to `set()` or `update()` this observable.
+    pub fn with<R, F: FnOnce(&T) -> R>(&self, handler: F) -> R {
+        let value = self.get();
+        handler(&value)
+    }
+
+    /// Replaces the value, and notifies subscribers.
+    pub fn set(&self, value: T) {
+        self.update(move |current| *current = value);
+    }
+
+    /// Changes the value in place (e.g, pushing onto a `Vec`), and notifies subscribers.
+    pub fn update<F: FnOnce(&mut T)>(&self, handler: F) {
+        let value = {
+            let mut value = self.shared.value.lock().unwrap();
+            handler(&mut *value);
+            value.clone()
+        };
+
+        // Subscribers are called without holding either lock, so that they're free to read (or
+        // even change) the value themselves.
+        let subscribers: Vec<Subscriber<T>> = self.shared.subscribers.lock().unwrap().iter().map(|(_, subscriber)| {
+            subscriber.clone()
+        }).collect();
+
+        for subscriber in subscribers {
+            subscriber(&value);
+        }
+    }
+
+    /// Calls `handler` with the new value every time it changes, until the returned `Binding` is
+    /// dropped.
+    pub fn subscribe<F: Fn(&T) + Send + Sync + 'static>(&self, handler: F) -> Binding<T> {
+        let id = self.shared.next_id.fetch_add(1, Ordering::Relaxed);
+        self.shared.subscribers.lock().unwrap().push((id, Arc::new(handler)));
+
+        Binding {
+            shared: self.shared.clone(),
+            id: id
+        }
+    }
+}
+
+impl<T> Clone for Observable<T> {
+    /// Returns another handle to the same value.
+    fn clone(&self) -> Self {
+        Observable {
+            shared: self.shared.clone()
+        }
+    }
+}
+
+impl<T: Clone + Default + Send + 'static> Default for Observable<T> {
+    fn default() -> Self {
+        Observable::new(T::default())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Observable<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Observable")
+            .field("value", &*self.shared.value.lock().unwrap())
+            .field("subscribers", &self.shared.subscribers.lock().unwrap().len())
+            .finish()
+    }
+}
+
+/// A subscription to an `Observable`, as returned by `Observable::subscribe()` and the widget
+/// `bind_*` methods. The subscription is removed when this is dropped.
+#[must_use = "the binding is removed as soon as this is dropped"]
+pub struct Binding<T> {
+    shared: Arc<Shared<T>>,
+    id: usize
+}
+
+impl<T> fmt::Debug for Binding<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Binding")
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl<T> Drop for Binding<T> {
+    /// Removes the subscription.
+    fn drop(&mut self) {
+        let id = self.id;
+        self.shared.subscribers.lock().unwrap().retain(|(subscriber, _)| *subscriber != id);
+    }
+}
+
+/// Works out how to get from `old` to `new`: the indexes (in `old`) of the items to remove, and
+/// then the indexes (in `new`) of the items to insert. Items common to both (per the longest
+/// common subsequence) stay put.
+///
+/// Returns `None` if the lists are too large to diff cheaply, in which case just reload.
+pub(crate) fn diff<T: PartialEq>(old: &[T], new: &[T]) -> Option<(Vec<usize>, Vec<usize>)> {
+    // The table is (old + 1) * (new + 1); past this, reloading is cheaper than working it out.
+    const MAX_CELLS: usize = 1_000_000;
+
+    let (n, m) = (old.len(), new.len());
+    if (n + 1).saturating_mul(m + 1) > MAX_CELLS {
+        return None;
+    }
+
+    // lengths[i][j] is the length of the longest common subsequence of old[i..] and new[j..].
+    let mut lengths = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i][j] = match old[i] == new[j] {
+                true => lengths[i + 1][j + 1] + 1,
+                false => lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let (mut removed, mut inserted) = (vec![], vec![]);
+    let (mut i, mut j) = (0, 0);
+
+    while i < n && j < m {
+        if old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            removed.push(i);
+            i += 1;
+        } else {
+            inserted.push(j);
+            j += 1;
+        }
+    }
+
+    removed.extend(i..n);
+    inserted.extend(j..m);
+
+    Some((removed, inserted))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use super::{diff, Observable};
+
+    /// Applies a diff to `old` the way a list view would - removals first, then insertions - and
+    /// returns the result.
+    fn apply(old: &[char], new: &[char]) -> Vec<char> {
+        let (removed, inserted) = diff(old, new).unwrap();
+        let mut list = old.to_vec();
+
+        for index in removed.iter().rev() {
+            list.remove(*index);
+        }
+
+        for index in inserted {
+            list.insert(index, new[index]);
+        }
+
+        list
+    }
+
+    #[test]
+    fn subscribers_see_changes() {
+        let value = Observable::new(1);
+        let seen = Arc::new(Mutex::new(vec![]));
+
+        let recorder = seen.clone();
+        let _binding = value.subscribe(move |value| recorder.lock().unwrap().push(*value));
+
+        value.set(2);
+        value.update(|value| *value += 1);
+
+        assert_eq!(*seen.lock().unwrap(), vec![2, 3]);
+        assert_eq!(value.get(), 3);
+    }
+
+    #[test]
+    fn dropping_the_binding_unsubscribes() {
+        let value = Observable::new(0);
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let counter = calls.clone();
+        let binding = value.subscribe(move |_| { counter.fetch_add(1, Ordering::SeqCst); });
+
+        value.set(1);
+        drop(binding);
+        value.set(2);
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn clones_share_subscribers() {
+        let value = Observable::new(0);
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let counter = calls.clone();
+        let _binding = value.subscribe(move |_| { counter.fetch_add(1, Ordering::SeqCst); });
+
+        value.clone().set(1);
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn subscribers_can_read_the_value() {
+        let value = Observable::new(0);
+        let reader = value.clone();
+        let seen = Arc::new(AtomicUsize::new(0));
+
+        let recorder = seen.clone();
+        let _binding = value.subscribe(move |_| recorder.store(reader.get(), Ordering::SeqCst));
+
+        value.set(5);
+
+        assert_eq!(seen.load(Ordering::SeqCst), 5);
+    }
+
+    #[test]
+    fn with_can_change_the_value() {
+        let value = Observable::new(1);
+        value.with(|current| value.set(current + 1));
+
+        assert_eq!(value.get(), 2);
+    }
+
+    #[test]
+    fn diff_insert() {
+        let (old, new) = (['a', 'c'], ['a', 'b', 'c', 'd']);
+
+        assert_eq!(diff(&old, &new), Some((vec![], vec![1, 3])));
+        assert_eq!(apply(&old, &new), new);
+    }
+
+    #[test]
+    fn diff_remove() {
+        let (old, new) = (['a', 'b', 'c', 'd'], ['b', 'd']);
+
+        assert_eq!(diff(&old, &new), Some((vec![0, 2], vec![])));
+        assert_eq!(apply(&old, &new), new);
+    }
+
+    #[test]
+    fn diff_move() {
+        let (old, new) = (['a', 'b', 'c'], ['c', 'a', 'b']);
+        let (removed, inserted) = diff(&old, &new).unwrap();
+
+        // A move is a removal and an insertion; 'a' and 'b' stay put.
+        assert_eq!((removed.len(), inserted.len()), (1, 1));
+        assert_eq!(apply(&old, &new), new);
+    }
+
+    #[test]
+    fn diff_unchanged() {
+        let list = ['a', 'b', 'c'];
+        assert_eq!(diff(&list, &list), Some((vec![], vec![])));
+    }
+
+    #[test]
+    fn diff_empty() {
+        let empty: [char; 0] = [];
+
+        assert_eq!(diff(&empty, &empty), Some((vec![], vec![])));
+        assert_eq!(diff(&empty, &['a', 'b']), Some((vec![], vec![0, 1])));
+        assert_eq!(diff(&['a', 'b'], &empty), Some((vec![0, 1], vec![])));
+    }
+
+    #[test]
+    fn diff_gives_up_on_large_lists() {
+        let (old, new) = (vec![0; 2_000], vec![1; 2_000]);
+        assert_eq!(diff(&old, &new), None);
+    }
+}
@@ -0,0 +1,159 @@
+//! Wraps `NSComboButton` (macOS 13 and up): a primary action, with a menu of related actions
+//! attached - e.g "Save ▾", where the menu offers "Save As..." and "Export...".
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::dispatch::assert_main_thread;
+use crate::foundation::{id, nil, YES, NO, NSInteger, NSString};
+use crate::image::Image;
+use crate::invoker::TargetActionHandler;
+use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
+use crate::macos::menu::Menu;
+
+/// How a `ComboButton` splits its action and its menu.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ComboButtonStyle {
+    /// Two parts: clicking the title runs the action, and the arrow shows the menu. This is the
+    /// default.
+    Split,
+
+    /// One part: clicking runs the action, and clicking and holding shows the menu. This is
+    /// usually the right choice in a toolbar.
+    Unified
+}
+
+impl From<ComboButtonStyle> for NSInteger {
+    fn from(style: ComboButtonStyle) -> Self {
+        match style {
+            ComboButtonStyle::Split => 0,
+            ComboButtonStyle::Unified => 1
+        }
+    }
+}
+
+/// A button with a primary action, and a menu of related ones. Needs macOS 13 or later.
+#[derive(Debug)]
+pub struct ComboButton {
+    /// The underlying `NSComboButton`.
+    pub objc: ShareId<Object>,
+
+    /// The menu, retained for as long as this button is alive.
+    pub menu: Menu,
+
+    handler: Option<TargetActionHandler>,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    pub center_y: LayoutAnchorY
+}
+
+impl ComboButton {
+    /// Creates a combo button showing `title`, offering `menu` alongside its action. Set the
+    /// action with `set_action()`.
+    pub fn new(title: &str, menu: Menu) -> Self {
+        assert_main_thread("ComboButton::new");
+
+        let title = NSString::new(title);
+
+        let view: id = unsafe {
+            let button: id = msg_send![class!(NSComboButton), comboButtonWithTitle:title
+                menu:&*menu.inner target:nil action:nil];
+            let _: () = msg_send![button, setTranslatesAutoresizingMaskIntoConstraints:NO];
+            button
+        };
+
+        ComboButton {
+            menu: menu,
+            handler: None,
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            objc: unsafe { ShareId::from_ptr(view) }
+        }
+    }
+
+    /// Sets the primary action, run when the button itself is clicked.
+    pub fn set_action<F: Fn() + Send + Sync + 'static>(&mut self, action: F) {
+        self.handler = Some(TargetActionHandler::new(&*self.objc, action));
+    }
+
+    /// Sets how the button splits its action and its menu.
+    pub fn set_style(&self, style: ComboButtonStyle) {
+        let style: NSInteger = style.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setStyle:style];
+        }
+    }
+
+    /// Sets the title.
+    pub fn set_title(&self, title: &str) {
+        let title = NSString::new(title);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTitle:title];
+        }
+    }
+
+    /// Sets an image, shown before the title.
+    pub fn set_image(&self, image: &Image) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setImage:&*image.0];
+        }
+    }
+
+    /// Sets whether the button can be clicked.
+    pub fn set_enabled(&self, enabled: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setEnabled:match enabled {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+}
+
+impl Layout for ComboButton {
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.objc.clone()
+    }
+
+    fn add_subview<V: Layout>(&self, _view: &V) {}
+}
+
+impl Drop for ComboButton {
+    /// Detaches the action, as its handler is going away.
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTarget:nil];
+            let _: () = msg_send![&*self.objc, setAction:nil];
+        }
+    }
+}
@@ -0,0 +1,612 @@
+//! A wrapper for NSButton. Currently the epitome of jank - if you're poking around here, expect
+//! that this will change at some point.
+//!
+//! For buttons that offer a menu of actions, see `PullDownButton` and (on macOS) `ComboButton`.
+
+use std::fmt;
+use std::sync::Once;
+
+use objc_id::ShareId;
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::binding::{Binding, Observable};
+use crate::dispatch::{assert_main_thread, MainThreadHandle};
+use crate::foundation::{id, nil, BOOL, YES, NO, NSInteger, NSUInteger, NSString};
+use crate::image::Image;
+use crate::invoker::TargetActionHandler;
+use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
+use crate::utils::load_or_register_class;
+
+mod pull_down;
+pub use pull_down::{PullDownButton, PullDownItem};
+
+#[cfg(target_os = "ios")]
+pub(crate) use pull_down::menu_for;
+
+#[cfg(feature = "macos")]
+mod combo;
+
+#[cfg(feature = "macos")]
+pub use combo::{ComboButton, ComboButtonStyle};
+
+/// The shape and look of a button.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BezelStyle {
+    /// The standard push button, with rounded ends. This is the default.
+    Rounded,
+
+    /// A rectangle, with square corners, that can be any height.
+    RegularSquare,
+
+    /// A triangle, for showing and hiding content.
+    Disclosure,
+
+    /// Like `RegularSquare`, without the shadow.
+    ShadowlessSquare,
+
+    /// A circle, e.g for an icon-only button.
+    Circular,
+
+    /// A square, for use in a textured (e.g, unified toolbar) window.
+    TexturedSquare,
+
+    /// The round button with a question mark, for opening help.
+    HelpButton,
+
+    /// A small square, e.g for the "+" and "-" buttons under a list.
+    SmallSquare,
+
+    /// A rounded button for use in a textured window, e.g in a toolbar.
+    TexturedRounded,
+
+    /// A rounded rectangle.
+    RoundRect,
+
+    /// A button that's only drawn when the mouse is over it (or it's on), as in a scope bar.
+    Recessed,
+
+    /// A small rounded button with a disclosure triangle.
+    RoundedDisclosure,
+
+    /// A small, pill-shaped button for inline use, e.g a count in a source list.
+    Inline
+}
+
+impl From<BezelStyle> for NSUInteger {
+    fn from(style: BezelStyle) -> Self {
+        match style {
+            BezelStyle::Rounded => 1,
+            BezelStyle::RegularSquare => 2,
+            BezelStyle::Disclosure => 5,
+            BezelStyle::ShadowlessSquare => 6,
+            BezelStyle::Circular => 7,
+            BezelStyle::TexturedSquare => 8,
+            BezelStyle::HelpButton => 9,
+            BezelStyle::SmallSquare => 10,
+            BezelStyle::TexturedRounded => 11,
+            BezelStyle::RoundRect => 12,
+            BezelStyle::Recessed => 13,
+            BezelStyle::RoundedDisclosure => 14,
+            BezelStyle::Inline => 15
+        }
+    }
+}
+
+/// How a button behaves when clicked: whether it stays pressed, and how it shows that.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ButtonType {
+    /// Highlights while pressed, and pops back up. This is the default.
+    MomentaryPushIn,
+
+    /// Like `MomentaryPushIn`, lighting up rather than pushing in.
+    MomentaryLight,
+
+    /// Shows its alternate title or image while pressed.
+    MomentaryChange,
+
+    /// Toggles between on and off with each click, staying pushed in while on.
+    PushOnPushOff,
+
+    /// Toggles between on and off with each click, showing its alternate title or image while
+    /// on.
+    Toggle,
+
+    /// Toggles between on and off with each click, lighting up while on.
+    OnOff,
+
+    /// A checkbox.
+    Switch,
+
+    /// A radio button. Radio buttons sharing a superview and action act as a group.
+    Radio
+}
+
+impl From<ButtonType> for NSUInteger {
+    fn from(button_type: ButtonType) -> Self {
+        match button_type {
+            ButtonType::MomentaryPushIn => 7,
+            ButtonType::MomentaryLight => 0,
+            ButtonType::MomentaryChange => 5,
+            ButtonType::PushOnPushOff => 1,
+            ButtonType::Toggle => 2,
+            ButtonType::OnOff => 6,
+            ButtonType::Switch => 3,
+            ButtonType::Radio => 4
+        }
+    }
+}
+
+/// Whether a toggling button (or checkbox) is on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ButtonState {
+    /// Off (unchecked).
+    Off,
+
+    /// On (checked).
+    On,
+
+    /// Partly on - e.g, a checkbox for a group where only some items are checked.
+    Mixed
+}
+
+impl From<ButtonState> for NSInteger {
+    fn from(state: ButtonState) -> Self {
+        match state {
+            ButtonState::Off => 0,
+            ButtonState::On => 1,
+            ButtonState::Mixed => -1
+        }
+    }
+}
+
+impl From<NSInteger> for ButtonState {
+    fn from(state: NSInteger) -> Self {
+        match state {
+            0 => ButtonState::Off,
+            -1 => ButtonState::Mixed,
+            _ => ButtonState::On
+        }
+    }
+}
+
+/// Where a button's image sits relative to its title.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImagePosition {
+    /// Don't show the image.
+    NoImage,
+
+    /// Show only the image, with no title.
+    ImageOnly,
+
+    /// To the left of the title.
+    ImageLeft,
+
+    /// To the right of the title.
+    ImageRight,
+
+    /// Below the title.
+    ImageBelow,
+
+    /// Above the title.
+    ImageAbove,
+
+    /// Behind the title.
+    ImageOverlaps,
+
+    /// Before the title, in the reading direction. This is usually what you want.
+    ImageLeading,
+
+    /// After the title, in the reading direction.
+    ImageTrailing
+}
+
+impl From<ImagePosition> for NSUInteger {
+    fn from(position: ImagePosition) -> Self {
+        match position {
+            ImagePosition::NoImage => 0,
+            ImagePosition::ImageOnly => 1,
+            ImagePosition::ImageLeft => 2,
+            ImagePosition::ImageRight => 3,
+            ImagePosition::ImageBelow => 4,
+            ImagePosition::ImageAbove => 5,
+            ImagePosition::ImageOverlaps => 6,
+            ImagePosition::ImageLeading => 7,
+            ImagePosition::ImageTrailing => 8
+        }
+    }
+}
+
+/// A key that presses a button.
+#[derive(Clone, Debug, PartialEq)]
+pub enum KeyEquivalent {
+    /// Return (and Enter). This makes the button the default one in its window, which draws it in
+    /// the accent color.
+    Return,
+
+    /// Escape (and Command-Period), for a Cancel button.
+    Escape,
+
+    /// Another key, given as the character it types.
+    Character(String)
+}
+
+impl KeyEquivalent {
+    /// Returns the string AppKit expects for this key.
+    fn to_objc(&self) -> NSString {
+        NSString::new(match self {
+            KeyEquivalent::Return => "\r",
+            KeyEquivalent::Escape => "\u{1b}",
+            KeyEquivalent::Character(key) => key
+        })
+    }
+}
+
+/// A wrapper for `NSButton`. Holds (retains) pointers for the Objective-C runtime 
+/// where our `NSButton` lives.
+#[derive(Debug)]
+pub struct Button {
+    pub objc: ShareId<Object>,
+    handler: Option<TargetActionHandler>,
+    
+    /// A pointer to the Objective-C runtime top layout constraint.
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    pub center_y: LayoutAnchorY
+}
+
+impl Button {
+    /// Creates a new `NSButton` instance, configures it appropriately,
+    /// and retains the necessary Objective-C runtime pointer.
+    pub fn new(text: &str) -> Self {
+        assert_main_thread("Button::new");
+
+        let title = NSString::new(text);
+
+        let view: id = unsafe {
+            let button: id = msg_send![register_class(), buttonWithTitle:title target:nil action:nil];
+            let _: () = msg_send![button, setTranslatesAutoresizingMaskIntoConstraints:NO];
+            button
+        };
+        
+        Button {
+            handler: None,
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            objc: unsafe { ShareId::from_ptr(view) },
+        }
+    }
+
+    /// Sets the bezel style for this button.
+    pub fn set_bezel_style(&self, bezel_style: BezelStyle) {
+        let style: NSUInteger = bezel_style.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setBezelStyle:style];
+        }
+    }
+
+    /// Sets how this button behaves when clicked (e.g, whether it toggles on and off).
+    pub fn set_button_type(&self, button_type: ButtonType) {
+        let button_type: NSUInteger = button_type.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setButtonType:button_type];
+        }
+    }
+
+    /// Sets the title.
+    pub fn set_text(&self, text: &str) {
+        let title = NSString::new(text);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTitle:title];
+        }
+    }
+
+    /// Sets the image shown alongside (or instead of) the title. Template images are tinted to
+    /// match the button's state.
+    pub fn set_image(&self, image: &Image) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setImage:&*image.0];
+        }
+    }
+
+    /// Sets where the image sits relative to the title.
+    pub fn set_image_position(&self, position: ImagePosition) {
+        let position: NSUInteger = position.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setImagePosition:position];
+        }
+    }
+
+    /// Sets the key that presses this button. `KeyEquivalent::Return` makes it the window's
+    /// default button, and `KeyEquivalent::Escape` its cancel button.
+    pub fn set_key_equivalent(&self, key: KeyEquivalent) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setKeyEquivalent:key.to_objc()];
+        }
+    }
+
+    /// Sets whether the button can be clicked.
+    pub fn set_enabled(&self, enabled: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setEnabled:match enabled {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Returns whether the button can be clicked.
+    pub fn is_enabled(&self) -> bool {
+        let enabled: BOOL = unsafe {
+            msg_send![&*self.objc, isEnabled]
+        };
+
+        enabled == YES
+    }
+
+    /// Sets whether a toggling button (or checkbox) is on.
+    pub fn set_state(&self, state: ButtonState) {
+        let state: NSInteger = state.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setState:state];
+        }
+    }
+
+    /// Returns whether a toggling button (or checkbox) is on.
+    pub fn state(&self) -> ButtonState {
+        let state: NSInteger = unsafe {
+            msg_send![&*self.objc, state]
+        };
+
+        state.into()
+    }
+
+    /// Attaches a callback for button press events. Don't get too creative now...
+    /// best just to message pass or something.
+    pub fn set_action<F: Fn() + Send + Sync + 'static>(&mut self, action: F) {
+        let handler = TargetActionHandler::new(&*self.objc, action);
+        self.handler = Some(handler);
+    }
+
+    /// For checkboxes and switches (see `ButtonType`), keeps the checked state and `checked` in
+    /// sync, both ways, until the returned `Binding` is dropped: changing the value (from any
+    /// thread) updates the button on the main thread, and clicks are written back to the value.
+    ///
+    /// This uses the button's action, so it replaces anything set with `set_action()`.
+    pub fn bind_checked(&mut self, checked: &Observable<bool>) -> Binding<bool> {
+        self.set_state(match checked.get() {
+            true => ButtonState::On,
+            false => ButtonState::Off
+        });
+
+        // The button is retained by us (and the handler is dropped with us), so holding the raw
+        // address in the action is fine.
+        let button = &*self.objc as *const Object as usize;
+        let model = checked.clone();
+
+        self.set_action(move || {
+            let state: NSInteger = unsafe { msg_send![button as id, state] };
+            model.set(ButtonState::from(state) == ButtonState::On);
+        });
+
+        // Updates hold the underlying button directly; a `Button` clone would detach our action
+        // when it's dropped.
+        let handle = MainThreadHandle::new(self.objc.clone());
+        checked.subscribe(move |checked| {
+            let state: NSInteger = match checked {
+                true => ButtonState::On.into(),
+                false => ButtonState::Off.into()
+            };
+
+            handle.update(move |button| unsafe {
+                let _: () = msg_send![&**button, setState:state];
+            });
+        })
+    }
+
+    /// Returns a builder, for configuring a `Button` in one go; see `ButtonBuilder`.
+    pub fn builder(text: &str) -> ButtonBuilder {
+        ButtonBuilder {
+            text: text.to_string(),
+            bezel_style: None,
+            button_type: None,
+            image: None,
+            key_equivalent: None,
+            enabled: true,
+            action: None
+        }
+    }
+
+    /// Builds a `Button` around `objc`.
+    fn wrap(objc: ShareId<Object>) -> Self {
+        let view = &*objc;
+
+        Button {
+            handler: None,
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            objc: objc
+        }
+    }
+}
+
+impl_objc_access!(Button);
+
+/// Configures a `Button` in one expression, rather than creating it and then setting it up.
+///
+/// ```rust,no_run
+/// use cacao::button::{Button, KeyEquivalent};
+///
+/// let button = Button::builder("Save")
+///     .key_equivalent(KeyEquivalent::Return)
+///     .action(|| println!("Saving..."))
+///     .build();
+/// ```
+pub struct ButtonBuilder {
+    text: String,
+    bezel_style: Option<BezelStyle>,
+    button_type: Option<ButtonType>,
+    image: Option<(Image, ImagePosition)>,
+    key_equivalent: Option<KeyEquivalent>,
+    enabled: bool,
+    action: Option<Box<dyn Fn() + Send + Sync + 'static>>
+}
+
+impl ButtonBuilder {
+    /// Sets the bezel style.
+    pub fn bezel_style(mut self, bezel_style: BezelStyle) -> Self {
+        self.bezel_style = Some(bezel_style);
+        self
+    }
+
+    /// Sets how the button behaves when clicked.
+    pub fn button_type(mut self, button_type: ButtonType) -> Self {
+        self.button_type = Some(button_type);
+        self
+    }
+
+    /// Sets an image, and where it sits relative to the title.
+    pub fn image(mut self, image: Image, position: ImagePosition) -> Self {
+        self.image = Some((image, position));
+        self
+    }
+
+    /// Sets the key that presses the button.
+    pub fn key_equivalent(mut self, key: KeyEquivalent) -> Self {
+        self.key_equivalent = Some(key);
+        self
+    }
+
+    /// Sets whether the button can be clicked. Defaults to `true`.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets the callback for button presses.
+    pub fn action<F: Fn() + Send + Sync + 'static>(mut self, action: F) -> Self {
+        self.action = Some(Box::new(action));
+        self
+    }
+
+    /// Creates the `Button`.
+    pub fn build(self) -> Button {
+        let mut button = Button::new(&self.text);
+
+        if let Some(bezel_style) = self.bezel_style {
+            button.set_bezel_style(bezel_style);
+        }
+
+        if let Some(button_type) = self.button_type {
+            button.set_button_type(button_type);
+        }
+
+        if let Some((image, position)) = &self.image {
+            button.set_image(image);
+            button.set_image_position(*position);
+        }
+
+        if let Some(key) = &self.key_equivalent {
+            button.set_key_equivalent(key.clone());
+        }
+
+        if !self.enabled {
+            button.set_enabled(false);
+        }
+
+        if let Some(action) = self.action {
+            button.set_action(action);
+        }
+
+        button
+    }
+}
+
+impl fmt::Debug for ButtonBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ButtonBuilder")
+            .field("text", &self.text)
+            .field("bezel_style", &self.bezel_style)
+            .field("button_type", &self.button_type)
+            .field("key_equivalent", &self.key_equivalent)
+            .field("enabled", &self.enabled)
+            .field("action", &self.action.is_some())
+            .finish()
+    }
+}
+
+impl Layout for Button {
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.objc.clone()
+    }
+
+    fn add_subview<V: Layout>(&self, view: &V) {
+        /*let backing_node = view.get_backing_node();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, addSubview:backing_node];
+        }*/
+    }
+}
+
+
+impl Drop for Button {
+    // Just to be sure, let's... nil these out. They should be weak references,
+    // but I'd rather be paranoid and remove them later.
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTarget:nil];
+            let _: () = msg_send![&*self.objc, setAction:nil];
+        }
+    }
+}
+
+/// Registers an `NSButton` subclass, and configures it to hold some ivars 
+/// for various things we need to store.
+fn register_class() -> *const Class {
+    static mut VIEW_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSButton);
+        VIEW_CLASS = load_or_register_class(superclass, "RSTButton", |_| {});
+    });
+
+    unsafe { VIEW_CLASS }
+}
@@ -0,0 +1,248 @@
+//! A button that shows a menu of actions when clicked: `NSPopUpButton` in pull-down mode on
+//! macOS, and a `UIButton` with a `UIMenu` on iOS.
+
+use std::fmt;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::dispatch::assert_main_thread;
+use crate::foundation::{id, nil, YES, NO, NSInteger, NSString};
+use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
+
+#[cfg(target_os = "macos")]
+use core_graphics::geometry::{CGPoint, CGRect, CGSize};
+
+#[cfg(target_os = "macos")]
+use crate::invoker::TargetActionHandler;
+
+#[cfg(target_os = "ios")]
+use block::ConcreteBlock;
+
+#[cfg(target_os = "ios")]
+use crate::foundation::{NSArray, NSUInteger};
+
+/// An entry in a `PullDownButton`'s menu.
+pub enum PullDownItem {
+    /// An action, with its title and what it does.
+    Action(String, Box<dyn Fn() + Send + Sync + 'static>),
+
+    /// A line between groups of actions.
+    Separator
+}
+
+impl PullDownItem {
+    /// Creates an action with the given title.
+    pub fn action<F: Fn() + Send + Sync + 'static>(title: &str, action: F) -> Self {
+        PullDownItem::Action(title.to_string(), Box::new(action))
+    }
+}
+
+impl fmt::Debug for PullDownItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PullDownItem::Action(title, _) => f.debug_tuple("Action").field(title).finish(),
+            PullDownItem::Separator => f.write_str("Separator")
+        }
+    }
+}
+
+/// A button with a title that shows a menu of actions when clicked, e.g "Sort By ▾".
+#[derive(Debug)]
+pub struct PullDownButton {
+    /// The underlying `NSPopUpButton` (or `UIButton`).
+    pub objc: ShareId<Object>,
+
+    #[cfg(target_os = "macos")]
+    handlers: Vec<TargetActionHandler>,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    pub center_y: LayoutAnchorY
+}
+
+impl PullDownButton {
+    /// Creates a button showing `title`, that offers `items` when clicked.
+    #[cfg(target_os = "macos")]
+    pub fn new(title: &str, items: Vec<PullDownItem>) -> Self {
+        assert_main_thread("PullDownButton::new");
+
+        let mut handlers = vec![];
+
+        let view: id = unsafe {
+            let frame = CGRect::new(&CGPoint::new(0., 0.), &CGSize::new(0., 0.));
+            let button: id = msg_send![class!(NSPopUpButton), alloc];
+            let button: id = msg_send![button, initWithFrame:frame pullsDown:YES];
+            let _: () = msg_send![button, setTranslatesAutoresizingMaskIntoConstraints:NO];
+
+            // In pull-down mode, the first item is never shown in the menu: its title is the
+            // button's.
+            let title = NSString::new(title);
+            let _: () = msg_send![button, addItemWithTitle:title];
+
+            let menu: id = msg_send![button, menu];
+
+            for item in items {
+                match item {
+                    PullDownItem::Action(title, action) => {
+                        let title = NSString::new(&title);
+                        let key = NSString::new("");
+                        let item: id = msg_send![class!(NSMenuItem), alloc];
+                        let item: id = msg_send![item, initWithTitle:title action:nil keyEquivalent:key];
+                        handlers.push(TargetActionHandler::new(&*item, move || action()));
+                        let _: () = msg_send![menu, addItem:item];
+                        let _: () = msg_send![item, release];
+                    },
+
+                    PullDownItem::Separator => {
+                        let separator: id = msg_send![class!(NSMenuItem), separatorItem];
+                        let _: () = msg_send![menu, addItem:separator];
+                    }
+                }
+            }
+
+            button
+        };
+
+        PullDownButton {
+            handlers: handlers,
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            objc: unsafe { ShareId::from_retained_ptr(view) }
+        }
+    }
+
+    /// Creates a button showing `title`, that offers `items` when tapped.
+    #[cfg(target_os = "ios")]
+    pub fn new(title: &str, items: Vec<PullDownItem>) -> Self {
+        assert_main_thread("PullDownButton::new");
+
+        let view: id = unsafe {
+            // `UIButtonTypeSystem`.
+            let button: id = msg_send![class!(UIButton), buttonWithType:1 as NSInteger];
+            let _: () = msg_send![button, setTranslatesAutoresizingMaskIntoConstraints:NO];
+
+            let title = NSString::new(title);
+            let _: () = msg_send![button, setTitle:title forState:0 as NSUInteger];
+
+            let _: () = msg_send![button, setMenu:menu_for(items)];
+            let _: () = msg_send![button, setShowsMenuAsPrimaryAction:YES];
+            button
+        };
+
+        PullDownButton {
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            objc: unsafe { ShareId::from_ptr(view) }
+        }
+    }
+
+    /// Sets the title shown on the button.
+    pub fn set_title(&self, title: &str) {
+        let title = NSString::new(title);
+
+        unsafe {
+            #[cfg(target_os = "macos")]
+            {
+                let item: id = msg_send![&*self.objc, itemAtIndex:0 as NSInteger];
+                let _: () = msg_send![item, setTitle:title];
+            }
+
+            #[cfg(target_os = "ios")]
+            let _: () = msg_send![&*self.objc, setTitle:title forState:0 as NSUInteger];
+        }
+    }
+
+    /// Sets whether the button can be clicked.
+    pub fn set_enabled(&self, enabled: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setEnabled:match enabled {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+}
+
+/// Builds a `UIMenu` of `UIAction`s. Separators split the actions into inline groups, which UIKit
+/// draws with a divider between them.
+#[cfg(target_os = "ios")]
+pub(crate) fn menu_for(items: Vec<PullDownItem>) -> id {
+    /// `UIMenuOptionsDisplayInline`.
+    const DISPLAY_INLINE: NSUInteger = 1;
+
+    let mut groups: Vec<Vec<id>> = vec![vec![]];
+
+    for item in items {
+        match item {
+            PullDownItem::Action(title, action) => {
+                let title = NSString::new(&title);
+                let handler = ConcreteBlock::new(move |_action: id| action());
+                let handler = handler.copy();
+
+                let action: id = unsafe {
+                    msg_send![class!(UIAction), actionWithTitle:title image:nil identifier:nil handler:&*handler]
+                };
+
+                groups.last_mut().unwrap().push(action);
+            },
+
+            PullDownItem::Separator => groups.push(vec![])
+        }
+    }
+
+    let children: Vec<id> = groups.into_iter().filter(|group| !group.is_empty()).map(|group| unsafe {
+        let title = NSString::new("");
+        let children: NSArray = group.into();
+        msg_send![class!(UIMenu), menuWithTitle:title image:nil identifier:nil options:DISPLAY_INLINE
+            children:children.into_inner()]
+    }).collect();
+
+    let title = NSString::new("");
+    let children: NSArray = children.into();
+
+    unsafe {
+        msg_send![class!(UIMenu), menuWithTitle:title children:children.into_inner()]
+    }
+}
+
+impl Layout for PullDownButton {
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.objc.clone()
+    }
+
+    fn add_subview<V: Layout>(&self, _view: &V) {}
+}
@@ -0,0 +1,150 @@
+//! Cameras attached to (or built into) the device.
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::camera::MEDIA_TYPE_VIDEO;
+use crate::foundation::{id, nil, YES, BOOL, NSArray, NSInteger, NSString};
+
+/// Which way a camera faces.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CameraPosition {
+    /// It doesn't face any particular way (e.g, an external webcam).
+    Unspecified,
+
+    /// On the back of the device, facing away from the user.
+    Back,
+
+    /// On the front of the device, facing the user.
+    Front
+}
+
+impl From<NSInteger> for CameraPosition {
+    fn from(position: NSInteger) -> Self {
+        match position {
+            1 => CameraPosition::Back,
+            2 => CameraPosition::Front,
+            _ => CameraPosition::Unspecified
+        }
+    }
+}
+
+/// A camera.
+#[derive(Clone, Debug)]
+pub struct CameraDevice {
+    /// The underlying `AVCaptureDevice`.
+    pub objc: ShareId<Object>
+}
+
+impl CameraDevice {
+    /// Wraps a system-returned `AVCaptureDevice`.
+    pub(crate) fn with(device: id) -> Self {
+        CameraDevice {
+            objc: unsafe { ShareId::from_ptr(device) }
+        }
+    }
+
+    /// Returns the camera the system would pick by default (e.g, the built-in one on a Mac, or
+    /// the back camera on an iPhone), if there is one.
+    pub fn default_device() -> Option<Self> {
+        let media_type = NSString::new(MEDIA_TYPE_VIDEO);
+
+        let device: id = unsafe {
+            msg_send![class!(AVCaptureDevice), defaultDeviceWithMediaType:media_type]
+        };
+
+        match device == nil {
+            true => None,
+            false => Some(CameraDevice::with(device))
+        }
+    }
+
+    /// Returns every camera that's available right now, e.g for offering a choice in settings.
+    #[cfg(target_os = "macos")]
+    pub fn all() -> Vec<Self> {
+        let media_type = NSString::new(MEDIA_TYPE_VIDEO);
+
+        // The discovery session needs device types spelled out, and the one for Continuity Camera
+        // only exists on newer releases; this lists everything, on any of them.
+        NSArray::wrap(unsafe {
+            msg_send![class!(AVCaptureDevice), devicesWithMediaType:media_type]
+        }).map(CameraDevice::with)
+    }
+
+    /// Returns every camera that's available right now, e.g for offering a choice in settings.
+    #[cfg(target_os = "ios")]
+    pub fn all() -> Vec<Self> {
+        let media_type = NSString::new(MEDIA_TYPE_VIDEO);
+
+        let device_types: NSArray = [
+            "AVCaptureDeviceTypeBuiltInWideAngleCamera",
+            "AVCaptureDeviceTypeBuiltInUltraWideCamera",
+            "AVCaptureDeviceTypeBuiltInTelephotoCamera"
+        ].iter().map(|device_type| NSString::new(device_type).into_inner()).collect::<Vec<id>>().into();
+
+        NSArray::wrap(unsafe {
+            let session: id = msg_send![class!(AVCaptureDeviceDiscoverySession),
+                discoverySessionWithDeviceTypes:device_types.into_inner()
+                mediaType:media_type
+                position:0 as NSInteger];
+
+            msg_send![session, devices]
+        }).map(CameraDevice::with)
+    }
+
+    /// Returns the camera with the given `unique_id()`, if it's available.
+    pub fn with_unique_id(unique_id: &str) -> Option<Self> {
+        let unique_id = NSString::new(unique_id);
+
+        let device: id = unsafe {
+            msg_send![class!(AVCaptureDevice), deviceWithUniqueID:unique_id]
+        };
+
+        match device == nil {
+            true => None,
+            false => Some(CameraDevice::with(device))
+        }
+    }
+
+    /// Returns an identifier for this camera that stays the same across launches - store this to
+    /// remember the user's choice.
+    pub fn unique_id(&self) -> String {
+        NSString::wrap(unsafe { msg_send![&*self.objc, uniqueID] }).to_string()
+    }
+
+    /// Returns a name for this camera to show the user (e.g, "FaceTime HD Camera").
+    pub fn name(&self) -> String {
+        NSString::wrap(unsafe { msg_send![&*self.objc, localizedName] }).to_string()
+    }
+
+    /// Returns which way this camera faces.
+    pub fn position(&self) -> CameraPosition {
+        let position: NSInteger = unsafe {
+            msg_send![&*self.objc, position]
+        };
+
+        position.into()
+    }
+
+    /// Returns whether this is an iPhone being used as a Continuity Camera. Always `false` before
+    /// macOS 13, and on iOS.
+    pub fn is_continuity_camera(&self) -> bool {
+        unsafe {
+            let responds: BOOL = msg_send![&*self.objc, respondsToSelector:sel!(isContinuityCamera)];
+
+            if responds != YES {
+                return false;
+            }
+
+            let continuity: BOOL = msg_send![&*self.objc, isContinuityCamera];
+            continuity == YES
+        }
+    }
+}
+
+impl PartialEq for CameraDevice {
+    fn eq(&self, other: &Self) -> bool {
+        self.unique_id() == other.unique_id()
+    }
+}
@@ -0,0 +1,112 @@
+//! Camera capture, via AVFoundation: pick a camera, show a live preview, take photos, and (on
+//! macOS 13 and up, and iOS) scan QR codes.
+//!
+//! The pieces fit together like so: a `CaptureSession` pulls video from a `CameraDevice`, a
+//! `CameraPreview` (a view) shows what the session sees, and the session hands back photos as
+//! `Image`s.
+//!
+//! ```rust,no_run
+//! use cacao::camera::{CameraDevice, CameraPreview, CaptureSession};
+//!
+//! cacao::camera::request_access(|granted| {
+//!     if !granted {
+//!         return;
+//!     }
+//!
+//!     // Back on the main thread - build the session...
+//! });
+//!
+//! let session = CaptureSession::new();
+//! if let Some(camera) = CameraDevice::default_device() {
+//!     session.set_device(&camera).ok();
+//! }
+//!
+//! let preview = CameraPreview::new();
+//! preview.set_session(&session);
+//! session.start();
+//!
+//! session.capture_photo(|result| {
+//!     if let Ok(image) = result {
+//!         // Show or save it...
+//!     }
+//! });
+//! ```
+//!
+//! Using the camera needs an `NSCameraUsageDescription` entry in your `Info.plist`, explaining
+//! why; sandboxed macOS apps also need the `com.apple.security.device.camera` entitlement. On
+//! macOS, `CameraDevice::all()` includes an iPhone being used as a Continuity Camera.
+
+use objc::{class, msg_send, sel, sel_impl};
+use block::ConcreteBlock;
+
+use crate::dispatch::main_async;
+use crate::foundation::{id, BOOL, YES, NSInteger, NSString};
+
+mod device;
+pub use device::{CameraDevice, CameraPosition};
+
+mod preview;
+pub use preview::{CameraPreview, PreviewGravity};
+
+mod session;
+pub use session::CaptureSession;
+
+/// `AVMediaTypeVideo`.
+pub(crate) static MEDIA_TYPE_VIDEO: &str = "vide";
+
+/// Whether the app may use the camera.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CameraAuthorization {
+    /// The user hasn't been asked yet; `request_access()` will ask them.
+    NotDetermined,
+
+    /// Camera access is blocked (e.g, by parental controls or a device management profile), and
+    /// the user can't change that.
+    Restricted,
+
+    /// The user said no. They can change their mind in Settings (or System Preferences).
+    Denied,
+
+    /// The user said yes.
+    Authorized
+}
+
+impl From<NSInteger> for CameraAuthorization {
+    fn from(status: NSInteger) -> Self {
+        match status {
+            1 => CameraAuthorization::Restricted,
+            2 => CameraAuthorization::Denied,
+            3 => CameraAuthorization::Authorized,
+            _ => CameraAuthorization::NotDetermined
+        }
+    }
+}
+
+/// Returns whether the app may use the camera.
+pub fn authorization_status() -> CameraAuthorization {
+    let status: NSInteger = unsafe {
+        let media_type = NSString::new(MEDIA_TYPE_VIDEO);
+        msg_send![class!(AVCaptureDevice), authorizationStatusForMediaType:media_type]
+    };
+
+    status.into()
+}
+
+/// Asks the user for permission to use the camera, if they haven't been asked already, then
+/// calls `handler` (on the main thread) with whether the app has it.
+pub fn request_access<F: FnOnce(bool) + Send + 'static>(handler: F) {
+    let handler = std::cell::Cell::new(Some(handler));
+
+    let completion = ConcreteBlock::new(move |granted: BOOL| {
+        if let Some(handler) = handler.take() {
+            main_async(move || handler(granted == YES));
+        }
+    });
+    let completion = completion.copy();
+
+    unsafe {
+        let media_type = NSString::new(MEDIA_TYPE_VIDEO);
+        let _: () = msg_send![class!(AVCaptureDevice), requestAccessForMediaType:media_type
+            completionHandler:completion];
+    }
+}
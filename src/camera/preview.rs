@@ -0,0 +1,178 @@
+//! A view that shows what a `CaptureSession` sees, backed by an `AVCaptureVideoPreviewLayer`.
+
+use std::sync::Once;
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::camera::CaptureSession;
+use crate::dispatch::assert_main_thread;
+use crate::foundation::{id, NO, NSString};
+use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
+use crate::utils::load_or_register_class;
+
+#[cfg(target_os = "macos")]
+use crate::foundation::YES;
+
+/// How the video fills the view, when their shapes differ.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PreviewGravity {
+    /// Fits all of the video in, letterboxing as needed. This is the default.
+    Fit,
+
+    /// Fills the view, cropping the video as needed. This is usually what you want for a
+    /// viewfinder.
+    Fill,
+
+    /// Stretches the video to the view's shape.
+    Stretch
+}
+
+impl PreviewGravity {
+    /// Returns the matching `AVLayerVideoGravity`.
+    fn to_objc(&self) -> NSString {
+        NSString::new(match self {
+            PreviewGravity::Fit => "AVLayerVideoGravityResizeAspect",
+            PreviewGravity::Fill => "AVLayerVideoGravityResizeAspectFill",
+            PreviewGravity::Stretch => "AVLayerVideoGravityResize"
+        })
+    }
+}
+
+/// Hands AppKit an `AVCaptureVideoPreviewLayer` to back the view with.
+#[cfg(target_os = "macos")]
+extern fn make_backing_layer(_: &Object, _: Sel) -> id {
+    unsafe { msg_send![class!(AVCaptureVideoPreviewLayer), layer] }
+}
+
+/// Tells UIKit to back the view with an `AVCaptureVideoPreviewLayer`.
+#[cfg(target_os = "ios")]
+extern fn layer_class(_: &Class, _: Sel) -> *const Class {
+    class!(AVCaptureVideoPreviewLayer)
+}
+
+/// Injects a view subclass whose layer is an `AVCaptureVideoPreviewLayer`, so the video tracks
+/// the view's size without any extra work.
+fn register_camera_preview_class() -> *const Class {
+    static mut VIEW_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        #[cfg(target_os = "macos")]
+        let superclass = class!(NSView);
+
+        #[cfg(target_os = "ios")]
+        let superclass = class!(UIView);
+
+        VIEW_CLASS = load_or_register_class(superclass, "RSTCameraPreview", |decl| {
+            #[cfg(target_os = "macos")]
+            decl.add_method(sel!(makeBackingLayer), make_backing_layer as extern fn(&Object, _) -> id);
+
+            #[cfg(target_os = "ios")]
+            decl.add_class_method(sel!(layerClass), layer_class as extern fn(&Class, _) -> *const Class);
+        });
+    });
+
+    unsafe { VIEW_CLASS }
+}
+
+/// A live camera preview. Point it at a `CaptureSession` with `set_session()`, and lay it out like
+/// any other view.
+#[derive(Clone, Debug)]
+pub struct CameraPreview {
+    /// The underlying view.
+    pub objc: ShareId<Object>,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    pub center_y: LayoutAnchorY
+}
+
+impl Default for CameraPreview {
+    fn default() -> Self {
+        CameraPreview::new()
+    }
+}
+
+impl CameraPreview {
+    /// Creates a preview, not yet showing anything.
+    pub fn new() -> Self {
+        assert_main_thread("CameraPreview::new");
+
+        let view: id = unsafe {
+            let view: id = msg_send![register_camera_preview_class(), new];
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints:NO];
+
+            #[cfg(target_os = "macos")]
+            let _: () = msg_send![view, setWantsLayer:YES];
+
+            view
+        };
+
+        CameraPreview {
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            objc: unsafe { ShareId::from_retained_ptr(view) }
+        }
+    }
+
+    /// Returns the `AVCaptureVideoPreviewLayer` backing this view.
+    fn preview_layer(&self) -> id {
+        unsafe { msg_send![&*self.objc, layer] }
+    }
+
+    /// Shows what `session` sees. The session still has to be started.
+    pub fn set_session(&self, session: &CaptureSession) {
+        unsafe {
+            let _: () = msg_send![self.preview_layer(), setSession:&*session.objc];
+        }
+    }
+
+    /// Sets how the video fills the view, when their shapes differ.
+    pub fn set_gravity(&self, gravity: PreviewGravity) {
+        unsafe {
+            let _: () = msg_send![self.preview_layer(), setVideoGravity:gravity.to_objc()];
+        }
+    }
+}
+
+impl Layout for CameraPreview {
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.objc.clone()
+    }
+
+    fn add_subview<V: Layout>(&self, view: &V) {
+        let backing_node = view.get_backing_node();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, addSubview:backing_node];
+        }
+    }
+}
@@ -0,0 +1,359 @@
+//! Wraps `AVCaptureSession`, which pulls video from a camera, along with the outputs we hang off
+//! it: one for photos, and one for reading codes.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::Once;
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::camera::CameraDevice;
+use crate::dispatch::{assert_main_thread, background, main_async, QualityOfService};
+use crate::error::{Error, CACAO_ERROR_DOMAIN};
+use crate::foundation::{id, nil, YES, BOOL, NSArray, NSString};
+use crate::image::Image;
+use crate::utils::{clear_ptr, load_or_register_class, try_load};
+
+static CAPTURE_HANDLERS_PTR: &str = "rstCaptureHandlersPtr";
+
+/// `AVMetadataObjectTypeQRCode`.
+static METADATA_TYPE_QR_CODE: &str = "org.iso.QRCode";
+
+/// Holds the callbacks for a `CaptureSession`, for the delegate to find.
+#[derive(Default)]
+struct CaptureHandlers {
+    photo: RefCell<Option<Box<dyn Fn(Result<Image, Error>) + 'static>>>,
+    code: RefCell<Option<Rc<dyn Fn(String) + 'static>>>
+}
+
+/// Called when a photo has been captured (or failed to be). `AVCapturePhotoOutput` calls this on
+/// a private queue, so the result is handed over to the main thread - where the handlers live -
+/// before anything else happens. Everything involved is retained until it gets there.
+extern fn did_finish_processing_photo(this: &Object, _: Sel, _output: id, photo: id, error: id) {
+    let (delegate, photo, error) = unsafe {
+        let delegate: id = msg_send![this, retain];
+        let photo: id = msg_send![photo, retain];
+        let error: id = msg_send![error, retain];
+        (delegate as usize, photo as usize, error as usize)
+    };
+
+    main_async(move || {
+        let (delegate, photo, error) = (delegate as id, photo as id, error as id);
+
+        finish_photo(unsafe { &*delegate }, photo, error);
+
+        unsafe {
+            let _: () = msg_send![delegate, release];
+            let _: () = msg_send![photo, release];
+            let _: () = msg_send![error, release];
+        }
+    });
+}
+
+/// Reads the captured photo, and calls the photo handler with it. Only call this on the main
+/// thread.
+fn finish_photo(this: &Object, photo: id, error: id) {
+    let handlers = match try_load::<CaptureHandlers>(this, CAPTURE_HANDLERS_PTR) {
+        Ok(handlers) => handlers,
+        Err(_) => { return; }
+    };
+
+    let handler = match handlers.photo.borrow_mut().take() {
+        Some(handler) => handler,
+        None => { return; }
+    };
+
+    if error != nil {
+        handler(Err(Error::new(error)));
+        return;
+    }
+
+    let image: id = unsafe {
+        let data: id = msg_send![photo, fileDataRepresentation];
+
+        #[cfg(target_os = "macos")]
+        let image: id = {
+            let image: id = msg_send![class!(NSImage), alloc];
+            msg_send![image, initWithData:data]
+        };
+
+        #[cfg(target_os = "ios")]
+        let image: id = {
+            let image: id = msg_send![class!(UIImage), alloc];
+            msg_send![image, initWithData:data]
+        };
+
+        image
+    };
+
+    handler(match image == nil {
+        true => Err(capture_error("The photo couldn't be read.")),
+        false => Ok(Image(unsafe { ShareId::from_retained_ptr(image) }))
+    });
+}
+
+/// Called (on the main thread) when codes are read from the video.
+extern fn did_output_metadata_objects(this: &Object, _: Sel, _output: id, objects: id, _connection: id) {
+    let handlers = match try_load::<CaptureHandlers>(this, CAPTURE_HANDLERS_PTR) {
+        Ok(handlers) => handlers,
+        Err(_) => { return; }
+    };
+
+    let codes = NSArray::wrap(objects).map(|object| {
+        let value: id = unsafe { msg_send![object, stringValue] };
+
+        match value == nil {
+            true => None,
+            false => Some(NSString::wrap(value).to_string())
+        }
+    });
+
+    // Cloned out of the cell, so the handler is free to replace itself while it runs.
+    let handler = handlers.code.borrow().clone();
+
+    if let Some(handler) = handler {
+        for code in codes.into_iter().flatten() {
+            handler(code);
+        }
+    }
+}
+
+/// Registers an `NSObject` subclass that acts as both an `AVCapturePhotoCaptureDelegate` and an
+/// `AVCaptureMetadataOutputObjectsDelegate`.
+fn register_capture_delegate_class() -> *const Class {
+    static mut DELEGATE_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSObject);
+        DELEGATE_CLASS = load_or_register_class(superclass, "RSTCaptureDelegate", |decl| {
+            decl.add_ivar::<usize>(CAPTURE_HANDLERS_PTR);
+
+            decl.add_method(
+                sel!(captureOutput:didFinishProcessingPhoto:error:),
+                did_finish_processing_photo as extern fn(&Object, _, _, _, _)
+            );
+
+            decl.add_method(
+                sel!(captureOutput:didOutputMetadataObjects:fromConnection:),
+                did_output_metadata_objects as extern fn(&Object, _, _, _, _)
+            );
+        });
+    });
+
+    unsafe { DELEGATE_CLASS }
+}
+
+/// Builds an error for failures on our side of the session.
+fn capture_error(description: &str) -> Error {
+    Error::Cocoa {
+        code: 0,
+        domain: CACAO_ERROR_DOMAIN.to_string(),
+        description: description.to_string()
+    }
+}
+
+/// A capture session: video from a camera, feeding previews, photos and code scanning. Keep this
+/// around for as long as you're using the camera; dropping it stops the session.
+pub struct CaptureSession {
+    /// The underlying `AVCaptureSession`.
+    pub objc: ShareId<Object>,
+
+    photo_output: ShareId<Object>,
+    metadata_output: Option<ShareId<Object>>,
+    delegate: ShareId<Object>,
+    handlers: Box<CaptureHandlers>
+}
+
+impl Default for CaptureSession {
+    fn default() -> Self {
+        CaptureSession::new()
+    }
+}
+
+impl CaptureSession {
+    /// Creates a session, set up for taking photos. Give it a camera with `set_device()`.
+    pub fn new() -> Self {
+        assert_main_thread("CaptureSession::new");
+
+        let handlers = Box::new(CaptureHandlers::default());
+
+        unsafe {
+            let session: id = msg_send![class!(AVCaptureSession), new];
+            let preset = NSString::new("AVCaptureSessionPresetPhoto");
+            let _: () = msg_send![session, setSessionPreset:preset];
+
+            let photo_output: id = msg_send![class!(AVCapturePhotoOutput), new];
+            let can_add: BOOL = msg_send![session, canAddOutput:photo_output];
+            if can_add == YES {
+                let _: () = msg_send![session, addOutput:photo_output];
+            }
+
+            let delegate: id = msg_send![register_capture_delegate_class(), new];
+            let ptr: *const CaptureHandlers = &*handlers;
+            (&mut *delegate).set_ivar(CAPTURE_HANDLERS_PTR, ptr as usize);
+
+            CaptureSession {
+                objc: ShareId::from_retained_ptr(session),
+                photo_output: ShareId::from_retained_ptr(photo_output),
+                metadata_output: None,
+                delegate: ShareId::from_retained_ptr(delegate),
+                handlers: handlers
+            }
+        }
+    }
+
+    /// Uses `device` as the session's camera, replacing whichever one it had. This fails if the
+    /// app isn't allowed to use the camera, or the camera's in use elsewhere.
+    pub fn set_device(&self, device: &CameraDevice) -> Result<(), Error> {
+        let mut error: id = nil;
+
+        let input: id = unsafe {
+            msg_send![class!(AVCaptureDeviceInput), deviceInputWithDevice:&*device.objc error:&mut error]
+        };
+
+        if input == nil {
+            return Err(Error::new(error));
+        }
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, beginConfiguration];
+
+            let inputs = NSArray::wrap(msg_send![&*self.objc, inputs]);
+            for existing in inputs.map(|input| input) {
+                let _: () = msg_send![&*self.objc, removeInput:existing];
+            }
+
+            let can_add: BOOL = msg_send![&*self.objc, canAddInput:input];
+            if can_add == YES {
+                let _: () = msg_send![&*self.objc, addInput:input];
+            }
+
+            let _: () = msg_send![&*self.objc, commitConfiguration];
+
+            match can_add {
+                YES => Ok(()),
+                _ => Err(capture_error("The capture session can't use this camera."))
+            }
+        }
+    }
+
+    /// Starts the flow of video. This takes a moment, so it happens on a background queue.
+    pub fn start(&self) {
+        let session: id = unsafe { msg_send![&*self.objc, retain] };
+        let session = session as usize;
+
+        background(QualityOfService::UserInitiated, move || unsafe {
+            let session = session as id;
+            let _: () = msg_send![session, startRunning];
+            let _: () = msg_send![session, release];
+        });
+    }
+
+    /// Stops the flow of video.
+    pub fn stop(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, stopRunning];
+        }
+    }
+
+    /// Returns whether video is flowing.
+    pub fn is_running(&self) -> bool {
+        let running: BOOL = unsafe {
+            msg_send![&*self.objc, isRunning]
+        };
+
+        running == YES
+    }
+
+    /// Takes a photo, and calls `handler` (on the main thread) with it. The session has to be
+    /// running. If a photo is already being taken, its handler is replaced.
+    pub fn capture_photo<F: Fn(Result<Image, Error>) + 'static>(&self, handler: F) {
+        *self.handlers.photo.borrow_mut() = Some(Box::new(handler));
+
+        unsafe {
+            let settings: id = msg_send![class!(AVCapturePhotoSettings), photoSettings];
+            let _: () = msg_send![&*self.photo_output, capturePhotoWithSettings:settings delegate:&*self.delegate];
+        }
+    }
+
+    /// Scans the video for QR codes, calling `handler` (on the main thread) with the contents of
+    /// each one found. It's called repeatedly while a code is in view, so de-duplicate (or
+    /// `stop()`) as needed.
+    ///
+    /// Returns `false` if code scanning isn't available (before macOS 13), or the session can't
+    /// scan with its current camera.
+    pub fn scan_qr_codes<F: Fn(String) + 'static>(&mut self, handler: F) -> bool {
+        *self.handlers.code.borrow_mut() = Some(Rc::new(handler));
+
+        if self.metadata_output.is_some() {
+            return true;
+        }
+
+        unsafe {
+            let class = Class::get("AVCaptureMetadataOutput");
+            let class = match class {
+                Some(class) => class,
+                None => { return false; }
+            };
+
+            let output: id = msg_send![class, new];
+            let output = ShareId::from_retained_ptr(output);
+
+            let can_add: BOOL = msg_send![&*self.objc, canAddOutput:&*output];
+            if can_add != YES {
+                return false;
+            }
+
+            let _: () = msg_send![&*self.objc, addOutput:&*output];
+
+            // The types on offer depend on the camera, so this has to come after the output's
+            // attached to the session.
+            let qr_code = NSString::new(METADATA_TYPE_QR_CODE).into_inner();
+            let available = NSArray::wrap(msg_send![&*output, availableMetadataObjectTypes]);
+            let contains: BOOL = msg_send![available.into_inner(), containsObject:qr_code];
+
+            if contains != YES {
+                let _: () = msg_send![&*self.objc, removeOutput:&*output];
+                return false;
+            }
+
+            let types: NSArray = vec![qr_code].into();
+            let _: () = msg_send![&*output, setMetadataObjectTypes:types.into_inner()];
+
+            let queue = ::dispatch::ffi::dispatch_get_main_queue() as id;
+            let _: () = msg_send![&*output, setMetadataObjectsDelegate:&*self.delegate queue:queue];
+
+            self.metadata_output = Some(output);
+        }
+
+        true
+    }
+}
+
+impl fmt::Debug for CaptureSession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CaptureSession")
+            .field("objc", &self.objc)
+            .field("running", &self.is_running())
+            .finish()
+    }
+}
+
+impl Drop for CaptureSession {
+    /// Stops the session and breaks the delegate link, as the handlers are going away.
+    fn drop(&mut self) {
+        clear_ptr(&self.delegate, CAPTURE_HANDLERS_PTR);
+
+        unsafe {
+            if let Some(output) = &self.metadata_output {
+                let _: () = msg_send![&**output, setMetadataObjectsDelegate:nil queue:nil];
+            }
+
+            let _: () = msg_send![&*self.objc, stopRunning];
+        }
+    }
+}
@@ -0,0 +1,597 @@
+//! Implements `Color`. Heavily based on the `Color` module in Servo's CSS parser, but tweaked
+//! for (what I believe) is a friendlier API.
+//!
+//! Beyond fixed RGBA colors, a `Color` can be one of the system's semantic colors (which adapt
+//! to light/dark mode, high contrast, and the user's accent color), or a dynamic color that
+//! resolves to one of two colors depending on the appearance it's drawn in:
+//!
+//! ```rust,no_run
+//! use cacao::color::{Color, SystemColor, rgb};
+//!
+//! let text = Color::System(SystemColor::Label);
+//! let brand = Color::dynamic(rgb(20, 40, 120), rgb(140, 170, 255));
+//! ```
+//!
+//! Colors can also be built from hex strings or HSB components, or pulled from your asset catalog:
+//!
+//! ```rust,no_run
+//! use cacao::color::Color;
+//!
+//! let accent = Color::hex("#FF6B35").unwrap();
+//! let pressed = accent.darker(0.2);
+//! let muted = Color::hsb(0.6, 0.3, 0.8, 1.0);
+//! let background = Color::named("CardBackground");
+//! ```
+//!
+//! ## Upgrading
+//! `Color` used to be a `Copy` struct with public `red`, `green`, `blue` and `alpha` fields. It's
+//! now an enum, as dynamic and named colors own their data, and it's `Clone` but not `Copy`. This
+//! is a breaking change:
+//!
+//! - A color you use after passing it by value needs a `.clone()` first.
+//! - The fields are now the `red()`, `green()`, `blue()` and `alpha()` accessors. You can also
+//! match on `Color::Rgba`.
+//!
+//! ```rust,no_run
+//! use cacao::color::rgb;
+//! use cacao::view::View;
+//!
+//! let (left, right) = (View::new(), View::new());
+//! let brand = rgb(20, 40, 120);
+//!
+//! // Previously: `left.set_background_color(brand);`, copying `brand`.
+//! left.set_background_color(brand.clone());
+//! right.set_background_color(brand);
+//! ```
+
+use block::ConcreteBlock;
+
+use core_foundation::base::TCFType;
+use core_graphics::base::CGFloat;
+use core_graphics::color::{CGColor, SysCGColorRef};
+
+use objc::{class, msg_send, sel, sel_impl};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(target_os = "macos")]
+use crate::appearance::Appearance;
+
+use crate::foundation::{id, nil, YES, NO, BOOL, NSString};
+
+mod system;
+pub use system::SystemColor;
+
+/// A color. Fixed colors have red, green, blue, and alpha components in a byte each; system and
+/// dynamic colors are resolved by the platform when they're drawn.
+///
+/// This isn't `Copy`; see the module documentation if you're upgrading from when it was.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Color {
+    /// A fixed color, with red, green, blue, and alpha components in a byte each.
+    Rgba {
+        /// The red component.
+        red: u8,
+        /// The green component.
+        green: u8,
+        /// The blue component.
+        blue: u8,
+        /// The alpha component.
+        alpha: u8
+    },
+
+    /// One of the system's semantic colors.
+    System(SystemColor),
+
+    /// A color that resolves to `light` or `dark` depending on the appearance it's drawn in.
+    Dynamic {
+        /// The color used in light mode.
+        light: Box<Color>,
+        /// The color used in dark mode.
+        dark: Box<Color>
+    },
+
+    /// A color from your app's asset catalog. If no color with this name exists, this draws as
+    /// transparent.
+    Named(String)
+}
+
+impl Default for Color {
+    fn default() -> Color {
+        Color::transparent()
+    }
+}
+
+impl Color {
+    /// Constructs a new Color value from float components. It expects the red,
+    /// green, blue and alpha channels in that order, and all values will be
+    /// clamped to the 0.0 ... 1.0 range.
+    #[inline]
+    pub fn from_floats(red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+        Self::new(
+            clamp_unit_f32(red),
+            clamp_unit_f32(green),
+            clamp_unit_f32(blue),
+            clamp_unit_f32(alpha),
+        )
+    }
+
+    /// Returns a transparent color.
+    #[inline]
+    pub fn transparent() -> Self {
+        Self::new(0, 0, 0, 0)
+    }
+
+    /// Same thing, but with `u8` values instead of floats in the 0 to 1 range.
+    #[inline]
+    pub fn new(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Color::Rgba {
+            red: red,
+            green: green,
+            blue: blue,
+            alpha: alpha,
+        }
+    }
+
+    /// Parses a hex color string - `#RGB`, `#RRGGBB` or `#RRGGBBAA` (the `#` is optional).
+    /// Returns `None` if the string isn't a valid hex color.
+    pub fn hex(hex: &str) -> Option<Self> {
+        let hex = hex.trim().trim_start_matches('#');
+
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let channel = |index: usize| u8::from_str_radix(&hex[index * 2..index * 2 + 2], 16).ok();
+
+        match hex.len() {
+            3 => {
+                let expanded: String = hex.chars().flat_map(|c| vec![c, c]).collect();
+                Color::hex(&expanded)
+            },
+
+            6 => Some(Color::new(channel(0)?, channel(1)?, channel(2)?, 255)),
+            8 => Some(Color::new(channel(0)?, channel(1)?, channel(2)?, channel(3)?)),
+            _ => None
+        }
+    }
+
+    /// Constructs a color from hue, saturation, brightness and alpha components. As with
+    /// `NSColor`, all components are in the 0.0 ... 1.0 range (so a hue of `0.5` is 180 degrees),
+    /// and will be clamped to it.
+    pub fn hsb(hue: f32, saturation: f32, brightness: f32, alpha: f32) -> Self {
+        let hue = hue.max(0.).min(1.) * 6.;
+        let saturation = saturation.max(0.).min(1.);
+        let brightness = brightness.max(0.).min(1.);
+
+        let sector = hue.floor();
+        let fraction = hue - sector;
+        let p = brightness * (1. - saturation);
+        let q = brightness * (1. - saturation * fraction);
+        let t = brightness * (1. - saturation * (1. - fraction));
+
+        let (red, green, blue) = match sector as u8 {
+            0 | 6 => (brightness, t, p),
+            1 => (q, brightness, p),
+            2 => (p, brightness, t),
+            3 => (p, q, brightness),
+            4 => (t, p, brightness),
+            _ => (brightness, p, q)
+        };
+
+        Color::from_floats(red, green, blue, alpha)
+    }
+
+    /// Returns the color with the given name from your app's asset catalog. Colors defined there
+    /// can have light, dark, and high contrast variants, which are resolved at draw time.
+    pub fn named(name: &str) -> Self {
+        Color::Named(name.to_string())
+    }
+
+    /// Returns a color that resolves to `light` or `dark` at draw time, depending on the
+    /// appearance of whatever's drawing it.
+    pub fn dynamic(light: Color, dark: Color) -> Self {
+        Color::Dynamic {
+            light: Box::new(light),
+            dark: Box::new(dark)
+        }
+    }
+
+    /// Maps to NS/UIColor.
+    pub fn into_platform_specific_color(&self) -> id {
+        match self {
+            Color::Rgba { red, green, blue, alpha } => {
+                let red = *red as CGFloat / 255.0;
+                let green = *green as CGFloat / 255.0;
+                let blue = *blue as CGFloat / 255.0;
+                let alpha = *alpha as CGFloat / 255.0;
+
+                #[cfg(target_os = "macos")]
+                let class = class!(NSColor);
+
+                #[cfg(target_os = "ios")]
+                let class = class!(UIColor);
+
+                unsafe {
+                    msg_send![class, colorWithRed:red green:green blue:blue alpha:alpha]
+                }
+            },
+
+            Color::System(color) => color.to_objc(),
+
+            Color::Dynamic { light, dark } => dynamic_color(light, dark),
+
+            Color::Named(name) => {
+                let name = NSString::new(name);
+
+                #[cfg(target_os = "macos")]
+                let color: id = unsafe { msg_send![class!(NSColor), colorNamed:name] };
+
+                #[cfg(target_os = "ios")]
+                let color: id = unsafe { msg_send![class!(UIColor), colorNamed:name] };
+
+                match color == nil {
+                    true => Color::transparent().into_platform_specific_color(),
+                    false => color
+                }
+            }
+        }
+    }
+
+    /// Maps to CGColor, used across platforms. System and dynamic colors are resolved against the
+    /// current appearance; if that changes, you'll need to fetch this again.
+    pub fn cg_color(&self) -> CGColor {
+        match self {
+            Color::Rgba { .. } => {
+                let (red, green, blue, alpha) = self.components();
+                CGColor::rgb(red, green, blue, alpha)
+            },
+
+            _ => unsafe {
+                let color: SysCGColorRef = msg_send![self.into_platform_specific_color(), CGColor];
+                CGColor::wrap_under_get_rule(color)
+            }
+        }
+    }
+
+    /// Returns the red, green, blue and alpha components (from 0 to 1). System and dynamic colors
+    /// are resolved (in the sRGB color space) against the current appearance. Colors that can't
+    /// be expressed that way (e.g, pattern colors) come back as transparent black.
+    fn components(&self) -> (CGFloat, CGFloat, CGFloat, CGFloat) {
+        self.try_components().unwrap_or((0.0, 0.0, 0.0, 0.0))
+    }
+
+    /// Returns the red, green, blue and alpha components (from 0 to 1), if the color can be
+    /// converted to sRGB.
+    fn try_components(&self) -> Option<(CGFloat, CGFloat, CGFloat, CGFloat)> {
+        if let Color::Rgba { red, green, blue, alpha } = self {
+            return Some((
+                *red as CGFloat / 255.0,
+                *green as CGFloat / 255.0,
+                *blue as CGFloat / 255.0,
+                *alpha as CGFloat / 255.0
+            ));
+        }
+
+        let mut red: CGFloat = 0.0;
+        let mut green: CGFloat = 0.0;
+        let mut blue: CGFloat = 0.0;
+        let mut alpha: CGFloat = 0.0;
+
+        let converted: BOOL = unsafe {
+            let color = self.into_platform_specific_color();
+
+            #[cfg(target_os = "macos")]
+            let color: id = {
+                let space: id = msg_send![class!(NSColorSpace), sRGBColorSpace];
+                msg_send![color, colorUsingColorSpace:space]
+            };
+
+            match color == nil {
+                true => NO,
+                false => msg_send![color, getRed:&mut red green:&mut green blue:&mut blue alpha:&mut alpha]
+            }
+        };
+
+        match converted {
+            YES => Some((red, green, blue, alpha)),
+            _ => None
+        }
+    }
+
+    /// Returns the red, green, blue and alpha components, in a byte each. System, dynamic and named
+    /// colors are resolved against the current appearance; colors that can't be converted to sRGB
+    /// (e.g, pattern colors) come back as transparent black. Use `try_to_rgba()` to tell those
+    /// apart.
+    pub fn to_rgba(&self) -> (u8, u8, u8, u8) {
+        self.try_to_rgba().unwrap_or((0, 0, 0, 0))
+    }
+
+    /// Like `to_rgba()`, but returns `None` if the color can't be converted to sRGB.
+    pub fn try_to_rgba(&self) -> Option<(u8, u8, u8, u8)> {
+        if let Color::Rgba { red, green, blue, alpha } = self {
+            return Some((*red, *green, *blue, *alpha));
+        }
+
+        let (red, green, blue, alpha) = self.try_components()?;
+
+        Some((
+            clamp_unit_f32(red as f32),
+            clamp_unit_f32(green as f32),
+            clamp_unit_f32(blue as f32),
+            clamp_unit_f32(alpha as f32)
+        ))
+    }
+
+    /// Returns this color as a `#RRGGBBAA` hex string.
+    pub fn to_hex(&self) -> String {
+        let (red, green, blue, alpha) = self.to_rgba();
+        format!("#{:02X}{:02X}{:02X}{:02X}", red, green, blue, alpha)
+    }
+
+    /// Returns the hue, saturation and brightness components, from 0 to 1.
+    pub fn to_hsb(&self) -> (f32, f32, f32) {
+        let (red, green, blue) = (self.red_f32(), self.green_f32(), self.blue_f32());
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let delta = max - min;
+
+        let hue = match delta == 0. {
+            true => 0.,
+            false if max == red => ((green - blue) / delta).rem_euclid(6.),
+            false if max == green => (blue - red) / delta + 2.,
+            false => (red - green) / delta + 4.
+        } / 6.;
+
+        let saturation = match max == 0. {
+            true => 0.,
+            false => delta / max
+        };
+
+        (hue, saturation, max)
+    }
+
+    /// Returns a fixed copy of this color with the given alpha (from 0 to 1).
+    pub fn with_alpha(&self, alpha: f32) -> Self {
+        let (red, green, blue, _) = self.to_rgba();
+        Color::new(red, green, blue, clamp_unit_f32(alpha))
+    }
+
+    /// Blends this color with `other`, returning a fixed color. A `fraction` of `0.0` returns this
+    /// color, and `1.0` returns `other`.
+    pub fn blend(&self, other: &Color, fraction: f32) -> Self {
+        let fraction = fraction.max(0.).min(1.);
+        let mix = |from: f32, to: f32| from + (to - from) * fraction;
+
+        Color::from_floats(
+            mix(self.red_f32(), other.red_f32()),
+            mix(self.green_f32(), other.green_f32()),
+            mix(self.blue_f32(), other.blue_f32()),
+            mix(self.alpha_f32(), other.alpha_f32())
+        )
+    }
+
+    /// Returns a lighter version of this color, by blending `fraction` of white into it.
+    pub fn lighter(&self, fraction: f32) -> Self {
+        let white = Color::new(255, 255, 255, self.to_rgba().3);
+        self.blend(&white, fraction)
+    }
+
+    /// Returns a darker version of this color, by blending `fraction` of black into it.
+    pub fn darker(&self, fraction: f32) -> Self {
+        let black = Color::new(0, 0, 0, self.to_rgba().3);
+        self.blend(&black, fraction)
+    }
+
+    /// Returns the red channel, from 0 to 255. Non-fixed colors are resolved as in `to_rgba()`.
+    #[inline]
+    pub fn red(&self) -> u8 {
+        self.to_rgba().0
+    }
+
+    /// Returns the green channel, from 0 to 255. Non-fixed colors are resolved as in `to_rgba()`.
+    #[inline]
+    pub fn green(&self) -> u8 {
+        self.to_rgba().1
+    }
+
+    /// Returns the blue channel, from 0 to 255. Non-fixed colors are resolved as in `to_rgba()`.
+    #[inline]
+    pub fn blue(&self) -> u8 {
+        self.to_rgba().2
+    }
+
+    /// Returns the alpha channel, from 0 to 255. Non-fixed colors are resolved as in `to_rgba()`.
+    #[inline]
+    pub fn alpha(&self) -> u8 {
+        self.to_rgba().3
+    }
+
+    /// Returns the red channel in a floating point number form, from 0 to 1.
+    #[inline]
+    pub fn red_f32(&self) -> f32 {
+        self.components().0 as f32
+    }
+
+    /// Returns the green channel in a floating point number form, from 0 to 1.
+    #[inline]
+    pub fn green_f32(&self) -> f32 {
+        self.components().1 as f32
+    }
+
+    /// Returns the blue channel in a floating point number form, from 0 to 1.
+    #[inline]
+    pub fn blue_f32(&self) -> f32 {
+        self.components().2 as f32
+    }
+
+    /// Returns the alpha channel in a floating point number form, from 0 to 1.
+    #[inline]
+    pub fn alpha_f32(&self) -> f32 {
+        self.components().3 as f32
+    }
+}
+
+/// Builds an `NSColor` that asks for `light` or `dark` each time it's drawn, based on the
+/// appearance it's being drawn in.
+#[cfg(target_os = "macos")]
+fn dynamic_color(light: &Color, dark: &Color) -> id {
+    let light = light.clone();
+    let dark = dark.clone();
+
+    let block = ConcreteBlock::new(move |appearance: id| -> id {
+        match Appearance::from_objc(appearance) {
+            Appearance::Dark => dark.into_platform_specific_color(),
+            _ => light.into_platform_specific_color()
+        }
+    });
+    let block = block.copy();
+
+    unsafe {
+        msg_send![class!(NSColor), colorWithName:nil dynamicProvider:block]
+    }
+}
+
+/// Builds a `UIColor` that asks for `light` or `dark` each time it's drawn, based on the trait
+/// collection it's being drawn in.
+#[cfg(target_os = "ios")]
+fn dynamic_color(light: &Color, dark: &Color) -> id {
+    let light = light.clone();
+    let dark = dark.clone();
+
+    let block = ConcreteBlock::new(move |traits: id| -> id {
+        let style: crate::foundation::NSInteger = unsafe { msg_send![traits, userInterfaceStyle] };
+
+        // UIUserInterfaceStyleDark
+        match style {
+            2 => dark.into_platform_specific_color(),
+            _ => light.into_platform_specific_color()
+        }
+    });
+    let block = block.copy();
+
+    unsafe {
+        msg_send![class!(UIColor), colorWithDynamicProvider:block]
+    }
+}
+
+#[inline]
+pub fn rgb(red: u8, green: u8, blue: u8) -> Color {
+    rgba(red, green, blue, 255)
+}
+
+#[inline]
+pub fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Color {
+    Color::new(red, green, blue, alpha)
+}
+
+fn clamp_unit_f32(val: f32) -> u8 {
+    // Whilst scaling by 256 and flooring would provide
+    // an equal distribution of integers to percentage inputs,
+    // this is not what Gecko does so we instead multiply by 255
+    // and round (adding 0.5 and flooring is equivalent to rounding)
+    //
+    // Chrome does something similar for the alpha value, but not
+    // the rgb values.
+    //
+    // See https://bugzilla.mozilla.org/show_bug.cgi?id=1340484
+    //
+    // Clamping to 256 and rounding after would let 1.0 map to 256, and
+    // `256.0_f32 as u8` is undefined behavior:
+    //
+    // https://github.com/rust-lang/rust/issues/10184
+    clamp_floor_256_f32(val * 255.)
+}
+
+fn clamp_floor_256_f32(val: f32) -> u8 {
+    val.round().max(0.).min(255.) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rgb, rgba, Color};
+
+    /// Asserts that two colors' channels are within one of each other, to allow for rounding.
+    fn assert_close(color: &Color, expected: &Color) {
+        let (a, b) = (color.to_rgba(), expected.to_rgba());
+        let close = |x: u8, y: u8| (x as i16 - y as i16).abs() <= 1;
+
+        assert!(
+            close(a.0, b.0) && close(a.1, b.1) && close(a.2, b.2) && close(a.3, b.3),
+            "{:?} isn't close to {:?}", a, b
+        );
+    }
+
+    #[test]
+    fn hex_long() {
+        assert_eq!(Color::hex("#FF6B35"), Some(rgb(255, 107, 53)));
+        assert_eq!(Color::hex("ff6b35"), Some(rgb(255, 107, 53)));
+        assert_eq!(Color::hex("  #FF6B35 "), Some(rgb(255, 107, 53)));
+        assert_eq!(Color::hex("#FF6B3580"), Some(rgba(255, 107, 53, 128)));
+    }
+
+    #[test]
+    fn hex_short() {
+        assert_eq!(Color::hex("#F80"), Some(rgb(255, 136, 0)));
+        assert_eq!(Color::hex("abc"), Some(rgb(170, 187, 204)));
+    }
+
+    #[test]
+    fn hex_invalid() {
+        assert_eq!(Color::hex(""), None);
+        assert_eq!(Color::hex("#"), None);
+        assert_eq!(Color::hex("#GG0000"), None);
+        assert_eq!(Color::hex("#FF 000"), None);
+        assert_eq!(Color::hex("#éé"), None);
+    }
+
+    #[test]
+    fn hex_wrong_length() {
+        assert_eq!(Color::hex("#F"), None);
+        assert_eq!(Color::hex("#FF"), None);
+        assert_eq!(Color::hex("#FF00"), None);
+        assert_eq!(Color::hex("#FF000"), None);
+        assert_eq!(Color::hex("#FF00000"), None);
+        assert_eq!(Color::hex("#FF0000000"), None);
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        assert_eq!(Color::hex("#FF6B3580").unwrap().to_hex(), "#FF6B3580");
+        assert_eq!(rgb(1, 2, 3).to_hex(), "#010203FF");
+    }
+
+    #[test]
+    fn hsb_primaries() {
+        assert_eq!(Color::hsb(0., 1., 1., 1.), rgb(255, 0, 0));
+        assert_eq!(Color::hsb(0.5, 1., 1., 1.), rgb(0, 255, 255));
+        assert_eq!(Color::hsb(0.3, 0., 1., 1.), rgb(255, 255, 255));
+        assert_eq!(Color::hsb(0.3, 1., 0., 0.5), rgba(0, 0, 0, 128));
+    }
+
+    #[test]
+    fn hsb_clamps() {
+        assert_eq!(Color::hsb(2., -1., 5., 1.), rgb(255, 255, 255));
+        assert_eq!(Color::hsb(-1., 1., 1., 1.), rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn hsb_round_trip() {
+        let colors = [
+            rgb(255, 107, 53),
+            rgb(20, 40, 120),
+            rgb(140, 170, 255),
+            rgb(0, 200, 100),
+            rgb(128, 128, 128),
+            rgb(0, 0, 0),
+            rgb(255, 0, 255)
+        ];
+
+        for color in colors.iter() {
+            let (hue, saturation, brightness) = color.to_hsb();
+            assert_close(&Color::hsb(hue, saturation, brightness, 1.), color);
+        }
+    }
+}
@@ -0,0 +1,230 @@
+//! The system's semantic colors. These adapt to the current appearance (light, dark, high
+//! contrast) and the user's accent color, so prefer them over fixed colors wherever you can.
+
+use objc::runtime::Sel;
+use objc::{class, msg_send, sel, sel_impl};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::foundation::id;
+
+/// A semantic (or named system) color. Where a color doesn't exist on a platform, the closest
+/// equivalent is used.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SystemColor {
+    /// Text that contains primary content.
+    Label,
+
+    /// Text of lesser importance than `Label`, e.g a subtitle.
+    SecondaryLabel,
+
+    /// Text of lesser importance than `SecondaryLabel`, e.g disabled text.
+    TertiaryLabel,
+
+    /// Text of lesser importance than `TertiaryLabel`, e.g watermark text.
+    QuaternaryLabel,
+
+    /// Text in an editable text field or text view.
+    Text,
+
+    /// Placeholder text in controls or text views.
+    PlaceholderText,
+
+    /// Selected text.
+    SelectedText,
+
+    /// The background of an editable text field or text view.
+    TextBackground,
+
+    /// The background of selected text.
+    SelectedTextBackground,
+
+    /// Links to other content.
+    Link,
+
+    /// Separators between sections of content.
+    Separator,
+
+    /// The background of selected content in a key window or view.
+    SelectedContentBackground,
+
+    /// The background of selected content in a window or view that isn't key.
+    UnemphasizedSelectedContentBackground,
+
+    /// The gridlines of an interface element, e.g a table view.
+    Grid,
+
+    /// Text in a header cell.
+    HeaderText,
+
+    /// The user's accent color, set in System Preferences.
+    ControlAccent,
+
+    /// The surface of a control.
+    Control,
+
+    /// The background of a large interface element, e.g a browser or table.
+    ControlBackground,
+
+    /// Text on enabled controls.
+    ControlText,
+
+    /// Text on disabled controls.
+    DisabledControlText,
+
+    /// The surface of a selected control.
+    SelectedControl,
+
+    /// Text on a selected control.
+    SelectedControlText,
+
+    /// The background of a window.
+    WindowBackground,
+
+    /// Text in a window's title bar.
+    WindowFrameText,
+
+    /// The background behind a document's content.
+    UnderPageBackground,
+
+    /// The highlight on find results.
+    FindHighlight,
+
+    /// The virtual light source onscreen.
+    Highlight,
+
+    /// The virtual shadow cast by raised objects onscreen.
+    Shadow,
+
+    /// The system red, adjusted for the current appearance.
+    Red,
+
+    /// The system orange, adjusted for the current appearance.
+    Orange,
+
+    /// The system yellow, adjusted for the current appearance.
+    Yellow,
+
+    /// The system green, adjusted for the current appearance.
+    Green,
+
+    /// The system teal, adjusted for the current appearance.
+    Teal,
+
+    /// The system blue, adjusted for the current appearance.
+    Blue,
+
+    /// The system indigo, adjusted for the current appearance.
+    Indigo,
+
+    /// The system purple, adjusted for the current appearance.
+    Purple,
+
+    /// The system pink, adjusted for the current appearance.
+    Pink,
+
+    /// The system brown, adjusted for the current appearance.
+    Brown,
+
+    /// The system gray, adjusted for the current appearance.
+    Gray
+}
+
+impl SystemColor {
+    /// Returns the `NSColor` class method for this color.
+    #[cfg(target_os = "macos")]
+    fn selector(&self) -> Sel {
+        match self {
+            SystemColor::Label => sel!(labelColor),
+            SystemColor::SecondaryLabel => sel!(secondaryLabelColor),
+            SystemColor::TertiaryLabel => sel!(tertiaryLabelColor),
+            SystemColor::QuaternaryLabel => sel!(quaternaryLabelColor),
+            SystemColor::Text => sel!(textColor),
+            SystemColor::PlaceholderText => sel!(placeholderTextColor),
+            SystemColor::SelectedText => sel!(selectedTextColor),
+            SystemColor::TextBackground => sel!(textBackgroundColor),
+            SystemColor::SelectedTextBackground => sel!(selectedTextBackgroundColor),
+            SystemColor::Link => sel!(linkColor),
+            SystemColor::Separator => sel!(separatorColor),
+            SystemColor::SelectedContentBackground => sel!(selectedContentBackgroundColor),
+            SystemColor::UnemphasizedSelectedContentBackground => sel!(unemphasizedSelectedContentBackgroundColor),
+            SystemColor::Grid => sel!(gridColor),
+            SystemColor::HeaderText => sel!(headerTextColor),
+            SystemColor::ControlAccent => sel!(controlAccentColor),
+            SystemColor::Control => sel!(controlColor),
+            SystemColor::ControlBackground => sel!(controlBackgroundColor),
+            SystemColor::ControlText => sel!(controlTextColor),
+            SystemColor::DisabledControlText => sel!(disabledControlTextColor),
+            SystemColor::SelectedControl => sel!(selectedControlColor),
+            SystemColor::SelectedControlText => sel!(selectedControlTextColor),
+            SystemColor::WindowBackground => sel!(windowBackgroundColor),
+            SystemColor::WindowFrameText => sel!(windowFrameTextColor),
+            SystemColor::UnderPageBackground => sel!(underPageBackgroundColor),
+            SystemColor::FindHighlight => sel!(findHighlightColor),
+            SystemColor::Highlight => sel!(highlightColor),
+            SystemColor::Shadow => sel!(shadowColor),
+            SystemColor::Red => sel!(systemRedColor),
+            SystemColor::Orange => sel!(systemOrangeColor),
+            SystemColor::Yellow => sel!(systemYellowColor),
+            SystemColor::Green => sel!(systemGreenColor),
+            SystemColor::Teal => sel!(systemTealColor),
+            SystemColor::Blue => sel!(systemBlueColor),
+            SystemColor::Indigo => sel!(systemIndigoColor),
+            SystemColor::Purple => sel!(systemPurpleColor),
+            SystemColor::Pink => sel!(systemPinkColor),
+            SystemColor::Brown => sel!(systemBrownColor),
+            SystemColor::Gray => sel!(systemGrayColor)
+        }
+    }
+
+    /// Returns the `UIColor` class method for this color. UIKit has fewer semantic colors than
+    /// AppKit, so several of these map to the closest equivalent.
+    #[cfg(target_os = "ios")]
+    fn selector(&self) -> Sel {
+        match self {
+            SystemColor::Label | SystemColor::Text | SystemColor::ControlText |
+            SystemColor::HeaderText | SystemColor::WindowFrameText => sel!(labelColor),
+            SystemColor::SecondaryLabel => sel!(secondaryLabelColor),
+            SystemColor::TertiaryLabel | SystemColor::DisabledControlText => sel!(tertiaryLabelColor),
+            SystemColor::QuaternaryLabel => sel!(quaternaryLabelColor),
+            SystemColor::PlaceholderText => sel!(placeholderTextColor),
+            SystemColor::SelectedText | SystemColor::SelectedControlText => sel!(whiteColor),
+            SystemColor::TextBackground | SystemColor::WindowBackground => sel!(systemBackgroundColor),
+            SystemColor::ControlBackground | SystemColor::UnderPageBackground => sel!(secondarySystemBackgroundColor),
+            SystemColor::Control => sel!(systemFillColor),
+            SystemColor::Link => sel!(linkColor),
+            SystemColor::Separator => sel!(separatorColor),
+            SystemColor::Grid => sel!(opaqueSeparatorColor),
+            SystemColor::SelectedTextBackground | SystemColor::SelectedContentBackground |
+            SystemColor::SelectedControl | SystemColor::ControlAccent => sel!(systemBlueColor),
+            SystemColor::UnemphasizedSelectedContentBackground => sel!(systemGray4Color),
+            SystemColor::FindHighlight => sel!(systemYellowColor),
+            SystemColor::Highlight => sel!(whiteColor),
+            SystemColor::Shadow => sel!(blackColor),
+            SystemColor::Red => sel!(systemRedColor),
+            SystemColor::Orange => sel!(systemOrangeColor),
+            SystemColor::Yellow => sel!(systemYellowColor),
+            SystemColor::Green => sel!(systemGreenColor),
+            SystemColor::Teal => sel!(systemTealColor),
+            SystemColor::Blue => sel!(systemBlueColor),
+            SystemColor::Indigo => sel!(systemIndigoColor),
+            SystemColor::Purple => sel!(systemPurpleColor),
+            SystemColor::Pink => sel!(systemPinkColor),
+            SystemColor::Brown => sel!(systemBrownColor),
+            SystemColor::Gray => sel!(systemGrayColor)
+        }
+    }
+
+    /// Returns the platform color (`NSColor` or `UIColor`) for this system color.
+    pub(crate) fn to_objc(&self) -> id {
+        #[cfg(target_os = "macos")]
+        let class = class!(NSColor);
+
+        #[cfg(target_os = "ios")]
+        let class = class!(UIColor);
+
+        unsafe { msg_send![class, performSelector:self.selector()] }
+    }
+}
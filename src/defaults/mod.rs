@@ -1,4 +1,5 @@
-//! Wraps `NSUserDefaults`, providing an interface to fetch and store small amounts of data.
+//! Wraps `NSUserDefaults`, providing an interface to fetch and store small amounts of data. The
+//! iCloud-synced equivalent, `NSUbiquitousKeyValueStore`, is wrapped by `UbiquitousKeyValueStore`.
 //!
 //! In general, this tries to take an approach popularized by `serde_json`'s `Value` struct. In
 //! this case, `Value` handles wrapping types for insertion/retrieval, shepherding between
@@ -31,6 +32,33 @@
 //! let value = defaults.get("test").unwrap().as_str().unwrap();
 //! assert_eq!(value, "value");
 //! ```
+//!
+//! ## Storing your own types
+//! With the `serde` feature enabled, anything that implements `Serialize` and `Deserialize` can be
+//! stored via `set()` and read back via `get_as()`. Values are stored as property list structures
+//! (dictionaries, arrays, strings and numbers) rather than opaque data, so they're readable with
+//! the `defaults` command.
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "serde")]
+//! # fn main() {
+//! use serde::{Deserialize, Serialize};
+//! use cacao::defaults::UserDefaults;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Preferences {
+//!     theme: String,
+//!     font_size: u32
+//! }
+//!
+//! let mut defaults = UserDefaults::suite("group.com.myapp.shared");
+//! defaults.set("prefs", &Preferences { theme: "dark".into(), font_size: 13 }).unwrap();
+//!
+//! let prefs: Option<Preferences> = defaults.get_as("prefs");
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
+//! ```
 
 use std::collections::HashMap;
 
@@ -38,11 +66,23 @@ use objc::{class, msg_send, sel, sel_impl};
 use objc::runtime::Object;
 use objc_id::Id;
 
+#[cfg(feature = "serde")]
+use serde::{Serialize, de::DeserializeOwned};
+
 use crate::foundation::{id, nil, YES, NO, BOOL, NSData, NSString, NSDictionary, NSNumber};
 
 mod value;
 pub use value::Value;
 
+pub use crate::kvo::ObservationToken;
+use crate::kvo::{observe, ObservingOption};
+
+mod ubiquitous;
+pub use ubiquitous::{ExternalChange, ExternalChangeReason, UbiquitousKeyValueStore};
+
+#[cfg(feature = "serde")]
+pub(crate) mod plist;
+
 /// Wraps and provides methods for interacting with `NSUserDefaults`, which can be used for storing
 /// pieces of information (preferences, or _defaults_) to persist across application launches.
 ///
@@ -139,6 +179,42 @@ impl UserDefaults {
         }
     }
     
+    /// Stores any `Serialize` type for the specified key, encoded as a property list structure.
+    /// Returns an error if the value can't be serialized, or can't be represented as a property
+    /// list (e.g, a top-level `None`).
+    ///
+    /// Requires the `serde` feature.
+    #[cfg(feature = "serde")]
+    pub fn set<K: AsRef<str>, T: Serialize>(&mut self, key: K, value: &T) -> Result<(), serde_json::Error> {
+        let value = plist::to_plist(&serde_json::to_value(value)?)?;
+        let key = NSString::new(key.as_ref());
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setObject:value forKey:key];
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves a value stored via `set()` (or any property list value that matches the shape of
+    /// `T`). Returns `None` if there's nothing stored for the key, or it can't be decoded as `T`.
+    ///
+    /// Requires the `serde` feature.
+    #[cfg(feature = "serde")]
+    pub fn get_as<K: AsRef<str>, T: DeserializeOwned>(&self, key: K) -> Option<T> {
+        let key = NSString::new(key.as_ref());
+
+        let result: id = unsafe {
+            msg_send![&*self.0, objectForKey:key.into_inner()]
+        };
+
+        if result == nil {
+            return None;
+        }
+
+        serde_json::from_value(plist::from_plist(result)).ok()
+    }
+
     /// Remove the default associated with the key. If the key doesn't exist, this is a noop.
     ///
     /// ```rust
@@ -183,44 +259,37 @@ impl UserDefaults {
             return None;
         }
 
-        if NSData::is(result) {
-            let data = NSData::wrap(result);
-            return Some(Value::Data(data.into_vec()));
-        }
-
-        if NSString::is(result) {
-            let s = NSString::wrap(result).to_str().to_string();
-            return Some(Value::String(s));
-        }
+        Value::from_objc(result)
+    }
 
-        // This works, but might not be the best approach. We basically need to inspect the
-        // `NSNumber` returned and see what the wrapped encoding type is. `q` and `d` represent
-        // `NSInteger` (platform specific) and `double` (f64) respectively, but conceivably we
-        // might need others.
-        //
-        // BOOL returns as "c", which... something makes me feel weird there, but testing it seems
-        // reliable.
-        //
-        // For context: https://nshipster.com/type-encodings/
-        if NSNumber::is(result) {
-            let number = NSNumber::wrap(result);
-            
-            return match number.objc_type() {
-                "c" => Some(Value::Bool(number.as_bool())),
-                "d" => Some(Value::Float(number.as_f64())),
-                "q" => Some(Value::Integer(number.as_i64())),
-
-                x => {
-                    // Debugging code that should be removed at some point.
-                    #[cfg(debug_assertions)]
-                    println!("Code: {}", x);
-
-                    None
-                }
-            };
-        }
+    /// Calls `handler` (on the main thread) with the new value whenever the value for `key`
+    /// changes - whether that's from elsewhere in your app, another process sharing the same
+    /// suite, or the `defaults` command. Keep the returned `ObservationToken` around for as long
+    /// as you want to be notified.
+    ///
+    /// Note that keys are observed as key paths, so keys containing a `.` won't work here.
+    ///
+    /// ```rust,no_run
+    /// use cacao::defaults::UserDefaults;
+    ///
+    /// let defaults = UserDefaults::standard();
+    ///
+    /// let token = defaults.observe("theme", |value| {
+    ///     println!("Theme is now {:?}", value);
+    /// });
+    /// ```
+    pub fn observe<K, F>(&self, key: K, handler: F) -> ObservationToken
+    where
+        K: AsRef<str>,
+        F: Fn(Option<Value>) + 'static
+    {
+        let key = key.as_ref();
+        let defaults = UserDefaults(unsafe { Id::from_ptr(&*self.0 as *const Object as id) });
+        let observed_key = key.to_string();
 
-        None
+        observe(&*self.0 as *const Object as id, key, &[ObservingOption::New], move |_| {
+            handler(defaults.get(&observed_key));
+        })
     }
 
     /// Returns a boolean value if the object stored for the specified key is managed by an
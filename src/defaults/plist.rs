@@ -0,0 +1,103 @@
+//! Converts between `serde` types and property list objects (`NSDictionary`, `NSArray`,
+//! `NSString`, `NSNumber` and `NSData`), so that anything implementing `Serialize` and
+//! `Deserialize` can be stored in `UserDefaults` as a structure that other tools (e.g, the
+//! `defaults` command) can read.
+//!
+//! We go by way of `serde_json::Value`, which maps cleanly onto property list types. The one
+//! exception is `null`, which property lists can't hold: `None` fields in maps are skipped, and
+//! `None` values elsewhere are an error.
+
+use serde::ser::Error as SerError;
+use serde_json::{Map, Number, Value as JsonValue};
+
+use objc::runtime::Class;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, YES, BOOL, NSArray, NSData, NSDictionary, NSNumber, NSString};
+
+/// Converts a JSON value into the equivalent property list object.
+pub(crate) fn to_plist(value: &JsonValue) -> Result<id, serde_json::Error> {
+    Ok(match value {
+        JsonValue::Null => {
+            return Err(serde_json::Error::custom("property lists can't hold null values"));
+        },
+
+        JsonValue::Bool(b) => NSNumber::bool(*b).into_inner(),
+
+        JsonValue::Number(number) => match number.as_i64() {
+            Some(i) => NSNumber::integer(i).into_inner(),
+            None => NSNumber::float(number.as_f64().unwrap_or_default()).into_inner()
+        },
+
+        JsonValue::String(s) => NSString::new(s).into_inner(),
+
+        JsonValue::Array(values) => {
+            let objects = values.iter().map(to_plist).collect::<Result<Vec<id>, _>>()?;
+            NSArray::from(objects).into_inner()
+        },
+
+        JsonValue::Object(map) => {
+            let mut dictionary = NSDictionary::new();
+
+            for (key, value) in map.iter() {
+                if value.is_null() {
+                    continue;
+                }
+
+                dictionary.insert(NSString::new(key), to_plist(value)?);
+            }
+
+            dictionary.into_inner()
+        }
+    })
+}
+
+/// Returns whether `object` is an instance of `class` (or a subclass of it).
+fn is_kind_of(object: id, class: &Class) -> bool {
+    let result: BOOL = unsafe { msg_send![object, isKindOfClass:class] };
+    result == YES
+}
+
+/// Converts a property list object into the equivalent JSON value. Anything that isn't a property
+/// list type (or is `nil`) becomes `null`.
+pub(crate) fn from_plist(object: id) -> JsonValue {
+    if object == nil {
+        return JsonValue::Null;
+    }
+
+    if NSString::is(object) {
+        return JsonValue::String(NSString::wrap(object).to_string());
+    }
+
+    if NSNumber::is(object) {
+        let number = NSNumber::wrap(object);
+
+        return match number.objc_type() {
+            "c" | "B" => JsonValue::Bool(number.as_bool()),
+            "d" | "f" => Number::from_f64(number.as_f64()).map_or(JsonValue::Null, JsonValue::Number),
+            _ => JsonValue::Number(number.as_i64().into())
+        };
+    }
+
+    if NSData::is(object) {
+        let data = NSData::wrap(object);
+        return JsonValue::Array(data.bytes().iter().map(|byte| JsonValue::from(*byte)).collect());
+    }
+
+    if is_kind_of(object, class!(NSArray)) {
+        return JsonValue::Array(NSArray::wrap(object).map(from_plist));
+    }
+
+    if is_kind_of(object, class!(NSDictionary)) {
+        let keys = NSArray::wrap(unsafe { msg_send![object, allKeys] });
+
+        let map: Map<String, JsonValue> = keys.map(|key| {
+            let value: id = unsafe { msg_send![object, objectForKey:key] };
+            (NSString::wrap(key).to_string(), from_plist(value))
+        }).into_iter().collect();
+
+        return JsonValue::Object(map);
+    }
+
+    JsonValue::Null
+}
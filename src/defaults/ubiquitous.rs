@@ -0,0 +1,217 @@
+//! Wraps `NSUbiquitousKeyValueStore`, which syncs small amounts of data (settings, the last page
+//! the user was reading, and so on) across a user's devices via iCloud.
+//!
+//! The API mirrors `UserDefaults`. Your app needs the
+//! `com.apple.developer.ubiquity-kvstore-identifier` entitlement, and the store is limited to
+//! 1MB (and 1024 keys) in total.
+//!
+//! ```rust,no_run
+//! use cacao::defaults::{UbiquitousKeyValueStore, Value};
+//!
+//! let mut store = UbiquitousKeyValueStore::default();
+//! store.insert("last-page", Value::Integer(42));
+//!
+//! // Keep this around for as long as you want to be notified.
+//! let observer = UbiquitousKeyValueStore::observe_external_changes(|change| {
+//!     println!("{:?} changed because of {:?}", change.keys, change.reason);
+//! });
+//! ```
+
+use std::rc::Rc;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::Id;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::foundation::{id, nil, YES, NO, BOOL, NSArray, NSInteger, NSString};
+use crate::notification_center::NotificationObserver;
+use crate::defaults::Value;
+
+#[cfg(feature = "serde")]
+use crate::defaults::plist;
+
+static DID_CHANGE_EXTERNALLY: &str = "NSUbiquitousKeyValueStoreDidChangeExternallyNotification";
+static CHANGE_REASON_KEY: &str = "NSUbiquitousKeyValueStoreChangeReasonKey";
+static CHANGED_KEYS_KEY: &str = "NSUbiquitousKeyValueStoreChangedKeysKey";
+
+/// Why values in the store changed externally.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExternalChangeReason {
+    /// Another device changed the values, and they've been synced down.
+    ServerChange,
+
+    /// This is the first sync with iCloud (e.g, the app was just installed on this device).
+    InitialSyncChange,
+
+    /// The app exceeded its storage quota; the store has been reset to the values in iCloud.
+    QuotaViolationChange,
+
+    /// The user signed in to a different iCloud account. All values may have changed.
+    AccountChange
+}
+
+impl From<NSInteger> for ExternalChangeReason {
+    fn from(reason: NSInteger) -> Self {
+        match reason {
+            1 => ExternalChangeReason::InitialSyncChange,
+            2 => ExternalChangeReason::QuotaViolationChange,
+            3 => ExternalChangeReason::AccountChange,
+            _ => ExternalChangeReason::ServerChange
+        }
+    }
+}
+
+/// Describes a set of values that changed externally.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExternalChange {
+    /// Why the values changed.
+    pub reason: ExternalChangeReason,
+
+    /// The keys whose values changed.
+    pub keys: Vec<String>
+}
+
+impl ExternalChange {
+    /// Extracts the change details from an `NSUbiquitousKeyValueStoreDidChangeExternallyNotification`.
+    fn new(notification: id) -> Self {
+        unsafe {
+            let user_info: id = msg_send![notification, userInfo];
+
+            let reason_key = NSString::new(CHANGE_REASON_KEY);
+            let reason: id = msg_send![user_info, objectForKey:reason_key];
+            let reason: NSInteger = match reason == nil {
+                true => 0,
+                false => msg_send![reason, integerValue]
+            };
+
+            let keys_key = NSString::new(CHANGED_KEYS_KEY);
+            let keys: id = msg_send![user_info, objectForKey:keys_key];
+
+            ExternalChange {
+                reason: reason.into(),
+                keys: match keys == nil {
+                    true => vec![],
+                    false => NSArray::wrap(keys).map(|key| NSString::wrap(key).to_string())
+                }
+            }
+        }
+    }
+}
+
+/// Wraps the iCloud key-value store. This is a shared, app-wide store; `default()` always returns
+/// the same one.
+#[derive(Debug)]
+pub struct UbiquitousKeyValueStore(pub Id<Object>);
+
+impl Default for UbiquitousKeyValueStore {
+    /// Returns the shared `NSUbiquitousKeyValueStore`.
+    fn default() -> Self {
+        UbiquitousKeyValueStore(unsafe {
+            Id::from_ptr(msg_send![class!(NSUbiquitousKeyValueStore), defaultStore])
+        })
+    }
+}
+
+impl UbiquitousKeyValueStore {
+    /// Inserts a value for the specified key. This updates the in-memory store immediately, and
+    /// syncs to iCloud at some point after.
+    pub fn insert<K: AsRef<str>>(&mut self, key: K, value: Value) {
+        let key = NSString::new(key.as_ref());
+        let value: id = value.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setObject:value forKey:key];
+        }
+    }
+
+    /// Removes the value associated with the key. If the key doesn't exist, this is a noop.
+    pub fn remove<K: AsRef<str>>(&mut self, key: K) {
+        let key = NSString::new(key.as_ref());
+
+        unsafe {
+            let _: () = msg_send![&*self.0, removeObjectForKey:key];
+        }
+    }
+
+    /// Returns a `Value` for the given key, or `None` if nothing is stored there.
+    pub fn get<K: AsRef<str>>(&self, key: K) -> Option<Value> {
+        let key = NSString::new(key.as_ref());
+
+        let result: id = unsafe {
+            msg_send![&*self.0, objectForKey:key]
+        };
+
+        if result == nil {
+            return None;
+        }
+
+        Value::from_objc(result)
+    }
+
+    /// Stores any `Serialize` type for the specified key, encoded as a property list structure.
+    ///
+    /// Requires the `serde` feature.
+    #[cfg(feature = "serde")]
+    pub fn set<K: AsRef<str>, T: Serialize>(&mut self, key: K, value: &T) -> Result<(), serde_json::Error> {
+        let value = plist::to_plist(&serde_json::to_value(value)?)?;
+        let key = NSString::new(key.as_ref());
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setObject:value forKey:key];
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves a value stored via `set()`. Returns `None` if there's nothing stored for the key,
+    /// or it can't be decoded as `T`.
+    ///
+    /// Requires the `serde` feature.
+    #[cfg(feature = "serde")]
+    pub fn get_as<K: AsRef<str>, T: DeserializeOwned>(&self, key: K) -> Option<T> {
+        let key = NSString::new(key.as_ref());
+
+        let result: id = unsafe {
+            msg_send![&*self.0, objectForKey:key]
+        };
+
+        if result == nil {
+            return None;
+        }
+
+        serde_json::from_value(plist::from_plist(result)).ok()
+    }
+
+    /// Pulls down any changes from iCloud, and schedules local changes to be pushed. You don't
+    /// need to call this regularly; it's useful at launch, to pick up changes made while your
+    /// app wasn't running. Returns `false` if the store couldn't be synchronized (e.g, the app
+    /// is missing the entitlement).
+    pub fn synchronize(&self) -> bool {
+        let result: BOOL = unsafe { msg_send![&*self.0, synchronize] };
+
+        match result {
+            YES => true,
+            NO => false,
+            _ => unreachable!()
+        }
+    }
+
+    /// Calls `handler` (on the main thread) whenever values in the store change because of
+    /// something outside of this process - another device, the initial sync, a quota violation,
+    /// or an iCloud account change. Keep the returned `NotificationObserver` around for as long as
+    /// you want to be notified.
+    pub fn observe_external_changes<F>(handler: F) -> NotificationObserver
+    where
+        F: Fn(ExternalChange) + 'static
+    {
+        let handler = Rc::new(handler);
+        let center: id = unsafe { msg_send![class!(NSNotificationCenter), defaultCenter] };
+
+        NotificationObserver::new(center, &[DID_CHANGE_EXTERNALLY], move |_, notification| {
+            handler(ExternalChange::new(notification));
+        })
+    }
+}
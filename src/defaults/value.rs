@@ -130,6 +130,46 @@ impl Value {
     }
 }
 
+impl Value {
+    /// Converts an object vended by `NSUserDefaults` (or `NSUbiquitousKeyValueStore`) into a
+    /// `Value`. Returns `None` for types we don't (yet) support.
+    pub(crate) fn from_objc(object: id) -> Option<Value> {
+        if NSData::is(object) {
+            let data = NSData::wrap(object);
+            return Some(Value::Data(data.into_vec()));
+        }
+
+        if NSString::is(object) {
+            let s = NSString::wrap(object).to_str().to_string();
+            return Some(Value::String(s));
+        }
+
+        // This works, but might not be the best approach. We basically need to inspect the
+        // `NSNumber` returned and see what the wrapped encoding type is. `q` and `d` represent
+        // `NSInteger` (platform specific) and `double` (f64) respectively, but conceivably we
+        // might need others.
+        //
+        // BOOL returns as "c", which... something makes me feel weird there, but testing it seems
+        // reliable.
+        //
+        // For context: https://nshipster.com/type-encodings/
+        if NSNumber::is(object) {
+            let number = NSNumber::wrap(object);
+            
+            return match number.objc_type() {
+                "c" => Some(Value::Bool(number.as_bool())),
+                "d" | "f" => Some(Value::Float(number.as_f64())),
+                "q" | "i" | "s" | "l" | "Q" | "I" | "S" | "L" | "C" => Some(Value::Integer(number.as_i64())),
+
+                // Anything else isn't something a property list can hold.
+                _ => None
+            };
+        }
+
+        None
+    }
+}
+
 impl From<Value> for id {
     /// Shepherds `Value` types into `NSObject`s that can be stored in `NSUserDefaults`.
     // These currently work, but may not be exhaustive and should be looked over past the preview
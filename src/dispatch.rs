@@ -0,0 +1,265 @@
+//! Helpers for Grand Central Dispatch, for moving work off of (and back onto) the main thread.
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use cacao::dispatch::{background, main_after, main_async, QualityOfService};
+//!
+//! background(QualityOfService::UserInitiated, || {
+//!     let thumbnails = vec![1, 2, 3]; // Expensive work...
+//!
+//!     main_async(move || {
+//!         // Back on the main thread; safe to update the UI.
+//!         println!("Generated {} thumbnails", thumbnails.len());
+//!     });
+//! });
+//!
+//! main_after(Duration::from_millis(250), || {
+//!     println!("A quarter second later...");
+//! });
+//! ```
+//!
+//! For anything more involved, `Queue` (re-exported from the `dispatch` crate) is available, and
+//! `serial_queue()` creates one that runs work in order.
+//!
+//! AppKit and UIKit must only be used from the main thread. Widgets check this when they're
+//! created (in debug builds), and `MainThreadMarker` lets your own code require it at compile
+//! time.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::os::raw::{c_long, c_void};
+use std::sync::Arc;
+use std::time::Duration;
+
+use ::dispatch::ffi::{dispatch_async_f, dispatch_get_global_queue};
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::error::Error;
+use crate::foundation::{BOOL, YES};
+
+pub use ::dispatch::{Queue, QueueAttribute};
+
+/// The quality of service for background work, which determines its priority (and how much
+/// energy the system is willing to spend on it).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum QualityOfService {
+    /// Work the user is actively waiting on, that needs to finish immediately (e.g, animation).
+    UserInteractive,
+
+    /// Work the user started and is waiting on (e.g, opening a document).
+    UserInitiated,
+
+    /// The default quality of service.
+    Default,
+
+    /// Long-running work the user isn't actively waiting on (e.g, an import with a progress bar).
+    Utility,
+
+    /// Work the user isn't aware of (e.g, indexing, backups).
+    Background
+}
+
+impl From<QualityOfService> for c_long {
+    fn from(qos: QualityOfService) -> Self {
+        match qos {
+            QualityOfService::UserInteractive => 0x21,
+            QualityOfService::UserInitiated => 0x19,
+            QualityOfService::Default => 0x15,
+            QualityOfService::Utility => 0x11,
+            QualityOfService::Background => 0x09
+        }
+    }
+}
+
+/// Runs `work` on the main thread, asynchronously. If you're already on the main thread, this
+/// still defers `work` until the current run loop pass completes.
+pub fn main_async<F: FnOnce() + Send + 'static>(work: F) {
+    Queue::main().exec_async(work);
+}
+
+/// Runs `work` on the main thread after `delay`.
+pub fn main_after<F: FnOnce() + Send + 'static>(delay: Duration, work: F) {
+    Queue::main().exec_after(delay, work);
+}
+
+/// Runs `work` on a global concurrent queue with the given quality of service.
+pub fn background<F: FnOnce() + Send + 'static>(qos: QualityOfService, work: F) {
+    let context = Box::into_raw(Box::new(work));
+
+    unsafe {
+        let queue = dispatch_get_global_queue(qos.into(), 0);
+        dispatch_async_f(queue, context as *mut c_void, run_boxed::<F>);
+    }
+}
+
+/// Creates a serial queue, which runs the work submitted to it one at a time, in order. `label`
+/// shows up in debuggers and crash logs; reverse-DNS style (`com.myapp.thumbnails`) is typical.
+pub fn serial_queue(label: &str) -> Queue {
+    Queue::create(label, QueueAttribute::Serial)
+}
+
+/// Creates a concurrent queue, which may run the work submitted to it in parallel.
+pub fn concurrent_queue(label: &str) -> Queue {
+    Queue::create(label, QueueAttribute::Concurrent)
+}
+
+/// Unboxes and runs a closure handed to `dispatch_async_f()`.
+extern fn run_boxed<F: FnOnce()>(context: *mut c_void) {
+    let work = unsafe { Box::from_raw(context as *mut F) };
+    work();
+}
+
+/// Returns whether the current thread is the main thread.
+pub fn is_main_thread() -> bool {
+    let result: BOOL = unsafe { msg_send![class!(NSThread), isMainThread] };
+    result == YES
+}
+
+/// Proof that the current thread is the main thread. It can't be sent to (or shared with) other
+/// threads, so a function that takes one can only ever be called on the main thread - which makes
+/// it a handy way to mark your own UI code as such.
+///
+/// ```rust,no_run
+/// use cacao::dispatch::MainThreadMarker;
+///
+/// fn refresh_sidebar(_: MainThreadMarker) {
+///     // Safe to touch widgets here.
+/// }
+///
+/// let marker = MainThreadMarker::new().expect("Not on the main thread!");
+/// refresh_sidebar(marker);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MainThreadMarker {
+    _not_send: PhantomData<*mut ()>
+}
+
+impl MainThreadMarker {
+    /// Returns a marker if this is the main thread, and `None` otherwise.
+    pub fn new() -> Option<Self> {
+        match is_main_thread() {
+            true => Some(MainThreadMarker { _not_send: PhantomData }),
+            false => None
+        }
+    }
+
+    /// Returns a marker without checking which thread this is.
+    ///
+    /// # Safety
+    ///
+    /// This must be called on the main thread.
+    pub unsafe fn new_unchecked() -> Self {
+        MainThreadMarker { _not_send: PhantomData }
+    }
+
+    /// Returns a marker if this is the main thread, and a `MainThreadViolation` naming `api`
+    /// otherwise.
+    pub fn try_new(api: &'static str) -> Result<Self, Error> {
+        MainThreadMarker::new().ok_or(Error::MainThreadViolation(api))
+    }
+}
+
+/// Checks (in debug builds) that `api` is being called on the main thread, panicking with a
+/// pointer to `main_async()` if not. Calling into AppKit/UIKit from elsewhere tends to crash
+/// much later, somewhere unrelated, so it's worth failing loudly at the call site instead.
+///
+/// Skipped with the `headless` feature on, as test harnesses don't run tests on the main thread.
+#[inline]
+pub(crate) fn assert_main_thread(api: &'static str) {
+    if cfg!(debug_assertions) && !cfg!(feature = "headless") {
+        if let Err(e) = MainThreadMarker::try_new(api) {
+            panic!("{}", e);
+        }
+    }
+}
+
+/// Asserts that a value is safe to move to another thread. Only used to carry values over to the
+/// main queue, where they're used (or dropped).
+struct AssertSend<T>(T);
+
+unsafe impl<T> Send for AssertSend<T> {}
+
+/// Owns a value that may only be used on the main thread, but that's fine to hand around (and
+/// drop) anywhere: when it's dropped off the main thread, the value is sent over there to be
+/// dropped instead.
+struct MainThreadBound<T: 'static>(Option<T>);
+
+unsafe impl<T: 'static> Send for MainThreadBound<T> {}
+unsafe impl<T: 'static> Sync for MainThreadBound<T> {}
+
+impl<T: 'static> MainThreadBound<T> {
+    /// Returns the value. This must only be called on the main thread.
+    fn get(&self) -> &T {
+        assert_main_thread("MainThreadHandle::update");
+
+        match &self.0 {
+            Some(value) => value,
+            None => unreachable!()
+        }
+    }
+}
+
+impl<T: 'static> Drop for MainThreadBound<T> {
+    fn drop(&mut self) {
+        if let Some(value) = self.0.take() {
+            if !is_main_thread() {
+                let value = AssertSend(value);
+                main_async(move || drop(value));
+            }
+        }
+    }
+}
+
+/// A handle to a widget that can be sent to (and cloned across) other threads. Updates made
+/// through it are queued onto the main thread and applied there, in the order they were made -
+/// so e.g a network callback on a worker thread can update your UI directly.
+///
+/// Widgets hand these out via their `handle()` methods.
+///
+/// ```rust,no_run
+/// use cacao::dispatch::{background, QualityOfService};
+/// use cacao::text::Label;
+///
+/// let label = Label::new();
+/// let handle = label.handle();
+///
+/// background(QualityOfService::UserInitiated, move || {
+///     let status = "Synced".to_string(); // Expensive work...
+///     handle.update(move |label| label.set_text(&status));
+/// });
+/// ```
+pub struct MainThreadHandle<T: 'static> {
+    inner: Arc<MainThreadBound<T>>
+}
+
+impl<T: 'static> MainThreadHandle<T> {
+    /// Wraps `value`, which should be a delegate-less clone of a widget.
+    pub(crate) fn new(value: T) -> Self {
+        MainThreadHandle {
+            inner: Arc::new(MainThreadBound(Some(value)))
+        }
+    }
+
+    /// Queues `update` to be run with the widget on the main thread. This returns immediately,
+    /// even when called on the main thread; the update runs on the next pass of the run loop.
+    pub fn update<F: FnOnce(&T) + Send + 'static>(&self, update: F) {
+        let inner = self.inner.clone();
+        main_async(move || update(inner.get()));
+    }
+}
+
+impl<T: 'static> Clone for MainThreadHandle<T> {
+    fn clone(&self) -> Self {
+        MainThreadHandle {
+            inner: self.inner.clone()
+        }
+    }
+}
+
+impl<T: 'static> fmt::Debug for MainThreadHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MainThreadHandle")
+            .field("type", &std::any::type_name::<T>())
+            .finish()
+    }
+}
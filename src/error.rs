@@ -1,29 +1,72 @@
-//! A wrapper for `NSError`.
+//! The error type used throughout the framework.
 //!
-//! It attempts to be thread safe where possible, and extract the "default" usable information out of
-//! an `NSError`. This might not be what you need, though, so if it's missing something... well,
-//! it's up for discussion.
+//! Most failures come from the system, as an `NSError`; we extract the "default" usable
+//! information out of it (domain, code and localized description) so that the error is thread
+//! safe. The remaining variants cover things that go wrong on the Rust side of the bridge. If
+//! something's missing... well, it's up for discussion.
+//!
+//! ```rust,no_run
+//! use cacao::error::Error;
+//! use cacao::filesystem::FileManager;
+//! # fn example(url: url::Url) {
+//!
+//! match FileManager::default().remove_item(url) {
+//!     Ok(()) => {},
+//!     Err(Error::Cocoa { code: 4, .. }) => println!("Already gone."),
+//!     Err(e) => eprintln!("Couldn't remove: {}", e)
+//! }
+//! # }
+//! ```
 
 use std::error;
 use std::fmt;
 
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::{id, nil, NSInteger, NSString};
+use crate::foundation::{id, NSInteger, NSString};
+
+/// The domain used when a Rust-side error is handed to the system as an `NSError`.
+pub static CACAO_ERROR_DOMAIN: &str = "com.cacao-rs";
+
+/// An error, either reported by the system or raised on the Rust side.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// An `NSError` from the system (or an error in the same shape).
+    Cocoa {
+        /// Represents the code. Some of these can be... archaic.
+        code: usize,
+
+        /// Represents the domain of the error.
+        domain: String,
 
-/// A wrapper around pieces of data extracted from `NSError`. This could be improved: right now, it
-/// allocates `String` instances when theoretically it could be avoided, and we might be erasing
-/// certain parts of the `NSError` object that are useful.
-#[derive(Clone, Debug)]
-pub struct Error {
-    /// Represents the code. Some of these can be... archaic.
-    pub code: usize,
+        /// Maps over to `[NSError localizedDescription]`.
+        description: String
+    },
 
-    /// Represents the domain of the error.
-    pub domain: String,
+    /// An API that has to be called on the main thread was called from another thread. Holds the
+    /// name of the API.
+    MainThreadViolation(&'static str),
 
-    /// Maps over to `[NSError localizedDescription]`.
-    pub description: String
+    /// An Objective-C class couldn't be registered with the runtime (e.g, because one with the
+    /// same name already exists). Holds the class name.
+    ClassRegistration(String),
+
+    /// An Objective-C object (or a value stored on one) wasn't what we expected - e.g, it's not
+    /// one of our classes, or the Rust side of it has gone away. Holds a description of the
+    /// problem.
+    Downcast(String),
+
+    /// A list view was asked for a cell with an identifier that was never registered. Holds the
+    /// identifier.
+    CellNotRegistered(&'static str),
+
+    /// A URL couldn't be parsed, or isn't the kind of URL the API needs (e.g, it isn't a file
+    /// URL). Holds a description of the problem.
+    InvalidUrl(String),
+
+    /// A file coordinator returned without running the accessor, and without an `NSError` to
+    /// say why.
+    CoordinationFailed
 }
 
 impl Error {
@@ -39,7 +82,7 @@ impl Error {
             (code, domain, description)
         };
 
-        Error {
+        Error::Cocoa {
             code: code,
             domain: domain.to_str().to_string(),
             description: description.to_str().to_string()
@@ -51,22 +94,66 @@ impl Error {
         Box::new(Error::new(error))
     }
 
+    /// Returns the error code. Rust-side errors have codes of their own, in
+    /// `CACAO_ERROR_DOMAIN`.
+    pub fn code(&self) -> usize {
+        match self {
+            Error::Cocoa { code, .. } => *code,
+            Error::MainThreadViolation(_) => 1,
+            Error::ClassRegistration(_) => 2,
+            Error::Downcast(_) => 3,
+            Error::InvalidUrl(_) => 4,
+            Error::CellNotRegistered(_) => 5,
+            Error::CoordinationFailed => 6
+        }
+    }
+
+    /// Returns the error domain.
+    pub fn domain(&self) -> &str {
+        match self {
+            Error::Cocoa { domain, .. } => domain,
+            _ => CACAO_ERROR_DOMAIN
+        }
+    }
+
     /// Used for cases where we need to return an `NSError` back to the system (e.g, top-level
     /// error handling). We just create a new `NSError` so the `Error` crate can be mostly
     /// thread safe.
     pub fn into_nserror(self) -> id {
+        let domain = NSString::new(self.domain());
+        let code = self.code() as NSInteger;
+        let key = NSString::new("NSLocalizedDescription");
+        let description = NSString::new(&self.to_string());
+
         unsafe {
-            let domain = NSString::new(&self.domain);
-            let code = self.code as NSInteger;
-            msg_send![class!(NSError), errorWithDomain:domain code:code userInfo:nil]
+            let user_info: id = msg_send![class!(NSDictionary), dictionaryWithObject:description forKey:key];
+            msg_send![class!(NSError), errorWithDomain:domain code:code userInfo:user_info]
         }
     }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description)
+        match self {
+            Error::Cocoa { description, .. } => write!(f, "{}", description),
+            Error::MainThreadViolation(api) => write!(
+                f,
+                "{} must be called on the main thread. Use cacao::dispatch::main_async() to get there from a background thread.",
+                api
+            ),
+            Error::ClassRegistration(name) => write!(f, "Unable to register the Objective-C class {}.", name),
+            Error::Downcast(problem) => write!(f, "Unexpected object: {}.", problem),
+            Error::InvalidUrl(problem) => write!(f, "{}", problem),
+            Error::CellNotRegistered(identifier) => write!(f, "No cell is registered for the identifier {}.", identifier),
+            Error::CoordinationFailed => write!(f, "The file coordinator didn't run the accessor.")
+        }
     }
 }
 
 impl error::Error for Error {}
+
+impl From<url::ParseError> for Error {
+    fn from(error: url::ParseError) -> Self {
+        Error::InvalidUrl(error.to_string())
+    }
+}
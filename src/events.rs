@@ -1,7 +1,9 @@
 //! Hoists some type definitions in a way that I personally find cleaner than what's in the Servo
 //! code.
 
-use crate::foundation::NSUInteger;
+use objc::runtime::Sel;
+
+use crate::foundation::{NSInteger, NSUInteger};
 
 #[derive(Clone, Copy, Debug)]
 pub enum EventModifierFlag {
@@ -39,3 +41,112 @@ impl From<&EventModifierFlag> for NSUInteger {
 pub enum EventType {
     KeyDown
 }
+
+/// How a Force Touch trackpad responds to pressure over a view: the clicks it gives, and the
+/// pressure stages it reports. Set with `View::set_pressure_behavior()`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PressureBehavior {
+    /// A normal click, then a deeper "force click". This is the default.
+    PrimaryDefault,
+
+    /// A single click, with no force click.
+    PrimaryClick,
+
+    /// Pressure is reported continuously, with no clicks after the first - e.g, for a drawing app
+    /// varying line thickness.
+    PrimaryGeneric,
+
+    /// Up to five stages, with a click at each - e.g, for fast-forwarding at increasing speeds.
+    PrimaryAccelerator,
+
+    /// A click, then a force click that's handled by the app rather than the system (e.g, Look
+    /// Up).
+    PrimaryDeepClick,
+
+    /// Like `PrimaryDeepClick`, but while dragging. This is what spring-loaded drag targets use.
+    PrimaryDeepDrag
+}
+
+impl From<PressureBehavior> for NSInteger {
+    fn from(behavior: PressureBehavior) -> Self {
+        match behavior {
+            PressureBehavior::PrimaryDefault => 0,
+            PressureBehavior::PrimaryClick => 1,
+            PressureBehavior::PrimaryGeneric => 2,
+            PressureBehavior::PrimaryAccelerator => 3,
+            PressureBehavior::PrimaryDeepClick => 5,
+            PressureBehavior::PrimaryDeepDrag => 6
+        }
+    }
+}
+
+/// An action that menu items and toolbar items send along the responder chain (to the focused
+/// view, then up through its superviews, its window, and the app), rather than to a fixed target.
+/// Whichever responder handles it is also asked whether it's currently possible, which is how
+/// items like Copy grey out when there's nothing selected.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    /// `copy:`
+    Copy,
+
+    /// `cut:`
+    Cut,
+
+    /// `paste:`
+    Paste,
+
+    /// `delete:`
+    Delete,
+
+    /// `selectAll:`
+    SelectAll,
+
+    /// `undo:`
+    Undo,
+
+    /// `redo:`
+    Redo,
+
+    /// `performFindPanelAction:`
+    Find,
+
+    /// Any other action, by its selector name (e.g, `"toggleSidebar:"`).
+    Other(String)
+}
+
+impl Command {
+    /// Returns the name of the selector this command is sent as.
+    pub fn selector_name(&self) -> &str {
+        match self {
+            Command::Copy => "copy:",
+            Command::Cut => "cut:",
+            Command::Paste => "paste:",
+            Command::Delete => "delete:",
+            Command::SelectAll => "selectAll:",
+            Command::Undo => "undo:",
+            Command::Redo => "redo:",
+            Command::Find => "performFindPanelAction:",
+            Command::Other(name) => name
+        }
+    }
+
+    /// Returns the selector this command is sent as.
+    pub(crate) fn selector(&self) -> Sel {
+        Sel::register(self.selector_name())
+    }
+
+    /// Returns the command for a selector.
+    pub(crate) fn from_selector(selector: Sel) -> Self {
+        match selector.name() {
+            "copy:" => Command::Copy,
+            "cut:" => Command::Cut,
+            "paste:" => Command::Paste,
+            "delete:" => Command::Delete,
+            "selectAll:" => Command::SelectAll,
+            "undo:" => Command::Undo,
+            "redo:" => Command::Redo,
+            "performFindPanelAction:" => Command::Find,
+            name => Command::Other(name.to_string())
+        }
+    }
+}
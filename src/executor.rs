@@ -0,0 +1,132 @@
+//! A small executor that runs `async` Rust on the main run loop, so that e.g the result of a
+//! network request can update your UI directly, without a channel or a `Dispatcher` in between.
+//!
+//! Futures spawned here are polled on the main thread (and so don't need to be `Send`). When a
+//! future is woken - from any thread - it's re-polled via the main dispatch queue, which the run
+//! loop drains alongside everything else.
+//!
+//! ```rust,no_run
+//! use cacao::executor::spawn;
+//! use cacao::text::Label;
+//!
+//! # async fn fetch_greeting() -> String { String::new() }
+//! let label = Label::new();
+//!
+//! spawn(async move {
+//!     let greeting = fetch_greeting().await;
+//!     label.set_text(&greeting);
+//! });
+//! ```
+//!
+//! This doesn't provide any I/O or timers of its own; bring those from whichever runtime-agnostic
+//! crates you like.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll, Wake, Waker};
+
+use dispatch::Queue;
+
+use crate::dispatch::MainThreadMarker;
+
+/// A spawned future, erased.
+type Task = Pin<Box<dyn Future<Output = ()> + 'static>>;
+
+thread_local! {
+    /// Futures that are waiting to be polled, keyed by task ID. Only ever touched on the main
+    /// thread.
+    static TASKS: RefCell<HashMap<usize, (Task, Arc<TaskWaker>)>> = RefCell::new(HashMap::new());
+
+    /// The ID to give the next spawned task.
+    static NEXT_TASK_ID: Cell<usize> = Cell::new(0);
+}
+
+/// Wakes a task by scheduling a poll on the main queue.
+#[derive(Debug)]
+struct TaskWaker {
+    id: usize,
+
+    /// Whether a poll is already scheduled, so that repeated wakes don't pile up.
+    scheduled: AtomicBool
+}
+
+impl TaskWaker {
+    /// Schedules a poll of this task, if one isn't already pending.
+    fn schedule(&self) {
+        if self.scheduled.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let id = self.id;
+        Queue::main().exec_async(move || poll(id));
+    }
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.schedule();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.schedule();
+    }
+}
+
+/// Polls the task with the given ID, dropping it if it's complete. The task is taken out of
+/// `TASKS` while it's polled, so that it can itself spawn (or wake) other tasks.
+fn poll(id: usize) {
+    let task = TASKS.with(|tasks| tasks.borrow_mut().remove(&id));
+
+    let (mut future, waker) = match task {
+        Some(task) => task,
+        None => { return; }
+    };
+
+    waker.scheduled.store(false, Ordering::Release);
+
+    let pending = {
+        let task_waker = Waker::from(waker.clone());
+        let mut context = Context::from_waker(&task_waker);
+        future.as_mut().poll(&mut context).is_pending()
+    };
+
+    if pending {
+        TASKS.with(|tasks| {
+            tasks.borrow_mut().insert(id, (future, waker));
+        });
+    }
+}
+
+/// Spawns `future` onto the main run loop. It's first polled on the next pass of the run loop,
+/// and runs until it completes; there's no handle to cancel it, so if you need that, select on
+/// a signal of your own inside the future.
+///
+/// This must be called from the main thread, as the future isn't `Send`; it panics otherwise.
+pub fn spawn<F: Future<Output = ()> + 'static>(future: F) {
+    // Tasks live in a thread local that only the main queue polls, so a future spawned anywhere
+    // else would never run. This is checked in release builds too, unlike most main-thread APIs.
+    if let Err(e) = MainThreadMarker::try_new("executor::spawn") {
+        panic!("{}", e);
+    }
+
+    let id = NEXT_TASK_ID.with(|next| {
+        let id = next.get();
+        next.set(id.wrapping_add(1));
+        id
+    });
+
+    let waker = Arc::new(TaskWaker {
+        id: id,
+        scheduled: AtomicBool::new(false)
+    });
+
+    TASKS.with(|tasks| {
+        tasks.borrow_mut().insert(id, (Box::pin(future), waker.clone()));
+    });
+
+    waker.schedule();
+}
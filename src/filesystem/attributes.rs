@@ -0,0 +1,144 @@
+//! Types describing files and directories, as returned by `FileManager`.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use objc::{msg_send, sel, sel_impl};
+use url::Url;
+
+use crate::filesystem::enums::FileType;
+use crate::foundation::{id, nil, NSNumber, NSString};
+
+/// Converts an `NSDate` (which may be `nil`) to a `SystemTime`.
+pub(crate) fn system_time(date: id) -> Option<SystemTime> {
+    if date == nil {
+        return None;
+    }
+
+    let seconds: f64 = unsafe { msg_send![date, timeIntervalSince1970] };
+
+    match seconds >= 0. {
+        true => UNIX_EPOCH.checked_add(Duration::from_secs_f64(seconds)),
+        false => UNIX_EPOCH.checked_sub(Duration::from_secs_f64(-seconds))
+    }
+}
+
+/// Looks up `key` in an `NSDictionary`.
+pub(crate) fn value_for_key(dictionary: id, key: &str) -> id {
+    let key = NSString::new(key);
+    unsafe { msg_send![dictionary, objectForKey:key] }
+}
+
+/// Looks up an `NSNumber` in an `NSDictionary`.
+pub(crate) fn number_for_key(dictionary: id, key: &str) -> Option<NSNumber> {
+    match value_for_key(dictionary, key) {
+        value if value == nil => None,
+        value => Some(NSNumber::wrap(value))
+    }
+}
+
+/// Looks up an `NSString` in an `NSDictionary`.
+pub(crate) fn string_for_key(dictionary: id, key: &str) -> Option<String> {
+    match value_for_key(dictionary, key) {
+        value if value == nil => None,
+        value => Some(NSString::wrap(value).to_string())
+    }
+}
+
+/// An item in a directory listing, from `FileManager::contents_of_directory()`. Apart from `url`,
+/// fields are only filled in if the corresponding `ResourceKey` was requested.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DirectoryEntry {
+    /// The location of the item.
+    pub url: Url,
+
+    /// The name of the item.
+    pub name: Option<String>,
+
+    /// Whether the item is a directory.
+    pub is_directory: Option<bool>,
+
+    /// Whether the item is a regular file.
+    pub is_regular_file: Option<bool>,
+
+    /// Whether the item is a symbolic link.
+    pub is_symbolic_link: Option<bool>,
+
+    /// Whether the item is hidden.
+    pub is_hidden: Option<bool>,
+
+    /// The size of the item, in bytes.
+    pub file_size: Option<u64>,
+
+    /// When the item was created.
+    pub creation_date: Option<SystemTime>,
+
+    /// When the item's contents were last modified.
+    pub modification_date: Option<SystemTime>
+}
+
+impl DirectoryEntry {
+    /// Builds an entry from a URL and the `NSDictionary` of resource values fetched for it.
+    pub(crate) fn new(url: Url, values: id) -> Self {
+        if values == nil {
+            return DirectoryEntry {
+                url: url,
+                name: None,
+                is_directory: None,
+                is_regular_file: None,
+                is_symbolic_link: None,
+                is_hidden: None,
+                file_size: None,
+                creation_date: None,
+                modification_date: None
+            };
+        }
+
+        DirectoryEntry {
+            url: url,
+            name: string_for_key(values, "NSURLNameKey"),
+            is_directory: number_for_key(values, "NSURLIsDirectoryKey").map(|n| n.as_bool()),
+            is_regular_file: number_for_key(values, "NSURLIsRegularFileKey").map(|n| n.as_bool()),
+            is_symbolic_link: number_for_key(values, "NSURLIsSymbolicLinkKey").map(|n| n.as_bool()),
+            is_hidden: number_for_key(values, "NSURLIsHiddenKey").map(|n| n.as_bool()),
+            file_size: number_for_key(values, "NSURLFileSizeKey").map(|n| n.as_i64() as u64),
+            creation_date: system_time(value_for_key(values, "NSURLCreationDateKey")),
+            modification_date: system_time(value_for_key(values, "NSURLContentModificationDateKey"))
+        }
+    }
+}
+
+/// The attributes of a file or directory, from `FileManager::attributes_of_item()`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileAttributes {
+    /// The type of the item.
+    pub file_type: FileType,
+
+    /// The size of the item, in bytes.
+    pub size: u64,
+
+    /// When the item was created, if known.
+    pub creation_date: Option<SystemTime>,
+
+    /// When the item was last modified, if known.
+    pub modification_date: Option<SystemTime>,
+
+    /// The POSIX permissions of the item (e.g, `0o644`).
+    pub posix_permissions: u16,
+
+    /// The name of the item's owner, if known.
+    pub owner: Option<String>
+}
+
+impl FileAttributes {
+    /// Builds attributes from the `NSDictionary` returned by `attributesOfItemAtPath:error:`.
+    pub(crate) fn new(attributes: id) -> Self {
+        FileAttributes {
+            file_type: string_for_key(attributes, "NSFileType").map_or(FileType::Unknown, |t| FileType::from(t.as_str())),
+            size: number_for_key(attributes, "NSFileSize").map_or(0, |n| n.as_i64() as u64),
+            creation_date: system_time(value_for_key(attributes, "NSFileCreationDate")),
+            modification_date: system_time(value_for_key(attributes, "NSFileModificationDate")),
+            posix_permissions: number_for_key(attributes, "NSFilePosixPermissions").map_or(0, |n| n.as_i64() as u16),
+            owner: string_for_key(attributes, "NSFileOwnerAccountName")
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Wraps `NSFileCoordinator`, for reading and writing files that other processes (or other parts
+//! of your app) might be using at the same time - e.g, documents in iCloud Drive, or files in a
+//! shared app group container.
+//!
+//! ```rust,no_run
+//! use url::Url;
+//! use cacao::filesystem::{FileCoordinator, ReadingOption, WritingOption};
+//!
+//! let url = Url::parse("file:///tmp/notes.txt").unwrap();
+//! let coordinator = FileCoordinator::new();
+//!
+//! let contents = coordinator.coordinate_read(url.clone(), &[], |url| {
+//!     std::fs::read_to_string(url.path())
+//! });
+//!
+//! coordinator.coordinate_write(url, &[WritingOption::ForReplacing], |url| {
+//!     std::fs::write(url.path(), "Updated")
+//! }).unwrap();
+//! ```
+
+use std::cell::RefCell;
+
+use block::ConcreteBlock;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::Id;
+use url::Url;
+
+use crate::error::Error;
+use crate::filesystem::{nsurl, url};
+use crate::filesystem::enums::{ReadingOption, WritingOption};
+use crate::filesystem::presenter::PresentedItem;
+use crate::foundation::{id, nil, NSUInteger};
+
+/// Coordinates access to files, so that reads and writes from different processes don't step on
+/// each other. Accessors run synchronously, on the calling thread - and may block while waiting
+/// on other processes - so avoid coordinating from the main thread where you can.
+#[derive(Debug)]
+pub struct FileCoordinator {
+    /// The underlying `NSFileCoordinator`.
+    pub objc: Id<Object>
+}
+
+impl Default for FileCoordinator {
+    fn default() -> Self {
+        FileCoordinator::new()
+    }
+}
+
+impl FileCoordinator {
+    /// Creates a new coordinator.
+    pub fn new() -> Self {
+        FileCoordinator {
+            objc: unsafe {
+                let coordinator: id = msg_send![class!(NSFileCoordinator), alloc];
+                let coordinator: id = msg_send![coordinator, initWithFilePresenter:nil];
+                Id::from_retained_ptr(coordinator)
+            }
+        }
+    }
+
+    /// Creates a new coordinator on behalf of `presenter`, so that it isn't notified about its own
+    /// reads and writes.
+    pub fn with_presenter<T>(presenter: &PresentedItem<T>) -> Self {
+        FileCoordinator {
+            objc: unsafe {
+                let coordinator: id = msg_send![class!(NSFileCoordinator), alloc];
+                let coordinator: id = msg_send![coordinator, initWithFilePresenter:&*presenter.objc];
+                Id::from_retained_ptr(coordinator)
+            }
+        }
+    }
+
+    /// Waits until it's safe to read the item at `url`, then calls `accessor` with the URL to
+    /// read from (which may differ from `url`, e.g if the item was moved), returning its result.
+    pub fn coordinate_read<F, R>(&self, url: Url, options: &[ReadingOption], accessor: F) -> Result<R, Error>
+    where
+        F: FnOnce(Url) -> R
+    {
+        let options = options.iter().fold(0, |opts, option| opts | NSUInteger::from(option));
+        let accessor = RefCell::new(Some(accessor));
+        let result = RefCell::new(None);
+
+        let block = ConcreteBlock::new(|new_url: id| {
+            if let Some(accessor) = accessor.borrow_mut().take() {
+                *result.borrow_mut() = Some(match self::url(new_url) {
+                    Some(new_url) => Ok(accessor(new_url)),
+                    None => Err(Error::InvalidUrl("The coordinated URL was invalid.".to_string()))
+                });
+            }
+        });
+
+        let mut error: id = nil;
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, coordinateReadingItemAtURL:nsurl(&url)
+                options:options
+                error:&mut error
+                byAccessor:&*block];
+        }
+
+        if error != nil {
+            return Err(Error::new(error));
+        }
+
+        result.into_inner().unwrap_or(Err(Error::CoordinationFailed))
+    }
+
+    /// Waits until it's safe to write to the item at `url`, then calls `accessor` with the URL to
+    /// write to (which may differ from `url`, e.g if the item was moved), returning its result.
+    /// Presenters of the item are asked to save their changes first.
+    pub fn coordinate_write<F, R>(&self, url: Url, options: &[WritingOption], accessor: F) -> Result<R, Error>
+    where
+        F: FnOnce(Url) -> R
+    {
+        let options = options.iter().fold(0, |opts, option| opts | NSUInteger::from(option));
+        let accessor = RefCell::new(Some(accessor));
+        let result = RefCell::new(None);
+
+        let block = ConcreteBlock::new(|new_url: id| {
+            if let Some(accessor) = accessor.borrow_mut().take() {
+                *result.borrow_mut() = Some(match self::url(new_url) {
+                    Some(new_url) => Ok(accessor(new_url)),
+                    None => Err(Error::InvalidUrl("The coordinated URL was invalid.".to_string()))
+                });
+            }
+        });
+
+        let mut error: id = nil;
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, coordinateWritingItemAtURL:nsurl(&url)
+                options:options
+                error:&mut error
+                byAccessor:&*block];
+        }
+
+        if error != nil {
+            return Err(Error::new(error));
+        }
+
+        result.into_inner().unwrap_or(Err(Error::CoordinationFailed))
+    }
+
+    /// Cancels any coordinated reads or writes this coordinator is waiting on.
+    pub fn cancel(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, cancel];
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Implements an `NSFileManagerDelegate`, which forwards to a `FileManagerDelegate`.
+
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, sel, sel_impl};
+use url::Url;
+
+use crate::error::Error;
+use crate::filesystem::url;
+use crate::filesystem::enums::FileOperation;
+use crate::filesystem::traits::FileManagerDelegate;
+use crate::foundation::{id, YES, NO, BOOL};
+use crate::utils::load_or_register_class_for;
+
+pub(crate) static FILE_MANAGER_DELEGATE_PTR: &str = "rstFileManagerDelegatePtr";
+
+/// Converts a Rust `bool` into an Objective-C `BOOL`.
+fn to_objc(result: bool) -> BOOL {
+    match result {
+        true => YES,
+        false => NO
+    }
+}
+
+/// Forwards `fileManager:shouldCopyItemAtURL:toURL:`.
+extern fn should_copy<T: FileManagerDelegate>(this: &Object, _: Sel, _: id, from: id, to: id) -> BOOL {
+    let delegate = load_or_return!(T, this, FILE_MANAGER_DELEGATE_PTR, YES);
+
+    to_objc(match (url(from), url(to)) {
+        (Some(from), Some(to)) => delegate.should_copy(from, to),
+        _ => true
+    })
+}
+
+/// Forwards `fileManager:shouldMoveItemAtURL:toURL:`.
+extern fn should_move<T: FileManagerDelegate>(this: &Object, _: Sel, _: id, from: id, to: id) -> BOOL {
+    let delegate = load_or_return!(T, this, FILE_MANAGER_DELEGATE_PTR, YES);
+
+    to_objc(match (url(from), url(to)) {
+        (Some(from), Some(to)) => delegate.should_move(from, to),
+        _ => true
+    })
+}
+
+/// Forwards `fileManager:shouldLinkItemAtURL:toURL:`.
+extern fn should_link<T: FileManagerDelegate>(this: &Object, _: Sel, _: id, from: id, to: id) -> BOOL {
+    let delegate = load_or_return!(T, this, FILE_MANAGER_DELEGATE_PTR, YES);
+
+    to_objc(match (url(from), url(to)) {
+        (Some(from), Some(to)) => delegate.should_link(from, to),
+        _ => true
+    })
+}
+
+/// Forwards `fileManager:shouldRemoveItemAtURL:`.
+extern fn should_remove<T: FileManagerDelegate>(this: &Object, _: Sel, _: id, item: id) -> BOOL {
+    let delegate = load_or_return!(T, this, FILE_MANAGER_DELEGATE_PTR, YES);
+
+    to_objc(match url(item) {
+        Some(item) => delegate.should_remove(item),
+        None => true
+    })
+}
+
+/// Asks the delegate whether to carry on after `error`, if the URLs involved were valid.
+fn should_proceed<T: FileManagerDelegate>(this: &Object, error: id, operation: Option<FileOperation>) -> BOOL {
+    let delegate = load_or_return!(T, this, FILE_MANAGER_DELEGATE_PTR, NO);
+
+    to_objc(match operation {
+        Some(operation) => delegate.should_proceed_after_error(Error::new(error), operation),
+        None => false
+    })
+}
+
+/// Forwards `fileManager:shouldProceedAfterError:copyingItemAtURL:toURL:`.
+extern fn should_proceed_after_copy_error<T: FileManagerDelegate>(this: &Object, _: Sel, _: id, error: id, from: id, to: id) -> BOOL {
+    let operation = match (url(from), url(to)) {
+        (Some(from), Some(to)) => Some(FileOperation::Copy { from: from, to: to }),
+        _ => None
+    };
+
+    should_proceed::<T>(this, error, operation)
+}
+
+/// Forwards `fileManager:shouldProceedAfterError:movingItemAtURL:toURL:`.
+extern fn should_proceed_after_move_error<T: FileManagerDelegate>(this: &Object, _: Sel, _: id, error: id, from: id, to: id) -> BOOL {
+    let operation = match (url(from), url(to)) {
+        (Some(from), Some(to)) => Some(FileOperation::Move { from: from, to: to }),
+        _ => None
+    };
+
+    should_proceed::<T>(this, error, operation)
+}
+
+/// Forwards `fileManager:shouldProceedAfterError:linkingItemAtURL:toURL:`.
+extern fn should_proceed_after_link_error<T: FileManagerDelegate>(this: &Object, _: Sel, _: id, error: id, from: id, to: id) -> BOOL {
+    let operation = match (url(from), url(to)) {
+        (Some(from), Some(to)) => Some(FileOperation::Link { from: from, to: to }),
+        _ => None
+    };
+
+    should_proceed::<T>(this, error, operation)
+}
+
+/// Forwards `fileManager:shouldProceedAfterError:removingItemAtURL:`.
+extern fn should_proceed_after_remove_error<T: FileManagerDelegate>(this: &Object, _: Sel, _: id, error: id, item: id) -> BOOL {
+    should_proceed::<T>(this, error, url(item).map(FileOperation::Remove))
+}
+
+/// Registers an `NSObject` subclass that conforms to `NSFileManagerDelegate`, forwarding to a
+/// `FileManagerDelegate`.
+pub(crate) fn register_file_manager_delegate_class<T: FileManagerDelegate + 'static>() -> *const Class {
+    unsafe {
+        let superclass = class!(NSObject);
+        load_or_register_class_for::<T, _>(superclass, "RSTFileManagerDelegate", |decl| {
+            decl.add_ivar::<usize>(FILE_MANAGER_DELEGATE_PTR);
+
+            decl.add_method(
+                sel!(fileManager:shouldCopyItemAtURL:toURL:),
+                should_copy::<T> as extern fn(&Object, _, _, id, id) -> BOOL
+            );
+            decl.add_method(
+                sel!(fileManager:shouldMoveItemAtURL:toURL:),
+                should_move::<T> as extern fn(&Object, _, _, id, id) -> BOOL
+            );
+            decl.add_method(
+                sel!(fileManager:shouldLinkItemAtURL:toURL:),
+                should_link::<T> as extern fn(&Object, _, _, id, id) -> BOOL
+            );
+            decl.add_method(
+                sel!(fileManager:shouldRemoveItemAtURL:),
+                should_remove::<T> as extern fn(&Object, _, _, id) -> BOOL
+            );
+            decl.add_method(
+                sel!(fileManager:shouldProceedAfterError:copyingItemAtURL:toURL:),
+                should_proceed_after_copy_error::<T> as extern fn(&Object, _, _, id, id, id) -> BOOL
+            );
+            decl.add_method(
+                sel!(fileManager:shouldProceedAfterError:movingItemAtURL:toURL:),
+                should_proceed_after_move_error::<T> as extern fn(&Object, _, _, id, id, id) -> BOOL
+            );
+            decl.add_method(
+                sel!(fileManager:shouldProceedAfterError:linkingItemAtURL:toURL:),
+                should_proceed_after_link_error::<T> as extern fn(&Object, _, _, id, id, id) -> BOOL
+            );
+            decl.add_method(
+                sel!(fileManager:shouldProceedAfterError:removingItemAtURL:),
+                should_proceed_after_remove_error::<T> as extern fn(&Object, _, _, id, id) -> BOOL
+            );
+        })
+    }
+}
@@ -1,5 +1,7 @@
 //! Certain enums that are useful (response types, etc).
 
+use url::Url;
+
 use crate::foundation::{NSInteger, NSUInteger};
 
 pub enum ModalResponse {
@@ -10,7 +12,10 @@ pub enum ModalResponse {
     Aborted,
     FirstButtonReturned,
     SecondButtonReturned,
-    ThirdButtonReturned
+    ThirdButtonReturned,
+
+    /// A response code we don't have a case for (e.g, a custom one passed to `stopModal`).
+    Unknown(NSInteger)
 }
 
 impl From<NSInteger> for ModalResponse {
@@ -24,11 +29,7 @@ impl From<NSInteger> for ModalResponse {
             -1000 => ModalResponse::Stopped,
             -1001 => ModalResponse::Aborted,
             -1002 => ModalResponse::Continue,
-
-            // @TODO: Definitely don't panic here, wtf was I thinking?
-            // Probably make this a ModalResponse::Unknown or something so a user can
-            // gracefully handle.
-            e => { panic!("Unknown NSModalResponse sent back! {}", e); }
+            e => ModalResponse::Unknown(e)
         }
     }
 }
@@ -117,3 +118,204 @@ impl From<SearchPathDirectory> for NSUInteger {
         }
     }
 }
+
+/// Properties of a file or directory that can be fetched along with a directory listing. Each
+/// one fills in the corresponding field of `DirectoryEntry`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ResourceKey {
+    /// Fills in `DirectoryEntry::name`.
+    Name,
+
+    /// Fills in `DirectoryEntry::is_directory`.
+    IsDirectory,
+
+    /// Fills in `DirectoryEntry::is_regular_file`.
+    IsRegularFile,
+
+    /// Fills in `DirectoryEntry::is_symbolic_link`.
+    IsSymbolicLink,
+
+    /// Fills in `DirectoryEntry::is_hidden`.
+    IsHidden,
+
+    /// Fills in `DirectoryEntry::file_size`.
+    FileSize,
+
+    /// Fills in `DirectoryEntry::creation_date`.
+    CreationDate,
+
+    /// Fills in `DirectoryEntry::modification_date`.
+    ModificationDate
+}
+
+impl ResourceKey {
+    /// Returns the `NSURLResourceKey` for this key.
+    pub(crate) fn to_str(&self) -> &'static str {
+        match self {
+            ResourceKey::Name => "NSURLNameKey",
+            ResourceKey::IsDirectory => "NSURLIsDirectoryKey",
+            ResourceKey::IsRegularFile => "NSURLIsRegularFileKey",
+            ResourceKey::IsSymbolicLink => "NSURLIsSymbolicLinkKey",
+            ResourceKey::IsHidden => "NSURLIsHiddenKey",
+            ResourceKey::FileSize => "NSURLFileSizeKey",
+            ResourceKey::CreationDate => "NSURLCreationDateKey",
+            ResourceKey::ModificationDate => "NSURLContentModificationDateKey"
+        }
+    }
+}
+
+/// Options for listing the contents of a directory.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DirectoryEnumerationOption {
+    /// Don't descend into subdirectories.
+    SkipsSubdirectoryDescendants,
+
+    /// Don't descend into packages (e.g, `.app` bundles).
+    SkipsPackageDescendants,
+
+    /// Skip hidden files.
+    SkipsHiddenFiles
+}
+
+impl From<&DirectoryEnumerationOption> for NSUInteger {
+    fn from(option: &DirectoryEnumerationOption) -> Self {
+        match option {
+            DirectoryEnumerationOption::SkipsSubdirectoryDescendants => 1 << 0,
+            DirectoryEnumerationOption::SkipsPackageDescendants => 1 << 1,
+            DirectoryEnumerationOption::SkipsHiddenFiles => 1 << 2
+        }
+    }
+}
+
+/// The type of a filesystem item.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FileType {
+    /// A regular file.
+    Regular,
+
+    /// A directory.
+    Directory,
+
+    /// A symbolic link.
+    SymbolicLink,
+
+    /// A socket.
+    Socket,
+
+    /// A character special file (e.g, a terminal).
+    CharacterSpecial,
+
+    /// A block special file (e.g, a disk).
+    BlockSpecial,
+
+    /// The type couldn't be determined.
+    Unknown
+}
+
+impl From<&str> for FileType {
+    fn from(file_type: &str) -> Self {
+        match file_type {
+            "NSFileTypeRegular" => FileType::Regular,
+            "NSFileTypeDirectory" => FileType::Directory,
+            "NSFileTypeSymbolicLink" => FileType::SymbolicLink,
+            "NSFileTypeSocket" => FileType::Socket,
+            "NSFileTypeCharacterSpecial" => FileType::CharacterSpecial,
+            "NSFileTypeBlockSpecial" => FileType::BlockSpecial,
+            _ => FileType::Unknown
+        }
+    }
+}
+
+/// An operation that a `FileManager` was performing on an item, passed to
+/// `FileManagerDelegate::should_proceed_after_error()`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FileOperation {
+    /// Copying an item from one location to another.
+    Copy { from: Url, to: Url },
+
+    /// Moving an item from one location to another.
+    Move { from: Url, to: Url },
+
+    /// Linking an item from one location to another.
+    Link { from: Url, to: Url },
+
+    /// Removing an item.
+    Remove(Url)
+}
+
+/// Options for a coordinated read, via `FileCoordinator::coordinate_read()`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReadingOption {
+    /// Don't ask presenters to save their changes first; read whatever's on disk.
+    WithoutChanges,
+
+    /// If the URL is a symbolic link, coordinate reading of the item it points to.
+    ResolvesSymbolicLink,
+
+    /// Only read metadata that's immediately available (e.g, don't download an iCloud item).
+    ImmediatelyAvailableMetadataOnly,
+
+    /// Read a snapshot of the item suitable for uploading; directories are zipped.
+    ForUploading
+}
+
+impl From<&ReadingOption> for NSUInteger {
+    fn from(option: &ReadingOption) -> Self {
+        match option {
+            ReadingOption::WithoutChanges => 1 << 0,
+            ReadingOption::ResolvesSymbolicLink => 1 << 1,
+            ReadingOption::ImmediatelyAvailableMetadataOnly => 1 << 2,
+            ReadingOption::ForUploading => 1 << 3
+        }
+    }
+}
+
+/// Options for a coordinated write, via `FileCoordinator::coordinate_write()`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WritingOption {
+    /// The write deletes the item.
+    ForDeleting,
+
+    /// The write moves the item.
+    ForMoving,
+
+    /// The write merges changes into the item.
+    ForMerging,
+
+    /// The write replaces the item entirely.
+    ForReplacing,
+
+    /// The write only changes metadata that doesn't depend on the contents.
+    ContentIndependentMetadataOnly
+}
+
+impl From<&WritingOption> for NSUInteger {
+    fn from(option: &WritingOption) -> Self {
+        match option {
+            WritingOption::ForDeleting => 1 << 0,
+            WritingOption::ForMoving => 1 << 1,
+            WritingOption::ForMerging => 1 << 2,
+            WritingOption::ForReplacing => 1 << 3,
+            WritingOption::ContentIndependentMetadataOnly => 1 << 4
+        }
+    }
+}
+
+/// Options for `FileManager::replace_item()`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReplaceOption {
+    /// Only use metadata (e.g, extended attributes) from the replacement, not the original.
+    UsingNewMetadataOnly,
+
+    /// Keep the backup of the original around after the replacement succeeds.
+    WithoutDeletingBackupItem
+}
+
+impl From<&ReplaceOption> for NSUInteger {
+    fn from(option: &ReplaceOption) -> Self {
+        match option {
+            ReplaceOption::UsingNewMetadataOnly => 1 << 0,
+            ReplaceOption::WithoutDeletingBackupItem => 1 << 1
+        }
+    }
+}
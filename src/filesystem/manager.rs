@@ -1,7 +1,6 @@
 //! A wrapper for `NSFileManager`, which is necessary for macOS/iOS (the sandbox makes things
 //! tricky, and this transparently handles it for you).
 
-use std::error::Error;
 use std::sync::RwLock;
 
 use objc_id::Id;
@@ -9,12 +8,35 @@ use objc::runtime::{BOOL, Object};
 use objc::{class, msg_send, sel, sel_impl};
 use url::Url;
 
-use crate::foundation::{id, nil, NO, NSString, NSUInteger};
-use crate::error::{Error as AppKitError};
-use crate::filesystem::enums::{SearchPathDirectory, SearchPathDomainMask};
+use crate::foundation::{id, nil, YES, NO, NSArray, NSString, NSUInteger};
+use crate::error::Error;
+use crate::filesystem::{self, nsurl, url};
+use crate::filesystem::attributes::{string_for_key, DirectoryEntry, FileAttributes};
+use crate::filesystem::delegate::{register_file_manager_delegate_class, FILE_MANAGER_DELEGATE_PTR};
+use crate::filesystem::enums::{DirectoryEnumerationOption, ReplaceOption, ResourceKey, SearchPathDirectory, SearchPathDomainMask};
+use crate::filesystem::traits::FileManagerDelegate;
+use crate::filesystem::ubiquity::DownloadingStatus;
 
-pub struct FileManager {
-    pub manager: RwLock<Id<Object>>
+/// Converts an `NSURL` the system handed back to a `Url`. It can hand back nil where it couldn't
+/// work out a location, which is reported as an error.
+fn resulting_url(url: id) -> Result<Url, Error> {
+    filesystem::url(url).ok_or_else(|| Error::InvalidUrl("The system didn't return a valid location for the item.".to_string()))
+}
+
+/// Returns the filesystem path for the given `Url`, as an `NSString`.
+fn nspath(url: &Url) -> id {
+    unsafe { msg_send![nsurl(url), path] }
+}
+
+pub struct FileManager<T = ()> {
+    pub manager: RwLock<Id<Object>>,
+
+    /// The delegate for batch operations, if this was created via `FileManager::with()`.
+    pub delegate: Option<Box<T>>,
+
+    /// The `NSFileManagerDelegate` that forwards to `delegate`. `NSFileManager` doesn't retain
+    /// its delegate, so we hold on to it here.
+    objc_delegate: Option<Id<Object>>
 }
 
 impl Default for FileManager {
@@ -25,26 +47,60 @@ impl Default for FileManager {
             manager: RwLock::new(unsafe {
                 let manager: id = msg_send![class!(NSFileManager), defaultManager];
                 Id::from_ptr(manager)
-            })
+            }),
+            delegate: None,
+            objc_delegate: None
         }
     }
 }
 
 impl FileManager {
-    /// Returns a new FileManager that opts in to delegate methods.
+    /// Returns a new FileManager, separate from the shared default one. To receive delegate
+    /// callbacks, use `FileManager::with()`.
     pub fn new() -> Self {
         FileManager {
             manager: RwLock::new(unsafe {
                 let manager: id = msg_send![class!(NSFileManager), new];
-                Id::from_ptr(manager)
-            })
+                Id::from_retained_ptr(manager)
+            }),
+            delegate: None,
+            objc_delegate: None
         }
     }
+}
+
+impl<T> FileManager<T> where T: FileManagerDelegate + 'static {
+    /// Returns a new FileManager that calls through to `delegate` during batch operations, so
+    /// that you can skip individual items or carry on past errors.
+    pub fn with(delegate: T) -> Self {
+        let delegate = Box::new(delegate);
 
+        let objc_delegate = unsafe {
+            let objc_delegate: id = msg_send![register_file_manager_delegate_class::<T>(), new];
+            let ptr: *const T = &*delegate;
+            (&mut *objc_delegate).set_ivar(FILE_MANAGER_DELEGATE_PTR, ptr as usize);
+            Id::from_retained_ptr(objc_delegate)
+        };
+
+        let manager = unsafe {
+            let manager: id = msg_send![class!(NSFileManager), new];
+            let _: () = msg_send![manager, setDelegate:&*objc_delegate];
+            Id::from_retained_ptr(manager)
+        };
+
+        FileManager {
+            manager: RwLock::new(manager),
+            delegate: Some(delegate),
+            objc_delegate: Some(objc_delegate)
+        }
+    }
+}
+
+impl<T> FileManager<T> {
     /// Given a directory/domain combination, will attempt to get the directory that matches.
     /// Returns a PathBuf that wraps the given location. If there's an error on the Objective-C
     /// side, we attempt to catch it and bubble it up.
-    pub fn get_directory(&self, directory: SearchPathDirectory, in_domain: SearchPathDomainMask) -> Result<Url, Box<dyn Error>> {
+    pub fn get_directory(&self, directory: SearchPathDirectory, in_domain: SearchPathDomainMask) -> Result<Url, Error> {
         let dir: NSUInteger = directory.into();
         let mask: NSUInteger = in_domain.into();
 
@@ -65,7 +121,7 @@ impl FileManager {
     /// Given two paths, moves file (`from`) to the location specified in `to`. This can result in
     /// an error on the Objective-C side, which we attempt to handle and bubble up as a result if
     /// so.
-    pub fn move_item(&self, from: Url, to: Url) -> Result<(), Box<dyn Error>> {
+    pub fn move_item(&self, from: Url, to: Url) -> Result<(), Error> {
         let from = NSString::new(from.as_str());
         let to = NSString::new(to.as_str());
 
@@ -77,13 +133,381 @@ impl FileManager {
             // already, so... going to leave it as read.
             let manager = self.manager.read().unwrap();
 
-            let error: id = nil;
-            let result: BOOL = msg_send![&**manager, moveItemAtURL:from_url toURL:to_url error:&error];
+            let mut error: id = nil;
+            let result: BOOL = msg_send![&**manager, moveItemAtURL:from_url toURL:to_url error:&mut error];
+            if result == NO {
+                return Err(Error::new(error));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Copies the item at `from` to `to`. If `from` is a directory, its contents are copied too.
+    pub fn copy_item(&self, from: Url, to: Url) -> Result<(), Error> {
+        unsafe {
+            let manager = self.manager.read().unwrap();
+
+            let mut error: id = nil;
+            let result: BOOL = msg_send![&**manager, copyItemAtURL:nsurl(&from) toURL:nsurl(&to) error:&mut error];
+            if result == NO {
+                return Err(Error::new(error));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the item at `url`. If it's a directory, its contents are removed too. This
+    /// deletes immediately; to move the item to the Trash instead, see `trash_item()`.
+    pub fn remove_item(&self, url: Url) -> Result<(), Error> {
+        unsafe {
+            let manager = self.manager.read().unwrap();
+
+            let mut error: id = nil;
+            let result: BOOL = msg_send![&**manager, removeItemAtURL:nsurl(&url) error:&mut error];
+            if result == NO {
+                return Err(Error::new(error));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates a directory at `url`. If `with_intermediates` is `true`, any missing parent
+    /// directories are created as well (and it's not an error if the directory already exists).
+    pub fn create_directory(&self, url: Url, with_intermediates: bool) -> Result<(), Error> {
+        let intermediates = match with_intermediates {
+            true => YES,
+            false => NO
+        };
+
+        unsafe {
+            let manager = self.manager.read().unwrap();
+
+            let mut error: id = nil;
+            let result: BOOL = msg_send![&**manager, createDirectoryAtURL:nsurl(&url)
+                withIntermediateDirectories:intermediates
+                attributes:nil
+                error:&mut error];
+
+            if result == NO {
+                return Err(Error::new(error));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Lists the items in the directory at `url` (without descending into subdirectories). The
+    /// properties named by `keys` are fetched as part of the listing, and filled in on each
+    /// `DirectoryEntry`.
+    pub fn contents_of_directory(
+        &self,
+        url: Url,
+        keys: &[ResourceKey],
+        options: &[DirectoryEnumerationOption]
+    ) -> Result<Vec<DirectoryEntry>, Error> {
+        let keys: Vec<NSString> = keys.iter().map(|key| NSString::new(key.to_str())).collect();
+        let keys = NSArray::from(keys.iter().map(|key| &*key.0).collect::<Vec<&Object>>());
+        let options = options.iter().fold(0, |opts, option| opts | NSUInteger::from(option));
+
+        let contents = unsafe {
+            let manager = self.manager.read().unwrap();
+
+            let mut error: id = nil;
+            let contents: id = msg_send![&**manager, contentsOfDirectoryAtURL:nsurl(&url)
+                includingPropertiesForKeys:&*keys.0
+                options:options
+                error:&mut error];
+
+            if contents == nil {
+                return Err(Error::new(error));
+            }
+
+            contents
+        };
+
+        NSArray::wrap(contents).map(|item| {
+            let absolute = NSString::wrap(unsafe { msg_send![item, absoluteString] });
+
+            let mut error: id = nil;
+            let values: id = unsafe { msg_send![item, resourceValuesForKeys:&*keys.0 error:&mut error] };
+            if values == nil {
+                return Err(Error::new(error));
+            }
+
+            Url::parse(absolute.to_str())
+                .map(|url| DirectoryEntry::new(url, values))
+                .map_err(|e| e.into())
+        }).into_iter().collect()
+    }
+
+    /// Returns the attributes (type, size, dates, permissions and so on) of the item at `url`.
+    pub fn attributes_of_item(&self, url: Url) -> Result<FileAttributes, Error> {
+        unsafe {
+            let manager = self.manager.read().unwrap();
+
+            let mut error: id = nil;
+            let attributes: id = msg_send![&**manager, attributesOfItemAtPath:nspath(&url) error:&mut error];
+            if attributes == nil {
+                return Err(Error::new(error));
+            }
+
+            Ok(FileAttributes::new(attributes))
+        }
+    }
+
+    /// Checks for an item at `url`, returning whether it exists and whether it's a directory.
+    fn item_exists(&self, url: &Url) -> (bool, bool) {
+        unsafe {
+            let manager = self.manager.read().unwrap();
+
+            let mut is_directory: BOOL = NO;
+            let exists: BOOL = msg_send![&**manager, fileExistsAtPath:nspath(url) isDirectory:&mut is_directory];
+            (exists == YES, is_directory == YES)
+        }
+    }
+
+    /// Returns whether a file or directory exists at `url`. Symbolic links are followed; a
+    /// broken link is reported as not existing.
+    pub fn file_exists(&self, url: Url) -> bool {
+        self.item_exists(&url).0
+    }
+
+    /// Returns whether a directory exists at `url`.
+    pub fn directory_exists(&self, url: Url) -> bool {
+        let (exists, is_directory) = self.item_exists(&url);
+        exists && is_directory
+    }
+
+    /// Moves the item at `url` to the Trash, returning its new location.
+    pub fn trash_item(&self, url: Url) -> Result<Url, Error> {
+        let trashed = unsafe {
+            let manager = self.manager.read().unwrap();
+
+            let mut error: id = nil;
+            let mut trashed: id = nil;
+            let result: BOOL = msg_send![&**manager, trashItemAtURL:nsurl(&url) resultingItemURL:&mut trashed error:&mut error];
             if result == NO {
-                return Err(AppKitError::new(error).into());
+                return Err(Error::new(error));
+            }
+
+            trashed
+        };
+
+        resulting_url(trashed)
+    }
+
+    /// Copies the item at `url` alongside itself, as Finder's Duplicate does (`Notes.txt` becomes
+    /// `Notes copy.txt`, then `Notes copy 2.txt`, and so on), returning the new location.
+    pub fn duplicate_item(&self, url: Url) -> Result<Url, Error> {
+        let path = url.to_file_path().map_err(|_| Error::InvalidUrl("Only file URLs can be duplicated.".to_string()))?;
+        let stem = path.file_stem().and_then(|stem| stem.to_str()).ok_or_else(|| Error::InvalidUrl("The item has no name.".to_string()))?;
+        let extension = path.extension().and_then(|extension| extension.to_str());
+
+        let mut copy = 1;
+
+        let destination = loop {
+            let name = match copy {
+                1 => format!("{} copy", stem),
+                n => format!("{} copy {}", stem, n)
+            };
+
+            let name = match extension {
+                Some(extension) => format!("{}.{}", name, extension),
+                None => name
+            };
+
+            let destination = Url::from_file_path(path.with_file_name(name))
+                .map_err(|_| Error::InvalidUrl("The duplicate's location is invalid.".to_string()))?;
+
+            if !self.item_exists(&destination).0 {
+                break destination;
+            }
+
+            copy += 1;
+        };
+
+        self.copy_item(url, destination.clone())?;
+        Ok(destination)
+    }
+
+    /// Replaces the item at `original` with the one at `replacement`, atomically - the usual
+    /// safe-save approach is to write to a file in `temporary_directory()` and then call this.
+    /// The original's metadata (e.g, creation date and permissions) is preserved. If
+    /// `backup_name` is given, the original is moved aside under that name while the replacement
+    /// happens. Returns the location of the resulting item, which may differ from `original`.
+    pub fn replace_item(
+        &self,
+        original: Url,
+        replacement: Url,
+        backup_name: Option<&str>,
+        options: &[ReplaceOption]
+    ) -> Result<Url, Error> {
+        let backup_name = backup_name.map(NSString::new);
+        let options = options.iter().fold(0, |opts, option| opts | NSUInteger::from(option));
+
+        let resulting = unsafe {
+            let manager = self.manager.read().unwrap();
+            let backup_name: id = match &backup_name {
+                Some(name) => &*name.0 as *const Object as id,
+                None => nil
+            };
+
+            let mut error: id = nil;
+            let mut resulting: id = nil;
+            let result: BOOL = msg_send![&**manager, replaceItemAtURL:nsurl(&original)
+                withItemAtURL:nsurl(&replacement)
+                backupItemName:backup_name
+                options:options
+                resultingItemURL:&mut resulting
+                error:&mut error];
+
+            if result == NO {
+                return Err(Error::new(error));
+            }
+
+            resulting
+        };
+
+        resulting_url(resulting)
+    }
+
+    /// Creates (and returns) a new temporary directory, on the same volume as `appropriate_for`,
+    /// for writing files that'll replace it via `replace_item()`. You're responsible for removing
+    /// the directory when you're done.
+    pub fn temporary_directory(&self, appropriate_for: Url) -> Result<Url, Error> {
+        let directory: NSUInteger = SearchPathDirectory::ItemReplacement.into();
+        let mask: NSUInteger = SearchPathDomainMask::User.into();
+
+        let directory = unsafe {
+            let manager = self.manager.read().unwrap();
+
+            let mut error: id = nil;
+            let directory: id = msg_send![&**manager, URLForDirectory:directory
+                inDomain:mask
+                appropriateForURL:nsurl(&appropriate_for)
+                create:YES
+                error:&mut error];
+
+            if directory == nil {
+                return Err(Error::new(error));
+            }
+
+            directory
+        };
+
+        resulting_url(directory)
+    }
+
+    /// Returns whether the user is signed in to iCloud (with iCloud Drive enabled for your app).
+    pub fn is_icloud_available(&self) -> bool {
+        unsafe {
+            let manager = self.manager.read().unwrap();
+            let token: id = msg_send![&**manager, ubiquityIdentityToken];
+            token != nil
+        }
+    }
+
+    /// Returns the location of your app's iCloud container, creating it if need be, or `None` if
+    /// iCloud isn't available. `identifier` is the container identifier from your entitlements;
+    /// pass `None` for the first one listed.
+    ///
+    /// This can block for a while the first time it's called, so call it off the main thread.
+    pub fn ubiquity_container(&self, identifier: Option<&str>) -> Option<Url> {
+        let identifier = identifier.map(NSString::new);
+
+        let container = unsafe {
+            let manager = self.manager.read().unwrap();
+            let identifier: id = match &identifier {
+                Some(identifier) => &*identifier.0 as *const Object as id,
+                None => nil
+            };
+
+            let container: id = msg_send![&**manager, URLForUbiquityContainerIdentifier:identifier];
+            container
+        };
+
+        match container == nil {
+            true => None,
+            false => url(container)
+        }
+    }
+
+    /// Returns whether the item at `url` is stored in iCloud.
+    pub fn is_ubiquitous_item(&self, url: Url) -> bool {
+        unsafe {
+            let manager = self.manager.read().unwrap();
+            let result: BOOL = msg_send![&**manager, isUbiquitousItemAtURL:nsurl(&url)];
+            result == YES
+        }
+    }
+
+    /// Returns whether there's an up to date local copy of the iCloud item at `url`.
+    pub fn downloading_status(&self, url: Url) -> Result<DownloadingStatus, Error> {
+        let key = NSString::new("NSURLUbiquitousItemDownloadingStatusKey");
+
+        let values = unsafe {
+            let keys: id = msg_send![class!(NSArray), arrayWithObject:key];
+
+            let mut error: id = nil;
+            let values: id = msg_send![nsurl(&url), resourceValuesForKeys:keys error:&mut error];
+            if values == nil {
+                return Err(Error::new(error));
+            }
+
+            values
+        };
+
+        Ok(match string_for_key(values, "NSURLUbiquitousItemDownloadingStatusKey") {
+            Some(status) => DownloadingStatus::from_status(&status),
+            None => DownloadingStatus::NotDownloaded
+        })
+    }
+
+    /// Starts downloading the iCloud item at `url`, if there isn't already an up to date local
+    /// copy. Use a `UbiquitousQuery` to follow its progress.
+    pub fn start_downloading_ubiquitous_item(&self, url: Url) -> Result<(), Error> {
+        unsafe {
+            let manager = self.manager.read().unwrap();
+
+            let mut error: id = nil;
+            let result: BOOL = msg_send![&**manager, startDownloadingUbiquitousItemAtURL:nsurl(&url) error:&mut error];
+            if result == NO {
+                return Err(Error::new(error));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the local copy of the iCloud item at `url`, to free up space. The item stays in
+    /// iCloud, and can be downloaded again.
+    pub fn evict_ubiquitous_item(&self, url: Url) -> Result<(), Error> {
+        unsafe {
+            let manager = self.manager.read().unwrap();
+
+            let mut error: id = nil;
+            let result: BOOL = msg_send![&**manager, evictUbiquitousItemAtURL:nsurl(&url) error:&mut error];
+            if result == NO {
+                return Err(Error::new(error));
             }
         }
 
         Ok(())
     }
 }
+
+impl<T> Drop for FileManager<T> {
+    /// Detaches the delegate, as `NSFileManager` only holds a weak reference to it.
+    fn drop(&mut self) {
+        if self.objc_delegate.is_some() {
+            let manager = self.manager.read().unwrap();
+
+            unsafe {
+                let _: () = msg_send![&**manager, setDelegate:nil];
+            }
+        }
+    }
+}
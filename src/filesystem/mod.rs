@@ -1,15 +1,56 @@
 
+use objc::{class, msg_send, sel, sel_impl};
+use url::Url;
+
+use crate::foundation::{id, nil, NSString};
+
 pub mod enums;
 pub use enums::*;
 
+pub mod attributes;
+pub use attributes::{DirectoryEntry, FileAttributes};
+
+pub mod coordinator;
+pub use coordinator::FileCoordinator;
+
+mod delegate;
+
 pub mod manager;
 pub use manager::FileManager;
 
 pub mod traits;
 pub use traits::*;
 
+pub mod presenter;
+pub use presenter::PresentedItem;
+
+pub mod ubiquity;
+pub use ubiquity::{DownloadingStatus, UbiquitousItem, UbiquitousQuery, UbiquitousScope};
+
 pub mod save;
 pub use save::FileSavePanel;
 
 pub mod select;
 pub use select::FileSelectPanel;
+
+#[cfg(target_os = "macos")]
+pub mod watcher;
+
+#[cfg(target_os = "macos")]
+pub use watcher::{DirectoryEvent, DirectoryEventKind, DirectoryWatcher};
+
+/// Returns an `NSURL` for the given `Url`.
+pub(crate) fn nsurl(url: &Url) -> id {
+    let url = NSString::new(url.as_str());
+    unsafe { msg_send![class!(NSURL), URLWithString:url] }
+}
+
+/// Converts an `NSURL` to a `Url`, if it's non-nil and valid.
+pub(crate) fn url(url: id) -> Option<Url> {
+    if url == nil {
+        return None;
+    }
+
+    let absolute = NSString::wrap(unsafe { msg_send![url, absoluteString] });
+    Url::parse(absolute.to_str()).ok()
+}
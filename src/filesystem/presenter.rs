@@ -0,0 +1,190 @@
+//! Implements `PresentedItem`, which registers a `FilePresenter` with the system so it's told
+//! about changes other processes make to a file or directory.
+
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+use url::Url;
+
+use block::Block;
+
+use crate::filesystem::url;
+use crate::filesystem::traits::FilePresenter;
+use crate::foundation::{id, nil, NSString};
+use crate::utils::{clear_ptr, load_or_register_class_for};
+
+static FILE_PRESENTER_PTR: &str = "rstFilePresenterPtr";
+static FILE_PRESENTER_URL: &str = "rstFilePresenterURL";
+
+/// Swaps the URL stored on the presenter, retaining the new one and releasing the old one.
+fn set_presented_url(this: &mut Object, url: id) {
+    unsafe {
+        let url: id = msg_send![url, copy];
+        let previous: id = *this.get_ivar(FILE_PRESENTER_URL);
+        this.set_ivar(FILE_PRESENTER_URL, url);
+
+        if previous != nil {
+            let _: () = msg_send![previous, release];
+        }
+    }
+}
+
+/// Calls a completion handler that takes an optional `NSError`.
+fn complete(handler: id, error: id) {
+    unsafe {
+        let handler = handler as *mut Block<(id,), ()>;
+        (*handler).call((error,));
+    }
+}
+
+/// Returns the presented item's URL, for `presentedItemURL`.
+extern fn presented_item_url(this: &Object, _: Sel) -> id {
+    unsafe { *this.get_ivar(FILE_PRESENTER_URL) }
+}
+
+/// Returns the main queue, so that callbacks happen on the main thread.
+extern fn presented_item_operation_queue(_: &Object, _: Sel) -> id {
+    unsafe { msg_send![class!(NSOperationQueue), mainQueue] }
+}
+
+/// Forwards `presentedItemDidChange`.
+extern fn presented_item_did_change<T: FilePresenter>(this: &Object, _: Sel) {
+    let presenter = load_or_return!(T, this, FILE_PRESENTER_PTR);
+    presenter.presented_item_did_change();
+}
+
+/// Forwards `presentedItemDidMoveToURL:`, after updating the URL we report.
+extern fn presented_item_did_move<T: FilePresenter>(this: &mut Object, _: Sel, new_url: id) {
+    set_presented_url(this, new_url);
+
+    if let Some(new_url) = url(new_url) {
+        let presenter = load_or_return!(T, this, FILE_PRESENTER_PTR);
+        presenter.presented_item_did_move(new_url);
+    }
+}
+
+/// Forwards `presentedSubitemDidChangeAtURL:`.
+extern fn presented_subitem_did_change<T: FilePresenter>(this: &Object, _: Sel, subitem: id) {
+    if let Some(subitem) = url(subitem) {
+        let presenter = load_or_return!(T, this, FILE_PRESENTER_PTR);
+        presenter.presented_subitem_did_change(subitem);
+    }
+}
+
+/// Forwards `savePresentedItemChangesWithCompletionHandler:`.
+extern fn save_presented_item_changes<T: FilePresenter>(this: &Object, _: Sel, handler: id) {
+    let presenter = load_or_return!(T, this, FILE_PRESENTER_PTR);
+
+    complete(handler, match presenter.save_presented_item_changes() {
+        Ok(_) => nil,
+        Err(error) => error.into_nserror()
+    });
+}
+
+/// Forwards `accommodatePresentedItemDeletionWithCompletionHandler:`.
+extern fn accommodate_presented_item_deletion<T: FilePresenter>(this: &Object, _: Sel, handler: id) {
+    let presenter = load_or_return!(T, this, FILE_PRESENTER_PTR);
+    presenter.accommodate_presented_item_deletion();
+    complete(handler, nil);
+}
+
+/// Releases the stored URL.
+extern fn dealloc(this: &Object, _: Sel) {
+    unsafe {
+        let url: id = *this.get_ivar(FILE_PRESENTER_URL);
+
+        if url != nil {
+            let _: () = msg_send![url, release];
+        }
+
+        let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+    }
+}
+
+/// Registers an `NSObject` subclass that conforms to `NSFilePresenter`, forwarding to a
+/// `FilePresenter`.
+fn register_file_presenter_class<T: FilePresenter + 'static>() -> *const Class {
+    unsafe {
+        let superclass = class!(NSObject);
+        load_or_register_class_for::<T, _>(superclass, "RSTFilePresenter", |decl| {
+            decl.add_ivar::<usize>(FILE_PRESENTER_PTR);
+            decl.add_ivar::<id>(FILE_PRESENTER_URL);
+
+            if let Some(protocol) = objc::runtime::Protocol::get("NSFilePresenter") {
+                decl.add_protocol(protocol);
+            }
+
+            decl.add_method(sel!(presentedItemURL), presented_item_url as extern fn(&Object, _) -> id);
+            decl.add_method(sel!(presentedItemOperationQueue), presented_item_operation_queue as extern fn(&Object, _) -> id);
+            decl.add_method(sel!(presentedItemDidChange), presented_item_did_change::<T> as extern fn(&Object, _));
+            decl.add_method(sel!(presentedItemDidMoveToURL:), presented_item_did_move::<T> as extern fn(&mut Object, _, id));
+            decl.add_method(sel!(presentedSubitemDidChangeAtURL:), presented_subitem_did_change::<T> as extern fn(&Object, _, id));
+            decl.add_method(
+                sel!(savePresentedItemChangesWithCompletionHandler:),
+                save_presented_item_changes::<T> as extern fn(&Object, _, id)
+            );
+            decl.add_method(
+                sel!(accommodatePresentedItemDeletionWithCompletionHandler:),
+                accommodate_presented_item_deletion::<T> as extern fn(&Object, _, id)
+            );
+            decl.add_method(sel!(dealloc), dealloc as extern fn(&Object, _));
+        })
+    }
+}
+
+/// A file or directory you're presenting, registered with the system so that `delegate` hears
+/// about changes to it. The registration is removed when this is dropped.
+#[derive(Debug)]
+pub struct PresentedItem<T = ()> {
+    /// The `NSFilePresenter` registered with `NSFileCoordinator`.
+    pub objc: ShareId<Object>,
+
+    /// The presenter that receives callbacks.
+    pub delegate: Option<Box<T>>
+}
+
+impl<T> PresentedItem<T> where T: FilePresenter + 'static {
+    /// Starts presenting the item at `url`, forwarding events to `delegate` (on the main thread).
+    pub fn new(url: Url, delegate: T) -> Self {
+        let delegate = Box::new(delegate);
+        let url = NSString::new(url.as_str());
+
+        let objc = unsafe {
+            let presenter: id = msg_send![register_file_presenter_class::<T>(), new];
+            let ptr: *const T = &*delegate;
+            (&mut *presenter).set_ivar(FILE_PRESENTER_PTR, ptr as usize);
+            (&mut *presenter).set_ivar(FILE_PRESENTER_URL, nil);
+
+            let url: id = msg_send![class!(NSURL), URLWithString:url];
+            set_presented_url(&mut *presenter, url);
+
+            let _: () = msg_send![class!(NSFileCoordinator), addFilePresenter:presenter];
+            ShareId::from_retained_ptr(presenter)
+        };
+
+        PresentedItem {
+            objc: objc,
+            delegate: Some(delegate)
+        }
+    }
+}
+
+impl<T> PresentedItem<T> {
+    /// Returns the item's current URL, which tracks moves and renames.
+    pub fn url(&self) -> Option<Url> {
+        url(unsafe { *self.objc.get_ivar(FILE_PRESENTER_URL) })
+    }
+}
+
+impl<T> Drop for PresentedItem<T> {
+    /// Unregisters the presenter, and breaks its link to the delegate - callbacks that were
+    /// already queued on the main thread then find nothing to call.
+    fn drop(&mut self) {
+        clear_ptr(&self.objc, FILE_PRESENTER_PTR);
+
+        unsafe {
+            let _: () = msg_send![class!(NSFileCoordinator), removeFilePresenter:&*self.objc];
+        }
+    }
+}
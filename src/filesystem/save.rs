@@ -2,6 +2,8 @@
 //! It currently doesn't implement _everything_ necessary, but it's functional
 //! enough for general use.
 
+use std::path::{Path, PathBuf};
+
 use block::ConcreteBlock;
 
 use objc::{class, msg_send, sel, sel_impl};
@@ -9,6 +11,12 @@ use objc::runtime::Object;
 use objc_id::ShareId;
 
 use crate::foundation::{id, nil, YES, NO, NSInteger, NSString};
+use crate::filesystem::enums::ModalResponse;
+use crate::layout::Layout;
+use crate::uti::{self, UTType};
+
+#[cfg(feature = "macos")]
+use crate::macos::window::Window;
 
 #[derive(Debug)]
 pub struct FileSavePanel {
@@ -18,8 +26,11 @@ pub struct FileSavePanel {
     /// The internal `NSObject` that routes delegate callbacks around.
     pub delegate: ShareId<Object>,
 
-    /// Whether the user can choose files. Defaults to `true`.
-    pub can_create_directories: bool
+    /// Whether the user can create directories. Defaults to `true`.
+    pub can_create_directories: bool,
+
+    /// Whether hidden files are shown. Defaults to `false`.
+    pub shows_hidden_files: bool
 }
 
 impl Default for FileSavePanel {
@@ -43,12 +54,14 @@ impl FileSavePanel {
                 ShareId::from_ptr(msg_send![class!(NSObject), new])
             },
 
-            can_create_directories: true
+            can_create_directories: true,
+            shows_hidden_files: false
         }
     }
 
     pub fn set_delegate(&mut self) {}
 
+    /// Sets the default name shown in the filename field.
     pub fn set_suggested_filename(&mut self, suggested_filename: &str) {
         unsafe {
             let filename = NSString::new(suggested_filename);
@@ -68,30 +81,87 @@ impl FileSavePanel {
         self.can_create_directories = can_create;
     }
 
-    /// Shows the panel as a modal. Currently sheets are not supported, but you're free (and able
-    /// to) thread the Objective C calls yourself by using the panel field on this struct.
+    /// Sets the content types the user can choose between. Passing an empty slice allows any
+    /// type.
+    pub fn set_allowed_content_types(&mut self, types: &[UTType]) {
+        uti::set_allowed_content_types(&self.panel, types);
+    }
+
+    /// Sets the directory the panel starts in.
+    pub fn set_directory<P: AsRef<Path>>(&mut self, path: P) {
+        let path = NSString::new(&path.as_ref().to_string_lossy());
+
+        unsafe {
+            let url: id = msg_send![class!(NSURL), fileURLWithPath:path isDirectory:YES];
+            let _: () = msg_send![&*self.panel, setDirectoryURL:url];
+        }
+    }
+
+    /// Sets a custom view to be displayed alongside the standard panel controls.
+    pub fn set_accessory_view<L: Layout>(&mut self, view: &L) {
+        let backing_node = view.get_backing_node();
+
+        unsafe {
+            let _: () = msg_send![&*self.panel, setAccessoryView:&*backing_node];
+        }
+    }
+
+    /// Sets whether the panel shows hidden files.
+    pub fn set_shows_hidden_files(&mut self, shows: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.panel, setShowsHiddenFiles:match shows {
+                true => YES,
+                false => NO
+            }];
+        }
+
+        self.shows_hidden_files = shows;
+    }
+
+    /// Shows the panel as a modal. If you'd rather attach it to a window, see `begin_sheet`.
     ///
-    /// Note that this clones the underlying `NSOpenPanel` pointer. This is theoretically safe as
+    /// `handler` is called with the chosen path, or `None` if the user cancelled.
+    ///
+    /// Note that this clones the underlying `NSSavePanel` pointer. This is theoretically safe as
     /// the system runs and manages that in another process, and we're still abiding by the general
     /// retain/ownership rules here.
-    pub fn show<F: Fn(Option<String>) + 'static>(&self, handler: F) {
+    pub fn show<F: Fn(Option<PathBuf>) + 'static>(&self, handler: F) {
+        let panel = self.panel.clone();
+        let completion = ConcreteBlock::new(move |result: NSInteger| {
+            let response: ModalResponse = result.into();
+
+            handler(match response {
+                ModalResponse::Ok => get_url(&panel).map(PathBuf::from),
+                _ => None
+            });
+        });
+
+        unsafe {
+            let _: () = msg_send![&*self.panel, beginWithCompletionHandler:completion.copy()];
+        }
+    }
+
+    /// Shows the panel as a sheet on the given window, calling `handler` with the chosen path (if
+    /// any) once the user is done.
+    #[cfg(feature = "macos")]
+    pub fn begin_sheet<W, F: Fn(Option<PathBuf>) + 'static>(&self, window: &Window<W>, handler: F) {
         let panel = self.panel.clone();
-        let completion = ConcreteBlock::new(move |_result: NSInteger| {
-            //let response: ModalResponse = result.into();
-            handler(get_url(&panel));
+        let completion = ConcreteBlock::new(move |result: NSInteger| {
+            let response: ModalResponse = result.into();
+
+            handler(match response {
+                ModalResponse::Ok => get_url(&panel).map(PathBuf::from),
+                _ => None
+            });
         });
-        let completion = completion.copy();
 
         unsafe {
-            let _: () = msg_send![&*self.panel, runModal];
-            completion.call((1,));
-            //beginWithCompletionHandler:completion.copy()];
-            //let _: () = msg_send![&*self.panel, beginWithCompletionHandler:completion.copy()];
+            let _: () = msg_send![&*self.panel, beginSheetModalForWindow:&*window.objc completionHandler:completion.copy()];
         }
     }
 }
 
-/// Retrieves the selected URLs from the provided panel.
+/// Retrieves the chosen URL from the provided panel.
 /// This is currently a bit ugly, but it's also not something that needs to be the best thing in
 /// the world as it (ideally) shouldn't be called repeatedly in hot spots.
 pub fn get_url(panel: &Object) -> Option<String> {
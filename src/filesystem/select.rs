@@ -2,7 +2,7 @@
 //! urls to work with. It currently doesn't implement _everything_ necessary, but it's functional
 //! enough for general use.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use block::ConcreteBlock;
 
@@ -12,6 +12,11 @@ use objc_id::ShareId;
 
 use crate::foundation::{id, YES, NO, NSInteger, NSString};
 use crate::filesystem::enums::ModalResponse;
+use crate::layout::Layout;
+use crate::uti::{self, UTType};
+
+#[cfg(feature = "macos")]
+use crate::macos::window::Window;
 
 #[derive(Debug)]
 pub struct FileSelectPanel {
@@ -35,7 +40,10 @@ pub struct FileSelectPanel {
 
     /// When the value of this property is true, the user may select multiple items from the 
     /// browser. Defaults to `false`.
-    pub allows_multiple_selection: bool
+    pub allows_multiple_selection: bool,
+
+    /// Whether hidden files are shown. Defaults to `false`.
+    pub shows_hidden_files: bool
 }
 
 impl Default for FileSelectPanel {
@@ -62,7 +70,8 @@ impl FileSelectPanel {
             can_choose_files: true,
             can_choose_directories: false,
             resolves_aliases: true,
-            allows_multiple_selection: true
+            allows_multiple_selection: true,
+            shows_hidden_files: false
         }
     }
 
@@ -92,6 +101,13 @@ impl FileSelectPanel {
         self.can_choose_directories = can_choose;
     }
 
+    /// Configures the panel so that only directories can be chosen. This is shorthand for
+    /// disabling file selection and enabling directory selection.
+    pub fn set_directories_only(&mut self) {
+        self.set_can_choose_files(false);
+        self.set_can_choose_directories(true);
+    }
+
     /// Sets whether the panel resolves aliases.
     pub fn set_resolves_aliases(&mut self, resolves: bool) {
         unsafe {
@@ -116,8 +132,44 @@ impl FileSelectPanel {
         self.allows_multiple_selection = allows;
     }
 
-    /// Shows the panel as a modal. Currently sheets are not supported, but you're free (and able
-    /// to) thread the Objective C calls yourself by using the panel field on this struct.
+    /// Sets the content types the user can choose between. Passing an empty slice allows any
+    /// type.
+    pub fn set_allowed_content_types(&mut self, types: &[UTType]) {
+        uti::set_allowed_content_types(&self.panel, types);
+    }
+
+    /// Sets the directory the panel starts in.
+    pub fn set_directory<P: AsRef<Path>>(&mut self, path: P) {
+        let path = NSString::new(&path.as_ref().to_string_lossy());
+
+        unsafe {
+            let url: id = msg_send![class!(NSURL), fileURLWithPath:path isDirectory:YES];
+            let _: () = msg_send![&*self.panel, setDirectoryURL:url];
+        }
+    }
+
+    /// Sets a custom view to be displayed alongside the standard panel controls.
+    pub fn set_accessory_view<L: Layout>(&mut self, view: &L) {
+        let backing_node = view.get_backing_node();
+
+        unsafe {
+            let _: () = msg_send![&*self.panel, setAccessoryView:&*backing_node];
+        }
+    }
+
+    /// Sets whether the panel shows hidden files.
+    pub fn set_shows_hidden_files(&mut self, shows: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.panel, setShowsHiddenFiles:match shows {
+                true => YES,
+                false => NO
+            }];
+        }
+
+        self.shows_hidden_files = shows;
+    }
+
+    /// Shows the panel as a modal. If you'd rather attach it to a window, see `begin_sheet`.
     ///
     /// Note that this clones the underlying `NSOpenPanel` pointer. This is theoretically safe as
     /// the system runs and manages that in another process, and we're still abiding by the general
@@ -137,6 +189,25 @@ impl FileSelectPanel {
             let _: () = msg_send![&*self.panel, beginWithCompletionHandler:completion.copy()];
         }
     }
+
+    /// Shows the panel as a sheet on the given window, calling `handler` with the selected
+    /// paths (if any) once the user is done.
+    #[cfg(feature = "macos")]
+    pub fn begin_sheet<W, F: Fn(Vec<PathBuf>) + 'static>(&self, window: &Window<W>, handler: F) {
+        let panel = self.panel.clone();
+        let completion = ConcreteBlock::new(move |result: NSInteger| {
+            let response: ModalResponse = result.into();
+
+            handler(match response {
+                ModalResponse::Ok => get_urls(&panel),
+                _ => Vec::new()
+            });
+        });
+
+        unsafe {
+            let _: () = msg_send![&*self.panel, beginSheetModalForWindow:&*window.objc completionHandler:completion.copy()];
+        }
+    }
 }
 
 /// Retrieves the selected URLs from the provided panel.
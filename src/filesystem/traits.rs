@@ -1,5 +1,12 @@
-//! A trait that you can implement to handle open and save file dialogs. This more or less maps
-//! over to `NSOpenPanel` and `NSSavePanel` handling.
+//! Traits that you can implement to handle open and save file dialogs (this more or less maps
+//! over to `NSOpenPanel` and `NSSavePanel` handling), and batch file operations.
+
+use url::Url;
+
+use crate::error::Error;
+use crate::filesystem::enums::FileOperation;
+
+/// A trait you can implement to respond to events from open and save panels.
 pub trait OpenSaveController {
     /// Called when the user has entered a filename (typically, during saving). `confirmed`
     /// indicates whether or not they hit the save button.
@@ -19,9 +26,46 @@ pub trait OpenSaveController {
     fn should_enable_url(&self, _url: &str) -> bool { true }
 }
 
-/// A trait you can implement for working with the underlying filesystem. This is important,
-/// notably, because sandboxed applications have different working restrictions surrounding what
-/// they can access.
-pub trait FileManagerController {
+/// A trait you can implement to control batch operations (copying, moving, linking and removing)
+/// performed by a `FileManager` created via `FileManager::with()`. This lets you skip individual
+/// items, or carry on past errors, rather than having the whole operation fail.
+///
+/// For directory operations, these are called for the directory and then for each item inside it.
+pub trait FileManagerDelegate {
+    /// Return `false` to skip copying the item at `from` to `to`.
+    fn should_copy(&self, _from: Url, _to: Url) -> bool { true }
+
+    /// Return `false` to skip moving the item at `from` to `to`.
+    fn should_move(&self, _from: Url, _to: Url) -> bool { true }
+
+    /// Return `false` to skip linking the item at `from` to `to`.
+    fn should_link(&self, _from: Url, _to: Url) -> bool { true }
+
+    /// Return `false` to skip removing the item at `url`.
+    fn should_remove(&self, _url: Url) -> bool { true }
+
+    /// Called when `operation` fails for an item. Return `true` to carry on with the rest of the
+    /// batch, or `false` (the default) to stop and have the operation return `error`.
+    fn should_proceed_after_error(&self, _error: Error, _operation: FileOperation) -> bool { false }
+}
+
+/// A trait you can implement to be told when other processes (or other parts of your app) read,
+/// change, move or delete a file or directory you're presenting - e.g, a document open in an
+/// editor. Register it via `PresentedItem::new()`. All methods are called on the main thread.
+pub trait FilePresenter {
+    /// Called when the contents (or attributes) of the item changed.
+    fn presented_item_did_change(&self) {}
+
+    /// Called when the item moved (or was renamed) to `url`.
+    fn presented_item_did_move(&self, _url: Url) {}
+
+    /// Called when something inside a presented directory changed.
+    fn presented_subitem_did_change(&self, _url: Url) {}
+
+    /// Called before someone else writes to the item, giving you a chance to save unsaved
+    /// changes first. Return an error if you couldn't.
+    fn save_presented_item_changes(&self) -> Result<(), Error> { Ok(()) }
 
+    /// Called before the item is deleted, so you can close it (and stop referring to it).
+    fn accommodate_presented_item_deletion(&self) {}
 }
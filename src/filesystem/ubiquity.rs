@@ -0,0 +1,243 @@
+//! Types for working with iCloud Drive ("ubiquitous") items: their download status, and
+//! `UbiquitousQuery`, which keeps you up to date on the items in your app's iCloud container.
+//!
+//! ```rust,no_run
+//! use cacao::filesystem::{DownloadingStatus, UbiquitousQuery, UbiquitousScope};
+//!
+//! // Keep this around for as long as you want to be notified.
+//! let query = UbiquitousQuery::new(UbiquitousScope::Documents, "*.txt", |items| {
+//!     for item in items {
+//!         if item.status == DownloadingStatus::NotDownloaded {
+//!             println!("In the cloud: {}", item.url);
+//!         }
+//!     }
+//! });
+//! ```
+
+use std::fmt;
+use std::rc::Rc;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+use url::Url;
+
+use crate::filesystem::url;
+use crate::foundation::{id, nil, NSNumber, NSString, NSUInteger};
+use crate::notification_center::NotificationObserver;
+
+static DID_FINISH_GATHERING: &str = "NSMetadataQueryDidFinishGatheringNotification";
+static DID_UPDATE: &str = "NSMetadataQueryDidUpdateNotification";
+
+/// `NSMetadataItemURLKey`.
+static URL_KEY: &str = "kMDItemURL";
+
+/// Returns the value of a metadata attribute, which may be `nil`.
+fn attribute(item: id, name: &str) -> id {
+    let name = NSString::new(name);
+    unsafe { msg_send![item, valueForAttribute:name] }
+}
+
+/// Returns the value of a boolean metadata attribute, defaulting to `false`.
+fn bool_attribute(item: id, name: &str) -> bool {
+    match attribute(item, name) {
+        value if value == nil => false,
+        value => NSNumber::wrap(value).as_bool()
+    }
+}
+
+/// Returns the value of a floating point metadata attribute, if it's set.
+fn f64_attribute(item: id, name: &str) -> Option<f64> {
+    match attribute(item, name) {
+        value if value == nil => None,
+        value => Some(NSNumber::wrap(value).as_f64())
+    }
+}
+
+/// Whether (and how up to date) a local copy of an iCloud item is.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DownloadingStatus {
+    /// There's no local copy.
+    NotDownloaded,
+
+    /// There's a local copy, but it may be out of date.
+    Downloaded,
+
+    /// The local copy is the most recent version.
+    Current
+}
+
+impl DownloadingStatus {
+    /// Maps an `NSURLUbiquitousItemDownloadingStatus` (or the metadata equivalent) value.
+    pub(crate) fn from_status(status: &str) -> Self {
+        match status {
+            "NSURLUbiquitousItemDownloadingStatusCurrent" => DownloadingStatus::Current,
+            "NSURLUbiquitousItemDownloadingStatusDownloaded" => DownloadingStatus::Downloaded,
+            _ => DownloadingStatus::NotDownloaded
+        }
+    }
+}
+
+/// Which part of your app's iCloud container to search.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UbiquitousScope {
+    /// The `Documents` directory, which is visible to the user in iCloud Drive.
+    Documents,
+
+    /// Everything outside of `Documents`, which is private to your app.
+    Data
+}
+
+impl UbiquitousScope {
+    fn to_str(&self) -> &'static str {
+        match self {
+            UbiquitousScope::Documents => "NSMetadataQueryUbiquitousDocumentsScope",
+            UbiquitousScope::Data => "NSMetadataQueryUbiquitousDataScope"
+        }
+    }
+}
+
+/// An item in your app's iCloud container, and its sync state.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UbiquitousItem {
+    /// The location of the item (whether or not it's been downloaded).
+    pub url: Url,
+
+    /// Whether there's an up to date local copy.
+    pub status: DownloadingStatus,
+
+    /// Whether the item is being downloaded.
+    pub is_downloading: bool,
+
+    /// Whether the item is being uploaded.
+    pub is_uploading: bool,
+
+    /// Whether the item has been uploaded.
+    pub is_uploaded: bool,
+
+    /// How much of the item has been downloaded (`0.0` to `100.0`), while it's downloading.
+    pub percent_downloaded: Option<f64>,
+
+    /// How much of the item has been uploaded (`0.0` to `100.0`), while it's uploading.
+    pub percent_uploaded: Option<f64>
+}
+
+impl UbiquitousItem {
+    /// Reads the attributes of an `NSMetadataItem`.
+    fn new(item: id) -> Option<Self> {
+        let status = attribute(item, "NSMetadataUbiquitousItemDownloadingStatusKey");
+        let status = match status == nil {
+            true => DownloadingStatus::NotDownloaded,
+            false => DownloadingStatus::from_status(NSString::wrap(status).to_str())
+        };
+
+        Some(UbiquitousItem {
+            url: url(attribute(item, URL_KEY))?,
+            status: status,
+            is_downloading: bool_attribute(item, "NSMetadataUbiquitousItemIsDownloadingKey"),
+            is_uploading: bool_attribute(item, "NSMetadataUbiquitousItemIsUploadingKey"),
+            is_uploaded: bool_attribute(item, "NSMetadataUbiquitousItemIsUploadedKey"),
+            percent_downloaded: f64_attribute(item, "NSMetadataUbiquitousItemPercentDownloadedKey"),
+            percent_uploaded: f64_attribute(item, "NSMetadataUbiquitousItemPercentUploadedKey")
+        })
+    }
+}
+
+/// Reads the current results of an `NSMetadataQuery`, pausing updates while doing so.
+fn results(query: &Object) -> Vec<UbiquitousItem> {
+    unsafe {
+        let _: () = msg_send![query, disableUpdates];
+
+        let count: NSUInteger = msg_send![query, resultCount];
+        let items = (0..count).filter_map(|index| {
+            let item: id = msg_send![query, resultAtIndex:index];
+            UbiquitousItem::new(item)
+        }).collect();
+
+        let _: () = msg_send![query, enableUpdates];
+        items
+    }
+}
+
+/// A live query over the items in your app's iCloud container, backed by `NSMetadataQuery`. The
+/// handler is called (on the main thread) with the full set of matching items once the initial
+/// search completes, and again whenever anything changes - including download and upload
+/// progress. The query stops when this is dropped.
+pub struct UbiquitousQuery {
+    /// The underlying `NSMetadataQuery`.
+    pub objc: ShareId<Object>,
+
+    /// Only held so that it's dropped (and stops observing) along with the query.
+    _observer: NotificationObserver
+}
+
+impl UbiquitousQuery {
+    /// Starts a query for items in `scope` whose file names match `pattern` (a wildcard pattern,
+    /// e.g `*` or `*.txt`).
+    pub fn new<F>(scope: UbiquitousScope, pattern: &str, handler: F) -> Self
+    where
+        F: Fn(Vec<UbiquitousItem>) + 'static
+    {
+        let objc = unsafe {
+            let query: id = msg_send![class!(NSMetadataQuery), new];
+            ShareId::from_retained_ptr(query)
+        };
+
+        // The pattern is passed as an argument, rather than spliced into the format string, so
+        // that quotes and format specifiers in it are matched literally.
+        let format = NSString::new("kMDItemFSName LIKE %@");
+        let pattern = NSString::new(pattern);
+        let scope = NSString::new(scope.to_str());
+
+        unsafe {
+            let arguments: id = msg_send![class!(NSArray), arrayWithObject:pattern];
+            let predicate: id = msg_send![class!(NSPredicate), predicateWithFormat:format argumentArray:arguments];
+            let scopes: id = msg_send![class!(NSArray), arrayWithObject:scope];
+            let _: () = msg_send![&*objc, setPredicate:predicate];
+            let _: () = msg_send![&*objc, setSearchScopes:scopes];
+        }
+
+        let center: id = unsafe { msg_send![class!(NSNotificationCenter), defaultCenter] };
+        let handler = Rc::new(handler);
+        let query = objc.clone();
+
+        let observer = NotificationObserver::new(center, &[DID_FINISH_GATHERING, DID_UPDATE], move |_, notification| {
+            let object: id = unsafe { msg_send![notification, object] };
+
+            if object == &*query as *const Object as id {
+                handler(results(&query));
+            }
+        });
+
+        unsafe {
+            let _: () = msg_send![&*objc, startQuery];
+        }
+
+        UbiquitousQuery {
+            objc: objc,
+            _observer: observer
+        }
+    }
+
+    /// Returns the current results.
+    pub fn results(&self) -> Vec<UbiquitousItem> {
+        results(&self.objc)
+    }
+}
+
+impl fmt::Debug for UbiquitousQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UbiquitousQuery")
+            .field("objc", &self.objc)
+            .finish()
+    }
+}
+
+impl Drop for UbiquitousQuery {
+    /// Stops the query.
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, stopQuery];
+        }
+    }
+}
@@ -0,0 +1,313 @@
+//! Implements `DirectoryWatcher`, which reports changes to the files inside a directory (built on
+//! FSEvents). This is what you'd want for e.g a live file tree in an editor.
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use cacao::filesystem::{DirectoryEventKind, DirectoryWatcher};
+//!
+//! # fn main() -> Result<(), cacao::error::Error> {
+//! // Keep this around for as long as you want to be notified.
+//! let watcher = DirectoryWatcher::new("/Users/me/Projects/site", true, Duration::from_millis(200), |events| {
+//!     for event in events {
+//!         if event.kinds.contains(&DirectoryEventKind::Created) {
+//!             println!("Created: {}", event.path.display());
+//!         }
+//!     }
+//! })?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::ffi::CStr;
+use std::fmt;
+use std::os::raw::{c_char, c_void};
+use std::path::{Path, PathBuf};
+use std::slice;
+use std::time::Duration;
+
+use dispatch::ffi::{dispatch_get_main_queue, dispatch_queue_t};
+
+use objc::runtime::Object;
+
+use crate::dispatch::{main_async, MainThreadMarker};
+use crate::error::{Error, CACAO_ERROR_DOMAIN};
+use crate::foundation::{NSArray, NSString};
+
+/// An opaque `FSEventStreamRef`.
+type FSEventStreamRef = *mut c_void;
+
+/// `FSEventStreamCallback`.
+type FSEventStreamCallback = extern "C" fn(FSEventStreamRef, *mut c_void, usize, *mut c_void, *const u32, *const u64);
+
+/// `FSEventStreamContext`.
+#[repr(C)]
+struct FSEventStreamContext {
+    version: isize,
+    info: *mut c_void,
+    retain: *const c_void,
+    release: *const c_void,
+    copy_description: *const c_void
+}
+
+/// `kFSEventStreamEventIdSinceNow`.
+const EVENT_ID_SINCE_NOW: u64 = 0xFFFFFFFFFFFFFFFF;
+
+/// `kFSEventStreamCreateFlagNoDefer | kFSEventStreamCreateFlagWatchRoot |
+/// kFSEventStreamCreateFlagFileEvents`.
+const CREATE_FLAGS: u32 = 0x02 | 0x04 | 0x10;
+
+const FLAG_MUST_SCAN_SUBDIRS: u32 = 0x01;
+const FLAG_ROOT_CHANGED: u32 = 0x20;
+const FLAG_ITEM_CREATED: u32 = 0x100;
+const FLAG_ITEM_REMOVED: u32 = 0x200;
+const FLAG_ITEM_INODE_META_MOD: u32 = 0x400;
+const FLAG_ITEM_RENAMED: u32 = 0x800;
+const FLAG_ITEM_MODIFIED: u32 = 0x1000;
+const FLAG_ITEM_FINDER_INFO_MOD: u32 = 0x2000;
+const FLAG_ITEM_CHANGE_OWNER: u32 = 0x4000;
+const FLAG_ITEM_XATTR_MOD: u32 = 0x8000;
+const FLAG_ITEM_IS_DIR: u32 = 0x20000;
+
+extern "C" {
+    fn FSEventStreamCreate(
+        allocator: *const c_void,
+        callback: FSEventStreamCallback,
+        context: *const FSEventStreamContext,
+        paths_to_watch: *const c_void,
+        since_when: u64,
+        latency: f64,
+        flags: u32
+    ) -> FSEventStreamRef;
+
+    fn FSEventStreamSetDispatchQueue(stream: FSEventStreamRef, queue: dispatch_queue_t);
+    fn FSEventStreamStart(stream: FSEventStreamRef) -> u8;
+    fn FSEventStreamStop(stream: FSEventStreamRef);
+    fn FSEventStreamInvalidate(stream: FSEventStreamRef);
+    fn FSEventStreamRelease(stream: FSEventStreamRef);
+}
+
+/// Something that happened to an item. Events are coalesced, so a single `DirectoryEvent` can
+/// carry several of these.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DirectoryEventKind {
+    /// The item was created.
+    Created,
+
+    /// The item was removed.
+    Removed,
+
+    /// The item was renamed, or moved in or out of the directory. You'll typically get one event
+    /// for the old path and one for the new; check which one still exists.
+    Renamed,
+
+    /// The item's contents were modified.
+    Modified,
+
+    /// The item's metadata (permissions, owner, extended attributes, etc) changed.
+    MetadataChanged,
+
+    /// The watched directory itself was moved or deleted.
+    RootChanged,
+
+    /// Events were dropped (e.g, too many changes happened at once), so anything under `path`
+    /// might have changed; rescan it.
+    RescanRequired
+}
+
+/// A change to an item inside a watched directory.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DirectoryEvent {
+    /// The item that changed.
+    pub path: PathBuf,
+
+    /// What happened to it.
+    pub kinds: Vec<DirectoryEventKind>,
+
+    /// Whether the item is a directory.
+    pub is_directory: bool
+}
+
+impl DirectoryEvent {
+    /// Decodes an event from its path and FSEvents flags.
+    fn new(path: PathBuf, flags: u32) -> Self {
+        let mut kinds = vec![];
+
+        let mapping = [
+            (FLAG_ITEM_CREATED, DirectoryEventKind::Created),
+            (FLAG_ITEM_REMOVED, DirectoryEventKind::Removed),
+            (FLAG_ITEM_RENAMED, DirectoryEventKind::Renamed),
+            (FLAG_ITEM_MODIFIED, DirectoryEventKind::Modified),
+            (FLAG_ITEM_INODE_META_MOD | FLAG_ITEM_FINDER_INFO_MOD | FLAG_ITEM_CHANGE_OWNER | FLAG_ITEM_XATTR_MOD, DirectoryEventKind::MetadataChanged),
+            (FLAG_ROOT_CHANGED, DirectoryEventKind::RootChanged),
+            (FLAG_MUST_SCAN_SUBDIRS, DirectoryEventKind::RescanRequired)
+        ];
+
+        for (mask, kind) in mapping.iter() {
+            if flags & mask != 0 {
+                kinds.push(*kind);
+            }
+        }
+
+        DirectoryEvent {
+            path: path,
+            kinds: kinds,
+            is_directory: flags & FLAG_ITEM_IS_DIR != 0
+        }
+    }
+}
+
+/// What the stream's `info` pointer refers to.
+struct WatcherContext {
+    root: PathBuf,
+    recursive: bool,
+    handler: Box<dyn Fn(Vec<DirectoryEvent>) + 'static>
+}
+
+/// Receives events from FSEvents (on the main queue), filters them, and calls the handler.
+extern "C" fn stream_callback(
+    _: FSEventStreamRef,
+    info: *mut c_void,
+    count: usize,
+    paths: *mut c_void,
+    flags: *const u32,
+    _: *const u64
+) {
+    let context = unsafe { &*(info as *const WatcherContext) };
+    let (paths, flags) = unsafe {
+        (slice::from_raw_parts(paths as *const *const c_char, count), slice::from_raw_parts(flags, count))
+    };
+
+    let events: Vec<DirectoryEvent> = paths.iter().zip(flags.iter()).filter_map(|(path, flags)| {
+        let path = PathBuf::from(unsafe { CStr::from_ptr(*path) }.to_string_lossy().into_owned());
+
+        let in_scope = context.recursive
+            || path == context.root
+            || path.parent() == Some(context.root.as_path());
+
+        match in_scope {
+            true => Some(DirectoryEvent::new(path, *flags)),
+            false => None
+        }
+    }).collect();
+
+    if !events.is_empty() {
+        (context.handler)(events);
+    }
+}
+
+/// Watches a directory for changes, calling a handler (on the main thread) with batches of
+/// events. Watching stops when this is dropped.
+///
+/// The handler isn't `Send`, so this has to be created - and dropped - on the main thread.
+pub struct DirectoryWatcher {
+    stream: FSEventStreamRef,
+    context: *mut WatcherContext
+}
+
+impl DirectoryWatcher {
+    /// Starts watching `path`. If `recursive` is `false`, only changes to the directory's
+    /// immediate children are reported. `latency` controls how long events are coalesced for
+    /// before being delivered; higher values mean fewer (but larger, and later) batches.
+    ///
+    /// Returns an error if this isn't called on the main thread, or if FSEvents can't watch
+    /// `path`.
+    pub fn new<P, F>(path: P, recursive: bool, latency: Duration, handler: F) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+        F: Fn(Vec<DirectoryEvent>) + 'static
+    {
+        MainThreadMarker::try_new("DirectoryWatcher::new")?;
+
+        // FSEvents reports canonical paths (e.g, `/private/tmp` rather than `/tmp`), so compare
+        // against the canonical root.
+        let root = path.as_ref().canonicalize().unwrap_or_else(|_| path.as_ref().to_path_buf());
+
+        let context = Box::into_raw(Box::new(WatcherContext {
+            root: root.clone(),
+            recursive: recursive,
+            handler: Box::new(handler)
+        }));
+
+        let stream_context = FSEventStreamContext {
+            version: 0,
+            info: context as *mut c_void,
+            retain: std::ptr::null(),
+            release: std::ptr::null(),
+            copy_description: std::ptr::null()
+        };
+
+        let root = NSString::new(&root.to_string_lossy());
+        let paths = NSArray::from(vec![&*root.0]);
+
+        let stream = unsafe {
+            let stream = FSEventStreamCreate(
+                std::ptr::null(),
+                stream_callback,
+                &stream_context,
+                &*paths.0 as *const Object as *const c_void,
+                EVENT_ID_SINCE_NOW,
+                latency.as_secs_f64(),
+                CREATE_FLAGS
+            );
+
+            if stream.is_null() {
+                drop(Box::from_raw(context));
+                return Err(watch_error(&root));
+            }
+
+            FSEventStreamSetDispatchQueue(stream, dispatch_get_main_queue());
+
+            if FSEventStreamStart(stream) == 0 {
+                FSEventStreamInvalidate(stream);
+                FSEventStreamRelease(stream);
+                drop(Box::from_raw(context));
+                return Err(watch_error(&root));
+            }
+
+            stream
+        };
+
+        Ok(DirectoryWatcher {
+            stream: stream,
+            context: context
+        })
+    }
+}
+
+impl fmt::Debug for DirectoryWatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let root = unsafe { &(*self.context).root };
+
+        f.debug_struct("DirectoryWatcher")
+            .field("root", root)
+            .finish()
+    }
+}
+
+impl Drop for DirectoryWatcher {
+    /// Stops and tears down the event stream, then frees the handler. A callback can already be
+    /// queued on the main queue when the stream is invalidated, so the handler is freed from the
+    /// main queue too - after anything that's ahead of it.
+    fn drop(&mut self) {
+        unsafe {
+            FSEventStreamStop(self.stream);
+            FSEventStreamInvalidate(self.stream);
+            FSEventStreamRelease(self.stream);
+        }
+
+        let context = self.context as usize;
+
+        main_async(move || unsafe {
+            drop(Box::from_raw(context as *mut WatcherContext));
+        });
+    }
+}
+
+/// The error returned when FSEvents won't watch `path` (e.g, it doesn't exist).
+fn watch_error(path: &NSString) -> Error {
+    Error::Cocoa {
+        code: 0,
+        domain: CACAO_ERROR_DOMAIN.to_string(),
+        description: format!("{} can't be watched for changes.", path.to_str())
+    }
+}
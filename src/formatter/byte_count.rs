@@ -0,0 +1,95 @@
+//! Wraps `NSByteCountFormatter`, for file and memory sizes - e.g "3.4 MB".
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, YES, NO, NSInteger};
+use crate::formatter::formatted;
+
+/// How a `ByteCountFormatter` counts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ByteCountStyle {
+    /// For file sizes: 1000 bytes to the KB on current systems. This is the default.
+    File,
+
+    /// For memory sizes: 1024 bytes to the KB.
+    Memory,
+
+    /// Always 1000 bytes to the KB.
+    Decimal,
+
+    /// Always 1024 bytes to the KB.
+    Binary
+}
+
+impl From<ByteCountStyle> for NSInteger {
+    fn from(style: ByteCountStyle) -> Self {
+        match style {
+            ByteCountStyle::File => 0,
+            ByteCountStyle::Memory => 1,
+            ByteCountStyle::Decimal => 2,
+            ByteCountStyle::Binary => 3
+        }
+    }
+}
+
+/// Formats byte counts, e.g "3.4 MB" or "Zero KB". Output follows the user's current locale.
+#[derive(Clone, Debug)]
+pub struct ByteCountFormatter {
+    /// The underlying `NSByteCountFormatter`.
+    pub objc: ShareId<Object>
+}
+
+impl Default for ByteCountFormatter {
+    fn default() -> Self {
+        ByteCountFormatter::new()
+    }
+}
+
+impl ByteCountFormatter {
+    /// Creates a formatter for file sizes.
+    pub fn new() -> Self {
+        ByteCountFormatter {
+            objc: unsafe {
+                let formatter: id = msg_send![class!(NSByteCountFormatter), new];
+                ShareId::from_retained_ptr(formatter)
+            }
+        }
+    }
+
+    /// Sets how bytes are counted.
+    pub fn set_style(&self, style: ByteCountStyle) {
+        let style: NSInteger = style.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setCountStyle:style];
+        }
+    }
+
+    /// Sets whether words can stand in for numbers - e.g "Zero KB" rather than "0 KB". On by
+    /// default.
+    pub fn set_allows_nonnumeric(&self, allows: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAllowsNonnumericFormatting:match allows {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether the exact byte count is added in parentheses, e.g "3.4 MB (3,400,000 bytes)".
+    pub fn set_includes_actual_byte_count(&self, includes: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setIncludesActualByteCount:match includes {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Formats `bytes`.
+    pub fn format(&self, bytes: i64) -> String {
+        formatted(unsafe { msg_send![&*self.objc, stringFromByteCount:bytes] })
+    }
+}
@@ -0,0 +1,159 @@
+//! Wraps `NSDateFormatter`, for dates and times - e.g "3 Mar 2020, 9:41 AM".
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, YES, NO, NSString, NSUInteger};
+use crate::formatter::{formatted, locale, ns_date};
+
+/// How much detail a `DateFormatter` shows, for the date and time parts separately. The exact
+/// output depends on the locale.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DateStyle {
+    /// Leave this part out.
+    None,
+
+    /// Numeric, e.g "3/3/20" or "9:41 AM".
+    Short,
+
+    /// Abbreviated, e.g "Mar 3, 2020".
+    Medium,
+
+    /// Spelled out, e.g "March 3, 2020" or "9:41:00 AM PST".
+    Long,
+
+    /// Everything, e.g "Tuesday, March 3, 2020 AD".
+    Full
+}
+
+impl From<DateStyle> for NSUInteger {
+    fn from(style: DateStyle) -> Self {
+        match style {
+            DateStyle::None => 0,
+            DateStyle::Short => 1,
+            DateStyle::Medium => 2,
+            DateStyle::Long => 3,
+            DateStyle::Full => 4
+        }
+    }
+}
+
+/// Formats (and parses) dates and times.
+#[derive(Clone, Debug)]
+pub struct DateFormatter {
+    /// The underlying `NSDateFormatter`.
+    pub objc: ShareId<Object>,
+
+    template: Option<String>
+}
+
+impl DateFormatter {
+    /// Creates a formatter showing the date and time in the given styles, e.g
+    /// `DateFormatter::new(DateStyle::Medium, DateStyle::None)` for just the date.
+    pub fn new(date_style: DateStyle, time_style: DateStyle) -> Self {
+        let date_style: NSUInteger = date_style.into();
+        let time_style: NSUInteger = time_style.into();
+
+        DateFormatter {
+            objc: unsafe {
+                let formatter: id = msg_send![class!(NSDateFormatter), new];
+                let _: () = msg_send![formatter, setDateStyle:date_style];
+                let _: () = msg_send![formatter, setTimeStyle:time_style];
+                ShareId::from_retained_ptr(formatter)
+            },
+
+            template: None
+        }
+    }
+
+    /// Creates a formatter showing the fields in `template`, arranged (and punctuated) however
+    /// the locale orders them - e.g `"MMMd"` comes out as "Mar 3" in the US, and "3 Mar" in the
+    /// UK. The template uses Unicode date field symbols (`y`, `MMM`, `d`, `EEEE`, `j` for the
+    /// locale's preferred hour, and so on).
+    pub fn with_template(template: &str) -> Self {
+        let formatter = DateFormatter {
+            objc: unsafe {
+                let formatter: id = msg_send![class!(NSDateFormatter), new];
+                ShareId::from_retained_ptr(formatter)
+            },
+
+            template: Some(template.to_string())
+        };
+
+        formatter.apply_template();
+        formatter
+    }
+
+    /// Turns the template into a format for the formatter's current locale.
+    fn apply_template(&self) {
+        if let Some(template) = &self.template {
+            let template = NSString::new(template);
+
+            unsafe {
+                let locale: id = msg_send![&*self.objc, locale];
+                let format: id = msg_send![class!(NSDateFormatter), dateFormatFromTemplate:template
+                    options:0 as NSUInteger locale:locale];
+                let _: () = msg_send![&*self.objc, setDateFormat:format];
+            }
+        }
+    }
+
+    /// Sets the locale to format for, e.g `"en_GB"`. By default, this is the user's.
+    pub fn set_locale(&self, identifier: &str) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setLocale:locale(identifier)];
+        }
+
+        self.apply_template();
+    }
+
+    /// Sets the time zone to show times in, by name (e.g, `"America/New_York"`, or `"UTC"`). By
+    /// default, this is the system's. Unknown names are ignored.
+    pub fn set_time_zone(&self, name: &str) {
+        let name = NSString::new(name);
+
+        unsafe {
+            let zone: id = msg_send![class!(NSTimeZone), timeZoneWithName:name];
+
+            if zone != nil {
+                let _: () = msg_send![&*self.objc, setTimeZone:zone];
+            }
+        }
+    }
+
+    /// Sets whether nearby dates are described relatively - "Today", "Yesterday", "Tomorrow" -
+    /// instead of being spelled out. Only applies when formatting with a date style.
+    pub fn set_uses_relative_dates(&self, relative: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setDoesRelativeDateFormatting:match relative {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Formats `time`.
+    pub fn format(&self, time: SystemTime) -> String {
+        formatted(unsafe { msg_send![&*self.objc, stringFromDate:ns_date(time)] })
+    }
+
+    /// Parses `string`, in this formatter's format, returning `None` if it doesn't match.
+    pub fn parse(&self, string: &str) -> Option<SystemTime> {
+        let string = NSString::new(string);
+        let date: id = unsafe { msg_send![&*self.objc, dateFromString:string] };
+
+        if date == nil {
+            return None;
+        }
+
+        let seconds: f64 = unsafe { msg_send![date, timeIntervalSince1970] };
+
+        match seconds >= 0. {
+            true => UNIX_EPOCH.checked_add(Duration::from_secs_f64(seconds)),
+            false => UNIX_EPOCH.checked_sub(Duration::from_secs_f64(-seconds))
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Wrappers for Foundation's formatters, which turn dates, numbers and sizes into text for
+//! display - "2 hours ago", "3.4 MB", "$1,299.00", "Tuesday, 3 March" - following the user's
+//! language and region settings, rather than hand-rolling (and getting wrong) the rules for each.
+//!
+//! ```rust,no_run
+//! use std::time::{Duration, SystemTime};
+//! use cacao::formatter::{ByteCountFormatter, ByteCountStyle, RelativeDateTimeFormatter};
+//!
+//! let size = ByteCountFormatter::new();
+//! size.set_style(ByteCountStyle::File);
+//! println!("{}", size.format(3_400_000)); // "3.4 MB"
+//!
+//! let relative = RelativeDateTimeFormatter::new();
+//! let then = SystemTime::now() - Duration::from_secs(2 * 60 * 60);
+//! println!("{}", relative.format(then, SystemTime::now())); // "2 hours ago"
+//! ```
+//!
+//! Formatters are relatively expensive to create, so hold on to them rather than making a new one
+//! each time. All of them use the user's current locale unless told otherwise (where they support
+//! it), via `set_locale()` and a locale identifier like `"en_US"` or `"fr_CA"`.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, NSString};
+
+mod byte_count;
+pub use byte_count::{ByteCountFormatter, ByteCountStyle};
+
+mod date;
+pub use date::{DateFormatter, DateStyle};
+
+mod number;
+pub use number::{NumberFormatter, NumberStyle};
+
+mod relative;
+pub use relative::{RelativeDateTimeFormatter, RelativeDateTimeStyle, RelativeUnitsStyle};
+
+/// Returns an (autoreleased) `NSLocale` for `identifier`.
+pub(crate) fn locale(identifier: &str) -> id {
+    let identifier = NSString::new(identifier);
+    unsafe { msg_send![class!(NSLocale), localeWithLocaleIdentifier:identifier] }
+}
+
+/// Returns an (autoreleased) `NSDate` for `time`.
+pub(crate) fn ns_date(time: SystemTime) -> id {
+    let seconds = match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs_f64(),
+        Err(error) => -error.duration().as_secs_f64()
+    };
+
+    unsafe { msg_send![class!(NSDate), dateWithTimeIntervalSince1970:seconds] }
+}
+
+/// Reads a string handed back by a formatter, which may be `nil` if it couldn't format the value.
+pub(crate) fn formatted(string: id) -> String {
+    match string == nil {
+        true => String::new(),
+        false => NSString::wrap(string).to_string()
+    }
+}
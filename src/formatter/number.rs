@@ -0,0 +1,139 @@
+//! Wraps `NSNumberFormatter`, for numbers, currency and percentages - e.g "1,299.5", "$1,299.50"
+//! or "42%".
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, NSString, NSUInteger};
+use crate::formatter::{formatted, locale};
+
+/// What kind of number a `NumberFormatter` is formatting.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NumberStyle {
+    /// Just the digits, e.g "1299.5".
+    None,
+
+    /// With grouping separators, e.g "1,299.5".
+    Decimal,
+
+    /// An amount of money, with the currency symbol, e.g "$1,299.50".
+    Currency,
+
+    /// An amount of money, with the ISO code, e.g "USD 1,299.50".
+    CurrencyIsoCode,
+
+    /// An amount of money, with the currency's name, e.g "1,299.50 US dollars".
+    CurrencyPlural,
+
+    /// An amount of money, in accounting style - negative amounts in parentheses.
+    CurrencyAccounting,
+
+    /// A fraction as a percentage: `0.42` becomes "42%".
+    Percent,
+
+    /// Scientific notation, e.g "1.2995E3".
+    Scientific,
+
+    /// Spelled out, e.g "one thousand two hundred ninety-nine point five".
+    SpellOut,
+
+    /// An ordinal, e.g "3rd".
+    Ordinal
+}
+
+impl From<NumberStyle> for NSUInteger {
+    fn from(style: NumberStyle) -> Self {
+        match style {
+            NumberStyle::None => 0,
+            NumberStyle::Decimal => 1,
+            NumberStyle::Currency => 2,
+            NumberStyle::Percent => 3,
+            NumberStyle::Scientific => 4,
+            NumberStyle::SpellOut => 5,
+            NumberStyle::Ordinal => 6,
+            NumberStyle::CurrencyIsoCode => 8,
+            NumberStyle::CurrencyPlural => 9,
+            NumberStyle::CurrencyAccounting => 10
+        }
+    }
+}
+
+/// Formats (and parses) numbers.
+#[derive(Clone, Debug)]
+pub struct NumberFormatter {
+    /// The underlying `NSNumberFormatter`.
+    pub objc: ShareId<Object>
+}
+
+impl NumberFormatter {
+    /// Creates a formatter in the given style.
+    pub fn new(style: NumberStyle) -> Self {
+        let style: NSUInteger = style.into();
+
+        NumberFormatter {
+            objc: unsafe {
+                let formatter: id = msg_send![class!(NSNumberFormatter), new];
+                let _: () = msg_send![formatter, setNumberStyle:style];
+                ShareId::from_retained_ptr(formatter)
+            }
+        }
+    }
+
+    /// Sets the locale to format for, e.g `"de_DE"`. By default, this is the user's.
+    pub fn set_locale(&self, identifier: &str) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setLocale:locale(identifier)];
+        }
+    }
+
+    /// Sets the currency, by ISO code (e.g, `"EUR"`). By default, it's the locale's.
+    pub fn set_currency_code(&self, code: &str) {
+        let code = NSString::new(code);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setCurrencyCode:code];
+        }
+    }
+
+    /// Sets the fewest digits shown after the decimal point.
+    pub fn set_minimum_fraction_digits(&self, digits: usize) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setMinimumFractionDigits:digits as NSUInteger];
+        }
+    }
+
+    /// Sets the most digits shown after the decimal point; the rest are rounded.
+    pub fn set_maximum_fraction_digits(&self, digits: usize) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setMaximumFractionDigits:digits as NSUInteger];
+        }
+    }
+
+    /// Formats `number`.
+    pub fn format(&self, number: f64) -> String {
+        unsafe {
+            let number: id = msg_send![class!(NSNumber), numberWithDouble:number];
+            formatted(msg_send![&*self.objc, stringFromNumber:number])
+        }
+    }
+
+    /// Formats `number`, without going through floating point (so large integers stay exact).
+    pub fn format_integer(&self, number: i64) -> String {
+        unsafe {
+            let number: id = msg_send![class!(NSNumber), numberWithLongLong:number];
+            formatted(msg_send![&*self.objc, stringFromNumber:number])
+        }
+    }
+
+    /// Parses `string`, in this formatter's style, returning `None` if it isn't a number.
+    pub fn parse(&self, string: &str) -> Option<f64> {
+        let string = NSString::new(string);
+        let number: id = unsafe { msg_send![&*self.objc, numberFromString:string] };
+
+        match number == nil {
+            true => None,
+            false => Some(unsafe { msg_send![number, doubleValue] })
+        }
+    }
+}
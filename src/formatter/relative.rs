@@ -0,0 +1,118 @@
+//! Wraps `NSRelativeDateTimeFormatter` (macOS 10.15, iOS 13 and up), for times relative to now -
+//! e.g "2 hours ago", "in 3 days" or "yesterday".
+
+use std::time::SystemTime;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, NSInteger};
+use crate::formatter::{formatted, locale, ns_date};
+
+/// Whether a `RelativeDateTimeFormatter` uses numbers or names.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RelativeDateTimeStyle {
+    /// Always numeric, e.g "1 day ago". This is the default.
+    Numeric,
+
+    /// Named, where the language has a name for it, e.g "yesterday".
+    Named
+}
+
+impl From<RelativeDateTimeStyle> for NSInteger {
+    fn from(style: RelativeDateTimeStyle) -> Self {
+        match style {
+            RelativeDateTimeStyle::Numeric => 0,
+            RelativeDateTimeStyle::Named => 1
+        }
+    }
+}
+
+/// How a `RelativeDateTimeFormatter` writes units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RelativeUnitsStyle {
+    /// Full words, e.g "2 hours ago". This is the default.
+    Full,
+
+    /// Full words, with the number spelled out too, e.g "two hours ago".
+    SpellOut,
+
+    /// Shortened, e.g "2 hr. ago".
+    Short,
+
+    /// As short as possible, e.g "2h ago".
+    Abbreviated
+}
+
+impl From<RelativeUnitsStyle> for NSInteger {
+    fn from(style: RelativeUnitsStyle) -> Self {
+        match style {
+            RelativeUnitsStyle::Full => 0,
+            RelativeUnitsStyle::SpellOut => 1,
+            RelativeUnitsStyle::Short => 2,
+            RelativeUnitsStyle::Abbreviated => 3
+        }
+    }
+}
+
+/// Formats one time relative to another, e.g "2 hours ago".
+#[derive(Clone, Debug)]
+pub struct RelativeDateTimeFormatter {
+    /// The underlying `NSRelativeDateTimeFormatter`.
+    pub objc: ShareId<Object>
+}
+
+impl Default for RelativeDateTimeFormatter {
+    fn default() -> Self {
+        RelativeDateTimeFormatter::new()
+    }
+}
+
+impl RelativeDateTimeFormatter {
+    /// Creates a formatter with numeric, full-word output.
+    pub fn new() -> Self {
+        RelativeDateTimeFormatter {
+            objc: unsafe {
+                let formatter: id = msg_send![class!(NSRelativeDateTimeFormatter), new];
+                ShareId::from_retained_ptr(formatter)
+            }
+        }
+    }
+
+    /// Sets whether to use numbers or names.
+    pub fn set_style(&self, style: RelativeDateTimeStyle) {
+        let style: NSInteger = style.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setDateTimeStyle:style];
+        }
+    }
+
+    /// Sets how units are written.
+    pub fn set_units_style(&self, style: RelativeUnitsStyle) {
+        let style: NSInteger = style.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setUnitsStyle:style];
+        }
+    }
+
+    /// Sets the locale to format for, e.g `"es_ES"`. By default, this is the user's.
+    pub fn set_locale(&self, identifier: &str) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setLocale:locale(identifier)];
+        }
+    }
+
+    /// Describes `time` relative to `reference` (usually `SystemTime::now()`), e.g "2 hours ago".
+    pub fn format(&self, time: SystemTime, reference: SystemTime) -> String {
+        formatted(unsafe { msg_send![&*self.objc, localizedStringForDate:ns_date(time) relativeToDate:ns_date(reference)] })
+    }
+
+    /// Describes an offset from now, in seconds: negative for the past ("2 hours ago"), and
+    /// positive for the future ("in 2 hours").
+    pub fn format_interval(&self, seconds: f64) -> String {
+        formatted(unsafe { msg_send![&*self.objc, localizedStringFromTimeInterval:seconds] })
+    }
+}
@@ -1,4 +1,23 @@
-//! A lightweight wrapper around `NSAutoreleasePool`.
+//! A lightweight wrapper around `NSAutoreleasePool`, along with `autoreleasepool()`, which is
+//! what you'll want most of the time.
+//!
+//! Objects the system hands back "autoreleased" (temporary strings, index sets, and so on) stick
+//! around until the enclosing pool drains - which, for the main thread, is once per pass of the
+//! event loop. If you're doing a lot of work in one go (e.g, a long-running loop that touches
+//! thousands of views), wrap each iteration so the temporaries are released as you go:
+//!
+//! ```rust,no_run
+//! use cacao::autoreleasepool;
+//! # fn import(_: &str) {}
+//! # let paths: Vec<String> = vec![];
+//!
+//! for path in paths.iter() {
+//!     autoreleasepool(|| {
+//!         // Whatever this creates along the way is released here, not at the end of the loop.
+//!         import(path);
+//!     });
+//! }
+//! ```
 
 use objc::{class, msg_send, sel, sel_impl};
 use objc::runtime::Object;
@@ -17,3 +36,12 @@ impl AutoReleasePool {
         let _: () = unsafe { msg_send![&*self.0, drain] };
     }
 }
+
+/// Runs `f` inside a new autorelease pool, which is drained once `f` returns (or panics). This
+/// is the equivalent of an `@autoreleasepool {}` block.
+///
+/// Anything `f` returns has to outlive the pool, so don't return autoreleased objects from it
+/// unless you've retained them first.
+pub fn autoreleasepool<T, F: FnOnce() -> T>(f: F) -> T {
+    objc::rc::autoreleasepool(f)
+}
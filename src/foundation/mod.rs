@@ -22,7 +22,7 @@ use objc::runtime;
 pub use objc::runtime::{BOOL, NO, YES};
 
 mod autoreleasepool;
-pub use autoreleasepool::AutoReleasePool;
+pub use autoreleasepool::{autoreleasepool, AutoReleasePool};
 
 mod array;
 pub use array::NSArray;
@@ -36,6 +36,9 @@ pub use dictionary::NSDictionary;
 mod number;
 pub use number::NSNumber;
 
+mod range;
+pub use range::NSRange;
+
 mod string;
 pub use string::NSString;
 
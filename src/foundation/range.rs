@@ -0,0 +1,98 @@
+use std::ops::Range;
+
+use objc::{Encode, Encoding};
+
+use crate::foundation::{NSInteger, NSUInteger};
+
+/// Wrapper for `NSRange`: a span of characters (in UTF-16 code units), or of items in a
+/// collection.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct NSRange {
+    /// Where the range starts.
+    pub location: NSUInteger,
+
+    /// How long the range is.
+    pub length: NSUInteger
+}
+
+impl NSRange {
+    /// `NSNotFound`, which Cocoa uses as the location of an empty (missing) range.
+    pub const NOT_FOUND: NSUInteger = NSInteger::max_value() as NSUInteger;
+
+    /// Returns a range with `NSNotFound` as its location, which Cocoa reads as "no range".
+    pub fn not_found() -> Self {
+        NSRange {
+            location: NSRange::NOT_FOUND,
+            length: 0
+        }
+    }
+
+    /// Converts this into a Rust range, or `None` if its location is `NSNotFound`.
+    pub fn to_range(&self) -> Option<Range<usize>> {
+        match self.location == NSRange::NOT_FOUND {
+            true => None,
+            false => Some(self.location as usize..(self.location + self.length) as usize)
+        }
+    }
+}
+
+impl From<Range<usize>> for NSRange {
+    fn from(range: Range<usize>) -> Self {
+        NSRange {
+            location: range.start as NSUInteger,
+            length: range.end.saturating_sub(range.start) as NSUInteger
+        }
+    }
+}
+
+impl From<Option<Range<usize>>> for NSRange {
+    fn from(range: Option<Range<usize>>) -> Self {
+        match range {
+            Some(range) => range.into(),
+            None => NSRange::not_found()
+        }
+    }
+}
+
+unsafe impl Encode for NSRange {
+    fn encode() -> Encoding {
+        let encoding = format!("{{_NSRange={}{}}}",
+            NSUInteger::encode().as_str(),
+            NSUInteger::encode().as_str()
+        );
+
+        unsafe { Encoding::from_str(&encoding) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NSRange;
+
+    #[test]
+    fn from_range() {
+        assert_eq!(NSRange::from(2..5), NSRange { location: 2, length: 3 });
+        assert_eq!(NSRange::from(4..4), NSRange { location: 4, length: 0 });
+
+        // A backwards range is empty, rather than wrapping around.
+        assert_eq!(NSRange::from(5..2), NSRange { location: 5, length: 0 });
+    }
+
+    #[test]
+    fn to_range() {
+        assert_eq!(NSRange { location: 2, length: 3 }.to_range(), Some(2..5));
+        assert_eq!(NSRange { location: 0, length: 0 }.to_range(), Some(0..0));
+        assert_eq!(NSRange::from(3..9).to_range(), Some(3..9));
+    }
+
+    #[test]
+    fn not_found_is_none() {
+        assert_eq!(NSRange::not_found().to_range(), None);
+        assert_eq!(NSRange::from(None), NSRange::not_found());
+        assert_eq!(NSRange::from(Some(1..3)), NSRange::from(1..3));
+
+        // Only the location matters.
+        assert_eq!(NSRange { location: NSRange::NOT_FOUND, length: 4 }.to_range(), None);
+    }
+}
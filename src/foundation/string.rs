@@ -27,6 +27,16 @@ impl NSString {
         })
     }
 
+    /// Creates an `NSString` that points at a `&'static str`'s bytes, rather than copying them
+    /// - for strings we hand to the system over and over (e.g, cell identifiers on every
+    /// dequeue).
+    pub(crate) fn no_copy(s: &'static str) -> Self {
+        NSString(unsafe {
+            let nsstring: id = msg_send![class!(NSString), alloc];
+            Id::from_retained_ptr(msg_send![nsstring, initWithBytesNoCopy:s.as_ptr() length:s.len() encoding:UTF8_ENCODING freeWhenDone:NO])
+        })
+    }
+
     /// In cases where we're vended an `NSString` by the system, this can be used to wrap and
     /// retain it.
     pub fn wrap(object: id) -> Self {
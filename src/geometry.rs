@@ -1,9 +1,24 @@
-//! Wrapper methods for various geometry types (rects, sizes, ec).
+//! Geometry types - rects, points, sizes and edge insets - in points, as `f64`s.
+//!
+//! These are what the framework's APIs take and return, so you shouldn't need to reach for
+//! `core_graphics` yourself; they convert to and from their `CG*` counterparts with `From`/`Into`
+//! if you're calling into the Objective-C runtime directly.
+//!
+//! Note that the origin of a coordinate space depends on the platform and the view: on macOS it's
+//! generally the bottom left (unless the view is flipped), while on iOS it's the top left. `top`
+//! and `y` are whatever the platform's origin makes them.
 
+use core_graphics::base::CGFloat;
 use core_graphics::geometry::{CGRect, CGPoint, CGSize};
 
+use objc::{Encode, Encoding};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// A struct that represents a box - top, left, width and height.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Rect {
     /// Distance from the top, in points.
     pub top: f64,
@@ -33,12 +48,69 @@ impl Rect {
             height: 0.0
         }
     }
+
+    /// Returns a `Rect` at `origin`, of `size`.
+    pub fn with(origin: Point, size: Size) -> Self {
+        Rect::new(origin.y, origin.x, size.width, size.height)
+    }
+
+    /// Returns the corner of this rect nearest the origin - (`left`, `top`).
+    pub fn origin(&self) -> Point {
+        Point::new(self.left, self.top)
+    }
+
+    /// Returns the width and height of this rect.
+    pub fn size(&self) -> Size {
+        Size::new(self.width, self.height)
+    }
+
+    /// Returns whether `point` falls within this rect.
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= self.left && point.x < self.left + self.width &&
+            point.y >= self.top && point.y < self.top + self.height
+    }
+
+    /// Returns the area this rect and `other` have in common, or `None` if they don't overlap.
+    /// Rects that only share an edge don't overlap, and neither does a zero-size rect.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let left = self.left.max(other.left);
+        let top = self.top.max(other.top);
+        let right = (self.left + self.width).min(other.left + other.width);
+        let bottom = (self.top + self.height).min(other.top + other.height);
+
+        if right <= left || bottom <= top {
+            return None;
+        }
+
+        Some(Rect::new(top, left, right - left, bottom - top))
+    }
+
+    /// Returns the smallest rect that contains both this rect and `other`. As with `CGRectUnion`,
+    /// a zero-size rect still counts by its origin.
+    pub fn union(&self, other: &Rect) -> Rect {
+        let left = self.left.min(other.left);
+        let top = self.top.min(other.top);
+        let right = (self.left + self.width).max(other.left + other.width);
+        let bottom = (self.top + self.height).max(other.top + other.height);
+
+        Rect::new(top, left, right - left, bottom - top)
+    }
+
+    /// Returns this rect, shrunk by `insets` on each side.
+    pub fn inset(&self, insets: EdgeInsets) -> Rect {
+        Rect {
+            top: self.top + insets.top as f64,
+            left: self.left + insets.left as f64,
+            width: self.width - (insets.left + insets.right) as f64,
+            height: self.height - (insets.top + insets.bottom) as f64
+        }
+    }
 }
 
 impl From<Rect> for CGRect {
     fn from(rect: Rect) -> CGRect {
         CGRect::new(
-             &CGPoint::new(rect.top, rect.left),
+             &CGPoint::new(rect.left, rect.top),
              &CGSize::new(rect.width, rect.height)
         )
     }
@@ -54,3 +126,220 @@ impl From<CGRect> for Rect {
         }
     }
 }
+
+/// A location, in points.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Point {
+    /// Distance from the left, in points.
+    pub x: f64,
+
+    /// Distance from the top, in points.
+    pub y: f64
+}
+
+impl Point {
+    /// Returns a new `Point` initialized with the values specified.
+    pub fn new(x: f64, y: f64) -> Self {
+        Point { x: x, y: y }
+    }
+
+    /// Returns the origin.
+    pub fn zero() -> Self {
+        Point { x: 0., y: 0. }
+    }
+}
+
+impl From<Point> for CGPoint {
+    fn from(point: Point) -> CGPoint {
+        CGPoint::new(point.x, point.y)
+    }
+}
+
+impl From<CGPoint> for Point {
+    fn from(point: CGPoint) -> Point {
+        Point {
+            x: point.x as f64,
+            y: point.y as f64
+        }
+    }
+}
+
+/// A width and height, in points.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Size {
+    /// Width, in points.
+    pub width: f64,
+
+    /// Height, in points.
+    pub height: f64
+}
+
+impl Size {
+    /// Returns a new `Size` initialized with the values specified.
+    pub fn new(width: f64, height: f64) -> Self {
+        Size { width: width, height: height }
+    }
+
+    /// Returns a zero'd out `Size`.
+    pub fn zero() -> Self {
+        Size { width: 0., height: 0. }
+    }
+}
+
+impl From<Size> for CGSize {
+    fn from(size: Size) -> CGSize {
+        CGSize::new(size.width, size.height)
+    }
+}
+
+impl From<CGSize> for Size {
+    fn from(size: CGSize) -> Size {
+        Size {
+            width: size.width as f64,
+            height: size.height as f64
+        }
+    }
+}
+
+/// Insets from each edge of a rect, in points - e.g, a scroll view's content insets, or the area
+/// of a screen obscured by hardware. This is laid out as `NSEdgeInsets`/`UIEdgeInsets`, so it can
+/// be passed to and returned from the Objective-C runtime directly.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EdgeInsets {
+    /// Inset from the top edge.
+    pub top: CGFloat,
+
+    /// Inset from the left edge.
+    pub left: CGFloat,
+
+    /// Inset from the bottom edge.
+    pub bottom: CGFloat,
+
+    /// Inset from the right edge.
+    pub right: CGFloat
+}
+
+impl EdgeInsets {
+    /// Returns a new `EdgeInsets` initialized with the values specified.
+    pub fn new(top: f64, left: f64, bottom: f64, right: f64) -> Self {
+        EdgeInsets {
+            top: top as CGFloat,
+            left: left as CGFloat,
+            bottom: bottom as CGFloat,
+            right: right as CGFloat
+        }
+    }
+
+    /// Returns insets of `inset` on every edge.
+    pub fn uniform(inset: f64) -> Self {
+        EdgeInsets::new(inset, inset, inset, inset)
+    }
+}
+
+unsafe impl Encode for EdgeInsets {
+    fn encode() -> Encoding {
+        #[cfg(target_os = "macos")]
+        let name = "NSEdgeInsets";
+
+        #[cfg(target_os = "ios")]
+        let name = "UIEdgeInsets";
+
+        let encoding = format!("{{{}={}{}{}{}}}",
+            name,
+            CGFloat::encode().as_str(),
+            CGFloat::encode().as_str(),
+            CGFloat::encode().as_str(),
+            CGFloat::encode().as_str()
+        );
+
+        unsafe { Encoding::from_str(&encoding) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EdgeInsets, Point, Rect};
+
+    #[test]
+    fn contains() {
+        let rect = Rect::new(10., 20., 100., 50.);
+
+        assert!(rect.contains(Point::new(50., 30.)));
+
+        // The top left edges are inside, the bottom right ones aren't.
+        assert!(rect.contains(Point::new(20., 10.)));
+        assert!(!rect.contains(Point::new(120., 30.)));
+        assert!(!rect.contains(Point::new(50., 60.)));
+        assert!(!rect.contains(Point::new(19.9, 30.)));
+
+        // A zero-size rect contains nothing, not even its own origin.
+        assert!(!Rect::zero().contains(Point::zero()));
+    }
+
+    #[test]
+    fn intersection() {
+        let a = Rect::new(0., 0., 100., 100.);
+        let b = Rect::new(50., 25., 100., 100.);
+
+        assert_eq!(a.intersection(&b), Some(Rect::new(50., 25., 75., 50.)));
+        assert_eq!(b.intersection(&a), a.intersection(&b));
+
+        let inner = Rect::new(10., 10., 20., 20.);
+        assert_eq!(a.intersection(&inner), Some(inner));
+        assert_eq!(a.intersection(&a), Some(a));
+    }
+
+    #[test]
+    fn intersection_without_overlap() {
+        let a = Rect::new(0., 0., 100., 100.);
+
+        assert_eq!(a.intersection(&Rect::new(200., 200., 10., 10.)), None);
+
+        // Sharing an edge or a corner isn't overlapping.
+        assert_eq!(a.intersection(&Rect::new(0., 100., 10., 10.)), None);
+        assert_eq!(a.intersection(&Rect::new(100., 0., 10., 10.)), None);
+        assert_eq!(a.intersection(&Rect::new(100., 100., 10., 10.)), None);
+
+        // Nor is a zero-size rect, even inside.
+        assert_eq!(a.intersection(&Rect::new(50., 50., 0., 0.)), None);
+        assert_eq!(a.intersection(&Rect::new(50., 50., 10., 0.)), None);
+    }
+
+    #[test]
+    fn union() {
+        let a = Rect::new(0., 0., 100., 100.);
+        let b = Rect::new(50., 25., 100., 100.);
+
+        assert_eq!(a.union(&b), Rect::new(0., 0., 125., 150.));
+        assert_eq!(b.union(&a), a.union(&b));
+
+        let inner = Rect::new(10., 10., 20., 20.);
+        assert_eq!(a.union(&inner), a);
+
+        // Disjoint rects are covered along with the gap between them.
+        let far = Rect::new(200., 200., 10., 10.);
+        assert_eq!(a.union(&far), Rect::new(0., 0., 210., 210.));
+    }
+
+    #[test]
+    fn union_with_zero_size() {
+        let a = Rect::new(10., 10., 20., 20.);
+
+        // A zero-size rect still pulls the union out to its origin.
+        assert_eq!(a.union(&Rect::zero()), Rect::new(0., 0., 30., 30.));
+        assert_eq!(a.union(&Rect::new(15., 15., 0., 0.)), a);
+        assert_eq!(Rect::zero().union(&Rect::zero()), Rect::zero());
+    }
+
+    #[test]
+    fn inset() {
+        let rect = Rect::new(0., 0., 100., 50.);
+
+        assert_eq!(rect.inset(EdgeInsets::new(5., 10., 15., 20.)), Rect::new(5., 10., 70., 30.));
+        assert_eq!(rect.inset(EdgeInsets::uniform(0.)), rect);
+    }
+}
@@ -0,0 +1,260 @@
+//! A collapsible section: a disclosure triangle, with content that slides open and closed below
+//! it - like "Show Details" in Finder's inspectors.
+
+use block::ConcreteBlock;
+use core_graphics::geometry::{CGRect, CGSize};
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::button::{BezelStyle, Button, ButtonState, ButtonType, ImagePosition};
+use crate::dispatch::assert_main_thread;
+use crate::foundation::{id, YES, NO, NSInteger};
+use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension, LayoutConstraint};
+use crate::view::View;
+
+/// How long the content takes to slide open or closed, in seconds.
+const ANIMATION_DURATION: f64 = 0.25;
+
+/// The pieces that expanding and collapsing touch, as raw pointers so that the button's action
+/// (which has to be `Send`) can hold them. They're all retained by the `DisclosureView`, which
+/// owns the button (and so the action).
+#[derive(Clone, Copy, Debug)]
+struct Parts {
+    button: usize,
+    clip: usize,
+    content: usize,
+    collapsed: usize,
+    expanded: usize
+}
+
+impl Parts {
+    fn new(button: &Button, clip: &View, content: &View, collapsed: &LayoutConstraint, expanded: &LayoutConstraint) -> Self {
+        Parts {
+            button: &*button.objc as *const Object as usize,
+            clip: &*clip.objc as *const Object as usize,
+            content: &*content.objc as *const Object as usize,
+            collapsed: &*collapsed.constraint as *const Object as usize,
+            expanded: &*expanded.constraint as *const Object as usize
+        }
+    }
+
+    /// Shows or hides the content. The clip view's height is pinned by the `collapsed`
+    /// constraint while animating (or closed); once open, the `expanded` constraint takes over,
+    /// so that the section follows its content if that changes size.
+    fn set_expanded(&self, expanded: bool, animated: bool) {
+        unsafe {
+            let clip = self.clip as id;
+            let content = self.content as id;
+            let collapsed = self.collapsed as id;
+            let expanded_constraint = self.expanded as id;
+
+            let target = match expanded {
+                true => {
+                    let size: CGSize = msg_send![content, fittingSize];
+                    size.height
+                },
+
+                false => {
+                    let frame: CGRect = msg_send![clip, frame];
+                    let _: () = msg_send![expanded_constraint, setActive:NO];
+                    let _: () = msg_send![collapsed, setConstant:frame.size.height];
+                    let _: () = msg_send![collapsed, setActive:YES];
+                    0.
+                }
+            };
+
+            if !animated {
+                let _: () = msg_send![collapsed, setConstant:target];
+                self.finish();
+                return;
+            }
+
+            let parts = *self;
+
+            let animation = ConcreteBlock::new(move |context: id| {
+                let _: () = msg_send![context, setDuration:ANIMATION_DURATION];
+                let _: () = msg_send![context, setAllowsImplicitAnimation:YES];
+
+                let animator: id = msg_send![parts.collapsed as id, animator];
+                let _: () = msg_send![animator, setConstant:target];
+            });
+            let animation = animation.copy();
+
+            let completion = ConcreteBlock::new(move || {
+                parts.finish();
+            });
+            let completion = completion.copy();
+
+            let _: () = msg_send![class!(NSAnimationContext), runAnimationGroup:&*animation
+                completionHandler:&*completion];
+        }
+    }
+
+    /// Once the content is fully open, hands its height over to the `expanded` constraint. This
+    /// checks the button, as the user may have closed it again mid-animation.
+    fn finish(&self) {
+        unsafe {
+            let state: NSInteger = msg_send![self.button as id, state];
+            let on: NSInteger = ButtonState::On.into();
+
+            if state == on {
+                let _: () = msg_send![self.collapsed as id, setActive:NO];
+                let _: () = msg_send![self.expanded as id, setActive:YES];
+            }
+        }
+    }
+}
+
+/// A disclosure triangle with a title, and content below it that's shown or hidden by clicking
+/// the triangle. It starts out collapsed.
+///
+/// Add your views to `content` (or to this, which does the same), and constrain them to
+/// `content`'s top, leading, trailing and bottom anchors - its height comes from what's in it.
+#[derive(Debug)]
+pub struct DisclosureView {
+    /// The underlying `NSView`, containing the triangle and the content.
+    pub objc: ShareId<Object>,
+
+    /// The disclosure triangle.
+    pub button: Button,
+
+    /// The view that's shown and hidden. Add your views to this.
+    pub content: View,
+
+    clip: View,
+    collapsed: LayoutConstraint,
+    expanded: LayoutConstraint,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    pub center_y: LayoutAnchorY
+}
+
+impl DisclosureView {
+    /// Creates a collapsed `DisclosureView`, with `title` beside its triangle.
+    pub fn new(title: &str) -> Self {
+        assert_main_thread("DisclosureView::new");
+
+        let view = View::new();
+        let clip = View::new();
+        let content = View::new();
+
+        let mut button = Button::new(title);
+        button.set_bezel_style(BezelStyle::Disclosure);
+        button.set_button_type(ButtonType::PushOnPushOff);
+        button.set_image_position(ImagePosition::ImageLeading);
+        button.set_state(ButtonState::Off);
+
+        unsafe {
+            let layer: id = msg_send![&*clip.objc, layer];
+            let _: () = msg_send![layer, setMasksToBounds:YES];
+        }
+
+        clip.add_subview(&content);
+        view.add_subview(&button);
+        view.add_subview(&clip);
+
+        let collapsed = clip.height.constraint_equal_to_constant(0.);
+        let expanded = clip.bottom.constraint_equal_to(&content.bottom);
+
+        LayoutConstraint::activate(&[
+            button.top.constraint_equal_to(&view.top),
+            button.leading.constraint_equal_to(&view.leading),
+            button.trailing.constraint_less_than_or_equal_to(&view.trailing),
+
+            clip.top.constraint_equal_to(&button.bottom).offset(4.),
+            clip.leading.constraint_equal_to(&view.leading),
+            clip.trailing.constraint_equal_to(&view.trailing),
+            clip.bottom.constraint_equal_to(&view.bottom),
+
+            content.top.constraint_equal_to(&clip.top),
+            content.leading.constraint_equal_to(&clip.leading),
+            content.trailing.constraint_equal_to(&clip.trailing),
+
+            collapsed.clone()
+        ]);
+
+        let parts = Parts::new(&button, &clip, &content, &collapsed, &expanded);
+
+        button.set_action(move || {
+            let state: NSInteger = unsafe { msg_send![parts.button as id, state] };
+            let on: NSInteger = ButtonState::On.into();
+            parts.set_expanded(state == on, true);
+        });
+
+        DisclosureView {
+            top: view.top.clone(),
+            leading: view.leading.clone(),
+            trailing: view.trailing.clone(),
+            bottom: view.bottom.clone(),
+            width: view.width.clone(),
+            height: view.height.clone(),
+            center_x: view.center_x.clone(),
+            center_y: view.center_y.clone(),
+            objc: view.objc.clone(),
+            button: button,
+            content: content,
+            clip: clip,
+            collapsed: collapsed,
+            expanded: expanded
+        }
+    }
+
+    /// Sets the title beside the triangle.
+    pub fn set_title(&self, title: &str) {
+        self.button.set_text(title);
+    }
+
+    /// Returns whether the content is showing.
+    pub fn is_expanded(&self) -> bool {
+        self.button.state() == ButtonState::On
+    }
+
+    /// Shows or hides the content, sliding it open or closed if `animated`.
+    pub fn set_expanded(&self, expanded: bool, animated: bool) {
+        if self.is_expanded() == expanded {
+            return;
+        }
+
+        self.button.set_state(match expanded {
+            true => ButtonState::On,
+            false => ButtonState::Off
+        });
+
+        Parts::new(&self.button, &self.clip, &self.content, &self.collapsed, &self.expanded)
+            .set_expanded(expanded, animated);
+    }
+}
+
+impl Layout for DisclosureView {
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.objc.clone()
+    }
+
+    /// Adds `view` to `content`.
+    fn add_subview<V: Layout>(&self, view: &V) {
+        self.content.add_subview(view);
+    }
+}
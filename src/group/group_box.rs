@@ -0,0 +1,178 @@
+//! Wraps `NSBox`, a container that draws a border (and, optionally, a title) around its content.
+
+use core_graphics::geometry::CGSize;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::color::Color;
+use crate::dispatch::assert_main_thread;
+use crate::foundation::{id, NO, NSString, NSUInteger};
+use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
+use crate::view::View;
+
+/// How a `Box` is drawn.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BoxStyle {
+    /// The standard look: a rounded, slightly darkened area with a subtle border. This is the
+    /// default.
+    Primary,
+
+    /// Drawn from the colors, border width and corner radius you set.
+    Custom
+}
+
+impl From<BoxStyle> for NSUInteger {
+    fn from(style: BoxStyle) -> Self {
+        match style {
+            BoxStyle::Primary => 0,
+            BoxStyle::Custom => 4
+        }
+    }
+}
+
+/// A container that visually groups its content, with an optional title above it.
+///
+/// Add subviews to it (or to `content`), and constrain them to `content`'s anchors - the content
+/// view sits inside the border and below the title.
+#[derive(Debug)]
+pub struct Box {
+    /// The underlying `NSBox`.
+    pub objc: ShareId<Object>,
+
+    /// The view inside the border, which subviews go into.
+    pub content: View,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    pub center_y: LayoutAnchorY
+}
+
+impl Default for Box {
+    fn default() -> Self {
+        Box::new()
+    }
+}
+
+impl Box {
+    /// Creates an untitled `Box`, in the primary style.
+    pub fn new() -> Self {
+        assert_main_thread("Box::new");
+
+        let view: id = unsafe {
+            let view: id = msg_send![class!(NSBox), new];
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints:NO];
+            let _: () = msg_send![view, setTitlePosition:0 as NSUInteger]; // NSNoTitle
+            view
+        };
+
+        Box {
+            content: unsafe { View::from_ptr(msg_send![view, contentView]) },
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            objc: unsafe { ShareId::from_retained_ptr(view) }
+        }
+    }
+
+    /// Sets the title shown above the content. An empty title hides it.
+    pub fn set_title(&self, title: &str) {
+        // NSNoTitle, NSAtTop
+        let position: NSUInteger = match title.is_empty() {
+            true => 0,
+            false => 2
+        };
+
+        let title = NSString::new(title);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTitle:title];
+            let _: () = msg_send![&*self.objc, setTitlePosition:position];
+        }
+    }
+
+    /// Sets how the box is drawn.
+    pub fn set_style(&self, style: BoxStyle) {
+        let style: NSUInteger = style.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setBoxType:style];
+        }
+    }
+
+    /// Sets the color inside the border. Only used with `BoxStyle::Custom`.
+    pub fn set_fill_color(&self, color: Color) {
+        let color = color.into_platform_specific_color();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setFillColor:color];
+        }
+    }
+
+    /// Sets the color of the border. Only used with `BoxStyle::Custom`.
+    pub fn set_border_color(&self, color: Color) {
+        let color = color.into_platform_specific_color();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setBorderColor:color];
+        }
+    }
+
+    /// Sets the width of the border, in points. Only used with `BoxStyle::Custom`.
+    pub fn set_border_width(&self, width: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setBorderWidth:width];
+        }
+    }
+
+    /// Sets the radius of the border's corners, in points. Only used with `BoxStyle::Custom`.
+    pub fn set_corner_radius(&self, radius: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setCornerRadius:radius];
+        }
+    }
+
+    /// Sets the space between the border and the content view, in points.
+    pub fn set_content_margins(&self, horizontal: f64, vertical: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setContentViewMargins:CGSize::new(horizontal, vertical)];
+        }
+    }
+}
+
+impl Layout for Box {
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.objc.clone()
+    }
+
+    /// Adds `view` to the content view.
+    fn add_subview<V: Layout>(&self, view: &V) {
+        self.content.add_subview(view);
+    }
+}
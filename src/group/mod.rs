@@ -0,0 +1,37 @@
+//! Views for visually grouping content, without drawing custom layers: `Box` (macOS), a titled,
+//! bordered container like the ones in System Preferences, `DisclosureView` (macOS), a section
+//! that collapses behind a disclosure triangle, and `Separator`, a hairline between sections.
+//!
+//! ```rust,no_run
+//! use cacao::group::{Box, Separator, SeparatorOrientation};
+//! use cacao::layout::{Layout, LayoutConstraint};
+//! use cacao::text::Label;
+//!
+//! let general = Box::new();
+//! general.set_title("General");
+//!
+//! let label = Label::new();
+//! general.add_subview(&label);
+//!
+//! LayoutConstraint::activate(&[
+//!     label.top.constraint_equal_to(&general.content.top).offset(8.),
+//!     label.leading.constraint_equal_to(&general.content.leading).offset(8.)
+//! ]);
+//!
+//! let separator = Separator::new(SeparatorOrientation::Horizontal);
+//! ```
+
+#[cfg(target_os = "macos")]
+mod disclosure;
+
+#[cfg(target_os = "macos")]
+pub use disclosure::DisclosureView;
+
+#[cfg(target_os = "macos")]
+mod group_box;
+
+#[cfg(target_os = "macos")]
+pub use group_box::{Box, BoxStyle};
+
+mod separator;
+pub use separator::{Separator, SeparatorOrientation};
@@ -0,0 +1,116 @@
+//! A hairline separator, for dividing sections of content. On macOS this is an `NSBox` of the
+//! separator type; on iOS, a `UIView` filled with the system separator color.
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::dispatch::assert_main_thread;
+use crate::foundation::{id, NO};
+use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension, LayoutConstraint};
+
+#[cfg(target_os = "macos")]
+use crate::foundation::NSUInteger;
+
+#[cfg(target_os = "ios")]
+use crate::color::{Color, SystemColor};
+
+/// Which way a `Separator` runs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SeparatorOrientation {
+    /// A line running left to right, between sections stacked vertically.
+    Horizontal,
+
+    /// A line running top to bottom, between sections side by side.
+    Vertical
+}
+
+/// A hairline between sections of content. Its thickness is fixed; constrain its length (e.g,
+/// the leading and trailing anchors of a horizontal one) to fit.
+#[derive(Debug)]
+pub struct Separator {
+    /// The underlying `NSBox` (`UIView` on iOS).
+    pub objc: ShareId<Object>,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    pub center_y: LayoutAnchorY
+}
+
+impl Separator {
+    /// Creates a separator running in `orientation`.
+    pub fn new(orientation: SeparatorOrientation) -> Self {
+        assert_main_thread("Separator::new");
+
+        #[cfg(target_os = "macos")]
+        let (view, thickness): (id, f64) = unsafe {
+            // NSBoxSeparator
+            let view: id = msg_send![class!(NSBox), new];
+            let _: () = msg_send![view, setBoxType:2 as NSUInteger];
+            (view, 1.)
+        };
+
+        #[cfg(target_os = "ios")]
+        let (view, thickness): (id, f64) = unsafe {
+            let view: id = msg_send![class!(UIView), new];
+            let color = Color::System(SystemColor::Separator).into_platform_specific_color();
+            let _: () = msg_send![view, setBackgroundColor:color];
+
+            // One pixel, rather than one point.
+            let screen: id = msg_send![class!(UIScreen), mainScreen];
+            let scale: f64 = msg_send![screen, scale];
+            (view, 1. / scale)
+        };
+
+        unsafe {
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints:NO];
+        }
+
+        let separator = Separator {
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            objc: unsafe { ShareId::from_retained_ptr(view) }
+        };
+
+        LayoutConstraint::activate(&[match orientation {
+            SeparatorOrientation::Horizontal => separator.height.constraint_equal_to_constant(thickness),
+            SeparatorOrientation::Vertical => separator.width.constraint_equal_to_constant(thickness)
+        }]);
+
+        separator
+    }
+}
+
+impl Layout for Separator {
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.objc.clone()
+    }
+
+    fn add_subview<V: Layout>(&self, _view: &V) {}
+}
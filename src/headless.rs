@@ -0,0 +1,731 @@
+//! A headless backend, for testing view-model and delegate logic on machines without a window
+//! server (e.g, CI). Enabled by the `headless` feature - which you'd typically only turn on for
+//! tests, via `[dev-dependencies]`.
+//!
+//! With it on, every class this framework registers (`RSTView`, `RSTTextField`, and so on) is
+//! swapped for a lightweight stand-in: a plain `NSObject` subclass that carries the same ivars and
+//! delegate callbacks, but no AppKit/UIKit machinery. Anything else sent to a stand-in is recorded
+//! rather than performed:
+//!
+//! - Setters (`setStringValue:` and friends) store their value, which the matching getter then
+//! returns - so e.g `TextField::get_value()` reads back what `set_text()` wrote.
+//! - Getters with nothing stored return zero, or (for objects) another stand-in.
+//! - Other messages that return an object (e.g, `constraintEqualToAnchor:`) return a fresh
+//! stand-in.
+//! - `init*` methods return the stand-in itself.
+//!
+//! ```rust,no_run
+//! use cacao::button::Button;
+//! use cacao::headless::{self, Value};
+//! use cacao::input::TextField;
+//!
+//! let field = TextField::new();
+//! field.set_text("Hello");
+//!
+//! assert_eq!(headless::property(&field.objc, "stringValue"), Some(Value::String("Hello".into())));
+//! assert_eq!(field.get_value(), "Hello");
+//!
+//! // Click a button, as AppKit would.
+//! let mut button = Button::new("Save");
+//! button.set_action(|| println!("Saved!"));
+//! assert!(headless::simulate_action(&button.objc));
+//! ```
+//!
+//! Main-thread assertions are skipped with this on, as test harnesses run tests on worker
+//! threads. Work sent to the main queue (e.g, via `MainThreadHandle`) still needs a run loop to
+//! happen, though, and callbacks that call through to the superclass implementation (which a
+//! stand-in doesn't have) can't be simulated. Stand-ins keep the real class's `dealloc`, so the
+//! same goes for releasing one whose `dealloc` does that (e.g, list view rows).
+//!
+//! # What works headless
+//!
+//! Stand-ins only replace the classes this framework registers. Anything that talks to a real
+//! AppKit/UIKit object is out of scope, as handing it a stand-in will crash (or worse). In
+//! practice:
+//!
+//! - Views, controls, and their delegates work, as do layout anchors and `LayoutConstraint`:
+//! activating constraints sends each one `setActive:`, which a stand-in records, rather than
+//! going through `NSLayoutConstraint`.
+//! - `add_subview()` and friends are recorded on the stand-in, rather than building a real view
+//! hierarchy - so there's no layout pass, drawing, or `snapshot()`.
+//! - `App`, `Window`, menus, alerts, panels, and anything else backed directly by a system class
+//! aren't stood in, and shouldn't be created in headless tests. Test the delegates and views you
+//! put in them instead.
+
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_void};
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+
+use objc::declare::ClassDecl;
+use objc::runtime::{self, Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, BOOL, NSString, NSUInteger, YES};
+
+extern "C" {
+    fn NSGetSizeAndAlignment(type_ptr: *const c_char, size: *mut NSUInteger, align: *mut NSUInteger) -> *const c_char;
+    fn method_getTypeEncoding(method: *const runtime::Method) -> *const c_char;
+    fn objc_setAssociatedObject(object: id, key: *const c_void, value: id, policy: usize);
+}
+
+/// `OBJC_ASSOCIATION_RETAIN_NONATOMIC`.
+const ASSOCIATION_RETAIN_NONATOMIC: usize = 1;
+
+/// The key state trackers are associated under; only its address matters.
+static STATE_TRACKER_KEY: u8 = 0;
+
+/// The ivar a state tracker keeps its stand-in's address in.
+static STATE_TRACKER_OWNER: &str = "rstStandInOwner";
+
+/// A value passed to (or stored on) a stand-in.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// `nil`.
+    Nil,
+
+    /// A `BOOL`.
+    Bool(bool),
+
+    /// Any integer type.
+    Int(i64),
+
+    /// A `float` or `double` (including `CGFloat`).
+    Float(f64),
+
+    /// An `NSString`.
+    String(String),
+
+    /// Any other object, identified by its class name.
+    Object(String),
+
+    /// A selector (e.g, a control's action).
+    Selector(String),
+
+    /// Anything else (e.g, a struct), identified by its Objective-C type encoding.
+    Other(String)
+}
+
+/// A message a stand-in received (and didn't otherwise handle).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Call {
+    /// The selector, e.g `setStringValue:`.
+    pub selector: String,
+
+    /// The argument, for single-argument messages.
+    pub argument: Option<Value>
+}
+
+/// A value stored by a setter: the raw bytes (so it can be handed back out by the getter), its
+/// type encoding, and the decoded version.
+struct Stored {
+    bytes: Vec<u8>,
+    encoding: String,
+    value: Value
+}
+
+/// What we know about a stand-in instance.
+#[derive(Default)]
+struct State {
+    calls: Vec<Call>,
+    properties: HashMap<String, Stored>,
+
+    /// Stand-ins handed out by object getters with nothing stored, so that asking twice returns
+    /// the same one. These are retained.
+    children: HashMap<String, usize>
+}
+
+lazy_static! {
+    /// Stand-in classes, mapped to the real class each stands in for. Both are `*const Class`es,
+    /// stored as `usize` so they can live in a static.
+    static ref STAND_IN_CLASSES: Mutex<HashMap<usize, usize>> = Mutex::new(HashMap::new());
+
+    /// State for live stand-ins, keyed by pointer.
+    static ref STATES: Mutex<HashMap<usize, State>> = Mutex::new(HashMap::new());
+}
+
+/// Classes that generic stand-ins (those handed out by getters) try for method signatures, in
+/// order - between them, they cover what we do with layout anchors and constraints.
+static GENERIC_SIGNATURE_CLASSES: &[&str] = &[
+    "NSLayoutXAxisAnchor",
+    "NSLayoutYAxisAnchor",
+    "NSLayoutDimension",
+    "NSLayoutConstraint"
+];
+
+/// Returns the part of a type encoding that identifies the type, minus qualifiers (`const` and
+/// so on).
+fn base_encoding(encoding: &str) -> &str {
+    encoding.trim_start_matches(|c| "rnNoORV".contains(c))
+}
+
+/// Decodes a value of the given type from its bytes.
+fn decode(encoding: &str, bytes: &[u8]) -> Value {
+    fn read<T: Copy>(bytes: &[u8]) -> T {
+        unsafe { std::ptr::read_unaligned(bytes.as_ptr() as *const T) }
+    }
+
+    match base_encoding(encoding).chars().next() {
+        Some('c') | Some('B') => Value::Bool(bytes.iter().any(|byte| *byte != 0)),
+        Some('s') => Value::Int(read::<i16>(bytes) as i64),
+        Some('S') => Value::Int(read::<u16>(bytes) as i64),
+        Some('i') => Value::Int(read::<i32>(bytes) as i64),
+        Some('I') => Value::Int(read::<u32>(bytes) as i64),
+        Some('l') | Some('q') => Value::Int(read::<i64>(bytes)),
+        Some('L') | Some('Q') => Value::Int(read::<u64>(bytes) as i64),
+        Some('f') => Value::Float(read::<f32>(bytes) as f64),
+        Some('d') => Value::Float(read::<f64>(bytes)),
+        Some(':') => match read::<usize>(bytes) {
+            0 => Value::Nil,
+            selector => Value::Selector(unsafe { Sel::from_ptr(selector as *const _) }.name().to_string())
+        },
+        Some('@') => describe(read::<usize>(bytes) as id),
+        _ => Value::Other(encoding.to_string())
+    }
+}
+
+/// Describes an object.
+fn describe(object: id) -> Value {
+    if object == nil {
+        return Value::Nil;
+    }
+
+    let is_string: BOOL = unsafe { msg_send![object, isKindOfClass:class!(NSString)] };
+
+    match is_string {
+        YES => Value::String(NSString::wrap(object).to_string()),
+        _ => Value::Object(unsafe { &*object }.class().name().to_string())
+    }
+}
+
+/// Returns the size of a type, from its encoding.
+fn size_of_encoding(encoding: *const c_char) -> usize {
+    let (mut size, mut align): (NSUInteger, NSUInteger) = (0, 0);
+    unsafe { NSGetSizeAndAlignment(encoding, &mut size, &mut align) };
+    size as usize
+}
+
+/// Returns the property a getter reads: `stringValue` for `stringValue`, and `hidden` for
+/// `isHidden`.
+fn getter_properties(selector: &str) -> Vec<String> {
+    let mut properties = vec![selector.to_string()];
+
+    if let Some(rest) = selector.strip_prefix("is") {
+        let mut chars = rest.chars();
+        if let Some(first) = chars.next() {
+            properties.push(first.to_lowercase().chain(chars).collect());
+        }
+    }
+
+    properties
+}
+
+/// Returns the property a setter writes: `stringValue` for `setStringValue:`.
+fn setter_property(selector: &str) -> Option<String> {
+    let rest = selector.strip_prefix("set")?.strip_suffix(':')?;
+
+    if rest.contains(':') {
+        return None;
+    }
+
+    let mut chars = rest.chars();
+    let first = chars.next()?;
+    Some(first.to_lowercase().chain(chars).collect())
+}
+
+/// Returns a method signature shaped like `selector`, with every argument (and the return
+/// value) an object. A last resort, for selectors no real class knows about.
+fn fallback_signature(selector: Sel) -> id {
+    let arguments = selector.name().matches(':').count();
+    let types = CString::new(format!("@@:{}", "@".repeat(arguments))).unwrap();
+
+    unsafe { msg_send![class!(NSMethodSignature), signatureWithObjCTypes:types.as_ptr()] }
+}
+
+/// Returns the real class a stand-in class stands in for, if it's one of ours.
+fn real_class(stand_in: &Class) -> Option<&'static Class> {
+    STAND_IN_CLASSES.lock().unwrap().get(&(stand_in as *const Class as usize)).map(|class| {
+        unsafe { &*(*class as *const Class) }
+    })
+}
+
+/// `-methodSignatureForSelector:`, answered from the real class.
+extern fn method_signature(this: &Object, _: Sel, selector: Sel) -> id {
+    let signature: id = match real_class(this.class()) {
+        Some(real) => unsafe { msg_send![real, instanceMethodSignatureForSelector:selector] },
+
+        None => GENERIC_SIGNATURE_CLASSES.iter().filter_map(|name| Class::get(name)).map(|class| {
+            let signature: id = unsafe { msg_send![class, instanceMethodSignatureForSelector:selector] };
+            signature
+        }).find(|signature| *signature != nil).unwrap_or(nil)
+    };
+
+    match signature {
+        signature if signature == nil => fallback_signature(selector),
+        signature => signature
+    }
+}
+
+/// `+methodSignatureForSelector:`, answered from the real class.
+extern fn class_method_signature(this: &Class, _: Sel, selector: Sel) -> id {
+    let signature: id = match real_class(this) {
+        Some(real) => unsafe { msg_send![real, methodSignatureForSelector:selector] },
+        None => nil
+    };
+
+    match signature {
+        signature if signature == nil => fallback_signature(selector),
+        signature => signature
+    }
+}
+
+/// Reads the argument at `index` from an invocation, returning its type encoding and bytes.
+fn read_argument(invocation: id, signature: id, index: NSUInteger) -> (String, Vec<u8>) {
+    unsafe {
+        let encoding: *const c_char = msg_send![signature, getArgumentTypeAtIndex:index];
+        let mut bytes = vec![0u8; size_of_encoding(encoding).max(std::mem::size_of::<usize>())];
+        let _: () = msg_send![invocation, getArgument:bytes.as_mut_ptr() atIndex:index];
+
+        (CStr::from_ptr(encoding).to_string_lossy().into_owned(), bytes)
+    }
+}
+
+/// Calls `handler` with the state for `this`, creating it (and attaching a tracker to drop it
+/// when `this` goes away) if need be.
+fn with_state<R, F: FnOnce(&mut State) -> R>(this: &Object, handler: F) -> R {
+    let key = this as *const Object as usize;
+    let mut states = STATES.lock().unwrap();
+
+    if !states.contains_key(&key) {
+        track(this);
+        states.insert(key, State::default());
+    }
+
+    handler(states.get_mut(&key).unwrap())
+}
+
+/// Returns (creating, if need be) the generic stand-in a getter hands out for `selector` on
+/// `this`.
+fn child(this: &Object, selector: &str) -> id {
+    let key = this as *const Object as usize;
+
+    if let Some(child) = STATES.lock().unwrap().get(&key).and_then(|state| state.children.get(selector).copied()) {
+        return child as id;
+    }
+
+    let child: id = unsafe { msg_send![generic_stand_in_class(), new] };
+    with_state(this, |state| state.children.insert(selector.to_string(), child as usize));
+    child
+}
+
+/// Returns a new (autoreleased) generic stand-in, for messages with arguments that return an
+/// object - e.g, `constraintEqualToAnchor:`, where each call should get its own.
+fn fresh_stand_in() -> id {
+    unsafe {
+        let stand_in: id = msg_send![generic_stand_in_class(), new];
+        msg_send![stand_in, autorelease]
+    }
+}
+
+/// `-forwardInvocation:`, which records the message and works out a return value.
+extern fn forward_invocation(this: &Object, _: Sel, invocation: id) {
+    let key = this as *const Object as usize;
+
+    let (selector, signature, arguments): (Sel, id, NSUInteger) = unsafe {
+        let selector: Sel = msg_send![invocation, selector];
+        let signature: id = msg_send![invocation, methodSignature];
+        let arguments: NSUInteger = msg_send![signature, numberOfArguments];
+        (selector, signature, arguments)
+    };
+
+    let name = selector.name().to_string();
+
+    // Single-argument messages: record (and, for setters, store) the argument.
+    let argument = match arguments {
+        3 => {
+            let (encoding, bytes) = read_argument(invocation, signature, 2);
+            let value = decode(&encoding, &bytes);
+
+            if let Some(property) = setter_property(&name) {
+                if base_encoding(&encoding).starts_with('@') {
+                    let _: id = unsafe { msg_send![*(bytes.as_ptr() as *const id), retain] };
+                }
+
+                let previous = with_state(this, |state| state.properties.insert(property, Stored {
+                    bytes: bytes,
+                    encoding: encoding,
+                    value: value.clone()
+                }));
+
+                release_stored(previous);
+            }
+
+            Some(value)
+        },
+
+        _ => None
+    };
+
+    with_state(this, |state| state.calls.push(Call {
+        selector: name.clone(),
+        argument: argument
+    }));
+
+    // Work out a return value, if there's one to give.
+    let (length, return_type): (NSUInteger, *const c_char) = unsafe {
+        (msg_send![signature, methodReturnLength], msg_send![signature, methodReturnType])
+    };
+
+    if length == 0 {
+        return;
+    }
+
+    let return_type = unsafe { CStr::from_ptr(return_type) }.to_string_lossy().into_owned();
+    let returns_object = base_encoding(&return_type).starts_with('@');
+
+    let stored = STATES.lock().unwrap().get(&key).and_then(|state| {
+        getter_properties(&name).iter().filter_map(|property| state.properties.get(property)).find(|stored| {
+            base_encoding(&stored.encoding) == base_encoding(&return_type) && stored.bytes.len() >= length as usize
+        }).map(|stored| stored.bytes.clone())
+    });
+
+    let bytes = match stored {
+        Some(bytes) => bytes,
+        None if returns_object && name.starts_with("init") => (this as *const Object as usize).to_ne_bytes().to_vec(),
+        None if returns_object && arguments == 2 => (child(this, &name) as usize).to_ne_bytes().to_vec(),
+        None if returns_object => (fresh_stand_in() as usize).to_ne_bytes().to_vec(),
+        None => vec![0u8; length as usize]
+    };
+
+    unsafe {
+        let _: () = msg_send![invocation, setReturnValue:bytes.as_ptr()];
+    }
+}
+
+/// `+forwardInvocation:`. Class methods that return an object (e.g, convenience constructors)
+/// return a fresh stand-in; anything else returns zero.
+extern fn class_forward_invocation(this: &Class, _: Sel, invocation: id) {
+    unsafe {
+        let signature: id = msg_send![invocation, methodSignature];
+        let length: NSUInteger = msg_send![signature, methodReturnLength];
+        let return_type: *const c_char = msg_send![signature, methodReturnType];
+
+        if length == 0 {
+            return;
+        }
+
+        let bytes = match base_encoding(&CStr::from_ptr(return_type).to_string_lossy()).starts_with('@') {
+            true => {
+                let instance: id = msg_send![this, new];
+                let instance: id = msg_send![instance, autorelease];
+                (instance as usize).to_ne_bytes().to_vec()
+            },
+
+            false => vec![0u8; length as usize]
+        };
+
+        let _: () = msg_send![invocation, setReturnValue:bytes.as_ptr()];
+    }
+}
+
+/// Releases a stored object value, if that's what it is.
+fn release_stored(stored: Option<Stored>) {
+    if let Some(stored) = stored {
+        if base_encoding(&stored.encoding).starts_with('@') {
+            unsafe {
+                let _: () = msg_send![*(stored.bytes.as_ptr() as *const id), release];
+            }
+        }
+    }
+}
+
+/// `-dealloc` for state trackers, which drops what we know about the stand-in the tracker was
+/// attached to. The runtime releases associated objects as their owner is deallocated, so this
+/// runs whenever a stand-in goes away - without replacing the stand-in's own `dealloc`.
+extern fn dealloc_state_tracker(this: &Object, _: Sel) {
+    let owner: usize = unsafe { *this.get_ivar(STATE_TRACKER_OWNER) };
+    let state = STATES.lock().unwrap().remove(&owner);
+
+    // Released with the lock dropped, as these may well be stand-ins themselves.
+    if let Some(state) = state {
+        for (_, stored) in state.properties {
+            release_stored(Some(stored));
+        }
+
+        for (_, child) in state.children {
+            unsafe {
+                let _: () = msg_send![child as id, release];
+            }
+        }
+    }
+
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+    }
+}
+
+/// Returns the class for state trackers: objects associated with a stand-in, whose only job is
+/// to drop the stand-in's state when it's deallocated.
+fn state_tracker_class() -> &'static Class {
+    if let Some(class) = Class::get("RSTStandInStateTracker") {
+        return class;
+    }
+
+    let mut decl = match ClassDecl::new("RSTStandInStateTracker", class!(NSObject)) {
+        Some(decl) => decl,
+
+        // Someone else registered it between the lookup and now.
+        None => { return Class::get("RSTStandInStateTracker").unwrap(); }
+    };
+
+    unsafe {
+        decl.add_ivar::<usize>(STATE_TRACKER_OWNER);
+        decl.add_method(sel!(dealloc), dealloc_state_tracker as extern fn(&Object, _));
+    }
+
+    decl.register()
+}
+
+/// Attaches a state tracker to `this`, so that its state is dropped along with it.
+fn track(this: &Object) {
+    unsafe {
+        let tracker: id = msg_send![state_tracker_class(), new];
+        (&mut *tracker).set_ivar(STATE_TRACKER_OWNER, this as *const Object as usize);
+
+        let key = &STATE_TRACKER_KEY as *const u8 as *const c_void;
+        objc_setAssociatedObject(this as *const Object as id, key, tracker, ASSOCIATION_RETAIN_NONATOMIC);
+        let _: () = msg_send![tracker, release];
+    }
+}
+
+/// Adds the forwarding machinery to a (not yet registered) stand-in class.
+unsafe fn add_forwarding(class: *mut Class) {
+    let methods: [(Sel, runtime::Imp, &str); 2] = [
+        (sel!(methodSignatureForSelector:), std::mem::transmute(method_signature as extern fn(&Object, Sel, Sel) -> id), "@@::\0"),
+        (sel!(forwardInvocation:), std::mem::transmute(forward_invocation as extern fn(&Object, Sel, id)), "v@:@\0")
+    ];
+
+    for (selector, imp, types) in methods.iter() {
+        runtime::class_addMethod(class, *selector, *imp, types.as_ptr() as *const c_char);
+    }
+}
+
+/// Returns the class for stand-ins that getters hand out.
+fn generic_stand_in_class() -> &'static Class {
+    if let Some(class) = Class::get("RSTStandInObject") {
+        return class;
+    }
+
+    unsafe {
+        let name = CString::new("RSTStandInObject").unwrap();
+        let class = runtime::objc_allocateClassPair(class!(NSObject), name.as_ptr(), 0);
+
+        // Someone else registered it between the lookup and now.
+        if class.is_null() {
+            return Class::get("RSTStandInObject").unwrap();
+        }
+
+        add_forwarding(class);
+        runtime::objc_registerClassPair(class);
+        &*class
+    }
+}
+
+/// Returns the stand-in class for `real`: an `NSObject` subclass with the same ivars and methods
+/// as `real` declares itself (i.e, our delegate pointers and callbacks), which records everything
+/// else it's sent. Called in place of registering `real`, when the `headless` feature is on.
+pub(crate) fn stand_in_class(real: &'static Class) -> &'static Class {
+    let name = format!("{}StandIn", real.name());
+
+    let mut classes = STAND_IN_CLASSES.lock().unwrap();
+
+    if let Some(class) = Class::get(&name) {
+        return class;
+    }
+
+    let class = unsafe {
+        let c_name = CString::new(name.as_str()).unwrap();
+        let class = runtime::objc_allocateClassPair(class!(NSObject), c_name.as_ptr(), 0);
+
+        for ivar in real.instance_variables().iter() {
+            let encoding = CString::new(ivar.type_encoding().as_str()).unwrap();
+            let (mut size, mut align): (NSUInteger, NSUInteger) = (0, 0);
+            NSGetSizeAndAlignment(encoding.as_ptr(), &mut size, &mut align);
+
+            let ivar_name = CString::new(ivar.name()).unwrap();
+            runtime::class_addIvar(class, ivar_name.as_ptr(), size as usize, align.trailing_zeros() as u8, encoding.as_ptr());
+        }
+
+        add_forwarding(class);
+
+        for method in real.instance_methods().iter() {
+            runtime::class_addMethod(class, method.name(), method.implementation(), method_getTypeEncoding(*method));
+        }
+
+        runtime::objc_registerClassPair(class);
+
+        let metaclass = runtime::object_getClass(class as *const Object) as *mut Class;
+        runtime::class_addMethod(
+            metaclass,
+            sel!(methodSignatureForSelector:),
+            std::mem::transmute(class_method_signature as extern fn(&Class, Sel, Sel) -> id),
+            "@@::\0".as_ptr() as *const c_char
+        );
+        runtime::class_addMethod(
+            metaclass,
+            sel!(forwardInvocation:),
+            std::mem::transmute(class_forward_invocation as extern fn(&Class, Sel, id)),
+            "v@:@\0".as_ptr() as *const c_char
+        );
+
+        &*class
+    };
+
+    classes.insert(class as *const Class as usize, real as *const Class as usize);
+    class
+}
+
+/// Returns whether `object` is a stand-in.
+pub fn is_stand_in(object: &Object) -> bool {
+    object.class() as *const Class == generic_stand_in_class() as *const Class ||
+        real_class(object.class()).is_some()
+}
+
+/// Returns the messages `object` has recorded, oldest first.
+pub fn calls(object: &Object) -> Vec<Call> {
+    STATES.lock().unwrap().get(&(object as *const Object as usize)).map(|state| {
+        state.calls.clone()
+    }).unwrap_or_default()
+}
+
+/// Forgets the messages `object` has recorded (but not the properties it's had set).
+pub fn clear_calls(object: &Object) {
+    if let Some(state) = STATES.lock().unwrap().get_mut(&(object as *const Object as usize)) {
+        state.calls.clear();
+    }
+}
+
+/// Returns the value last set for `property` (e.g, `stringValue`, for `setStringValue:`), if any.
+pub fn property(object: &Object, property: &str) -> Option<Value> {
+    STATES.lock().unwrap().get(&(object as *const Object as usize)).and_then(|state| {
+        state.properties.get(property).map(|stored| stored.value.clone())
+    })
+}
+
+/// Sends `selector` (which takes a single object argument, or none) to `object` - e.g, to fire a
+/// delegate callback as the system would. The argument is `nil`; use `simulate_with()` to pass
+/// one.
+pub fn simulate(object: &Object, selector: &str) {
+    simulate_with(object, selector, nil);
+}
+
+/// Like `simulate()`, passing `argument` (e.g, a notification, or the sender).
+pub fn simulate_with(object: &Object, selector: &str, argument: id) {
+    let selector = Sel::register(selector);
+
+    unsafe {
+        let _: id = msg_send![object, performSelector:selector withObject:argument];
+    }
+}
+
+/// Simulates the user activating a control (e.g, clicking a `Button`): sends the action it's been
+/// given to its target, just as AppKit would. Returns whether there was an action to send.
+pub fn simulate_action(control: &Object) -> bool {
+    let (target, action) = {
+        let states = STATES.lock().unwrap();
+        let state = match states.get(&(control as *const Object as usize)) {
+            Some(state) => state,
+            None => { return false; }
+        };
+
+        let read = |property: &str| state.properties.get(property).map(|stored| {
+            unsafe { std::ptr::read_unaligned(stored.bytes.as_ptr() as *const usize) }
+        }).unwrap_or(0);
+
+        (read("target"), read("action"))
+    };
+
+    if target == 0 || action == 0 {
+        return false;
+    }
+
+    unsafe {
+        let action = Sel::from_ptr(action as *const _);
+        let _: id = msg_send![target as id, performSelector:action withObject:control];
+    }
+
+    true
+}
+
+#[cfg(all(test, feature = "headless"))]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use super::*;
+    use crate::button::Button;
+    use crate::input::TextField;
+
+    #[test]
+    fn text_field_reads_back_what_was_set() {
+        let field = TextField::new();
+        assert!(is_stand_in(&field.objc));
+
+        field.set_text("Hello");
+
+        assert_eq!(property(&field.objc, "stringValue"), Some(Value::String("Hello".into())));
+        assert_eq!(field.get_value(), "Hello");
+    }
+
+    #[test]
+    fn setters_are_recorded() {
+        let field = TextField::new();
+        clear_calls(&field.objc);
+
+        field.set_text("Hello");
+
+        assert_eq!(calls(&field.objc), vec![Call {
+            selector: "setStringValue:".into(),
+            argument: Some(Value::String("Hello".into()))
+        }]);
+    }
+
+    #[test]
+    fn simulated_action_fires_the_handler() {
+        let fired = Arc::new(AtomicBool::new(false));
+        let handler_fired = fired.clone();
+
+        let mut button = Button::new("Save");
+        button.set_action(move || handler_fired.store(true, Ordering::SeqCst));
+
+        assert!(simulate_action(&button.objc));
+        assert!(fired.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn simulated_action_without_one_set() {
+        let button = Button::new("Save");
+        assert!(!simulate_action(&button.objc));
+    }
+
+    #[test]
+    fn state_is_dropped_with_the_stand_in() {
+        let stand_in: id = unsafe { msg_send![generic_stand_in_class(), new] };
+        let key = stand_in as usize;
+
+        unsafe {
+            let identifier = NSString::new("row");
+            let _: () = msg_send![stand_in, setIdentifier:&*identifier.0];
+        }
+
+        assert_eq!(property(unsafe { &*stand_in }, "identifier"), Some(Value::String("row".into())));
+
+        unsafe {
+            let _: () = msg_send![stand_in, release];
+        }
+
+        assert!(!STATES.lock().unwrap().contains_key(&key));
+    }
+}
@@ -7,11 +7,24 @@ use block::ConcreteBlock;
 
 use core_graphics::{
     base::{CGFloat},
-    geometry::{CGRect, CGPoint, CGSize}
+    geometry::{CGRect, CGSize}
 };
 use core_graphics::context::{CGContext, CGContextRef};
 
-use crate::foundation::{id, YES, NO};
+use crate::foundation::{id, nil, YES, NO, NSData};
+use crate::geometry::{Point, Rect, Size};
+
+#[cfg(target_os = "macos")]
+use crate::foundation::NSUInteger;
+
+#[cfg(target_os = "ios")]
+extern "C" {
+    fn UIImagePNGRepresentation(image: id) -> id;
+}
+
+/// `NSBitmapImageFileTypePNG`.
+#[cfg(target_os = "macos")]
+const PNG_FILE_TYPE: NSUInteger = 4;
 
 #[derive(Debug)]
 pub enum ResizeBehavior {
@@ -40,7 +53,10 @@ fn min_cgfloat(x: CGFloat, y: CGFloat) -> CGFloat {
 }
 
 impl ResizeBehavior {
-    pub fn apply(&self, source: CGRect, target: CGRect) -> CGRect {
+    pub fn apply(&self, source: Rect, target: Rect) -> Rect {
+        let source: CGRect = source.into();
+        let target: CGRect = target.into();
+
         // if equal, just return source
         if(
             source.origin.x == target.origin.x && 
@@ -48,7 +64,7 @@ impl ResizeBehavior {
             source.size.width == target.size.width &&
             source.size.height == target.size.height
         ) {
-            return source;
+            return source.into();
         }
 
         if(
@@ -57,7 +73,7 @@ impl ResizeBehavior {
             source.size.width == 0. &&
             source.size.height == 0. 
         ) {
-            return source;
+            return source.into();
         }
 
         let mut scales = CGSize::new(0., 0.);
@@ -88,14 +104,14 @@ impl ResizeBehavior {
         result.size.height *= scales.height;
         result.origin.x = target.origin.x + (target.size.width - result.size.width) / 2.;
         result.origin.y = target.origin.y + (target.size.height - result.size.height) / 2.;
-        result
+        result.into()
     }
 }
 
 #[derive(Debug)]
 pub struct DrawConfig {
-    pub source: (CGFloat, CGFloat),
-    pub target: (CGFloat, CGFloat),
+    pub source: Size,
+    pub target: Size,
     pub resize: ResizeBehavior
 }
 
@@ -110,22 +126,39 @@ impl Image {
         })
     }
 
+    /// Encodes this image as a PNG, returning the bytes - e.g, to write a snapshot to disk, or
+    /// compare it against a known-good one. Returns `None` if the image can't be encoded.
+    pub fn png_data(&self) -> Option<Vec<u8>> {
+        #[cfg(target_os = "macos")]
+        let data: id = unsafe {
+            let tiff: id = msg_send![&*self.0, TIFFRepresentation];
+            if tiff == nil {
+                return None;
+            }
+
+            let rep: id = msg_send![class!(NSBitmapImageRep), imageRepWithData:tiff];
+            let properties: id = msg_send![class!(NSDictionary), dictionary];
+            msg_send![rep, representationUsingType:PNG_FILE_TYPE properties:properties]
+        };
+
+        #[cfg(target_os = "ios")]
+        let data: id = unsafe { UIImagePNGRepresentation(&*self.0 as *const Object as id) };
+
+        match data == nil {
+            true => None,
+            false => Some(NSData::wrap(data).into_vec())
+        }
+    }
+
     /// Draw a custom image and get it back as a returned `Image`.
     pub fn draw<F>(config: DrawConfig, handler: F) -> Self
     where
-        F: Fn(CGRect, &CGContextRef) -> bool + 'static
+        F: Fn(Rect, &CGContextRef) -> bool + 'static
     {
-        let source_frame = CGRect::new(
-             &CGPoint::new(0., 0.),
-             &CGSize::new(config.source.0, config.source.1)
-        );
-
-        let target_frame = CGRect::new(
-             &CGPoint::new(0., 0.),
-             &CGSize::new(config.target.0, config.target.1)
-        );
-
+        let source_frame = Rect::with(Point::zero(), config.source);
+        let target_frame = Rect::with(Point::zero(), config.target);
         let resized_frame = config.resize.apply(source_frame, target_frame);
+        let size: CGSize = config.target.into();
 
         let block = ConcreteBlock::new(move |_destination: CGRect| unsafe {
             let current_context: id = msg_send![class!(NSGraphicsContext), currentContext];
@@ -133,10 +166,10 @@ impl Image {
             let context = CGContext::from_existing_context_ptr(context_ptr);
             let _: () = msg_send![class!(NSGraphicsContext), saveGraphicsState];
 
-            context.translate(resized_frame.origin.x, resized_frame.origin.y);
+            context.translate(resized_frame.left, resized_frame.top);
             context.scale(
-                resized_frame.size.width / config.source.0,
-                resized_frame.size.height / config.source.1
+                resized_frame.width / config.source.width,
+                resized_frame.height / config.source.height
             );
 
             let result = handler(resized_frame, &context);
@@ -151,7 +184,7 @@ impl Image {
         let block = block.copy();
 
         Image(unsafe {
-            let img: id = msg_send![class!(NSImage), imageWithSize:target_frame.size flipped:YES drawingHandler:block];
+            let img: id = msg_send![class!(NSImage), imageWithSize:size flipped:YES drawingHandler:block];
             ShareId::from_ptr(img)
         })
     }
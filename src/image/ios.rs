@@ -1,6 +1,5 @@
 use std::sync::Once;
 
-use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel, BOOL};
 use objc::{class, sel, sel_impl};
 use objc_id::Id;
@@ -8,7 +7,7 @@ use objc_id::Id;
 use crate::foundation::{id, YES, NO, NSUInteger};
 use crate::dragdrop::DragInfo;
 use crate::view::{VIEW_DELEGATE_PTR, ViewDelegate};
-use crate::utils::load;
+use crate::utils::{load_or_register_class, load_or_register_class_for};
 
 /// Injects an `NSView` subclass. This is used for the default views that don't use delegates - we
 /// have separate classes here since we don't want to waste cycles on methods that will never be
@@ -19,8 +18,7 @@ pub(crate) fn register_view_class() -> *const Class {
 
     INIT.call_once(|| unsafe {
         let superclass = class!(UIView);
-        let mut decl = ClassDecl::new("RSTView", superclass).unwrap();
-        VIEW_CLASS = decl.register();
+        VIEW_CLASS = load_or_register_class(superclass, "RSTView", |_| {});
     });
 
     unsafe { VIEW_CLASS }
@@ -28,18 +26,11 @@ pub(crate) fn register_view_class() -> *const Class {
 
 /// Injects an `NSView` subclass, with some callback and pointer ivars for what we
 /// need to do.
-pub(crate) fn register_view_class_with_delegate<T: ViewDelegate>() -> *const Class {
-    static mut VIEW_CLASS: *const Class = 0 as *const Class;
-    static INIT: Once = Once::new();
-
-    INIT.call_once(|| unsafe {
-        let superclass = class!(UIView);
-        let mut decl = ClassDecl::new("RSTViewWithDelegate", superclass).unwrap();
-        decl.add_ivar::<usize>(VIEW_DELEGATE_PTR);
-        VIEW_CLASS = decl.register();
-    });
-
+pub(crate) fn register_view_class_with_delegate<T: ViewDelegate + 'static>() -> *const Class {
     unsafe {
-        VIEW_CLASS
+        let superclass = class!(UIView);
+        load_or_register_class_for::<T, _>(superclass, "RSTViewWithDelegate", |decl| {
+            decl.add_ivar::<usize>(VIEW_DELEGATE_PTR);
+        })
     }
 }
@@ -9,7 +9,6 @@
 
 use std::sync::Once;
 
-use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel, BOOL};
 use objc::{class, sel, sel_impl};
 use objc_id::Id;
@@ -17,7 +16,7 @@ use objc_id::Id;
 use crate::foundation::{id, YES, NO, NSUInteger};
 use crate::dragdrop::DragInfo;
 use crate::view::{VIEW_DELEGATE_PTR, ViewDelegate};
-use crate::utils::load;
+use crate::utils::load_or_register_class;
 
 /// Injects an `NSView` subclass. This is used for the default views that don't use delegates - we
 /// have separate classes here since we don't want to waste cycles on methods that will never be
@@ -28,11 +27,9 @@ pub(crate) fn register_image_view_class() -> *const Class {
 
     INIT.call_once(|| unsafe {
         let superclass = class!(NSImageView);
-        let mut decl = ClassDecl::new("RSTImageView", superclass).unwrap();
-
-        //decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
-    
-        VIEW_CLASS = decl.register();
+        VIEW_CLASS = load_or_register_class(superclass, "RSTImageView", |_decl| {
+            //decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
+        });
     });
 
     unsafe { VIEW_CLASS }
@@ -2,6 +2,7 @@ use objc_id::ShareId;
 use objc::runtime::{Class, Object};
 use objc::{msg_send, sel, sel_impl};
 
+use crate::dispatch::{assert_main_thread, MainThreadHandle};
 use crate::foundation::{id, nil, YES, NO, NSArray, NSString};
 use crate::color::Color;
 use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
@@ -76,6 +77,8 @@ impl Default for ImageView {
 impl ImageView {
     /// Returns a default `View`, suitable for 
     pub fn new() -> Self {
+        assert_main_thread("ImageView::new");
+
         let view = allocate_view(register_image_view_class);
 
         ImageView {
@@ -91,6 +94,12 @@ impl ImageView {
         }
     }
 
+    /// Returns a handle to this image view that can be sent to other threads, and used to update
+    /// it from there; see `MainThreadHandle`.
+    pub fn handle(&self) -> ImageViewHandle {
+        MainThreadHandle::new(self.clone())
+    }
+
     /// Call this to set the background color for the backing layer.
     pub fn set_background_color(&self, color: Color) {
         let bg = color.into_platform_specific_color();
@@ -107,8 +116,27 @@ impl ImageView {
             let _: () = msg_send![&*self.objc, setImage:&*image.0];
         }
     }
+
+    /// Builds a `ImageView` around `objc`.
+    fn wrap(objc: ShareId<Object>) -> Self {
+        let view = &*objc;
+
+        ImageView {
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            objc: objc
+        }
+    }
 }
 
+impl_objc_access!(ImageView);
+
 impl Layout for ImageView {
     fn get_backing_node(&self) -> ShareId<Object> {
         self.objc.clone()
@@ -141,3 +169,7 @@ impl Drop for ImageView {
         }*/
     }
 }
+
+/// An `ImageView` handle that can be sent to other threads. Updates are applied on the main
+/// thread.
+pub type ImageViewHandle = MainThreadHandle<ImageView>;
@@ -0,0 +1,119 @@
+//! A builder for `TextField`s.
+
+use std::fmt;
+
+use crate::color::Color;
+use crate::input::{TextField, TextFieldDelegate};
+use crate::text::{Font, TextAlign};
+
+/// Configures a `TextField` in one expression, rather than a string of setters in `did_load()`.
+/// Everything is optional; anything left unset keeps the system default.
+///
+/// ```rust,no_run
+/// use cacao::input::TextField;
+/// use cacao::text::Font;
+///
+/// let field = TextField::builder()
+///     .placeholder("Name")
+///     .font(Font::system(14.))
+///     .on_change(|text| println!("Name is now {}", text))
+///     .build();
+/// ```
+#[derive(Default)]
+pub struct TextFieldBuilder {
+    text: Option<String>,
+    placeholder: Option<String>,
+    font: Option<Font>,
+    alignment: Option<TextAlign>,
+    background_color: Option<Color>,
+    on_change: Option<Box<dyn Fn(String) + 'static>>
+}
+
+impl TextFieldBuilder {
+    /// Sets the initial text.
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// Sets the placeholder text, shown while the field is empty.
+    pub fn placeholder(mut self, text: &str) -> Self {
+        self.placeholder = Some(text.to_string());
+        self
+    }
+
+    /// Sets the font.
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the text alignment.
+    pub fn alignment(mut self, alignment: TextAlign) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Sets the background color.
+    pub fn background_color(mut self, color: Color) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+
+    /// Sets a handler that's called with the current text whenever the user edits it.
+    pub fn on_change<F: Fn(String) + 'static>(mut self, handler: F) -> Self {
+        self.on_change = Some(Box::new(handler));
+        self
+    }
+
+    /// Creates the `TextField`.
+    pub fn build(self) -> TextField {
+        self.configure(TextField::new())
+    }
+
+    /// Creates the `TextField`, with a delegate.
+    pub fn build_with<T: TextFieldDelegate + 'static>(self, delegate: T) -> TextField<T> {
+        self.configure(TextField::with(delegate))
+    }
+
+    /// Applies everything that's been set.
+    fn configure<T>(self, mut field: TextField<T>) -> TextField<T> {
+        if let Some(text) = self.text {
+            field.set_text(&text);
+        }
+
+        if let Some(placeholder) = self.placeholder {
+            field.set_placeholder(&placeholder);
+        }
+
+        if let Some(font) = self.font {
+            field.set_font(&font);
+        }
+
+        if let Some(alignment) = self.alignment {
+            field.set_text_alignment(alignment);
+        }
+
+        if let Some(color) = self.background_color {
+            field.set_background_color(color);
+        }
+
+        if let Some(handler) = self.on_change {
+            field.set_on_change(handler);
+        }
+
+        field
+    }
+}
+
+impl fmt::Debug for TextFieldBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextFieldBuilder")
+            .field("text", &self.text)
+            .field("placeholder", &self.placeholder)
+            .field("font", &self.font)
+            .field("background_color", &self.background_color)
+            .field("on_change", &self.on_change.is_some())
+            .finish()
+    }
+}
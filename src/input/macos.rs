@@ -9,7 +9,6 @@
 
 use std::sync::Once;
 
-use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel, BOOL};
 use objc::{class, sel, sel_impl};
 use objc_id::Id;
@@ -17,7 +16,7 @@ use objc_id::Id;
 use crate::foundation::{id, YES, NO, NSUInteger};
 use crate::dragdrop::DragInfo;
 use crate::input::{TEXTFIELD_DELEGATE_PTR, TextFieldDelegate};
-use crate::utils::load;
+use crate::utils::{load_or_register_class, load_or_register_class_for};
 
 /// Injects an `NSTextField` subclass. This is used for the default views that don't use delegates - we
 /// have separate classes here since we don't want to waste cycles on methods that will never be
@@ -28,8 +27,7 @@ pub(crate) fn register_view_class() -> *const Class {
 
     INIT.call_once(|| unsafe {
         let superclass = class!(NSTextField);
-        let mut decl = ClassDecl::new("RSTTextInputField", superclass).unwrap();
-        VIEW_CLASS = decl.register();
+        VIEW_CLASS = load_or_register_class(superclass, "RSTTextInputField", |_| {});
     });
 
     unsafe { VIEW_CLASS }
@@ -37,22 +35,13 @@ pub(crate) fn register_view_class() -> *const Class {
 
 /// Injects an `NSTextField` subclass, with some callback and pointer ivars for what we
 /// need to do.
-pub(crate) fn register_view_class_with_delegate<T: TextFieldDelegate>() -> *const Class {
-    static mut VIEW_CLASS: *const Class = 0 as *const Class;
-    static INIT: Once = Once::new();
-
-    INIT.call_once(|| unsafe {
-        let superclass = class!(NSView);
-        let mut decl = ClassDecl::new("RSTTextInputFieldWithDelegate", superclass).unwrap();
-
+pub(crate) fn register_view_class_with_delegate<T: TextFieldDelegate + 'static>() -> *const Class {
+    // This has to be an `NSTextField` like the plain class, or fields with delegates can't be
+    // edited (and never get a field editor to handle Cut, Copy, Paste and Undo).
+    let superclass = class!(NSTextField);
+    load_or_register_class_for::<T, _>(superclass, "RSTTextInputFieldWithDelegate", |decl| {
         // A pointer to the "view controller" on the Rust side. It's expected that this doesn't
         // move.
-        decl.add_ivar::<usize>(TEXTFIELD_DELEGATE_PTR);        
-       
-        VIEW_CLASS = decl.register();
-    });
-
-    unsafe {
-        VIEW_CLASS
-    }
+        decl.add_ivar::<usize>(TEXTFIELD_DELEGATE_PTR);
+    })
 }
@@ -40,14 +40,20 @@
 //!
 //! For more information on Autolayout, view the module or check out the examples folder.
 
+use std::rc::Rc;
+
 use objc_id::ShareId;
-use objc::runtime::{Class, Object};
-use objc::{msg_send, sel, sel_impl};
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
 
+use crate::binding::{Binding, Observable};
+use crate::dispatch::{assert_main_thread, MainThreadHandle};
 use crate::foundation::{id, nil, YES, NO, NSArray, NSInteger, NSString};
 use crate::color::Color;
 use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
+use crate::notification_center::NotificationObserver;
 use crate::text::{Font, TextAlign};
+use crate::utils::clear_ptr;
 
 #[cfg(target_os = "macos")]
 mod macos;
@@ -67,8 +73,19 @@ use ios::{register_view_class, register_view_class_with_delegate};
 mod traits;
 pub use traits::TextFieldDelegate;
 
+mod builder;
+pub use builder::TextFieldBuilder;
+
 pub(crate) static TEXTFIELD_DELEGATE_PTR: &str = "rstTextFieldDelegatePtr";
 
+/// Posted by the system whenever the user edits the text in a field.
+#[cfg(target_os = "macos")]
+static TEXT_DID_CHANGE: &str = "NSControlTextDidChangeNotification";
+
+/// Posted by the system whenever the user edits the text in a field.
+#[cfg(target_os = "ios")]
+static TEXT_DID_CHANGE: &str = "UITextFieldTextDidChangeNotification";
+
 /// A helper method for instantiating view classes and applying default settings to them.
 fn allocate_view(registration_fn: fn() -> *const Class) -> id { 
     unsafe {
@@ -115,7 +132,10 @@ pub struct TextField<T = ()> {
     pub center_x: LayoutAnchorX,
 
     /// A pointer to the Objective-C runtime center Y layout constraint.
-    pub center_y: LayoutAnchorY
+    pub center_y: LayoutAnchorY,
+
+    /// Observes edits, if an `on_change` handler has been set.
+    change_observer: Option<NotificationObserver>
 }
 
 impl Default for TextField {
@@ -127,6 +147,8 @@ impl Default for TextField {
 impl TextField {
     /// Returns a default `TextField`, suitable for 
     pub fn new() -> Self {
+        assert_main_thread("TextField::new");
+
         let view = allocate_view(register_view_class);
 
         TextField {
@@ -140,14 +162,40 @@ impl TextField {
             center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
             center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
             objc: unsafe { ShareId::from_ptr(view) },
+            change_observer: None
+        }
+    }
+}
+
+impl TextField {
+    /// Builds a `TextField` around `objc`, with no delegate.
+    fn wrap(objc: ShareId<Object>) -> Self {
+        let view = &*objc;
+
+        TextField {
+            delegate: None,
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            change_observer: None,
+            objc: objc
         }
     }
 }
 
+impl_objc_access!(TextField<T>, TEXTFIELD_DELEGATE_PTR);
+
 impl<T> TextField<T> where T: TextFieldDelegate + 'static {
     /// Initializes a new TextField with a given `TextFieldDelegate`. This enables you to respond to events
     /// and customize the view as a module, similar to class-based systems.
     pub fn with(delegate: T) -> TextField<T> {
+        assert_main_thread("TextField::with");
+
         let delegate = Box::new(delegate);
         
         let label = allocate_view(register_view_class_with_delegate::<T>);
@@ -169,6 +217,7 @@ impl<T> TextField<T> where T: TextFieldDelegate + 'static {
             center_x: LayoutAnchorX::new(unsafe { msg_send![label, centerXAnchor] }),
             center_y: LayoutAnchorY::new(unsafe { msg_send![label, centerYAnchor] }),
             objc: unsafe { ShareId::from_ptr(label) },
+            change_observer: None
         };
 
         //(&mut delegate).did_load(label.clone_as_handle()); 
@@ -193,10 +242,17 @@ impl<T> TextField<T> {
             height: self.height.clone(),
             center_x: self.center_x.clone(),
             center_y: self.center_y.clone(),
-            objc: self.objc.clone()
+            objc: self.objc.clone(),
+            change_observer: None
         }
     }
 
+    /// Returns a handle to this text field that can be sent to other threads, and used to update it
+    /// from there; see `MainThreadHandle`.
+    pub fn handle(&self) -> TextFieldHandle {
+        MainThreadHandle::new(self.clone_as_handle())
+    }
+
     /// Grabs the value from the textfield and returns it as an owned String.
     pub fn get_value(&self) -> String {
         let value = NSString::wrap(unsafe {
@@ -238,6 +294,173 @@ impl<T> TextField<T> {
             let _: () = msg_send![&*self.objc, setFont:&*font.objc];
         }
     }
+
+    /// Sets whether the font follows the user's preferred text size (Dynamic Type) as it
+    /// changes. This only works for fonts from `Font::preferred()` or `Font::scaled()`.
+    #[cfg(target_os = "ios")]
+    pub fn set_adjusts_font_for_content_size_category(&self, adjusts: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAdjustsFontForContentSizeCategory:match adjusts {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets the placeholder text, which is shown (dimmed) while the field is empty.
+    pub fn set_placeholder(&self, text: &str) {
+        let s = NSString::new(text);
+
+        unsafe {
+            #[cfg(target_os = "macos")]
+            let _: () = msg_send![&*self.objc, setPlaceholderString:&*s.0];
+
+            #[cfg(target_os = "ios")]
+            let _: () = msg_send![&*self.objc, setPlaceholder:&*s.0];
+        }
+    }
+
+    /// Sends a standard edit action to whatever's doing the editing: on macOS, that's the
+    /// window's field editor (an `NSTextView`), which only exists while the field is focused; on
+    /// iOS, it's the field itself.
+    fn perform_edit_action(&self, action: Sel) {
+        unsafe {
+            #[cfg(target_os = "macos")]
+            let editor: id = msg_send![&*self.objc, currentEditor];
+
+            #[cfg(target_os = "ios")]
+            let editor: id = &*self.objc as *const Object as id;
+
+            if editor != nil {
+                let _: id = msg_send![editor, performSelector:action withObject:nil];
+            }
+        }
+    }
+
+    /// Cuts the selected text to the clipboard, as Edit > Cut would. Does nothing unless the
+    /// field is focused.
+    pub fn cut(&self) {
+        self.perform_edit_action(sel!(cut:));
+    }
+
+    /// Copies the selected text to the clipboard, as Edit > Copy would. Does nothing unless the
+    /// field is focused.
+    pub fn copy(&self) {
+        self.perform_edit_action(sel!(copy:));
+    }
+
+    /// Pastes from the clipboard over the selection, as Edit > Paste would. Does nothing unless
+    /// the field is focused.
+    pub fn paste(&self) {
+        self.perform_edit_action(sel!(paste:));
+    }
+
+    /// Selects all of the text. On macOS, this focuses the field if it isn't already.
+    pub fn select_all(&self) {
+        #[cfg(target_os = "macos")]
+        unsafe {
+            let editor: id = msg_send![&*self.objc, currentEditor];
+
+            if editor == nil {
+                let _: () = msg_send![&*self.objc, selectText:nil];
+                return;
+            }
+        }
+
+        self.perform_edit_action(sel!(selectAll:));
+    }
+
+    /// Returns the undo manager that records edits to this field, if it's focused.
+    fn undo_manager(&self) -> id {
+        unsafe {
+            #[cfg(target_os = "macos")]
+            let editor: id = msg_send![&*self.objc, currentEditor];
+
+            #[cfg(target_os = "ios")]
+            let editor: id = &*self.objc as *const Object as id;
+
+            match editor == nil {
+                true => nil,
+                false => msg_send![editor, undoManager]
+            }
+        }
+    }
+
+    /// Undoes the last edit, as Edit > Undo would. Does nothing unless the field is focused.
+    pub fn undo(&self) {
+        let manager = self.undo_manager();
+
+        if manager != nil {
+            unsafe {
+                let _: () = msg_send![manager, undo];
+            }
+        }
+    }
+
+    /// Redoes the last undone edit, as Edit > Redo would. Does nothing unless the field is
+    /// focused.
+    pub fn redo(&self) {
+        let manager = self.undo_manager();
+
+        if manager != nil {
+            unsafe {
+                let _: () = msg_send![manager, redo];
+            }
+        }
+    }
+
+    /// Calls `handler` with the current text every time the user edits it. Setting a new handler
+    /// replaces the old one; the handler lives as long as this `TextField`.
+    pub fn set_on_change<F: Fn(String) + 'static>(&mut self, handler: F) {
+        let center: id = unsafe { msg_send![class!(NSNotificationCenter), defaultCenter] };
+        let handler = Rc::new(handler);
+        let field = self.objc.clone();
+
+        self.change_observer = Some(NotificationObserver::new(center, &[TEXT_DID_CHANGE], move |_, notification| {
+            let object: id = unsafe { msg_send![notification, object] };
+
+            if object == &*field as *const Object as id {
+                let text = NSString::wrap(unsafe {
+                    msg_send![&*field, stringValue]
+                });
+
+                handler(text.to_string());
+            }
+        }));
+    }
+}
+
+impl<T: 'static> TextField<T> {
+    /// Keeps the text and `text` in sync, both ways, until the returned `Binding` is dropped:
+    /// changing the value (from any thread) updates the field on the main thread, and edits the
+    /// user makes are written back to the value.
+    ///
+    /// This uses the field's change handler, so it replaces anything set with `set_on_change()`.
+    pub fn bind_text(&mut self, text: &Observable<String>) -> Binding<String> {
+        self.set_text(&text.get());
+
+        let model = text.clone();
+        self.set_on_change(move |value| model.set(value));
+
+        let handle = self.handle();
+        text.subscribe(move |value| {
+            let value = value.clone();
+
+            // Edits come back around through here; skip them, so as not to disturb the cursor.
+            handle.update(move |field| {
+                if field.get_value() != value {
+                    field.set_text(&value);
+                }
+            });
+        })
+    }
+}
+
+impl TextField {
+    /// Returns a builder, for configuring a `TextField` in one go; see `TextFieldBuilder`.
+    pub fn builder() -> TextFieldBuilder {
+        TextFieldBuilder::default()
+    }
 }
 
 impl<T> Layout for TextField<T> {
@@ -260,9 +483,12 @@ impl<T> Drop for TextField<T> {
     /// this has a superview (i.e, it's in the heirarchy) on the AppKit side. If it does, we go
     /// ahead and remove it - this is intended to match the semantics of how Rust handles things).
     ///
-    /// There are, thankfully, no delegates we need to break here.
+    /// The delegate pointer on the Objective-C side is cleared too, so any callbacks that arrive
+    /// after this (e.g, if something else is still holding on to the view) are ignored.
     fn drop(&mut self) {
         if self.delegate.is_some() {
+            clear_ptr(&self.objc, TEXTFIELD_DELEGATE_PTR);
+
             unsafe {
                 let superview: id = msg_send![&*self.objc, superview];
                 if superview != nil {
@@ -272,3 +498,14 @@ impl<T> Drop for TextField<T> {
         }
     }
 }
+
+/// A `TextField` handle that can be sent to other threads. Updates are applied on the main thread.
+pub type TextFieldHandle = MainThreadHandle<TextField>;
+
+impl TextFieldHandle {
+    /// Sets the text, on the main thread.
+    pub fn set_text(&self, text: &str) {
+        let text = text.to_string();
+        self.update(move |field| field.set_text(&text));
+    }
+}
@@ -9,16 +9,15 @@
 //! is going away.
 
 use std::fmt;
-use std::sync::{Arc, Mutex, Once};
+use std::sync::{Arc, Mutex};
 
 use objc_id::ShareId;
-use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel};
 use objc::{class, msg_send, sel, sel_impl};
 use block::{Block, ConcreteBlock, RcBlock};
 
 use crate::foundation::{id, nil, NSString};
-use crate::utils::load;
+use crate::utils::load_or_register_class_for;
 
 pub static ACTION_CALLBACK_PTR: &str = "rstTargetActionPtr";
 
@@ -79,7 +78,7 @@ impl TargetActionHandler {
 
 /// This will fire for an NSButton callback.
 extern fn perform<F: Fn() + 'static>(this: &mut Object, _: Sel, _sender: id) {
-    let action = load::<Action>(this, ACTION_CALLBACK_PTR);
+    let action = load_or_return!(Action, this, ACTION_CALLBACK_PTR);
     (action.0)();
 }
 
@@ -96,18 +95,11 @@ extern fn perform<F: Fn() + 'static>(this: &mut Object, _: Sel, _sender: id) {
 /// on drop. We handle the heap copy on the Rust side, so setting the block
 /// is just an ivar.
 pub(crate) fn register_invoker_class<F: Fn() + 'static>() -> *const Class {
-    static mut VIEW_CLASS: *const Class = 0 as *const Class;
-    static INIT: Once = Once::new();
-
-    INIT.call_once(|| unsafe {
+    unsafe {
         let superclass = class!(NSObject);
-        let mut decl = ClassDecl::new("RSTTargetActionHandler", superclass).unwrap();
-
-        decl.add_ivar::<usize>(ACTION_CALLBACK_PTR);
-        decl.add_method(sel!(perform:), perform::<F> as extern fn (&mut Object, _, id));
-        
-        VIEW_CLASS = decl.register();
-    });
-
-    unsafe { VIEW_CLASS }
+        load_or_register_class_for::<F, _>(superclass, "RSTTargetActionHandler", |decl| {
+            decl.add_ivar::<usize>(ACTION_CALLBACK_PTR);
+            decl.add_method(sel!(perform:), perform::<F> as extern fn (&mut Object, _, id));
+        })
+    }
 }
@@ -0,0 +1,138 @@
+//! Types for adaptive layouts: size classes, which describe roughly how much room there is along
+//! each axis, interface orientations, and the user's preferred text size.
+//!
+//! Size classes are the thing to switch layouts on - an iPad app in Split View can be compact
+//! while the device is in landscape - so prefer them to orientation where you can.
+
+use crate::foundation::{id, nil, NSInteger, NSUInteger, NSString};
+
+/// How much room there is along an axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SizeClass {
+    /// Not known yet (e.g, the view controller isn't on screen).
+    Unspecified,
+
+    /// Constrained space, e.g the width of an iPhone in portrait.
+    Compact,
+
+    /// Expansive space, e.g the width of a full-screen iPad.
+    Regular
+}
+
+impl From<NSInteger> for SizeClass {
+    fn from(size_class: NSInteger) -> Self {
+        match size_class {
+            1 => SizeClass::Compact,
+            2 => SizeClass::Regular,
+            _ => SizeClass::Unspecified
+        }
+    }
+}
+
+/// An orientation the interface can be shown in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InterfaceOrientation {
+    /// Upright, with the home button (or indicator) at the bottom.
+    Portrait,
+
+    /// Upside down.
+    PortraitUpsideDown,
+
+    /// On its side, with the home button (or indicator) on the left.
+    LandscapeLeft,
+
+    /// On its side, with the home button (or indicator) on the right.
+    LandscapeRight
+}
+
+impl InterfaceOrientation {
+    /// Combines `orientations` into a `UIInterfaceOrientationMask`.
+    pub(crate) fn mask(orientations: &[InterfaceOrientation]) -> NSUInteger {
+        orientations.iter().fold(0, |mask, orientation| mask | match orientation {
+            InterfaceOrientation::Portrait => 1 << 1,
+            InterfaceOrientation::PortraitUpsideDown => 1 << 2,
+            InterfaceOrientation::LandscapeLeft => 1 << 4,
+            InterfaceOrientation::LandscapeRight => 1 << 3
+        })
+    }
+}
+
+/// The user's preferred text size, from Settings (or Control Center).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ContentSizeCategory {
+    /// Not known (e.g, the view controller isn't on screen).
+    Unspecified,
+
+    /// The smallest size.
+    ExtraSmall,
+
+    /// Small.
+    Small,
+
+    /// Medium.
+    Medium,
+
+    /// Large. This is the default.
+    Large,
+
+    /// Extra large.
+    ExtraLarge,
+
+    /// Extra extra large.
+    ExtraExtraLarge,
+
+    /// The largest of the standard sizes.
+    ExtraExtraExtraLarge,
+
+    /// The smallest of the larger sizes from the Accessibility settings. Layouts often need to
+    /// change at these sizes (e.g, stacking things vertically that would otherwise sit side by
+    /// side).
+    AccessibilityMedium,
+
+    /// Accessibility large.
+    AccessibilityLarge,
+
+    /// Accessibility extra large.
+    AccessibilityExtraLarge,
+
+    /// Accessibility extra extra large.
+    AccessibilityExtraExtraLarge,
+
+    /// The largest size.
+    AccessibilityExtraExtraExtraLarge
+}
+
+impl ContentSizeCategory {
+    /// Returns whether this is one of the Accessibility sizes.
+    pub fn is_accessibility_category(&self) -> bool {
+        match self {
+            ContentSizeCategory::AccessibilityMedium | ContentSizeCategory::AccessibilityLarge |
+            ContentSizeCategory::AccessibilityExtraLarge | ContentSizeCategory::AccessibilityExtraExtraLarge |
+            ContentSizeCategory::AccessibilityExtraExtraExtraLarge => true,
+            _ => false
+        }
+    }
+
+    /// Reads a `UIContentSizeCategory`.
+    pub(crate) fn from_category(category: id) -> Self {
+        if category == nil {
+            return ContentSizeCategory::Unspecified;
+        }
+
+        match NSString::wrap(category).to_str() {
+            "UICTContentSizeCategoryXS" => ContentSizeCategory::ExtraSmall,
+            "UICTContentSizeCategoryS" => ContentSizeCategory::Small,
+            "UICTContentSizeCategoryM" => ContentSizeCategory::Medium,
+            "UICTContentSizeCategoryL" => ContentSizeCategory::Large,
+            "UICTContentSizeCategoryXL" => ContentSizeCategory::ExtraLarge,
+            "UICTContentSizeCategoryXXL" => ContentSizeCategory::ExtraExtraLarge,
+            "UICTContentSizeCategoryXXXL" => ContentSizeCategory::ExtraExtraExtraLarge,
+            "UICTContentSizeCategoryAccessibilityM" => ContentSizeCategory::AccessibilityMedium,
+            "UICTContentSizeCategoryAccessibilityL" => ContentSizeCategory::AccessibilityLarge,
+            "UICTContentSizeCategoryAccessibilityXL" => ContentSizeCategory::AccessibilityExtraLarge,
+            "UICTContentSizeCategoryAccessibilityXXL" => ContentSizeCategory::AccessibilityExtraExtraLarge,
+            "UICTContentSizeCategoryAccessibilityXXXL" => ContentSizeCategory::AccessibilityExtraExtraExtraLarge,
+            _ => ContentSizeCategory::Unspecified
+        }
+    }
+}
@@ -9,7 +9,6 @@ use std::sync::Once;
 //use block::Block;
 
 use objc::{class, msg_send, sel, sel_impl};
-use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel};
 
 use url::Url;
@@ -21,8 +20,12 @@ use crate::foundation::{id, nil, BOOL, YES, NO, NSUInteger, NSArray, NSString};
 use crate::ios::app::{AppDelegate, APP_DELEGATE};
 use crate::ios::scene::{SceneConfig, SceneConnectionOptions, SceneSession};
 
+#[cfg(feature = "networking")]
+use crate::networking::store_background_events_completion_handler;
+
 #[cfg(feature = "cloudkit")]
 use crate::cloudkit::share::CKShareMetaData;
+use crate::utils::load_or_register_class;
 
 /// A handy method for grabbing our `AppDelegate` from the pointer. This is different from our
 /// standard `utils` version as this doesn't require `RefCell` backing.
@@ -47,6 +50,17 @@ extern fn configuration_for_scene_session<T: AppDelegate>(this: &Object, _: Sel,
     ).into_inner()
 }
 
+/// Fires when the system hands us events for a background `NSURLSession`. We hold on to the
+/// completion handler until the session's delegate has received them all.
+extern fn handle_events_for_background_url_session<T: AppDelegate>(this: &Object, _: Sel, _: id, identifier: id, completion: id) {
+    let identifier = NSString::wrap(identifier).to_string();
+
+    #[cfg(feature = "networking")]
+    store_background_events_completion_handler(identifier.clone(), completion);
+
+    app::<T>(this).handle_events_for_background_url_session(&identifier);
+}
+
 /// Registers an `NSObject` application delegate, and configures it for the various callbacks and
 /// pointers we need to have.
 pub(crate) fn register_app_delegate_class<T: AppDelegate>() -> *const Class {
@@ -55,25 +69,29 @@ pub(crate) fn register_app_delegate_class<T: AppDelegate>() -> *const Class {
 
     INIT.call_once(|| unsafe {
         let superclass = class!(NSObject);
-        let mut decl = ClassDecl::new("RSTAppDelegate", superclass).unwrap();
-
-        // Launching Applications
-        decl.add_method(
-            sel!(application:didFinishLaunchingWithOptions:),
-            did_finish_launching::<T> as extern fn(&Object, _, _, id) -> BOOL
-        );
-
-        // Scenes
-        decl.add_method(
-            sel!(application:configurationForConnectingSceneSession:options:),
-            configuration_for_scene_session::<T> as extern fn(&Object, _, _, id, id) -> id
-        );
-        /*decl.add_method(
-            sel!(application:didDiscardSceneSessions:),
-            did_discard_scene_sessions::<T> as extern fn(&Object, _, _, id)
-        );*/
-        
-        DELEGATE_CLASS = decl.register();
+        DELEGATE_CLASS = load_or_register_class(superclass, "RSTAppDelegate", |decl| {
+            // Launching Applications
+            decl.add_method(
+                sel!(application:didFinishLaunchingWithOptions:),
+                did_finish_launching::<T> as extern fn(&Object, _, _, id) -> BOOL
+            );
+
+            // Scenes
+            decl.add_method(
+                sel!(application:configurationForConnectingSceneSession:options:),
+                configuration_for_scene_session::<T> as extern fn(&Object, _, _, id, id) -> id
+            );
+            // Background Transfers
+            decl.add_method(
+                sel!(application:handleEventsForBackgroundURLSession:completionHandler:),
+                handle_events_for_background_url_session::<T> as extern fn(&Object, _, _, id, id)
+            );
+
+            /*decl.add_method(
+                sel!(application:didDiscardSceneSessions:),
+                did_discard_scene_sessions::<T> as extern fn(&Object, _, _, id)
+            );*/
+        });
     });
 
     unsafe {
@@ -36,13 +36,18 @@
 
 use libc::{c_char, c_int};
 use std::ffi::CString;
+use std::future::Future;
+
+use block::ConcreteBlock;
 
 use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::{id, nil, YES, NO, NSString, NSUInteger, AutoReleasePool};
-use crate::ios::scene::{WindowSceneDelegate, register_window_scene_delegate_class};
+use crate::error::Error;
+use crate::foundation::{id, nil, YES, NO, BOOL, NSInteger, NSString, NSUInteger, AutoReleasePool};
+use crate::ios::scene::{SceneSession, WindowSceneDelegate, register_window_scene_delegate_class};
 use crate::notification_center::Dispatcher;
+use crate::user_activity::UserActivity;
 use crate::utils::activate_cocoa_multithreading;
 
 mod class;
@@ -141,6 +146,15 @@ where
     }
 } 
 
+impl<T, W, F> App<T, W, F> where T: AppDelegate + Dispatcher + 'static, T::Message: 'static {
+    /// Registers the app delegate as the receiver for `App::dispatch()`, so that any widget
+    /// callback can send it a message (which it gets in `Dispatcher::on_message()`).
+    pub fn with_dispatcher(self) -> Self {
+        crate::notification_center::router::register(&*self.delegate);
+        self
+    }
+}
+
 impl<T, W, F> App<T, W, F> {  
     /// Handles calling through to `UIApplicationMain()`, ensuring that it's using our custom
     /// `UIApplication` and `UIApplicationDelegate` classes.
@@ -159,3 +173,106 @@ impl<T, W, F> App<T, W, F> {
     }
 }
 
+
+impl App {
+    /// Sends `message` to the app delegate's `Dispatcher::on_message()`, on the main thread. This
+    /// can be called from anywhere; off the main thread, the message is delivered asynchronously.
+    ///
+    /// Panics if `with_dispatcher()` wasn't called, or the delegate takes a different message
+    /// type.
+    pub fn dispatch<M: Send + 'static>(message: M) {
+        crate::notification_center::router::route(message);
+    }
+
+    /// Asks the system to activate a scene: the one for `session` if given (e.g, to bring an
+    /// existing window forward), or a new one otherwise - which, on iPad, opens a new window.
+    /// `activity` is handed to the scene's `WindowSceneDelegate::will_connect()`, via
+    /// `SceneConnectionOptions::user_activities()`.
+    pub fn request_scene_activation(session: Option<&SceneSession>, activity: Option<&UserActivity>) {
+        let session: id = match session {
+            Some(session) => &*session.0 as *const Object as id,
+            None => nil
+        };
+
+        let activity: id = match activity {
+            Some(activity) => &*activity.inner as *const Object as id,
+            None => nil
+        };
+
+        shared_application(|app| unsafe {
+            let _: () = msg_send![app, requestSceneSessionActivation:session
+                userActivity:activity
+                options:nil
+                errorHandler:nil];
+        });
+    }
+
+    /// Asks the system to close the scene for `session` (on iPad, its window). Its
+    /// `WindowSceneDelegate::did_disconnect()` is called as it goes.
+    pub fn request_scene_destruction(session: &SceneSession) {
+        shared_application(|app| unsafe {
+            let _: () = msg_send![app, requestSceneSessionDestruction:&*session.0
+                options:nil
+                errorHandler:nil];
+        });
+    }
+
+    /// Sets the number shown in a badge on the app's icon, e.g for unread items. `0` removes the
+    /// badge. The badge only shows if the user has allowed badges, which you ask for alongside
+    /// notification permissions (`NotificationAuthOption::Badge`).
+    pub fn set_badge_count(count: usize) {
+        shared_application(|app| unsafe {
+            let _: () = msg_send![app, setApplicationIconBadgeNumber:count as NSInteger];
+        });
+    }
+
+    /// Returns whether the app can switch its icon, which needs alternate icons declared under
+    /// `CFBundleIcons` in your `Info.plist`.
+    pub fn supports_alternate_icons() -> bool {
+        let app: id = unsafe { msg_send![register_app_class(), sharedApplication] };
+        let supports: BOOL = unsafe { msg_send![app, supportsAlternateIcons] };
+        supports == YES
+    }
+
+    /// Returns the name of the alternate icon in use, or `None` for the primary icon.
+    pub fn alternate_icon() -> Option<String> {
+        let app: id = unsafe { msg_send![register_app_class(), sharedApplication] };
+        let name: id = unsafe { msg_send![app, alternateIconName] };
+
+        match name == nil {
+            true => None,
+            false => Some(NSString::wrap(name).to_string())
+        }
+    }
+
+    /// Switches the app's icon to the alternate icon called `name` (as declared in your
+    /// `Info.plist`), or back to the primary icon for `None`. The system tells the user about the
+    /// change; `completion` is called (on the main thread) once it's done, or with why it failed.
+    pub fn set_alternate_icon<F>(name: Option<&str>, completion: F)
+    where
+        F: Fn(Result<(), Error>) + 'static
+    {
+        let name: id = match name {
+            Some(name) => NSString::new(name).into_inner(),
+            None => nil
+        };
+
+        let handler = ConcreteBlock::new(move |error: id| {
+            completion(match error == nil {
+                true => Ok(()),
+                false => Err(Error::new(error))
+            });
+        });
+        let handler = handler.copy();
+
+        shared_application(|app| unsafe {
+            let _: () = msg_send![app, setAlternateIconName:name completionHandler:&*handler];
+        });
+    }
+
+    /// Spawns `future` onto the main run loop, so it can update UI as it goes. Must be called
+    /// from the main thread. See `cacao::executor` for details.
+    pub fn spawn<F: Future<Output = ()> + 'static>(future: F) {
+        crate::executor::spawn(future);
+    }
+}
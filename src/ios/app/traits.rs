@@ -21,4 +21,9 @@ pub trait AppDelegate {
     fn did_finish_launching(&self) {}
 
     fn config_for_scene_session(&self, session: SceneSession, options: SceneConnectionOptions) -> SceneConfig;
+
+    /// Fired when the system relaunches (or wakes) your app to deliver events for a background
+    /// `URLSession`. Recreate the session with this identifier, and its `DownloadDelegate` will
+    /// receive the events; the system is told you're done once they've all been delivered.
+    fn handle_events_for_background_url_session(&self, _identifier: &str) {}
 }
@@ -0,0 +1,229 @@
+//! Wraps `BGTaskScheduler`, which lets your app do work (e.g, syncing) while it's in the
+//! background: the system launches (or wakes) it when conditions suit, and runs the handler you
+//! registered for the task.
+//!
+//! Two kinds of task are available. App refresh tasks are short (around 30 seconds), and are for
+//! fetching small amounts of content; processing tasks can run for minutes, and usually run while
+//! the device is idle (and, optionally, charging).
+//!
+//! Each task identifier has to be listed under `BGTaskSchedulerPermittedIdentifiers` in your
+//! `Info.plist`, and the `fetch` (for app refresh) or `processing` background modes enabled under
+//! `UIBackgroundModes`.
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use cacao::ios::{BackgroundTaskRequest, BackgroundTaskScheduler};
+//!
+//! // While launching - before `did_finish_launching()` returns:
+//! BackgroundTaskScheduler::register("com.example.refresh", |task| {
+//!     task.set_expiration_handler(|| {
+//!         // Out of time; stop what you're doing.
+//!     });
+//!
+//!     // Sync...
+//!     task.complete(true);
+//! });
+//!
+//! // Later, e.g when the app enters the background:
+//! let request = BackgroundTaskRequest::app_refresh("com.example.refresh");
+//! request.set_earliest_begin(Duration::from_secs(15 * 60));
+//! BackgroundTaskScheduler::submit(&request).ok();
+//! ```
+
+use std::fmt;
+use std::time::Duration;
+
+use block::ConcreteBlock;
+use ::dispatch::ffi::dispatch_get_main_queue;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::error::Error;
+use crate::foundation::{id, nil, YES, NO, BOOL, NSString};
+
+/// A background task the system is running, handed to the handler you registered for it.
+pub struct BackgroundTask {
+    /// The underlying `BGTask`.
+    pub objc: ShareId<Object>
+}
+
+impl BackgroundTask {
+    /// Returns the task's identifier.
+    pub fn identifier(&self) -> String {
+        NSString::wrap(unsafe { msg_send![&*self.objc, identifier] }).to_string()
+    }
+
+    /// Sets a handler to be called (on the main thread) if the system needs the task to stop
+    /// before it's completed. Wrap up quickly, then call `complete()`.
+    pub fn set_expiration_handler<F: Fn() + 'static>(&self, handler: F) {
+        let handler = ConcreteBlock::new(move || handler());
+        let handler = handler.copy();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setExpirationHandler:&*handler];
+        }
+    }
+
+    /// Tells the system the task is done, and whether it succeeded. This must be called - the
+    /// system stops launching your app for background work if tasks are left hanging.
+    pub fn complete(&self, success: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTaskCompletedWithSuccess:match success {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+}
+
+impl fmt::Debug for BackgroundTask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BackgroundTask")
+            .field("identifier", &self.identifier())
+            .finish()
+    }
+}
+
+/// A request to run a background task, once conditions allow.
+#[derive(Debug)]
+pub struct BackgroundTaskRequest {
+    /// The underlying `BGAppRefreshTaskRequest` or `BGProcessingTaskRequest`.
+    pub objc: ShareId<Object>,
+
+    processing: bool
+}
+
+impl BackgroundTaskRequest {
+    /// Creates a request for a short app refresh task.
+    pub fn app_refresh(identifier: &str) -> Self {
+        let identifier = NSString::new(identifier);
+
+        BackgroundTaskRequest {
+            objc: unsafe {
+                let request: id = msg_send![class!(BGAppRefreshTaskRequest), alloc];
+                let request: id = msg_send![request, initWithIdentifier:identifier];
+                ShareId::from_retained_ptr(request)
+            },
+
+            processing: false
+        }
+    }
+
+    /// Creates a request for a longer processing task.
+    pub fn processing(identifier: &str) -> Self {
+        let identifier = NSString::new(identifier);
+
+        BackgroundTaskRequest {
+            objc: unsafe {
+                let request: id = msg_send![class!(BGProcessingTaskRequest), alloc];
+                let request: id = msg_send![request, initWithIdentifier:identifier];
+                ShareId::from_retained_ptr(request)
+            },
+
+            processing: true
+        }
+    }
+
+    /// Sets how long from now the task should start, at the earliest. The system decides when it
+    /// actually runs, which may be a good while later.
+    pub fn set_earliest_begin(&self, delay: Duration) {
+        unsafe {
+            let date: id = msg_send![class!(NSDate), dateWithTimeIntervalSinceNow:delay.as_secs_f64()];
+            let _: () = msg_send![&*self.objc, setEarliestBeginDate:date];
+        }
+    }
+
+    /// Sets whether a processing task needs a network connection. Does nothing for app refresh
+    /// tasks.
+    pub fn set_requires_network(&self, requires: bool) {
+        if !self.processing {
+            return;
+        }
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setRequiresNetworkConnectivity:match requires {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether a processing task only runs while the device is charging. This lets it use
+    /// more energy (e.g, for heavy work like reindexing). Does nothing for app refresh tasks.
+    pub fn set_requires_external_power(&self, requires: bool) {
+        if !self.processing {
+            return;
+        }
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setRequiresExternalPower:match requires {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+}
+
+/// Registers handlers for, and schedules, background tasks.
+#[derive(Debug)]
+pub struct BackgroundTaskScheduler;
+
+impl BackgroundTaskScheduler {
+    /// Registers `handler` to run (on the main thread) whenever the system runs the task with the
+    /// given identifier. This has to be done for every identifier while the app is launching -
+    /// before `AppDelegate::did_finish_launching()` returns. Returns `false` if the identifier
+    /// couldn't be registered (e.g, it isn't listed in your `Info.plist`).
+    pub fn register<F: Fn(BackgroundTask) + 'static>(identifier: &str, handler: F) -> bool {
+        let identifier = NSString::new(identifier);
+
+        let handler = ConcreteBlock::new(move |task: id| {
+            handler(BackgroundTask {
+                objc: unsafe { ShareId::from_ptr(task) }
+            });
+        });
+        let handler = handler.copy();
+
+        let registered: BOOL = unsafe {
+            let scheduler: id = msg_send![class!(BGTaskScheduler), sharedScheduler];
+            let queue = dispatch_get_main_queue() as id;
+            msg_send![scheduler, registerForTaskWithIdentifier:identifier usingQueue:queue launchHandler:&*handler]
+        };
+
+        registered == YES
+    }
+
+    /// Submits `request`, replacing any pending request for the same task.
+    pub fn submit(request: &BackgroundTaskRequest) -> Result<(), Error> {
+        let mut error: id = nil;
+
+        let submitted: BOOL = unsafe {
+            let scheduler: id = msg_send![class!(BGTaskScheduler), sharedScheduler];
+            msg_send![scheduler, submitTaskRequest:&*request.objc error:&mut error]
+        };
+
+        match submitted {
+            YES => Ok(()),
+            _ => Err(Error::new(error))
+        }
+    }
+
+    /// Cancels the pending request for the task with the given identifier, if there is one.
+    pub fn cancel(identifier: &str) {
+        let identifier = NSString::new(identifier);
+
+        unsafe {
+            let scheduler: id = msg_send![class!(BGTaskScheduler), sharedScheduler];
+            let _: () = msg_send![scheduler, cancelTaskRequestWithIdentifier:identifier];
+        }
+    }
+
+    /// Cancels every pending request.
+    pub fn cancel_all() {
+        unsafe {
+            let scheduler: id = msg_send![class!(BGTaskScheduler), sharedScheduler];
+            let _: () = msg_send![scheduler, cancelAllTaskRequests];
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! Handles the Objective-C functionality for the `DocumentPicker` module.
+
+use std::sync::Once;
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, sel, sel_impl};
+
+use crate::foundation::{id, NSArray};
+use crate::ios::document_picker::{DOCUMENT_PICKER_PTR, PickerHandler, SecurityScopedUrl};
+use crate::utils::load_or_register_class;
+
+/// Called when the user has picked documents. We wrap the URLs up as `SecurityScopedUrl`'s and
+/// pass them back to the Rust side.
+extern fn did_pick_documents(this: &Object, _: Sel, _: id, urls: id) {
+    let handler = load_or_return!(PickerHandler, this, DOCUMENT_PICKER_PTR);
+
+    let urls = NSArray::wrap(urls).map(|url| {
+        SecurityScopedUrl::new(url)
+    });
+
+    (handler.0)(urls);
+}
+
+/// Called when the user cancelled the picker. We pass back an empty `Vec`.
+extern fn was_cancelled(this: &Object, _: Sel, _: id) {
+    let handler = load_or_return!(PickerHandler, this, DOCUMENT_PICKER_PTR);
+    (handler.0)(Vec::new());
+}
+
+/// Registers an `NSObject` subclass that acts as a `UIDocumentPickerDelegate`, and routes the
+/// callbacks back to Rust.
+pub(crate) fn register_document_picker_delegate_class() -> *const Class {
+    static mut DELEGATE_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSObject);
+        DELEGATE_CLASS = load_or_register_class(superclass, "RSTDocumentPickerDelegate", |decl| {
+            decl.add_ivar::<usize>(DOCUMENT_PICKER_PTR);
+
+            decl.add_method(sel!(documentPicker:didPickDocumentsAtURLs:), did_pick_documents as extern fn(&Object, _, _, _));
+            decl.add_method(sel!(documentPickerWasCancelled:), was_cancelled as extern fn(&Object, _, _));
+        });
+    });
+
+    unsafe { DELEGATE_CLASS }
+}
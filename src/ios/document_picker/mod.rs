@@ -0,0 +1,235 @@
+//! Wraps `UIDocumentPickerViewController`, which enables your iOS application to open (or export)
+//! files that live outside of its sandbox - e.g, in iCloud Drive or another app's storage.
+//!
+//! Files picked this way are security-scoped: you can only access them while you hold the
+//! `SecurityScopedUrl` you're handed in the callback. Once it drops, access is relinquished.
+//!
+//! ```rust,no_run
+//! use cacao::ios::DocumentPicker;
+//! use cacao::uti::UTType;
+//! use cacao::view::ViewController;
+//!
+//! # fn example<T>(controller: &ViewController<T>) {
+//! let mut picker = DocumentPicker::open(&[UTType::PLAIN_TEXT]);
+//! picker.set_allows_multiple_selection(true);
+//! picker.show(controller, |urls| {
+//!     for url in urls {
+//!         println!("{:?}", url.path());
+//!     }
+//! });
+//! # }
+//! ```
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use objc::{class, msg_send, sel, sel_impl};
+use objc::runtime::Object;
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, YES, NO, BOOL, NSArray, NSString, NSUInteger};
+use crate::utils::Controller;
+use crate::uti::UTType;
+
+mod class;
+use class::register_document_picker_delegate_class;
+
+pub(crate) static DOCUMENT_PICKER_PTR: &str = "rstDocumentPickerPtr";
+
+/// Corresponds to `UIDocumentPickerModeImport`.
+const MODE_IMPORT: NSUInteger = 0;
+
+/// Corresponds to `UIDocumentPickerModeOpen`.
+const MODE_OPEN: NSUInteger = 1;
+
+/// Holds the callback for a `DocumentPicker`. This exists to give us a concrete (sized) type to
+/// point to from the Objective-C side.
+pub(crate) struct PickerHandler(pub Box<dyn Fn(Vec<SecurityScopedUrl>) + 'static>);
+
+/// A URL handed back from a `DocumentPicker`. Upon creation, this starts accessing the
+/// security-scoped resource; when it's dropped, access is relinquished. Hold onto this for as long
+/// as you need to read or write the file.
+pub struct SecurityScopedUrl {
+    /// The underlying `NSURL`.
+    pub objc: ShareId<Object>,
+
+    accessing: bool
+}
+
+impl SecurityScopedUrl {
+    /// Wraps an `NSURL` and begins accessing it.
+    pub(crate) fn new(url: id) -> Self {
+        let accessing: BOOL = unsafe {
+            msg_send![url, startAccessingSecurityScopedResource]
+        };
+
+        SecurityScopedUrl {
+            objc: unsafe { ShareId::from_ptr(url) },
+            accessing: match accessing {
+                YES => true,
+                NO => false,
+                _ => unreachable!()
+            }
+        }
+    }
+
+    /// Returns the file path for this URL.
+    pub fn path(&self) -> PathBuf {
+        let path = NSString::wrap(unsafe {
+            msg_send![&*self.objc, path]
+        });
+
+        PathBuf::from(path.to_str())
+    }
+
+    /// Whether access to the security-scoped resource was granted. If this is `false`, the URL
+    /// may still be usable if it's inside your sandbox (e.g, an imported copy).
+    pub fn is_accessing(&self) -> bool {
+        self.accessing
+    }
+}
+
+impl fmt::Debug for SecurityScopedUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecurityScopedUrl")
+            .field("path", &self.path())
+            .field("accessing", &self.accessing)
+            .finish()
+    }
+}
+
+impl Drop for SecurityScopedUrl {
+    /// Relinquishes access to the security-scoped resource, if we had it.
+    fn drop(&mut self) {
+        if self.accessing {
+            unsafe {
+                let _: () = msg_send![&*self.objc, stopAccessingSecurityScopedResource];
+            }
+        }
+    }
+}
+
+/// A wrapper for `UIDocumentPickerViewController`. Holds (retains) the picker, along with the
+/// delegate and callback, so keep this around until the user has finished picking.
+pub struct DocumentPicker {
+    /// The underlying `UIDocumentPickerViewController`.
+    pub objc: ShareId<Object>,
+
+    delegate: ShareId<Object>,
+    handler: Option<Box<PickerHandler>>
+}
+
+impl DocumentPicker {
+    /// Internal method for initializing a picker with the given content types and mode.
+    fn with_types(content_types: &[UTType], mode: NSUInteger) -> Self {
+        let types: NSArray = content_types.iter().map(|t| {
+            NSString::new(t.identifier()).into_inner()
+        }).collect::<Vec<id>>().into();
+
+        DocumentPicker {
+            objc: unsafe {
+                let alloc: id = msg_send![class!(UIDocumentPickerViewController), alloc];
+                let picker: id = msg_send![alloc, initWithDocumentTypes:types.into_inner() inMode:mode];
+                ShareId::from_ptr(picker)
+            },
+
+            delegate: unsafe {
+                ShareId::from_ptr(msg_send![register_document_picker_delegate_class(), new])
+            },
+
+            handler: None
+        }
+    }
+
+    /// Creates a picker for opening documents in place, filtered to the given content types.
+    pub fn open(content_types: &[UTType]) -> Self {
+        DocumentPicker::with_types(content_types, MODE_OPEN)
+    }
+
+    /// Creates a picker that imports a copy of the picked documents into your sandbox, filtered
+    /// to the given content types.
+    pub fn import(content_types: &[UTType]) -> Self {
+        DocumentPicker::with_types(content_types, MODE_IMPORT)
+    }
+
+    /// Creates a picker that lets the user choose where to export the files at the given paths.
+    pub fn export<P: AsRef<Path>>(paths: &[P]) -> Self {
+        let urls: NSArray = paths.iter().map(|path| {
+            let path = NSString::new(&path.as_ref().to_string_lossy());
+            unsafe { msg_send![class!(NSURL), fileURLWithPath:path] }
+        }).collect::<Vec<id>>().into();
+
+        DocumentPicker {
+            objc: unsafe {
+                let alloc: id = msg_send![class!(UIDocumentPickerViewController), alloc];
+                let picker: id = msg_send![alloc, initForExportingURLs:urls.into_inner()];
+                ShareId::from_ptr(picker)
+            },
+
+            delegate: unsafe {
+                ShareId::from_ptr(msg_send![register_document_picker_delegate_class(), new])
+            },
+
+            handler: None
+        }
+    }
+
+    /// Sets whether the user can pick more than one document.
+    pub fn set_allows_multiple_selection(&mut self, allows: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAllowsMultipleSelection:match allows {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets the directory the picker starts in.
+    pub fn set_directory<P: AsRef<Path>>(&mut self, path: P) {
+        let path = NSString::new(&path.as_ref().to_string_lossy());
+
+        unsafe {
+            let url: id = msg_send![class!(NSURL), fileURLWithPath:path isDirectory:YES];
+            let _: () = msg_send![&*self.objc, setDirectoryURL:url];
+        }
+    }
+
+    /// Presents the picker from the given controller. `handler` is called with the picked URLs
+    /// once the user is done, or with an empty `Vec` if they cancelled.
+    pub fn show<VC, F>(&mut self, controller: &VC, handler: F)
+    where
+        VC: Controller,
+        F: Fn(Vec<SecurityScopedUrl>) + 'static
+    {
+        let handler = Box::new(PickerHandler(Box::new(handler)));
+        let backing_node = controller.get_backing_node();
+
+        unsafe {
+            let ptr: *const PickerHandler = &*handler;
+            let delegate = &*self.delegate as *const Object as *mut Object;
+            (&mut *delegate).set_ivar(DOCUMENT_PICKER_PTR, ptr as usize);
+
+            let _: () = msg_send![&*self.objc, setDelegate:&*self.delegate];
+            let _: () = msg_send![&*backing_node, presentViewController:&*self.objc animated:YES completion:nil];
+        }
+
+        self.handler = Some(handler);
+    }
+}
+
+impl fmt::Debug for DocumentPicker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DocumentPicker")
+            .field("objc", &self.objc)
+            .finish()
+    }
+}
+
+impl Drop for DocumentPicker {
+    /// Breaks the delegate link, as the delegate (and callback) are going away.
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setDelegate:nil];
+        }
+    }
+}
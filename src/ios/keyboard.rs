@@ -0,0 +1,185 @@
+//! Observes the software keyboard showing and hiding, so that you can keep content (e.g, the field
+//! being edited) from ending up behind it.
+//!
+//! ```rust,no_run
+//! use cacao::ios::KeyboardObserver;
+//! use cacao::scrollview::ScrollView;
+//!
+//! let form = ScrollView::new();
+//!
+//! // Keep hold of this for as long as the form is on screen.
+//! let keyboard = KeyboardObserver::adjusting(&form);
+//! ```
+
+use std::cell::Cell;
+use std::fmt;
+use std::rc::Rc;
+
+use block::ConcreteBlock;
+use core_graphics::geometry::CGRect;
+
+use objc::{class, msg_send, sel, sel_impl};
+use objc::runtime::Object;
+
+use crate::foundation::{id, nil, NO, BOOL, NSInteger, NSString};
+use crate::geometry::{EdgeInsets, Rect};
+use crate::notification_center::NotificationObserver;
+use crate::scrollview::ScrollView;
+
+static WILL_SHOW: &str = "UIKeyboardWillShowNotification";
+static WILL_HIDE: &str = "UIKeyboardWillHideNotification";
+
+/// Where the keyboard is headed, and how it's getting there.
+#[derive(Clone, Copy, Debug)]
+pub struct KeyboardInfo {
+    /// The keyboard's frame once it's done animating, in screen coordinates.
+    pub frame: Rect,
+
+    /// How long the keyboard takes to animate, in seconds. Animate your own changes over the same
+    /// duration to keep in step.
+    pub animation_duration: f64,
+
+    /// The `UIViewAnimationCurve` the keyboard animates with.
+    pub animation_curve: NSInteger,
+
+    /// Whether the keyboard belongs to this app (rather than another app, in multitasking).
+    pub is_local: bool
+}
+
+impl KeyboardInfo {
+    /// Reads the info out of a keyboard notification's `userInfo`.
+    fn from_notification(notification: id) -> Self {
+        unsafe {
+            let user_info: id = msg_send![notification, userInfo];
+            let value = |key: &str| -> id {
+                let key = NSString::new(key);
+                msg_send![user_info, objectForKey:key]
+            };
+
+            let frame: CGRect = msg_send![value("UIKeyboardFrameEndUserInfoKey"), CGRectValue];
+            let duration: f64 = msg_send![value("UIKeyboardAnimationDurationUserInfoKey"), doubleValue];
+            let curve: NSInteger = msg_send![value("UIKeyboardAnimationCurveUserInfoKey"), integerValue];
+
+            let is_local = value("UIKeyboardIsLocalUserInfoKey");
+            let is_local: BOOL = match is_local == nil {
+                true => NO,
+                false => msg_send![is_local, boolValue]
+            };
+
+            KeyboardInfo {
+                frame: frame.into(),
+                animation_duration: duration,
+                animation_curve: curve,
+                is_local: is_local != NO
+            }
+        }
+    }
+}
+
+/// What the keyboard is about to do.
+#[derive(Clone, Copy, Debug)]
+pub enum KeyboardEvent {
+    /// The keyboard is about to show (or change size, while showing).
+    WillShow(KeyboardInfo),
+
+    /// The keyboard is about to hide.
+    WillHide(KeyboardInfo)
+}
+
+/// Calls you back as the keyboard shows and hides. Callbacks stop when this is dropped.
+pub struct KeyboardObserver {
+    observer: NotificationObserver
+}
+
+impl KeyboardObserver {
+    /// Calls `handler` whenever the keyboard is about to show or hide.
+    pub fn new<F: Fn(KeyboardEvent) + 'static>(handler: F) -> Self {
+        let handler = Rc::new(handler);
+
+        let observer = unsafe {
+            let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+
+            NotificationObserver::new(center, &[WILL_SHOW, WILL_HIDE], move |name, notification| {
+                let info = KeyboardInfo::from_notification(notification);
+
+                handler(match name == WILL_SHOW {
+                    true => KeyboardEvent::WillShow(info),
+                    false => KeyboardEvent::WillHide(info)
+                });
+            })
+        };
+
+        KeyboardObserver {
+            observer: observer
+        }
+    }
+
+    /// Insets the bottom of `scroll_view`'s content (and scroll indicator) by however much the
+    /// keyboard covers it, in step with the keyboard's animation - and puts it back when the
+    /// keyboard hides. Any bottom inset you'd set beforehand is added to.
+    pub fn adjusting<T>(scroll_view: &ScrollView<T>) -> Self {
+        let scroll_view = scroll_view.clone_as_handle();
+        let base_inset: Rc<Cell<Option<f64>>> = Rc::new(Cell::new(None));
+
+        KeyboardObserver::new(move |event| {
+            let view = &*scroll_view.objc;
+
+            unsafe {
+                let mut insets: EdgeInsets = msg_send![view, contentInset];
+                let base = base_inset.get().unwrap_or(insets.bottom);
+
+                let info = match event {
+                    KeyboardEvent::WillShow(info) => {
+                        base_inset.set(Some(base));
+                        insets.bottom = base + keyboard_overlap(view, &info);
+                        info
+                    },
+
+                    KeyboardEvent::WillHide(info) => {
+                        base_inset.set(None);
+                        insets.bottom = base;
+                        info
+                    }
+                };
+
+                let target = view as *const Object as id;
+                let animations = ConcreteBlock::new(move || {
+                    let _: () = msg_send![target, setContentInset:insets];
+                    let _: () = msg_send![target, setVerticalScrollIndicatorInsets:insets];
+                });
+                let animations = animations.copy();
+
+                let _: () = msg_send![class!(UIView), animateWithDuration:info.animation_duration
+                    animations:&*animations];
+            }
+        })
+    }
+}
+
+impl fmt::Debug for KeyboardObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyboardObserver")
+            .field("observer", &self.observer)
+            .finish()
+    }
+}
+
+/// Returns how much of `view`'s height the keyboard, once shown, covers.
+fn keyboard_overlap(view: &Object, info: &KeyboardInfo) -> f64 {
+    unsafe {
+        let window: id = msg_send![view, window];
+        if window == nil {
+            return 0.;
+        }
+
+        let screen: id = msg_send![window, screen];
+        let coordinate_space: id = msg_send![screen, coordinateSpace];
+
+        let frame: CGRect = info.frame.into();
+        let frame: CGRect = msg_send![view, convertRect:frame fromCoordinateSpace:coordinate_space];
+        let bounds: CGRect = msg_send![view, bounds];
+
+        let overlap = (bounds.origin.y + bounds.size.height) - frame.origin.y;
+        overlap.max(0.) as f64
+    }
+}
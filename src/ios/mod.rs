@@ -4,11 +4,41 @@
 //! differences. With that said, there are certain things that just don't map between the two - for
 //! iOS, these things are contained here.
 
+mod adaptivity;
+pub use adaptivity::{ContentSizeCategory, InterfaceOrientation, SizeClass};
+
 mod app;
 pub use app::*;
 
+#[cfg(feature = "background-tasks")]
+mod background_tasks;
+
+#[cfg(feature = "background-tasks")]
+pub use background_tasks::{BackgroundTask, BackgroundTaskRequest, BackgroundTaskScheduler};
+
+mod document_picker;
+pub use document_picker::{DocumentPicker, SecurityScopedUrl};
+
+mod keyboard;
+pub use keyboard::{KeyboardEvent, KeyboardInfo, KeyboardObserver};
+
+mod navigation;
+pub use navigation::{
+    BarButtonItem, BarButtonItemStyle, LargeTitleDisplayMode, NavigationController, NavigationItem,
+    NavigationItemStyle, SearchController, SystemBarButtonItem
+};
+
+mod printing;
+pub use printing::PrintInteraction;
+
 mod scene;
 pub use scene::*;
 
+mod status_bar;
+pub use status_bar::{ScreenEdge, StatusBarAnimation, StatusBarStyle};
+
+mod tab_bar;
+pub use tab_bar::{TabBarController, TabBarDelegate};
+
 mod window;
 pub use window::*;
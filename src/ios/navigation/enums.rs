@@ -0,0 +1,169 @@
+use crate::foundation::NSInteger;
+
+/// Whether a screen's title is shown in the large style, below the navigation bar.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LargeTitleDisplayMode {
+    /// Follow whatever the previous screen in the stack did.
+    Automatic,
+
+    /// Always show the large title.
+    Always,
+
+    /// Never show the large title.
+    Never
+}
+
+impl From<LargeTitleDisplayMode> for NSInteger {
+    fn from(mode: LargeTitleDisplayMode) -> Self {
+        match mode {
+            LargeTitleDisplayMode::Automatic => 0,
+            LargeTitleDisplayMode::Always => 1,
+            LargeTitleDisplayMode::Never => 2
+        }
+    }
+}
+
+/// How a `BarButtonItem` is drawn.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BarButtonItemStyle {
+    /// The standard style.
+    Plain,
+
+    /// A bolder style, for buttons that complete a task (e.g, "Done").
+    Done
+}
+
+impl From<BarButtonItemStyle> for NSInteger {
+    fn from(style: BarButtonItemStyle) -> Self {
+        match style {
+            BarButtonItemStyle::Plain => 0,
+            BarButtonItemStyle::Done => 2
+        }
+    }
+}
+
+/// The standard buttons UIKit provides, with localized titles (or icons) built in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SystemBarButtonItem {
+    /// "Done".
+    Done,
+
+    /// "Cancel".
+    Cancel,
+
+    /// "Edit".
+    Edit,
+
+    /// "Save".
+    Save,
+
+    /// A plus sign.
+    Add,
+
+    /// A pencil and paper, for writing something new.
+    Compose,
+
+    /// A curved arrow, for replying.
+    Reply,
+
+    /// The share icon.
+    Action,
+
+    /// A folder, for moving things.
+    Organize,
+
+    /// An open book.
+    Bookmarks,
+
+    /// A magnifying glass.
+    Search,
+
+    /// A circular arrow.
+    Refresh,
+
+    /// An X, for stopping a load.
+    Stop,
+
+    /// A camera.
+    Camera,
+
+    /// A trash can.
+    Trash,
+
+    /// A play symbol.
+    Play,
+
+    /// A pause symbol.
+    Pause,
+
+    /// A rewind symbol.
+    Rewind,
+
+    /// A fast forward symbol.
+    FastForward,
+
+    /// "Undo".
+    Undo,
+
+    /// "Redo".
+    Redo,
+
+    /// An X, for closing.
+    Close
+}
+
+impl From<SystemBarButtonItem> for NSInteger {
+    fn from(item: SystemBarButtonItem) -> Self {
+        match item {
+            SystemBarButtonItem::Done => 0,
+            SystemBarButtonItem::Cancel => 1,
+            SystemBarButtonItem::Edit => 2,
+            SystemBarButtonItem::Save => 3,
+            SystemBarButtonItem::Add => 4,
+            SystemBarButtonItem::Compose => 7,
+            SystemBarButtonItem::Reply => 8,
+            SystemBarButtonItem::Action => 9,
+            SystemBarButtonItem::Organize => 10,
+            SystemBarButtonItem::Bookmarks => 11,
+            SystemBarButtonItem::Search => 12,
+            SystemBarButtonItem::Refresh => 13,
+            SystemBarButtonItem::Stop => 14,
+            SystemBarButtonItem::Camera => 15,
+            SystemBarButtonItem::Trash => 16,
+            SystemBarButtonItem::Play => 17,
+            SystemBarButtonItem::Pause => 18,
+            SystemBarButtonItem::Rewind => 19,
+            SystemBarButtonItem::FastForward => 20,
+            SystemBarButtonItem::Undo => 21,
+            SystemBarButtonItem::Redo => 22,
+            SystemBarButtonItem::Close => 24
+        }
+    }
+}
+
+/// How the navigation bar lays out a screen's title and buttons (iOS 16 and up). The browser and
+/// editor styles put the title on the leading side, freeing the center for buttons - which is
+/// how an iPad app gets a Mac-style toolbar.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NavigationItemStyle {
+    /// The standard layout, with the title in the center. This is the default.
+    Navigator,
+
+    /// For apps whose history matters more than their place in a hierarchy (e.g, a web browser):
+    /// the title sits on the leading side, after the back button.
+    Browser,
+
+    /// For document editors: the title sits on the leading side, with the back button as a
+    /// way out of the document.
+    Editor
+}
+
+impl From<NavigationItemStyle> for NSInteger {
+    fn from(style: NavigationItemStyle) -> Self {
+        match style {
+            NavigationItemStyle::Navigator => 0,
+            NavigationItemStyle::Browser => 1,
+            NavigationItemStyle::Editor => 2
+        }
+    }
+}
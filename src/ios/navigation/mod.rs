@@ -0,0 +1,425 @@
+//! Wraps `UINavigationController`, which manages a stack of screens - pushing new ones on as the
+//! user drills down, and popping back (via the back button, or swiping from the edge) as they
+//! return.
+//!
+//! ```rust,no_run
+//! use cacao::ios::{BarButtonItem, BarButtonItemStyle, NavigationController, NavigationItem, SearchController};
+//! use cacao::view::{ViewController, ViewDelegate};
+//!
+//! #[derive(Default)]
+//! struct Inbox;
+//! impl ViewDelegate for Inbox {}
+//!
+//! let inbox = ViewController::new(Inbox::default());
+//! let navigation = NavigationController::new(&inbox);
+//! navigation.set_prefers_large_titles(true);
+//!
+//! let mut compose = BarButtonItem::new("Compose", BarButtonItemStyle::Plain);
+//! compose.set_action(|| println!("Composing..."));
+//!
+//! let item = NavigationItem::for_controller(&inbox);
+//! item.set_title("Inbox");
+//! item.set_right_bar_buttons(&[&compose]);
+//!
+//! let mut search = SearchController::new();
+//! search.set_search_handler(|text| println!("Filtering by {}", text));
+//! item.set_search_controller(&search);
+//! ```
+//!
+//! Only the Objective-C side of a pushed controller is retained by the stack; keep the Rust
+//! `ViewController` (and any `BarButtonItem`s) around for as long as it's in there.
+
+use objc::{class, msg_send, sel, sel_impl};
+use objc::runtime::Object;
+use objc_id::ShareId;
+
+use crate::button::{menu_for, PullDownItem};
+use crate::dispatch::assert_main_thread;
+use crate::foundation::{id, nil, YES, NO, NSArray, NSInteger, NSString, NSUInteger};
+use crate::image::Image;
+use crate::invoker::TargetActionHandler;
+use crate::utils::Controller;
+
+mod enums;
+pub use enums::{BarButtonItemStyle, LargeTitleDisplayMode, NavigationItemStyle, SystemBarButtonItem};
+
+mod search;
+pub use search::SearchController;
+
+/// A wrapper for `UINavigationController`.
+#[derive(Clone, Debug)]
+pub struct NavigationController {
+    /// The underlying `UINavigationController`.
+    pub objc: ShareId<Object>
+}
+
+impl NavigationController {
+    /// Creates a navigation stack, with `root` at the bottom of it.
+    pub fn new<VC: Controller>(root: &VC) -> Self {
+        assert_main_thread("NavigationController::new");
+
+        let root = root.get_backing_node();
+
+        NavigationController {
+            objc: unsafe {
+                let alloc: id = msg_send![class!(UINavigationController), alloc];
+                let controller: id = msg_send![alloc, initWithRootViewController:&*root];
+                ShareId::from_retained_ptr(controller)
+            }
+        }
+    }
+
+    /// Pushes `controller` onto the stack, making it the visible screen.
+    pub fn push<VC: Controller>(&self, controller: &VC, animated: bool) {
+        let controller = controller.get_backing_node();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, pushViewController:&*controller animated:match animated {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Pops the top screen off the stack, returning to the one beneath it. Does nothing if only
+    /// the root is left.
+    pub fn pop(&self, animated: bool) {
+        unsafe {
+            let _: id = msg_send![&*self.objc, popViewControllerAnimated:match animated {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Pops every screen but the root off the stack.
+    pub fn pop_to_root(&self, animated: bool) {
+        unsafe {
+            let _: id = msg_send![&*self.objc, popToRootViewControllerAnimated:match animated {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Returns how many screens are on the stack, including the root.
+    pub fn len(&self) -> usize {
+        unsafe {
+            let controllers: id = msg_send![&*self.objc, viewControllers];
+            let count: NSUInteger = msg_send![controllers, count];
+            count as usize
+        }
+    }
+
+    /// Sets whether screens show their titles in the large style. Individual screens can opt out
+    /// with `NavigationItem::set_large_title_display_mode()`.
+    pub fn set_prefers_large_titles(&self, prefers: bool) {
+        unsafe {
+            let bar: id = msg_send![&*self.objc, navigationBar];
+            let _: () = msg_send![bar, setPrefersLargeTitles:match prefers {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Shows or hides the navigation bar.
+    pub fn set_navigation_bar_hidden(&self, hidden: bool, animated: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setNavigationBarHidden:match hidden {
+                true => YES,
+                false => NO
+            } animated:match animated {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether the user can swipe from the leading edge to go back. On by default.
+    pub fn set_back_swipe_enabled(&self, enabled: bool) {
+        unsafe {
+            let recognizer: id = msg_send![&*self.objc, interactivePopGestureRecognizer];
+            if recognizer != nil {
+                let _: () = msg_send![recognizer, setEnabled:match enabled {
+                    true => YES,
+                    false => NO
+                }];
+            }
+        }
+    }
+}
+
+impl Controller for NavigationController {
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.objc.clone()
+    }
+}
+
+/// A wrapper for `UIBarButtonItem`: a button that lives in a navigation bar (or toolbar). Keep
+/// this around for as long as it's shown, as it holds the action.
+#[derive(Debug)]
+pub struct BarButtonItem {
+    /// The underlying `UIBarButtonItem`.
+    pub objc: ShareId<Object>,
+
+    handler: Option<TargetActionHandler>
+}
+
+impl BarButtonItem {
+    /// Creates a button with the given title.
+    pub fn new(title: &str, style: BarButtonItemStyle) -> Self {
+        let title = NSString::new(title);
+        let style: NSInteger = style.into();
+
+        BarButtonItem {
+            objc: unsafe {
+                let alloc: id = msg_send![class!(UIBarButtonItem), alloc];
+                let item: id = msg_send![alloc, initWithTitle:title style:style target:nil action:nil];
+                ShareId::from_retained_ptr(item)
+            },
+
+            handler: None
+        }
+    }
+
+    /// Creates one of the standard buttons, e.g "Done" or the share icon.
+    pub fn system(item: SystemBarButtonItem) -> Self {
+        let item: NSInteger = item.into();
+
+        BarButtonItem {
+            objc: unsafe {
+                let alloc: id = msg_send![class!(UIBarButtonItem), alloc];
+                let item: id = msg_send![alloc, initWithBarButtonSystemItem:item target:nil action:nil];
+                ShareId::from_retained_ptr(item)
+            },
+
+            handler: None
+        }
+    }
+
+    /// Creates a button showing `image` (e.g, an SF Symbol).
+    pub fn with_image(image: &Image, style: BarButtonItemStyle) -> Self {
+        let style: NSInteger = style.into();
+
+        BarButtonItem {
+            objc: unsafe {
+                let alloc: id = msg_send![class!(UIBarButtonItem), alloc];
+                let item: id = msg_send![alloc, initWithImage:&*image.0 style:style target:nil action:nil];
+                ShareId::from_retained_ptr(item)
+            },
+
+            handler: None
+        }
+    }
+
+    /// Creates a flexible space, which pushes the buttons on either side of it apart.
+    pub fn flexible_space() -> Self {
+        BarButtonItem {
+            objc: unsafe { ShareId::from_ptr(msg_send![class!(UIBarButtonItem), flexibleSpaceItem]) },
+            handler: None
+        }
+    }
+
+    /// Creates a fixed space, `width` points wide.
+    pub fn fixed_space(width: f64) -> Self {
+        BarButtonItem {
+            objc: unsafe { ShareId::from_ptr(msg_send![class!(UIBarButtonItem), fixedSpaceItemOfWidth:width]) },
+            handler: None
+        }
+    }
+
+    /// Sets the title.
+    pub fn set_title(&self, title: &str) {
+        let title = NSString::new(title);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTitle:title];
+        }
+    }
+
+    /// Sets the image.
+    pub fn set_image(&self, image: &Image) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setImage:&*image.0];
+        }
+    }
+
+    /// Sets a menu of actions, shown when the button is tapped - or, if it also has an action,
+    /// when it's held down.
+    pub fn set_menu(&self, items: Vec<PullDownItem>) {
+        let menu = menu_for(items);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setMenu:menu];
+        }
+    }
+
+    /// Sets the callback for when the button is tapped.
+    pub fn set_action<F: Fn() + Send + Sync + 'static>(&mut self, action: F) {
+        self.handler = Some(TargetActionHandler::new(&*self.objc, action));
+    }
+
+    /// Sets whether the button can be tapped.
+    pub fn set_enabled(&self, enabled: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setEnabled:match enabled {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+}
+
+/// A wrapper for a screen's `UINavigationItem`, which configures what the navigation bar shows
+/// while that screen is on top: its title, and any buttons.
+#[derive(Clone, Debug)]
+pub struct NavigationItem {
+    /// The underlying `UINavigationItem`.
+    pub objc: ShareId<Object>
+}
+
+impl NavigationItem {
+    /// Returns the navigation item for `controller`.
+    pub fn for_controller<VC: Controller>(controller: &VC) -> Self {
+        let controller = controller.get_backing_node();
+
+        NavigationItem {
+            objc: unsafe { ShareId::from_ptr(msg_send![&*controller, navigationItem]) }
+        }
+    }
+
+    /// Sets the title shown in the navigation bar.
+    pub fn set_title(&self, title: &str) {
+        let title = NSString::new(title);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTitle:title];
+        }
+    }
+
+    /// Sets whether this screen shows its title in the large style.
+    pub fn set_large_title_display_mode(&self, mode: LargeTitleDisplayMode) {
+        let mode: NSInteger = mode.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setLargeTitleDisplayMode:mode];
+        }
+    }
+
+    /// Sets the buttons shown on the leading side of the bar, in place of the back button (unless
+    /// `set_leading_items_supplement_back_button()` says otherwise).
+    pub fn set_left_bar_buttons(&self, buttons: &[&BarButtonItem]) {
+        let buttons = bar_button_array(buttons);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setLeftBarButtonItems:buttons.into_inner()];
+        }
+    }
+
+    /// Sets the buttons shown on the trailing side of the bar. The first is the outermost.
+    pub fn set_right_bar_buttons(&self, buttons: &[&BarButtonItem]) {
+        let buttons = bar_button_array(buttons);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setRightBarButtonItems:buttons.into_inner()];
+        }
+    }
+
+    /// Sets whether the leading buttons are shown alongside the back button, rather than in place
+    /// of it.
+    pub fn set_leading_items_supplement_back_button(&self, supplement: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setLeftItemsSupplementBackButton:match supplement {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Hides (or shows) the back button.
+    pub fn set_hides_back_button(&self, hides: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setHidesBackButton:match hides {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets a line of text shown above the title, e.g for instructions.
+    pub fn set_prompt(&self, prompt: &str) {
+        let prompt = NSString::new(prompt);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setPrompt:prompt];
+        }
+    }
+
+    /// Sets how the bar lays out this screen's title and buttons. Needs iOS 16 or later.
+    pub fn set_style(&self, style: NavigationItemStyle) {
+        let style: NSInteger = style.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setStyle:style];
+        }
+    }
+
+    /// Sets the buttons shown in the center of the bar, each in its own group. This needs the
+    /// browser or editor style (see `set_style()`), and iOS 16 or later; on iPhone, the bar
+    /// moves them into an overflow menu as space runs out.
+    pub fn set_center_bar_buttons(&self, buttons: &[&BarButtonItem]) {
+        let groups: NSArray = buttons.iter().map(|button| unsafe {
+            msg_send![&*button.objc, creatingFixedGroup]
+        }).collect::<Vec<id>>().into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setCenterItemGroups:groups.into_inner()];
+        }
+    }
+
+    /// Lets the user rearrange (and add or remove) the center buttons, as in a Mac toolbar. The
+    /// identifier is what their arrangement is saved under. Needs iOS 16 or later.
+    pub fn set_customization_identifier(&self, identifier: &str) {
+        let identifier = NSString::new(identifier);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setCustomizationIdentifier:identifier];
+        }
+    }
+
+    /// Puts `controller`'s search field in the navigation bar, while this screen is showing.
+    pub fn set_search_controller(&self, controller: &SearchController) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setSearchController:&*controller.objc];
+        }
+    }
+
+    /// Sets whether the search field scrolls out of sight with the content (the default), or
+    /// stays put.
+    pub fn set_hides_search_bar_when_scrolling(&self, hides: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setHidesSearchBarWhenScrolling:match hides {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets the title used for the back button on the screen pushed after this one. By default,
+    /// that's this screen's title.
+    pub fn set_back_button_title(&self, title: &str) {
+        let title = NSString::new(title);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setBackButtonTitle:title];
+        }
+    }
+}
+
+/// Gathers `buttons` into an `NSArray`, for handing to a `UINavigationItem`.
+fn bar_button_array(buttons: &[&BarButtonItem]) -> NSArray {
+    buttons.iter().map(|button| {
+        &*button.objc as *const Object as id
+    }).collect::<Vec<id>>().into()
+}
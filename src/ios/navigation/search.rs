@@ -0,0 +1,166 @@
+//! Wraps `UISearchController`, which puts a search field in the navigation bar (see
+//! `NavigationItem::set_search_controller()`), and reports what's typed into it.
+
+use std::fmt;
+use std::sync::Once;
+
+use objc::declare::ClassDecl;
+use objc::runtime::{Class, Object, Protocol, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::dispatch::assert_main_thread;
+use crate::foundation::{id, nil, YES, NO, NSString};
+use crate::utils::{clear_ptr, load_or_register_class, try_load};
+
+static SEARCH_HANDLER_PTR: &str = "rstSearchHandlerPtr";
+
+/// Gives us a sized pointer to store in the updater's ivar.
+struct SearchHandler(Box<dyn Fn(String) + 'static>);
+
+/// A search field for the navigation bar. Keep this around for as long as it's shown, as it holds
+/// the search handler.
+pub struct SearchController {
+    /// The underlying `UISearchController`.
+    pub objc: ShareId<Object>,
+
+    updater: Option<(ShareId<Object>, Box<SearchHandler>)>
+}
+
+impl Default for SearchController {
+    fn default() -> Self {
+        SearchController::new()
+    }
+}
+
+impl SearchController {
+    /// Creates a search controller that shows its results in place, in the screen it's attached
+    /// to - filter what you're showing from the search handler.
+    pub fn new() -> Self {
+        assert_main_thread("SearchController::new");
+
+        SearchController {
+            objc: unsafe {
+                let alloc: id = msg_send![class!(UISearchController), alloc];
+                let controller: id = msg_send![alloc, initWithSearchResultsController:nil];
+                let _: () = msg_send![controller, setObscuresBackgroundDuringPresentation:NO];
+                ShareId::from_retained_ptr(controller)
+            },
+
+            updater: None
+        }
+    }
+
+    /// Sets a handler to be called with the search text whenever it changes (including when the
+    /// search is cancelled, with an empty string).
+    pub fn set_search_handler<F: Fn(String) + 'static>(&mut self, handler: F) {
+        let handler = Box::new(SearchHandler(Box::new(handler)));
+
+        // `searchResultsUpdater` is a weak reference, so the updater is ours to keep alive.
+        let updater = unsafe {
+            let updater: id = msg_send![register_search_updater_class(), new];
+            let ptr: *const SearchHandler = &*handler;
+            (&mut *updater).set_ivar(SEARCH_HANDLER_PTR, ptr as usize);
+            let _: () = msg_send![&*self.objc, setSearchResultsUpdater:updater];
+            ShareId::from_retained_ptr(updater)
+        };
+
+        if let Some((old, _)) = self.updater.replace((updater, handler)) {
+            clear_ptr(&old, SEARCH_HANDLER_PTR);
+        }
+    }
+
+    /// Sets the placeholder shown in the empty search field.
+    pub fn set_placeholder(&self, placeholder: &str) {
+        let placeholder = NSString::new(placeholder);
+
+        unsafe {
+            let bar: id = msg_send![&*self.objc, searchBar];
+            let _: () = msg_send![bar, setPlaceholder:placeholder];
+        }
+    }
+
+    /// Returns the current search text.
+    pub fn text(&self) -> String {
+        unsafe {
+            let bar: id = msg_send![&*self.objc, searchBar];
+            let text: id = msg_send![bar, text];
+
+            match text == nil {
+                true => String::new(),
+                false => NSString::wrap(text).to_string()
+            }
+        }
+    }
+
+    /// Sets whether the screen's content is dimmed while searching. Off by default, as results
+    /// are usually filtered in place.
+    pub fn set_obscures_background(&self, obscures: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setObscuresBackgroundDuringPresentation:match obscures {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+}
+
+impl fmt::Debug for SearchController {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SearchController")
+            .field("objc", &self.objc)
+            .finish()
+    }
+}
+
+impl Drop for SearchController {
+    /// Detaches the updater, and breaks its link to the handler, which is going away.
+    fn drop(&mut self) {
+        if let Some((updater, _)) = &self.updater {
+            clear_ptr(updater, SEARCH_HANDLER_PTR);
+
+            unsafe {
+                let _: () = msg_send![&*self.objc, setSearchResultsUpdater:nil];
+            }
+        }
+    }
+}
+
+/// Called whenever the search text changes.
+extern fn update_search_results(this: &Object, _: Sel, controller: id) {
+    if let Ok(handler) = try_load::<SearchHandler>(this, SEARCH_HANDLER_PTR) {
+        let text: id = unsafe {
+            let bar: id = msg_send![controller, searchBar];
+            msg_send![bar, text]
+        };
+
+        (handler.0)(match text == nil {
+            true => String::new(),
+            false => NSString::wrap(text).to_string()
+        });
+    }
+}
+
+/// Registers an `NSObject` subclass that conforms to `UISearchResultsUpdating`.
+fn register_search_updater_class() -> *const Class {
+    static mut UPDATER_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSObject);
+        UPDATER_CLASS = load_or_register_class(superclass, "RSTSearchResultsUpdater", |decl: &mut ClassDecl| {
+            decl.add_ivar::<usize>(SEARCH_HANDLER_PTR);
+
+            if let Some(protocol) = Protocol::get("UISearchResultsUpdating") {
+                decl.add_protocol(protocol);
+            }
+
+            decl.add_method(
+                sel!(updateSearchResultsForSearchController:),
+                update_search_results as extern fn(&Object, _, _)
+            );
+        });
+    });
+
+    unsafe { UPDATER_CLASS }
+}
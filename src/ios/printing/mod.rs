@@ -0,0 +1,85 @@
+//! Wraps `UIPrintInteractionController`, which is how you print on iOS. Unlike macOS, there's a
+//! single shared controller, and the system handles page setup via the print sheet.
+
+use std::path::Path;
+
+use block::ConcreteBlock;
+
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, BOOL, YES, NO, NSString};
+use crate::layout::Layout;
+
+/// Provides access to the system print sheet.
+#[derive(Debug)]
+pub struct PrintInteraction;
+
+impl PrintInteraction {
+    /// Returns whether printing is available on this device.
+    pub fn is_available() -> bool {
+        let available: BOOL = unsafe {
+            msg_send![class!(UIPrintInteractionController), isPrintingAvailable]
+        };
+
+        match available {
+            YES => true,
+            NO => false,
+            _ => unreachable!()
+        }
+    }
+
+    /// Shows the print sheet for the given view. `completion` is called with whether the job was
+    /// sent to a printer.
+    pub fn print_view<L, F>(view: &L, job_name: &str, completion: F)
+    where
+        L: Layout,
+        F: Fn(bool) + 'static
+    {
+        let backing_node = view.get_backing_node();
+
+        unsafe {
+            let controller = shared_controller(job_name);
+            let formatter: id = msg_send![&*backing_node, viewPrintFormatter];
+            let _: () = msg_send![controller, setPrintFormatter:formatter];
+            present(controller, completion);
+        }
+    }
+
+    /// Shows the print sheet for the file at the given path (e.g, a PDF or an image).
+    /// `completion` is called with whether the job was sent to a printer.
+    pub fn print_file<P, F>(path: P, job_name: &str, completion: F)
+    where
+        P: AsRef<Path>,
+        F: Fn(bool) + 'static
+    {
+        let path = NSString::new(&path.as_ref().to_string_lossy());
+
+        unsafe {
+            let controller = shared_controller(job_name);
+            let url: id = msg_send![class!(NSURL), fileURLWithPath:path];
+            let _: () = msg_send![controller, setPrintingItem:url];
+            present(controller, completion);
+        }
+    }
+}
+
+/// Returns the shared print controller, reset and configured with a fresh print info.
+unsafe fn shared_controller(job_name: &str) -> id {
+    let job_name = NSString::new(job_name);
+
+    let controller: id = msg_send![class!(UIPrintInteractionController), sharedPrintController];
+    let info: id = msg_send![class!(UIPrintInfo), printInfo];
+    let _: () = msg_send![info, setJobName:job_name];
+    let _: () = msg_send![controller, setPrintInfo:info];
+    controller
+}
+
+/// Presents the print sheet, calling back when it's dismissed.
+unsafe fn present<F: Fn(bool) + 'static>(controller: id, completion: F) {
+    let block = ConcreteBlock::new(move |_: id, completed: BOOL, _: id| {
+        completion(completed == YES);
+    });
+    let block = block.copy();
+
+    let _: () = msg_send![controller, presentAnimated:YES completionHandler:block];
+}
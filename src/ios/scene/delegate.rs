@@ -9,7 +9,6 @@ use std::unreachable;
 use block::Block;
 
 use objc::{class, msg_send, sel, sel_impl};
-use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel};
 
 use url::Url;
@@ -17,7 +16,7 @@ use url::Url;
 use crate::error::Error;
 use crate::foundation::{id, nil, BOOL, YES, NO, NSUInteger, NSArray, NSString};
 use crate::user_activity::UserActivity;
-use crate::utils::load;
+use crate::utils::load_or_register_class;
 
 use crate::ios::app::{SCENE_DELEGATE_VENDOR};
 use crate::ios::scene::{Scene, SceneConfig, SceneConnectionOptions, SceneSession, WindowSceneDelegate};
@@ -51,7 +50,7 @@ extern fn init<
 extern fn scene_will_connect_to_session_with_options<
     T: WindowSceneDelegate
 >(this: &Object, _: Sel, scene: id, session: id, options: id) {
-    let delegate = load::<T>(this, WINDOW_SCENE_PTR);
+    let delegate = load_or_return!(T, this, WINDOW_SCENE_PTR);
 
     delegate.will_connect(
         Scene::with(scene),
@@ -60,8 +59,63 @@ extern fn scene_will_connect_to_session_with_options<
     );
 }
 
-/// Registers an `NSObject` application delegate, and configures it for the various callbacks and
-/// pointers we need to have.
+/// Fires when the scene has been disconnected.
+extern fn scene_did_disconnect<T: WindowSceneDelegate>(this: &Object, _: Sel, scene: id) {
+    let delegate = load_or_return!(T, this, WINDOW_SCENE_PTR);
+    delegate.did_disconnect(Scene::with(scene));
+}
+
+/// Fires when the scene has become active.
+extern fn scene_did_become_active<T: WindowSceneDelegate>(this: &Object, _: Sel, scene: id) {
+    let delegate = load_or_return!(T, this, WINDOW_SCENE_PTR);
+    delegate.did_become_active(Scene::with(scene));
+}
+
+/// Fires when the scene is about to resign being active.
+extern fn scene_will_resign_active<T: WindowSceneDelegate>(this: &Object, _: Sel, scene: id) {
+    let delegate = load_or_return!(T, this, WINDOW_SCENE_PTR);
+    delegate.will_resign_active(Scene::with(scene));
+}
+
+/// Fires when the scene is about to enter the foreground.
+extern fn scene_will_enter_foreground<T: WindowSceneDelegate>(this: &Object, _: Sel, scene: id) {
+    let delegate = load_or_return!(T, this, WINDOW_SCENE_PTR);
+    delegate.will_enter_foreground(Scene::with(scene));
+}
+
+/// Fires when the scene has entered the background.
+extern fn scene_did_enter_background<T: WindowSceneDelegate>(this: &Object, _: Sel, scene: id) {
+    let delegate = load_or_return!(T, this, WINDOW_SCENE_PTR);
+    delegate.did_enter_background(Scene::with(scene));
+}
+
+/// Asks for an activity to save the scene's state with.
+extern fn state_restoration_activity<T: WindowSceneDelegate>(this: &Object, _: Sel, scene: id) -> id {
+    let delegate = load_or_return!(T, this, WINDOW_SCENE_PTR, nil);
+
+    match delegate.state_restoration_activity(Scene::with(scene)) {
+        Some(activity) => activity.into_autoreleased(),
+        None => nil
+    }
+}
+
+/// Drops the `WindowSceneDelegate` this was vended, as the scene is done with us.
+extern fn dealloc<T: WindowSceneDelegate>(this: &Object, _: Sel) {
+    unsafe {
+        let ptr: usize = *this.get_ivar(WINDOW_SCENE_PTR);
+
+        if ptr != 0 {
+            let this = this as *const Object as *mut Object;
+            (&mut *this).set_ivar(WINDOW_SCENE_PTR, 0usize);
+            let _ = Box::from_raw(ptr as *mut T);
+        }
+
+        let _: () = msg_send![super(this, class!(UIResponder)), dealloc];
+    }
+}
+
+/// Registers a `UIResponder` subclass to act as the `UIWindowSceneDelegate`; each instance vends
+/// (and owns) a `WindowSceneDelegate`.
 pub(crate) fn register_window_scene_delegate_class<
     T: WindowSceneDelegate,
     F: Fn() -> Box<T>
@@ -72,25 +126,35 @@ pub(crate) fn register_window_scene_delegate_class<
     use objc::runtime::{Protocol, class_addProtocol};
     INIT.call_once(|| unsafe {
         let superclass = class!(UIResponder);
-        let mut decl = ClassDecl::new("RSTWindowSceneDelegate", superclass).unwrap();
-
-        let p = Protocol::get("UIWindowSceneDelegate").unwrap();
-        
-        // A spot to hold a pointer to 
-        decl.add_ivar::<usize>(WINDOW_SCENE_PTR);
-        decl.add_protocol(p);
-
-        // Override the `init` call to handle creating and attaching a WindowSceneDelegate.
-        decl.add_method(sel!(init), init::<T, F> as extern fn(&mut Object, _) -> id);
-
-        // UIWindowSceneDelegate API
-        decl.add_method(
-            sel!(scene:willConnectToSession:options:),
-            scene_will_connect_to_session_with_options::<T> as extern fn(&Object, _, _, _, _)
-        );
-
-        // Launching Applications       
-        DELEGATE_CLASS = decl.register();
+        DELEGATE_CLASS = load_or_register_class(superclass, "RSTWindowSceneDelegate", |decl| {
+            let p = Protocol::get("UIWindowSceneDelegate").unwrap();
+
+            // A spot to hold a pointer to
+            decl.add_ivar::<usize>(WINDOW_SCENE_PTR);
+            decl.add_protocol(p);
+
+            // Override the `init` call to handle creating and attaching a WindowSceneDelegate.
+            decl.add_method(sel!(init), init::<T, F> as extern fn(&mut Object, _) -> id);
+
+            // UIWindowSceneDelegate API
+            decl.add_method(
+                sel!(scene:willConnectToSession:options:),
+                scene_will_connect_to_session_with_options::<T> as extern fn(&Object, _, _, _, _)
+            );
+            decl.add_method(sel!(sceneDidDisconnect:), scene_did_disconnect::<T> as extern fn(&Object, _, _));
+            decl.add_method(sel!(sceneDidBecomeActive:), scene_did_become_active::<T> as extern fn(&Object, _, _));
+            decl.add_method(sel!(sceneWillResignActive:), scene_will_resign_active::<T> as extern fn(&Object, _, _));
+            decl.add_method(sel!(sceneWillEnterForeground:), scene_will_enter_foreground::<T> as extern fn(&Object, _, _));
+            decl.add_method(sel!(sceneDidEnterBackground:), scene_did_enter_background::<T> as extern fn(&Object, _, _));
+
+            // State restoration
+            decl.add_method(sel!(stateRestorationActivityForScene:), state_restoration_activity::<T> as extern fn(&Object, _, _) -> id);
+
+            // Frees the vended delegate
+            decl.add_method(sel!(dealloc), dealloc::<T> as extern fn(&Object, _));
+
+            // Launching Applications
+        });
     });
 
     unsafe {
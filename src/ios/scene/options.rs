@@ -2,11 +2,10 @@ use objc::{class, msg_send, sel, sel_impl};
 use objc::runtime::Object;
 use objc_id::Id;
 
-use crate::foundation::{id, NSString};
+use crate::foundation::{id, nil, NSArray, NSString};
+use crate::user_activity::UserActivity;
 
-/// A wrapper for UISceneConfiguration.
-///
-/// Due to the way we have to implement this, you likely never need to touch this.
+/// A wrapper for `UISceneConnectionOptions`, which describes why a scene is being connected.
 #[derive(Debug)]
 pub struct SceneConnectionOptions(Id<Object>);
 
@@ -17,7 +16,20 @@ impl SceneConnectionOptions {
         })
     }
 
-    /// Consumes and returns the underlying `UISceneConfiguration`.
+    /// Returns the activities the scene was requested with - e.g, the one passed to
+    /// `App::request_scene_activation()`, or from a Handoff.
+    pub fn user_activities(&self) -> Vec<UserActivity> {
+        let activities: id = unsafe { msg_send![&*self.0, userActivities] };
+        if activities == nil {
+            return Vec::new();
+        }
+
+        NSArray::wrap(unsafe { msg_send![activities, allObjects] }).map(|activity| {
+            UserActivity::with_inner(activity)
+        })
+    }
+
+    /// Consumes and returns the underlying `UISceneConnectionOptions`.
     pub fn into_inner(mut self) -> id {
         &mut *self.0
     }
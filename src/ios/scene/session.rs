@@ -2,8 +2,9 @@ use objc_id::Id;
 use objc::runtime::Object;
 use objc::{msg_send, sel, sel_impl};
 
-use crate::foundation::{id, NSString};
+use crate::foundation::{id, nil, NSString};
 use crate::ios::scene::enums::SessionRole;
+use crate::user_activity::UserActivity;
 
 #[derive(Debug)]
 pub struct SceneSession(pub Id<Object>);
@@ -20,4 +21,23 @@ impl SceneSession {
             msg_send![&*self.0, role]
         }).into()
     }
+
+    /// Returns an identifier for this session that's stable across launches - handy for keying
+    /// per-window state you store yourself.
+    pub fn persistent_identifier(&self) -> String {
+        NSString::wrap(unsafe {
+            msg_send![&*self.0, persistentIdentifier]
+        }).to_string()
+    }
+
+    /// Returns the activity the scene's `WindowSceneDelegate::state_restoration_activity()`
+    /// returned the last time it was disconnected, if any.
+    pub fn state_restoration_activity(&self) -> Option<UserActivity> {
+        let activity: id = unsafe { msg_send![&*self.0, stateRestorationActivity] };
+
+        match activity == nil {
+            true => None,
+            false => Some(UserActivity::with_inner(activity))
+        }
+    }
 }
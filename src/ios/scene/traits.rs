@@ -1,10 +1,39 @@
 use crate::ios::scene::{Scene, SceneSession, SceneConnectionOptions};
+use crate::user_activity::UserActivity;
 
+/// Implement this to manage a scene - on iPad, one of (potentially) several windows of your app.
+/// A new instance is vended (via the factory handed to `App::new()`) for each scene, so state kept
+/// here is per-window.
 pub trait WindowSceneDelegate {
+    /// Called when the scene is being connected to your app - this is where you'd create a
+    /// `Window` for it. If the scene is being restored, `session.state_restoration_activity()`
+    /// holds what `state_restoration_activity()` returned last time; if it was requested with
+    /// `App::request_scene_activation()`, the activity passed there is in `options`.
     fn will_connect(
         &self,
         scene: Scene,
         session: SceneSession,
         options: SceneConnectionOptions
     );
+
+    /// Called when the scene has been disconnected - e.g, the user closed it, or the system
+    /// reclaimed it while it was in the background. It may be reconnected later (with a new
+    /// delegate), so save anything you need to restore it.
+    fn did_disconnect(&self, _scene: Scene) {}
+
+    /// Called when the scene has become active, and is receiving events.
+    fn did_become_active(&self, _scene: Scene) {}
+
+    /// Called when the scene is about to stop receiving events (e.g, an incoming call).
+    fn will_resign_active(&self, _scene: Scene) {}
+
+    /// Called when the scene is about to come into the foreground.
+    fn will_enter_foreground(&self, _scene: Scene) {}
+
+    /// Called when the scene has gone into the background.
+    fn did_enter_background(&self, _scene: Scene) {}
+
+    /// Return an activity describing the scene's state, and the system will hand it back (via
+    /// `SceneSession::state_restoration_activity()`) when the scene is next connected.
+    fn state_restoration_activity(&self, _scene: Scene) -> Option<UserActivity> { None }
 }
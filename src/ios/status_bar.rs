@@ -0,0 +1,86 @@
+//! Types for controlling the status bar and home indicator, which view controllers report via
+//! `ViewDelegate`.
+//!
+//! UIKit only asks the view controller that's running the show - the root, or whatever's presented
+//! fullscreen over it. Inside a `NavigationController`, visibility comes from the controller on
+//! top, but the style follows the navigation bar.
+//!
+//! This relies on view controller-based status bar appearance, which is on unless your
+//! `Info.plist` sets `UIViewControllerBasedStatusBarAppearance` to `NO`.
+
+use crate::foundation::{NSInteger, NSUInteger};
+
+/// The style of the status bar's content.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StatusBarStyle {
+    /// Dark content in light mode, and light content in dark mode.
+    Default,
+
+    /// Light content, for use over dark backgrounds.
+    LightContent,
+
+    /// Dark content, for use over light backgrounds.
+    DarkContent
+}
+
+impl From<StatusBarStyle> for NSInteger {
+    fn from(style: StatusBarStyle) -> Self {
+        match style {
+            StatusBarStyle::Default => 0,
+            StatusBarStyle::LightContent => 1,
+            StatusBarStyle::DarkContent => 3
+        }
+    }
+}
+
+/// How the status bar animates when it's shown or hidden.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StatusBarAnimation {
+    /// No animation.
+    None,
+
+    /// Fades in and out. This is the default.
+    Fade,
+
+    /// Slides in and out from the top of the screen.
+    Slide
+}
+
+impl From<StatusBarAnimation> for NSInteger {
+    fn from(animation: StatusBarAnimation) -> Self {
+        match animation {
+            StatusBarAnimation::None => 0,
+            StatusBarAnimation::Fade => 1,
+            StatusBarAnimation::Slide => 2
+        }
+    }
+}
+
+/// An edge of the screen, for deferring system gestures (e.g, so that a swipe up from the bottom
+/// edge in a game goes to the game first, rather than going home).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScreenEdge {
+    /// The top edge.
+    Top,
+
+    /// The left edge.
+    Left,
+
+    /// The bottom edge.
+    Bottom,
+
+    /// The right edge.
+    Right
+}
+
+impl ScreenEdge {
+    /// Combines `edges` into a `UIRectEdge` mask.
+    pub(crate) fn mask(edges: &[ScreenEdge]) -> NSUInteger {
+        edges.iter().fold(0, |mask, edge| mask | match edge {
+            ScreenEdge::Top => 1 << 0,
+            ScreenEdge::Left => 1 << 1,
+            ScreenEdge::Bottom => 1 << 2,
+            ScreenEdge::Right => 1 << 3
+        })
+    }
+}
@@ -0,0 +1,46 @@
+//! Handles the Objective-C functionality for the `TabBarController` module.
+
+use objc::runtime::{Class, Object, Sel, BOOL};
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, YES, NO, NSUInteger};
+use crate::ios::tab_bar::{TAB_BAR_DELEGATE_PTR, TabBarDelegate};
+use crate::utils::load_or_register_class_for;
+
+/// Returns the index of `controller` among the tabs of `tab_bar_controller`.
+fn index_of(tab_bar_controller: id, controller: id) -> usize {
+    unsafe {
+        let controllers: id = msg_send![tab_bar_controller, viewControllers];
+        let index: NSUInteger = msg_send![controllers, indexOfObject:controller];
+        index as usize
+    }
+}
+
+/// Called before the user switches tabs.
+extern fn should_select<T: TabBarDelegate>(this: &Object, _: Sel, tab_bar_controller: id, controller: id) -> BOOL {
+    let delegate = load_or_return!(T, this, TAB_BAR_DELEGATE_PTR, YES);
+
+    match delegate.should_select_tab(index_of(tab_bar_controller, controller)) {
+        true => YES,
+        false => NO
+    }
+}
+
+/// Called after the user has switched tabs.
+extern fn did_select<T: TabBarDelegate>(this: &Object, _: Sel, tab_bar_controller: id, controller: id) {
+    let delegate = load_or_return!(T, this, TAB_BAR_DELEGATE_PTR);
+    delegate.tab_selected(index_of(tab_bar_controller, controller));
+}
+
+/// Registers an `NSObject` subclass that acts as a `UITabBarControllerDelegate`, and routes the
+/// callbacks back to Rust.
+pub(crate) fn register_tab_bar_delegate_class<T: TabBarDelegate + 'static>() -> *const Class {
+    let superclass = class!(NSObject);
+
+    load_or_register_class_for::<T, _>(superclass, "RSTTabBarControllerDelegate", |decl| unsafe {
+        decl.add_ivar::<usize>(TAB_BAR_DELEGATE_PTR);
+
+        decl.add_method(sel!(tabBarController:shouldSelectViewController:), should_select::<T> as extern fn(&Object, _, _, _) -> BOOL);
+        decl.add_method(sel!(tabBarController:didSelectViewController:), did_select::<T> as extern fn(&Object, _, _, _));
+    })
+}
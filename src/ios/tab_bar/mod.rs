@@ -0,0 +1,218 @@
+//! Wraps `UITabBarController`, which shows one of several screens at a time, with a tab bar along
+//! the bottom for switching between them.
+//!
+//! ```rust,no_run
+//! use cacao::ios::{TabBarController, TabBarDelegate};
+//! use cacao::view::{ViewController, ViewDelegate};
+//!
+//! #[derive(Default)]
+//! struct Screen;
+//! impl ViewDelegate for Screen {}
+//!
+//! struct Tabs;
+//!
+//! impl TabBarDelegate for Tabs {
+//!     fn tab_selected(&self, index: usize) {
+//!         println!("Switched to tab {}", index);
+//!     }
+//! }
+//!
+//! let home = ViewController::new(Screen::default());
+//! let settings = ViewController::new(Screen::default());
+//!
+//! let tabs = TabBarController::with(Tabs);
+//! tabs.add_tab("Home", "house", &home);
+//! tabs.add_tab("Settings", "gear", &settings);
+//! tabs.set_badge(1, Some("1"));
+//! ```
+//!
+//! As with `NavigationController`, only the Objective-C side of each tab's controller is retained;
+//! keep the Rust `ViewController`s around for as long as they're tabs.
+
+use std::fmt;
+
+use objc::{class, msg_send, sel, sel_impl};
+use objc::runtime::Object;
+use objc_id::ShareId;
+
+use crate::dispatch::assert_main_thread;
+use crate::foundation::{id, nil, NSInteger, NSString, NSUInteger};
+use crate::utils::{clear_ptr, Controller};
+
+mod class;
+use class::register_tab_bar_delegate_class;
+
+mod traits;
+pub use traits::TabBarDelegate;
+
+pub(crate) static TAB_BAR_DELEGATE_PTR: &str = "rstTabBarDelegatePtr";
+
+/// A wrapper for `UITabBarController`.
+pub struct TabBarController<T = ()> {
+    /// The underlying `UITabBarController`.
+    pub objc: ShareId<Object>,
+
+    /// The delegate for this controller, if there is one.
+    pub delegate: Option<Box<T>>,
+
+    /// The Objective-C object that routes `UITabBarControllerDelegate` calls to `delegate`.
+    objc_delegate: Option<ShareId<Object>>
+}
+
+impl Default for TabBarController {
+    fn default() -> Self {
+        TabBarController::new()
+    }
+}
+
+impl TabBarController {
+    /// Creates a tab bar controller, with no tabs and no delegate.
+    pub fn new() -> Self {
+        assert_main_thread("TabBarController::new");
+
+        TabBarController {
+            objc: unsafe { ShareId::from_retained_ptr(msg_send![class!(UITabBarController), new]) },
+            delegate: None,
+            objc_delegate: None
+        }
+    }
+}
+
+impl<T> TabBarController<T> where T: TabBarDelegate + 'static {
+    /// Creates a tab bar controller, with no tabs, that reports tab switches to `delegate`.
+    pub fn with(delegate: T) -> Self {
+        assert_main_thread("TabBarController::with");
+
+        let delegate = Box::new(delegate);
+
+        let objc_delegate = unsafe {
+            let objc_delegate: id = msg_send![register_tab_bar_delegate_class::<T>(), new];
+            let ptr: *const T = &*delegate;
+            (&mut *objc_delegate).set_ivar(TAB_BAR_DELEGATE_PTR, ptr as usize);
+            ShareId::from_retained_ptr(objc_delegate)
+        };
+
+        let objc = unsafe {
+            let controller: id = msg_send![class!(UITabBarController), new];
+            let _: () = msg_send![controller, setDelegate:&*objc_delegate];
+            ShareId::from_retained_ptr(controller)
+        };
+
+        TabBarController {
+            objc: objc,
+            delegate: Some(delegate),
+            objc_delegate: Some(objc_delegate)
+        }
+    }
+}
+
+impl<T> TabBarController<T> {
+    /// Adds a tab for `controller` at the end of the tab bar, labeled with `title` and the SF
+    /// Symbol named `symbol` (e.g, `"house"`).
+    pub fn add_tab<VC: Controller>(&self, title: &str, symbol: &str, controller: &VC) {
+        let controller = controller.get_backing_node();
+        let title = NSString::new(title);
+        let symbol = NSString::new(symbol);
+
+        unsafe {
+            let image: id = msg_send![class!(UIImage), systemImageNamed:symbol];
+            let tag = self.len() as NSInteger;
+
+            let item: id = msg_send![class!(UITabBarItem), alloc];
+            let item: id = msg_send![item, initWithTitle:title image:image tag:tag];
+            let _: () = msg_send![&*controller, setTabBarItem:item];
+            let _: () = msg_send![item, release];
+
+            let controllers: id = msg_send![&*self.objc, viewControllers];
+            let controllers: id = match controllers == nil {
+                true => msg_send![class!(NSArray), arrayWithObject:&*controller],
+                false => msg_send![controllers, arrayByAddingObject:&*controller]
+            };
+
+            let _: () = msg_send![&*self.objc, setViewControllers:controllers];
+        }
+    }
+
+    /// Returns the number of tabs.
+    pub fn len(&self) -> usize {
+        unsafe {
+            let controllers: id = msg_send![&*self.objc, viewControllers];
+            if controllers == nil {
+                return 0;
+            }
+
+            let count: NSUInteger = msg_send![controllers, count];
+            count as usize
+        }
+    }
+
+    /// Sets the badge shown on the tab at `index` (e.g, an unread count), or clears it for `None`.
+    /// Does nothing if there's no such tab.
+    pub fn set_badge(&self, index: usize, badge: Option<&str>) {
+        if index >= self.len() {
+            return;
+        }
+
+        let badge = badge.map(NSString::new);
+
+        unsafe {
+            let controllers: id = msg_send![&*self.objc, viewControllers];
+            let controller: id = msg_send![controllers, objectAtIndex:index as NSUInteger];
+            let item: id = msg_send![controller, tabBarItem];
+
+            match badge {
+                Some(badge) => { let _: () = msg_send![item, setBadgeValue:badge]; },
+                None => { let _: () = msg_send![item, setBadgeValue:nil]; }
+            }
+        }
+    }
+
+    /// Switches to the tab at `index`. Does nothing if there's no such tab.
+    pub fn select(&self, index: usize) {
+        if index >= self.len() {
+            return;
+        }
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setSelectedIndex:index as NSUInteger];
+        }
+    }
+
+    /// Returns the index of the selected tab, if there are any tabs.
+    pub fn selected_index(&self) -> Option<usize> {
+        if self.len() == 0 {
+            return None;
+        }
+
+        let index: NSUInteger = unsafe { msg_send![&*self.objc, selectedIndex] };
+        Some(index as usize)
+    }
+}
+
+impl<T> Controller for TabBarController<T> {
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.objc.clone()
+    }
+}
+
+impl<T> fmt::Debug for TabBarController<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TabBarController")
+            .field("objc", &self.objc)
+            .field("tabs", &self.len())
+            .finish()
+    }
+}
+
+impl<T> Drop for TabBarController<T> {
+    /// Breaks the delegate link, as the delegate is going away.
+    fn drop(&mut self) {
+        if let Some(objc_delegate) = &self.objc_delegate {
+            clear_ptr(objc_delegate, TAB_BAR_DELEGATE_PTR);
+
+            unsafe {
+                let _: () = msg_send![&*self.objc, setDelegate:nil];
+            }
+        }
+    }
+}
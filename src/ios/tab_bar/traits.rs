@@ -0,0 +1,11 @@
+//! Traits for `TabBarController`.
+
+/// Implement this to hear about (and have a say in) the user switching tabs.
+pub trait TabBarDelegate {
+    /// Called before the user switches to the tab at `index`. Return `false` to stay put.
+    fn should_select_tab(&self, _index: usize) -> bool { true }
+
+    /// Called after the user has switched to the tab at `index`. This isn't called when you
+    /// switch tabs yourself, via `TabBarController::select()`.
+    fn tab_selected(&self, _index: usize) {}
+}
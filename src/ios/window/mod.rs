@@ -5,6 +5,7 @@ use objc::{class, msg_send, sel, sel_impl};
 use objc::runtime::Object;
 use objc_id::Id;
 
+use crate::dispatch::assert_main_thread;
 use crate::foundation::id;
 use crate::geometry::Rect;
 use crate::ios::Scene;
@@ -15,6 +16,8 @@ pub struct Window(pub Id<Object>);
 
 impl Window {
     pub fn new(frame: Rect) -> Self {
+        assert_main_thread("Window::new");
+
         Window(unsafe {
             let rect: CGRect = frame.into();
             let alloc: id = msg_send![class!(UIWindow), alloc];
@@ -0,0 +1,266 @@
+//! Key-Value Observing, for watching properties on Objective-C objects - e.g, a `WKWebView`'s
+//! `estimatedProgress`, or an `AVPlayer`'s `status`.
+//!
+//! ```rust,no_run
+//! use cacao::foundation::id;
+//! use cacao::kvo::{observe, ObservingOption};
+//!
+//! # let webview: id = std::ptr::null_mut();
+//! // Keep this around for as long as you want to be notified.
+//! let token = observe(webview, "estimatedProgress", &[ObservingOption::New], |change| {
+//!     println!("Loaded {:?}", change.new_f64());
+//! });
+//! ```
+//!
+//! Handlers are always called on the main thread, regardless of which thread the property
+//! changed on.
+
+use std::fmt;
+use std::os::raw::c_void;
+use std::sync::Once;
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::{Id, ShareId};
+
+use crate::foundation::{id, nil, YES, NO, BOOL, NSNumber, NSString, NSUInteger};
+use crate::utils::load_or_register_class;
+
+static KVO_OBSERVER_PTR: &str = "rstKVOObserverPtr";
+
+/// `NSKeyValueChangeNewKey`.
+static NEW_KEY: &str = "new";
+
+/// `NSKeyValueChangeOldKey`.
+static OLD_KEY: &str = "old";
+
+/// `NSKeyValueChangeNotificationIsPriorKey`.
+static PRIOR_KEY: &str = "notificationIsPrior";
+
+/// Options that control what's included in a `KeyValueChange`, and when you're notified.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ObservingOption {
+    /// Include the new value.
+    New,
+
+    /// Include the old value.
+    Old,
+
+    /// Call the handler immediately, with the current value, before observation begins.
+    Initial,
+
+    /// Call the handler both before and after each change.
+    Prior
+}
+
+impl From<&ObservingOption> for NSUInteger {
+    fn from(option: &ObservingOption) -> Self {
+        match option {
+            ObservingOption::New => 1 << 0,
+            ObservingOption::Old => 1 << 1,
+            ObservingOption::Initial => 1 << 2,
+            ObservingOption::Prior => 1 << 3
+        }
+    }
+}
+
+/// Describes a change to an observed property. Values are only present if you asked for them via
+/// `ObservingOption`s.
+#[derive(Clone, Debug)]
+pub struct KeyValueChange {
+    /// The underlying change dictionary.
+    pub objc: ShareId<Object>
+}
+
+impl KeyValueChange {
+    /// Returns the value for a key in the change dictionary, or `nil`. `NSNull` (which KVO uses
+    /// for properties that were `nil`) is mapped to `nil`.
+    fn value(&self, key: &str) -> id {
+        unsafe {
+            let key = NSString::new(key);
+            let value: id = msg_send![&*self.objc, objectForKey:key];
+            let null: id = msg_send![class!(NSNull), null];
+
+            match value == null {
+                true => nil,
+                false => value
+            }
+        }
+    }
+
+    /// Returns the new value, or `nil`.
+    pub fn new_value(&self) -> id {
+        self.value(NEW_KEY)
+    }
+
+    /// Returns the old value, or `nil`.
+    pub fn old_value(&self) -> id {
+        self.value(OLD_KEY)
+    }
+
+    /// Returns the new value as an `f64`, if it's a number.
+    pub fn new_f64(&self) -> Option<f64> {
+        number(self.new_value()).map(|number| number.as_f64())
+    }
+
+    /// Returns the new value as an `i64`, if it's a number.
+    pub fn new_i64(&self) -> Option<i64> {
+        number(self.new_value()).map(|number| number.as_i64())
+    }
+
+    /// Returns the new value as a `bool`, if it's a number.
+    pub fn new_bool(&self) -> Option<bool> {
+        number(self.new_value()).map(|number| number.as_bool())
+    }
+
+    /// Returns the new value as a `String`, if it's a string.
+    pub fn new_string(&self) -> Option<String> {
+        let value = self.new_value();
+
+        match value != nil && NSString::is(value) {
+            true => Some(NSString::wrap(value).to_string()),
+            false => None
+        }
+    }
+
+    /// Returns whether this notification was sent before the change (when observing with
+    /// `ObservingOption::Prior`).
+    pub fn is_prior(&self) -> bool {
+        let value = self.value(PRIOR_KEY);
+
+        match value == nil {
+            true => false,
+            false => NSNumber::wrap(value).as_bool()
+        }
+    }
+}
+
+/// Wraps `value` as an `NSNumber`, if it is one.
+fn number(value: id) -> Option<NSNumber> {
+    match value != nil && NSNumber::is(value) {
+        true => Some(NSNumber::wrap(value)),
+        false => None
+    }
+}
+
+/// Gives us a sized pointer to store in the observer's ivar.
+struct ObserverCallback(Box<dyn Fn(KeyValueChange) + 'static>);
+
+/// A handle for an observation registered via `observe()`. Your handler is called for as long as
+/// you hold this; when it drops, the observation is removed.
+pub struct ObservationToken {
+    object: ShareId<Object>,
+    observer: Id<Object>,
+    key_path: String,
+    callback: *mut ObserverCallback
+}
+
+impl fmt::Debug for ObservationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ObservationToken")
+            .field("key_path", &self.key_path)
+            .finish()
+    }
+}
+
+impl Drop for ObservationToken {
+    /// Removes the observation, and clears the callback pointer so that any change still queued
+    /// for the main thread is ignored.
+    fn drop(&mut self) {
+        unsafe {
+            let key_path = NSString::new(&self.key_path);
+            let _: () = msg_send![&*self.object, removeObserver:&*self.observer forKeyPath:key_path];
+
+            (&mut *self.observer).set_ivar(KVO_OBSERVER_PTR, 0 as usize);
+            let _ = Box::from_raw(self.callback);
+        }
+    }
+}
+
+/// Calls `handler` (on the main thread) whenever the property at `key_path` changes on `object`.
+/// The observed object is retained for as long as the returned `ObservationToken` lives.
+pub fn observe<F>(object: id, key_path: &str, options: &[ObservingOption], handler: F) -> ObservationToken
+where
+    F: Fn(KeyValueChange) + 'static
+{
+    let options = options.iter().fold(0, |opts, option| opts | NSUInteger::from(option));
+    let callback = Box::into_raw(Box::new(ObserverCallback(Box::new(handler))));
+
+    let observer = unsafe {
+        let observer: id = msg_send![register_kvo_observer_class(), new];
+        (&mut *observer).set_ivar(KVO_OBSERVER_PTR, callback as usize);
+        Id::from_retained_ptr(observer)
+    };
+
+    // Set up the token before registering, as `ObservingOption::Initial` fires right away.
+    let token = ObservationToken {
+        object: unsafe { ShareId::from_ptr(object) },
+        observer: observer,
+        key_path: key_path.to_string(),
+        callback: callback
+    };
+
+    unsafe {
+        let key_path = NSString::new(key_path);
+        let _: () = msg_send![object, addObserver:&*token.observer
+            forKeyPath:key_path
+            options:options
+            context:std::ptr::null_mut::<c_void>()
+        ];
+    }
+
+    token
+}
+
+/// Calls the handler, if the observation is still live.
+extern fn value_did_change(this: &Object, _: Sel, change: id) {
+    let ptr: usize = unsafe { *this.get_ivar(KVO_OBSERVER_PTR) };
+
+    if ptr == 0 {
+        return;
+    }
+
+    let callback = unsafe { &*(ptr as *const ObserverCallback) };
+
+    (callback.0)(KeyValueChange {
+        objc: unsafe { ShareId::from_ptr(change) }
+    });
+}
+
+/// Fired by KVO. Changes can come in on any thread, so we hop to the main thread where needed.
+extern fn observe_value(this: &Object, _: Sel, _key_path: id, _object: id, change: id, _context: *mut c_void) {
+    let is_main_thread: BOOL = unsafe { msg_send![class!(NSThread), isMainThread] };
+
+    match is_main_thread {
+        YES => value_did_change(this, sel!(rstValueDidChange:), change),
+
+        _ => unsafe {
+            let _: () = msg_send![this, performSelectorOnMainThread:sel!(rstValueDidChange:)
+                withObject:change
+                waitUntilDone:NO
+            ];
+        }
+    }
+}
+
+/// Injects an `NSObject` subclass that receives KVO change notifications and forwards them to a
+/// Rust callback.
+fn register_kvo_observer_class() -> *const Class {
+    static mut OBSERVER_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSObject);
+        OBSERVER_CLASS = load_or_register_class(superclass, "RSTKeyValueObserver", |decl| {
+            decl.add_ivar::<usize>(KVO_OBSERVER_PTR);
+
+            decl.add_method(sel!(rstValueDidChange:), value_did_change as extern fn(&Object, _, id));
+            decl.add_method(
+                sel!(observeValueForKeyPath:ofObject:change:context:),
+                observe_value as extern fn(&Object, _, id, id, id, *mut c_void)
+            );
+        });
+    });
+
+    unsafe { OBSERVER_CLASS }
+}
@@ -0,0 +1,45 @@
+//! Autoresizing masks, which describe how a view positioned by frame (rather than by constraints)
+//! follows its superview as that's resized. See `Layout::set_autoresizing_mask()`.
+
+use crate::foundation::NSUInteger;
+
+/// Which parts of a view stretch when its superview is resized. Anything not in the mask stays
+/// fixed - so with an empty mask, the view keeps its size and its distance from the top left.
+///
+/// Top and bottom assume the superview's origin is its top left, which is the case for the views
+/// in this framework on both platforms.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AutoresizingMask {
+    /// The space between the view and the left of its superview stretches.
+    FlexibleLeftMargin,
+
+    /// The view's width stretches.
+    FlexibleWidth,
+
+    /// The space between the view and the right of its superview stretches.
+    FlexibleRightMargin,
+
+    /// The space between the view and the top of its superview stretches.
+    FlexibleTopMargin,
+
+    /// The view's height stretches.
+    FlexibleHeight,
+
+    /// The space between the view and the bottom of its superview stretches.
+    FlexibleBottomMargin
+}
+
+impl From<&AutoresizingMask> for NSUInteger {
+    fn from(mask: &AutoresizingMask) -> Self {
+        // `NSAutoresizingMaskOptions` and `UIViewAutoresizing` share these values, with min Y
+        // being the top in a flipped (or UIKit) view.
+        match mask {
+            AutoresizingMask::FlexibleLeftMargin => 1 << 0,
+            AutoresizingMask::FlexibleWidth => 1 << 1,
+            AutoresizingMask::FlexibleRightMargin => 1 << 2,
+            AutoresizingMask::FlexibleTopMargin => 1 << 3,
+            AutoresizingMask::FlexibleHeight => 1 << 4,
+            AutoresizingMask::FlexibleBottomMargin => 1 << 5
+        }
+    }
+}
@@ -66,6 +66,18 @@ impl LayoutConstraint {
     //
     // I regret nothing, lol. If you have a better solution I'm all ears.
     pub fn activate(constraints: &[LayoutConstraint]) {
+        // Headless, these are stand-ins, which `NSLayoutConstraint` can't be handed; each one
+        // records being activated instead. See `crate::headless`.
+        #[cfg(feature = "headless")]
+        {
+            for constraint in constraints {
+                let _: () = unsafe { msg_send![&*constraint.constraint, setActive:crate::foundation::YES] };
+            }
+
+            return;
+        }
+
+        #[cfg(not(feature = "headless"))]
         unsafe {
             let ids: Vec<&Object> = constraints.into_iter().map(|constraint| {
                 &*constraint.constraint
@@ -10,6 +10,9 @@
 pub mod traits;
 pub use traits::Layout;
 
+pub mod autoresizing;
+pub use autoresizing::AutoresizingMask;
+
 pub mod constraint;
 pub use constraint::LayoutConstraint;
 
@@ -21,3 +24,6 @@ pub use horizontal::LayoutAnchorX;
 
 pub mod vertical;
 pub use vertical::LayoutAnchorY;
+
+pub mod stack;
+pub use stack::{Padding, Stack, StackDirection};
@@ -0,0 +1,412 @@
+//! A lightweight, declarative way to lay out a row or column of views, for the (very common)
+//! screens that are just "these things, one after another, with some breathing room". A `Stack`
+//! adds its children to a parent view and generates the constraints to pin them in place -
+//! edges to the parent (inset by the padding), and each child to the next (separated by the
+//! spacing).
+//!
+//! ```rust,no_run
+//! use cacao::layout::Stack;
+//! use cacao::input::TextField;
+//! use cacao::text::Label;
+//! use cacao::view::View;
+//!
+//! let content = View::new();
+//! let title = Label::new();
+//! let name = TextField::new();
+//!
+//! Stack::vertical()
+//!     .padding(16.)
+//!     .spacing(8.)
+//!     .child(&title)
+//!     .child(&name)
+//!     .apply(&content);
+//! ```
+//!
+//! Or, with the `layout!` macro, which expands to the same thing:
+//!
+//! ```rust,no_run
+//! # use cacao::layout;
+//! # use cacao::input::TextField;
+//! # use cacao::text::Label;
+//! # use cacao::view::View;
+//! # let content = View::new();
+//! # let title = Label::new();
+//! # let name = TextField::new();
+//! layout!(content => vertical(padding = 16., spacing = 8.) [
+//!     title,
+//!     name
+//! ]);
+//! ```
+//!
+//! Stacks nest by way of plain views: lay out a horizontal `Stack` inside a `View`, and put that
+//! `View` in a vertical one. Anything fancier, and you'll want to write the constraints yourself.
+
+use objc::runtime::Object;
+use objc::{msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutConstraint};
+
+/// Which way a `Stack` lays out its children.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StackDirection {
+    /// Leading to trailing, in a row.
+    Horizontal,
+
+    /// Top to bottom, in a column.
+    Vertical
+}
+
+/// Insets from each edge of a parent view.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Padding {
+    /// Inset from the top edge.
+    pub top: f64,
+
+    /// Inset from the leading edge.
+    pub leading: f64,
+
+    /// Inset from the bottom edge.
+    pub bottom: f64,
+
+    /// Inset from the trailing edge.
+    pub trailing: f64
+}
+
+impl Padding {
+    /// The same inset on every edge.
+    pub fn all(inset: f64) -> Self {
+        Padding {
+            top: inset,
+            leading: inset,
+            bottom: inset,
+            trailing: inset
+        }
+    }
+
+    /// One inset for the top and bottom edges, and another for the leading and trailing edges.
+    pub fn symmetric(vertical: f64, horizontal: f64) -> Self {
+        Padding {
+            top: vertical,
+            leading: horizontal,
+            bottom: vertical,
+            trailing: horizontal
+        }
+    }
+}
+
+impl From<f64> for Padding {
+    fn from(inset: f64) -> Self {
+        Padding::all(inset)
+    }
+}
+
+/// The anchors for a view, pulled from its backing node.
+struct Anchors {
+    top: LayoutAnchorY,
+    leading: LayoutAnchorX,
+    bottom: LayoutAnchorY,
+    trailing: LayoutAnchorX
+}
+
+impl Anchors {
+    fn new(node: &Object) -> Self {
+        Anchors {
+            top: LayoutAnchorY::new(unsafe { msg_send![node, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![node, leadingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![node, bottomAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![node, trailingAnchor] })
+        }
+    }
+}
+
+/// A row or column of views; see the module documentation.
+#[derive(Clone, Debug)]
+pub struct Stack {
+    direction: StackDirection,
+    padding: Padding,
+    spacing: f64,
+    children: Vec<ShareId<Object>>
+}
+
+impl Stack {
+    /// Creates an empty stack, laid out in `direction`.
+    pub fn new(direction: StackDirection) -> Self {
+        Stack {
+            direction: direction,
+            padding: Padding::default(),
+            spacing: 0.,
+            children: vec![]
+        }
+    }
+
+    /// Creates an empty row.
+    pub fn horizontal() -> Self {
+        Stack::new(StackDirection::Horizontal)
+    }
+
+    /// Creates an empty column.
+    pub fn vertical() -> Self {
+        Stack::new(StackDirection::Vertical)
+    }
+
+    /// Sets the insets from the parent's edges.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the space between children.
+    pub fn spacing<F: Into<f64>>(mut self, spacing: F) -> Self {
+        self.spacing = spacing.into();
+        self
+    }
+
+    /// Appends a child.
+    pub fn child<V: Layout>(mut self, view: &V) -> Self {
+        self.children.push(view.get_backing_node());
+        self
+    }
+
+    /// Adds the children to `parent` as subviews, in order. `apply` does this for you; you only
+    /// need it if you're working with `constraints` directly.
+    pub fn add_to<P: Layout>(&self, parent: &P) {
+        let parent_node = parent.get_backing_node();
+
+        for child in &self.children {
+            unsafe {
+                let _: () = msg_send![&*parent_node, addSubview:&**child];
+            }
+        }
+    }
+
+    /// Returns the constraints that lay the children out in `parent` - without activating them,
+    /// in case you'd like to adjust (or hold on to) them first. The children need to be in
+    /// `parent` before these are activated; see `add_to`.
+    pub fn constraints<P: Layout>(&self, parent: &P) -> Vec<LayoutConstraint> {
+        let parent_node = parent.get_backing_node();
+        let parent_anchors = Anchors::new(&parent_node);
+        let padding = self.padding;
+        let mut constraints = vec![];
+
+        for (index, child) in self.children.iter().enumerate() {
+            let anchors = Anchors::new(child);
+            let is_first = index == 0;
+            let is_last = index == self.children.len() - 1;
+
+            // The previous child, which this one follows on from.
+            let previous = match is_first {
+                true => None,
+                false => Some(Anchors::new(&self.children[index - 1]))
+            };
+
+            match self.direction {
+                StackDirection::Vertical => {
+                    constraints.push(anchors.leading.constraint_equal_to(&parent_anchors.leading).offset(padding.leading));
+                    constraints.push(anchors.trailing.constraint_equal_to(&parent_anchors.trailing).offset(-padding.trailing));
+
+                    constraints.push(match previous {
+                        Some(previous) => anchors.top.constraint_equal_to(&previous.bottom).offset(self.spacing),
+                        None => anchors.top.constraint_equal_to(&parent_anchors.top).offset(padding.top)
+                    });
+
+                    if is_last {
+                        constraints.push(anchors.bottom.constraint_equal_to(&parent_anchors.bottom).offset(-padding.bottom));
+                    }
+                },
+
+                StackDirection::Horizontal => {
+                    constraints.push(anchors.top.constraint_equal_to(&parent_anchors.top).offset(padding.top));
+                    constraints.push(anchors.bottom.constraint_equal_to(&parent_anchors.bottom).offset(-padding.bottom));
+
+                    constraints.push(match previous {
+                        Some(previous) => anchors.leading.constraint_equal_to(&previous.trailing).offset(self.spacing),
+                        None => anchors.leading.constraint_equal_to(&parent_anchors.leading).offset(padding.leading)
+                    });
+
+                    if is_last {
+                        constraints.push(anchors.trailing.constraint_equal_to(&parent_anchors.trailing).offset(-padding.trailing));
+                    }
+                }
+            }
+        }
+
+        constraints
+    }
+
+    /// Adds the children to `parent` as subviews, and activates the constraints that lay them out.
+    pub fn apply<P: Layout>(&self, parent: &P) {
+        self.add_to(parent);
+        LayoutConstraint::activate(&self.constraints(parent));
+    }
+}
+
+/// Lays out views in a row or column inside a parent view; shorthand for building and applying a
+/// `Stack`. The options (`padding`, `spacing`) map to the `Stack` methods of the same name, and
+/// can be left out - along with their parentheses - if you don't need any.
+///
+/// ```rust,no_run
+/// # use cacao::layout;
+/// # use cacao::button::Button;
+/// # use cacao::view::View;
+/// # let toolbar = View::new();
+/// # let back = Button::new("Back");
+/// # let forward = Button::new("Forward");
+/// layout!(toolbar => horizontal(spacing = 4.) [back, forward]);
+/// ```
+///
+/// ```rust,no_run
+/// # use cacao::layout;
+/// # use cacao::text::Label;
+/// # use cacao::view::View;
+/// # let content = View::new();
+/// # let title = Label::new();
+/// layout!(content => vertical [title]);
+/// ```
+#[macro_export]
+macro_rules! layout {
+    ($parent:expr => $direction:ident [$($child:expr),* $(,)?]) => {
+        $crate::layout!($parent => $direction() [$($child),*])
+    };
+
+    ($parent:expr => $direction:ident ($($option:ident = $value:expr),* $(,)?) [$($child:expr),* $(,)?]) => {
+        $crate::layout::Stack::$direction()
+            $(.$option($value))*
+            $(.child(&$child))*
+            .apply(&$parent)
+    };
+}
+
+#[cfg(all(test, feature = "headless"))]
+mod tests {
+    use objc::runtime::{Object, Sel};
+    use objc::{msg_send, sel, sel_impl};
+
+    use super::{Padding, Stack};
+    use crate::foundation::id;
+    use crate::headless::{self, Value};
+    use crate::layout::{Layout, LayoutConstraint};
+    use crate::view::View;
+
+    fn offsets(constraints: &[LayoutConstraint]) -> Vec<f64> {
+        constraints.iter().map(|constraint| constraint.offset).collect()
+    }
+
+    /// Returns whether `anchor` (e.g, `topAnchor`) on `view` was asked for a constraint.
+    fn constrained(view: &View, anchor: &str) -> bool {
+        let node = view.get_backing_node();
+        let anchor: id = unsafe {
+            msg_send![&*node, performSelector:Sel::register(anchor)]
+        };
+
+        headless::calls(unsafe { &*(anchor as *const Object) }).iter().any(|call| {
+            call.selector == "constraintEqualToAnchor:"
+        })
+    }
+
+    #[test]
+    fn padding() {
+        assert_eq!(Padding::from(4.), Padding::all(4.));
+        assert_eq!(Padding::symmetric(2., 8.), Padding { top: 2., leading: 8., bottom: 2., trailing: 8. });
+    }
+
+    #[test]
+    fn empty_stack_has_no_constraints() {
+        let parent = View::new();
+        assert!(Stack::vertical().padding(16.).constraints(&parent).is_empty());
+    }
+
+    #[test]
+    fn vertical_constraints() {
+        let parent = View::new();
+        let (first, second, third) = (View::new(), View::new(), View::new());
+
+        let constraints = Stack::vertical()
+            .padding(16.)
+            .spacing(8.)
+            .child(&first)
+            .child(&second)
+            .child(&third)
+            .constraints(&parent);
+
+        // Leading, trailing and top for each child (the top to the previous child, after the
+        // first), and the last child's bottom.
+        assert_eq!(offsets(&constraints), vec![
+            16., -16., 16.,
+            16., -16., 8.,
+            16., -16., 8., -16.
+        ]);
+
+        assert!(constrained(&first, "topAnchor"));
+        assert!(!constrained(&first, "bottomAnchor"));
+        assert!(!constrained(&second, "bottomAnchor"));
+        assert!(constrained(&third, "bottomAnchor"));
+    }
+
+    #[test]
+    fn horizontal_constraints() {
+        let parent = View::new();
+        let (first, second) = (View::new(), View::new());
+
+        let constraints = Stack::horizontal()
+            .padding(Padding::symmetric(4., 12.))
+            .spacing(6.)
+            .child(&first)
+            .child(&second)
+            .constraints(&parent);
+
+        // Top, bottom and leading for each child, and the last child's trailing.
+        assert_eq!(offsets(&constraints), vec![
+            4., -4., 12.,
+            4., -4., 6., -12.
+        ]);
+
+        assert!(constrained(&first, "leadingAnchor"));
+        assert!(!constrained(&first, "trailingAnchor"));
+        assert!(constrained(&second, "trailingAnchor"));
+    }
+
+    #[test]
+    fn constraints_leave_the_hierarchy_alone() {
+        let parent = View::new();
+        let child = View::new();
+        let node = parent.get_backing_node();
+        headless::clear_calls(&node);
+
+        let stack = Stack::vertical().child(&child);
+        let constraints = stack.constraints(&parent);
+
+        assert!(!headless::calls(&node).iter().any(|call| call.selector == "addSubview:"));
+        assert!(constraints.iter().all(|constraint| headless::property(&constraint.constraint, "active").is_none()));
+    }
+
+    #[test]
+    fn apply_adds_children_and_constrains_them() {
+        let parent = View::new();
+        let (first, second) = (View::new(), View::new());
+        let node = parent.get_backing_node();
+        headless::clear_calls(&node);
+
+        Stack::vertical().child(&first).child(&second).apply(&parent);
+
+        let added = headless::calls(&node).iter().filter(|call| call.selector == "addSubview:").count();
+        assert_eq!(added, 2);
+
+        assert!(constrained(&first, "topAnchor"));
+        assert!(constrained(&second, "bottomAnchor"));
+    }
+
+    #[test]
+    fn activating_constraints() {
+        let parent = View::new();
+        let child = View::new();
+
+        let constraints = Stack::horizontal().padding(10.).child(&child).constraints(&parent);
+        LayoutConstraint::activate(&constraints);
+
+        for constraint in &constraints {
+            assert_eq!(headless::property(&constraint.constraint, "active"), Some(Value::Bool(true)));
+            assert_eq!(headless::property(&constraint.constraint, "constant"), Some(Value::Float(constraint.offset)));
+        }
+    }
+}
@@ -1,9 +1,26 @@
 //! Various traits related to controllers opting in to autolayout routines and support for view
 //! heirarchies.
 
+use core_graphics::geometry::CGRect;
+
 use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
 use objc_id::ShareId;
 
+use crate::foundation::{id, nil, YES, NO, NSUInteger};
+use crate::geometry::Rect;
+use crate::image::Image;
+use crate::layout::AutoresizingMask;
+
+#[cfg(target_os = "macos")]
+use crate::foundation::NSData;
+
+#[cfg(target_os = "ios")]
+use block::ConcreteBlock;
+
+#[cfg(target_os = "ios")]
+use crate::foundation::BOOL;
+
 /// A trait that view wrappers must conform to. Enables managing the subview tree.
 pub trait Layout {
     /// Returns a reference to the backing Objective-C layer. This is optional, as we try to keep
@@ -13,4 +30,125 @@ pub trait Layout {
 
     /// This trait should implement adding a view to the subview tree for a given view.
     fn add_subview<V: Layout>(&self, _view: &V);
+
+    /// Positions this view at `rect`, in its superview's coordinates (which, for views in this
+    /// framework, have their origin at the top left).
+    ///
+    /// This is an escape hatch from Autolayout - for absolutely positioned overlays, or views you
+    /// move by hand as part of an animation. Views are created ready for constraints, which
+    /// ignore the frame; call `set_translates_autoresizing_mask_into_constraints(true)` first, so
+    /// that the frame sticks and anything constrained to this view follows it.
+    fn set_frame(&self, rect: Rect) {
+        let view = self.get_backing_node();
+        let frame: CGRect = rect.into();
+
+        unsafe {
+            let _: () = msg_send![&*view, setFrame:frame];
+        }
+    }
+
+    /// Returns where this view currently is, in its superview's coordinates - whether that was
+    /// set with `set_frame()`, or worked out by Autolayout.
+    fn frame(&self) -> Rect {
+        let view = self.get_backing_node();
+        let frame: CGRect = unsafe { msg_send![&*view, frame] };
+        frame.into()
+    }
+
+    /// Sets how a view positioned with `set_frame()` follows its superview as that's resized -
+    /// e.g, `&[AutoresizingMask::FlexibleWidth, AutoresizingMask::FlexibleHeight]` to fill it.
+    /// Only takes effect with `set_translates_autoresizing_mask_into_constraints(true)`.
+    fn set_autoresizing_mask(&self, mask: &[AutoresizingMask]) {
+        let view = self.get_backing_node();
+        let mask = mask.iter().fold(0, |mask, option| mask | NSUInteger::from(option));
+
+        unsafe {
+            let _: () = msg_send![&*view, setAutoresizingMask:mask];
+        }
+    }
+
+    /// Sets whether this view's frame and autoresizing mask are turned into constraints. Views in
+    /// this framework turn this off when they're created, so they can be laid out with anchors;
+    /// turn it back on to position this one with `set_frame()` instead. Don't constrain the
+    /// view's own position or size when this is on, as they'll conflict.
+    fn set_translates_autoresizing_mask_into_constraints(&self, translates: bool) {
+        let view = self.get_backing_node();
+
+        unsafe {
+            let _: () = msg_send![&*view, setTranslatesAutoresizingMaskIntoConstraints:match translates {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Renders this view (and its subviews) into an `Image`, at its current size - e.g, for
+    /// sharing a screenshot of a chart, or comparing against a known-good image in tests.
+    ///
+    /// On macOS, this uses `cacheDisplayInRect:toBitmapImageRep:`, which draws the view hierarchy
+    /// but not any layer-only content (e.g, a `CAMetalLayer`). On iOS, this uses
+    /// `drawViewHierarchyInRect:afterScreenUpdates:`, which captures what's on screen.
+    ///
+    /// Returns `None` if the view can't be rendered (e.g, it has no size yet).
+    fn snapshot(&self) -> Option<Image> {
+        let view = self.get_backing_node();
+
+        #[cfg(target_os = "macos")]
+        let image: id = unsafe {
+            let bounds: CGRect = msg_send![&*view, bounds];
+            let rep: id = msg_send![&*view, bitmapImageRepForCachingDisplayInRect:bounds];
+
+            if rep == nil {
+                return None;
+            }
+
+            let _: () = msg_send![&*view, cacheDisplayInRect:bounds toBitmapImageRep:rep];
+
+            let image: id = msg_send![class!(NSImage), alloc];
+            let image: id = msg_send![image, initWithSize:bounds.size];
+            let _: () = msg_send![image, addRepresentation:rep];
+            msg_send![image, autorelease]
+        };
+
+        #[cfg(target_os = "ios")]
+        let image: id = unsafe {
+            let bounds: CGRect = msg_send![&*view, bounds];
+            let target = &*view as *const Object as id;
+
+            let actions = ConcreteBlock::new(move |_context: id| {
+                let _: BOOL = msg_send![target, drawViewHierarchyInRect:bounds afterScreenUpdates:YES];
+            });
+            let actions = actions.copy();
+
+            let renderer: id = msg_send![class!(UIGraphicsImageRenderer), alloc];
+            let renderer: id = msg_send![renderer, initWithBounds:bounds];
+            let image: id = msg_send![renderer, imageWithActions:&*actions];
+            let _: () = msg_send![renderer, release];
+            image
+        };
+
+        match image == nil {
+            true => None,
+            false => Some(Image::with(image))
+        }
+    }
+
+    /// Renders this view (and its subviews) as a PDF, returning the bytes. Unlike `snapshot()`,
+    /// text and vector content stays resolution independent.
+    ///
+    /// Returns `None` if the view can't be rendered (e.g, it has no size yet).
+    #[cfg(target_os = "macos")]
+    fn snapshot_pdf(&self) -> Option<Vec<u8>> {
+        let view = self.get_backing_node();
+
+        let data: id = unsafe {
+            let bounds: CGRect = msg_send![&*view, bounds];
+            msg_send![&*view, dataWithPDFInsideRect:bounds]
+        };
+
+        match data == nil {
+            true => None,
+            false => Some(NSData::wrap(data).into_vec())
+        }
+    }
 }
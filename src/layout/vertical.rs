@@ -26,7 +26,7 @@ impl LayoutAnchorY {
     pub fn constraint_equal_to(&self, anchor_to: &LayoutAnchorY) -> LayoutConstraint {
         match (&self.0, &anchor_to.0) {
             (Some(from), Some(to)) => LayoutConstraint::new(unsafe {
-                let b: id = msg_send![*from, constraintEqualToAnchor:&*to.clone()];
+                let b: id = msg_send![*from, constraintEqualToAnchor:&*to];
                 b
             }),
 
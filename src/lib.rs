@@ -57,12 +57,44 @@
 //!
 //! The following are a list of [Cargo features][cargo-features] that can be enabled or disabled.
 //!
+//! Enabled by default (turn off `default-features` and pick the ones you need, e.g for a menu bar
+//! utility that only needs a status item and a window):
+//!
+//! - **macos**: AppKit support.
+//! - **filesystem**: `FileManager`, open/save panels, and iCloud Drive helpers.
+//! - **input**: The `TextField` control.
+//! - **listview**: The `ListView` control.
+//! - **networking**: `URLRequest`, and `URLSession`-backed downloads.
+//! - **progress**: The `ProgressIndicator` control.
+//! - **sharing**: The system share sheet.
+//! - **toolbar**: Window toolbars (and the preferences window helper, which is built on them).
+//!
+//! Opt-in:
+//!
+//! - **background-tasks**: Links `BackgroundTasks.framework` on iOS, for scheduling work to run
+//! while the app is in the background.
+//! - **camera**: Provides camera previews, photo capture and QR code scanning. Links
+//! `AVFoundation.framework`.
 //! - **cloudkit**: Links `CloudKit.framework` and provides some wrappers around CloudKit
 //! functionality. Currently not feature complete.
+//! - **headless**: Swaps the classes this framework registers for lightweight stand-ins that
+//! record what they're sent, so that view and delegate logic can be tested without a window
+//! server. For tests only; see `cacao::headless`.
+//! - **local-authentication**: Links `LocalAuthentication.framework` and provides a wrapper for
+//! authenticating the user via Touch ID, Face ID, or their passcode.
+//! - **photo-picker**: Provides a photo and video picker. Links `PhotosUI.framework` on iOS.
+//! - **serde**: Enables `serde`-based APIs: window state restoration, typed `UserDefaults` values,
+//! notification `userInfo` payloads, and XPC connections. Also implements `Serialize` and
+//! `Deserialize` for geometry, `Color`, `FontDescriptor` and `WindowConfig`.
+//! - **sound**: Provides sound effect playback. Links `AVFoundation.framework` and
+//! `AudioToolbox.framework` on iOS.
 //! - **user-notifications**: Links `UserNotifications.framework` and provides functionality for
 //! emitting notifications on macOS and iOS. Note that this _requires_ your application be
 //! code-signed, and will not work without it.
+//! - **web-authentication**: Links `AuthenticationServices.framework` for browser-based sign-in
+//! flows, and (on iOS) `SafariServices.framework` for an in-app Safari browser.
 //! - **webview**: Links `WebKit.framework` and provides a `WebView` control backed by `WKWebView`.
+//! Implies `networking`.
 //! - **webview-downloading**: Enables downloading files from the `WebView` via a private
 //! interface. This is not an App-Store-safe feature, so be aware of that before enabling.
 //!
@@ -79,36 +111,101 @@ pub use lazy_static;
 /// back the pointer and handle keeping the pools for themselves.
 pub type Node = objc_id::ShareId<objc::runtime::Object>;
 
+pub use foundation::autoreleasepool;
+
+// Declared first, so that its macros are visible to everything below.
+#[macro_use]
+pub(crate) mod utils;
+
 #[cfg(feature = "macos")]
 pub mod macos;
 
 #[cfg(feature = "ios")]
 pub mod ios;
 
+pub mod alert;
+
+#[cfg(feature = "macos")]
+pub mod appearance;
+
+pub mod binding;
+
 pub mod button;
 
+#[cfg(feature = "camera")]
+pub mod camera;
+
 #[cfg(feature = "cloudkit")]
 pub mod cloudkit;
 
 pub mod color;
+pub mod dispatch;
 pub mod dragdrop;
 pub mod error;
 pub mod events;
+pub mod executor;
 pub mod defaults;
+
+#[cfg(feature = "filesystem")]
 pub mod filesystem;
+
+pub mod formatter;
 pub mod foundation;
 pub mod geometry;
+pub mod group;
+
+#[cfg(feature = "headless")]
+pub mod headless;
+
 pub mod image;
+
+#[cfg(feature = "input")]
 pub mod input;
+
 pub(crate) mod invoker;
+pub mod kvo;
 pub mod layout;
+
+#[cfg(feature = "listview")]
 pub mod listview;
+
+#[cfg(feature = "local-authentication")]
+pub mod local_authentication;
+
+#[cfg(feature = "networking")]
 pub mod networking;
+
 pub mod notification_center;
+pub mod operation_queue;
+pub mod pageview;
 pub mod pasteboard;
+
+#[cfg(feature = "photo-picker")]
+pub mod photo_picker;
+
+pub mod process;
+
+#[cfg(feature = "progress")]
 pub mod progress;
+
+#[cfg(feature = "macos")]
+pub mod screen;
+
 pub mod scrollview;
+
+#[cfg(feature = "sharing")]
+pub mod sharing;
+
+#[cfg(feature = "sound")]
+pub mod sound;
+
+#[cfg(feature = "macos")]
+pub mod statusbar;
+
 pub mod text;
+pub mod timer;
+pub mod undo;
+pub mod uti;
 
 #[cfg(feature = "quicklook")]
 pub mod quicklook;
@@ -117,9 +214,11 @@ pub mod quicklook;
 pub mod user_notifications;
 
 pub mod user_activity;
-pub(crate) mod utils;
 
 pub mod view;
 
+#[cfg(feature = "web-authentication")]
+pub mod web_authentication;
+
 #[cfg(feature = "webview")]
 pub mod webview;
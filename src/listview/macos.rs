@@ -9,18 +9,17 @@
 
 use std::sync::Once;
 
-use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel, BOOL};
 use objc::{class, sel, sel_impl, msg_send};
 use objc_id::Id;
 
-use crate::foundation::{id, YES, NO, NSArray, NSInteger, NSUInteger};
+use crate::foundation::{autoreleasepool, id, nil, YES, NO, NSArray, NSInteger, NSUInteger};
 use crate::dragdrop::DragInfo;
 use crate::listview::{
     LISTVIEW_DELEGATE_PTR, LISTVIEW_CELL_VENDOR_PTR,
     ListViewDelegate, RowEdge
 };
-use crate::utils::load;
+use crate::utils::{load_or_register_class, load_or_register_class_for};
 
 /// Determines the number of items by way of the backing data source (the Rust struct).
 extern fn number_of_items<T: ListViewDelegate>(
@@ -28,7 +27,7 @@ extern fn number_of_items<T: ListViewDelegate>(
     _: Sel,
     _: id
 ) -> NSInteger {
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, LISTVIEW_DELEGATE_PTR, 0);
     view.number_of_items() as NSInteger
 }
 
@@ -39,17 +38,19 @@ extern fn view_for_column<T: ListViewDelegate>(
     _: id,
     item: NSInteger
 ) -> id {
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
-    let item = view.item_for(item as usize);
-
-    // A hacky method of returning the underlying pointer
-    // without Rust annoying us.
-    //
-    // @TODO: probably find a better way to do this. It's theoretically fine,
-    // as we *know* the underlying view will be retained by the NSTableView, so
-    // passing over one more won't really screw up retain counts.
+    let view = load_or_return!(T, this, LISTVIEW_DELEGATE_PTR, nil);
+
+    // Configuring a row tends to create temporaries (strings, images, and so on), so do it in a
+    // pool of its own - otherwise they pile up until the event loop drains, which during fast
+    // scrolling can be a while. The row itself has to outlive the pool, so we retain it inside,
+    // and hand it back autoreleased (as the table view expects).
     unsafe {
-        msg_send![&*item.objc, self]
+        let row: id = autoreleasepool(|| {
+            let row = view.item_for(item as usize);
+            msg_send![&*row.objc, retain]
+        });
+
+        msg_send![row, autorelease]
     }
 }
 
@@ -61,7 +62,7 @@ extern fn row_actions_for_row<T: ListViewDelegate>(
     edge: NSInteger
 ) -> id {
     let edge: RowEdge = edge.into();
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, LISTVIEW_DELEGATE_PTR, nil);
     
     let actions = view.actions_for(row as usize, edge);
 
@@ -80,7 +81,7 @@ extern fn enforce_normalcy(_: &Object, _: Sel) -> BOOL {
 
 /// Called when a drag/drop operation has entered this view.
 extern fn dragging_entered<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) -> NSUInteger {
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, LISTVIEW_DELEGATE_PTR, 0);
     view.dragging_entered(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     }).into()
@@ -88,7 +89,7 @@ extern fn dragging_entered<T: ListViewDelegate>(this: &mut Object, _: Sel, info:
 
 /// Called when a drag/drop operation has entered this view.
 extern fn prepare_for_drag_operation<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, LISTVIEW_DELEGATE_PTR, NO);
     
     match view.prepare_for_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -100,7 +101,7 @@ extern fn prepare_for_drag_operation<T: ListViewDelegate>(this: &mut Object, _:
 
 /// Called when a drag/drop operation has entered this view.
 extern fn perform_drag_operation<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, LISTVIEW_DELEGATE_PTR, NO);
         
     match view.perform_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -112,7 +113,7 @@ extern fn perform_drag_operation<T: ListViewDelegate>(this: &mut Object, _: Sel,
 
 /// Called when a drag/drop operation has entered this view.
 extern fn conclude_drag_operation<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) {
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, LISTVIEW_DELEGATE_PTR);
     
     view.conclude_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -121,7 +122,7 @@ extern fn conclude_drag_operation<T: ListViewDelegate>(this: &mut Object, _: Sel
 
 /// Called when a drag/drop operation has entered this view.
 extern fn dragging_exited<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) {
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, LISTVIEW_DELEGATE_PTR);
         
     view.dragging_exited(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -138,9 +139,7 @@ pub(crate) fn register_listview_class() -> *const Class {
 
     INIT.call_once(|| unsafe {
         let superclass = class!(NSTableView);
-        let mut decl = ClassDecl::new("RSTListView", superclass).unwrap();
-       
-        VIEW_CLASS = decl.register();
+        VIEW_CLASS = load_or_register_class(superclass, "RSTListView", |_| {});
     });
 
     unsafe {
@@ -152,37 +151,28 @@ pub(crate) fn register_listview_class() -> *const Class {
 /// need to do. Note that we treat and constrain this as a one-column "list" view to match
 /// `UITableView` semantics; if `NSTableView`'s multi column behavior is needed, then it can
 /// be added in.
-pub(crate) fn register_listview_class_with_delegate<T: ListViewDelegate>() -> *const Class {
-    static mut VIEW_CLASS: *const Class = 0 as *const Class;
-    static INIT: Once = Once::new();
-
-    INIT.call_once(|| unsafe {
-        let superclass = class!(NSTableView);
-        let mut decl = ClassDecl::new("RSTListViewWithDelegate", superclass).unwrap();
-
-        // A pointer to the "view controller" on the Rust side. It's expected that this doesn't
-        // move.
-        decl.add_ivar::<usize>(LISTVIEW_DELEGATE_PTR);
-        decl.add_ivar::<usize>(LISTVIEW_CELL_VENDOR_PTR);
-        
-        decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
-
-        // Tableview-specific
-        decl.add_method(sel!(numberOfRowsInTableView:), number_of_items::<T> as extern fn(&Object, _, id) -> NSInteger);
-        decl.add_method(sel!(tableView:viewForTableColumn:row:), view_for_column::<T> as extern fn(&Object, _, id, id, NSInteger) -> id);
-        decl.add_method(sel!(tableView:rowActionsForRow:edge:), row_actions_for_row::<T> as extern fn(&Object, _, id, NSInteger, NSInteger) -> id);
-
-        // Drag and drop operations (e.g, accepting files)
-        decl.add_method(sel!(draggingEntered:), dragging_entered::<T> as extern fn (&mut Object, _, _) -> NSUInteger);
-        decl.add_method(sel!(prepareForDragOperation:), prepare_for_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
-        decl.add_method(sel!(performDragOperation:), perform_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
-        decl.add_method(sel!(concludeDragOperation:), conclude_drag_operation::<T> as extern fn (&mut Object, _, _));
-        decl.add_method(sel!(draggingExited:), dragging_exited::<T> as extern fn (&mut Object, _, _));
-        
-        VIEW_CLASS = decl.register();
-    });
-
+pub(crate) fn register_listview_class_with_delegate<T: ListViewDelegate + 'static>() -> *const Class {
     unsafe {
-        VIEW_CLASS
+        let superclass = class!(NSTableView);
+        load_or_register_class_for::<T, _>(superclass, "RSTListViewWithDelegate", |decl| {
+            // A pointer to the "view controller" on the Rust side. It's expected that this doesn't
+            // move.
+            decl.add_ivar::<usize>(LISTVIEW_DELEGATE_PTR);
+            decl.add_ivar::<usize>(LISTVIEW_CELL_VENDOR_PTR);
+
+            decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
+
+            // Tableview-specific
+            decl.add_method(sel!(numberOfRowsInTableView:), number_of_items::<T> as extern fn(&Object, _, id) -> NSInteger);
+            decl.add_method(sel!(tableView:viewForTableColumn:row:), view_for_column::<T> as extern fn(&Object, _, id, id, NSInteger) -> id);
+            decl.add_method(sel!(tableView:rowActionsForRow:edge:), row_actions_for_row::<T> as extern fn(&Object, _, id, NSInteger, NSInteger) -> id);
+
+            // Drag and drop operations (e.g, accepting files)
+            decl.add_method(sel!(draggingEntered:), dragging_entered::<T> as extern fn (&mut Object, _, _) -> NSUInteger);
+            decl.add_method(sel!(prepareForDragOperation:), prepare_for_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
+            decl.add_method(sel!(performDragOperation:), perform_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
+            decl.add_method(sel!(concludeDragOperation:), conclude_drag_operation::<T> as extern fn (&mut Object, _, _));
+            decl.add_method(sel!(draggingExited:), dragging_exited::<T> as extern fn (&mut Object, _, _));
+        })
     }
 }
@@ -48,12 +48,15 @@ use objc_id::ShareId;
 use objc::runtime::{Class, Object};
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::{id, nil, YES, NO, NSArray, NSString, NSUInteger};
+use crate::binding::{diff, Binding, Observable};
+use crate::dispatch::{assert_main_thread, MainThreadHandle};
+use crate::foundation::{autoreleasepool, id, nil, YES, NO, NSArray, NSString, NSUInteger};
 use crate::color::Color;
+use crate::error::Error;
 use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
-use crate::pasteboard::PasteboardType;
 use crate::scrollview::ScrollView;
-use crate::utils::CGSize;
+use crate::utils::{clear_ptr, CGSize};
+use crate::uti::UTType;
 
 #[cfg(target_os = "macos")]
 mod macos;
@@ -83,7 +86,7 @@ pub(crate) static LISTVIEW_DELEGATE_PTR: &str = "rstListViewDelegatePtr";
 pub(crate) static LISTVIEW_CELL_VENDOR_PTR: &str = "rstListViewCellVendorPtr";
 
 use std::any::Any;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
 
 use std::rc::Rc;
 use std::cell::RefCell;
@@ -118,27 +121,53 @@ impl CellFactory {
         }));
     }
 
+    /// Vends a new cell of type `R` for `identifier`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if nothing is registered for `identifier`, or if what's registered isn't an `R`.
+    /// Use `try_get()` if you'd rather handle that.
     pub fn get<R>(&self, identifier: &'static str) -> Box<R>
+    where
+        R: ViewDelegate + 'static
+    {
+        match self.try_get(identifier) {
+            Ok(view) => view,
+            Err(e) => { panic!("{}", e); }
+        }
+    }
+
+    /// Vends a new cell of type `R` for `identifier`, or returns an error if nothing is
+    /// registered for it (or what's registered isn't an `R`).
+    pub fn try_get<R>(&self, identifier: &'static str) -> Result<Box<R>, Error>
     where
         R: ViewDelegate + 'static
     {
         let lock = self.0.borrow();
-        let vendor = match lock.get(identifier) {
-            Some(v) => v,
-            None => { 
-                panic!("Unable to dequeue cell of type {}: did you forget to register it?", identifier);
-            }
-        };
-        let view = vendor();
+        let vendor = lock.get(identifier).ok_or(Error::CellNotRegistered(identifier))?;
 
-        if let Ok(view) = view.downcast::<R>() {
-            view
-        } else {
-            panic!("Asking for cell of type {}, but failed to match the type!", identifier);
-        }
+        vendor().downcast::<R>().map_err(|_| {
+            Error::Downcast(format!("the cell registered for {} is not a {}", identifier, std::any::type_name::<R>()))
+        })
     }
 }
 
+/// Builds an `NSIndexSet` out of `indexes`. This happens inside its own autorelease pool, so that
+/// large batches don't leave temporaries lying around until the event loop drains.
+#[cfg(target_os = "macos")]
+fn index_set<I: IntoIterator<Item = usize>>(indexes: I) -> ShareId<Object> {
+    autoreleasepool(|| unsafe {
+        let index_set: id = msg_send![class!(NSMutableIndexSet), new];
+
+        for index in indexes {
+            let index = index as NSUInteger;
+            let _: () = msg_send![index_set, addIndex:index];
+        }
+
+        ShareId::from_retained_ptr(index_set)
+    })
+}
+
 /// A helper method for instantiating view classes and applying default settings to them.
 fn allocate_view(registration_fn: fn() -> *const Class) -> id { 
     unsafe {
@@ -160,9 +189,9 @@ fn allocate_view(registration_fn: fn() -> *const Class) -> id {
 
             // NSTableView requires at least one column to be manually added if doing so by code.
             // A relic of a bygone era, indeed.
-            let identifier = NSString::new("CacaoListViewColumn");
-            let default_column_alloc: id = msg_send![class!(NSTableColumn), new];
-            let default_column: id = msg_send![default_column_alloc, initWithIdentifier:identifier.into_inner()];
+            let identifier = NSString::no_copy("CacaoListViewColumn");
+            let default_column_alloc: id = msg_send![class!(NSTableColumn), alloc];
+            let default_column: id = msg_send![default_column_alloc, initWithIdentifier:&*identifier.0];
             let _: () = msg_send![default_column, setResizingMask:(1<<0)];
             let _: () = msg_send![tableview, addTableColumn:default_column];
         }
@@ -222,6 +251,8 @@ impl Default for ListView {
 impl ListView {
     /// Returns a default `View`, suitable for 
     pub fn new() -> Self {
+        assert_main_thread("ListView::new");
+
         let view = allocate_view(register_listview_class);
         
         #[cfg(target_os = "macos")]
@@ -265,6 +296,8 @@ impl<T> ListView<T> where T: ListViewDelegate + 'static {
     /// Initializes a new View with a given `ViewDelegate`. This enables you to respond to events
     /// and customize the view as a module, similar to class-based systems.
     pub fn with(delegate: T) -> ListView<T> {
+        assert_main_thread("ListView::with");
+
         let mut delegate = Box::new(delegate);
         let cell = CellFactory::new();
         
@@ -322,6 +355,19 @@ impl<T> ListView<T> where T: ListViewDelegate + 'static {
 }
 
 impl<T> ListView<T> {
+    /// Returns the underlying `NSTableView` (`UITableView` on iOS), for handing to other
+    /// frameworks (or Swift/Objective-C code). It isn't retained; it stays valid for as long as
+    /// this `ListView` keeps it alive.
+    ///
+    /// On macOS, the table lives inside an `NSScrollView` (see `scrollview`), which is what gets
+    /// added to the view hierarchy.
+    ///
+    /// There's no `into_objc()`, as the table gets its rows from the delegate here; a table that
+    /// outlives its `ListView` has nothing to show.
+    pub fn as_objc(&self) -> id {
+        &*self.objc as *const Object as id
+    }
+
     /// An internal method that returns a clone of this object, sans references to the delegate or
     /// callback pointer. We use this in calling `did_load()` - implementing delegates get a way to
     /// reference, customize and use the view but without the trickery of holding pieces of the
@@ -345,6 +391,12 @@ impl<T> ListView<T> {
         }
     }
 
+    /// Returns a handle to this list view that can be sent to other threads, and used to update it
+    /// from there; see `MainThreadHandle`.
+    pub fn handle(&self) -> ListViewHandle {
+        MainThreadHandle::new(self.clone_as_handle())
+    }
+
     /// Register a cell/row vendor function with an identifier. This is stored internally and used
     /// for row-reuse.
     pub fn register<F, R>(&self, identifier: &'static str, vendor: F)
@@ -356,20 +408,36 @@ impl<T> ListView<T> {
     }
 
     /// Dequeue a reusable cell. If one is not in the queue, will create and cache one for reuse.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no cell is registered for `identifier`. Use `try_dequeue()` if you'd rather
+    /// handle that.
     pub fn dequeue<R: ViewDelegate + 'static>(&self, identifier: &'static str) -> ListViewRow<R> {
+        match self.try_dequeue(identifier) {
+            Ok(view) => view,
+            Err(e) => { panic!("{}", e); }
+        }
+    }
+
+    /// Like `dequeue()`, but returns an error if no cell is registered for `identifier` (or the
+    /// registered cell isn't an `R`).
+    pub fn try_dequeue<R: ViewDelegate + 'static>(&self, identifier: &'static str) -> Result<ListViewRow<R>, Error> {
         #[cfg(target_os = "macos")]
         unsafe {
-            let key = NSString::new(identifier).into_inner();
-            let cell: id = msg_send![&*self.objc, makeViewWithIdentifier:key owner:nil];
+            let key = NSString::no_copy(identifier);
+            let cell: id = msg_send![&*self.objc, makeViewWithIdentifier:&*key.0 owner:nil];
             
             if cell != nil {
-                ListViewRow::from_cached(cell)
-            } else {
-                let delegate: Box<R> = self.cell_factory.get(identifier);
-                let view = ListViewRow::with_boxed(delegate);
-                view.set_identifier(identifier);
-                view
+                if let Some(view) = ListViewRow::from_cached(cell) {
+                    return Ok(view);
+                }
             }
+
+            let delegate: Box<R> = self.cell_factory.try_get(identifier)?;
+            let view = ListViewRow::with_boxed(delegate);
+            view.set_identifier(identifier);
+            Ok(view)
         }
     }
 
@@ -399,56 +467,27 @@ impl<T> ListView<T> {
     pub fn insert_rows<I: IntoIterator<Item = usize>>(&self, indexes: I, animation: RowAnimation) {
         #[cfg(target_os = "macos")]
         unsafe {
-            let index_set: id = msg_send![class!(NSMutableIndexSet), new];
-            
-            for index in indexes {
-                let x: NSUInteger = index as NSUInteger;
-                let _: () = msg_send![index_set, addIndex:x];
-            }
-
+            let rows = index_set(indexes);
             let animation_options: NSUInteger = animation.into();
-
-            // We need to temporarily retain this; it can drop after the underlying NSTableView
-            // has also retained it.
-            let x = ShareId::from_ptr(index_set);
-            let _: () = msg_send![&*self.objc, insertRowsAtIndexes:&*x withAnimation:animation_options];
+            let _: () = msg_send![&*self.objc, insertRowsAtIndexes:&*rows withAnimation:animation_options];
         }
     }
 
     pub fn reload_rows(&self, indexes: &[usize]) {
         #[cfg(target_os = "macos")]
         unsafe {
-            let index_set: id = msg_send![class!(NSMutableIndexSet), new];
-            
-            for index in indexes {
-                let x: NSUInteger = *index as NSUInteger;
-                let _: () = msg_send![index_set, addIndex:x];
-            }
-
-            let x = ShareId::from_ptr(index_set);
-
-            let ye: id = msg_send![class!(NSIndexSet), indexSetWithIndex:0];
-            let y = ShareId::from_ptr(ye);
-            let _: () = msg_send![&*self.objc, reloadDataForRowIndexes:&*x columnIndexes:&*y];
+            let rows = index_set(indexes.iter().cloned());
+            let columns = index_set(Some(0));
+            let _: () = msg_send![&*self.objc, reloadDataForRowIndexes:&*rows columnIndexes:&*columns];
         }
     }
 
     pub fn remove_rows<I: IntoIterator<Item = usize>>(&self, indexes: I, animations: RowAnimation) {
         #[cfg(target_os = "macos")]
         unsafe {
-            let index_set: id = msg_send![class!(NSMutableIndexSet), new];
-            
-            for index in indexes {
-                let x: NSUInteger = index as NSUInteger;
-                let _: () = msg_send![index_set, addIndex:x];
-            }
-
+            let rows = index_set(indexes);
             let animation_options: NSUInteger = animations.into();
-
-            // We need to temporarily retain this; it can drop after the underlying NSTableView
-            // has also retained it.
-            let x = ShareId::from_ptr(index_set);
-            let _: () = msg_send![&*self.objc, removeRowsAtIndexes:&*x withAnimation:animation_options];
+            let _: () = msg_send![&*self.objc, removeRowsAtIndexes:&*rows withAnimation:animation_options];
         }
     }
 
@@ -488,14 +527,13 @@ impl<T> ListView<T> {
         }
     }
 
-    /// Register this view for drag and drop operations.
-    pub fn register_for_dragged_types(&self, types: &[PasteboardType]) {
+    /// Register this view for drag and drop operations. Takes `UTType`s, or anything that
+    /// converts to one (e.g, `PasteboardType`).
+    pub fn register_for_dragged_types<U: Clone + Into<UTType>>(&self, types: &[U]) {
         unsafe {
-            let types: NSArray = types.into_iter().map(|t| {
-                // This clone probably doesn't need to be here, but it should also be cheap as
-                // this is just an enum... and this is not an oft called method.
-                let x: NSString = t.clone().into();
-                x.into_inner()
+            let types: NSArray = types.iter().map(|t| {
+                let uti: UTType = t.clone().into();
+                NSString::new(uti.identifier()).into_inner()
             }).collect::<Vec<id>>().into();
 
             let _: () = msg_send![&*self.objc, registerForDraggedTypes:types.into_inner()];
@@ -507,6 +545,44 @@ impl<T> ListView<T> {
             let _: () = msg_send![&*self.objc, reloadData];
         }
     }
+
+    /// Keeps the rows in sync with `items`, until the returned `Binding` is dropped. Your
+    /// `ListViewDelegate` should read from `items` (for `number_of_items()` and `item_for()`); this
+    /// takes care of telling the list view what changed - rows that were removed or inserted are
+    /// animated with `animation`, and everything else stays put. The value can be changed from
+    /// any thread; the list view is updated on the main thread.
+    pub fn bind_items<V>(&self, items: &Observable<Vec<V>>, animation: RowAnimation) -> Binding<Vec<V>>
+    where
+        V: Clone + PartialEq + Send + 'static
+    {
+        let handle = self.handle();
+        let source = items.clone();
+        let shown = Arc::new(Mutex::new(items.get()));
+
+        items.subscribe(move |_| {
+            let source = source.clone();
+            let shown = shown.clone();
+
+            // Diff against whatever's current by the time this runs on the main thread (rather
+            // than the value we were notified with), so that the delegate and the list view agree
+            // on the row count even if several changes land in between.
+            handle.update(move |list| {
+                let current = source.get();
+                let mut shown = shown.lock().unwrap();
+
+                match diff(&shown, &current) {
+                    Some((removed, inserted)) => list.perform_batch_updates(|list| {
+                        list.remove_rows(removed.iter().cloned(), animation);
+                        list.insert_rows(inserted.iter().cloned(), animation);
+                    }),
+
+                    None => list.reload()
+                }
+
+                *shown = current;
+            });
+        })
+    }
 }
 
 impl<T> Layout for ListView<T> {
@@ -540,9 +616,13 @@ impl<T> Drop for ListView<T> {
     /// this has a superview (i.e, it's in the heirarchy) on the AppKit side. If it does, we go
     /// ahead and remove it - this is intended to match the semantics of how Rust handles things).
     ///
-    /// There are, thankfully, no delegates we need to break here.
+    /// The delegate pointer on the Objective-C side is cleared too, so any callbacks that arrive
+    /// after this (e.g, if something else is still holding on to the view) are ignored.
     fn drop(&mut self) {
         if self.delegate.is_some() {
+            clear_ptr(&self.objc, LISTVIEW_DELEGATE_PTR);
+            clear_ptr(&self.objc, LISTVIEW_CELL_VENDOR_PTR);
+
             unsafe {
                 let superview: id = msg_send![&*self.objc, superview];
                 if superview != nil {
@@ -552,3 +632,13 @@ impl<T> Drop for ListView<T> {
         }
     }
 }
+
+/// A `ListView` handle that can be sent to other threads. Updates are applied on the main thread.
+pub type ListViewHandle = MainThreadHandle<ListView>;
+
+impl ListViewHandle {
+    /// Reloads the list's data, on the main thread.
+    pub fn reload(&self) {
+        self.update(|list| list.reload());
+    }
+}
@@ -9,7 +9,6 @@
 
 use std::sync::Once;
 
-use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel, BOOL};
 use objc::{class, msg_send, sel, sel_impl};
 use objc_id::Id;
@@ -17,7 +16,7 @@ use objc_id::Id;
 use crate::foundation::{id, YES, NO, NSUInteger};
 use crate::dragdrop::DragInfo;
 use crate::listview::row::{LISTVIEW_ROW_DELEGATE_PTR, ViewDelegate};
-use crate::utils::load;
+use crate::utils::{load_or_register_class, load_or_register_class_for};
 
 /// Enforces normalcy, or: a needlessly cruel method in terms of the name. You get the idea though.
 extern fn enforce_normalcy(_: &Object, _: Sel) -> BOOL {
@@ -26,7 +25,7 @@ extern fn enforce_normalcy(_: &Object, _: Sel) -> BOOL {
 
 /// Called when a drag/drop operation has entered this view.
 extern fn dragging_entered<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> NSUInteger {
-    let view = load::<T>(this, LISTVIEW_ROW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, LISTVIEW_ROW_DELEGATE_PTR, 0);
     view.dragging_entered(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     }).into()
@@ -34,7 +33,7 @@ extern fn dragging_entered<T: ViewDelegate>(this: &mut Object, _: Sel, info: id)
 
 /// Called when a drag/drop operation has entered this view.
 extern fn prepare_for_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
-    let view = load::<T>(this, LISTVIEW_ROW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, LISTVIEW_ROW_DELEGATE_PTR, NO);
     
     match view.prepare_for_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -46,7 +45,7 @@ extern fn prepare_for_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel,
 
 /// Called when a drag/drop operation has entered this view.
 extern fn perform_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
-    let view = load::<T>(this, LISTVIEW_ROW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, LISTVIEW_ROW_DELEGATE_PTR, NO);
         
     match view.perform_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -58,7 +57,7 @@ extern fn perform_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, inf
 
 /// Called when a drag/drop operation has entered this view.
 extern fn conclude_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) {
-    let view = load::<T>(this, LISTVIEW_ROW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, LISTVIEW_ROW_DELEGATE_PTR);
     
     view.conclude_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -67,7 +66,7 @@ extern fn conclude_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, in
 
 /// Called when a drag/drop operation has entered this view.
 extern fn dragging_exited<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) {
-    let view = load::<T>(this, LISTVIEW_ROW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, LISTVIEW_ROW_DELEGATE_PTR);
         
     view.dragging_exited(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -82,8 +81,10 @@ extern fn dealloc<T: ViewDelegate>(this: &Object, _: Sel) {
     // Load the Box pointer here, and just let it drop normally.
     unsafe {
         let ptr: usize = *(&*this).get_ivar(LISTVIEW_ROW_DELEGATE_PTR);
-        let obj = ptr as *mut T;
-        let _x = Box::from_raw(obj);
+        if ptr != 0 {
+            let obj = ptr as *mut T;
+            let _x = Box::from_raw(obj);
+        }
 
         let _: () = msg_send![super(this, class!(NSView)), dealloc];
     }
@@ -98,11 +99,9 @@ pub(crate) fn register_listview_row_class() -> *const Class {
 
     INIT.call_once(|| unsafe {
         let superclass = class!(NSView);
-        let mut decl = ClassDecl::new("RSTTableViewRow", superclass).unwrap();
-
-        decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
-    
-        VIEW_CLASS = decl.register();
+        VIEW_CLASS = load_or_register_class(superclass, "RSTTableViewRow", |decl| {
+            decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
+        });
     });
 
     unsafe { VIEW_CLASS }
@@ -110,34 +109,25 @@ pub(crate) fn register_listview_row_class() -> *const Class {
 
 /// Injects an `NSView` subclass, with some callback and pointer ivars for what we
 /// need to do.
-pub(crate) fn register_listview_row_class_with_delegate<T: ViewDelegate>() -> *const Class {
-    static mut VIEW_CLASS: *const Class = 0 as *const Class;
-    static INIT: Once = Once::new();
-
-    INIT.call_once(|| unsafe {
-        let superclass = class!(NSView);
-        let mut decl = ClassDecl::new("RSTableViewRowWithDelegate", superclass).unwrap();
-
-        // A pointer to the "view controller" on the Rust side. It's expected that this doesn't
-        // move.
-        decl.add_ivar::<usize>(LISTVIEW_ROW_DELEGATE_PTR);
-        
-        decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
-
-        // Drag and drop operations (e.g, accepting files)
-        decl.add_method(sel!(draggingEntered:), dragging_entered::<T> as extern fn (&mut Object, _, _) -> NSUInteger);
-        decl.add_method(sel!(prepareForDragOperation:), prepare_for_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
-        decl.add_method(sel!(performDragOperation:), perform_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
-        decl.add_method(sel!(concludeDragOperation:), conclude_drag_operation::<T> as extern fn (&mut Object, _, _));
-        decl.add_method(sel!(draggingExited:), dragging_exited::<T> as extern fn (&mut Object, _, _));
-        
-        // Cleanup
-        decl.add_method(sel!(dealloc), dealloc::<T> as extern fn (&Object, _));
-
-        VIEW_CLASS = decl.register();
-    });
-
+pub(crate) fn register_listview_row_class_with_delegate<T: ViewDelegate + 'static>() -> *const Class {
     unsafe {
-        VIEW_CLASS
+        let superclass = class!(NSView);
+        load_or_register_class_for::<T, _>(superclass, "RSTableViewRowWithDelegate", |decl| {
+            // A pointer to the "view controller" on the Rust side. It's expected that this doesn't
+            // move.
+            decl.add_ivar::<usize>(LISTVIEW_ROW_DELEGATE_PTR);
+
+            decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
+
+            // Drag and drop operations (e.g, accepting files)
+            decl.add_method(sel!(draggingEntered:), dragging_entered::<T> as extern fn (&mut Object, _, _) -> NSUInteger);
+            decl.add_method(sel!(prepareForDragOperation:), prepare_for_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
+            decl.add_method(sel!(performDragOperation:), perform_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
+            decl.add_method(sel!(concludeDragOperation:), conclude_drag_operation::<T> as extern fn (&mut Object, _, _));
+            decl.add_method(sel!(draggingExited:), dragging_exited::<T> as extern fn (&mut Object, _, _));
+
+            // Cleanup
+            decl.add_method(sel!(dealloc), dealloc::<T> as extern fn (&Object, _));
+        })
     }
 }
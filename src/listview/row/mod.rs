@@ -45,10 +45,12 @@ use objc_id::ShareId;
 use objc::runtime::{Class, Object};
 use objc::{msg_send, sel, sel_impl};
 
+use crate::dispatch::assert_main_thread;
 use crate::foundation::{id, nil, YES, NO, NSArray, NSString};
 use crate::color::Color;
 use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
-use crate::pasteboard::PasteboardType;
+use crate::utils::clear_ptr;
+use crate::uti::UTType;
 use crate::view::ViewDelegate;
 
 #[cfg(target_os = "macos")]
@@ -123,6 +125,8 @@ impl Default for ListViewRow {
 impl ListViewRow {
     /// Returns a default `View`, suitable for 
     pub fn new() -> Self {
+        assert_main_thread("ListViewRow::new");
+
         let view = allocate_view(register_listview_row_class);
 
         ListViewRow {
@@ -151,9 +155,16 @@ impl<T> ListViewRow<T> where T: ViewDelegate + 'static {
     /// - It takes ownership of the returned row in row_for_item
     /// - When it takes ownership, it "forgets" the pointer - and the `dealloc` method on the
     /// backing view cell will clean it up whenever it's dropped.
-    pub(crate) fn from_cached(view: id) -> ListViewRow<T> {
+    ///
+    /// Returns `None` if the delegate is gone (i.e, a dequeued row was dropped rather than
+    /// returned to the system), in which case the cell can't be reused.
+    pub(crate) fn from_cached(view: id) -> Option<ListViewRow<T>> {
         let delegate = unsafe {
             let ptr: usize = *(&*view).get_ivar(LISTVIEW_ROW_DELEGATE_PTR);
+            if ptr == 0 {
+                return None;
+            }
+
             let obj = ptr as *mut T;
             Box::from_raw(obj)
             //&*obj
@@ -173,10 +184,12 @@ impl<T> ListViewRow<T> where T: ViewDelegate + 'static {
             objc: unsafe { ShareId::from_ptr(view) },
         };
 
-        view
+        Some(view)
     }
 
     pub fn with(delegate: T) -> ListViewRow<T> {
+        assert_main_thread("ListViewRow::with");
+
         let delegate = Box::new(delegate);
         Self::with_boxed(delegate)
     }
@@ -261,10 +274,10 @@ impl<T> ListViewRow<T> {
 
     /// Sets the identifier, which enables cells to be reused and dequeued properly.
     pub fn set_identifier(&self, identifier: &'static str) {
-        let identifier = NSString::new(identifier).into_inner();
+        let identifier = NSString::no_copy(identifier);
 
         unsafe {
-            let _: () = msg_send![&*self.objc, setIdentifier:identifier];
+            let _: () = msg_send![&*self.objc, setIdentifier:&*identifier.0];
         }
     }
 
@@ -279,14 +292,13 @@ impl<T> ListViewRow<T> {
         }
     }
 
-    /// Register this view for drag and drop operations.
-    pub fn register_for_dragged_types(&self, types: &[PasteboardType]) {
+    /// Register this view for drag and drop operations. Takes `UTType`s, or anything that
+    /// converts to one (e.g, `PasteboardType`).
+    pub fn register_for_dragged_types<U: Clone + Into<UTType>>(&self, types: &[U]) {
         unsafe {
-            let types: NSArray = types.into_iter().map(|t| {
-                // This clone probably doesn't need to be here, but it should also be cheap as
-                // this is just an enum... and this is not an oft called method.
-                let x: NSString = t.clone().into();
-                x.into_inner()
+            let types: NSArray = types.iter().map(|t| {
+                let uti: UTType = t.clone().into();
+                NSString::new(uti.identifier()).into_inner()
             }).collect::<Vec<id>>().into();
 
             let _: () = msg_send![&*self.objc, registerForDraggedTypes:types.into_inner()];
@@ -309,6 +321,12 @@ impl<T> Layout for ListViewRow<T> {
 }
 
 impl<T> Drop for ListViewRow<T> {
+    /// If this row still owns its delegate (i.e, it was never handed back to the system), the
+    /// delegate is dropped along with it - so we clear the pointer on the Objective-C side, which
+    /// means any callbacks that arrive after this are ignored (and the cell won't be reused).
     fn drop(&mut self) {
+        if self.delegate.is_some() {
+            clear_ptr(&self.objc, LISTVIEW_ROW_DELEGATE_PTR);
+        }
     }
 }
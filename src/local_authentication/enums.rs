@@ -0,0 +1,46 @@
+//! Enums used when checking for and requesting local authentication.
+
+use crate::foundation::NSInteger;
+
+/// What the user has to do to authenticate.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AuthenticationPolicy {
+    /// Touch ID or Face ID only. Fails if biometrics aren't available or enrolled.
+    Biometrics,
+
+    /// Touch ID or Face ID, falling back to the device passcode (or, on macOS, the user's password
+    /// or a paired Apple Watch) if biometrics aren't available.
+    DeviceOwner
+}
+
+impl From<AuthenticationPolicy> for NSInteger {
+    fn from(policy: AuthenticationPolicy) -> Self {
+        match policy {
+            AuthenticationPolicy::Biometrics => 1,
+            AuthenticationPolicy::DeviceOwner => 2
+        }
+    }
+}
+
+/// The type of biometric authentication the device supports.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BiometryType {
+    /// The device doesn't support biometric authentication.
+    None,
+
+    /// Touch ID.
+    TouchId,
+
+    /// Face ID.
+    FaceId
+}
+
+impl From<NSInteger> for BiometryType {
+    fn from(biometry: NSInteger) -> Self {
+        match biometry {
+            1 => BiometryType::TouchId,
+            2 => BiometryType::FaceId,
+            _ => BiometryType::None
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Typed errors for local authentication, mapped from `LAError` codes.
+
+use std::error;
+use std::fmt;
+
+use objc::{msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, NSInteger, NSString};
+
+/// Why authentication failed (or couldn't be attempted).
+#[derive(Clone, Debug, PartialEq)]
+pub enum AuthenticationError {
+    /// The user failed to provide valid credentials.
+    AuthenticationFailed,
+
+    /// The user cancelled.
+    UserCancel,
+
+    /// The user chose the fallback option (e.g, "Enter Password"). You should offer your own
+    /// way of authenticating.
+    UserFallback,
+
+    /// The system cancelled authentication, e.g because another app came to the foreground.
+    SystemCancel,
+
+    /// A passcode (or password) isn't set on the device.
+    PasscodeNotSet,
+
+    /// Biometry isn't available on the device.
+    BiometryNotAvailable,
+
+    /// The user hasn't enrolled any fingerprints or faces.
+    BiometryNotEnrolled,
+
+    /// Biometry is locked out after too many failed attempts; the user has to enter their
+    /// passcode to unlock it.
+    BiometryLockout,
+
+    /// The app cancelled authentication.
+    AppCancel,
+
+    /// The authentication context was invalidated.
+    InvalidContext,
+
+    /// Authentication required showing UI, which wasn't allowed.
+    NotInteractive,
+
+    /// Any other error, with its code and description.
+    Other(NSInteger, String)
+}
+
+impl AuthenticationError {
+    /// Maps an `NSError` in the `LAErrorDomain` to an `AuthenticationError`.
+    pub(crate) fn new(error: id) -> Self {
+        if error == nil {
+            return AuthenticationError::Other(0, "An unknown error occurred.".to_string());
+        }
+
+        let code: NSInteger = unsafe { msg_send![error, code] };
+
+        match code {
+            -1 => AuthenticationError::AuthenticationFailed,
+            -2 => AuthenticationError::UserCancel,
+            -3 => AuthenticationError::UserFallback,
+            -4 => AuthenticationError::SystemCancel,
+            -5 => AuthenticationError::PasscodeNotSet,
+            -6 => AuthenticationError::BiometryNotAvailable,
+            -7 => AuthenticationError::BiometryNotEnrolled,
+            -8 => AuthenticationError::BiometryLockout,
+            -9 => AuthenticationError::AppCancel,
+            -10 => AuthenticationError::InvalidContext,
+            -1004 => AuthenticationError::NotInteractive,
+
+            code => AuthenticationError::Other(code, NSString::wrap(unsafe {
+                msg_send![error, localizedDescription]
+            }).to_string())
+        }
+    }
+}
+
+impl fmt::Display for AuthenticationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthenticationError::AuthenticationFailed => write!(f, "Authentication failed."),
+            AuthenticationError::UserCancel => write!(f, "The user cancelled authentication."),
+            AuthenticationError::UserFallback => write!(f, "The user chose the fallback option."),
+            AuthenticationError::SystemCancel => write!(f, "The system cancelled authentication."),
+            AuthenticationError::PasscodeNotSet => write!(f, "No passcode is set on this device."),
+            AuthenticationError::BiometryNotAvailable => write!(f, "Biometry is not available on this device."),
+            AuthenticationError::BiometryNotEnrolled => write!(f, "No biometric identities are enrolled."),
+            AuthenticationError::BiometryLockout => write!(f, "Biometry is locked out after too many failed attempts."),
+            AuthenticationError::AppCancel => write!(f, "The app cancelled authentication."),
+            AuthenticationError::InvalidContext => write!(f, "The authentication context is invalid."),
+            AuthenticationError::NotInteractive => write!(f, "Authentication requires user interaction, which isn't allowed."),
+            AuthenticationError::Other(code, description) => write!(f, "{} ({})", description, code)
+        }
+    }
+}
+
+impl error::Error for AuthenticationError {}
@@ -0,0 +1,127 @@
+//! Wraps `LocalAuthentication.framework`, for gating sensitive actions behind Touch ID, Face ID,
+//! or the device passcode.
+//!
+//! ```rust,no_run
+//! use cacao::local_authentication::{AuthenticationPolicy, BiometricAuth};
+//!
+//! let auth = BiometricAuth::new(AuthenticationPolicy::DeviceOwner);
+//!
+//! if auth.is_available() {
+//!     auth.evaluate("unlock your vault", |result| match result {
+//!         Ok(_) => { /* Show the vault... */ },
+//!         Err(e) => eprintln!("Couldn't authenticate: {}", e)
+//!     });
+//! }
+//! ```
+//!
+//! On iOS, using Face ID requires an `NSFaceIDUsageDescription` entry in your `Info.plist`.
+
+use block::ConcreteBlock;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, YES, NO, BOOL, NSInteger, NSString};
+
+mod enums;
+pub use enums::{AuthenticationPolicy, BiometryType};
+
+mod error;
+pub use error::AuthenticationError;
+
+/// Wraps an `LAContext`, which handles checking for and requesting authentication with a given
+/// policy. A successful authentication is reused by later evaluations on the same `BiometricAuth`
+/// for a short time, so create a new one if you need the user to authenticate again.
+#[derive(Clone, Debug)]
+pub struct BiometricAuth {
+    /// The underlying `LAContext`.
+    pub objc: ShareId<Object>,
+
+    /// The policy to evaluate.
+    pub policy: AuthenticationPolicy
+}
+
+impl BiometricAuth {
+    /// Creates a new authentication context for the given policy.
+    pub fn new(policy: AuthenticationPolicy) -> Self {
+        BiometricAuth {
+            objc: unsafe {
+                let context: id = msg_send![class!(LAContext), new];
+                ShareId::from_retained_ptr(context)
+            },
+
+            policy: policy
+        }
+    }
+
+    /// Checks whether authentication can be attempted with this policy - e.g, whether the device
+    /// supports biometry, and whether the user has enrolled. Returns the reason if it can't.
+    pub fn availability(&self) -> Result<(), AuthenticationError> {
+        let policy: NSInteger = self.policy.into();
+        let mut error: id = nil;
+
+        let result: BOOL = unsafe {
+            msg_send![&*self.objc, canEvaluatePolicy:policy error:&mut error]
+        };
+
+        match result {
+            YES => Ok(()),
+            NO => Err(AuthenticationError::new(error)),
+            _ => unreachable!()
+        }
+    }
+
+    /// Returns whether authentication can be attempted with this policy.
+    pub fn is_available(&self) -> bool {
+        self.availability().is_ok()
+    }
+
+    /// Returns the kind of biometry the device supports. Note that this is only set after
+    /// checking availability (which this does for you).
+    pub fn biometry_type(&self) -> BiometryType {
+        let _ = self.availability();
+        let biometry: NSInteger = unsafe { msg_send![&*self.objc, biometryType] };
+        biometry.into()
+    }
+
+    /// Sets the title of the fallback button (e.g, "Enter Password"). Pass an empty string to
+    /// hide it.
+    pub fn set_fallback_title(&self, title: &str) {
+        let title = NSString::new(title);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setLocalizedFallbackTitle:title];
+        }
+    }
+
+    /// Asks the user to authenticate, showing `reason` in the prompt (e.g, "unlock your vault").
+    /// `callback` is called with the result, on a background thread.
+    pub fn evaluate<F>(&self, reason: &str, callback: F)
+    where
+        F: Fn(Result<(), AuthenticationError>) + Send + Sync + 'static
+    {
+        let policy: NSInteger = self.policy.into();
+        let reason = NSString::new(reason);
+
+        let block = ConcreteBlock::new(move |success: BOOL, error: id| {
+            callback(match success {
+                YES => Ok(()),
+                _ => Err(AuthenticationError::new(error))
+            });
+        });
+        let block = block.copy();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, evaluatePolicy:policy localizedReason:reason reply:block];
+        }
+    }
+
+    /// Cancels any in-progress authentication. The pending `evaluate()` callback receives
+    /// `AuthenticationError::AppCancel`.
+    pub fn invalidate(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, invalidate];
+        }
+    }
+}
@@ -5,8 +5,8 @@
 use std::sync::Once;
 
 use objc::class;
-use objc::declare::ClassDecl;
 use objc::runtime::{Class};
+use crate::utils::load_or_register_class;
 
 /// Used for injecting a custom NSApplication. Currently does nothing.
 pub(crate) fn register_app_class() -> *const Class {
@@ -15,8 +15,7 @@ pub(crate) fn register_app_class() -> *const Class {
 
     INIT.call_once(|| unsafe {
         let superclass = class!(NSApplication);
-        let decl = ClassDecl::new("RSTApplication", superclass).unwrap();
-        APP_CLASS = decl.register();
+        APP_CLASS = load_or_register_class(superclass, "RSTApplication", |_| {});
     });
 
     unsafe {
@@ -9,7 +9,6 @@ use std::unreachable;
 use block::Block;
 
 use objc::{class, msg_send, sel, sel_impl};
-use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel};
 
 use url::Url;
@@ -22,6 +21,7 @@ use crate::user_activity::UserActivity;
 
 #[cfg(feature = "cloudkit")]
 use crate::cloudkit::share::CKShareMetaData;
+use crate::utils::load_or_register_class;
 
 /// A handy method for grabbing our `AppDelegate` from the pointer. This is different from our
 /// standard `utils` version as this doesn't require `RefCell` backing.
@@ -200,17 +200,27 @@ extern fn accepted_cloudkit_share<T: AppDelegate>(this: &Object, _: Sel, _: id,
     app::<T>(this).user_accepted_cloudkit_share(share);
 }
 
-/// Fires when the application receives an `application:openURLs` message.
+/// Fires when the application receives an `application:openURLs` message. Since implementing this
+/// means AppKit won't send `application:openFiles:`, file URLs are split off and routed to
+/// `open_files`.
 extern fn open_urls<T: AppDelegate>(this: &Object, _: Sel, _: id, file_urls: id) {
-    let urls = NSArray::wrap(file_urls).map(|url| {
+    let (files, urls): (Vec<Url>, Vec<Url>) = NSArray::wrap(file_urls).map(|url| {
         let uri = NSString::wrap(unsafe {
             msg_send![url, absoluteString]
         });
         
         Url::parse(uri.to_str())
-    }).into_iter().filter_map(|url| url.ok()).collect();
+    }).into_iter().filter_map(|url| url.ok()).partition(|url| url.scheme() == "file");
 
-    app::<T>(this).open_urls(urls);
+    let delegate = app::<T>(this);
+
+    if !files.is_empty() {
+        delegate.open_files(files.into_iter().filter_map(|url| url.to_file_path().ok()).collect());
+    }
+
+    if !urls.is_empty() {
+        delegate.open_urls(urls);
+    }
 }
 
 /// Fires when the application receives an `application:openFileWithoutUI:` message.
@@ -280,6 +290,14 @@ extern fn did_change_occlusion_state<T: AppDelegate>(this: &Object, _: Sel, _: i
     app::<T>(this).occlusion_state_changed();
 }
 
+/// Called when the application receives an `applicationSupportsSecureRestorableState:` message.
+extern fn supports_secure_restorable_state<T: AppDelegate>(this: &Object, _: Sel, _: id) -> BOOL {
+    match app::<T>(this).supports_secure_restorable_state() {
+        true => YES,
+        false => NO
+    }
+}
+
 /// Called when the application receives an `application:delegateHandlesKey:` message.
 /// Note: this may not fire in sandboxed applications. Apple's documentation is unclear on the
 /// matter.
@@ -300,78 +318,76 @@ pub(crate) fn register_app_delegate_class<T: AppDelegate + AppDelegate>() -> *co
 
     INIT.call_once(|| unsafe {
         let superclass = class!(NSObject);
-        let mut decl = ClassDecl::new("RSTAppDelegate", superclass).unwrap();
-
-        decl.add_ivar::<usize>(APP_PTR);
-
-        // Launching Applications
-        decl.add_method(sel!(applicationWillFinishLaunching:), will_finish_launching::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationDidFinishLaunching:), did_finish_launching::<T> as extern fn(&Object, _, _));
-        
-        // Managing Active Status
-        decl.add_method(sel!(applicationWillBecomeActive:), will_become_active::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationDidBecomeActive:), did_become_active::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationWillResignActive:), will_resign_active::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationDidResignActive:), did_resign_active::<T> as extern fn(&Object, _, _));
-
-        // Terminating Applications
-        decl.add_method(sel!(applicationShouldTerminate:), should_terminate::<T> as extern fn(&Object, _, _) -> NSUInteger);
-        decl.add_method(sel!(applicationWillTerminate:), will_terminate::<T> as extern fn(&Object, _, _));
-
-        // Hiding Applications
-        decl.add_method(sel!(applicationWillHide:), will_hide::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationDidHide:), did_hide::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationWillUnhide:), will_unhide::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationDidUnhide:), did_unhide::<T> as extern fn(&Object, _, _));
-
-        // Managing Windows
-        decl.add_method(sel!(applicationWillUpdate:), will_update::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationDidUpdate:), did_update::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationShouldHandleReopen:hasVisibleWindows:), should_handle_reopen::<T> as extern fn(&Object, _, _, BOOL) -> BOOL);
-
-        // Dock Menu
-        decl.add_method(sel!(applicationDockMenu:), dock_menu::<T> as extern fn(&Object, _, _) -> id);
-
-        // Displaying Errors
-        decl.add_method(sel!(application:willPresentError:), will_present_error::<T> as extern fn(&Object, _, _, id) -> id);
-
-        // Managing the Screen
-        decl.add_method(sel!(applicationDidChangeScreenParameters:), did_change_screen_parameters::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationDidChangeOcclusionState:), did_change_occlusion_state::<T> as extern fn(&Object, _, _));
-
-        // User Activities
-        decl.add_method(sel!(application:willContinueUserActivityWithType:), will_continue_user_activity_with_type::<T> as extern fn(&Object, _, _, id) -> BOOL);
-        decl.add_method(sel!(application:continueUserActivity:restorationHandler:), continue_user_activity::<T> as extern fn(&Object, _, _, id, id) -> BOOL);
-        decl.add_method(sel!(application:didFailToContinueUserActivityWithType:error:), failed_to_continue_user_activity::<T> as extern fn(&Object, _, _, id, id));
-        decl.add_method(sel!(application:didUpdateUserActivity:), did_update_user_activity::<T> as extern fn(&Object, _, _, id));
-
-        // Handling push notifications
-        decl.add_method(sel!(application:didRegisterForRemoteNotificationsWithDeviceToken:), registered_for_remote_notifications::<T> as extern fn(&Object, _, _, id));
-        decl.add_method(sel!(application:didFailToRegisterForRemoteNotificationsWithError:), failed_to_register_for_remote_notifications::<T> as extern fn(&Object, _, _, id));
-        decl.add_method(sel!(application:didReceiveRemoteNotification:), did_receive_remote_notification::<T> as extern fn(&Object, _, _, id));
-
-        // CloudKit
-        #[cfg(feature = "cloudkit")]
-        decl.add_method(sel!(application:userDidAcceptCloudKitShareWithMetadata:), accepted_cloudkit_share::<T> as extern fn(&Object, _, _, id));
-
-        // Opening Files
-        decl.add_method(sel!(application:openURLs:), open_urls::<T> as extern fn(&Object, _, _, id));
-        decl.add_method(sel!(application:openFileWithoutUI:), open_file_without_ui::<T> as extern fn(&Object, _, _, id) -> BOOL);
-        decl.add_method(sel!(applicationShouldOpenUntitledFile:), should_open_untitled_file::<T> as extern fn(&Object, _, _) -> BOOL);
-        decl.add_method(sel!(applicationOpenUntitledFile:), open_untitled_file::<T> as extern fn(&Object, _, _) -> BOOL);
-        decl.add_method(sel!(application:openTempFile:), open_temp_file::<T> as extern fn(&Object, _, _, id) -> BOOL);
-
-        // Printing
-        decl.add_method(sel!(application:printFile:), print_file::<T> as extern fn(&Object, _, _, id) -> BOOL);
-        decl.add_method(sel!(application:printFiles:withSettings:showPrintPanels:), print_files::<T> as extern fn(&Object, _, id, id, id, BOOL) -> NSUInteger);
-
-        // @TODO: Restoring Application State
-        // Depends on NSCoder support, which is... welp.
-
-        // Scripting
-        decl.add_method(sel!(application:delegateHandlesKey:), delegate_handles_key::<T> as extern fn(&Object, _, _, id) -> BOOL);
-
-        DELEGATE_CLASS = decl.register();
+        DELEGATE_CLASS = load_or_register_class(superclass, "RSTAppDelegate", |decl| {
+            decl.add_ivar::<usize>(APP_PTR);
+
+            // Launching Applications
+            decl.add_method(sel!(applicationWillFinishLaunching:), will_finish_launching::<T> as extern fn(&Object, _, _));
+            decl.add_method(sel!(applicationDidFinishLaunching:), did_finish_launching::<T> as extern fn(&Object, _, _));
+
+            // Managing Active Status
+            decl.add_method(sel!(applicationWillBecomeActive:), will_become_active::<T> as extern fn(&Object, _, _));
+            decl.add_method(sel!(applicationDidBecomeActive:), did_become_active::<T> as extern fn(&Object, _, _));
+            decl.add_method(sel!(applicationWillResignActive:), will_resign_active::<T> as extern fn(&Object, _, _));
+            decl.add_method(sel!(applicationDidResignActive:), did_resign_active::<T> as extern fn(&Object, _, _));
+
+            // Terminating Applications
+            decl.add_method(sel!(applicationShouldTerminate:), should_terminate::<T> as extern fn(&Object, _, _) -> NSUInteger);
+            decl.add_method(sel!(applicationWillTerminate:), will_terminate::<T> as extern fn(&Object, _, _));
+
+            // Hiding Applications
+            decl.add_method(sel!(applicationWillHide:), will_hide::<T> as extern fn(&Object, _, _));
+            decl.add_method(sel!(applicationDidHide:), did_hide::<T> as extern fn(&Object, _, _));
+            decl.add_method(sel!(applicationWillUnhide:), will_unhide::<T> as extern fn(&Object, _, _));
+            decl.add_method(sel!(applicationDidUnhide:), did_unhide::<T> as extern fn(&Object, _, _));
+
+            // Managing Windows
+            decl.add_method(sel!(applicationWillUpdate:), will_update::<T> as extern fn(&Object, _, _));
+            decl.add_method(sel!(applicationDidUpdate:), did_update::<T> as extern fn(&Object, _, _));
+            decl.add_method(sel!(applicationShouldHandleReopen:hasVisibleWindows:), should_handle_reopen::<T> as extern fn(&Object, _, _, BOOL) -> BOOL);
+
+            // Dock Menu
+            decl.add_method(sel!(applicationDockMenu:), dock_menu::<T> as extern fn(&Object, _, _) -> id);
+
+            // Displaying Errors
+            decl.add_method(sel!(application:willPresentError:), will_present_error::<T> as extern fn(&Object, _, _, id) -> id);
+
+            // Managing the Screen
+            decl.add_method(sel!(applicationDidChangeScreenParameters:), did_change_screen_parameters::<T> as extern fn(&Object, _, _));
+            decl.add_method(sel!(applicationDidChangeOcclusionState:), did_change_occlusion_state::<T> as extern fn(&Object, _, _));
+
+            // User Activities
+            decl.add_method(sel!(application:willContinueUserActivityWithType:), will_continue_user_activity_with_type::<T> as extern fn(&Object, _, _, id) -> BOOL);
+            decl.add_method(sel!(application:continueUserActivity:restorationHandler:), continue_user_activity::<T> as extern fn(&Object, _, _, id, id) -> BOOL);
+            decl.add_method(sel!(application:didFailToContinueUserActivityWithType:error:), failed_to_continue_user_activity::<T> as extern fn(&Object, _, _, id, id));
+            decl.add_method(sel!(application:didUpdateUserActivity:), did_update_user_activity::<T> as extern fn(&Object, _, _, id));
+
+            // Handling push notifications
+            decl.add_method(sel!(application:didRegisterForRemoteNotificationsWithDeviceToken:), registered_for_remote_notifications::<T> as extern fn(&Object, _, _, id));
+            decl.add_method(sel!(application:didFailToRegisterForRemoteNotificationsWithError:), failed_to_register_for_remote_notifications::<T> as extern fn(&Object, _, _, id));
+            decl.add_method(sel!(application:didReceiveRemoteNotification:), did_receive_remote_notification::<T> as extern fn(&Object, _, _, id));
+
+            // CloudKit
+            #[cfg(feature = "cloudkit")]
+            decl.add_method(sel!(application:userDidAcceptCloudKitShareWithMetadata:), accepted_cloudkit_share::<T> as extern fn(&Object, _, _, id));
+
+            // Opening Files
+            decl.add_method(sel!(application:openURLs:), open_urls::<T> as extern fn(&Object, _, _, id));
+            decl.add_method(sel!(application:openFileWithoutUI:), open_file_without_ui::<T> as extern fn(&Object, _, _, id) -> BOOL);
+            decl.add_method(sel!(applicationShouldOpenUntitledFile:), should_open_untitled_file::<T> as extern fn(&Object, _, _) -> BOOL);
+            decl.add_method(sel!(applicationOpenUntitledFile:), open_untitled_file::<T> as extern fn(&Object, _, _) -> BOOL);
+            decl.add_method(sel!(application:openTempFile:), open_temp_file::<T> as extern fn(&Object, _, _, id) -> BOOL);
+
+            // Printing
+            decl.add_method(sel!(application:printFile:), print_file::<T> as extern fn(&Object, _, _, id) -> BOOL);
+            decl.add_method(sel!(application:printFiles:withSettings:showPrintPanels:), print_files::<T> as extern fn(&Object, _, id, id, id, BOOL) -> NSUInteger);
+
+            // Restoring Application State
+            decl.add_method(sel!(applicationSupportsSecureRestorableState:), supports_secure_restorable_state::<T> as extern fn(&Object, _, _) -> BOOL);
+
+            // Scripting
+            decl.add_method(sel!(application:delegateHandlesKey:), delegate_handles_key::<T> as extern fn(&Object, _, _, id) -> BOOL);
+        });
     });
 
     unsafe {
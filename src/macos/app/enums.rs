@@ -1,6 +1,6 @@
 //! Various types used at the AppController level.
 
-use crate::foundation::NSUInteger;
+use crate::foundation::{NSInteger, NSUInteger};
 
 /// Used for determining how an application should handle quitting/terminating.
 /// You return this in your `AppController` `should_terminate` method.
@@ -156,3 +156,40 @@ impl From<&PresentationOption> for NSUInteger {
         }
     }
 }
+
+/// Describes whether an application shows up in the Dock and can have a menu bar and windows.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ActivationPolicy {
+    /// An ordinary application, with a Dock icon and a menu bar.
+    Regular,
+
+    /// No Dock icon or menu bar, but can activate and show windows (e.g, menu bar utilities).
+    Accessory,
+
+    /// No Dock icon, and can't create windows or be activated (e.g, background agents).
+    Prohibited
+}
+
+impl From<ActivationPolicy> for NSInteger {
+    fn from(policy: ActivationPolicy) -> Self {
+        match policy {
+            ActivationPolicy::Regular => 0,
+            ActivationPolicy::Accessory => 1,
+            ActivationPolicy::Prohibited => 2
+        }
+    }
+}
+
+impl From<NSInteger> for ActivationPolicy {
+    fn from(policy: NSInteger) -> Self {
+        match policy {
+            1 => ActivationPolicy::Accessory,
+            2 => ActivationPolicy::Prohibited,
+            _ => ActivationPolicy::Regular
+        }
+    }
+}
+
+/// A pending request for the user's attention, from `App::request_user_attention()`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AttentionRequest(pub(crate) NSInteger);
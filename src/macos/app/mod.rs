@@ -34,6 +34,8 @@
 //! Certain lifecycle events are specific to certain platforms. Where this is the case, the
 //! documentation makes every effort to note.
 
+use std::future::Future;
+use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 use lazy_static::lazy_static;
 
@@ -41,10 +43,11 @@ use objc_id::Id;
 use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::{id, nil, YES, NO, NSUInteger, AutoReleasePool};
+use crate::appearance::{Appearance, SYSTEM_APPEARANCE_DID_CHANGE};
+use crate::foundation::{id, nil, YES, NO, NSInteger, NSUInteger, NSString, AutoReleasePool};
 use crate::invoker::TargetActionHandler;
 use crate::macos::menu::Menu;
-use crate::notification_center::Dispatcher;
+use crate::notification_center::{Dispatcher, NotificationObserver};
 use crate::utils::activate_cocoa_multithreading;
 
 mod class;
@@ -104,7 +107,7 @@ fn shared_application<F: Fn(id)>(handler: F) {
 ///
 /// This also enables support for dispatching a message, `M`. Your `AppDelegate` can optionally
 /// implement the `Dispatcher` trait to receive messages that you might dispatch from deeper in the
-/// application.
+/// application - register it with `with_dispatcher()`, and send messages with `App::dispatch()`.
 pub struct App<T = (), M = ()> {
     pub inner: Id<Object>,
     pub objc_delegate: Id<Object>,
@@ -113,7 +116,7 @@ pub struct App<T = (), M = ()> {
     _t: std::marker::PhantomData<M>
 }
 
-impl<T> App<T> {  
+impl<T, M> App<T, M> {  
     /// Kicks off the NSRunLoop for the NSApplication instance. This blocks when called.
     /// If you're wondering where to go from here... you need an `AppDelegate` that implements
     /// `did_finish_launching`. :)
@@ -164,6 +167,15 @@ impl<T> App<T> where T: AppDelegate + 'static {
     }
 } 
 
+impl<T> App<T> where T: AppDelegate + Dispatcher + 'static, T::Message: 'static {
+    /// Registers the app delegate as the receiver for `App::dispatch()`, so that any widget
+    /// callback can send it a message (which it gets in `Dispatcher::on_message()`).
+    pub fn with_dispatcher(self) -> Self {
+        crate::notification_center::router::register(&*self.delegate);
+        self
+    }
+}
+
 //  This is a very basic "dispatch" mechanism. In macOS, it's critical that UI work happen on the
 //  UI ("main") thread. We can hook into the standard mechanism for this by dispatching on
 //  queues; in our case, we'll just offer two points - one for the background queue(s), and one
@@ -210,6 +222,15 @@ impl<T, M> App<T, M> where M: Send + Sync + 'static, T: AppDelegate + Dispatcher
 }
 
 impl App {
+    /// Sends `message` to the app delegate's `Dispatcher::on_message()`, on the main thread. This
+    /// can be called from anywhere; off the main thread, the message is delivered asynchronously.
+    ///
+    /// Panics if `with_dispatcher()` wasn't called, or the delegate takes a different message
+    /// type.
+    pub fn dispatch<M: Send + 'static>(message: M) {
+        crate::notification_center::router::route(message);
+    }
+
     /// Registers for remote notifications from APNS.
     pub fn register_for_remote_notifications() {
         shared_application(|app| unsafe {
@@ -254,6 +275,49 @@ impl App {
         });
     }
 
+    /// Sets the number shown in a badge on the app's Dock icon, e.g for unread items. `0` removes
+    /// the badge.
+    pub fn set_badge_count(count: usize) {
+        App::set_badge_label(match count {
+            0 => None,
+            count => Some(count.to_string())
+        }.as_deref());
+    }
+
+    /// Sets the text shown in a badge on the app's Dock icon; `None` removes the badge. Keep it
+    /// short - a number, or a character or two.
+    pub fn set_badge_label(label: Option<&str>) {
+        let label: id = match label {
+            Some(label) => NSString::new(label).into_inner(),
+            None => nil
+        };
+
+        shared_application(move |app| unsafe {
+            let dock_tile: id = msg_send![app, dockTile];
+            let _: () = msg_send![dock_tile, setBadgeLabel:label];
+        });
+    }
+
+    /// Asks for the user's attention while the app is in the background, by bouncing its Dock
+    /// icon: once, or - if `critical` - until the app is activated. Does nothing if the app is
+    /// already active. The returned request can be passed to `cancel_user_attention_request()`.
+    pub fn request_user_attention(critical: bool) -> AttentionRequest {
+        let request_type: NSUInteger = match critical {
+            true => 0,
+            false => 10
+        };
+
+        let app: id = unsafe { msg_send![register_app_class(), sharedApplication] };
+        AttentionRequest(unsafe { msg_send![app, requestUserAttention:request_type] })
+    }
+
+    /// Stops the Dock icon bouncing for `request`, if it still is.
+    pub fn cancel_user_attention_request(request: AttentionRequest) {
+        shared_application(move |app| unsafe {
+            let _: () = msg_send![app, cancelUserAttentionRequest:request.0];
+        });
+    }
+
     /// Sets a set of `Menu`'s as the top level Menu for the current application. Note that behind
     /// the scenes, Cocoa/AppKit make a copy of the menu you pass in - so we don't retain it, and
     /// you shouldn't bother to either.
@@ -296,7 +360,8 @@ impl App {
     /// routines after the NSMenu has been set, otherwise it won't be interact-able without
     /// switching away from the app and then coming back.
     ///
-    /// @TODO: Accept an ActivationPolicy enum or something.
+    /// This sets the activation policy to `ActivationPolicy::Regular`; if you need something
+    /// else, call `set_activation_policy()` afterwards.
     pub fn activate() {
         shared_application(|app| unsafe {
             let _: () = msg_send![app, setActivationPolicy:0];
@@ -305,6 +370,54 @@ impl App {
         });
     }
 
+    /// Sets the activation policy for the application, which controls whether it has a Dock icon
+    /// and menu bar.
+    pub fn set_activation_policy(policy: ActivationPolicy) {
+        shared_application(move |app| unsafe {
+            let policy: NSInteger = policy.into();
+            let _: () = msg_send![app, setActivationPolicy:policy];
+        });
+    }
+
+    /// Sets the appearance for the application, which windows and views inherit unless they set
+    /// their own. `Appearance::System` follows the system setting.
+    pub fn set_appearance(appearance: Appearance) {
+        shared_application(move |app| unsafe {
+            let _: () = msg_send![app, setAppearance:appearance.to_objc()];
+        });
+    }
+
+    /// Returns the appearance the application is currently drawing with - either `Light` or
+    /// `Dark`.
+    pub fn effective_appearance() -> Appearance {
+        let app: id = unsafe { msg_send![register_app_class(), sharedApplication] };
+        Appearance::from_objc(unsafe { msg_send![app, effectiveAppearance] })
+    }
+
+    /// Calls `handler` (on the main thread) with the new system appearance whenever the user
+    /// switches between light and dark mode. Keep the returned `NotificationObserver` around for
+    /// as long as you want to be notified.
+    ///
+    /// Note that this reports the _system_ setting; if you've forced an appearance via
+    /// `set_appearance()`, the application's effective appearance won't change.
+    pub fn observe_system_appearance<F>(handler: F) -> NotificationObserver
+    where
+        F: Fn(Appearance) + 'static
+    {
+        let handler = Rc::new(handler);
+        let center: id = unsafe { msg_send![class!(NSDistributedNotificationCenter), defaultCenter] };
+
+        NotificationObserver::new(center, &[SYSTEM_APPEARANCE_DID_CHANGE], move |_, _| {
+            handler(Appearance::system());
+        })
+    }
+
+    /// Spawns `future` onto the main run loop, so it can update UI as it goes. Must be called
+    /// from the main thread. See `cacao::executor` for details.
+    pub fn spawn<F: Future<Output = ()> + 'static>(future: F) {
+        crate::executor::spawn(future);
+    }
+
     /// Terminates the application, firing the requisite cleanup delegate methods in the process.
     ///
     /// This is typically called when the user chooses to quit via the App menu.
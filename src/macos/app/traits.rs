@@ -1,6 +1,8 @@
 //! Traits that an implementing application can conform to. These aim to wrap the general
 //! lifecycles across macOS/iOS/etc, while still conforming to a Rust-ish approach.
 
+use std::path::PathBuf;
+
 use url::Url;
 
 use crate::error::Error;
@@ -140,9 +142,21 @@ pub trait AppDelegate {
     /// file."
     ///
     /// Note that since we have this as the de-facto method of handling resource opens, the system
-    /// will _not_ call `application:openFile:` or `application:openFiles`.
+    /// will _not_ call `application:openFile:` or `application:openFiles`. File URLs are routed
+    /// to `open_files` instead, which - unless you implement it - passes them back here.
     fn open_urls(&self, _urls: Vec<Url>) { }
 
+    /// Fired when the user opens files with your application - e.g, by double-clicking a document
+    /// in Finder, or dropping it on your Dock icon. Your `Info.plist` must declare the document
+    /// types you handle via the `CFBundleDocumentTypes` key, or the system won't offer them to you.
+    ///
+    /// This fills the role of `application:openFiles:`. `UTType::of_file` will tell you which of
+    /// your document types each one is. The default implementation forwards the files to
+    /// `open_urls` as `file://` URLs.
+    fn open_files(&self, paths: Vec<PathBuf>) {
+        self.open_urls(paths.into_iter().filter_map(|path| Url::from_file_path(path).ok()).collect());
+    }
+
     /// Fired when the file is requested to be opened programmatically. This is not a commonly used
     /// or implemented method.
     ///
@@ -189,6 +203,11 @@ pub trait AppDelegate {
         PrintResponse::Failure
     }
 
+    /// Fired when AppKit wants to know whether your app's restorable state can be decoded with
+    /// secure coding. Window state in this framework is always stored as plain strings, so the
+    /// default implementation returns `true`; there's generally no reason to change it.
+    fn supports_secure_restorable_state(&self) -> bool { true }
+
     /// Fired when the occlusion state for the app has changed.
     ///
     /// From Apple's docs, as there's no other way to describe this better: _upon receiving this method, you can query the 
@@ -0,0 +1,165 @@
+//! A wrapper for `NSAppleScript`, which compiles and runs AppleScript source - handy for
+//! automating other applications (Finder, Mail, and so on).
+//!
+//! ```rust,no_run
+//! use cacao::macos::AppleScript;
+//!
+//! match AppleScript::run("tell application \"Finder\" to get name of front window") {
+//!     Ok(name) => println!("Front Finder window: {}", name),
+//!     Err(e) => eprintln!("Script failed: {}", e)
+//! }
+//! ```
+//!
+//! Controlling other applications requires the user's permission. Your `Info.plist` should carry
+//! an `NSAppleEventsUsageDescription`, and sandboxed apps need the
+//! `com.apple.security.automation.apple-events` entitlement.
+//!
+//! `NSAppleScript` is not thread safe; only use this from the main thread.
+
+use std::error;
+use std::fmt;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::Id;
+
+use crate::foundation::{id, nil, YES, NO, BOOL, NSInteger, NSRange, NSString};
+
+/// An error from compiling or running a script, pulled out of the error dictionary that
+/// `NSAppleScript` hands back.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScriptError {
+    /// The full error message (`NSAppleScriptErrorMessage`).
+    pub message: String,
+
+    /// A shorter version of the message, if provided (`NSAppleScriptErrorBriefMessage`).
+    pub brief_message: Option<String>,
+
+    /// The error number, if provided (`NSAppleScriptErrorNumber`) - e.g, `-1743` when the user
+    /// hasn't allowed your app to control another app.
+    pub number: Option<i64>,
+
+    /// The name of the application that generated the error, if any
+    /// (`NSAppleScriptErrorAppName`).
+    pub app_name: Option<String>,
+
+    /// The location and length of the offending source, if known (`NSAppleScriptErrorRange`).
+    pub range: Option<(usize, usize)>
+}
+
+impl ScriptError {
+    /// Builds a `ScriptError` from an `NSAppleScript` error dictionary.
+    fn new(info: id) -> Self {
+        if info == nil {
+            return ScriptError {
+                message: "An unknown AppleScript error occurred.".to_string(),
+                ..Default::default()
+            };
+        }
+
+        let value = |key: &str| -> id {
+            let key = NSString::new(key);
+            unsafe { msg_send![info, objectForKey:key] }
+        };
+
+        let string = |key: &str| -> Option<String> {
+            let string = value(key);
+
+            match string == nil {
+                true => None,
+                false => Some(NSString::wrap(string).to_string())
+            }
+        };
+
+        let number = value("NSAppleScriptErrorNumber");
+        let range = value("NSAppleScriptErrorRange");
+
+        ScriptError {
+            message: string("NSAppleScriptErrorMessage").unwrap_or_default(),
+            brief_message: string("NSAppleScriptErrorBriefMessage"),
+            app_name: string("NSAppleScriptErrorAppName"),
+
+            number: match number == nil {
+                true => None,
+                false => Some(unsafe {
+                    let number: NSInteger = msg_send![number, integerValue];
+                    number as i64
+                })
+            },
+
+            range: match range == nil {
+                true => None,
+                false => Some(unsafe {
+                    let range: NSRange = msg_send![range, rangeValue];
+                    (range.location as usize, range.length as usize)
+                })
+            }
+        }
+    }
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.number {
+            Some(number) => write!(f, "{} ({})", self.message, number),
+            None => write!(f, "{}", self.message)
+        }
+    }
+}
+
+impl error::Error for ScriptError {}
+
+/// A compiled AppleScript. If you're running a script more than once, compile it once via `new()`
+/// and call `execute()` as needed; otherwise, `AppleScript::run()` does both in one go.
+#[derive(Debug)]
+pub struct AppleScript(pub Id<Object>);
+
+impl AppleScript {
+    /// Compiles the given source. Returns an error if it doesn't compile.
+    pub fn new(source: &str) -> Result<Self, ScriptError> {
+        let source = NSString::new(source);
+
+        let script = AppleScript(unsafe {
+            let alloc: id = msg_send![class!(NSAppleScript), alloc];
+            let script: id = msg_send![alloc, initWithSource:source];
+            Id::from_retained_ptr(script)
+        });
+
+        let mut error: id = nil;
+        let compiled: BOOL = unsafe {
+            msg_send![&*script.0, compileAndReturnError:&mut error]
+        };
+
+        match compiled {
+            YES => Ok(script),
+            NO => Err(ScriptError::new(error)),
+            _ => unreachable!()
+        }
+    }
+
+    /// Compiles and runs the given source, returning the result as a string.
+    pub fn run(source: &str) -> Result<String, ScriptError> {
+        AppleScript::new(source)?.execute()
+    }
+
+    /// Runs this script, returning the result as a string. Results that have no string
+    /// representation (e.g, a script that doesn't return anything) come back empty.
+    pub fn execute(&self) -> Result<String, ScriptError> {
+        let mut error: id = nil;
+
+        let descriptor: id = unsafe {
+            msg_send![&*self.0, executeAndReturnError:&mut error]
+        };
+
+        if descriptor == nil {
+            return Err(ScriptError::new(error));
+        }
+
+        let value: id = unsafe { msg_send![descriptor, stringValue] };
+
+        Ok(match value == nil {
+            true => String::new(),
+            false => NSString::wrap(value).to_string()
+        })
+    }
+}
@@ -2,7 +2,7 @@
 use objc::{class, msg_send, sel, sel_impl};
 use crate::foundation::id;
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum CursorType {
     Arrow,
     Crosshair,
@@ -29,6 +29,32 @@ pub enum CursorType {
     ContextMenu
 }
 
+/// Returns the `NSCursor` for a cursor type.
+pub(crate) fn cursor_for(cursor_type: CursorType) -> id {
+    unsafe {
+        match cursor_type {
+            CursorType::Arrow => msg_send![class!(NSCursor), arrowCursor],
+            CursorType::Crosshair => msg_send![class!(NSCursor), crosshairCursor],
+            CursorType::ClosedHand => msg_send![class!(NSCursor), closedHandCursor],
+            CursorType::OpenHand => msg_send![class!(NSCursor), openHandCursor],
+            CursorType::PointingHand => msg_send![class!(NSCursor), pointingHandCursor],
+            CursorType::ResizeLeft => msg_send![class!(NSCursor), resizeLeftCursor],
+            CursorType::ResizeRight => msg_send![class!(NSCursor), resizeRightCursor],
+            CursorType::ResizeLeftRight => msg_send![class!(NSCursor), resizeLeftRightCursor],
+            CursorType::ResizeUp => msg_send![class!(NSCursor), resizeUpCursor],
+            CursorType::ResizeDown => msg_send![class!(NSCursor), resizeDownCursor],
+            CursorType::ResizeUpDown => msg_send![class!(NSCursor), resizeUpDownCursor],
+            CursorType::DisappearingItem => msg_send![class!(NSCursor), disappearingItemCursor],
+            CursorType::IBeam => msg_send![class!(NSCursor), IBeamCursor],
+            CursorType::IBeamVertical => msg_send![class!(NSCursor), IBeamCursorForVerticalLayout],
+            CursorType::OperationNotAllowed => msg_send![class!(NSCursor), operationNotAllowedCursor],
+            CursorType::DragLink => msg_send![class!(NSCursor), dragLinkCursor],
+            CursorType::DragCopy => msg_send![class!(NSCursor), dragCopyCursor],
+            CursorType::ContextMenu => msg_send![class!(NSCursor), contextualMenuCursor]
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Cursor;
 
@@ -37,28 +63,15 @@ impl Cursor {
     /// The inverse of this call, which you should call when ready, is `pop()`.
     pub fn push(cursor_type: CursorType) {
         unsafe {
-            let cursor: id = match cursor_type {
-                CursorType::Arrow => msg_send![class!(NSCursor), arrowCursor],
-                CursorType::Crosshair => msg_send![class!(NSCursor), crosshairCursor],
-                CursorType::ClosedHand => msg_send![class!(NSCursor), closedHandCursor],
-                CursorType::OpenHand => msg_send![class!(NSCursor), openHandCursor],
-                CursorType::PointingHand => msg_send![class!(NSCursor), pointingHandCursor],
-                CursorType::ResizeLeft => msg_send![class!(NSCursor), resizeLeftCursor],
-                CursorType::ResizeRight => msg_send![class!(NSCursor), resizeRightCursor],
-                CursorType::ResizeLeftRight => msg_send![class!(NSCursor), resizeLeftRightCursor],
-                CursorType::ResizeUp => msg_send![class!(NSCursor), resizeUpCursor],
-                CursorType::ResizeDown => msg_send![class!(NSCursor), resizeDownCursor],
-                CursorType::ResizeUpDown => msg_send![class!(NSCursor), resizeUpDownCursor],
-                CursorType::DisappearingItem => msg_send![class!(NSCursor), disappearingItemCursor],
-                CursorType::IBeam => msg_send![class!(NSCursor), IBeamCursor],
-                CursorType::IBeamVertical => msg_send![class!(NSCursor), IBeamCursorForVerticalLayout],
-                CursorType::OperationNotAllowed => msg_send![class!(NSCursor), operationNotAllowedCursor],
-                CursorType::DragLink => msg_send![class!(NSCursor), dragLinkCursor],
-                CursorType::DragCopy => msg_send![class!(NSCursor), dragCopyCursor],
-                CursorType::ContextMenu => msg_send![class!(NSCursor), contextualMenuCursor]
-            };
-            
-            let _: () = msg_send![cursor, push];
+            let _: () = msg_send![cursor_for(cursor_type), push];
+        }
+    }
+
+    /// Sets the cursor, until something else changes it (e.g, a `TrackingArea` the mouse moves
+    /// into). For a change that's undone later, prefer `push()` and `pop()`.
+    pub fn set(cursor_type: CursorType) {
+        unsafe {
+            let _: () = msg_send![cursor_for(cursor_type), set];
         }
     }
 
@@ -0,0 +1,109 @@
+//! Interop with windows and views that something else created - most commonly
+//! [winit](https://github.com/rust-windowing/winit), for apps that use it for input and event
+//! handling, but want cacao for native chrome: menus, toolbars, dialogs, and the odd `ListView`
+//! alongside the renderer.
+//!
+//! winit hands out raw `NSWindow` and `NSView` pointers (via `WindowExtMacOS`), which wrap into
+//! cacao handles here; going the other way, a cacao view's layer can be handed to an external
+//! renderer (e.g, `wgpu` or `metal`).
+//!
+//! ```rust,no_run
+//! use cacao::layout::Layout;
+//! use cacao::macos::interop;
+//! use cacao::view::View;
+//!
+//! # fn example(ns_window: *mut std::ffi::c_void, ns_view: *mut std::ffi::c_void) {
+//! // From winit: `window.ns_window()` and `window.ns_view()`.
+//! let window = unsafe { interop::window_from_raw(ns_window) };
+//! window.set_title("Renderer");
+//!
+//! let content = unsafe { interop::view_from_raw(ns_view) };
+//! let canvas = View::new();
+//! content.add_subview(&canvas);
+//!
+//! // Give this to your renderer as its surface.
+//! let layer = interop::attach_metal_layer(&canvas);
+//! # }
+//! ```
+//!
+//! winit still owns (and drives) the window and view; the handles here retain them, but don't
+//! install delegates, so events keep flowing to winit. All of this must happen on the main thread.
+
+use std::ffi::c_void;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, YES};
+use crate::layout::Layout;
+use crate::macos::window::Window;
+use crate::view::View;
+
+/// Wraps a raw `NSWindow` pointer (e.g, from winit's `ns_window()`) as a `Window`.
+///
+/// # Safety
+///
+/// `ns_window` must be a valid, non-null pointer to an `NSWindow`.
+pub unsafe fn window_from_raw(ns_window: *mut c_void) -> Window {
+    Window::from_ptr(ns_window as id)
+}
+
+/// Wraps a raw `NSView` pointer (e.g, from winit's `ns_view()`) as a `View`, which you can then
+/// add cacao views to.
+///
+/// # Safety
+///
+/// `ns_view` must be a valid, non-null pointer to an `NSView`.
+pub unsafe fn view_from_raw(ns_view: *mut c_void) -> View {
+    View::from_ptr(ns_view as id)
+}
+
+/// Returns the raw `NSWindow` pointer for a `Window`, for APIs that take one (e.g, a
+/// `raw-window-handle` implementation). It isn't retained.
+pub fn raw_window<T>(window: &Window<T>) -> *mut c_void {
+    &*window.objc as *const Object as *mut c_void
+}
+
+/// Returns the raw `NSView` pointer for a view, for APIs that take one. It isn't retained.
+pub fn raw_view<V: Layout>(view: &V) -> *mut c_void {
+    &*view.get_backing_node() as *const Object as *mut c_void
+}
+
+/// Returns the `CALayer` backing a view, making the view layer-backed first if need be. The layer
+/// is owned by the view (and isn't retained).
+pub fn layer<V: Layout>(view: &V) -> *mut c_void {
+    let view = view.get_backing_node();
+
+    unsafe {
+        let _: () = msg_send![&*view, setWantsLayer:YES];
+        let layer: id = msg_send![&*view, layer];
+        layer as *mut c_void
+    }
+}
+
+/// Makes a view host a fresh `CAMetalLayer` (at the backing scale of its window, if it's in one),
+/// and returns it - ready to hand to a Metal-based renderer. AppKit keeps the layer's frame in
+/// sync with the view; the layer is owned by the view (and isn't retained).
+///
+/// Keep the layer's `drawableSize` up to date as the view resizes; `ViewDelegate` (or your event
+/// loop's resize events) will tell you when.
+pub fn attach_metal_layer<V: Layout>(view: &V) -> *mut c_void {
+    let view = view.get_backing_node();
+
+    unsafe {
+        let layer: id = msg_send![class!(CAMetalLayer), layer];
+
+        let window: id = msg_send![&*view, window];
+        if window != nil {
+            let scale: f64 = msg_send![window, backingScaleFactor];
+            let _: () = msg_send![layer, setContentsScale:scale];
+        }
+
+        // Setting the layer before `wantsLayer` makes this a layer-hosting view, which is what
+        // renderers expect - AppKit leaves the layer's contents alone.
+        let _: () = msg_send![&*view, setLayer:layer];
+        let _: () = msg_send![&*view, setWantsLayer:YES];
+
+        layer as *mut c_void
+    }
+}
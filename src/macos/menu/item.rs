@@ -7,8 +7,8 @@ use objc::runtime::{Object, Sel};
 use objc_id::ShareId;
 use std::sync::Once;
 
-use crate::foundation::{id, nil, NSString, NSUInteger};
-use crate::events::EventModifierFlag;
+use crate::foundation::{id, nil, NSInteger, NSString, NSUInteger};
+use crate::events::{Command, EventModifierFlag};
 use crate::invoker::TargetActionHandler;
 
 /// Internal method (shorthand) for generating `NSMenuItem` holders.
@@ -48,6 +48,58 @@ fn make_menu_item(
     }
 }
 
+/// The standard find actions, as understood by `NSTextFinder` (and anything else that handles
+/// `performFindPanelAction:`, like `NSTextView`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FindAction {
+    /// Shows the find bar.
+    ShowFindInterface,
+
+    /// Shows the find bar, with the replace field.
+    ShowReplaceInterface,
+
+    /// Hides the find bar.
+    HideFindInterface,
+
+    /// Selects the next match.
+    NextMatch,
+
+    /// Selects the previous match.
+    PreviousMatch,
+
+    /// Replaces every match.
+    ReplaceAll,
+
+    /// Replaces the current match.
+    Replace,
+
+    /// Replaces the current match, then selects the next one.
+    ReplaceAndFind,
+
+    /// Uses the selected text as the search string.
+    SetSearchString,
+
+    /// Selects every match.
+    SelectAll
+}
+
+impl From<FindAction> for NSInteger {
+    fn from(action: FindAction) -> Self {
+        match action {
+            FindAction::ShowFindInterface => 1,
+            FindAction::NextMatch => 2,
+            FindAction::PreviousMatch => 3,
+            FindAction::ReplaceAll => 4,
+            FindAction::Replace => 5,
+            FindAction::ReplaceAndFind => 6,
+            FindAction::SetSearchString => 7,
+            FindAction::SelectAll => 9,
+            FindAction::HideFindInterface => 11,
+            FindAction::ShowReplaceInterface => 12
+        }
+    }
+}
+
 /// Represents varying `NSMenuItem` types - e.g, a separator vs an action.
 #[derive(Debug)]
 pub enum MenuItem {
@@ -66,6 +118,13 @@ impl MenuItem {
         make_menu_item(title, None, None, None)
     }
 
+    /// Creates and returns a `MenuItem::Entry` that sends `command` along the responder chain,
+    /// rather than calling a handler. It's enabled only while something in the chain handles the
+    /// command (and, for views and windows with delegates, `validate()` agrees).
+    pub fn command(title: &str, command: Command) -> Self {
+        make_menu_item(title, None, Some(command.selector()), None)
+    }
+
     /// Configures the menu item, if it's not a separator, to support a key equivalent.
     pub fn key(self, key: &str) -> Self {
         match self {
@@ -209,4 +268,38 @@ impl MenuItem {
     pub fn paste() -> Self {
         make_menu_item("Paste", Some("v"), Some(sel!(paste:)), None)
     }
+
+    /// Returns one of the standard Find menu items (e.g, "Find..." with Command-F), which sends
+    /// `action` along the responder chain to whatever's focused - e.g, a text view with the find
+    /// bar enabled.
+    pub fn find(action: FindAction) -> Self {
+        let (title, key, modifiers): (&str, Option<&str>, Option<&[EventModifierFlag]>) = match action {
+            FindAction::ShowFindInterface => ("Find…", Some("f"), None),
+            FindAction::ShowReplaceInterface => (
+                "Find and Replace…",
+                Some("f"),
+                Some(&[EventModifierFlag::Command, EventModifierFlag::Option])
+            ),
+            FindAction::HideFindInterface => ("Hide Find Bar", None, None),
+            FindAction::NextMatch => ("Find Next", Some("g"), None),
+            FindAction::PreviousMatch => ("Find Previous", Some("G"), None),
+            FindAction::ReplaceAll => ("Replace All", None, None),
+            FindAction::Replace => ("Replace", None, None),
+            FindAction::ReplaceAndFind => ("Replace and Find", None, None),
+            FindAction::SetSearchString => ("Use Selection for Find", Some("e"), None),
+            FindAction::SelectAll => ("Select All Matches", None, None)
+        };
+
+        let item = make_menu_item(title, key, Some(sel!(performFindPanelAction:)), modifiers);
+
+        if let MenuItem::Entry((item, _)) = &item {
+            let tag: NSInteger = action.into();
+
+            unsafe {
+                let _: () = msg_send![&**item, setTag:tag];
+            }
+        }
+
+        item
+    }
 }
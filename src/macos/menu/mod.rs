@@ -4,4 +4,4 @@ pub mod menu;
 pub use menu::Menu;
 
 pub mod item;
-pub use item::MenuItem;
+pub use item::{FindAction, MenuItem};
@@ -15,16 +15,35 @@
 //! of your app as a cross platform codebase, with the initial 10% being scaffolding code for the
 //! platform (e.g, NSApplication vs UIApplication lifecycle).
 
-mod alert;
-pub use alert::Alert;
+// Alerts are cross-platform now; these stay re-exported here for existing code.
+pub use crate::alert::{Alert, AlertResponse, AlertStyle};
 
 mod app;
 pub use app::*;
 
+mod applescript;
+pub use applescript::{AppleScript, ScriptError};
+
 mod cursor;
 pub use cursor::{Cursor, CursorType};
 
+pub mod interop;
 pub mod menu;
+
+#[cfg(feature = "toolbar")]
+pub mod preferences;
+
 pub mod printing;
+pub mod services;
+
+#[cfg(feature = "toolbar")]
 pub mod toolbar;
+
+mod tracking_area;
+pub use tracking_area::{TrackingActivity, TrackingArea, TrackingAreaBuilder};
+
 pub mod window;
+pub mod workspace;
+
+#[cfg(feature = "serde")]
+pub mod xpc;
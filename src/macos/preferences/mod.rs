@@ -0,0 +1,254 @@
+//! A helper for building the standard macOS preferences window: a toolbar of icons along the top,
+//! each of which shows a different pane. The window animates its height when switching between
+//! panes, and remembers the last selected pane across launches.
+//!
+//! ```rust,no_run
+//! use cacao::geometry::Size;
+//! use cacao::image::{DrawConfig, Image, ResizeBehavior};
+//! use cacao::macos::preferences::{PreferencesPane, PreferencesWindow};
+//! use cacao::view::ViewDelegate;
+//!
+//! #[derive(Default)]
+//! struct GeneralPane;
+//! impl ViewDelegate for GeneralPane {}
+//!
+//! // A blank icon, to keep things short; draw (or load) a real one here.
+//! let icon = Image::draw(DrawConfig {
+//!     source: Size::new(32., 32.),
+//!     target: Size::new(32., 32.),
+//!     resize: ResizeBehavior::Stretch
+//! }, |_frame, _context| true);
+//!
+//! let preferences = PreferencesWindow::new(vec![
+//!     PreferencesPane::new(icon, "General", GeneralPane::default())
+//! ]);
+//!
+//! preferences.show();
+//! ```
+
+use core_graphics::base::CGFloat;
+use core_graphics::geometry::{CGRect, CGPoint, CGSize};
+
+use objc::{msg_send, sel, sel_impl};
+use objc::runtime::Object;
+
+use crate::defaults::{UserDefaults, Value};
+use crate::foundation::{id, nil, YES, NO, NSString};
+use crate::layout::{Layout, LayoutConstraint, LayoutAnchorX, LayoutAnchorY};
+use crate::macos::toolbar::{Toolbar, ToolbarDelegate, ToolbarItem};
+use crate::macos::window::{Window, WindowConfig, WindowStyle, WindowToolbarStyle};
+use crate::view::View;
+
+mod pane;
+pub use pane::PreferencesPane;
+
+/// The key used to store the last selected pane in `UserDefaults`.
+static SELECTED_PANE_KEY: &str = "CacaoPreferencesSelectedPane";
+
+/// Acts as the toolbar delegate for the preferences window, and owns the panes.
+/// The pane titles double as the toolbar item identifiers.
+#[derive(Debug)]
+pub struct PreferencesToolbar {
+    panes: Vec<PreferencesPane>
+}
+
+impl PreferencesToolbar {
+    /// Returns the item identifiers, which are the pane titles.
+    fn identifiers(&self) -> Vec<&'static str> {
+        self.panes.iter().map(|pane| pane.title).collect()
+    }
+}
+
+impl ToolbarDelegate for PreferencesToolbar {
+    fn allowed_item_identifiers(&self) -> Vec<&'static str> {
+        self.identifiers()
+    }
+
+    fn default_item_identifiers(&self) -> Vec<&'static str> {
+        self.identifiers()
+    }
+
+    fn selectable_item_identifiers(&self) -> Vec<&'static str> {
+        self.identifiers()
+    }
+
+    fn item_for(&self, identifier: &str) -> Option<&ToolbarItem> {
+        self.panes.iter().find(|pane| pane.title == identifier).map(|pane| &pane.item)
+    }
+}
+
+/// A window that displays a set of `PreferencesPane`s, switching between them via the toolbar.
+pub struct PreferencesWindow {
+    /// The underlying window.
+    pub window: Window,
+
+    /// The content view that panes are swapped in and out of.
+    content: View,
+
+    /// The toolbar, which also owns the panes.
+    toolbar: Toolbar<PreferencesToolbar>
+}
+
+impl PreferencesWindow {
+    /// Creates a new preferences window with the given panes. The last selected pane (or the
+    /// first one, if there isn't one) is selected by default. If `panes` is empty, the window
+    /// is simply empty.
+    pub fn new(mut panes: Vec<PreferencesPane>) -> Self {
+        let mut config = WindowConfig::default();
+        config.set_styles(&[WindowStyle::Titled, WindowStyle::Closable]);
+
+        let window = Window::new(config);
+        let content = View::new();
+        window.set_content_view(&content);
+
+        let window_ptr = &*window.objc as *const Object as usize;
+        let content_ptr = &*content.objc as *const Object as usize;
+
+        for pane in panes.iter_mut() {
+            let view_ptr = &*pane.view as *const Object as usize;
+            let title = pane.title;
+
+            pane.item.set_action(move || {
+                select_pane(window_ptr as id, content_ptr as id, view_ptr as id, title, true);
+            });
+        }
+
+        let toolbar = Toolbar::new("CacaoPreferencesToolbar", PreferencesToolbar { panes: panes });
+
+        window.set_toolbar(&toolbar);
+        window.set_toolbar_style(WindowToolbarStyle::Preference);
+
+        let preferences = PreferencesWindow {
+            window: window,
+            content: content,
+            toolbar: toolbar
+        };
+
+        let last_selected = UserDefaults::standard().get(SELECTED_PANE_KEY).and_then(|value| {
+            value.as_str().map(|title| title.to_string())
+        });
+
+        if let Some(title) = last_selected {
+            preferences.select(title);
+        } else if let Some(pane) = preferences.panes().first() {
+            preferences.select(pane.title);
+        }
+
+        preferences
+    }
+
+    /// Returns the panes in this window.
+    fn panes(&self) -> &[PreferencesPane] {
+        match &self.toolbar.delegate {
+            Some(delegate) => &delegate.panes,
+            None => &[]
+        }
+    }
+
+    /// Selects the pane with the given title, without animating. If no pane has that title, the
+    /// first pane is selected instead. This does nothing if there are no panes.
+    pub fn select<S: AsRef<str>>(&self, title: S) {
+        let panes = self.panes();
+        let pane = match panes.iter().find(|pane| pane.title == title.as_ref()).or_else(|| panes.first()) {
+            Some(pane) => pane,
+            None => { return; }
+        };
+
+        let window = &*self.window.objc as *const Object as id;
+        let content = &*self.content.objc as *const Object as id;
+        let view = &*pane.view as *const Object as id;
+        select_pane(window, content, view, pane.title, false);
+    }
+
+    /// Shows the window.
+    pub fn show(&self) {
+        self.window.show();
+    }
+
+    /// Closes the window.
+    pub fn close(&self) {
+        self.window.close();
+    }
+}
+
+/// Swaps `view` into `content`, resizing `window` to fit (animated, if requested), and records
+/// the selection so it can be restored later.
+fn select_pane(window: id, content: id, view: id, title: &str, animate: bool) {
+    unsafe {
+        let subviews: id = msg_send![content, subviews];
+        let current: id = msg_send![subviews, firstObject];
+
+        // Clicking the already-selected pane should be a noop.
+        if current == view {
+            return;
+        }
+
+        if current != nil {
+            let _: () = msg_send![current, removeFromSuperview];
+        }
+
+        let size: CGSize = msg_send![view, fittingSize];
+        let frame: CGRect = msg_send![window, frame];
+        let content_rect: CGRect = msg_send![window, contentRectForFrameRect:frame];
+
+        let width = match size.width > 0. {
+            true => size.width,
+            false => content_rect.size.width
+        };
+
+        let height = match size.height > 0. {
+            true => size.height,
+            false => content_rect.size.height
+        };
+
+        let new_content_rect = CGRect::new(&CGPoint::new(0., 0.), &CGSize::new(width, height));
+        let new_frame: CGRect = msg_send![window, frameRectForContentRect:new_content_rect];
+
+        // Keep the top edge in place, since AppKit's coordinate system starts at the bottom.
+        let origin_y: CGFloat = frame.origin.y + frame.size.height - new_frame.size.height;
+        let new_frame = CGRect::new(
+            &CGPoint::new(frame.origin.x, origin_y),
+            &CGSize::new(new_frame.size.width, new_frame.size.height)
+        );
+
+        let _: () = msg_send![window, setFrame:new_frame display:YES animate:match animate {
+            true => YES,
+            false => NO
+        }];
+
+        let _: () = msg_send![content, addSubview:view];
+
+        let (top, leading, trailing, bottom) = (
+            LayoutAnchorY::new(msg_send![content, topAnchor]),
+            LayoutAnchorX::new(msg_send![content, leadingAnchor]),
+            LayoutAnchorX::new(msg_send![content, trailingAnchor]),
+            LayoutAnchorY::new(msg_send![content, bottomAnchor])
+        );
+
+        let (view_top, view_leading, view_trailing, view_bottom) = (
+            LayoutAnchorY::new(msg_send![view, topAnchor]),
+            LayoutAnchorX::new(msg_send![view, leadingAnchor]),
+            LayoutAnchorX::new(msg_send![view, trailingAnchor]),
+            LayoutAnchorY::new(msg_send![view, bottomAnchor])
+        );
+
+        LayoutConstraint::activate(&[
+            view_top.constraint_equal_to(&top),
+            view_leading.constraint_equal_to(&leading),
+            view_trailing.constraint_equal_to(&trailing),
+            view_bottom.constraint_equal_to(&bottom)
+        ]);
+
+        let identifier = NSString::new(title);
+        let toolbar: id = msg_send![window, toolbar];
+        if toolbar != nil {
+            let _: () = msg_send![toolbar, setSelectedItemIdentifier:identifier];
+        }
+
+        let window_title = NSString::new(title);
+        let _: () = msg_send![window, setTitle:window_title];
+    }
+
+    let mut defaults = UserDefaults::standard();
+    defaults.insert(SELECTED_PANE_KEY, Value::string(title));
+}
@@ -0,0 +1,57 @@
+//! A pane in a `PreferencesWindow`.
+
+use std::any::Any;
+use std::fmt;
+
+use objc_id::ShareId;
+use objc::runtime::Object;
+
+use crate::image::Image;
+use crate::layout::Layout;
+use crate::macos::toolbar::ToolbarItem;
+use crate::view::{View, ViewDelegate};
+
+/// Represents a single pane in a `PreferencesWindow` - an icon and title (shown in the toolbar),
+/// and the view that's displayed when it's selected.
+pub struct PreferencesPane {
+    /// The title for this pane. This doubles as the toolbar item identifier, so it should be
+    /// unique amongst your panes.
+    pub title: &'static str,
+
+    /// The toolbar item for this pane.
+    pub(crate) item: ToolbarItem,
+
+    /// The backing view for this pane.
+    pub(crate) view: ShareId<Object>,
+
+    /// Holds the actual `View<T>` (and thus, your delegate) for as long as the pane is alive.
+    _holder: Box<dyn Any>
+}
+
+impl PreferencesPane {
+    /// Creates a new pane with the given icon and title, backed by a `View` using your delegate.
+    pub fn new<T: ViewDelegate + 'static>(icon: Image, title: &'static str, delegate: T) -> Self {
+        let view = View::with(delegate);
+        let backing_node = view.get_backing_node();
+
+        let mut item = ToolbarItem::new(title);
+        item.set_title(title);
+        item.set_image(&icon);
+
+        PreferencesPane {
+            title: title,
+            item: item,
+            view: backing_node,
+            _holder: Box::new(view)
+        }
+    }
+}
+
+impl fmt::Debug for PreferencesPane {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PreferencesPane")
+            .field("title", &self.title)
+            .field("item", &self.item)
+            .finish()
+    }
+}
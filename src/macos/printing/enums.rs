@@ -1,6 +1,6 @@
 //! Enums used through the general printing flow.
 
-use crate::foundation::NSUInteger;
+use crate::foundation::{NSInteger, NSUInteger};
 
 /// Used for handling printing files. You return this in relevant `AppController` methods.
 #[derive(Copy, Clone, Debug)]
@@ -30,3 +30,45 @@ impl From<PrintResponse> for NSUInteger {
         }
     }
 }
+
+/// Describes the orientation of printed pages.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PrintOrientation {
+    /// Pages are taller than they are wide.
+    Portrait,
+
+    /// Pages are wider than they are tall.
+    Landscape
+}
+
+impl From<PrintOrientation> for NSInteger {
+    fn from(orientation: PrintOrientation) -> Self {
+        match orientation {
+            PrintOrientation::Portrait => 0,
+            PrintOrientation::Landscape => 1
+        }
+    }
+}
+
+/// Describes how content is paginated along an axis when printing.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PrintPagination {
+    /// Content is split across pages automatically.
+    Automatic,
+
+    /// Content is scaled down to fit on a single page along this axis.
+    Fit,
+
+    /// Content is clipped at page boundaries.
+    Clip
+}
+
+impl From<PrintPagination> for NSUInteger {
+    fn from(pagination: PrintPagination) -> Self {
+        match pagination {
+            PrintPagination::Automatic => 0,
+            PrintPagination::Fit => 1,
+            PrintPagination::Clip => 2
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Wraps `NSPrintInfo`, which describes how a print job should be laid out (paper size, margins,
+//! orientation, and so on) - i.e, page setup.
+
+use std::path::Path;
+
+use core_graphics::base::CGFloat;
+
+use objc::{class, msg_send, sel, sel_impl};
+use objc::runtime::Object;
+use objc_id::ShareId;
+
+use crate::foundation::{id, YES, NO, NSInteger, NSString, NSUInteger};
+use crate::macos::printing::enums::{PrintOrientation, PrintPagination};
+use crate::macos::printing::settings::PrintSettings;
+use crate::utils::CGSize;
+
+/// `PrintInfo` holds page setup options for a print job. Creating a new one copies the shared
+/// (application-wide) settings, so you can tweak it without affecting anything else.
+#[derive(Clone, Debug)]
+pub struct PrintInfo {
+    pub objc: ShareId<Object>
+}
+
+impl Default for PrintInfo {
+    fn default() -> Self {
+        PrintInfo::new()
+    }
+}
+
+impl PrintInfo {
+    /// Returns a copy of the shared `NSPrintInfo`.
+    pub fn new() -> Self {
+        PrintInfo {
+            objc: unsafe {
+                let shared: id = msg_send![class!(NSPrintInfo), sharedPrintInfo];
+                let info: id = msg_send![shared, copy];
+                ShareId::from_retained_ptr(info)
+            }
+        }
+    }
+
+    /// Returns the underlying print settings.
+    pub fn settings(&self) -> PrintSettings {
+        PrintSettings::with_inner(unsafe {
+            msg_send![&*self.objc, dictionary]
+        })
+    }
+
+    /// Sets the page orientation.
+    pub fn set_orientation(&self, orientation: PrintOrientation) {
+        let orientation: NSInteger = orientation.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setOrientation:orientation];
+        }
+    }
+
+    /// Sets the paper size, in points.
+    pub fn set_paper_size(&self, width: f64, height: f64) {
+        let size = CGSize::new(width as CGFloat, height as CGFloat);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setPaperSize:size];
+        }
+    }
+
+    /// Sets the page margins, in points.
+    pub fn set_margins(&self, top: f64, left: f64, bottom: f64, right: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTopMargin:top as CGFloat];
+            let _: () = msg_send![&*self.objc, setLeftMargin:left as CGFloat];
+            let _: () = msg_send![&*self.objc, setBottomMargin:bottom as CGFloat];
+            let _: () = msg_send![&*self.objc, setRightMargin:right as CGFloat];
+        }
+    }
+
+    /// Sets how content is paginated horizontally.
+    pub fn set_horizontal_pagination(&self, pagination: PrintPagination) {
+        let pagination: NSUInteger = pagination.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setHorizontalPagination:pagination];
+        }
+    }
+
+    /// Sets how content is paginated vertically.
+    pub fn set_vertical_pagination(&self, pagination: PrintPagination) {
+        let pagination: NSUInteger = pagination.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setVerticalPagination:pagination];
+        }
+    }
+
+    /// Sets whether content is centered on the page, horizontally and vertically.
+    pub fn set_centered(&self, horizontally: bool, vertically: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setHorizontallyCentered:match horizontally {
+                true => YES,
+                false => NO
+            }];
+
+            let _: () = msg_send![&*self.objc, setVerticallyCentered:match vertically {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Configures this print job to save to a PDF at the given path, rather than going to a
+    /// printer.
+    pub fn set_save_to_pdf<P: AsRef<Path>>(&self, path: P) {
+        let path = NSString::new(&path.as_ref().to_string_lossy());
+        let disposition = NSString::new("NSPrintSaveJob");
+        let url_key = NSString::new("NSJobSavingURL");
+
+        unsafe {
+            let url: id = msg_send![class!(NSURL), fileURLWithPath:path];
+            let _: () = msg_send![&*self.objc, setJobDisposition:disposition];
+
+            let dictionary: id = msg_send![&*self.objc, dictionary];
+            let _: () = msg_send![dictionary, setObject:url forKey:url_key];
+        }
+    }
+}
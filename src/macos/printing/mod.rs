@@ -2,7 +2,13 @@
 //! itself).
 
 pub mod enums;
-pub use enums::PrintResponse;
+pub use enums::{PrintOrientation, PrintPagination, PrintResponse};
+
+pub mod info;
+pub use info::PrintInfo;
+
+pub mod operation;
+pub use operation::PrintOperation;
 
 pub mod settings;
 pub use settings::PrintSettings;
@@ -0,0 +1,123 @@
+//! Wraps `NSPrintOperation`, which handles actually printing a view - whether that's to a printer,
+//! or to a PDF file.
+
+use std::path::Path;
+
+use objc::{class, msg_send, sel, sel_impl};
+use objc::runtime::Object;
+use objc_id::ShareId;
+
+use crate::foundation::{id, BOOL, YES, NO, NSString};
+use crate::layout::Layout;
+use crate::macos::printing::info::PrintInfo;
+
+#[cfg(feature = "webview")]
+use crate::webview::WebView;
+
+/// A print operation for a view. Configure it as necessary, then call `run()`.
+///
+/// ```rust,no_run
+/// use cacao::macos::printing::{PrintInfo, PrintOperation};
+/// use cacao::view::View;
+///
+/// let view = View::new();
+/// let operation = PrintOperation::new(&view, &PrintInfo::new());
+/// operation.run();
+/// ```
+#[derive(Clone, Debug)]
+pub struct PrintOperation {
+    pub objc: ShareId<Object>
+}
+
+impl PrintOperation {
+    /// Creates a print operation for the given view, using the given page setup.
+    pub fn new<L: Layout>(view: &L, info: &PrintInfo) -> Self {
+        let backing_node = view.get_backing_node();
+
+        PrintOperation {
+            objc: unsafe {
+                let operation: id = msg_send![class!(NSPrintOperation), printOperationWithView:&*backing_node
+                    printInfo:&*info.objc
+                ];
+
+                ShareId::from_ptr(operation)
+            }
+        }
+    }
+
+    /// Creates a print operation for the given `WebView`, using the given page setup. This prints
+    /// the full contents of the loaded page, not just what's currently visible.
+    ///
+    /// This is only supported on macOS 11.0+.
+    #[cfg(feature = "webview")]
+    pub fn webview<T>(webview: &WebView<T>, info: &PrintInfo) -> Self {
+        PrintOperation {
+            objc: unsafe {
+                let operation: id = msg_send![&*webview.objc, printOperationWithPrintInfo:&*info.objc];
+                ShareId::from_ptr(operation)
+            }
+        }
+    }
+
+    /// Creates an operation that renders the given view to a PDF file at `path`, without showing
+    /// any panels. Call `run()` to actually write the file.
+    pub fn pdf<L: Layout, P: AsRef<Path>>(view: &L, path: P, info: &PrintInfo) -> Self {
+        let info = PrintInfo {
+            objc: unsafe {
+                let info: id = msg_send![&*info.objc, copy];
+                ShareId::from_retained_ptr(info)
+            }
+        };
+
+        info.set_save_to_pdf(path);
+
+        let operation = PrintOperation::new(view, &info);
+        operation.set_shows_print_panel(false);
+        operation.set_shows_progress_panel(false);
+        operation
+    }
+
+    /// Sets whether the print panel is shown when this operation runs. Set this to `false` to
+    /// print silently.
+    pub fn set_shows_print_panel(&self, shows: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setShowsPrintPanel:match shows {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether a progress panel is shown while this operation runs.
+    pub fn set_shows_progress_panel(&self, shows: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setShowsProgressPanel:match shows {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets the job title, which is shown in the print panel and the printer queue.
+    pub fn set_job_title(&self, title: &str) {
+        let title = NSString::new(title);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setJobTitle:title];
+        }
+    }
+
+    /// Runs the operation, blocking until it's done. Returns `true` if the job was successful,
+    /// or `false` if it failed or the user cancelled.
+    pub fn run(&self) -> bool {
+        let result: BOOL = unsafe {
+            msg_send![&*self.objc, runOperation]
+        };
+
+        match result {
+            YES => true,
+            NO => false,
+            _ => unreachable!()
+        }
+    }
+}
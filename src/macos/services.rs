@@ -0,0 +1,209 @@
+//! Enables your application to act as a provider for macOS Services - the items in the Services
+//! menu that let users send a selection (text, files, and so on) from any app to yours.
+//!
+//! Services are declared in your `Info.plist`, under the `NSServices` key. Each entry has an
+//! `NSMessage` value, which is the name you register a handler for here:
+//!
+//! ```rust,no_run
+//! use cacao::macos::services::ServicesProvider;
+//! use cacao::pasteboard::PasteboardType;
+//!
+//! let mut provider = ServicesProvider::new();
+//!
+//! // Matches `<key>NSMessage</key><string>uppercaseText</string>` in Info.plist.
+//! provider.add_service("uppercaseText", |pasteboard, _user_data| {
+//!     let text = pasteboard.get_string(PasteboardType::String).ok_or("No text was provided.")?;
+//!
+//!     pasteboard.clear_contents();
+//!     pasteboard.set_string(&text.to_uppercase(), PasteboardType::String);
+//!     Ok(())
+//! });
+//!
+//! provider.register();
+//! ```
+//!
+//! Keep the `ServicesProvider` around for as long as you want to handle requests - typically, the
+//! lifetime of your app.
+
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::fmt;
+use std::mem;
+use std::sync::Once;
+
+use objc::runtime::{class_addMethod, Class, Imp, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, NSString};
+use crate::pasteboard::Pasteboard;
+use crate::utils::load_or_register_class;
+
+pub(crate) static SERVICES_PROVIDER_PTR: &str = "rstServicesProviderPtr";
+
+/// The part of a service selector that follows the `NSMessage` name.
+static SERVICE_SELECTOR_SUFFIX: &str = ":userData:error:";
+
+/// The Objective-C type encoding for a service method: `v@:@@^@`.
+static SERVICE_METHOD_TYPES: &str = "v@:@@^@";
+
+extern "C" {
+    fn NSUpdateDynamicServices();
+}
+
+/// A boxed service handler.
+type ServiceHandler = Box<dyn Fn(&Pasteboard, &str) -> Result<(), String> + 'static>;
+
+/// Holds the handlers for a `ServicesProvider`, keyed by `NSMessage` name. This exists to give us
+/// a concrete (sized) type to point to from the Objective-C side.
+pub(crate) struct ServiceHandlers(HashMap<String, ServiceHandler>);
+
+/// Handles a request for a service. The selector tells us which service it is.
+extern fn perform_service(this: &Object, cmd: Sel, pasteboard: id, user_data: id, error: *mut id) {
+    let handlers = load_or_return!(ServiceHandlers, this, SERVICES_PROVIDER_PTR);
+    let name = cmd.name().trim_end_matches(SERVICE_SELECTOR_SUFFIX);
+
+    let user_data = match user_data == nil {
+        true => String::new(),
+        false => NSString::wrap(user_data).to_string()
+    };
+
+    let result = match handlers.0.get(name) {
+        Some(handler) => handler(&Pasteboard::with(pasteboard), &user_data),
+        None => Err(format!("No handler registered for the \"{}\" service.", name))
+    };
+
+    // Services report failure through an `NSString **` (not an `NSError **`), which - as with
+    // any out-param - expects an autoreleased object.
+    if let Err(message) = result {
+        if !error.is_null() {
+            unsafe {
+                // NSUTF8StringEncoding
+                let encoding: usize = 4;
+
+                let string: id = msg_send![class!(NSString), alloc];
+                let string: id = msg_send![string, initWithBytes:message.as_ptr() length:message.len() encoding:encoding];
+                *error = msg_send![string, autorelease];
+            }
+        }
+    }
+}
+
+/// Registers the services provider class. Service methods are added to it as they're registered,
+/// since their selectors depend on names declared in `Info.plist`.
+fn register_services_provider_class() -> *const Class {
+    static mut PROVIDER_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSObject);
+        PROVIDER_CLASS = load_or_register_class(superclass, "RSTServicesProvider", |decl| {
+            decl.add_ivar::<usize>(SERVICES_PROVIDER_PTR);
+        });
+    });
+
+    unsafe { PROVIDER_CLASS }
+}
+
+/// Acts as the services provider for your application. Add a handler for each `NSMessage` you
+/// declared in `Info.plist`, then call `register()`.
+pub struct ServicesProvider {
+    /// The underlying provider object.
+    pub objc: ShareId<Object>,
+
+    handlers: Box<ServiceHandlers>
+}
+
+impl Default for ServicesProvider {
+    fn default() -> Self {
+        ServicesProvider::new()
+    }
+}
+
+impl ServicesProvider {
+    /// Creates a new, empty `ServicesProvider`.
+    pub fn new() -> Self {
+        let handlers = Box::new(ServiceHandlers(HashMap::new()));
+
+        let objc = unsafe {
+            let provider: id = msg_send![register_services_provider_class(), new];
+            let ptr: *const ServiceHandlers = &*handlers;
+            (&mut *provider).set_ivar(SERVICES_PROVIDER_PTR, ptr as usize);
+            ShareId::from_retained_ptr(provider)
+        };
+
+        ServicesProvider {
+            objc: objc,
+            handlers: handlers
+        }
+    }
+
+    /// Adds a handler for the service with the given `NSMessage` name. The handler receives the
+    /// pasteboard holding the user's selection, along with the `NSUserData` string from your
+    /// `Info.plist` (or an empty string, if there isn't one).
+    ///
+    /// To return a result (for services that declare `NSReturnTypes`), clear the pasteboard and
+    /// write to it. Returning an `Err` reports the message back to the user.
+    pub fn add_service<F>(&mut self, message: &str, handler: F)
+    where
+        F: Fn(&Pasteboard, &str) -> Result<(), String> + 'static
+    {
+        let selector = format!("{}{}", message, SERVICE_SELECTOR_SUFFIX);
+        let types = CString::new(SERVICE_METHOD_TYPES).unwrap();
+
+        unsafe {
+            let imp: Imp = mem::transmute(perform_service as extern fn(&Object, Sel, id, id, *mut id));
+
+            // This returns `NO` if the method already exists (e.g, a second provider handling the
+            // same service), which is fine - it routes to the same function either way.
+            class_addMethod(
+                register_services_provider_class() as *mut Class,
+                Sel::register(&selector),
+                imp,
+                types.as_ptr()
+            );
+        }
+
+        self.handlers.0.insert(message.to_string(), Box::new(handler));
+    }
+
+    /// Sets this as the services provider for the application.
+    pub fn register(&self) {
+        unsafe {
+            let app: id = msg_send![class!(NSApplication), sharedApplication];
+            let _: () = msg_send![app, setServicesProvider:&*self.objc];
+        }
+    }
+
+    /// Asks the system to re-scan for services. You only need this if your app changes the
+    /// services it provides while running (e.g, by installing a helper).
+    pub fn update_dynamic_services() {
+        unsafe {
+            NSUpdateDynamicServices();
+        }
+    }
+}
+
+impl fmt::Debug for ServicesProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ServicesProvider")
+            .field("objc", &self.objc)
+            .field("services", &self.handlers.0.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl Drop for ServicesProvider {
+    /// Unregisters this provider, if it's the one the application is using, as the handlers are
+    /// going away.
+    fn drop(&mut self) {
+        unsafe {
+            let app: id = msg_send![class!(NSApplication), sharedApplication];
+            let current: id = msg_send![app, servicesProvider];
+
+            if current == &*self.objc as *const Object as id {
+                let _: () = msg_send![app, setServicesProvider:nil];
+            }
+        }
+    }
+}
@@ -2,17 +2,17 @@
 
 use std::sync::Once;
 
-use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel};
 use objc::{class, sel, sel_impl, msg_send};
 
-use crate::foundation::{id, NSArray, NSString};
-use crate::macos::toolbar::{TOOLBAR_PTR, ToolbarDelegate};
-use crate::utils::load;
+use crate::foundation::{id, nil, BOOL, YES, NO, NSArray, NSString};
+use crate::macos::toolbar::{SHARE_ITEMS_PTR, TOOLBAR_PTR, ToolbarDelegate};
+use crate::macos::toolbar::item::ShareItemsProvider;
+use crate::utils::{load_or_register_class, load_or_register_class_for};
 
 /// Retrieves and passes the allowed item identifiers for this toolbar.
 extern fn allowed_item_identifiers<T: ToolbarDelegate>(this: &Object, _: Sel, _: id) -> id {
-    let toolbar = load::<T>(this, TOOLBAR_PTR);
+    let toolbar = load_or_return!(T, this, TOOLBAR_PTR, nil);
 
     let identifiers: NSArray = toolbar.allowed_item_identifiers().iter().map(|identifier| {
         NSString::new(identifier).into_inner()
@@ -23,7 +23,7 @@ extern fn allowed_item_identifiers<T: ToolbarDelegate>(this: &Object, _: Sel, _:
 
 /// Retrieves and passes the default item identifiers for this toolbar.
 extern fn default_item_identifiers<T: ToolbarDelegate>(this: &Object, _: Sel, _: id) -> id {
-    let toolbar = load::<T>(this, TOOLBAR_PTR);
+    let toolbar = load_or_return!(T, this, TOOLBAR_PTR, nil);
 
     let identifiers: NSArray = toolbar.default_item_identifiers().iter().map(|identifier| {
         NSString::new(identifier).into_inner()
@@ -32,39 +32,131 @@ extern fn default_item_identifiers<T: ToolbarDelegate>(this: &Object, _: Sel, _:
     identifiers.into_inner()
 }
 
+/// Retrieves and passes the selectable item identifiers for this toolbar.
+extern fn selectable_item_identifiers<T: ToolbarDelegate>(this: &Object, _: Sel, _: id) -> id {
+    let toolbar = load_or_return!(T, this, TOOLBAR_PTR, nil);
+
+    let identifiers: NSArray = toolbar.selectable_item_identifiers().iter().map(|identifier| {
+        NSString::new(identifier).into_inner()
+    }).collect::<Vec<id>>().into();
+
+    identifiers.into_inner()
+}
+
 /// Loads the controller, grabs whatever item is for this identifier, and returns what the
 /// Objective-C runtime needs.
 extern fn item_for_identifier<T: ToolbarDelegate>(this: &Object, _: Sel, _: id, identifier: id, _: id) -> id {
-    let toolbar = load::<T>(this, TOOLBAR_PTR);
+    let toolbar = load_or_return!(T, this, TOOLBAR_PTR, nil);
     let identifier = NSString::wrap(identifier);
     
-    let item = toolbar.item_for(identifier.to_str());
+    match toolbar.item_for(identifier.to_str()) {
+        Some(item) => unsafe { msg_send![&*item.objc, self] },
+        None => nil
+    }
+}
+
+/// Called by our `NSToolbarItem` subclass when it's being validated. Finds the item from the
+/// delegate and asks whether it should be enabled.
+extern fn validate_item<T: ToolbarDelegate>(this: &Object, _: Sel, item: id) -> BOOL {
+    let toolbar = load_or_return!(T, this, TOOLBAR_PTR, YES);
+    let identifier = NSString::wrap(unsafe { msg_send![item, itemIdentifier] });
+
+    let item = match toolbar.item_for(identifier.to_str()) {
+        Some(item) => item,
+        None => { return YES; }
+    };
+
+    match toolbar.validate(item) {
+        true => YES,
+        false => NO
+    }
+}
+
+/// Overrides `[NSToolbarItem validate]`. AppKit's validation runs first; if it leaves the item
+/// enabled, the owning toolbar (if it's one of ours) gets the final say.
+extern fn validate(this: &Object, _: Sel) {
     unsafe {
-        msg_send![&*item.objc, self]
+        let _: () = msg_send![super(this, class!(NSToolbarItem)), validate];
+
+        let enabled: BOOL = msg_send![this, isEnabled];
+        if enabled == NO {
+            return;
+        }
+
+        let toolbar: id = msg_send![this, toolbar];
+        if toolbar == nil {
+            return;
+        }
+
+        let responds: BOOL = msg_send![toolbar, respondsToSelector:sel!(rstValidateToolbarItem:)];
+        if responds == NO {
+            return;
+        }
+
+        let enabled: BOOL = msg_send![toolbar, rstValidateToolbarItem:this];
+        let _: () = msg_send![this, setEnabled:enabled];
     }
-    //&mut *item.objc
 }
 
 /// Registers a `NSToolbar` subclass, and configures it to hold some ivars for various things we need
 /// to store. We use it as our delegate as well, just to cut down on moving pieces.
-pub(crate) fn register_toolbar_class<T: ToolbarDelegate>() -> *const Class {
-    static mut TOOLBAR_CLASS: *const Class = 0 as *const Class;
+pub(crate) fn register_toolbar_class<T: ToolbarDelegate + 'static>() -> *const Class {
+    unsafe {
+        let superclass = class!(NSToolbar);
+        load_or_register_class_for::<T, _>(superclass, "RSTToolbar", |decl| {
+            // For callbacks
+            decl.add_ivar::<usize>(TOOLBAR_PTR);
+
+            // Add callback methods
+            decl.add_method(sel!(toolbarAllowedItemIdentifiers:), allowed_item_identifiers::<T> as extern fn(&Object, _, _) -> id);
+            decl.add_method(sel!(toolbarDefaultItemIdentifiers:), default_item_identifiers::<T> as extern fn(&Object, _, _) -> id);
+            decl.add_method(sel!(toolbarSelectableItemIdentifiers:), selectable_item_identifiers::<T> as extern fn(&Object, _, _) -> id);
+            decl.add_method(sel!(toolbar:itemForItemIdentifier:willBeInsertedIntoToolbar:), item_for_identifier::<T> as extern fn(&Object, _, _, _, _) -> id);
+            decl.add_method(sel!(rstValidateToolbarItem:), validate_item::<T> as extern fn(&Object, _, _) -> BOOL);
+        })
+    }
+}
+
+/// Registers an `NSToolbarItem` subclass that routes validation back through the owning
+/// toolbar's delegate.
+pub(crate) fn register_toolbar_item_class() -> *const Class {
+    static mut ITEM_CLASS: *const Class = 0 as *const Class;
     static INIT: Once = Once::new();
 
     INIT.call_once(|| unsafe {
-        let superclass = class!(NSToolbar);
-        let mut decl = ClassDecl::new("RSTToolbar", superclass).unwrap();
-        
-        // For callbacks
-        decl.add_ivar::<usize>(TOOLBAR_PTR);
+        let superclass = class!(NSToolbarItem);
+        ITEM_CLASS = load_or_register_class(superclass, "RSTToolbarItem", |decl| {
+            decl.add_method(sel!(validate), validate as extern fn(&Object, _));
+        });
+    });
 
-        // Add callback methods
-        decl.add_method(sel!(toolbarAllowedItemIdentifiers:), allowed_item_identifiers::<T> as extern fn(&Object, _, _) -> id);
-        decl.add_method(sel!(toolbarDefaultItemIdentifiers:), default_item_identifiers::<T> as extern fn(&Object, _, _) -> id);
-        decl.add_method(sel!(toolbar:itemForItemIdentifier:willBeInsertedIntoToolbar:), item_for_identifier::<T> as extern fn(&Object, _, _, _, _) -> id);
+    unsafe { ITEM_CLASS }
+}
+
+/// Retrieves the items that a sharing service picker toolbar item should share.
+extern fn items_for_sharing_service_picker(this: &Object, _: Sel, _: id) -> id {
+    let provider = load_or_return!(ShareItemsProvider, this, SHARE_ITEMS_PTR, nil);
 
-        TOOLBAR_CLASS = decl.register();
+    let items: NSArray = (provider.0)().iter().map(|item| {
+        NSString::new(item).into_inner()
+    }).collect::<Vec<id>>().into();
+
+    items.into_inner()
+}
+
+/// Registers an `NSObject` subclass that acts as the delegate for sharing service picker toolbar
+/// items, and calls back to the Rust side for whatever should be shared.
+pub(crate) fn register_share_item_delegate_class() -> *const Class {
+    static mut DELEGATE_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSObject);
+        DELEGATE_CLASS = load_or_register_class(superclass, "RSTSharingServicePickerToolbarItemDelegate", |decl| {
+            decl.add_ivar::<usize>(SHARE_ITEMS_PTR);
+            decl.add_method(sel!(itemsForSharingServicePickerToolbarItem:), items_for_sharing_service_picker as extern fn(&Object, _, _) -> id);
+        });
     });
 
-    unsafe { TOOLBAR_CLASS }
+    unsafe { DELEGATE_CLASS }
 }
@@ -1,6 +1,6 @@
 //! Various types used for Toolbar configuration.
 
-use crate::foundation::NSUInteger;
+use crate::foundation::{NSInteger, NSUInteger, NSString};
 
 /// Represents the display mode(s) a Toolbar can render in.
 #[derive(Clone, Copy, Debug)]
@@ -51,3 +51,78 @@ impl From<ToolbarSizeMode> for NSUInteger {
         }
     }
 }
+
+/// Represents an item identifier for items in a Toolbar. Aside from your own (custom) items,
+/// AppKit provides a number of standard items that it'll construct and manage for you - you just
+/// need to include them in your allowed/default identifiers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ItemIdentifier {
+    /// One of your own items. When AppKit needs this item, it'll call `ToolbarDelegate::item_for`
+    /// with this identifier.
+    Custom(&'static str),
+
+    /// A fixed-width space.
+    Space,
+
+    /// A flexible-width space, which will expand to fill available room.
+    FlexibleSpace,
+
+    /// A separator that tracks the divider of a sidebar in the window's split view. This only
+    /// works if the window's content view controller is an `NSSplitViewController`.
+    ///
+    /// This is only supported on macOS 11.0+.
+    SidebarTrackingSeparator,
+
+    /// The standard "toggle sidebar" button. This sends `toggleSidebar:` down the responder chain,
+    /// which an `NSSplitViewController` in your window will pick up.
+    ToggleSidebar
+}
+
+impl ItemIdentifier {
+    /// Returns the identifier as a string slice. For system identifiers, this is the value AppKit
+    /// uses under the hood.
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            ItemIdentifier::Custom(identifier) => identifier,
+            ItemIdentifier::Space => "NSToolbarSpaceItem",
+            ItemIdentifier::FlexibleSpace => "NSToolbarFlexibleSpaceItem",
+            ItemIdentifier::SidebarTrackingSeparator => "NSToolbarSidebarTrackingSeparatorItemIdentifier",
+            ItemIdentifier::ToggleSidebar => "NSToolbarToggleSidebarItem"
+        }
+    }
+}
+
+impl From<&'static str> for ItemIdentifier {
+    fn from(identifier: &'static str) -> Self {
+        ItemIdentifier::Custom(identifier)
+    }
+}
+
+impl From<ItemIdentifier> for NSString {
+    fn from(identifier: ItemIdentifier) -> Self {
+        NSString::new(identifier.to_str())
+    }
+}
+
+/// Represents how a segmented toolbar item handles selection.
+#[derive(Clone, Copy, Debug)]
+pub enum SegmentedSelectionMode {
+    /// Only one segment can be selected at a time.
+    SelectOne,
+
+    /// Any number of segments can be selected at a time.
+    SelectAny,
+
+    /// Segments act as momentary buttons; nothing stays selected.
+    Momentary
+}
+
+impl From<SegmentedSelectionMode> for NSInteger {
+    fn from(mode: SegmentedSelectionMode) -> Self {
+        match mode {
+            SegmentedSelectionMode::SelectOne => 0,
+            SegmentedSelectionMode::SelectAny => 1,
+            SegmentedSelectionMode::Momentary => 2
+        }
+    }
+}
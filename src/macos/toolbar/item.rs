@@ -7,12 +7,30 @@ use std::fmt;
 use core_graphics::geometry::CGSize;
 
 use objc_id::{Id, ShareId};
-use objc::runtime::{Object};
+use objc::runtime::{Class, Object};
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::{id, NSString};
+use crate::foundation::{id, nil, BOOL, YES, NO, NSArray, NSInteger, NSString};
+use crate::events::Command;
 use crate::invoker::TargetActionHandler;
-use crate::button::Button;
+use crate::button::{BezelStyle, Button};
+use crate::image::Image;
+use crate::macos::menu::Menu;
+use crate::utils::clear_ptr;
+use crate::macos::toolbar::{
+    SHARE_ITEMS_PTR, SegmentedSelectionMode,
+    register_share_item_delegate_class, register_toolbar_item_class
+};
+
+/// Holds the callback used to retrieve items for a sharing service picker toolbar item. This
+/// exists to give us a concrete (sized) type to point to from the Objective-C side.
+pub(crate) struct ShareItemsProvider(pub Box<dyn Fn() -> Vec<String> + 'static>);
+
+impl fmt::Debug for ShareItemsProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShareItemsProvider").finish()
+    }
+}
 
 /// Wraps `NSToolbarItem`. Enables configuring things like size, view, and so on.
 #[derive(Debug)]
@@ -20,6 +38,9 @@ pub struct ToolbarItem {
     pub identifier: String,
     pub objc: Id<Object>,
     pub button: Option<Button>,
+    pub menu: Option<Menu>,
+    view: Option<ShareId<Object>>,
+    share_delegate: Option<(ShareId<Object>, Box<ShareItemsProvider>)>,
     handler: Option<TargetActionHandler>
 }
 
@@ -27,11 +48,16 @@ impl ToolbarItem {
     /// Creates and returns a new `ToolbarItem`, ensuring the underlying `NSToolbarItem` is
     /// properly initialized.
     pub fn new<S: Into<String>>(identifier: S) -> Self {
+        ToolbarItem::with_class(identifier, unsafe { &*register_toolbar_item_class() })
+    }
+
+    /// Internal method for initializing a specific `NSToolbarItem` subclass.
+    fn with_class<S: Into<String>>(identifier: S, cls: &Class) -> Self {
         let identifier = identifier.into();
 
         let objc = unsafe {
             let identifr = NSString::new(&identifier);
-            let alloc: id = msg_send![class!(NSToolbarItem), alloc];
+            let alloc: id = msg_send![cls, alloc];
             let item: id = msg_send![alloc, initWithItemIdentifier:identifr];
             Id::from_ptr(item)
         };
@@ -40,10 +66,101 @@ impl ToolbarItem {
             identifier: identifier,
             objc: objc,
             button: None,
+            menu: None,
+            view: None,
+            share_delegate: None,
             handler: None
         }
     }
 
+    /// Creates and returns a new `ToolbarItem` backed by `NSSearchToolbarItem`, which displays a
+    /// search field that expands and collapses as needed. Use `set_search_action` to be notified
+    /// of what the user is searching for.
+    ///
+    /// This is only supported on macOS 11.0+.
+    pub fn search_field<S: Into<String>>(identifier: S) -> Self {
+        let mut item = ToolbarItem::with_class(identifier, class!(NSSearchToolbarItem));
+
+        item.view = Some(unsafe {
+            let field: id = msg_send![&*item.objc, searchField];
+            ShareId::from_ptr(field)
+        });
+
+        item
+    }
+
+    /// Creates and returns a new `ToolbarItem` backed by `NSToolbarItemGroup`, rendered as a
+    /// segmented control with the given titles. Use `set_segment_action` to be notified when a
+    /// segment is clicked.
+    pub fn segmented<S: Into<String>>(identifier: S, titles: &[&str], mode: SegmentedSelectionMode) -> Self {
+        let identifier = identifier.into();
+        let mode: NSInteger = mode.into();
+
+        let titles: NSArray = titles.iter().map(|title| {
+            NSString::new(title).into_inner()
+        }).collect::<Vec<id>>().into();
+
+        let objc = unsafe {
+            let identifr = NSString::new(&identifier);
+            let item: id = msg_send![class!(NSToolbarItemGroup), groupWithItemIdentifier:identifr
+                titles:titles.into_inner()
+                selectionMode:mode
+                labels:nil
+                target:nil
+                action:nil
+            ];
+
+            Id::from_ptr(item)
+        };
+
+        ToolbarItem {
+            identifier: identifier,
+            objc: objc,
+            button: None,
+            menu: None,
+            view: None,
+            share_delegate: None,
+            handler: None
+        }
+    }
+
+    /// Creates and returns a new `ToolbarItem` backed by `NSMenuToolbarItem`, which shows the
+    /// passed `Menu` as a pull-down when clicked. The menu is retained for as long as this item
+    /// is alive.
+    pub fn menu<S: Into<String>>(identifier: S, menu: Menu) -> Self {
+        let mut item = ToolbarItem::with_class(identifier, class!(NSMenuToolbarItem));
+
+        unsafe {
+            let _: () = msg_send![&*item.objc, setMenu:&*menu.inner];
+        }
+
+        item.menu = Some(menu);
+        item
+    }
+
+    /// Creates and returns a new `ToolbarItem` backed by `NSSharingServicePickerToolbarItem`.
+    /// When clicked, it'll call `items` to figure out what should be shared, and present the
+    /// system sharing menu accordingly.
+    pub fn share<S, F>(identifier: S, items: F) -> Self
+    where
+        S: Into<String>,
+        F: Fn() -> Vec<String> + 'static
+    {
+        let mut item = ToolbarItem::with_class(identifier, class!(NSSharingServicePickerToolbarItem));
+        let provider = Box::new(ShareItemsProvider(Box::new(items)));
+
+        let delegate = unsafe {
+            let delegate: id = msg_send![register_share_item_delegate_class(), new];
+            let ptr: *const ShareItemsProvider = &*provider;
+            (&mut *delegate).set_ivar(SHARE_ITEMS_PTR, ptr as usize);
+            let _: () = msg_send![&*item.objc, setDelegate:delegate];
+            ShareId::from_ptr(delegate)
+        };
+
+        item.share_delegate = Some((delegate, provider));
+        item
+    }
+
     /// Sets the title for this item.
     pub fn set_title(&mut self, title: &str) {
         unsafe {
@@ -53,9 +170,35 @@ impl ToolbarItem {
         }
     }
 
+    /// Sets the image for this item.
+    pub fn set_image(&self, image: &Image) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setImage:&*image.0];
+        }
+    }
+
+    /// Sets the label shown for this item in the customization palette.
+    pub fn set_palette_label(&self, label: &str) {
+        unsafe {
+            let label = NSString::new(label);
+            let _: () = msg_send![&*self.objc, setPaletteLabel:label];
+        }
+    }
+
+    /// Sets whether this item is enabled. Note that if your `ToolbarDelegate` implements
+    /// `validate`, AppKit will periodically override this.
+    pub fn set_enabled(&self, enabled: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setEnabled:match enabled {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
     /// Sets and takes ownership of the button for this item.
     pub fn set_button(&mut self, button: Button) {
-        button.set_bezel_style(11);
+        button.set_bezel_style(BezelStyle::TexturedRounded);
 
         unsafe {
             let _: () = msg_send![&*self.objc, setView:&*button.objc];
@@ -80,8 +223,99 @@ impl ToolbarItem {
         }
     }
 
+    /// Attaches a callback for when this item is clicked.
     pub fn set_action<F: Fn() + Send + Sync + 'static>(&mut self, action: F) {
         let handler = TargetActionHandler::new(&*self.objc, action);
         self.handler = Some(handler);
     }
+
+    /// Makes this item send `command` along the responder chain when clicked, instead of calling
+    /// an action. The toolbar enables it only while something in the chain handles the command;
+    /// see `MenuItem::command()`. Replaces any action set with `set_action()`.
+    pub fn set_command(&mut self, command: Command) {
+        self.handler = None;
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTarget:nil];
+            let _: () = msg_send![&*self.objc, setAction:command.selector()];
+        }
+    }
+
+    /// For items created via `ToolbarItem::search_field`, attaches a callback that receives the
+    /// current search text whenever it changes. This does nothing for other item types.
+    pub fn set_search_action<F: Fn(String) + Send + Sync + 'static>(&mut self, action: F) {
+        if let Some(view) = &self.view {
+            // The field is retained by this item (and the handler is dropped with it), so
+            // holding the raw address in the callback is fine.
+            let field = &**view as *const Object as usize;
+
+            self.handler = Some(TargetActionHandler::new(&*view, move || {
+                let text = NSString::wrap(unsafe {
+                    msg_send![field as id, stringValue]
+                });
+
+                action(text.to_string());
+            }));
+        }
+    }
+
+    /// Returns whether this item was created via `ToolbarItem::segmented`.
+    fn is_segmented(&self) -> bool {
+        let is_group: BOOL = unsafe {
+            msg_send![&*self.objc, isKindOfClass:class!(NSToolbarItemGroup)]
+        };
+
+        is_group == YES
+    }
+
+    /// For items created via `ToolbarItem::segmented`, attaches a callback that receives the
+    /// index of the segment that was clicked. This does nothing for other item types.
+    ///
+    /// The target and action are set on the group itself, rather than the segmented control it
+    /// builds, as the group relies on being the control's target to keep its selection in sync.
+    pub fn set_segment_action<F: Fn(usize) + Send + Sync + 'static>(&mut self, action: F) {
+        if !self.is_segmented() {
+            return;
+        }
+
+        // See the note in `set_search_action` regarding holding the address here.
+        let group = &*self.objc as *const Object as usize;
+
+        self.handler = Some(TargetActionHandler::new(&*self.objc, move || {
+            let index: NSInteger = unsafe {
+                msg_send![group as id, selectedIndex]
+            };
+
+            if index >= 0 {
+                action(index as usize);
+            }
+        }));
+    }
+
+    /// For items created via `ToolbarItem::segmented`, sets which segment is selected.
+    pub fn set_selected_segment(&self, index: usize) {
+        if !self.is_segmented() {
+            return;
+        }
+
+        let index = index as NSInteger;
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setSelectedIndex:index];
+        }
+    }
+}
+
+impl Drop for ToolbarItem {
+    /// The toolbar can keep the `NSToolbarItem` alive past this, so detach the share delegate and
+    /// break its link to the items provider, which is going away.
+    fn drop(&mut self) {
+        if let Some((delegate, _)) = &self.share_delegate {
+            clear_ptr(delegate, SHARE_ITEMS_PTR);
+
+            unsafe {
+                let _: () = msg_send![&*self.objc, setDelegate:nil];
+            }
+        }
+    }
 }
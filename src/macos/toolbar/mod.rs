@@ -8,9 +8,11 @@ use objc::runtime::Object;
 use objc_id::ShareId;
 
 use crate::foundation::{id, nil, YES, NO, NSString, NSUInteger};
+use crate::utils::clear_ptr;
 
 mod class;
 use class::register_toolbar_class;
+pub(crate) use class::{register_share_item_delegate_class, register_toolbar_item_class};
 
 mod item;
 pub use item::ToolbarItem;
@@ -19,9 +21,10 @@ mod traits;
 pub use traits::ToolbarDelegate;
 
 mod enums;
-pub use enums::{ToolbarDisplayMode, ToolbarSizeMode};
+pub use enums::{ItemIdentifier, SegmentedSelectionMode, ToolbarDisplayMode, ToolbarSizeMode};
 
 pub(crate) static TOOLBAR_PTR: &str = "rstToolbarPtr";
+pub(crate) static SHARE_ITEMS_PTR: &str = "rstShareItemsPtr";
 
 /// A wrapper for `NSToolbar`. Holds (retains) pointers for the Objective-C runtime 
 /// where our `NSToolbar` and associated delegate live.
@@ -100,6 +103,54 @@ impl<T> Toolbar<T> {
         }
     }
 
+    /// Sets whether the user can customize this toolbar (e.g, via the context menu or
+    /// `run_customization_palette`). Which items they can choose from is determined by
+    /// `ToolbarDelegate::allowed_item_identifiers`.
+    pub fn set_allows_user_customization(&self, allows: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAllowsUserCustomization:match allows {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether the toolbar automatically saves its configuration (e.g, the user's
+    /// customizations and display mode) to `UserDefaults`, keyed by the toolbar identifier.
+    pub fn set_autosaves_configuration(&self, autosaves: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAutosavesConfiguration:match autosaves {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Shows the toolbar customization sheet.
+    pub fn run_customization_palette(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, runCustomizationPalette:nil];
+        }
+    }
+
+    /// Asks the toolbar to validate its visible items. AppKit does this periodically on its own,
+    /// but you can call this to force an update after your application state changes.
+    pub fn validate_visible_items(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, validateVisibleItems];
+        }
+    }
+
+    /// Marks the item with the given identifier as selected. The item must be one of the
+    /// identifiers returned from `ToolbarDelegate::selectable_item_identifiers`.
+    pub fn set_selected_item_identifier(&self, identifier: &str) {
+        let identifier = NSString::new(identifier);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setSelectedItemIdentifier:identifier];
+        }
+    }
+
     /// Set whether the toolbar is visible or not.
     pub fn set_visible(&self, visibility: bool) {
         unsafe {
@@ -113,9 +164,13 @@ impl<T> Toolbar<T> {
 
 impl<T> Drop for Toolbar<T> {
     /// A bit of extra cleanup for the delegate system. If we have a non-`None` delegate, this is
-    /// the OG Toolbar and should be cleaned up for any possible cyclical references.
+    /// the OG Toolbar and should be cleaned up for any possible cyclical references. The delegate
+    /// pointer is cleared too, so callbacks from a toolbar that's still attached to a window are
+    /// ignored.
     fn drop(&mut self) {
         if self.delegate.is_some() {
+            clear_ptr(&self.objc, TOOLBAR_PTR);
+
             unsafe {
                 let _: () = msg_send![&*self.objc, setDelegate:nil];
             }
@@ -1,6 +1,5 @@
 //! Traits that can be used for Toolbar construction. Relatively straightforward, as far as these
-//! go. Currently a bit incomplete in that we don't support the customizing workflow, but feel free
-//! to pull request it.
+//! go.
 
 use crate::macos::toolbar::{Toolbar, ToolbarItem};
 
@@ -11,12 +10,26 @@ pub trait ToolbarDelegate {
     /// interest of a uniform and expectable API.
     fn did_load(&self, _toolbar: Toolbar) {}
 
-    /// What items are allowed in this toolbar.
+    /// What items are allowed in this toolbar. For the standard AppKit items, use
+    /// `ItemIdentifier::to_str()` (e.g, `ItemIdentifier::FlexibleSpace.to_str()`).
     fn allowed_item_identifiers(&self) -> Vec<&'static str>;
 
     /// The default items in this toolbar.
     fn default_item_identifiers(&self) -> Vec<&'static str>;
 
-    /// For a given `identifier`, return the `ToolbarItem` that should be displayed.
-    fn item_for(&self, _identifier: &str) -> &ToolbarItem;
+    /// The items in this toolbar that can be selected (e.g, for a preferences window, where the
+    /// selected item indicates the current pane). The default implementation returns none.
+    fn selectable_item_identifiers(&self) -> Vec<&'static str> { vec![] }
+
+    /// For a given `identifier`, return the `ToolbarItem` that should be displayed, or `None` if
+    /// you don't have one (in which case AppKit leaves it out). This is only called for your own
+    /// identifiers; AppKit handles the system ones.
+    fn item_for(&self, _identifier: &str) -> Option<&ToolbarItem>;
+
+    /// Called when AppKit is validating a `ToolbarItem` (e.g, when the window becomes key, or
+    /// after `Toolbar::validate_visible_items`). Return `false` to disable the item. The default
+    /// implementation returns `true`.
+    ///
+    /// This only applies to items created via `ToolbarItem::new`.
+    fn validate(&self, _item: &ToolbarItem) -> bool { true }
 }
@@ -0,0 +1,310 @@
+//! Wraps `NSTrackingArea`, which reports the mouse entering, moving within and leaving a region
+//! of a view - for hover effects, tooltips you draw yourself, or setting the cursor.
+//!
+//! ```rust,no_run
+//! use cacao::macos::{CursorType, TrackingActivity, TrackingArea};
+//! use cacao::view::View;
+//!
+//! let view = View::new();
+//!
+//! let tracking_area = TrackingArea::builder()
+//!     .active(TrackingActivity::InKeyWindow)
+//!     .cursor(CursorType::PointingHand)
+//!     .on_mouse_entered(|point| println!("Entered at {:?}", point))
+//!     .on_mouse_exited(|_| println!("Exited"))
+//!     .attach(&view);
+//!
+//! // Keep `tracking_area` around for as long as you want to hear about the mouse.
+//! ```
+
+use std::fmt;
+use std::sync::Once;
+
+use core_graphics::geometry::{CGPoint, CGRect, CGSize};
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, NSUInteger};
+use crate::geometry::{Point, Rect};
+use crate::layout::Layout;
+use crate::macos::CursorType;
+use crate::macos::cursor::cursor_for;
+use crate::utils::{clear_ptr, load_or_register_class, try_load};
+
+static TRACKING_AREA_PTR: &str = "rstTrackingAreaPtr";
+
+/// When a tracking area reports the mouse.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TrackingActivity {
+    /// Only while the view is the first responder.
+    WhenFirstResponder,
+
+    /// Only while the view's window is the key window. This is the default.
+    InKeyWindow,
+
+    /// Only while the app is active.
+    InActiveApp,
+
+    /// Always, even while the app is in the background.
+    Always
+}
+
+impl From<TrackingActivity> for NSUInteger {
+    fn from(activity: TrackingActivity) -> Self {
+        match activity {
+            TrackingActivity::WhenFirstResponder => 0x10,
+            TrackingActivity::InKeyWindow => 0x20,
+            TrackingActivity::InActiveApp => 0x40,
+            TrackingActivity::Always => 0x80
+        }
+    }
+}
+
+/// `NSTrackingMouseEnteredAndExited`.
+const MOUSE_ENTERED_AND_EXITED: NSUInteger = 0x01;
+
+/// `NSTrackingMouseMoved`.
+const MOUSE_MOVED: NSUInteger = 0x02;
+
+/// `NSTrackingCursorUpdate`.
+const CURSOR_UPDATE: NSUInteger = 0x04;
+
+/// `NSTrackingInVisibleRect`.
+const IN_VISIBLE_RECT: NSUInteger = 0x200;
+
+/// `NSTrackingEnabledDuringMouseDrag`.
+const ENABLED_DURING_MOUSE_DRAG: NSUInteger = 0x400;
+
+/// Holds the callbacks for a `TrackingArea`, for its owner object to find.
+#[derive(Default)]
+struct TrackingHandlers {
+    view: Option<ShareId<Object>>,
+    cursor: Option<CursorType>,
+    entered: Option<Box<dyn Fn(Point) + 'static>>,
+    exited: Option<Box<dyn Fn(Point) + 'static>>,
+    moved: Option<Box<dyn Fn(Point) + 'static>>
+}
+
+impl TrackingHandlers {
+    /// Returns where `event` happened, in the view's coordinates.
+    fn location(&self, event: id) -> Point {
+        let view = match &self.view {
+            Some(view) => view,
+            None => { return Point::new(0., 0.); }
+        };
+
+        unsafe {
+            let location: CGPoint = msg_send![event, locationInWindow];
+            let location: CGPoint = msg_send![&**view, convertPoint:location fromView:nil];
+            location.into()
+        }
+    }
+}
+
+/// Called when the mouse enters the area.
+extern fn mouse_entered(this: &Object, _: Sel, event: id) {
+    if let Ok(handlers) = try_load::<TrackingHandlers>(this, TRACKING_AREA_PTR) {
+        if let Some(handler) = &handlers.entered {
+            handler(handlers.location(event));
+        }
+    }
+}
+
+/// Called when the mouse leaves the area.
+extern fn mouse_exited(this: &Object, _: Sel, event: id) {
+    if let Ok(handlers) = try_load::<TrackingHandlers>(this, TRACKING_AREA_PTR) {
+        if let Some(handler) = &handlers.exited {
+            handler(handlers.location(event));
+        }
+    }
+}
+
+/// Called when the mouse moves within the area.
+extern fn mouse_moved(this: &Object, _: Sel, event: id) {
+    if let Ok(handlers) = try_load::<TrackingHandlers>(this, TRACKING_AREA_PTR) {
+        if let Some(handler) = &handlers.moved {
+            handler(handlers.location(event));
+        }
+    }
+}
+
+/// Called when the mouse enters the area, and AppKit wants to know which cursor to show.
+extern fn cursor_update(this: &Object, _: Sel, _event: id) {
+    if let Ok(handlers) = try_load::<TrackingHandlers>(this, TRACKING_AREA_PTR) {
+        if let Some(cursor) = handlers.cursor {
+            unsafe {
+                let _: () = msg_send![cursor_for(cursor), set];
+            }
+        }
+    }
+}
+
+/// Registers an `NSObject` subclass to own tracking areas, and receive their events.
+fn register_tracking_area_owner_class() -> *const Class {
+    static mut OWNER_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSObject);
+        OWNER_CLASS = load_or_register_class(superclass, "RSTTrackingAreaOwner", |decl| {
+            decl.add_ivar::<usize>(TRACKING_AREA_PTR);
+
+            decl.add_method(sel!(mouseEntered:), mouse_entered as extern fn(&Object, _, _));
+            decl.add_method(sel!(mouseExited:), mouse_exited as extern fn(&Object, _, _));
+            decl.add_method(sel!(mouseMoved:), mouse_moved as extern fn(&Object, _, _));
+            decl.add_method(sel!(cursorUpdate:), cursor_update as extern fn(&Object, _, _));
+        });
+    });
+
+    unsafe { OWNER_CLASS }
+}
+
+/// Configures a `TrackingArea`. By default, the area follows the view's visible bounds, and is
+/// active while the view's window is key.
+#[derive(Default)]
+pub struct TrackingAreaBuilder {
+    rect: Option<Rect>,
+    activity: Option<TrackingActivity>,
+    during_drag: bool,
+    handlers: TrackingHandlers
+}
+
+impl TrackingAreaBuilder {
+    /// Tracks a fixed region of the view, in its coordinates, rather than following its bounds.
+    /// If the view resizes, you'll need to replace the area yourself.
+    pub fn rect(mut self, rect: Rect) -> Self {
+        self.rect = Some(rect);
+        self
+    }
+
+    /// Sets when the area reports the mouse.
+    pub fn active(mut self, activity: TrackingActivity) -> Self {
+        self.activity = Some(activity);
+        self
+    }
+
+    /// Keeps reporting the mouse while a button's held down (e.g, during a drag that started
+    /// elsewhere). By default, events stop until it's released.
+    pub fn during_mouse_drag(mut self, enabled: bool) -> Self {
+        self.during_drag = enabled;
+        self
+    }
+
+    /// Sets the cursor to `cursor` while the mouse is in the area.
+    pub fn cursor(mut self, cursor: CursorType) -> Self {
+        self.handlers.cursor = Some(cursor);
+        self
+    }
+
+    /// Sets a handler that's called with where (in the view's coordinates) the mouse entered.
+    pub fn on_mouse_entered<F: Fn(Point) + 'static>(mut self, handler: F) -> Self {
+        self.handlers.entered = Some(Box::new(handler));
+        self
+    }
+
+    /// Sets a handler that's called with where (in the view's coordinates) the mouse left.
+    pub fn on_mouse_exited<F: Fn(Point) + 'static>(mut self, handler: F) -> Self {
+        self.handlers.exited = Some(Box::new(handler));
+        self
+    }
+
+    /// Sets a handler that's called with the mouse's location (in the view's coordinates)
+    /// whenever it moves within the area. This fires often; keep it cheap.
+    pub fn on_mouse_moved<F: Fn(Point) + 'static>(mut self, handler: F) -> Self {
+        self.handlers.moved = Some(Box::new(handler));
+        self
+    }
+
+    /// Creates the tracking area, and adds it to `view`.
+    pub fn attach<V: Layout>(self, view: &V) -> TrackingArea {
+        let view = view.get_backing_node();
+        let mut handlers = Box::new(self.handlers);
+        handlers.view = Some(view.clone());
+
+        let mut options: NSUInteger = self.activity.unwrap_or(TrackingActivity::InKeyWindow).into();
+
+        if handlers.entered.is_some() || handlers.exited.is_some() {
+            options |= MOUSE_ENTERED_AND_EXITED;
+        }
+
+        if handlers.moved.is_some() {
+            options |= MOUSE_MOVED;
+        }
+
+        if handlers.cursor.is_some() {
+            options |= CURSOR_UPDATE;
+        }
+
+        if self.during_drag {
+            options |= ENABLED_DURING_MOUSE_DRAG;
+        }
+
+        // With `NSTrackingInVisibleRect`, AppKit ignores the rect and keeps the area in step with
+        // the view's visible bounds for us.
+        let rect = match self.rect {
+            Some(rect) => rect.into(),
+
+            None => {
+                options |= IN_VISIBLE_RECT;
+                CGRect::new(&CGPoint::new(0., 0.), &CGSize::new(0., 0.))
+            }
+        };
+
+        unsafe {
+            let owner: id = msg_send![register_tracking_area_owner_class(), new];
+            let ptr: *const TrackingHandlers = &*handlers;
+            (&mut *owner).set_ivar(TRACKING_AREA_PTR, ptr as usize);
+
+            let area: id = msg_send![class!(NSTrackingArea), alloc];
+            let area: id = msg_send![area, initWithRect:rect options:options owner:owner userInfo:nil];
+            let _: () = msg_send![&*view, addTrackingArea:area];
+
+            TrackingArea {
+                objc: ShareId::from_retained_ptr(area),
+                owner: ShareId::from_retained_ptr(owner),
+                handlers: handlers
+            }
+        }
+    }
+}
+
+/// A region of a view that reports the mouse. Keep this around for as long as you want to hear
+/// about it; dropping it removes the area from the view.
+pub struct TrackingArea {
+    /// The underlying `NSTrackingArea`.
+    pub objc: ShareId<Object>,
+
+    owner: ShareId<Object>,
+    handlers: Box<TrackingHandlers>
+}
+
+impl TrackingArea {
+    /// Returns a builder for configuring a tracking area.
+    pub fn builder() -> TrackingAreaBuilder {
+        TrackingAreaBuilder::default()
+    }
+}
+
+impl fmt::Debug for TrackingArea {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TrackingArea")
+            .field("objc", &self.objc)
+            .field("cursor", &self.handlers.cursor)
+            .finish()
+    }
+}
+
+impl Drop for TrackingArea {
+    /// Removes the area from its view, and breaks the link to the handlers, which are going away.
+    fn drop(&mut self) {
+        clear_ptr(&self.owner, TRACKING_AREA_PTR);
+
+        if let Some(view) = &self.handlers.view {
+            unsafe {
+                let _: () = msg_send![&**view, removeTrackingArea:&*self.objc];
+            }
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! A builder for `Window`s.
+
+use crate::geometry::Rect;
+use crate::macos::window::{TitleVisibility, Window, WindowConfig, WindowDelegate, WindowStyle};
+
+/// Configures a `Window` in one expression. This covers both the things that have to be decided
+/// before the `NSWindow` exists (style, initial frame - i.e, the `WindowConfig`), and the things
+/// you'd otherwise set up one by one afterwards. Anything left unset keeps the default.
+///
+/// ```rust,no_run
+/// use cacao::macos::window::Window;
+///
+/// let window = Window::builder()
+///     .title("Preferences")
+///     .size(480., 320.)
+///     .minimum_size(320., 240.)
+///     .autosave_name("PreferencesWindow")
+///     .build();
+/// ```
+///
+/// With a delegate, everything here is applied before your `did_load()` runs.
+#[derive(Debug, Default)]
+pub struct WindowBuilder {
+    config: WindowConfig,
+    title: Option<String>,
+    title_visibility: Option<TitleVisibility>,
+    titlebar_appears_transparent: Option<bool>,
+    movable_by_background: Option<bool>,
+    minimum_size: Option<(f64, f64)>,
+    maximum_size: Option<(f64, f64)>,
+    autosave_name: Option<String>
+}
+
+impl WindowBuilder {
+    /// Sets the title.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Sets whether the title is shown.
+    pub fn title_visibility(mut self, visibility: TitleVisibility) -> Self {
+        self.title_visibility = Some(visibility);
+        self
+    }
+
+    /// Sets whether the titlebar is transparent.
+    pub fn titlebar_appears_transparent(mut self, transparent: bool) -> Self {
+        self.titlebar_appears_transparent = Some(transparent);
+        self
+    }
+
+    /// Sets whether the window can be moved by dragging its background.
+    pub fn movable_by_background(mut self, movable: bool) -> Self {
+        self.movable_by_background = Some(movable);
+        self
+    }
+
+    /// Sets the style(s) of the window (titled, closable, resizable and so on), replacing the
+    /// defaults.
+    pub fn styles(mut self, styles: &[WindowStyle]) -> Self {
+        self.config.set_styles(styles);
+        self
+    }
+
+    /// Sets the initial frame of the content area.
+    pub fn frame(mut self, frame: Rect) -> Self {
+        self.config.initial_dimensions = frame;
+        self
+    }
+
+    /// Sets the initial size of the content area.
+    pub fn size<F: Into<f64>>(mut self, width: F, height: F) -> Self {
+        self.config.initial_dimensions.width = width.into();
+        self.config.initial_dimensions.height = height.into();
+        self
+    }
+
+    /// Sets the minimum size of the content area.
+    pub fn minimum_size<F: Into<f64>>(mut self, width: F, height: F) -> Self {
+        self.minimum_size = Some((width.into(), height.into()));
+        self
+    }
+
+    /// Sets the maximum size of the content area.
+    pub fn maximum_size<F: Into<f64>>(mut self, width: F, height: F) -> Self {
+        self.maximum_size = Some((width.into(), height.into()));
+        self
+    }
+
+    /// Sets the autosave name, which the system uses to remember the window's frame across
+    /// launches.
+    pub fn autosave_name(mut self, name: &str) -> Self {
+        self.autosave_name = Some(name.to_string());
+        self
+    }
+
+    /// Creates the `Window`.
+    pub fn build(self) -> Window {
+        let (config, setup) = self.split();
+        let window = Window::new(config);
+        setup.apply(&window);
+        window
+    }
+
+    /// Creates the `Window`, with a delegate.
+    pub fn build_with<T: WindowDelegate + 'static>(self, delegate: T) -> Window<T> {
+        let (config, setup) = self.split();
+        Window::with_setup(config, delegate, move |window| setup.apply(window))
+    }
+
+    /// Separates the `WindowConfig` (needed to create the window) from everything else.
+    fn split(mut self) -> (WindowConfig, Self) {
+        let config = std::mem::replace(&mut self.config, WindowConfig::default());
+        (config, self)
+    }
+
+    /// Applies everything that's been set, other than the `WindowConfig`.
+    fn apply<T>(self, window: &Window<T>) {
+        if let Some(title) = self.title {
+            window.set_title(&title);
+        }
+
+        if let Some(visibility) = self.title_visibility {
+            window.set_title_visibility(visibility);
+        }
+
+        if let Some(transparent) = self.titlebar_appears_transparent {
+            window.set_titlebar_appears_transparent(transparent);
+        }
+
+        if let Some(movable) = self.movable_by_background {
+            window.set_movable_by_background(movable);
+        }
+
+        if let Some((width, height)) = self.minimum_size {
+            window.set_minimum_content_size(width, height);
+        }
+
+        if let Some((width, height)) = self.maximum_size {
+            window.set_maximum_content_size(width, height);
+        }
+
+        if let Some(name) = self.autosave_name {
+            window.set_autosave_name(&name);
+        }
+    }
+}
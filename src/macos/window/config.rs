@@ -2,11 +2,15 @@
 //! mask). This configuration object acts as a way to orchestrate enabling customization before the
 //! window object is created - it's returned in your `WindowDelegate` object.
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::foundation::NSUInteger;
 use crate::geometry::Rect;
 use crate::macos::window::enums::WindowStyle;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WindowConfig {
     /// The style the window should have.
     pub style: NSUInteger,
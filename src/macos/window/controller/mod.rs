@@ -32,7 +32,7 @@ use objc::{msg_send, sel, sel_impl};
 use objc_id::ShareId;
 
 use crate::foundation::{id, nil};
-use crate::utils::Controller;
+use crate::utils::{clear_ptr, Controller};
 use crate::macos::window::{Window, WindowConfig, WindowDelegate, WINDOW_DELEGATE_PTR};
 
 mod class;
@@ -103,3 +103,11 @@ impl<T> WindowController<T> where T: WindowDelegate + 'static {
         }
     }
 }
+
+impl<T> Drop for WindowController<T> {
+    /// AppKit can keep the `NSWindowController` alive past this (e.g, while the window is
+    /// closing), so break its link to the delegate, which is going away with `window`.
+    fn drop(&mut self) {
+        clear_ptr(&self.objc, WINDOW_DELEGATE_PTR);
+    }
+}
@@ -80,6 +80,7 @@ impl From<&WindowStyle> for NSUInteger {
 }
 
 /// Describes whether a window shows a title or not.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum TitleVisibility {
     /// Title is visible.
     Visible,
@@ -96,3 +97,68 @@ impl From<TitleVisibility> for NSInteger {
         }
     }
 }
+
+/// Describes how a window's toolbar is laid out in relation to its titlebar.
+///
+/// This is only supported on macOS 11.0+; on older versions, setting it does nothing.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WindowToolbarStyle {
+    /// Let the system decide, based on the window configuration.
+    Automatic,
+
+    /// The toolbar is shown below the title, with items laid out horizontally.
+    Expanded,
+
+    /// Like `Expanded`, but items are centered - typically used for preferences windows.
+    Preference,
+
+    /// The toolbar is merged into the titlebar.
+    Unified,
+
+    /// Like `Unified`, but with reduced margins. Useful for windows that want more room for
+    /// content.
+    UnifiedCompact
+}
+
+impl From<WindowToolbarStyle> for NSInteger {
+    fn from(style: WindowToolbarStyle) -> Self {
+        match style {
+            WindowToolbarStyle::Automatic => 0,
+            WindowToolbarStyle::Expanded => 1,
+            WindowToolbarStyle::Preference => 2,
+            WindowToolbarStyle::Unified => 3,
+            WindowToolbarStyle::UnifiedCompact => 4
+        }
+    }
+}
+
+/// The standard buttons in a window's titlebar.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WindowButton {
+    /// The close button (red).
+    Close,
+
+    /// The minimize button (yellow).
+    Miniaturize,
+
+    /// The zoom/full screen button (green).
+    Zoom,
+
+    /// The document icon beside the title, for windows representing a file.
+    DocumentIcon,
+
+    /// The versions menu beside the title, for windows representing a document.
+    DocumentVersions
+}
+
+impl From<WindowButton> for NSUInteger {
+    fn from(button: WindowButton) -> Self {
+        match button {
+            WindowButton::Close => 0,
+            WindowButton::Miniaturize => 1,
+            WindowButton::Zoom => 2,
+            WindowButton::DocumentIcon => 4,
+            WindowButton::DocumentVersions => 6
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! A registry of open windows, keyed by whatever makes sense for your app (a document path, an
+//! enum of panels, and so on).
+//!
+//! ```rust,no_run
+//! use cacao::macos::window::{Window, WindowConfig, WindowManager};
+//!
+//! #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+//! enum Panel {
+//!     Inspector,
+//!     Log
+//! }
+//!
+//! let windows: WindowManager<Panel> = WindowManager::new();
+//!
+//! // Opens the inspector the first time, and brings it to the front after that.
+//! windows.open_or_focus(Panel::Inspector, || {
+//!     let window = Window::new(WindowConfig::default());
+//!     window.set_title("Inspector");
+//!     window
+//! });
+//! ```
+//!
+//! Windows are removed (and dropped) once they close, however that happens.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::rc::{Rc, Weak};
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::id;
+use crate::macos::window::Window;
+use crate::notification_center::{Dispatcher, NotificationObserver};
+
+static WINDOW_WILL_CLOSE: &str = "NSWindowWillCloseNotification";
+
+/// The open windows, shared with the close observer.
+type Windows<K, T> = Rc<RefCell<HashMap<K, Rc<Window<T>>>>>;
+
+/// Tracks open windows by key, so that you can focus (rather than duplicate) a window that's
+/// already open, reach a specific window's delegate, and close everything at once.
+///
+/// This is main-thread only. To reach a window from another thread, `App::dispatch()` a message
+/// that names the key, and forward it on with `dispatch()` here.
+pub struct WindowManager<K, T = ()> {
+    windows: Windows<K, T>,
+    observer: NotificationObserver
+}
+
+impl<K, T> WindowManager<K, T>
+where
+    K: Clone + Eq + Hash + 'static,
+    T: 'static
+{
+    /// Creates an empty manager.
+    pub fn new() -> Self {
+        let windows: Windows<K, T> = Rc::new(RefCell::new(HashMap::new()));
+        let registry = Rc::downgrade(&windows);
+
+        let center: id = unsafe { msg_send![class!(NSNotificationCenter), defaultCenter] };
+        let observer = NotificationObserver::new(center, &[WINDOW_WILL_CLOSE], move |_, notification| {
+            let object: id = unsafe { msg_send![notification, object] };
+            remove_window(&registry, object);
+        });
+
+        WindowManager {
+            windows: windows,
+            observer: observer
+        }
+    }
+
+    /// Brings the window for `key` to the front if it's open; otherwise, calls `build` to create
+    /// it, shows it, and starts tracking it.
+    pub fn open_or_focus<F: FnOnce() -> Window<T>>(&self, key: K, build: F) {
+        if let Some(window) = self.window(&key) {
+            window.make_key_and_order_front();
+            return;
+        }
+
+        // `build` is called without the registry borrowed, so it's free to use the manager.
+        let window = Rc::new(build());
+        self.windows.borrow_mut().insert(key, window.clone());
+        window.show();
+    }
+
+    /// Calls `handler` with the window for `key`, if it's open.
+    pub fn with<R, F: FnOnce(&Window<T>) -> R>(&self, key: &K, handler: F) -> Option<R> {
+        self.window(key).map(|window| handler(&window))
+    }
+
+    /// Calls `handler` with each open window.
+    pub fn for_each<F: FnMut(&K, &Window<T>)>(&self, mut handler: F) {
+        let windows: Vec<(K, Rc<Window<T>>)> = self.windows.borrow().iter().map(|(key, window)| {
+            (key.clone(), window.clone())
+        }).collect();
+
+        for (key, window) in windows {
+            handler(&key, &window);
+        }
+    }
+
+    /// Returns whether the window for `key` is open.
+    pub fn contains(&self, key: &K) -> bool {
+        self.windows.borrow().contains_key(key)
+    }
+
+    /// Returns the keys of the open windows, in no particular order.
+    pub fn keys(&self) -> Vec<K> {
+        self.windows.borrow().keys().cloned().collect()
+    }
+
+    /// Returns the number of open windows.
+    pub fn len(&self) -> usize {
+        self.windows.borrow().len()
+    }
+
+    /// Returns whether there are no open windows.
+    pub fn is_empty(&self) -> bool {
+        self.windows.borrow().is_empty()
+    }
+
+    /// Closes the window for `key`, if it's open. Returns whether it was.
+    pub fn close(&self, key: &K) -> bool {
+        match self.window(key) {
+            Some(window) => {
+                window.close();
+                true
+            },
+
+            None => false
+        }
+    }
+
+    /// Closes every open window.
+    pub fn close_all(&self) {
+        self.for_each(|_, window| window.close());
+    }
+
+    /// Returns the window for `key`, without holding the registry borrowed.
+    fn window(&self, key: &K) -> Option<Rc<Window<T>>> {
+        self.windows.borrow().get(key).cloned()
+    }
+}
+
+impl<K, T> WindowManager<K, T>
+where
+    K: Clone + Eq + Hash + 'static,
+    T: Dispatcher + 'static
+{
+    /// Hands `message` to the `Dispatcher::on_message()` of the delegate for the window at `key`.
+    /// Returns whether the window was open to receive it.
+    pub fn dispatch(&self, key: &K, message: T::Message) -> bool {
+        let window = match self.window(key) {
+            Some(window) => window,
+            None => { return false; }
+        };
+
+        match &window.delegate {
+            Some(delegate) => {
+                delegate.on_message(message);
+                true
+            },
+
+            None => false
+        }
+    }
+}
+
+impl<K, T> Default for WindowManager<K, T>
+where
+    K: Clone + Eq + Hash + 'static,
+    T: 'static
+{
+    fn default() -> Self {
+        WindowManager::new()
+    }
+}
+
+impl<K: fmt::Debug, T> fmt::Debug for WindowManager<K, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WindowManager")
+            .field("windows", &self.windows.borrow().keys().collect::<Vec<_>>())
+            .field("observer", &self.observer)
+            .finish()
+    }
+}
+
+/// Stops tracking the window backed by `object`, if it's one of ours. The window itself is dropped
+/// on the next pass of the run loop, rather than in the middle of it closing (which would cut off
+/// its delegate's `will_close()`).
+fn remove_window<K, T>(registry: &Weak<RefCell<HashMap<K, Rc<Window<T>>>>>, object: id)
+where
+    K: Clone + Eq + Hash + 'static,
+    T: 'static
+{
+    let windows = match registry.upgrade() {
+        Some(windows) => windows,
+        None => { return; }
+    };
+
+    let key = windows.borrow().iter().find(|(_, window)| {
+        &*window.objc as *const Object as id == object
+    }).map(|(key, _)| key.clone());
+
+    let window = key.and_then(|key| windows.borrow_mut().remove(&key));
+
+    if let Some(window) = window {
+        crate::executor::spawn(async move {
+            drop(window);
+        });
+    }
+}
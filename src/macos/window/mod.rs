@@ -19,15 +19,23 @@ use objc::{msg_send, sel, sel_impl, class};
 use objc::runtime::Object;
 use objc_id::ShareId;
 
+use crate::dispatch::assert_main_thread;
 use crate::color::Color;
-use crate::foundation::{id, nil, YES, NO, NSString, NSInteger, NSUInteger};
+use crate::foundation::{id, nil, YES, NO, BOOL, NSString, NSInteger, NSUInteger};
+use crate::geometry::Rect;
 use crate::layout::traits::Layout;
+#[cfg(feature = "toolbar")]
 use crate::macos::toolbar::{Toolbar, ToolbarDelegate};
-use crate::utils::Controller;
+use crate::screen::Screen;
+use crate::undo::UndoManager;
+use crate::utils::{clear_ptr, Controller};
 
 mod class;
 use class::{register_window_class, register_window_class_with_delegate};
 
+mod builder;
+pub use builder::WindowBuilder;
+
 mod config;
 pub use config::WindowConfig;
 
@@ -37,6 +45,15 @@ pub use controller::WindowController;
 mod enums;
 pub use enums::*;
 
+mod manager;
+pub use manager::WindowManager;
+
+#[cfg(feature = "serde")]
+mod restoration;
+
+#[cfg(feature = "serde")]
+pub use restoration::RestorableState;
+
 mod traits;
 pub use traits::WindowDelegate;
 
@@ -67,6 +84,8 @@ impl Window {
     /// Why the config? Well, certain properties of windows are really not meant to be altered
     /// after we initialize the backing `NSWindow`.
     pub fn new(config: WindowConfig) -> Window {
+        assert_main_thread("Window::new");
+
         let objc = unsafe {
             // This behavior might make sense to keep as default (YES), but I think the majority of
             // apps that would use this toolkit wouldn't be tab-oriented...
@@ -104,6 +123,24 @@ impl Window {
             delegate: None
         }
     }
+
+    /// Returns a builder, for configuring a `Window` in one go; see `WindowBuilder`.
+    pub fn builder() -> WindowBuilder {
+        WindowBuilder::default()
+    }
+
+    /// Wraps an existing `NSWindow` (e.g, one created by another framework), retaining it. The
+    /// window keeps whatever delegate it already has; this `Window` never installs one.
+    ///
+    /// # Safety
+    ///
+    /// `window` must be a valid, non-nil pointer to an `NSWindow`, or a subclass of one.
+    pub unsafe fn from_ptr(window: id) -> Window {
+        Window {
+            objc: ShareId::from_ptr(window),
+            delegate: None
+        }
+    }
 }
 
 impl<T> Window<T> where T: WindowDelegate + 'static {
@@ -112,6 +149,14 @@ impl<T> Window<T> where T: WindowDelegate + 'static {
     /// enables easier structure of your codebase, and in a way simulates traditional class based
     /// architectures... just without the subclassing.
     pub fn with(config: WindowConfig, delegate: T) -> Self {
+        Window::with_setup(config, delegate, |_| {})
+    }
+
+    /// Like `with()`, but runs `setup` on the window before the delegate's `did_load()` - so
+    /// that anything the delegate does there takes precedence.
+    pub(crate) fn with_setup<F: FnOnce(&Window)>(config: WindowConfig, delegate: T, setup: F) -> Self {
+        assert_main_thread("Window::with");
+
         let mut delegate = Box::new(delegate);
         
         let objc = unsafe {
@@ -155,10 +200,13 @@ impl<T> Window<T> where T: WindowDelegate + 'static {
         };
 
         {
-            (&mut delegate).did_load(Window {
+            let handle = Window {
                 delegate: None,
                 objc: objc.clone()
-            });
+            };
+
+            setup(&handle);
+            (&mut delegate).did_load(handle);
         }
 
         Window {
@@ -215,6 +263,31 @@ impl<T> Window<T> {
         }
     }
 
+    /// Opts this window into state restoration, under the given identifier. After a relaunch,
+    /// AppKit will look for a window with the same identifier and hand it the saved state via
+    /// `WindowDelegate::decode_restorable_state`.
+    ///
+    /// Identifiers should be unique per window, and stable across launches.
+    #[cfg(feature = "serde")]
+    pub fn set_restoration_identifier(&self, identifier: &str) {
+        let identifier = NSString::new(identifier);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setIdentifier:identifier];
+            let _: () = msg_send![&*self.objc, setRestorationClass:restoration::register_window_restoration_class()];
+            let _: () = msg_send![&*self.objc, setRestorable:YES];
+        }
+    }
+
+    /// Marks this window's restorable state as stale, so AppKit will ask for it again (via
+    /// `WindowDelegate::encode_restorable_state`) at the next opportunity. Call this whenever
+    /// something you save changes.
+    pub fn invalidate_restorable_state(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, invalidateRestorableState];
+        }
+    }
+
     /// Sets the minimum size this window can shrink to.
     pub fn set_minimum_content_size<F: Into<f64>>(&self, width: F, height: F) {
         unsafe {
@@ -232,6 +305,7 @@ impl<T> Window<T> {
     }
 
     /// Used for setting a toolbar on this window. 
+    #[cfg(feature = "toolbar")]
     pub fn set_toolbar<TC: ToolbarDelegate>(&self, toolbar: &Toolbar<TC>) {
         unsafe {
             let _: () = msg_send![&*self.objc, setToolbar:&*toolbar.objc];
@@ -246,6 +320,17 @@ impl<T> Window<T> {
         }
     }
 
+    /// Sets how the toolbar is laid out in relation to the titlebar. To get the macOS 11+ look
+    /// where the toolbar is merged into the titlebar, use `WindowToolbarStyle::Unified` (and
+    /// ideally `WindowStyle::FullSizeContentView` on your `WindowConfig`).
+    pub fn set_toolbar_style(&self, style: WindowToolbarStyle) {
+        let style: NSInteger = style.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setToolbarStyle:style];
+        }
+    }
+
     /// Set whether the toolbar toggle button is shown. Has no effect if no toolbar exists on this
     /// window.
     pub fn set_shows_toolbar_button(&self, shows: bool) {
@@ -328,17 +413,109 @@ impl<T> Window<T> {
         }
     }
 
+    /// Returns the undo manager for this window, if it has one. Operations registered here are
+    /// what the standard Edit ▸ Undo/Redo menu items act on while this window is key.
+    pub fn undo_manager(&self) -> Option<UndoManager> {
+        UndoManager::with(unsafe {
+            msg_send![&*self.objc, undoManager]
+        })
+    }
+
     /// Miniaturize this window.
     pub fn miniaturize(&self) {
         unsafe {
-            let _: () = msg_send![&*self.objc, miniaturize];
+            let _: () = msg_send![&*self.objc, miniaturize:nil];
         }
     }
 
     /// De-mimizes this window.
     pub fn deminiaturize(&self) {
         unsafe {
-            let _: () = msg_send![&*self.objc, deminiaturize];
+            let _: () = msg_send![&*self.objc, deminiaturize:nil];
+        }
+    }
+
+    /// Toggles this window between its standard (e.g, fill the screen) and user-chosen sizes,
+    /// as the zoom button does when Option-clicked.
+    pub fn zoom(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, zoom:nil];
+        }
+    }
+
+    /// Returns whether this window is zoomed.
+    pub fn is_zoomed(&self) -> bool {
+        let zoomed: BOOL = unsafe { msg_send![&*self.objc, isZoomed] };
+        zoomed == YES
+    }
+
+    /// Brings this window to the front of its level, without making it key.
+    pub fn order_front(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, orderFront:nil];
+        }
+    }
+
+    /// Sends this window to the back of its level.
+    pub fn order_back(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, orderBack:nil];
+        }
+    }
+
+    /// Takes this window off screen, without closing it - `show()` or `order_front()` brings it
+    /// back.
+    pub fn order_out(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, orderOut:nil];
+        }
+    }
+
+    /// Hides (or shows) one of the titlebar buttons. Hiding all three traffic lights is the
+    /// first step to drawing your own.
+    pub fn set_button_hidden(&self, button: WindowButton, hidden: bool) {
+        unsafe {
+            let button: id = msg_send![&*self.objc, standardWindowButton:NSUInteger::from(button)];
+
+            if button != nil {
+                let _: () = msg_send![button, setHidden:match hidden {
+                    true => YES,
+                    false => NO
+                }];
+            }
+        }
+    }
+
+    /// Disables (or enables) one of the titlebar buttons. It's still shown, but dimmed.
+    pub fn set_button_enabled(&self, button: WindowButton, enabled: bool) {
+        unsafe {
+            let button: id = msg_send![&*self.objc, standardWindowButton:NSUInteger::from(button)];
+
+            if button != nil {
+                let _: () = msg_send![button, setEnabled:match enabled {
+                    true => YES,
+                    false => NO
+                }];
+            }
+        }
+    }
+
+    /// Returns where one of the titlebar buttons sits, in the window's coordinates (from the
+    /// bottom left), or `None` if the window doesn't have it. Useful for lining up custom
+    /// titlebar content with the traffic lights.
+    pub fn button_frame(&self, button: WindowButton) -> Option<Rect> {
+        unsafe {
+            let button: id = msg_send![&*self.objc, standardWindowButton:NSUInteger::from(button)];
+
+            match button == nil {
+                true => None,
+                false => {
+                    let frame: CGRect = msg_send![button, frame];
+                    let superview: id = msg_send![button, superview];
+                    let frame: CGRect = msg_send![superview, convertRect:frame toView:nil];
+                    Some(frame.into())
+                }
+            }
         }
     }
 
@@ -460,6 +637,17 @@ impl<T> Window<T> {
         }
     }
 
+    /// Returns the screen the window is on - the one containing most of it - or `None` if the
+    /// window is offscreen.
+    pub fn screen(&self) -> Option<Screen> {
+        let screen: id = unsafe { msg_send![&*self.objc, screen] };
+
+        match screen == nil {
+            true => None,
+            false => Some(Screen::with(screen))
+        }
+    }
+
     /// Given a window and callback handler, will run it as a "sheet" (model-ish) and then run the
     /// handler once the sheet is dismissed.
     ///
@@ -501,9 +689,12 @@ impl<T> Drop for Window<T> {
     /// intentionally don't provide this when cloning it as a handler. This ensures that we only
     /// release the backing Window when the original `Window<T>` is dropped.
     ///
-    /// Well, theoretically.
+    /// Well, theoretically. The delegate pointer is cleared as well, so that anything still in
+    /// flight for the window (e.g, a pending resize) is ignored rather than hitting freed memory.
     fn drop(&mut self) {
         if self.delegate.is_some() {
+            clear_ptr(&self.objc, WINDOW_DELEGATE_PTR);
+
             unsafe {
                 // Break the delegate - this shouldn't be an issue, but we should strive to be safe
                 // here anyway.
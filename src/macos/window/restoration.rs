@@ -0,0 +1,133 @@
+//! Implements window state restoration (`NSWindowRestoration`), so that windows can reopen with
+//! their prior content after the app relaunches.
+//!
+//! To opt a window in, give it an identifier via `Window::set_restoration_identifier()`, and
+//! implement `encode_restorable_state` and `decode_restorable_state` on your `WindowDelegate`.
+//! Any value that implements `serde::Serialize` can be stored; it's encoded as JSON into the
+//! (secure) coder that AppKit hands us.
+//!
+//! AppKit restores windows between `will_finish_launching` and `did_finish_launching`, and it
+//! expects the window to exist at that point - so create your restorable windows in
+//! `will_finish_launching`. Windows that aren't found are skipped.
+
+use std::sync::Once;
+
+use block::Block;
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::error::Error;
+use crate::foundation::{id, nil, YES, NO, BOOL, NSArray, NSString};
+use crate::utils::load_or_register_class;
+
+/// `NSUserCancelledError`, which AppKit treats as a quiet failure when restoring.
+const USER_CANCELLED_ERROR: usize = 3072;
+
+/// A handle to the coder AppKit uses to save and restore a window's state. This is only valid for
+/// the duration of the `WindowDelegate` callback it's passed to.
+#[derive(Debug)]
+pub struct RestorableState {
+    coder: id
+}
+
+impl RestorableState {
+    /// Wraps an `NSCoder`.
+    pub(crate) fn with(coder: id) -> Self {
+        RestorableState {
+            coder: coder
+        }
+    }
+
+    /// Encodes `value` under `key`. Returns an error if `value` can't be serialized.
+    pub fn encode<T: Serialize>(&mut self, key: &str, value: &T) -> Result<(), serde_json::Error> {
+        let json = serde_json::to_string(value)?;
+        let json = NSString::new(&json);
+        let key = NSString::new(key);
+
+        unsafe {
+            let _: () = msg_send![self.coder, encodeObject:json forKey:key];
+        }
+
+        Ok(())
+    }
+
+    /// Decodes the value stored under `key`. Returns `None` if there's no such value, or if it
+    /// can't be deserialized into `T` (e.g, because your type changed since it was saved).
+    pub fn decode<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let key = NSString::new(key);
+
+        let json: id = unsafe {
+            msg_send![self.coder, decodeObjectOfClass:class!(NSString) forKey:key]
+        };
+
+        if json == nil {
+            return None;
+        }
+
+        serde_json::from_str(NSString::wrap(json).to_str()).ok()
+    }
+}
+
+/// Called when AppKit wants a window back during restoration. We look for an existing window with
+/// the matching identifier, and hand that back; if there isn't one, we let AppKit know (quietly).
+extern fn restore_window(_: &Class, _: Sel, identifier: id, _state: id, handler: id) {
+    let window = unsafe {
+        let app: id = msg_send![class!(NSApplication), sharedApplication];
+        let windows = NSArray::wrap(msg_send![app, windows]);
+
+        windows.map(|window| window).into_iter().find(|window| {
+            let window_identifier: id = msg_send![*window, identifier];
+
+            match window_identifier == nil {
+                true => false,
+                false => {
+                    let is_equal: BOOL = msg_send![window_identifier, isEqualToString:identifier];
+
+                    match is_equal {
+                        YES => true,
+                        NO => false,
+                        _ => unreachable!()
+                    }
+                }
+            }
+        })
+    };
+
+    let handler = handler as *mut Block<(id, id), ()>;
+
+    unsafe {
+        match window {
+            Some(window) => (*handler).call((window, nil)),
+
+            None => {
+                let error = Error::Cocoa {
+                    code: USER_CANCELLED_ERROR,
+                    domain: "NSCocoaErrorDomain".to_string(),
+                    description: "No window exists for this restoration identifier.".to_string()
+                };
+
+                (*handler).call((nil, error.into_nserror()));
+            }
+        }
+    }
+}
+
+/// Registers a class conforming to `NSWindowRestoration`, which windows point to via
+/// `setRestorationClass:`.
+pub(crate) fn register_window_restoration_class() -> *const Class {
+    static mut RESTORATION_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSObject);
+        RESTORATION_CLASS = load_or_register_class(superclass, "RSTWindowRestoration", |decl| {
+            decl.add_class_method(sel!(restoreWindowWithIdentifier:state:completionHandler:), restore_window as extern fn(&Class, _, id, id, id));
+        });
+    });
+
+    unsafe { RESTORATION_CLASS }
+}
@@ -2,9 +2,13 @@
 //! module. There's a few different ones, and it's just... cleaner, if
 //! it's organized here.
 
+use crate::events::Command;
 use crate::macos::app::PresentationOption;
 use crate::macos::window::Window;
 
+#[cfg(feature = "serde")]
+use crate::macos::window::RestorableState;
+
 /// Lifecycle events for anything that `impl Window`'s. These map to the standard Cocoa
 /// lifecycle methods, but mix in a few extra things to handle offering configuration tools
 /// in lieu of subclasses.
@@ -119,6 +123,22 @@ pub trait WindowDelegate {
     /// Fired when the Window receives an `update` message from higher up in the chain.
     fn did_update(&self) {}
 
+    /// Fired when AppKit is saving this window's state (e.g, before the app quits). Encode
+    /// whatever you need to put the window back the way it was. Only called for windows that have
+    /// a restoration identifier.
+    #[cfg(feature = "serde")]
+    fn encode_restorable_state(&self, _state: &mut RestorableState) {}
+
+    /// Fired after a relaunch, once AppKit has restored this window; decode what you saved in
+    /// `encode_restorable_state` here.
+    #[cfg(feature = "serde")]
+    fn decode_restorable_state(&self, _state: &RestorableState) {}
+
+    /// Called when a menu item or toolbar item that sends `command` along the responder chain
+    /// reaches this window, to ask whether it should be enabled. AppKit's own answer (e.g, for
+    /// Close or Minimize) is asked first; this can only turn an item off.
+    fn validate(&self, _command: Command) -> bool { true }
+
     /// If you want your window to close when the `ESC` key is hit, implement this.
     /// This is mostly useful for windows that present as modal sheets.
     fn cancel(&self) {}
@@ -0,0 +1,235 @@
+//! Wraps `NSRunningApplication`, for inspecting and controlling other running applications (and
+//! being notified as they come and go).
+//!
+//! ```rust,no_run
+//! use cacao::macos::workspace::{ApplicationEvent, RunningApplication};
+//!
+//! for app in RunningApplication::all() {
+//!     println!("{:?} ({:?})", app.name(), app.bundle_identifier());
+//! }
+//!
+//! // Keep this around for as long as you want to be notified.
+//! let observer = RunningApplication::observe(|event| {
+//!     if let ApplicationEvent::Launched(app) = event {
+//!         println!("Launched: {:?}", app.name());
+//!     }
+//! });
+//! ```
+
+use std::rc::Rc;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, NSArray, NSInteger, NSString, NSUInteger};
+use crate::image::Image;
+use crate::macos::app::ActivationPolicy;
+use crate::macos::workspace::{observe, shared_workspace};
+use crate::notification_center::NotificationObserver;
+use crate::utils::as_bool;
+
+/// `NSApplicationActivateAllWindows | NSApplicationActivateIgnoringOtherApps`.
+const ACTIVATE_OPTIONS: NSUInteger = (1 << 0) | (1 << 1);
+
+static DID_LAUNCH: &str = "NSWorkspaceDidLaunchApplicationNotification";
+static DID_TERMINATE: &str = "NSWorkspaceDidTerminateApplicationNotification";
+static DID_ACTIVATE: &str = "NSWorkspaceDidActivateApplicationNotification";
+static DID_DEACTIVATE: &str = "NSWorkspaceDidDeactivateApplicationNotification";
+static DID_HIDE: &str = "NSWorkspaceDidHideApplicationNotification";
+static DID_UNHIDE: &str = "NSWorkspaceDidUnhideApplicationNotification";
+
+/// The `userInfo` key that holds the application a notification is about.
+static APPLICATION_KEY: &str = "NSWorkspaceApplicationKey";
+
+/// Wraps an `NSString` that may be `nil`.
+fn optional_string(string: id) -> Option<String> {
+    match string == nil {
+        true => None,
+        false => Some(NSString::wrap(string).to_string())
+    }
+}
+
+/// Something that happened to a running application.
+#[derive(Clone, Debug)]
+pub enum ApplicationEvent {
+    /// The application launched.
+    Launched(RunningApplication),
+
+    /// The application terminated.
+    Terminated(RunningApplication),
+
+    /// The application became active (frontmost).
+    Activated(RunningApplication),
+
+    /// The application resigned active status.
+    Deactivated(RunningApplication),
+
+    /// The application was hidden.
+    Hidden(RunningApplication),
+
+    /// The application was unhidden.
+    Unhidden(RunningApplication)
+}
+
+/// A wrapper for `NSRunningApplication`. This is cheap to clone; clones refer to the same
+/// application.
+#[derive(Clone, Debug)]
+pub struct RunningApplication {
+    /// The underlying `NSRunningApplication`.
+    pub objc: ShareId<Object>
+}
+
+impl RunningApplication {
+    /// Wraps an existing `NSRunningApplication`.
+    pub(crate) fn with(application: id) -> Self {
+        RunningApplication {
+            objc: unsafe { ShareId::from_ptr(application) }
+        }
+    }
+
+    /// Returns the application for the current process.
+    pub fn current() -> Self {
+        RunningApplication::with(unsafe {
+            msg_send![class!(NSRunningApplication), currentApplication]
+        })
+    }
+
+    /// Returns all running applications visible to the current user.
+    pub fn all() -> Vec<Self> {
+        NSArray::wrap(unsafe {
+            msg_send![shared_workspace(), runningApplications]
+        }).map(|application| RunningApplication::with(application))
+    }
+
+    /// Returns the frontmost application (the one receiving key events), if there is one.
+    pub fn frontmost() -> Option<Self> {
+        let application: id = unsafe {
+            msg_send![shared_workspace(), frontmostApplication]
+        };
+
+        match application == nil {
+            true => None,
+            false => Some(RunningApplication::with(application))
+        }
+    }
+
+    /// Returns all running instances of the application with the given bundle identifier.
+    pub fn with_bundle_identifier(bundle_identifier: &str) -> Vec<Self> {
+        let bundle_identifier = NSString::new(bundle_identifier);
+
+        NSArray::wrap(unsafe {
+            msg_send![class!(NSRunningApplication), runningApplicationsWithBundleIdentifier:bundle_identifier]
+        }).map(|application| RunningApplication::with(application))
+    }
+
+    /// Calls `handler` (on the main thread) whenever an application launches, terminates, or
+    /// changes its active or hidden state. Keep the returned `NotificationObserver` around for as
+    /// long as you want to be notified.
+    pub fn observe<F>(handler: F) -> NotificationObserver
+    where
+        F: Fn(ApplicationEvent) + 'static
+    {
+        let handler = Rc::new(handler);
+        let names = [DID_LAUNCH, DID_TERMINATE, DID_ACTIVATE, DID_DEACTIVATE, DID_HIDE, DID_UNHIDE];
+
+        observe(&names, move |name, notification| {
+            let application = unsafe {
+                let user_info: id = msg_send![notification, userInfo];
+                let key = NSString::new(APPLICATION_KEY);
+                let application: id = msg_send![user_info, objectForKey:key];
+                application
+            };
+
+            if application == nil {
+                return;
+            }
+
+            let application = RunningApplication::with(application);
+
+            handler(match name {
+                n if n == DID_LAUNCH => ApplicationEvent::Launched(application),
+                n if n == DID_TERMINATE => ApplicationEvent::Terminated(application),
+                n if n == DID_ACTIVATE => ApplicationEvent::Activated(application),
+                n if n == DID_DEACTIVATE => ApplicationEvent::Deactivated(application),
+                n if n == DID_HIDE => ApplicationEvent::Hidden(application),
+                _ => ApplicationEvent::Unhidden(application)
+            });
+        })
+    }
+
+    /// Returns the bundle identifier, if the application has one.
+    pub fn bundle_identifier(&self) -> Option<String> {
+        optional_string(unsafe { msg_send![&*self.objc, bundleIdentifier] })
+    }
+
+    /// Returns the localized name of the application, if available.
+    pub fn name(&self) -> Option<String> {
+        optional_string(unsafe { msg_send![&*self.objc, localizedName] })
+    }
+
+    /// Returns the application's icon, if available.
+    pub fn icon(&self) -> Option<Image> {
+        let icon: id = unsafe { msg_send![&*self.objc, icon] };
+
+        match icon == nil {
+            true => None,
+            false => Some(Image::with(icon))
+        }
+    }
+
+    /// Returns the process identifier, or `-1` if the application has no process.
+    pub fn process_identifier(&self) -> i32 {
+        unsafe { msg_send![&*self.objc, processIdentifier] }
+    }
+
+    /// Returns the activation policy, which tells you whether it's an ordinary app (with a Dock
+    /// icon), an accessory, or a background agent.
+    pub fn activation_policy(&self) -> ActivationPolicy {
+        let policy: NSInteger = unsafe { msg_send![&*self.objc, activationPolicy] };
+        policy.into()
+    }
+
+    /// Returns whether this is the frontmost application.
+    pub fn is_active(&self) -> bool {
+        as_bool(unsafe { msg_send![&*self.objc, isActive] })
+    }
+
+    /// Returns whether the application is hidden.
+    pub fn is_hidden(&self) -> bool {
+        as_bool(unsafe { msg_send![&*self.objc, isHidden] })
+    }
+
+    /// Returns whether the application has terminated.
+    pub fn is_terminated(&self) -> bool {
+        as_bool(unsafe { msg_send![&*self.objc, isTerminated] })
+    }
+
+    /// Brings the application (and all of its windows) to the front. Returns `false` if the
+    /// application has quit, or can't be activated.
+    pub fn activate(&self) -> bool {
+        as_bool(unsafe { msg_send![&*self.objc, activateWithOptions:ACTIVATE_OPTIONS] })
+    }
+
+    /// Hides the application. Returns whether the request was sent.
+    pub fn hide(&self) -> bool {
+        as_bool(unsafe { msg_send![&*self.objc, hide] })
+    }
+
+    /// Unhides the application. Returns whether the request was sent.
+    pub fn unhide(&self) -> bool {
+        as_bool(unsafe { msg_send![&*self.objc, unhide] })
+    }
+
+    /// Asks the application to quit, as if the user chose Quit. The application may refuse (e.g,
+    /// to save changes). Returns whether the request was sent.
+    pub fn terminate(&self) -> bool {
+        as_bool(unsafe { msg_send![&*self.objc, terminate] })
+    }
+
+    /// Quits the application immediately, without giving it a chance to save. Returns whether the
+    /// request was sent.
+    pub fn force_terminate(&self) -> bool {
+        as_bool(unsafe { msg_send![&*self.objc, forceTerminate] })
+    }
+}
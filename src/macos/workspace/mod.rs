@@ -0,0 +1,27 @@
+//! Wraps parts of `NSWorkspace`, which provides information about (and notifications from) the
+//! system as a whole - e.g, which applications are running, or when the system goes to sleep.
+
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::id;
+use crate::notification_center::NotificationObserver;
+
+mod application;
+pub use application::{ApplicationEvent, RunningApplication};
+
+mod system;
+pub use system::{observe_system_events, SystemEvent};
+
+/// Returns the shared `NSWorkspace`.
+pub(crate) fn shared_workspace() -> id {
+    unsafe { msg_send![class!(NSWorkspace), sharedWorkspace] }
+}
+
+/// Registers `handler` with the workspace notification center for each of the given names.
+pub(crate) fn observe<F>(names: &[&str], handler: F) -> NotificationObserver
+where
+    F: Fn(&str, id) + Clone + 'static
+{
+    let center: id = unsafe { msg_send![shared_workspace(), notificationCenter] };
+    NotificationObserver::new(center, names, handler)
+}
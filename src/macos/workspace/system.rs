@@ -0,0 +1,77 @@
+//! Notifications about the state of the system as a whole - sleep and wake, displays going to
+//! sleep, and fast user switching.
+//!
+//! ```rust,no_run
+//! use cacao::macos::workspace::{observe_system_events, SystemEvent};
+//!
+//! // Keep this around for as long as you want to be notified.
+//! let observer = observe_system_events(|event| match event {
+//!     SystemEvent::WillSleep => { /* Pause syncing... */ },
+//!     SystemEvent::DidWake => { /* ...and resume it. */ },
+//!     _ => {}
+//! });
+//! ```
+
+use std::rc::Rc;
+
+use crate::macos::workspace::observe;
+use crate::notification_center::NotificationObserver;
+
+static WILL_SLEEP: &str = "NSWorkspaceWillSleepNotification";
+static DID_WAKE: &str = "NSWorkspaceDidWakeNotification";
+static WILL_POWER_OFF: &str = "NSWorkspaceWillPowerOffNotification";
+static SCREENS_DID_SLEEP: &str = "NSWorkspaceScreensDidSleepNotification";
+static SCREENS_DID_WAKE: &str = "NSWorkspaceScreensDidWakeNotification";
+static SESSION_DID_BECOME_ACTIVE: &str = "NSWorkspaceSessionDidBecomeActiveNotification";
+static SESSION_DID_RESIGN_ACTIVE: &str = "NSWorkspaceSessionDidResignActiveNotification";
+
+/// Something that happened to the system as a whole.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SystemEvent {
+    /// The system is about to sleep. You have a short amount of time to wrap up work.
+    WillSleep,
+
+    /// The system woke from sleep.
+    DidWake,
+
+    /// The user asked to log out, restart or shut down.
+    WillPowerOff,
+
+    /// The displays went to sleep.
+    ScreensDidSleep,
+
+    /// The displays woke up.
+    ScreensDidWake,
+
+    /// The user switched back to this session (via fast user switching).
+    SessionDidBecomeActive,
+
+    /// The user switched away from this session (via fast user switching).
+    SessionDidResignActive
+}
+
+/// Calls `handler` (on the main thread) whenever the system sleeps or wakes, the displays sleep or
+/// wake, or the user switches in or out of this session. Keep the returned `NotificationObserver`
+/// around for as long as you want to be notified.
+pub fn observe_system_events<F>(handler: F) -> NotificationObserver
+where
+    F: Fn(SystemEvent) + 'static
+{
+    let handler = Rc::new(handler);
+    let names = [
+        WILL_SLEEP, DID_WAKE, WILL_POWER_OFF, SCREENS_DID_SLEEP, SCREENS_DID_WAKE,
+        SESSION_DID_BECOME_ACTIVE, SESSION_DID_RESIGN_ACTIVE
+    ];
+
+    observe(&names, move |name, _| {
+        handler(match name {
+            n if n == WILL_SLEEP => SystemEvent::WillSleep,
+            n if n == DID_WAKE => SystemEvent::DidWake,
+            n if n == WILL_POWER_OFF => SystemEvent::WillPowerOff,
+            n if n == SCREENS_DID_SLEEP => SystemEvent::ScreensDidSleep,
+            n if n == SCREENS_DID_WAKE => SystemEvent::ScreensDidWake,
+            n if n == SESSION_DID_BECOME_ACTIVE => SystemEvent::SessionDidBecomeActive,
+            _ => SystemEvent::SessionDidResignActive
+        });
+    })
+}
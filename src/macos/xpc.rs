@@ -0,0 +1,325 @@
+//! A client for talking to XPC services and helper tools - e.g, the privileged or sandboxed
+//! helper that ships inside your app bundle.
+//!
+//! Rather than describing the remote interface via an Objective-C protocol, you describe it with
+//! Rust types: a request type and a response type (typically enums), serialized with `serde`.
+//! This requires the `serde` feature.
+//!
+//! ```rust,no_run
+//! use serde::{Deserialize, Serialize};
+//! use cacao::macos::xpc::XpcConnection;
+//!
+//! #[derive(Serialize)]
+//! enum Request {
+//!     Compress { path: String }
+//! }
+//!
+//! #[derive(Deserialize)]
+//! enum Response {
+//!     Compressed { bytes: u64 },
+//!     Failed(String)
+//! }
+//!
+//! let connection: XpcConnection<Request, Response> = XpcConnection::service("com.example.MyApp.Compressor").unwrap();
+//!
+//! connection.set_invalidation_handler(|| {
+//!     eprintln!("Helper went away.");
+//! });
+//!
+//! connection.send_with_reply(&Request::Compress { path: "/tmp/file".into() }, |response| {
+//!     println!("{:?}", response.is_ok());
+//! }).unwrap();
+//! ```
+//!
+//! ## Wire format
+//! Each message is an XPC dictionary with a single `message` key, holding the JSON-encoded
+//! value as data. Replies are expected in the same format - so your helper should read the
+//! `message` key from the incoming dictionary, and set one on the dictionary it gets from
+//! `xpc_dictionary_create_reply()`.
+
+use std::ffi::CString;
+use std::fmt;
+use std::marker::PhantomData;
+use std::os::raw::{c_char, c_void};
+use std::slice;
+use std::sync::{Arc, Mutex};
+
+use block::{Block, ConcreteBlock};
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// The dictionary key messages are stored under.
+static MESSAGE_KEY: &str = "message";
+
+/// `XPC_CONNECTION_MACH_SERVICE_PRIVILEGED`.
+const MACH_SERVICE_PRIVILEGED: u64 = 1 << 1;
+
+/// An opaque XPC object (`xpc_object_t`).
+type XpcObject = *mut c_void;
+
+/// Opaque storage for the XPC type and error globals, which we only ever compare addresses of.
+#[repr(C)]
+struct XpcOpaque {
+    _private: [u8; 0]
+}
+
+extern "C" {
+    static _xpc_type_error: XpcOpaque;
+    static _xpc_type_dictionary: XpcOpaque;
+    static _xpc_error_connection_interrupted: XpcOpaque;
+    static _xpc_error_connection_invalid: XpcOpaque;
+
+    fn xpc_connection_create(name: *const c_char, queue: *mut c_void) -> XpcObject;
+    fn xpc_connection_create_mach_service(name: *const c_char, queue: *mut c_void, flags: u64) -> XpcObject;
+    fn xpc_connection_set_event_handler(connection: XpcObject, handler: *mut c_void);
+    fn xpc_connection_resume(connection: XpcObject);
+    fn xpc_connection_cancel(connection: XpcObject);
+    fn xpc_connection_send_message(connection: XpcObject, message: XpcObject);
+    fn xpc_connection_send_message_with_reply(connection: XpcObject, message: XpcObject, queue: *mut c_void, handler: *mut c_void);
+    fn xpc_dictionary_create(keys: *const *const c_char, values: *const XpcObject, count: usize) -> XpcObject;
+    fn xpc_dictionary_set_data(dictionary: XpcObject, key: *const c_char, bytes: *const c_void, length: usize);
+    fn xpc_dictionary_get_data(dictionary: XpcObject, key: *const c_char, length: *mut usize) -> *const c_void;
+    fn xpc_get_type(object: XpcObject) -> *const XpcOpaque;
+    fn xpc_release(object: XpcObject);
+}
+
+/// Errors that can occur when sending a message, or waiting on a reply.
+#[derive(Clone, Debug, PartialEq)]
+pub enum XpcError {
+    /// The remote process exited or crashed. The connection is still usable; the service will be
+    /// relaunched on the next message.
+    Interrupted,
+
+    /// The connection is no longer usable - e.g, the service couldn't be found, or the connection
+    /// was cancelled.
+    Invalidated,
+
+    /// The message couldn't be encoded, or the reply couldn't be decoded.
+    Serialization(String),
+
+    /// The reply wasn't in the expected format.
+    UnexpectedReply,
+
+    /// The service name contained a nul byte, so couldn't be passed to XPC.
+    InvalidName
+}
+
+impl fmt::Display for XpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            XpcError::Interrupted => write!(f, "The XPC connection was interrupted."),
+            XpcError::Invalidated => write!(f, "The XPC connection was invalidated."),
+            XpcError::Serialization(message) => write!(f, "XPC message serialization failed: {}", message),
+            XpcError::UnexpectedReply => write!(f, "The XPC reply was not in the expected format."),
+            XpcError::InvalidName => write!(f, "The XPC service name contained a nul byte.")
+        }
+    }
+}
+
+impl std::error::Error for XpcError {}
+
+/// Maps an XPC error object to an `XpcError`.
+fn error_for(object: XpcObject) -> XpcError {
+    unsafe {
+        match object as *const XpcOpaque == &_xpc_error_connection_interrupted as *const XpcOpaque {
+            true => XpcError::Interrupted,
+            false => XpcError::Invalidated
+        }
+    }
+}
+
+/// Returns whether `object` is an XPC error.
+fn is_error(object: XpcObject) -> bool {
+    unsafe { xpc_get_type(object) == &_xpc_type_error as *const XpcOpaque }
+}
+
+/// The handlers that can be set on a connection. These are called from a background queue.
+#[derive(Default)]
+struct ConnectionHandlers {
+    interruption: Option<Box<dyn Fn() + Send + Sync + 'static>>,
+    invalidation: Option<Box<dyn Fn() + Send + Sync + 'static>>
+}
+
+/// A connection to an XPC service, typed by the messages you send (`Request`) and the replies you
+/// get back (`Response`).
+///
+/// Replies and connection events are delivered on a background queue. Dropping the connection
+/// cancels it.
+pub struct XpcConnection<Request, Response> {
+    connection: XpcObject,
+    handlers: Arc<Mutex<ConnectionHandlers>>,
+    _types: PhantomData<(Request, Response)>
+}
+
+impl<Request, Response> XpcConnection<Request, Response>
+where
+    Request: Serialize,
+    Response: DeserializeOwned + 'static
+{
+    /// Connects to an XPC service bundled with your application (in `Contents/XPCServices`).
+    /// Returns `XpcError::InvalidName` if `name` contains a nul byte.
+    pub fn service(name: &str) -> Result<Self, XpcError> {
+        let name = CString::new(name).map_err(|_| XpcError::InvalidName)?;
+
+        Ok(XpcConnection::with(unsafe {
+            xpc_connection_create(name.as_ptr(), std::ptr::null_mut())
+        }))
+    }
+
+    /// Connects to a launchd Mach service (e.g, a helper tool or launch agent). Set `privileged`
+    /// if the service runs as root (e.g, a helper installed via `SMJobBless`). Returns
+    /// `XpcError::InvalidName` if `name` contains a nul byte.
+    pub fn mach_service(name: &str, privileged: bool) -> Result<Self, XpcError> {
+        let name = CString::new(name).map_err(|_| XpcError::InvalidName)?;
+        let flags = match privileged {
+            true => MACH_SERVICE_PRIVILEGED,
+            false => 0
+        };
+
+        Ok(XpcConnection::with(unsafe {
+            xpc_connection_create_mach_service(name.as_ptr(), std::ptr::null_mut(), flags)
+        }))
+    }
+
+    /// Wires up the event handler and resumes the connection.
+    fn with(connection: XpcObject) -> Self {
+        let handlers = Arc::new(Mutex::new(ConnectionHandlers::default()));
+        let event_handlers = handlers.clone();
+
+        let block = ConcreteBlock::new(move |event: XpcObject| {
+            if !is_error(event) {
+                return;
+            }
+
+            let handlers = event_handlers.lock().unwrap();
+            let handler = match error_for(event) {
+                XpcError::Interrupted => &handlers.interruption,
+                _ => &handlers.invalidation
+            };
+
+            if let Some(handler) = handler {
+                handler();
+            }
+        });
+        let block = block.copy();
+
+        unsafe {
+            xpc_connection_set_event_handler(connection, &*block as *const Block<(XpcObject,), ()> as *mut c_void);
+            xpc_connection_resume(connection);
+        }
+
+        XpcConnection {
+            connection: connection,
+            handlers: handlers,
+            _types: PhantomData
+        }
+    }
+
+    /// Sets a handler that's called when the remote process exits or crashes. The connection
+    /// remains usable; you may want to re-send any state the service needs.
+    pub fn set_interruption_handler<F: Fn() + Send + Sync + 'static>(&self, handler: F) {
+        let mut handlers = self.handlers.lock().unwrap();
+        handlers.interruption = Some(Box::new(handler));
+    }
+
+    /// Sets a handler that's called when the connection becomes unusable (e.g, the service
+    /// couldn't be found).
+    pub fn set_invalidation_handler<F: Fn() + Send + Sync + 'static>(&self, handler: F) {
+        let mut handlers = self.handlers.lock().unwrap();
+        handlers.invalidation = Some(Box::new(handler));
+    }
+
+    /// Encodes `message` into an XPC dictionary. The caller is responsible for releasing it.
+    fn encode(message: &Request) -> Result<XpcObject, XpcError> {
+        let json = serde_json::to_vec(message).map_err(|e| XpcError::Serialization(e.to_string()))?;
+        let key = CString::new(MESSAGE_KEY).unwrap();
+
+        unsafe {
+            let dictionary = xpc_dictionary_create(std::ptr::null(), std::ptr::null(), 0);
+            xpc_dictionary_set_data(dictionary, key.as_ptr(), json.as_ptr() as *const c_void, json.len());
+            Ok(dictionary)
+        }
+    }
+
+    /// Sends a message without waiting for a reply.
+    pub fn send(&self, message: &Request) -> Result<(), XpcError> {
+        let message = XpcConnection::<Request, Response>::encode(message)?;
+
+        unsafe {
+            xpc_connection_send_message(self.connection, message);
+            xpc_release(message);
+        }
+
+        Ok(())
+    }
+
+    /// Sends a message, calling `handler` with the decoded reply (or an error) once it arrives.
+    pub fn send_with_reply<F>(&self, message: &Request, handler: F) -> Result<(), XpcError>
+    where
+        F: Fn(Result<Response, XpcError>) + Send + Sync + 'static
+    {
+        let message = XpcConnection::<Request, Response>::encode(message)?;
+
+        let block = ConcreteBlock::new(move |reply: XpcObject| {
+            if is_error(reply) {
+                handler(Err(error_for(reply)));
+                return;
+            }
+
+            let is_dictionary = unsafe {
+                xpc_get_type(reply) == &_xpc_type_dictionary as *const XpcOpaque
+            };
+
+            if !is_dictionary {
+                handler(Err(XpcError::UnexpectedReply));
+                return;
+            }
+
+            let key = CString::new(MESSAGE_KEY).unwrap();
+            let mut length: usize = 0;
+            let bytes = unsafe { xpc_dictionary_get_data(reply, key.as_ptr(), &mut length) };
+
+            if bytes.is_null() {
+                handler(Err(XpcError::UnexpectedReply));
+                return;
+            }
+
+            let bytes = unsafe { slice::from_raw_parts(bytes as *const u8, length) };
+            handler(serde_json::from_slice(bytes).map_err(|e| XpcError::Serialization(e.to_string())));
+        });
+        let block = block.copy();
+
+        unsafe {
+            xpc_connection_send_message_with_reply(
+                self.connection,
+                message,
+                std::ptr::null_mut(),
+                &*block as *const Block<(XpcObject,), ()> as *mut c_void
+            );
+
+            xpc_release(message);
+        }
+
+        Ok(())
+    }
+}
+
+impl<Request, Response> fmt::Debug for XpcConnection<Request, Response> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("XpcConnection")
+            .field("connection", &self.connection)
+            .finish()
+    }
+}
+
+impl<Request, Response> Drop for XpcConnection<Request, Response> {
+    /// Cancels and releases the connection. Any outstanding reply handlers are called with
+    /// `XpcError::Invalidated`.
+    fn drop(&mut self) {
+        unsafe {
+            xpc_connection_cancel(self.connection);
+            xpc_release(self.connection);
+        }
+    }
+}
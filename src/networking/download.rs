@@ -0,0 +1,238 @@
+//! Wraps `NSURLSessionDownloadTask`, and the types describing its progress and errors.
+
+use std::fmt;
+
+use block::ConcreteBlock;
+
+use objc::runtime::Object;
+use objc::{msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::error::Error;
+use crate::foundation::{id, nil, YES, BOOL, NSData, NSInteger, NSString, NSUInteger};
+
+/// `NSURLSessionTransferSizeUnknown`.
+const TRANSFER_SIZE_UNKNOWN: i64 = -1;
+
+/// `NSURLErrorCancelled`.
+const URL_ERROR_CANCELLED: usize = -999i64 as usize;
+
+/// The `NSError` `userInfo` key holding resume data for a failed download.
+static RESUME_DATA_KEY: &str = "NSURLSessionDownloadTaskResumeData";
+
+/// Converts a byte count that may be `NSURLSessionTransferSizeUnknown`.
+pub(crate) fn transfer_size(bytes: i64) -> Option<u64> {
+    match bytes {
+        TRANSFER_SIZE_UNKNOWN => None,
+        bytes => Some(bytes as u64)
+    }
+}
+
+/// Wraps `NSData` that may be `nil`, copying the bytes out.
+fn optional_data(data: id) -> Option<Vec<u8>> {
+    match data == nil {
+        true => None,
+        false => Some(NSData::wrap(data).bytes().to_vec())
+    }
+}
+
+/// A snapshot of a download's progress.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DownloadProgress {
+    /// The number of bytes received since the last progress update.
+    pub bytes_written: u64,
+
+    /// The total number of bytes received so far.
+    pub total_bytes_written: u64,
+
+    /// The expected size of the download, if the server provided one.
+    pub total_bytes_expected: Option<u64>
+}
+
+impl DownloadProgress {
+    /// Returns the fraction (`0.0` to `1.0`) of the download that's complete, if the expected
+    /// size is known.
+    pub fn fraction_completed(&self) -> Option<f64> {
+        match self.total_bytes_expected {
+            Some(0) | None => None,
+            Some(expected) => Some(self.total_bytes_written as f64 / expected as f64)
+        }
+    }
+}
+
+/// An error that caused a download to fail.
+#[derive(Clone, Debug)]
+pub struct DownloadError {
+    /// The underlying error.
+    pub error: Error,
+
+    /// If the download can be resumed, data to pass to `URLSession::resume_download()`.
+    pub resume_data: Option<Vec<u8>>
+}
+
+impl DownloadError {
+    /// Extracts the error (and any resume data) from an `NSError`.
+    pub(crate) fn new(error: id) -> Self {
+        let resume_data = unsafe {
+            let user_info: id = msg_send![error, userInfo];
+
+            match user_info == nil {
+                true => None,
+                false => {
+                    let key = NSString::new(RESUME_DATA_KEY);
+                    optional_data(msg_send![user_info, objectForKey:key])
+                }
+            }
+        };
+
+        DownloadError {
+            error: Error::new(error),
+            resume_data: resume_data
+        }
+    }
+
+    /// Returns whether the download failed because it was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.error.code() == URL_ERROR_CANCELLED
+    }
+}
+
+impl fmt::Display for DownloadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+impl std::error::Error for DownloadError {}
+
+/// A wrapper for `NSURLSessionDownloadTask`. This is cheap to clone; clones refer to the same
+/// task.
+#[derive(Clone, Debug)]
+pub struct DownloadTask {
+    /// The underlying `NSURLSessionDownloadTask`.
+    pub objc: ShareId<Object>
+}
+
+impl DownloadTask {
+    /// Wraps an existing `NSURLSessionDownloadTask`.
+    pub(crate) fn with(task: id) -> Self {
+        DownloadTask {
+            objc: unsafe { ShareId::from_ptr(task) }
+        }
+    }
+
+    /// Returns an identifier for this task, unique within its session.
+    pub fn identifier(&self) -> usize {
+        let identifier: NSUInteger = unsafe { msg_send![&*self.objc, taskIdentifier] };
+        identifier as usize
+    }
+
+    /// Returns the URL this task is downloading, as originally requested.
+    pub fn url(&self) -> Option<String> {
+        unsafe {
+            let request: id = msg_send![&*self.objc, originalRequest];
+
+            if request == nil {
+                return None;
+            }
+
+            let url: id = msg_send![request, URL];
+
+            match url == nil {
+                true => None,
+                false => Some(NSString::wrap(msg_send![url, absoluteString]).to_string())
+            }
+        }
+    }
+
+    /// Attaches a description to this task. This persists with background tasks, so it's a good
+    /// place to stash whatever you need to pick things back up after a relaunch.
+    pub fn set_description(&self, description: &str) {
+        let description = NSString::new(description);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTaskDescription:description];
+        }
+    }
+
+    /// Returns the description set via `set_description()`, if any.
+    pub fn description(&self) -> Option<String> {
+        let description: id = unsafe { msg_send![&*self.objc, taskDescription] };
+
+        match description == nil {
+            true => None,
+            false => Some(NSString::wrap(description).to_string())
+        }
+    }
+
+    /// Returns the progress of this task so far.
+    pub fn progress(&self) -> DownloadProgress {
+        let (received, expected): (i64, i64) = unsafe {
+            (msg_send![&*self.objc, countOfBytesReceived], msg_send![&*self.objc, countOfBytesExpectedToReceive])
+        };
+
+        DownloadProgress {
+            bytes_written: 0,
+            total_bytes_written: received as u64,
+            total_bytes_expected: transfer_size(expected)
+        }
+    }
+
+    /// Starts (or resumes) the task.
+    pub fn resume(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, resume];
+        }
+    }
+
+    /// Temporarily suspends the task.
+    pub fn suspend(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, suspend];
+        }
+    }
+
+    /// Cancels the task. The session's delegate receives a cancelled `DownloadError`.
+    pub fn cancel(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, cancel];
+        }
+    }
+
+    /// Cancels the task, calling `handler` with data that can later be passed to
+    /// `URLSession::resume_download()` to pick up where it left off. `handler` gets `None` if the
+    /// download can't be resumed (e.g, the server doesn't support range requests).
+    ///
+    /// `handler` is called on a background thread.
+    pub fn cancel_producing_resume_data<F>(&self, handler: F)
+    where
+        F: Fn(Option<Vec<u8>>) + Send + Sync + 'static
+    {
+        let block = ConcreteBlock::new(move |data: id| {
+            handler(optional_data(data));
+        });
+        let block = block.copy();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, cancelByProducingResumeData:block];
+        }
+    }
+
+    /// Returns the HTTP status code of the response, once there is one.
+    pub fn status_code(&self) -> Option<NSInteger> {
+        unsafe {
+            let response: id = msg_send![&*self.objc, response];
+
+            if response == nil {
+                return None;
+            }
+
+            let responds: BOOL = msg_send![response, respondsToSelector:sel!(statusCode)];
+
+            match responds {
+                YES => Some(msg_send![response, statusCode]),
+                _ => None
+            }
+        }
+    }
+}
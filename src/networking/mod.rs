@@ -1,5 +1,29 @@
 //! A lightweight wrapper over some networking components, like `NSURLRequest` and co.
 //! This is currently not meant to be exhaustive.
+//!
+//! For downloading (potentially large) files, see `URLSession` and `DownloadDelegate`:
+//!
+//! ```rust,no_run
+//! use std::path::PathBuf;
+//! use url::Url;
+//! use cacao::networking::{DownloadDelegate, DownloadProgress, DownloadTask, SessionConfiguration, URLSession};
+//!
+//! struct Downloader;
+//!
+//! impl DownloadDelegate for Downloader {
+//!     fn progress(&self, _task: DownloadTask, progress: DownloadProgress) {
+//!         println!("{:?}", progress.fraction_completed());
+//!     }
+//!
+//!     fn did_finish_downloading(&self, _task: DownloadTask, location: PathBuf) {
+//!         // The file at `location` is removed once this returns, so move it somewhere.
+//!         std::fs::rename(location, "/tmp/download.zip").ok();
+//!     }
+//! }
+//!
+//! let session = URLSession::new(SessionConfiguration::Default, Downloader);
+//! session.download(&Url::parse("https://example.com/large.zip").unwrap());
+//! ```
 
 use objc::{msg_send, sel, sel_impl};
 use objc::runtime::Object;
@@ -7,6 +31,16 @@ use objc_id::Id;
 
 use crate::foundation::{id, NSString};
 
+mod download;
+pub use download::{DownloadError, DownloadProgress, DownloadTask};
+
+mod session;
+pub use session::{SessionConfiguration, URLSession};
+pub(crate) use session::store_background_events_completion_handler;
+
+mod traits;
+pub use traits::DownloadDelegate;
+
 #[derive(Debug)]
 pub struct URLRequest {
     pub inner: Id<Object>
@@ -0,0 +1,281 @@
+//! Wraps `NSURLSession`, configured for download tasks - including background sessions, which
+//! keep downloading while your app is suspended (or not running at all).
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Mutex, Once};
+
+use block::Block;
+use lazy_static::lazy_static;
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::{Id, ShareId};
+use url::Url;
+
+use crate::foundation::{id, nil, NSData, NSString};
+use crate::networking::download::transfer_size;
+use crate::networking::{DownloadDelegate, DownloadError, DownloadProgress, DownloadTask};
+use crate::utils::load_or_register_class;
+
+static SESSION_DELEGATE_PTR: &str = "rstURLSessionDelegatePtr";
+
+lazy_static! {
+    /// Completion handlers the system hands the app delegate when relaunching to deliver
+    /// background session events, keyed by session identifier. These are `Block<(), ()>`s,
+    /// stored as `usize` so they can live in a static.
+    static ref BACKGROUND_EVENTS_COMPLETION_HANDLERS: Mutex<HashMap<String, usize>> = Mutex::new(HashMap::new());
+}
+
+/// Holds onto the completion handler for a background session's events, until the session's
+/// delegate has received them all.
+pub(crate) fn store_background_events_completion_handler(identifier: String, handler: id) {
+    let handler: id = unsafe { msg_send![handler, copy] };
+    let mut handlers = BACKGROUND_EVENTS_COMPLETION_HANDLERS.lock().unwrap();
+
+    if let Some(previous) = handlers.insert(identifier, handler as usize) {
+        unsafe {
+            let _: () = msg_send![previous as id, release];
+        }
+    }
+}
+
+/// Calls (and releases) the stored completion handler for a background session, if there is one.
+fn call_background_events_completion_handler(identifier: &str) {
+    let handler = BACKGROUND_EVENTS_COMPLETION_HANDLERS.lock().unwrap().remove(identifier);
+
+    if let Some(handler) = handler {
+        unsafe {
+            let block = handler as *mut Block<(), ()>;
+            (*block).call(());
+            let _: () = msg_send![handler as id, release];
+        }
+    }
+}
+
+/// How a `URLSession` is configured.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SessionConfiguration {
+    /// The default configuration, which uses the shared disk cache, cookie storage and keychain.
+    Default,
+
+    /// A configuration that keeps caches, cookies and credentials in memory only.
+    Ephemeral,
+
+    /// A background session with the given identifier. Transfers continue (in a separate process)
+    /// while your app is suspended or terminated; recreate the session with the same identifier
+    /// to receive their events.
+    Background(String)
+}
+
+impl SessionConfiguration {
+    /// Returns the `NSURLSessionConfiguration` for this configuration.
+    fn to_objc(&self) -> id {
+        unsafe {
+            match self {
+                SessionConfiguration::Default => msg_send![class!(NSURLSessionConfiguration), defaultSessionConfiguration],
+                SessionConfiguration::Ephemeral => msg_send![class!(NSURLSessionConfiguration), ephemeralSessionConfiguration],
+                SessionConfiguration::Background(identifier) => {
+                    let identifier = NSString::new(identifier);
+                    msg_send![class!(NSURLSessionConfiguration), backgroundSessionConfigurationWithIdentifier:identifier]
+                }
+            }
+        }
+    }
+}
+
+/// The delegate, boxed as a sized type so it can be stored behind a thin pointer.
+struct SessionDelegate(Box<dyn DownloadDelegate>);
+
+/// Calls `handler` with the session's delegate, unless the session has been dropped.
+fn with_delegate<F: Fn(&dyn DownloadDelegate)>(this: &Object, handler: F) {
+    let ptr: usize = unsafe { *this.get_ivar(SESSION_DELEGATE_PTR) };
+
+    if ptr == 0 {
+        return;
+    }
+
+    let delegate = unsafe { &*(ptr as *const SessionDelegate) };
+    handler(&*delegate.0);
+}
+
+/// Forwards `URLSession:downloadTask:didWriteData:totalBytesWritten:totalBytesExpectedToWrite:`.
+extern fn did_write_data(this: &Object, _: Sel, _: id, task: id, written: i64, total_written: i64, total_expected: i64) {
+    with_delegate(this, |delegate| {
+        delegate.progress(DownloadTask::with(task), DownloadProgress {
+            bytes_written: written as u64,
+            total_bytes_written: total_written as u64,
+            total_bytes_expected: transfer_size(total_expected)
+        });
+    });
+}
+
+/// Forwards `URLSession:downloadTask:didResumeAtOffset:expectedTotalBytes:`.
+extern fn did_resume(this: &Object, _: Sel, _: id, task: id, offset: i64, expected: i64) {
+    with_delegate(this, |delegate| {
+        delegate.did_resume(DownloadTask::with(task), offset as u64, transfer_size(expected));
+    });
+}
+
+/// Forwards `URLSession:downloadTask:didFinishDownloadingToURL:`.
+extern fn did_finish_downloading(this: &Object, _: Sel, _: id, task: id, location: id) {
+    let location = PathBuf::from(NSString::wrap(unsafe {
+        msg_send![location, path]
+    }).to_string());
+
+    with_delegate(this, |delegate| {
+        delegate.did_finish_downloading(DownloadTask::with(task), location.clone());
+    });
+}
+
+/// Forwards `URLSession:task:didCompleteWithError:`.
+extern fn did_complete(this: &Object, _: Sel, _: id, task: id, error: id) {
+    with_delegate(this, |delegate| {
+        delegate.did_complete(DownloadTask::with(task), match error == nil {
+            true => None,
+            false => Some(DownloadError::new(error))
+        });
+    });
+}
+
+/// Forwards `URLSessionDidFinishEventsForBackgroundURLSession:`, then lets the system know we're
+/// done.
+extern fn did_finish_background_events(this: &Object, _: Sel, session: id) {
+    with_delegate(this, |delegate| {
+        delegate.did_finish_background_events();
+    });
+
+    let identifier: id = unsafe {
+        let configuration: id = msg_send![session, configuration];
+        msg_send![configuration, identifier]
+    };
+
+    if identifier != nil {
+        call_background_events_completion_handler(NSString::wrap(identifier).to_str());
+    }
+}
+
+/// Registers an `NSObject` subclass that acts as an `NSURLSessionDownloadDelegate`.
+fn register_session_delegate_class() -> *const Class {
+    static mut DELEGATE_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSObject);
+        DELEGATE_CLASS = load_or_register_class(superclass, "RSTURLSessionDelegate", |decl| {
+            decl.add_ivar::<usize>(SESSION_DELEGATE_PTR);
+
+            decl.add_method(
+                sel!(URLSession:downloadTask:didWriteData:totalBytesWritten:totalBytesExpectedToWrite:),
+                did_write_data as extern fn(&Object, _, _, id, i64, i64, i64)
+            );
+            decl.add_method(
+                sel!(URLSession:downloadTask:didResumeAtOffset:expectedTotalBytes:),
+                did_resume as extern fn(&Object, _, _, id, i64, i64)
+            );
+            decl.add_method(
+                sel!(URLSession:downloadTask:didFinishDownloadingToURL:),
+                did_finish_downloading as extern fn(&Object, _, _, id, id)
+            );
+            decl.add_method(
+                sel!(URLSession:task:didCompleteWithError:),
+                did_complete as extern fn(&Object, _, _, id, id)
+            );
+            decl.add_method(
+                sel!(URLSessionDidFinishEventsForBackgroundURLSession:),
+                did_finish_background_events as extern fn(&Object, _, id)
+            );
+        });
+    });
+
+    unsafe { DELEGATE_CLASS }
+}
+
+/// A session for downloading files, which reports events to a `DownloadDelegate` on the main
+/// thread.
+///
+/// Dropping the session lets outstanding tasks finish, but stops delivering their events (and
+/// background transfers carry on in the system regardless; recreate the session to hear about
+/// them).
+pub struct URLSession {
+    /// The underlying `NSURLSession`.
+    pub objc: ShareId<Object>,
+
+    /// The `RSTURLSessionDelegate` instance, which holds a pointer to `delegate`.
+    objc_delegate: Id<Object>,
+
+    /// The Rust delegate.
+    delegate: Box<SessionDelegate>
+}
+
+impl URLSession {
+    /// Creates a session with the given configuration, delivering events to `delegate`.
+    pub fn new<T: DownloadDelegate + 'static>(configuration: SessionConfiguration, delegate: T) -> Self {
+        let delegate = Box::new(SessionDelegate(Box::new(delegate)));
+        let ptr = &*delegate as *const SessionDelegate as usize;
+
+        unsafe {
+            let objc_delegate: id = msg_send![register_session_delegate_class(), new];
+            (&mut *objc_delegate).set_ivar(SESSION_DELEGATE_PTR, ptr);
+
+            let queue: id = msg_send![class!(NSOperationQueue), mainQueue];
+            let session: id = msg_send![class!(NSURLSession), sessionWithConfiguration:configuration.to_objc()
+                delegate:objc_delegate
+                delegateQueue:queue
+            ];
+
+            URLSession {
+                objc: ShareId::from_ptr(session),
+                objc_delegate: Id::from_retained_ptr(objc_delegate),
+                delegate: delegate
+            }
+        }
+    }
+
+    /// Starts downloading `url`, returning the task.
+    pub fn download(&self, url: &Url) -> DownloadTask {
+        let url = NSString::new(url.as_str());
+
+        let task = unsafe {
+            let url: id = msg_send![class!(NSURL), URLWithString:url];
+            DownloadTask::with(msg_send![&*self.objc, downloadTaskWithURL:url])
+        };
+
+        task.resume();
+        task
+    }
+
+    /// Resumes a download that failed or was cancelled, using resume data from
+    /// `DownloadError::resume_data` or `DownloadTask::cancel_producing_resume_data()`.
+    pub fn resume_download(&self, resume_data: &[u8]) -> DownloadTask {
+        let data = NSData::new(resume_data.to_vec());
+
+        let task = DownloadTask::with(unsafe {
+            msg_send![&*self.objc, downloadTaskWithResumeData:data]
+        });
+
+        task.resume();
+        task
+    }
+}
+
+impl fmt::Debug for URLSession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("URLSession")
+            .field("objc", &self.objc)
+            .finish()
+    }
+}
+
+impl Drop for URLSession {
+    /// Detaches the delegate (so no further events reach it), and tells the session to invalidate
+    /// itself once outstanding tasks finish.
+    fn drop(&mut self) {
+        unsafe {
+            self.objc_delegate.set_ivar(SESSION_DELEGATE_PTR, 0usize);
+
+            let _: () = msg_send![&*self.objc, finishTasksAndInvalidate];
+        }
+    }
+}
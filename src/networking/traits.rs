@@ -0,0 +1,31 @@
+//! The `DownloadDelegate` trait, which receives events for the download tasks in a `URLSession`.
+
+use std::path::PathBuf;
+
+use crate::networking::{DownloadError, DownloadProgress, DownloadTask};
+
+/// Receives events for download tasks. All methods are called on the main thread.
+///
+/// For background sessions, tasks outlive your process: if your app is relaunched to handle
+/// events for a session, recreate it (with the same identifier) and events for tasks started in
+/// a previous launch are delivered here.
+pub trait DownloadDelegate {
+    /// Called periodically as data is received.
+    fn progress(&self, _task: DownloadTask, _progress: DownloadProgress) {}
+
+    /// Called when a download resumes (from resume data). `offset` is the number of bytes that
+    /// didn't need to be downloaded again.
+    fn did_resume(&self, _task: DownloadTask, _offset: u64, _total_bytes_expected: Option<u64>) {}
+
+    /// Called when a download finishes, with the location of a temporary file holding the
+    /// contents. The file is removed as soon as this returns, so move (or open) it before then.
+    fn did_finish_downloading(&self, task: DownloadTask, location: PathBuf);
+
+    /// Called when a task completes - successfully, or with an error. If the error is
+    /// resumable, it carries resume data.
+    fn did_complete(&self, _task: DownloadTask, _error: Option<DownloadError>) {}
+
+    /// Called (for background sessions) once all events that were queued up while your app
+    /// wasn't running have been delivered. The system is told you're done after this returns.
+    fn did_finish_background_events(&self) {}
+}
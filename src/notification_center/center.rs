@@ -0,0 +1,95 @@
+//! Wraps `NSNotificationCenter`, for subscribing to and posting notifications.
+
+use std::rc::Rc;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::foundation::{id, nil, NSString};
+use crate::notification_center::{Notification, NotificationObserver};
+
+/// A notification center. Most of the time you want `NotificationCenter::default()`; on macOS,
+/// `NotificationCenter::distributed()` reaches other processes too.
+#[derive(Clone, Debug)]
+pub struct NotificationCenter {
+    /// The underlying `NSNotificationCenter`.
+    pub objc: ShareId<Object>
+}
+
+impl Default for NotificationCenter {
+    /// Returns the default center for this process (`[NSNotificationCenter defaultCenter]`).
+    fn default() -> Self {
+        NotificationCenter {
+            objc: unsafe {
+                ShareId::from_ptr(msg_send![class!(NSNotificationCenter), defaultCenter])
+            }
+        }
+    }
+}
+
+impl NotificationCenter {
+    /// Returns the distributed notification center, which delivers notifications between
+    /// processes. Note that sandboxed apps can't post distributed notifications with a
+    /// `userInfo`.
+    #[cfg(feature = "macos")]
+    pub fn distributed() -> Self {
+        NotificationCenter {
+            objc: unsafe {
+                ShareId::from_ptr(msg_send![class!(NSDistributedNotificationCenter), defaultCenter])
+            }
+        }
+    }
+
+    /// Calls `handler` (on the main thread) whenever a notification with the given name is posted.
+    /// This works for system notifications (e.g, `NSWindowDidBecomeKeyNotification`) as well as
+    /// your own. Keep the returned `NotificationObserver` around for as long as you want to be
+    /// notified.
+    pub fn subscribe<F>(&self, name: &str, handler: F) -> NotificationObserver
+    where
+        F: Fn(Notification) + 'static
+    {
+        let handler = Rc::new(handler);
+
+        NotificationObserver::new(&*self.objc as *const Object as id, &[name], move |name, notification| {
+            handler(Notification::new(name, notification));
+        })
+    }
+
+    /// Posts a notification with the given name, and no `userInfo`.
+    pub fn post(&self, name: &str) {
+        let name = NSString::new(name);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, postNotificationName:name object:nil];
+        }
+    }
+
+    /// Posts a notification with the given name, encoding `user_info` as the `userInfo`
+    /// dictionary. `user_info` must serialize to a map (e.g, a struct); subscribers can decode it
+    /// via `Notification::user_info()`.
+    ///
+    /// Requires the `serde` feature.
+    #[cfg(feature = "serde")]
+    pub fn post_with<T: Serialize>(&self, name: &str, user_info: &T) -> Result<(), serde_json::Error> {
+        use serde::ser::Error;
+
+        let value = serde_json::to_value(user_info)?;
+
+        if !value.is_object() {
+            return Err(serde_json::Error::custom("userInfo must serialize to a map"));
+        }
+
+        let user_info = crate::defaults::plist::to_plist(&value)?;
+        let name = NSString::new(name);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, postNotificationName:name object:nil userInfo:user_info];
+        }
+
+        Ok(())
+    }
+}
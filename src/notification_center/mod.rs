@@ -11,63 +11,49 @@
 //! integrating with certain aspects of the underlying Cocoa/Foundation/Kit frameworks.
 //!
 //! ## Example
+//! ```rust,no_run
+//! # #[cfg(feature = "serde")]
+//! # fn main() {
+//! use serde::{Deserialize, Serialize};
+//! use cacao::notification_center::NotificationCenter;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct DocumentSaved {
+//!     path: String
+//! }
+//!
+//! let center = NotificationCenter::default();
+//!
+//! // Handlers fire for as long as you hold on to the observer.
+//! let observer = center.subscribe("MyAppDocumentSaved", |notification| {
+//!     if let Some(saved) = notification.user_info::<DocumentSaved>() {
+//!         println!("Saved {}", saved.path);
+//!     }
+//! });
+//!
+//! center.post_with("MyAppDocumentSaved", &DocumentSaved {
+//!     path: "/tmp/file.txt".into()
+//! }).unwrap();
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
+//! ```
+//!
+//! Posting with a `userInfo`, and decoding it, requires the `serde` feature.
 
-//use std::sync::Mutex;
-//use std::collections::HashMap;
-
-//use lazy_static::lazy_static;
-//use objc::{class, msg_send, sel, sel_impl};
-//use objc::runtime::Object;
-//use objc_id::ShareId;
+mod center;
+pub use center::NotificationCenter;
 
 mod name;
 pub use name::NotificationName;
 
-mod traits;
-pub use traits::Dispatcher;
-
-/*lazy_static! {
-    pub static ref DefaultNotificationCenter: NotificationCenter = {
-        NotificationCenter {
-            objc: unsafe {
-                ShareId::from_ptr(msg_send![class!(NSNotificationCenter), defaultCenter])
-            },
+mod notification;
+pub use notification::Notification;
 
-            subscribers: Mutex::new(HashMap::new())
-        }
-    };
-}*/
+mod observer;
+pub use observer::NotificationObserver;
 
-// Wraps a reference to an `NSNotificationCenter` instance. Currently this only supports the
-// default center; in the future it should aim to support custom variants.
-//#[derive(Debug)]
-//pub struct NotificationCenter {
-//    pub objc: ShareId<Object>,
-    //pub subscribers: Mutex<HashMap<String, Vec<Dispatcher>>>
-//}
-
-/*impl Default for NotificationCenter {
-    /// Returns a wrapper over `[NSNotificationCenter defaultCenter]`. From here you can handle
-    /// observing, removing, and posting notifications.
-    fn default() -> Self {
-        NotificationCenter {
-            objc: unsafe {
-                ShareId::from_ptr(msg_send![class!(NSNotificationCenter), defaultCenter])
-            }
-        }
-    }
-}*/
-
-/*impl NotificationCenter {
-    pub fn observe<T: Dispatcher>(&self, name: &str, handler: &T) {
-
-    }
-
-    pub fn remove<T: Dispatcher>(&self, name: &str, handler: &T) {
-
-    }
-
-    pub fn post(&self, name: &str) {
+mod traits;
+pub use traits::Dispatcher;
 
-    }
-}*/
+pub(crate) mod router;
@@ -0,0 +1,72 @@
+//! A wrapper for `NSNotification`, as delivered to subscribers.
+
+use objc::runtime::Object;
+use objc::{msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+#[cfg(feature = "serde")]
+use serde::de::DeserializeOwned;
+
+use crate::foundation::{id, nil, NSString};
+
+/// A notification delivered to a subscriber.
+#[derive(Clone, Debug)]
+pub struct Notification {
+    /// The name the notification was posted under.
+    pub name: String,
+
+    /// The underlying `NSNotification`.
+    pub objc: ShareId<Object>
+}
+
+impl Notification {
+    /// Wraps an `NSNotification`.
+    pub(crate) fn new(name: &str, notification: id) -> Self {
+        Notification {
+            name: name.to_string(),
+            objc: unsafe { ShareId::from_ptr(notification) }
+        }
+    }
+
+    /// Returns the `object` the notification was posted with (e.g, the window, for window
+    /// notifications), or `nil`.
+    pub fn object(&self) -> id {
+        unsafe { msg_send![&*self.objc, object] }
+    }
+
+    /// Returns the string value for `key` in the `userInfo` dictionary, if there is one.
+    pub fn user_info_string(&self, key: &str) -> Option<String> {
+        let value: id = unsafe {
+            let user_info: id = msg_send![&*self.objc, userInfo];
+
+            if user_info == nil {
+                return None;
+            }
+
+            let key = NSString::new(key);
+            msg_send![user_info, objectForKey:key]
+        };
+
+        match value != nil && NSString::is(value) {
+            true => Some(NSString::wrap(value).to_string()),
+            false => None
+        }
+    }
+
+    /// Decodes the `userInfo` dictionary into `T` - typically the same type that was passed to
+    /// `NotificationCenter::post_with()`, though this works with any notification whose
+    /// `userInfo` matches the shape of `T`. Returns `None` if there's no `userInfo`, or it
+    /// doesn't decode.
+    ///
+    /// Requires the `serde` feature.
+    #[cfg(feature = "serde")]
+    pub fn user_info<T: DeserializeOwned>(&self) -> Option<T> {
+        let user_info: id = unsafe { msg_send![&*self.objc, userInfo] };
+
+        if user_info == nil {
+            return None;
+        }
+
+        serde_json::from_value(crate::defaults::plist::from_plist(user_info)).ok()
+    }
+}
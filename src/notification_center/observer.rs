@@ -0,0 +1,73 @@
+//! A handle for block-based notification observers, which removes them when dropped.
+
+use std::fmt;
+
+use block::ConcreteBlock;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, NSString};
+
+/// Holds observers registered with a notification center. Callbacks fire (on the main thread) for
+/// as long as you hold this; when it drops, the observers are removed.
+pub struct NotificationObserver {
+    center: ShareId<Object>,
+    tokens: Vec<ShareId<Object>>
+}
+
+impl NotificationObserver {
+    /// Registers `handler` with `center` for each of the given notification names. The handler
+    /// receives the name and the `NSNotification`.
+    pub(crate) fn new<F>(center: id, names: &[&str], handler: F) -> Self
+    where
+        F: Fn(&str, id) + Clone + 'static
+    {
+        let tokens = names.iter().map(|name| {
+            let notification_name = NSString::new(name);
+            let name = name.to_string();
+            let handler = handler.clone();
+
+            let block = ConcreteBlock::new(move |notification: id| {
+                handler(&name, notification);
+            });
+            let block = block.copy();
+
+            unsafe {
+                let queue: id = msg_send![class!(NSOperationQueue), mainQueue];
+                let token: id = msg_send![center, addObserverForName:notification_name
+                    object:nil
+                    queue:queue
+                    usingBlock:block
+                ];
+
+                ShareId::from_ptr(token)
+            }
+        }).collect();
+
+        NotificationObserver {
+            center: unsafe { ShareId::from_ptr(center) },
+            tokens: tokens
+        }
+    }
+}
+
+impl fmt::Debug for NotificationObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NotificationObserver")
+            .field("observers", &self.tokens.len())
+            .finish()
+    }
+}
+
+impl Drop for NotificationObserver {
+    /// Removes the observers, as the handler is going away.
+    fn drop(&mut self) {
+        unsafe {
+            for token in self.tokens.iter() {
+                let _: () = msg_send![&*self.center, removeObserver:&**token];
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Routes messages passed to `App::dispatch()` through to the `Dispatcher` the app registered
+//! with `App::with_dispatcher()`.
+//!
+//! There's only ever one app delegate, so there's only ever one route; it holds the delegate
+//! pointer and the `TypeId` of its `Message` type, and takes messages boxed as `Any`.
+
+use std::any::{Any, TypeId};
+use std::sync::{Arc, Mutex};
+
+use lazy_static::lazy_static;
+
+use crate::dispatch::{is_main_thread, main_async};
+use crate::notification_center::Dispatcher;
+
+/// Delivers a boxed message (which has already been checked to be the right type).
+type Handler = Arc<dyn Fn(Box<dyn Any>) + Send + Sync + 'static>;
+
+struct Route {
+    message: TypeId,
+    message_name: &'static str,
+    handler: Handler
+}
+
+lazy_static! {
+    static ref ROUTE: Mutex<Option<Route>> = Mutex::new(None);
+}
+
+/// Sends messages passed to `App::dispatch()` to `delegate`, which must outlive the app (i.e,
+/// it's the boxed app delegate).
+pub(crate) fn register<T>(delegate: &T)
+where
+    T: Dispatcher + 'static,
+    T::Message: 'static
+{
+    let delegate_ptr = delegate as *const T as usize;
+
+    let handler: Handler = Arc::new(move |message: Box<dyn Any>| {
+        if let Ok(message) = message.downcast::<T::Message>() {
+            let delegate = unsafe { &*(delegate_ptr as *const T) };
+            delegate.on_message(*message);
+        }
+    });
+
+    *ROUTE.lock().unwrap() = Some(Route {
+        message: TypeId::of::<T::Message>(),
+        message_name: std::any::type_name::<T::Message>(),
+        handler: handler
+    });
+}
+
+/// Delivers `message` to the registered `Dispatcher` on the main thread - immediately if this is
+/// the main thread, and asynchronously otherwise.
+///
+/// Panics (on the calling thread) if no dispatcher was registered, or if it takes a different
+/// type of message.
+pub(crate) fn route<M: Send + 'static>(message: M) {
+    let handler = match &*ROUTE.lock().unwrap() {
+        Some(route) if route.message == TypeId::of::<M>() => route.handler.clone(),
+
+        Some(route) => panic!(
+            "App::dispatch() was called with a {}, but the app's Dispatcher takes a {}.",
+            std::any::type_name::<M>(),
+            route.message_name
+        ),
+
+        None => panic!(
+            "App::dispatch() was called with a {}, but no Dispatcher is registered. Call App::with_dispatcher() first.",
+            std::any::type_name::<M>()
+        )
+    };
+
+    // The lock is released by now, so the handler is free to dispatch further messages.
+    match is_main_thread() {
+        true => handler(Box::new(message)),
+        false => main_async(move || handler(Box::new(message)))
+    }
+}
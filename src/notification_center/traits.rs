@@ -1,7 +1,50 @@
+/// Implemented by your `AppDelegate` to receive messages sent from elsewhere in your app with
+/// `App::dispatch()`. Define a `Message` enum covering what your widgets need to tell the app
+/// about, and handle it in one place - instead of sharing state between sibling delegates.
+///
+/// ```rust,no_run
+/// use cacao::app::{App, AppDelegate};
+/// use cacao::notification_center::Dispatcher;
+///
+/// enum Message {
+///     Refresh,
+///     Open(String)
+/// }
+///
+/// #[derive(Default)]
+/// struct MyApp;
+///
+/// impl AppDelegate for MyApp {}
+///
+/// impl Dispatcher for MyApp {
+///     type Message = Message;
+///
+///     fn on_message(&self, message: Message) {
+///         match message {
+///             Message::Refresh => {},
+///             Message::Open(path) => println!("Opening {}", path)
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     App::new("com.my.app", MyApp::default()).with_dispatcher().run();
+/// }
+///
+/// // ...and then, from any callback (on any thread):
+/// // App::dispatch(Message::Refresh);
+/// ```
 pub trait Dispatcher {
     type Message: Send + Sync;
 
-    fn on_ui_message(&self, _message: Self::Message) {}
+    /// Called on the main thread with each message passed to `App::dispatch()`.
+    fn on_message(&self, _message: Self::Message) {}
+
+    /// Called on the main thread with each message passed to `App::dispatch_main()`. Defaults to
+    /// forwarding to `on_message()`.
+    fn on_ui_message(&self, message: Self::Message) {
+        self.on_message(message);
+    }
 
     fn on_background_message(&self, _message: Self::Message) {}
 }
@@ -0,0 +1,312 @@
+//! Wraps `NSOperationQueue` and `NSBlockOperation`, for pipelined background work (e.g,
+//! thumbnailing, or importing a batch of files) where you want dependencies between steps, a cap
+//! on concurrency, cancellation, or progress reporting.
+//!
+//! For one-off work, the helpers in `cacao::dispatch` are simpler.
+//!
+//! ```rust,no_run
+//! use cacao::operation_queue::{Operation, OperationQueue};
+//!
+//! let queue = OperationQueue::new();
+//! queue.set_name("com.example.MyApp.Import");
+//! queue.set_max_concurrent_operations(Some(2));
+//!
+//! let download = Operation::new(|| {
+//!     println!("Downloading...");
+//! });
+//!
+//! let import = Operation::new(|| {
+//!     println!("Importing...");
+//! });
+//!
+//! // `import` won't start until `download` has finished.
+//! import.add_dependency(&download);
+//!
+//! queue.set_progress_total(2);
+//! let token = queue.observe_progress(|fraction| {
+//!     println!("{:.0}% done", fraction * 100.);
+//! });
+//!
+//! queue.add_operations(&[&download, &import], false);
+//! ```
+
+use std::os::raw::c_long;
+use std::sync::Mutex;
+
+use block::ConcreteBlock;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::dispatch::QualityOfService;
+use crate::foundation::{id, YES, NO, NSArray, NSInteger, NSString};
+use crate::kvo::{observe, ObservationToken, ObservingOption};
+use crate::utils::as_bool;
+
+/// `NSOperationQueueDefaultMaxConcurrentOperationCount`.
+const DEFAULT_MAX_CONCURRENT_OPERATIONS: NSInteger = -1;
+
+/// `NSQualityOfService` shares its values with the libdispatch QoS classes.
+fn quality_of_service(qos: QualityOfService) -> NSInteger {
+    c_long::from(qos) as NSInteger
+}
+
+/// A unit of work that can be added to an `OperationQueue`. This is cheap to clone; clones refer
+/// to the same operation.
+#[derive(Clone, Debug)]
+pub struct Operation {
+    /// The underlying `NSBlockOperation`.
+    pub objc: ShareId<Object>
+}
+
+impl Operation {
+    /// Creates an operation that runs `work` when it's started by a queue. Operations are
+    /// typically run on a background thread.
+    pub fn new<F: FnOnce() + Send + 'static>(work: F) -> Self {
+        let work = Mutex::new(Some(work));
+
+        let block = ConcreteBlock::new(move || {
+            if let Some(work) = work.lock().unwrap().take() {
+                work();
+            }
+        });
+        let block = block.copy();
+
+        Operation {
+            objc: unsafe {
+                ShareId::from_ptr(msg_send![class!(NSBlockOperation), blockOperationWithBlock:block])
+            }
+        }
+    }
+
+    /// Makes this operation wait until `operation` has finished (or been cancelled) before it
+    /// starts. The two operations can be on different queues.
+    pub fn add_dependency(&self, operation: &Operation) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, addDependency:&*operation.objc];
+        }
+    }
+
+    /// Removes a dependency added via `add_dependency()`.
+    pub fn remove_dependency(&self, operation: &Operation) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, removeDependency:&*operation.objc];
+        }
+    }
+
+    /// Sets the quality of service this operation runs with.
+    pub fn set_quality_of_service(&self, qos: QualityOfService) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setQualityOfService:quality_of_service(qos)];
+        }
+    }
+
+    /// Sets a handler that's called (on a background thread) once the operation finishes, or is
+    /// cancelled.
+    pub fn set_completion_handler<F: Fn() + Send + Sync + 'static>(&self, handler: F) {
+        let block = ConcreteBlock::new(move || {
+            handler();
+        });
+        let block = block.copy();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setCompletionBlock:block];
+        }
+    }
+
+    /// Cancels the operation. If it hasn't started yet, it never will; if it's already running,
+    /// it runs to completion.
+    pub fn cancel(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, cancel];
+        }
+    }
+
+    /// Returns whether the operation has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        as_bool(unsafe { msg_send![&*self.objc, isCancelled] })
+    }
+
+    /// Returns whether the operation is currently running.
+    pub fn is_executing(&self) -> bool {
+        as_bool(unsafe { msg_send![&*self.objc, isExecuting] })
+    }
+
+    /// Returns whether the operation has finished (or was cancelled before it started).
+    pub fn is_finished(&self) -> bool {
+        as_bool(unsafe { msg_send![&*self.objc, isFinished] })
+    }
+
+    /// Returns whether the operation's dependencies have all finished, so it can start.
+    pub fn is_ready(&self) -> bool {
+        as_bool(unsafe { msg_send![&*self.objc, isReady] })
+    }
+
+    /// Blocks the current thread until the operation finishes. Don't call this from the main
+    /// thread.
+    pub fn wait_until_finished(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, waitUntilFinished];
+        }
+    }
+}
+
+/// A queue that runs `Operation`s, respecting their dependencies. This is cheap to clone; clones
+/// refer to the same queue.
+#[derive(Clone, Debug)]
+pub struct OperationQueue {
+    /// The underlying `NSOperationQueue`.
+    pub objc: ShareId<Object>
+}
+
+impl Default for OperationQueue {
+    /// Returns a new queue.
+    fn default() -> Self {
+        OperationQueue::new()
+    }
+}
+
+impl OperationQueue {
+    /// Creates a new queue, which runs operations on background threads.
+    pub fn new() -> Self {
+        OperationQueue {
+            objc: unsafe {
+                let queue: id = msg_send![class!(NSOperationQueue), new];
+                ShareId::from_retained_ptr(queue)
+            }
+        }
+    }
+
+    /// Returns the queue for the main thread. Operations added here run one at a time, and can
+    /// safely touch UI.
+    pub fn main() -> Self {
+        OperationQueue {
+            objc: unsafe {
+                ShareId::from_ptr(msg_send![class!(NSOperationQueue), mainQueue])
+            }
+        }
+    }
+
+    /// Sets the name of the queue, which shows up in debuggers.
+    pub fn set_name(&self, name: &str) {
+        let name = NSString::new(name);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setName:name];
+        }
+    }
+
+    /// Caps how many operations can run at once. `None` lets the system decide, based on the
+    /// hardware and current load; `Some(1)` makes this a serial queue.
+    pub fn set_max_concurrent_operations(&self, count: Option<usize>) {
+        let count = match count {
+            Some(count) => count as NSInteger,
+            None => DEFAULT_MAX_CONCURRENT_OPERATIONS
+        };
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setMaxConcurrentOperationCount:count];
+        }
+    }
+
+    /// Sets the default quality of service for operations on this queue.
+    pub fn set_quality_of_service(&self, qos: QualityOfService) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setQualityOfService:quality_of_service(qos)];
+        }
+    }
+
+    /// Suspends (or resumes) the queue. While suspended, no new operations are started; ones that
+    /// are already running continue.
+    pub fn set_suspended(&self, suspended: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setSuspended:match suspended {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Returns whether the queue is suspended.
+    pub fn is_suspended(&self) -> bool {
+        as_bool(unsafe { msg_send![&*self.objc, isSuspended] })
+    }
+
+    /// Adds an operation to the queue. An operation can only be added to one queue, once.
+    pub fn add_operation(&self, operation: &Operation) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, addOperation:&*operation.objc];
+        }
+    }
+
+    /// Adds several operations to the queue at once. If `wait_until_finished` is `true`, this
+    /// blocks the current thread until they've all finished - so don't do that on the main thread.
+    pub fn add_operations(&self, operations: &[&Operation], wait_until_finished: bool) {
+        let operations = NSArray::from(operations.iter().map(|operation| &*operation.objc).collect::<Vec<&Object>>());
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, addOperations:operations waitUntilFinished:match wait_until_finished {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Wraps `work` in an operation and adds it to the queue, for when you don't need to
+    /// configure dependencies.
+    pub fn add<F: FnOnce() + Send + 'static>(&self, work: F) {
+        self.add_operation(&Operation::new(work));
+    }
+
+    /// Cancels every operation on the queue.
+    pub fn cancel_all(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, cancelAllOperations];
+        }
+    }
+
+    /// Blocks the current thread until every operation on the queue has finished. Don't call this
+    /// from the main thread.
+    pub fn wait_until_all_finished(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, waitUntilAllOperationsAreFinished];
+        }
+    }
+
+    /// Returns the number of operations that haven't finished yet.
+    pub fn operation_count(&self) -> usize {
+        let count: NSInteger = unsafe { msg_send![&*self.objc, operationCount] };
+        count as usize
+    }
+
+    /// Returns the queue's `NSProgress`.
+    fn progress(&self) -> id {
+        unsafe { msg_send![&*self.objc, progress] }
+    }
+
+    /// Sets how many operations make up the whole job, for progress reporting. Each operation
+    /// that finishes after this is set counts as one unit. Requires macOS 10.15+ or iOS 13+.
+    pub fn set_progress_total(&self, total: u64) {
+        unsafe {
+            let _: () = msg_send![self.progress(), setTotalUnitCount:total as i64];
+        }
+    }
+
+    /// Returns the fraction (`0.0` to `1.0`) of the job that's complete. See
+    /// `set_progress_total()`.
+    pub fn fraction_completed(&self) -> f64 {
+        unsafe { msg_send![self.progress(), fractionCompleted] }
+    }
+
+    /// Calls `handler` (on the main thread) with the fraction of the job that's complete,
+    /// whenever it changes. See `set_progress_total()`. Keep the returned `ObservationToken`
+    /// around for as long as you want to be notified.
+    pub fn observe_progress<F: Fn(f64) + 'static>(&self, handler: F) -> ObservationToken {
+        observe(self.progress(), "fractionCompleted", &[ObservingOption::New], move |change| {
+            if let Some(fraction) = change.new_f64() {
+                handler(fraction);
+            }
+        })
+    }
+}
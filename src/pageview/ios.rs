@@ -0,0 +1,157 @@
+//! The iOS side of `PageView`: a `UIPageViewController`, with an `NSObject` subclass acting as its
+//! data source and delegate.
+
+use objc::runtime::{Class, Object, Sel, BOOL};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, YES, NO, NSArray, NSInteger, NSUInteger};
+use crate::pageview::{PAGEVIEW_PAGES_PTR, PageView, PageViewDataSource, Pages};
+use crate::utils::{load_or_register_class_for, Controller};
+
+/// `UIPageViewControllerTransitionStyleScroll`.
+const TRANSITION_STYLE_SCROLL: NSInteger = 1;
+
+/// `UIPageViewControllerNavigationOrientationHorizontal`.
+const ORIENTATION_HORIZONTAL: NSInteger = 0;
+
+/// `UIPageViewControllerNavigationDirectionForward`.
+const DIRECTION_FORWARD: NSInteger = 0;
+
+/// `UIPageViewControllerNavigationDirectionReverse`.
+const DIRECTION_REVERSE: NSInteger = 1;
+
+/// Returns the page before `controller`, or `nil` at the start.
+extern fn page_before<T: PageViewDataSource>(this: &Object, _: Sel, _: id, controller: id) -> id {
+    let pages = load_or_return!(Pages<T>, this, PAGEVIEW_PAGES_PTR, nil);
+
+    match pages.index_of(controller) {
+        Some(index) if index > 0 => pages.controller(index - 1),
+        _ => nil
+    }
+}
+
+/// Returns the page after `controller`, or `nil` at the end.
+extern fn page_after<T: PageViewDataSource>(this: &Object, _: Sel, _: id, controller: id) -> id {
+    let pages = load_or_return!(Pages<T>, this, PAGEVIEW_PAGES_PTR, nil);
+
+    match pages.index_of(controller) {
+        Some(index) if index + 1 < pages.count() => pages.controller(index + 1),
+        _ => nil
+    }
+}
+
+/// Returns the number of dots for the page indicator.
+extern fn presentation_count<T: PageViewDataSource>(this: &Object, _: Sel, _: id) -> NSInteger {
+    let pages = load_or_return!(Pages<T>, this, PAGEVIEW_PAGES_PTR, 0);
+    pages.count() as NSInteger
+}
+
+/// Returns which dot the page indicator starts on.
+extern fn presentation_index<T: PageViewDataSource>(this: &Object, _: Sel, _: id) -> NSInteger {
+    let pages = load_or_return!(Pages<T>, this, PAGEVIEW_PAGES_PTR, 0);
+    pages.current() as NSInteger
+}
+
+/// Called once the user has finished swiping; if they went through with it, records the new page.
+extern fn did_finish_animating<T: PageViewDataSource>(
+    this: &Object,
+    _: Sel,
+    page_view_controller: id,
+    _finished: BOOL,
+    _previous: id,
+    completed: BOOL
+) {
+    let pages = load_or_return!(Pages<T>, this, PAGEVIEW_PAGES_PTR);
+
+    if completed == NO {
+        return;
+    }
+
+    let controller: id = unsafe {
+        let controllers: id = msg_send![page_view_controller, viewControllers];
+        msg_send![controllers, firstObject]
+    };
+
+    if let Some(index) = pages.index_of(controller) {
+        pages.set_current(index);
+    }
+}
+
+/// Registers an `NSObject` subclass that acts as a `UIPageViewControllerDataSource` and
+/// `UIPageViewControllerDelegate`, and calls back into `Pages`.
+fn register_page_view_data_source_class<T: PageViewDataSource + 'static>() -> *const Class {
+    load_or_register_class_for::<T, _>(class!(NSObject), "RSTPageViewDataSource", |decl| unsafe {
+        decl.add_ivar::<usize>(PAGEVIEW_PAGES_PTR);
+
+        decl.add_method(sel!(pageViewController:viewControllerBeforeViewController:), page_before::<T> as extern fn(&Object, _, _, _) -> id);
+        decl.add_method(sel!(pageViewController:viewControllerAfterViewController:), page_after::<T> as extern fn(&Object, _, _, _) -> id);
+        decl.add_method(sel!(presentationCountForPageViewController:), presentation_count::<T> as extern fn(&Object, _, _) -> NSInteger);
+        decl.add_method(sel!(presentationIndexForPageViewController:), presentation_index::<T> as extern fn(&Object, _, _) -> NSInteger);
+        decl.add_method(
+            sel!(pageViewController:didFinishAnimating:previousViewControllers:transitionCompleted:),
+            did_finish_animating::<T> as extern fn(&Object, _, _, BOOL, _, BOOL)
+        );
+    })
+}
+
+/// Creates the `UIPageViewController` and its data source, returning its view, itself, and the
+/// data source.
+pub(crate) fn create<T: PageViewDataSource + 'static>(pages: &Pages<T>) -> (id, ShareId<Object>, ShareId<Object>) {
+    unsafe {
+        let data_source: id = msg_send![register_page_view_data_source_class::<T>(), new];
+        let ptr: *const Pages<T> = pages;
+        (&mut *data_source).set_ivar(PAGEVIEW_PAGES_PTR, ptr as usize);
+
+        let controller: id = msg_send![class!(UIPageViewController), alloc];
+        let controller: id = msg_send![controller, initWithTransitionStyle:TRANSITION_STYLE_SCROLL
+            navigationOrientation:ORIENTATION_HORIZONTAL
+            options:nil];
+
+        let _: () = msg_send![controller, setDataSource:data_source];
+        let _: () = msg_send![controller, setDelegate:data_source];
+
+        let view: id = msg_send![controller, view];
+        (view, ShareId::from_retained_ptr(controller), ShareId::from_retained_ptr(data_source))
+    }
+}
+
+/// Shows `index`, without going through the delegate.
+fn show_page<T: PageViewDataSource>(page_view: &PageView<T>, index: usize, direction: NSInteger, animated: bool) {
+    let controllers: NSArray = vec![page_view.pages.controller(index)].into();
+
+    unsafe {
+        let _: () = msg_send![&*page_view.controller, setViewControllers:controllers.into_inner()
+            direction:direction
+            animated:match animated {
+                true => YES,
+                false => NO
+            }
+            completion:nil];
+    }
+}
+
+/// Shows the first of the current pages, replacing the `previous` ones.
+pub(crate) fn reload<T: PageViewDataSource>(page_view: &PageView<T>, _previous: &[Box<dyn Controller>]) {
+    if page_view.pages.count() > 0 {
+        show_page(page_view, 0, DIRECTION_FORWARD, false);
+    }
+}
+
+/// Scrolls to the page at `index`, in whichever direction that is.
+pub(crate) fn scroll_to_page<T: PageViewDataSource>(page_view: &PageView<T>, index: usize, animated: bool) {
+    let direction = match index < page_view.pages.current() {
+        true => DIRECTION_REVERSE,
+        false => DIRECTION_FORWARD
+    };
+
+    show_page(page_view, index, direction, animated);
+}
+
+/// Breaks the data source and delegate links.
+pub(crate) fn detach(controller: &Object) {
+    unsafe {
+        let _: () = msg_send![controller, setDataSource:nil];
+        let _: () = msg_send![controller, setDelegate:nil];
+    }
+}
@@ -0,0 +1,179 @@
+//! The macOS side of `PageView`: an `NSScrollView` with the pages laid out side by side, and an
+//! observer that snaps to the nearest page once the user stops scrolling.
+
+use core_graphics::base::CGFloat;
+use core_graphics::geometry::{CGPoint, CGRect};
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, YES, NO, NSString};
+use crate::layout::{Layout, LayoutConstraint};
+use crate::pageview::{PAGEVIEW_PAGES_PTR, PageView, PageViewDataSource, Pages};
+use crate::utils::{load_or_register_class_for, Controller};
+use crate::view::View;
+
+static DID_END_LIVE_SCROLL: &str = "NSScrollViewDidEndLiveScrollNotification";
+static FRAME_DID_CHANGE: &str = "NSViewFrameDidChangeNotification";
+
+/// Returns the width of a page, which is the width of the scroll view.
+fn page_width(scroll_view: &Object) -> CGFloat {
+    let bounds: CGRect = unsafe { msg_send![scroll_view, bounds] };
+    bounds.size.width
+}
+
+/// Scrolls `scroll_view` so that the page at `index` fills it.
+pub(crate) fn scroll_to_page(scroll_view: &Object, index: usize, animated: bool) {
+    let point = CGPoint::new(page_width(scroll_view) * index as CGFloat, 0.);
+
+    unsafe {
+        let clip_view: id = msg_send![scroll_view, contentView];
+
+        match animated {
+            true => {
+                let animator: id = msg_send![clip_view, animator];
+                let _: () = msg_send![animator, setBoundsOrigin:point];
+            },
+
+            false => {
+                let _: () = msg_send![clip_view, scrollToPoint:point];
+                let _: () = msg_send![scroll_view, reflectScrolledClipView:clip_view];
+            }
+        }
+    }
+}
+
+/// Called when the user stops scrolling; snaps to whichever page is mostly showing.
+extern fn did_end_live_scroll<T: PageViewDataSource>(this: &Object, _: Sel, notification: id) {
+    let pages = load_or_return!(Pages<T>, this, PAGEVIEW_PAGES_PTR);
+
+    let scroll_view: id = unsafe { msg_send![notification, object] };
+    let scroll_view = unsafe { &*scroll_view };
+    let width = page_width(scroll_view);
+    if width <= 0. || pages.count() == 0 {
+        return;
+    }
+
+    let bounds: CGRect = unsafe {
+        let clip_view: id = msg_send![scroll_view, contentView];
+        msg_send![clip_view, bounds]
+    };
+
+    let index = (bounds.origin.x / width).round().max(0.) as usize;
+    let index = index.min(pages.count() - 1);
+
+    scroll_to_page(scroll_view, index, true);
+    pages.set_current(index);
+}
+
+/// Called when the scroll view is resized, which changes the page width; keeps the current page
+/// lined up.
+extern fn frame_did_change<T: PageViewDataSource>(this: &Object, _: Sel, notification: id) {
+    let pages = load_or_return!(Pages<T>, this, PAGEVIEW_PAGES_PTR);
+
+    let scroll_view: id = unsafe { msg_send![notification, object] };
+    scroll_to_page(unsafe { &*scroll_view }, pages.current(), false);
+}
+
+/// Registers an `NSObject` subclass that observes the scroll view, and calls back into `Pages`.
+fn register_page_view_observer_class<T: PageViewDataSource + 'static>() -> *const Class {
+    load_or_register_class_for::<T, _>(class!(NSObject), "RSTPageViewObserver", |decl| unsafe {
+        decl.add_ivar::<usize>(PAGEVIEW_PAGES_PTR);
+
+        decl.add_method(sel!(rstDidEndLiveScroll:), did_end_live_scroll::<T> as extern fn(&Object, _, _));
+        decl.add_method(sel!(rstFrameDidChange:), frame_did_change::<T> as extern fn(&Object, _, _));
+    })
+}
+
+/// Creates the scroll view, the document view for the pages to live in, and the observer.
+pub(crate) fn create<T: PageViewDataSource + 'static>(pages: &Pages<T>) -> (id, View, ShareId<Object>) {
+    let document = View::new();
+
+    let scroll_view: id = unsafe {
+        let scroll_view: id = msg_send![class!(NSScrollView), new];
+        let _: () = msg_send![scroll_view, setTranslatesAutoresizingMaskIntoConstraints:NO];
+        let _: () = msg_send![scroll_view, setDrawsBackground:NO];
+        let _: () = msg_send![scroll_view, setHasHorizontalScroller:NO];
+        let _: () = msg_send![scroll_view, setHasVerticalScroller:NO];
+        let _: () = msg_send![scroll_view, setVerticalScrollElasticity:1];
+        let _: () = msg_send![scroll_view, setDocumentView:&*document.objc];
+        let _: () = msg_send![scroll_view, setPostsFrameChangedNotifications:YES];
+        msg_send![scroll_view, autorelease]
+    };
+
+    // The document view is as tall as what's visible, and as wide as the pages need.
+    let clip_view = unsafe { View::from_ptr(msg_send![scroll_view, contentView]) };
+    LayoutConstraint::activate(&[
+        document.top.constraint_equal_to(&clip_view.top),
+        document.leading.constraint_equal_to(&clip_view.leading),
+        document.height.constraint_equal_to(&clip_view.height)
+    ]);
+
+    let observer = unsafe {
+        let observer: id = msg_send![register_page_view_observer_class::<T>(), new];
+        let ptr: *const Pages<T> = pages;
+        (&mut *observer).set_ivar(PAGEVIEW_PAGES_PTR, ptr as usize);
+
+        let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+
+        let name = NSString::new(DID_END_LIVE_SCROLL);
+        let _: () = msg_send![center, addObserver:observer
+            selector:sel!(rstDidEndLiveScroll:)
+            name:name
+            object:scroll_view];
+
+        let name = NSString::new(FRAME_DID_CHANGE);
+        let _: () = msg_send![center, addObserver:observer
+            selector:sel!(rstFrameDidChange:)
+            name:name
+            object:scroll_view];
+
+        ShareId::from_retained_ptr(observer)
+    };
+
+    (scroll_view, document, observer)
+}
+
+/// Swaps the `previous` pages' views out for the current ones, side by side, each as wide as the
+/// scroll view.
+pub(crate) fn reload<T: PageViewDataSource>(page_view: &PageView<T>, previous: &[Box<dyn Controller>]) {
+    for page in previous {
+        unsafe {
+            let view: id = msg_send![&*page.get_backing_node(), view];
+            let _: () = msg_send![view, removeFromSuperview];
+        }
+    }
+
+    let mut constraints = vec![];
+    let mut leading = page_view.document.leading.clone();
+
+    for index in 0..page_view.pages.count() {
+        let page = unsafe {
+            let view: id = msg_send![page_view.pages.controller(index), view];
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints:NO];
+            View::from_ptr(view)
+        };
+
+        page_view.document.add_subview(&page);
+
+        constraints.push(page.top.constraint_equal_to(&page_view.document.top));
+        constraints.push(page.bottom.constraint_equal_to(&page_view.document.bottom));
+        constraints.push(page.leading.constraint_equal_to(&leading));
+        constraints.push(page.width.constraint_equal_to(&page_view.width));
+        leading = page.trailing.clone();
+    }
+
+    constraints.push(page_view.document.trailing.constraint_equal_to(&leading));
+    LayoutConstraint::activate(&constraints);
+
+    scroll_to_page(&*page_view.objc, 0, false);
+}
+
+/// Stops observing the scroll view.
+pub(crate) fn detach(observer: &Object) {
+    unsafe {
+        let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+        let _: () = msg_send![center, removeObserver:observer];
+    }
+}
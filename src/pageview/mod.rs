@@ -0,0 +1,287 @@
+//! A paging container: shows one page at a time, with the user swiping (or scrolling) between
+//! them. Useful for onboarding flows, carousels and the like.
+//!
+//! On iOS, this wraps `UIPageViewController`, which also shows a page indicator (the dots) below
+//! the pages. On macOS, this is an `NSScrollView` with the pages laid out side by side, which
+//! snaps to the nearest page when the user stops scrolling. There's no system page indicator on
+//! macOS; use `PageViewDataSource::page_changed()` to drive your own.
+//!
+//! ```rust,no_run
+//! use cacao::pageview::{PageView, PageViewDataSource};
+//! use cacao::view::{Controller, ViewController, ViewDelegate};
+//!
+//! struct Step(usize);
+//! impl ViewDelegate for Step {}
+//!
+//! struct Onboarding;
+//!
+//! impl PageViewDataSource for Onboarding {
+//!     fn number_of_pages(&self) -> usize { 3 }
+//!
+//!     fn page(&self, index: usize) -> Box<dyn Controller> {
+//!         Box::new(ViewController::new(Step(index)))
+//!     }
+//!
+//!     fn page_changed(&self, index: usize) {
+//!         println!("Now on step {}", index + 1);
+//!     }
+//! }
+//!
+//! let pages = PageView::new(Onboarding);
+//! pages.scroll_to_page(1, true);
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+
+use objc::{msg_send, sel, sel_impl};
+use objc::runtime::Object;
+use objc_id::ShareId;
+
+use crate::dispatch::assert_main_thread;
+use crate::foundation::id;
+use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
+use crate::utils::{clear_ptr, Controller};
+
+#[cfg(target_os = "macos")]
+use crate::view::View;
+
+#[cfg(target_os = "macos")]
+mod macos;
+
+#[cfg(target_os = "ios")]
+mod ios;
+
+mod traits;
+pub use traits::PageViewDataSource;
+
+pub(crate) static PAGEVIEW_PAGES_PTR: &str = "rstPageViewPagesPtr";
+
+/// The data source, along with the pages it's handed us so far. The Objective-C side points at
+/// this.
+pub(crate) struct Pages<T> {
+    pub data_source: T,
+    controllers: RefCell<HashMap<usize, Box<dyn Controller>>>,
+    current: Cell<usize>
+}
+
+impl<T> Pages<T> where T: PageViewDataSource {
+    /// Returns the number of pages, per the data source.
+    pub fn count(&self) -> usize {
+        self.data_source.number_of_pages()
+    }
+
+    /// Returns the controller for the page at `index`, asking the data source for it if we haven't
+    /// already. It stays valid until `clear()` is called.
+    pub fn controller(&self, index: usize) -> id {
+        if let Some(controller) = self.controllers.borrow().get(&index) {
+            return &*controller.get_backing_node() as *const Object as id;
+        }
+
+        // The data source is called without the cache borrowed, in case it does something
+        // reentrant.
+        let controller = self.data_source.page(index);
+        let ptr = &*controller.get_backing_node() as *const Object as id;
+        self.controllers.borrow_mut().insert(index, controller);
+        ptr
+    }
+
+    /// Returns the index of the page backed by `controller`, if it's one of ours.
+    pub fn index_of(&self, controller: id) -> Option<usize> {
+        self.controllers.borrow().iter().find(|(_, page)| {
+            &*page.get_backing_node() as *const Object as id == controller
+        }).map(|(index, _)| *index)
+    }
+
+    /// Returns the index of the visible page.
+    pub fn current(&self) -> usize {
+        self.current.get()
+    }
+
+    /// Records `index` as the visible page, letting the data source know if that's a change.
+    pub fn set_current(&self, index: usize) {
+        if self.current.replace(index) != index {
+            self.data_source.page_changed(index);
+        }
+    }
+
+    /// Drops every page we've been handed, and goes back to the first.
+    pub fn clear(&self) -> Vec<Box<dyn Controller>> {
+        self.current.set(0);
+        self.controllers.borrow_mut().drain().map(|(_, page)| page).collect()
+    }
+}
+
+/// A paging container; see the module documentation.
+pub struct PageView<T> {
+    /// The underlying view: an `NSScrollView` on macOS, or the `UIPageViewController`'s view on
+    /// iOS.
+    pub objc: ShareId<Object>,
+
+    /// The underlying `UIPageViewController`.
+    #[cfg(target_os = "ios")]
+    pub controller: ShareId<Object>,
+
+    /// The view the pages are laid out in, inside the scroll view.
+    #[cfg(target_os = "macos")]
+    document: View,
+
+    /// The Objective-C object that calls back into `pages`: a notification observer on macOS, and
+    /// the data source (and delegate) on iOS.
+    handler: ShareId<Object>,
+
+    pages: Box<Pages<T>>,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    pub center_y: LayoutAnchorY
+}
+
+impl<T> PageView<T> where T: PageViewDataSource + 'static {
+    /// Creates a `PageView` showing the first page from `data_source`.
+    pub fn new(data_source: T) -> Self {
+        assert_main_thread("PageView::new");
+
+        let pages = Box::new(Pages {
+            data_source: data_source,
+            controllers: RefCell::new(HashMap::new()),
+            current: Cell::new(0)
+        });
+
+        #[cfg(target_os = "macos")]
+        let (view, document, handler) = macos::create(&*pages);
+
+        #[cfg(target_os = "ios")]
+        let (view, controller, handler) = ios::create(&*pages);
+
+        let page_view = PageView {
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            objc: unsafe { ShareId::from_ptr(view) },
+
+            #[cfg(target_os = "ios")]
+            controller: controller,
+
+            #[cfg(target_os = "macos")]
+            document: document,
+
+            handler: handler,
+            pages: pages
+        };
+
+        page_view.reload();
+        page_view
+    }
+
+    /// Asks the data source for its pages again (dropping the ones it handed over before), and
+    /// goes back to the first.
+    pub fn reload(&self) {
+        let previous = self.pages.clear();
+
+        #[cfg(target_os = "macos")]
+        macos::reload(self, &previous);
+
+        #[cfg(target_os = "ios")]
+        ios::reload(self, &previous);
+    }
+
+    /// Returns the index of the visible page.
+    pub fn current_page(&self) -> usize {
+        self.pages.current()
+    }
+
+    /// Returns the number of pages.
+    pub fn number_of_pages(&self) -> usize {
+        self.pages.count()
+    }
+
+    /// Scrolls to the page at `index`. Does nothing if there's no such page.
+    pub fn scroll_to_page(&self, index: usize, animated: bool) {
+        if index >= self.pages.count() {
+            return;
+        }
+
+        #[cfg(target_os = "macos")]
+        macos::scroll_to_page(&*self.objc, index, animated);
+
+        #[cfg(target_os = "ios")]
+        ios::scroll_to_page(self, index, animated);
+
+        self.pages.set_current(index);
+    }
+
+    /// Returns a reference to the data source.
+    pub fn data_source(&self) -> &T {
+        &self.pages.data_source
+    }
+}
+
+impl<T> Layout for PageView<T> {
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.objc.clone()
+    }
+
+    fn add_subview<V: Layout>(&self, view: &V) {
+        let backing_node = view.get_backing_node();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, addSubview:backing_node];
+        }
+    }
+}
+
+#[cfg(target_os = "ios")]
+impl<T> Controller for PageView<T> {
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.controller.clone()
+    }
+}
+
+impl<T> fmt::Debug for PageView<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PageView")
+            .field("objc", &self.objc)
+            .field("current_page", &self.pages.current.get())
+            .finish()
+    }
+}
+
+impl<T> Drop for PageView<T> {
+    /// Breaks the link from the Objective-C side, as the pages are going away.
+    fn drop(&mut self) {
+        clear_ptr(&self.handler, PAGEVIEW_PAGES_PTR);
+
+        #[cfg(target_os = "macos")]
+        macos::detach(&*self.handler);
+
+        #[cfg(target_os = "ios")]
+        ios::detach(&*self.controller);
+    }
+}
@@ -0,0 +1,18 @@
+//! Traits for `PageView`.
+
+use crate::utils::Controller;
+
+/// Implement this to supply the pages for a `PageView`.
+pub trait PageViewDataSource {
+    /// Returns the number of pages.
+    fn number_of_pages(&self) -> usize;
+
+    /// Returns the controller for the page at `index` - typically a `ViewController` wrapping
+    /// your page's `ViewDelegate`. Each page is asked for once, and held by the `PageView` until
+    /// it's reloaded (or dropped).
+    fn page(&self, index: usize) -> Box<dyn Controller>;
+
+    /// Called when the visible page changes - whether the user swiped to it, or you scrolled to
+    /// it with `PageView::scroll_to_page()`.
+    fn page_changed(&self, _index: usize) {}
+}
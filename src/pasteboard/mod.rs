@@ -9,8 +9,9 @@ use objc::{class, msg_send, sel, sel_impl};
 use objc_id::ShareId;
 use url::Url;
 
-use crate::foundation::{id, nil, NSString, NSArray};
+use crate::foundation::{id, nil, BOOL, YES, NO, NSString, NSArray};
 use crate::error::Error;
+use crate::uti::UTType;
 
 mod types;
 pub use types::{PasteboardName, PasteboardType};
@@ -65,8 +66,62 @@ impl Pasteboard {
         }
     }
 
+    /// Returns the types of data currently on the pasteboard, most preferred first.
+    pub fn types(&self) -> Vec<UTType> {
+        let types: id = unsafe { msg_send![&*self.0, types] };
+
+        if types == nil {
+            return vec![];
+        }
+
+        let types = NSArray::wrap(types);
+
+        match types.count() {
+            0 => vec![],
+            _ => types.map(|t| UTType::new(NSString::wrap(t).to_str()))
+        }
+    }
+
+    /// Returns whether the pasteboard has data of the given type (or of a type that conforms to
+    /// it - e.g, `public.png` data satisfies `UTType::IMAGE`).
+    pub fn contains(&self, uti: &UTType) -> bool {
+        self.types().iter().any(|t| t.conforms_to(uti))
+    }
+
+    /// Returns the string contents of the pasteboard for the given type, if there are any.
+    pub fn get_string(&self, pasteboard_type: PasteboardType) -> Option<String> {
+        let pasteboard_type: NSString = pasteboard_type.into();
+
+        let value: id = unsafe {
+            msg_send![&*self.0, stringForType:&*pasteboard_type.0]
+        };
+
+        match value == nil {
+            true => None,
+            false => Some(NSString::wrap(value).to_string())
+        }
+    }
+
+    /// Writes a string to the pasteboard as the given type. You generally want to call
+    /// `clear_contents()` first, as writing fails if another app owns the pasteboard contents.
+    /// Returns whether the write succeeded.
+    pub fn set_string(&self, value: &str, pasteboard_type: PasteboardType) -> bool {
+        let value = NSString::new(value);
+        let pasteboard_type: NSString = pasteboard_type.into();
+
+        let result: BOOL = unsafe {
+            msg_send![&*self.0, setString:value forType:&*pasteboard_type.0]
+        };
+
+        match result {
+            YES => true,
+            NO => false,
+            _ => unreachable!()
+        }
+    }
+
     /// Looks inside the pasteboard contents and extracts what FileURLs are there, if any.
-    pub fn get_file_urls(&self) -> Result<Vec<Url>, Box<dyn std::error::Error>> {
+    pub fn get_file_urls(&self) -> Result<Vec<Url>, Error> {
         unsafe {
             let class: id = msg_send![class!(NSURL), class];
             let classes = NSArray::new(&[class]);
@@ -78,11 +133,11 @@ impl Pasteboard {
                 // This error is not necessarily "correct", but in the event of an error in
                 // Pasteboard server retrieval I'm not sure where to check... and this stuff is
                 // kinda ancient and has conflicting docs in places. ;P
-                return Err(Box::new(Error {
+                return Err(Error::Cocoa {
                     code: 666,
                     domain: "com.cacao-rs.pasteboard".to_string(),
                     description: "Pasteboard server returned no data.".to_string()
-                }));
+                });
             }
 
             let urls = NSArray::wrap(contents).map(|url| {
@@ -95,7 +150,7 @@ impl Pasteboard {
     }
 
     /// Looks inside the pasteboard contents and extracts what FileURLs are there, if any.
-    pub fn get_file_paths(&self) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+    pub fn get_file_paths(&self) -> Result<Vec<PathBuf>, Error> {
         unsafe {
             let class: id = msg_send![class!(NSURL), class];
             let classes = NSArray::new(&[class]);
@@ -107,11 +162,11 @@ impl Pasteboard {
                 // This error is not necessarily "correct", but in the event of an error in
                 // Pasteboard server retrieval I'm not sure where to check... and this stuff is
                 // kinda ancient and has conflicting docs in places. ;P
-                return Err(Box::new(Error {
+                return Err(Error::Cocoa {
                     code: 666,
                     domain: "com.cacao-rs.pasteboard".to_string(),
                     description: "Pasteboard server returned no data.".to_string()
-                }));
+                });
             }
 
             let urls = NSArray::wrap(contents).map(|url| {
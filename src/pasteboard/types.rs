@@ -2,6 +2,7 @@
 //! exhaustive clone, but feel free to pull request accordingly!
 
 use crate::foundation::NSString;
+use crate::uti::UTType;
 
 /// Constants for the standard system pasteboard names.
 #[derive(Debug, Copy, Clone)]
@@ -83,24 +84,31 @@ pub enum PasteboardType {
     TIFF
 }
 
+impl From<PasteboardType> for UTType {
+    fn from(pboard_type: PasteboardType) -> Self {
+        match pboard_type {
+            PasteboardType::URL => UTType::URL,
+            PasteboardType::Color => UTType::new("com.apple.cocoa.pasteboard.color"),
+            PasteboardType::FileURL => UTType::FILE_URL,
+            PasteboardType::Font => UTType::new("com.apple.cocoa.pasteboard.character-formatting"),
+            PasteboardType::HTML => UTType::HTML,
+            PasteboardType::MultipleTextSelection => UTType::new("com.apple.cocoa.pasteboard.multiple-text-selection"),
+            PasteboardType::PDF => UTType::PDF,
+            PasteboardType::PNG => UTType::PNG,
+            PasteboardType::RTF => UTType::RTF,
+            PasteboardType::RTFD => UTType::FLAT_RTFD,
+            PasteboardType::Ruler => UTType::new("com.apple.cocoa.pasteboard.paragraph-formatting"),
+            PasteboardType::Sound => UTType::new("com.apple.cocoa.pasteboard.sound"),
+            PasteboardType::String => UTType::UTF8_PLAIN_TEXT,
+            PasteboardType::TabularText => UTType::TAB_SEPARATED_TEXT,
+            PasteboardType::TIFF => UTType::TIFF,
+        }
+    }
+}
+
 impl From<PasteboardType> for NSString {
     fn from(pboard_type: PasteboardType) -> Self {
-        NSString::new(match pboard_type {
-            PasteboardType::URL => "public.url",
-            PasteboardType::Color => "com.apple.cocoa.pasteboard.color",
-            PasteboardType::FileURL => "public.file-url",
-            PasteboardType::Font => "com.apple.cocoa.pasteboard.character-formatting",
-            PasteboardType::HTML => "public.html",
-            PasteboardType::MultipleTextSelection => "com.apple.cocoa.pasteboard.multiple-text-selection",
-            PasteboardType::PDF => "com.adobe.pdf",
-            PasteboardType::PNG => "public.png",
-            PasteboardType::RTF => "public.rtf",
-            PasteboardType::RTFD => "com.apple.flat-rtfd",
-            PasteboardType::Ruler => "com.apple.cocoa.pasteboard.paragraph-formatting",
-            PasteboardType::Sound => "com.apple.cocoa.pasteboard.sound",
-            PasteboardType::String => "public.utf8-plain-text",
-            PasteboardType::TabularText => "public.utf8-tab-separated-values-text",
-            PasteboardType::TIFF => "public.tiff",
-        })
+        let uti: UTType = pboard_type.into();
+        NSString::new(uti.identifier())
     }
 }
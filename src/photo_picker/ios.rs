@@ -0,0 +1,222 @@
+//! The iOS side of `PhotoPicker`: a `PHPickerViewController`, with an `NSObject` subclass acting
+//! as its delegate.
+//!
+//! The picker hands back item providers rather than files, and loads them on background threads.
+//! Each item is copied into the temporary directory as it arrives; once they've all arrived, we hop
+//! back to the main thread to load the images and call the handler.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::sync::Once;
+
+use block::ConcreteBlock;
+
+use objc::runtime::{Class, Object, Sel, BOOL};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::dispatch::main_async;
+use crate::foundation::{id, nil, YES, NSArray, NSInteger, NSString};
+use crate::image::Image;
+use crate::photo_picker::{PHOTO_PICKER_PTR, PhotoFilter, PickedMedia, PickerHandler};
+use crate::utils::{load_or_register_class, try_load};
+
+static IMAGE_TYPE: &str = "public.image";
+static MOVIE_TYPE: &str = "public.movie";
+
+/// What an item turned out to be.
+#[derive(Clone, Copy, Debug)]
+enum MediaKind {
+    Image,
+    Video
+}
+
+/// Tracks items as they're copied out on background threads.
+#[derive(Debug)]
+struct Loading {
+    remaining: usize,
+    files: Vec<Option<(MediaKind, PathBuf)>>
+}
+
+/// Copies the file at `url` into the temporary directory, under a unique name (keeping the
+/// extension). The picker deletes its copy once the load handler returns, so this has to happen
+/// before then.
+fn copy_to_temporary_directory(url: id) -> Option<PathBuf> {
+    let source = PathBuf::from(NSString::wrap(unsafe { msg_send![url, path] }).to_string());
+
+    let name = NSString::wrap(unsafe {
+        let uuid: id = msg_send![class!(NSUUID), UUID];
+        msg_send![uuid, UUIDString]
+    }).to_string();
+
+    let mut destination = std::env::temp_dir().join(name);
+    if let Some(extension) = source.extension() {
+        destination.set_extension(extension);
+    }
+
+    std::fs::copy(&source, &destination).ok().map(|_| destination)
+}
+
+/// Called on the main thread once every item has been copied out. Loads the images, and hands
+/// everything to the handler (if the `PhotoPicker` is still around).
+fn finish(delegate: usize, files: Vec<Option<(MediaKind, PathBuf)>>) {
+    let delegate = delegate as id;
+
+    let media = files.into_iter().flatten().filter_map(|(kind, path)| match kind {
+        MediaKind::Image => {
+            let path = NSString::new(&path.to_string_lossy());
+            let image: id = unsafe { msg_send![class!(UIImage), imageWithContentsOfFile:path] };
+
+            match image == nil {
+                true => None,
+                false => Some(PickedMedia::Image(Image::with(image)))
+            }
+        },
+
+        MediaKind::Video => Some(PickedMedia::Video(path))
+    }).collect::<Vec<PickedMedia>>();
+
+    unsafe {
+        if let Ok(handler) = try_load::<PickerHandler>(&*delegate, PHOTO_PICKER_PTR) {
+            (handler.0)(media);
+        }
+
+        let _: () = msg_send![delegate, release];
+    }
+}
+
+/// Returns whether `provider` has something of the given type.
+fn has_item_of_type(provider: id, identifier: &str) -> bool {
+    let identifier = NSString::new(identifier);
+    let result: BOOL = unsafe { msg_send![provider, hasItemConformingToTypeIdentifier:identifier] };
+    result == YES
+}
+
+/// Called when the user is done picking (or cancelled, in which case `results` is empty). Dismisses
+/// the picker, and starts copying out whatever was picked.
+extern fn did_finish_picking(this: &Object, _: Sel, picker: id, results: id) {
+    unsafe {
+        let _: () = msg_send![picker, dismissViewControllerAnimated:YES completion:nil];
+    }
+
+    let items = NSArray::wrap(results).map(|result| {
+        let provider: id = unsafe { msg_send![result, itemProvider] };
+
+        match (has_item_of_type(provider, IMAGE_TYPE), has_item_of_type(provider, MOVIE_TYPE)) {
+            (true, _) => Some((provider, MediaKind::Image)),
+            (_, true) => Some((provider, MediaKind::Video)),
+            _ => None
+        }
+    });
+
+    let count = items.iter().flatten().count();
+    if count == 0 {
+        let handler = load_or_return!(PickerHandler, this, PHOTO_PICKER_PTR);
+        (handler.0)(Vec::new());
+        return;
+    }
+
+    let loading = Arc::new(Mutex::new(Loading {
+        remaining: count,
+        files: vec![None; items.len()]
+    }));
+
+    // Kept alive until `finish()`, so the handler can be found (or found missing) from there.
+    let delegate: id = unsafe { msg_send![this, retain] };
+    let delegate = delegate as usize;
+
+    for (index, item) in items.into_iter().enumerate() {
+        let (provider, kind) = match item {
+            Some(item) => item,
+            None => continue
+        };
+
+        let loading = loading.clone();
+        let handler = ConcreteBlock::new(move |url: id, _error: id| {
+            let path = match url == nil {
+                true => None,
+                false => copy_to_temporary_directory(url)
+            };
+
+            let mut loading = loading.lock().unwrap();
+            loading.files[index] = path.map(|path| (kind, path));
+            loading.remaining -= 1;
+
+            if loading.remaining == 0 {
+                let files = std::mem::take(&mut loading.files);
+                main_async(move || finish(delegate, files));
+            }
+        });
+        let handler = handler.copy();
+
+        let identifier = NSString::new(match kind {
+            MediaKind::Image => IMAGE_TYPE,
+            MediaKind::Video => MOVIE_TYPE
+        });
+
+        unsafe {
+            let _: id = msg_send![provider, loadFileRepresentationForTypeIdentifier:identifier
+                completionHandler:handler];
+        }
+    }
+}
+
+/// Registers an `NSObject` subclass that acts as a `PHPickerViewControllerDelegate`.
+fn register_photo_picker_delegate_class() -> *const Class {
+    static mut DELEGATE_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSObject);
+        DELEGATE_CLASS = load_or_register_class(superclass, "RSTPhotoPickerDelegate", |decl| {
+            decl.add_ivar::<usize>(PHOTO_PICKER_PTR);
+            decl.add_method(sel!(picker:didFinishPicking:), did_finish_picking as extern fn(&Object, _, _, _));
+        });
+    });
+
+    unsafe { DELEGATE_CLASS }
+}
+
+/// Returns the `PHPickerFilter` for `filter`.
+fn picker_filter(filter: PhotoFilter) -> id {
+    unsafe {
+        match filter {
+            PhotoFilter::Images => msg_send![class!(PHPickerFilter), imagesFilter],
+            PhotoFilter::Videos => msg_send![class!(PHPickerFilter), videosFilter],
+
+            PhotoFilter::Any => {
+                let images: id = msg_send![class!(PHPickerFilter), imagesFilter];
+                let videos: id = msg_send![class!(PHPickerFilter), videosFilter];
+                let filters: NSArray = vec![images, videos].into();
+                msg_send![class!(PHPickerFilter), anyFilterMatchingSubfilters:filters.into_inner()]
+            }
+        }
+    }
+}
+
+/// Creates the picker and its delegate, and presents it from `controller`.
+pub(crate) fn present(
+    filter: PhotoFilter,
+    selection_limit: usize,
+    handler: &PickerHandler,
+    controller: &Object
+) -> (ShareId<Object>, ShareId<Object>) {
+    unsafe {
+        let delegate: id = msg_send![register_photo_picker_delegate_class(), new];
+        let ptr: *const PickerHandler = handler;
+        (&mut *delegate).set_ivar(PHOTO_PICKER_PTR, ptr as usize);
+
+        let configuration: id = msg_send![class!(PHPickerConfiguration), new];
+        let _: () = msg_send![configuration, setSelectionLimit:selection_limit as NSInteger];
+        let _: () = msg_send![configuration, setFilter:picker_filter(filter)];
+
+        let picker: id = msg_send![class!(PHPickerViewController), alloc];
+        let picker: id = msg_send![picker, initWithConfiguration:configuration];
+        let _: () = msg_send![configuration, release];
+
+        let _: () = msg_send![picker, setDelegate:delegate];
+        let _: () = msg_send![controller, presentViewController:picker animated:YES completion:nil];
+
+        (ShareId::from_retained_ptr(picker), ShareId::from_retained_ptr(delegate))
+    }
+}
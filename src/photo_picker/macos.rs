@@ -0,0 +1,80 @@
+//! The macOS side of `PhotoPicker`: an `NSOpenPanel` that only allows images and movies.
+
+use std::path::PathBuf;
+
+use block::ConcreteBlock;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, YES, NO, NSArray, NSInteger, NSString};
+use crate::image::Image;
+use crate::photo_picker::{PhotoFilter, PickedMedia};
+use crate::uti::{self, UTType};
+
+/// `NSModalResponseOK`.
+const MODAL_RESPONSE_OK: NSInteger = 1;
+
+/// Loads what's at each of the panel's URLs: images as `Image`s, and anything else as a path.
+fn picked_media(panel: &Object) -> Vec<PickedMedia> {
+    let urls = NSArray::wrap(unsafe { msg_send![panel, URLs] });
+
+    urls.map(|url| {
+        let path = NSString::wrap(unsafe { msg_send![url, path] }).to_string();
+        let path = PathBuf::from(path);
+
+        let is_image = path.extension()
+            .and_then(|extension| UTType::from_extension(&extension.to_string_lossy()))
+            .map(|uti| uti.conforms_to(&UTType::IMAGE))
+            .unwrap_or(false);
+
+        let image: id = match is_image {
+            true => unsafe {
+                let image: id = msg_send![class!(NSImage), alloc];
+                msg_send![image, initWithContentsOfURL:url]
+            },
+
+            false => nil
+        };
+
+        match image == nil {
+            true => PickedMedia::Video(path),
+            false => PickedMedia::Image(Image(unsafe { ShareId::from_retained_ptr(image) }))
+        }
+    })
+}
+
+/// Configures and shows the panel, returning it.
+pub(crate) fn present<F>(filter: PhotoFilter, selection_limit: usize, completion: F) -> ShareId<Object>
+where
+    F: Fn(Vec<PickedMedia>) + 'static
+{
+    let panel: ShareId<Object> = unsafe {
+        let panel: id = msg_send![class!(NSOpenPanel), openPanel];
+        let _: () = msg_send![panel, setCanChooseFiles:YES];
+        let _: () = msg_send![panel, setCanChooseDirectories:NO];
+        uti::set_allowed_content_types(&*panel, &filter.content_types());
+        let _: () = msg_send![panel, setAllowsMultipleSelection:match selection_limit == 1 {
+            true => NO,
+            false => YES
+        }];
+
+        ShareId::from_ptr(panel)
+    };
+
+    let handler_panel = panel.clone();
+    let handler = ConcreteBlock::new(move |response: NSInteger| {
+        completion(match response == MODAL_RESPONSE_OK {
+            true => picked_media(&handler_panel),
+            false => Vec::new()
+        });
+    });
+    let handler = handler.copy();
+
+    unsafe {
+        let _: () = msg_send![&*panel, beginWithCompletionHandler:handler];
+    }
+
+    panel
+}
@@ -0,0 +1,171 @@
+//! Lets the user pick photos and videos. On iOS this wraps `PHPickerViewController`, which runs
+//! out of process and doesn't need photo library permission; on macOS, it's an `NSOpenPanel`
+//! limited to images and movies (which the Photos library shows up in, in the sidebar).
+//!
+//! Images are handed back loaded, as `Image`s. Videos are handed back as file paths: on iOS,
+//! they're copies in the app's temporary directory, which are yours to move or delete.
+//!
+//! ```rust,no_run
+//! use cacao::photo_picker::{PhotoFilter, PhotoPicker, PickedMedia};
+//!
+//! let mut picker = PhotoPicker::new(PhotoFilter::Images);
+//! picker.set_selection_limit(0);
+//!
+//! # #[cfg(target_os = "macos")]
+//! picker.show(|media| {
+//!     for item in media {
+//!         if let PickedMedia::Image(image) = item {
+//!             // Show it...
+//!         }
+//!     }
+//! });
+//! ```
+
+use std::fmt;
+use std::path::PathBuf;
+
+use objc::{msg_send, sel, sel_impl};
+use objc::runtime::Object;
+use objc_id::ShareId;
+
+use crate::foundation::nil;
+use crate::image::Image;
+use crate::utils::clear_ptr;
+use crate::uti::UTType;
+
+#[cfg(target_os = "ios")]
+use crate::utils::Controller;
+
+#[cfg(target_os = "macos")]
+mod macos;
+
+#[cfg(target_os = "ios")]
+mod ios;
+
+pub(crate) static PHOTO_PICKER_PTR: &str = "rstPhotoPickerPtr";
+
+/// Holds the callback for a `PhotoPicker`. This exists to give us a concrete (sized) type to
+/// point to from the Objective-C side.
+pub(crate) struct PickerHandler(pub Box<dyn Fn(Vec<PickedMedia>) + 'static>);
+
+/// Which kinds of media the picker offers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PhotoFilter {
+    /// Photos (and other images) only.
+    Images,
+
+    /// Videos only.
+    Videos,
+
+    /// Both photos and videos.
+    Any
+}
+
+impl PhotoFilter {
+    /// Returns the content types this filter allows.
+    pub(crate) fn content_types(&self) -> Vec<UTType> {
+        match self {
+            PhotoFilter::Images => vec![UTType::IMAGE],
+            PhotoFilter::Videos => vec![UTType::MOVIE],
+            PhotoFilter::Any => vec![UTType::IMAGE, UTType::MOVIE]
+        }
+    }
+}
+
+/// Something the user picked.
+#[derive(Clone, Debug)]
+pub enum PickedMedia {
+    /// A photo (or other image), loaded.
+    Image(Image),
+
+    /// A video, as a file on disk.
+    Video(PathBuf)
+}
+
+/// A photo and video picker. Keep this around while it's showing, as it retains your completion
+/// handler.
+pub struct PhotoPicker {
+    /// Which kinds of media the user can pick.
+    pub filter: PhotoFilter,
+
+    /// How many items the user can pick; `0` means there's no limit. Defaults to `1`.
+    pub selection_limit: usize,
+
+    /// The underlying picker (`NSOpenPanel` or `PHPickerViewController`), once shown.
+    pub objc: Option<ShareId<Object>>,
+
+    delegate: Option<ShareId<Object>>,
+    handler: Option<Box<PickerHandler>>
+}
+
+impl PhotoPicker {
+    /// Creates a picker offering the given kinds of media.
+    pub fn new(filter: PhotoFilter) -> Self {
+        PhotoPicker {
+            filter: filter,
+            selection_limit: 1,
+            objc: None,
+            delegate: None,
+            handler: None
+        }
+    }
+
+    /// Sets how many items the user can pick; `0` means there's no limit.
+    ///
+    /// `NSOpenPanel` can only allow one item or any number of them, so on macOS, anything other
+    /// than `1` allows any number.
+    pub fn set_selection_limit(&mut self, limit: usize) {
+        self.selection_limit = limit;
+    }
+
+    /// Shows the picker as a panel. This returns immediately; `completion` is called with what
+    /// the user picked, in the order they picked it, or with an empty `Vec` if they cancelled.
+    #[cfg(target_os = "macos")]
+    pub fn show<F: Fn(Vec<PickedMedia>) + 'static>(&mut self, completion: F) {
+        self.objc = Some(macos::present(self.filter, self.selection_limit, completion));
+    }
+
+    /// Presents the picker from the given controller. `completion` is called with what the user
+    /// picked, in the order they picked it, or with an empty `Vec` if they cancelled.
+    ///
+    /// Items are copied out of the photo library before `completion` is called, so it may be a
+    /// moment (e.g, for long videos, or photos that have to be downloaded from iCloud).
+    #[cfg(target_os = "ios")]
+    pub fn show<VC, F>(&mut self, controller: &VC, completion: F)
+    where
+        VC: Controller,
+        F: Fn(Vec<PickedMedia>) + 'static
+    {
+        let handler = Box::new(PickerHandler(Box::new(completion)));
+        let backing_node = controller.get_backing_node();
+
+        let (picker, delegate) = ios::present(self.filter, self.selection_limit, &handler, &backing_node);
+
+        self.objc = Some(picker);
+        self.delegate = Some(delegate);
+        self.handler = Some(handler);
+    }
+}
+
+impl fmt::Debug for PhotoPicker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PhotoPicker")
+            .field("filter", &self.filter)
+            .field("selection_limit", &self.selection_limit)
+            .field("objc", &self.objc)
+            .finish()
+    }
+}
+
+impl Drop for PhotoPicker {
+    /// Breaks the delegate link, if there is one, as the handler is going away.
+    fn drop(&mut self) {
+        if let (Some(picker), Some(delegate)) = (&self.objc, &self.delegate) {
+            clear_ptr(delegate, PHOTO_PICKER_PTR);
+
+            unsafe {
+                let _: () = msg_send![&**picker, setDelegate:nil];
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Enums used when querying process and system state.
+
+use crate::foundation::NSInteger;
+
+/// Describes how hot the system is running. As this rises, you should reduce the work you're
+/// doing (e.g, lower frame rates, defer background work).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ThermalState {
+    /// Within normal limits; no action needed.
+    Nominal,
+
+    /// Slightly elevated. Consider reducing background work.
+    Fair,
+
+    /// High. Reduce CPU, GPU and I/O usage.
+    Serious,
+
+    /// Critical. Do as little as possible until this drops.
+    Critical
+}
+
+impl From<NSInteger> for ThermalState {
+    fn from(state: NSInteger) -> Self {
+        match state {
+            1 => ThermalState::Fair,
+            2 => ThermalState::Serious,
+            3 => ThermalState::Critical,
+            _ => ThermalState::Nominal
+        }
+    }
+}
+
+/// Options for `ProcessInfo::begin_activity()`, describing the kind of work you're doing (and so
+/// what the system should hold off on while you do it).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ActivityOption {
+    /// Work the user asked for, e.g an import or export. Disables App Nap and idle system sleep.
+    UserInitiated,
+
+    /// The same as `UserInitiated`, but allows the system to sleep while idle.
+    UserInitiatedAllowingIdleSystemSleep,
+
+    /// Maintenance or housekeeping work the user didn't ask for.
+    Background,
+
+    /// Work that needs the highest timer and I/O precision (e.g, audio or video playback). Use
+    /// sparingly; this costs battery life.
+    LatencyCritical,
+
+    /// Prevents the display from sleeping while idle.
+    IdleDisplaySleepDisabled,
+
+    /// Prevents the system from sleeping while idle.
+    IdleSystemSleepDisabled,
+
+    /// Prevents the system from terminating the process suddenly.
+    SuddenTerminationDisabled,
+
+    /// Prevents the system from automatically terminating the process.
+    AutomaticTerminationDisabled
+}
+
+impl From<&ActivityOption> for u64 {
+    fn from(option: &ActivityOption) -> Self {
+        let idle_system_sleep_disabled = 1 << 20;
+        let user_initiated = 0x00FF_FFFF | idle_system_sleep_disabled;
+
+        match option {
+            ActivityOption::UserInitiated => user_initiated,
+            ActivityOption::UserInitiatedAllowingIdleSystemSleep => user_initiated & !idle_system_sleep_disabled,
+            ActivityOption::Background => 0x0000_00FF,
+            ActivityOption::LatencyCritical => 0xFF_0000_0000,
+            ActivityOption::IdleDisplaySleepDisabled => 1 << 40,
+            ActivityOption::IdleSystemSleepDisabled => idle_system_sleep_disabled,
+            ActivityOption::SuddenTerminationDisabled => 1 << 14,
+            ActivityOption::AutomaticTerminationDisabled => 1 << 15
+        }
+    }
+}
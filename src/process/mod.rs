@@ -0,0 +1,249 @@
+//! Wraps `NSProcessInfo`, which provides information about the current process and the system it's
+//! running on - OS version, memory, thermal and power state, and so on.
+//!
+//! ```rust,no_run
+//! use cacao::process::{ActivityOption, ProcessInfo, ThermalState};
+//!
+//! let version = ProcessInfo::os_version();
+//! println!("Running on {}.{}.{}", version.major, version.minor, version.patch);
+//!
+//! // Keep this around for as long as you want to be notified.
+//! let observer = ProcessInfo::observe_thermal_state(|state| {
+//!     if state >= ThermalState::Serious {
+//!         // Back off on expensive work...
+//!     }
+//! });
+//!
+//! // App Nap won't throttle this process until `activity` is dropped.
+//! let activity = ProcessInfo::begin_activity(&[ActivityOption::UserInitiated], "Exporting");
+//! ```
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc::{Encode, Encoding};
+use objc_id::ShareId;
+
+use crate::foundation::{id, YES, NO, BOOL, NSArray, NSInteger, NSString, NSUInteger};
+use crate::notification_center::NotificationObserver;
+
+mod enums;
+pub use enums::{ActivityOption, ThermalState};
+
+static THERMAL_STATE_DID_CHANGE: &str = "NSProcessInfoThermalStateDidChangeNotification";
+static POWER_STATE_DID_CHANGE: &str = "NSProcessInfoPowerStateDidChangeNotification";
+
+/// Returns the `NSProcessInfo` for this process.
+pub(crate) fn process_info() -> id {
+    unsafe { msg_send![class!(NSProcessInfo), processInfo] }
+}
+
+/// Returns the default `NSNotificationCenter`.
+fn default_center() -> id {
+    unsafe { msg_send![class!(NSNotificationCenter), defaultCenter] }
+}
+
+/// A version of the operating system, e.g `11.2.3`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct OperatingSystemVersion {
+    /// The major version (e.g, `11`).
+    pub major: NSInteger,
+
+    /// The minor version (e.g, `2`).
+    pub minor: NSInteger,
+
+    /// The patch version (e.g, `3`).
+    pub patch: NSInteger
+}
+
+unsafe impl Encode for OperatingSystemVersion {
+    fn encode() -> Encoding {
+        let encoding = format!("{{NSOperatingSystemVersion={}{}{}}}",
+            NSInteger::encode().as_str(),
+            NSInteger::encode().as_str(),
+            NSInteger::encode().as_str()
+        );
+
+        unsafe { Encoding::from_str(&encoding) }
+    }
+}
+
+/// An in-progress activity, started via `ProcessInfo::begin_activity()`. The activity ends when
+/// this is dropped.
+#[derive(Debug)]
+pub struct ActivityToken {
+    token: ShareId<Object>
+}
+
+impl Drop for ActivityToken {
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![process_info(), endActivity:&*self.token];
+        }
+    }
+}
+
+/// Provides access to information about the current process and system.
+#[derive(Debug)]
+pub struct ProcessInfo;
+
+impl ProcessInfo {
+    /// Returns the version of the operating system.
+    pub fn os_version() -> OperatingSystemVersion {
+        unsafe { msg_send![process_info(), operatingSystemVersion] }
+    }
+
+    /// Returns a human readable version string for the operating system (e.g,
+    /// `Version 11.2.3 (Build 20D91)`). This isn't meant to be parsed; use `os_version()`.
+    pub fn os_version_string() -> String {
+        NSString::wrap(unsafe {
+            msg_send![process_info(), operatingSystemVersionString]
+        }).to_string()
+    }
+
+    /// Returns whether the operating system is at least the given version.
+    pub fn is_os_at_least(major: NSInteger, minor: NSInteger, patch: NSInteger) -> bool {
+        let version = OperatingSystemVersion {
+            major: major,
+            minor: minor,
+            patch: patch
+        };
+
+        let result: BOOL = unsafe {
+            msg_send![process_info(), isOperatingSystemAtLeastVersion:version]
+        };
+
+        match result {
+            YES => true,
+            NO => false,
+            _ => unreachable!()
+        }
+    }
+
+    /// Returns whether Low Power Mode is enabled. Available on iOS, and on macOS 12+; on older
+    /// versions of macOS this always returns `false`.
+    pub fn is_low_power_mode_enabled() -> bool {
+        unsafe {
+            let info = process_info();
+            let responds: BOOL = msg_send![info, respondsToSelector:sel!(isLowPowerModeEnabled)];
+
+            if responds == NO {
+                return false;
+            }
+
+            match msg_send![info, isLowPowerModeEnabled] {
+                YES => true,
+                NO => false,
+                _ => unreachable!()
+            }
+        }
+    }
+
+    /// Returns the current thermal state of the system.
+    pub fn thermal_state() -> ThermalState {
+        let state: NSInteger = unsafe { msg_send![process_info(), thermalState] };
+        state.into()
+    }
+
+    /// Returns the amount of physical memory on the system, in bytes.
+    pub fn physical_memory() -> u64 {
+        unsafe { msg_send![process_info(), physicalMemory] }
+    }
+
+    /// Returns the number of processing cores on the system.
+    pub fn processor_count() -> usize {
+        let count: NSUInteger = unsafe { msg_send![process_info(), processorCount] };
+        count as usize
+    }
+
+    /// Returns the number of processing cores currently available.
+    pub fn active_processor_count() -> usize {
+        let count: NSUInteger = unsafe { msg_send![process_info(), activeProcessorCount] };
+        count as usize
+    }
+
+    /// Returns how long the system has been running since it was last restarted, in seconds.
+    pub fn system_uptime() -> f64 {
+        unsafe { msg_send![process_info(), systemUptime] }
+    }
+
+    /// Returns the name of this process.
+    pub fn process_name() -> String {
+        NSString::wrap(unsafe {
+            msg_send![process_info(), processName]
+        }).to_string()
+    }
+
+    /// Returns the identifier of this process.
+    pub fn process_identifier() -> i32 {
+        unsafe { msg_send![process_info(), processIdentifier] }
+    }
+
+    /// Returns the command line arguments this process was launched with. Unlike
+    /// `std::env::args()`, this includes arguments Cocoa added (e.g, `-NSDocumentRevisionsDebugMode`).
+    pub fn arguments() -> Vec<String> {
+        NSArray::wrap(unsafe {
+            msg_send![process_info(), arguments]
+        }).map(|argument| NSString::wrap(argument).to_string())
+    }
+
+    /// Returns the environment variables for this process.
+    pub fn environment() -> HashMap<String, String> {
+        unsafe {
+            let environment: id = msg_send![process_info(), environment];
+            let keys = NSArray::wrap(msg_send![environment, allKeys]);
+
+            keys.map(|key| {
+                let value: id = msg_send![environment, objectForKey:key];
+                (NSString::wrap(key).to_string(), NSString::wrap(value).to_string())
+            }).into_iter().collect()
+        }
+    }
+
+    /// Calls `handler` (on the main thread) with the new thermal state whenever it changes. Keep
+    /// the returned `NotificationObserver` around for as long as you want to be notified.
+    pub fn observe_thermal_state<F>(handler: F) -> NotificationObserver
+    where
+        F: Fn(ThermalState) + 'static
+    {
+        let handler = Rc::new(handler);
+
+        NotificationObserver::new(default_center(), &[THERMAL_STATE_DID_CHANGE], move |_, _| {
+            handler(ProcessInfo::thermal_state());
+        })
+    }
+
+    /// Calls `handler` (on the main thread) with whether Low Power Mode is enabled, whenever it
+    /// changes. Keep the returned `NotificationObserver` around for as long as you want to be
+    /// notified.
+    pub fn observe_low_power_mode<F>(handler: F) -> NotificationObserver
+    where
+        F: Fn(bool) + 'static
+    {
+        let handler = Rc::new(handler);
+
+        NotificationObserver::new(default_center(), &[POWER_STATE_DID_CHANGE], move |_, _| {
+            handler(ProcessInfo::is_low_power_mode_enabled());
+        })
+    }
+
+    /// Tells the system you're starting some work, so that it doesn't throttle the process via
+    /// App Nap (or, depending on `options`, put the system or display to sleep) until it's done.
+    /// `reason` is shown in diagnostic tools.
+    ///
+    /// The activity lasts until the returned `ActivityToken` is dropped.
+    pub fn begin_activity(options: &[ActivityOption], reason: &str) -> ActivityToken {
+        let options = options.iter().fold(0, |opts, option| opts | u64::from(option));
+        let reason = NSString::new(reason);
+
+        ActivityToken {
+            token: unsafe {
+                let token: id = msg_send![process_info(), beginActivityWithOptions:options reason:reason];
+                ShareId::from_ptr(token)
+            }
+        }
+    }
+}
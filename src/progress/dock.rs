@@ -0,0 +1,95 @@
+//! Shows a `Progress` on the app's Dock icon, as a bar along the bottom of it - like Finder does
+//! for long copies.
+
+use std::fmt;
+
+use core_graphics::geometry::{CGPoint, CGRect, CGSize};
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::dispatch::assert_main_thread;
+use crate::foundation::{id, nil, NO, NSUInteger};
+use crate::kvo::ObservationToken;
+use crate::progress::{Progress, ProgressIndicatorStyle};
+
+/// Keeps a `Progress` showing on the Dock icon. The icon goes back to normal when this drops.
+pub struct DockProgress {
+    indicator: ShareId<Object>,
+    token: Option<ObservationToken>
+}
+
+impl fmt::Debug for DockProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DockProgress")
+            .field("indicator", &self.indicator)
+            .finish()
+    }
+}
+
+impl Progress {
+    /// Shows this progress as a bar on the app's Dock icon, for as long as the returned
+    /// `DockProgress` is held. Only one can show at a time; the last one wins.
+    pub fn show_in_dock(&self) -> DockProgress {
+        assert_main_thread("Progress::show_in_dock");
+
+        let (dock_tile, indicator) = unsafe {
+            let app: id = msg_send![class!(NSApplication), sharedApplication];
+            let dock_tile: id = msg_send![app, dockTile];
+            let size: CGSize = msg_send![dock_tile, size];
+
+            let icon: id = msg_send![app, applicationIconImage];
+            let frame = CGRect::new(&CGPoint::new(0., 0.), &size);
+            let view: id = msg_send![class!(NSImageView), alloc];
+            let view: id = msg_send![view, initWithFrame:frame];
+            let _: () = msg_send![view, setImage:icon];
+
+            let frame = CGRect::new(&CGPoint::new(0., 0.), &CGSize::new(size.width, size.height / 6.));
+            let indicator: id = msg_send![class!(NSProgressIndicator), alloc];
+            let indicator: id = msg_send![indicator, initWithFrame:frame];
+            let style: NSUInteger = ProgressIndicatorStyle::Bar.into();
+            let _: () = msg_send![indicator, setStyle:style];
+            let _: () = msg_send![indicator, setIndeterminate:NO];
+            let _: () = msg_send![indicator, setMinValue:0.0f64];
+            let _: () = msg_send![indicator, setMaxValue:1.0f64];
+            let _: () = msg_send![view, addSubview:indicator];
+
+            let _: () = msg_send![dock_tile, setContentView:view];
+            let _: () = msg_send![view, release];
+
+            (dock_tile as usize, ShareId::from_retained_ptr(indicator))
+        };
+
+        let bar = indicator.clone();
+        let token = self.observe_fraction_completed(move |fraction| unsafe {
+            let _: () = msg_send![&*bar, setDoubleValue:fraction];
+            let _: () = msg_send![dock_tile as id, display];
+        });
+
+        DockProgress {
+            indicator: indicator,
+            token: Some(token)
+        }
+    }
+}
+
+impl Drop for DockProgress {
+    /// Stops observing, and puts the Dock icon back - unless another `DockProgress` has taken
+    /// over since.
+    fn drop(&mut self) {
+        self.token.take();
+
+        unsafe {
+            let app: id = msg_send![class!(NSApplication), sharedApplication];
+            let dock_tile: id = msg_send![app, dockTile];
+            let view: id = msg_send![dock_tile, contentView];
+            let superview: id = msg_send![&*self.indicator, superview];
+
+            if view != nil && view == superview {
+                let _: () = msg_send![dock_tile, setContentView:nil];
+                let _: () = msg_send![dock_tile, display];
+            }
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use std::sync::Once;
 
-use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel, BOOL};
 use objc::{class, sel, sel_impl};
+use crate::utils::load_or_register_class;
 
 /// Injects an `NSView` subclass. This is used for the default views that don't use delegates - we
 /// have separate classes here since we don't want to waste cycles on methods that will never be
@@ -13,8 +13,7 @@ pub(crate) fn register_progress_indicator_class() -> *const Class {
 
     INIT.call_once(|| unsafe {
         let superclass = class!(NSProgressIndicator);
-        let decl = ClassDecl::new("RSTProgressIndicator", superclass).unwrap();
-        VIEW_CLASS = decl.register();
+        VIEW_CLASS = load_or_register_class(superclass, "RSTProgressIndicator", |_| {});
     });
 
     unsafe { VIEW_CLASS }
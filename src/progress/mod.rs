@@ -2,8 +2,10 @@ use objc_id::ShareId;
 use objc::runtime::{Class, Object};
 use objc::{msg_send, sel, sel_impl};
 
+use crate::dispatch::{assert_main_thread, MainThreadHandle};
 use crate::foundation::{id, nil, YES, NO, NSUInteger};
 use crate::color::Color;
+use crate::kvo::ObservationToken;
 use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
 
 #[cfg(target_os = "macos")]
@@ -21,7 +23,16 @@ use ios::{register_progress_indicator_class};
 mod enums;
 pub use enums::ProgressIndicatorStyle;
 
-#[derive(Debug)]
+mod reporting;
+pub use reporting::Progress;
+
+#[cfg(target_os = "macos")]
+mod dock;
+
+#[cfg(target_os = "macos")]
+pub use dock::DockProgress;
+
+#[derive(Clone, Debug)]
 pub struct ProgressIndicator {
     /// A pointer to the Objective-C runtime view controller.
     pub objc: ShareId<Object>,
@@ -60,6 +71,8 @@ impl Default for ProgressIndicator {
 impl ProgressIndicator {
     /// Returns a default `ProgressIndicator`, suitable for 
     pub fn new() -> Self {
+        assert_main_thread("ProgressIndicator::new");
+
         let view = unsafe {
             let view: id = msg_send![register_progress_indicator_class(), new];
             let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints:NO];
@@ -82,9 +95,34 @@ impl ProgressIndicator {
             objc: unsafe { ShareId::from_ptr(view) },
         }
     }
+
+    /// Builds a `ProgressIndicator` around `objc`.
+    fn wrap(objc: ShareId<Object>) -> Self {
+        let view = &*objc;
+
+        ProgressIndicator {
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            objc: objc
+        }
+    }
 }
 
+impl_objc_access!(ProgressIndicator);
+
 impl ProgressIndicator {
+    /// Returns a handle to this indicator that can be sent to other threads, and used to update
+    /// it from there; see `MainThreadHandle`.
+    pub fn handle(&self) -> ProgressIndicatorHandle {
+        MainThreadHandle::new(self.clone())
+    }
+
     /// Call this to set the background color for the backing layer.
     pub fn set_background_color(&self, color: Color) {
         let bg = color.into_platform_specific_color();
@@ -122,6 +160,36 @@ impl ProgressIndicator {
         }
     }
 
+    /// Sets the indicator's value. On macOS, this is within the indicator's range (`0.0` to
+    /// `100.0`, unless it's been changed); on iOS, it runs from `0.0` to `1.0`.
+    pub fn set_value(&self, value: f64) {
+        unsafe {
+            #[cfg(target_os = "macos")]
+            let _: () = msg_send![&*self.objc, setDoubleValue:value];
+
+            #[cfg(target_os = "ios")]
+            let _: () = msg_send![&*self.objc, setProgress:value as f32 animated:YES];
+        }
+    }
+
+    /// Makes this indicator follow `progress`, for as long as the returned token is held.
+    pub fn bind(&self, progress: &Progress) -> ObservationToken {
+        self.set_indeterminate(false);
+
+        let indicator = self.clone();
+
+        progress.observe_fraction_completed(move |fraction| {
+            #[cfg(target_os = "macos")]
+            let fraction = unsafe {
+                let min: f64 = msg_send![&*indicator.objc, minValue];
+                let max: f64 = msg_send![&*indicator.objc, maxValue];
+                min + (max - min) * fraction
+            };
+
+            indicator.set_value(fraction);
+        })
+    }
+
     pub fn set_indeterminate(&self, is_indeterminate: bool) {
         unsafe {
             let _: () = msg_send![&*self.objc, setIndeterminate:match is_indeterminate {
@@ -164,3 +232,14 @@ impl Drop for ProgressIndicator {
         }*/
     }
 }
+
+/// A `ProgressIndicator` handle that can be sent to other threads. Updates are applied on the
+/// main thread.
+pub type ProgressIndicatorHandle = MainThreadHandle<ProgressIndicator>;
+
+impl ProgressIndicatorHandle {
+    /// Increments the indicator's value, on the main thread.
+    pub fn increment(&self, by: f64) {
+        self.update(move |indicator| indicator.increment(by));
+    }
+}
@@ -0,0 +1,245 @@
+//! Wraps `NSProgress`, which tracks how far along some piece of work is. Progress objects can be
+//! composed - a parent's progress is made up of its children's - so e.g a batch download can
+//! report overall progress while each file reports its own.
+
+use std::fmt;
+
+use block::ConcreteBlock;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, YES, NO, BOOL, NSString};
+use crate::kvo::{observe, ObservationToken, ObservingOption};
+
+/// Tracks the progress of some piece of work, in units that you pick (e.g, bytes, or files).
+///
+/// This is cheap to clone, and clones refer to the same `NSProgress`; `NSProgress` itself is
+/// thread safe, so it's fine to report from a background thread while the UI observes it.
+#[derive(Clone)]
+pub struct Progress {
+    /// The underlying `NSProgress`.
+    pub objc: ShareId<Object>
+}
+
+unsafe impl Send for Progress {}
+unsafe impl Sync for Progress {}
+
+impl Progress {
+    /// Creates a `Progress` for `total_units` units of work.
+    pub fn new(total_units: i64) -> Self {
+        Progress {
+            objc: unsafe {
+                ShareId::from_ptr(msg_send![class!(NSProgress), progressWithTotalUnitCount:total_units])
+            }
+        }
+    }
+
+    /// Creates a `Progress` for `total_units` units of work, as a child of `parent`. Once this
+    /// finishes, it counts for `pending_units` of the parent's units; until then, the parent
+    /// counts it proportionally.
+    pub fn with_parent(parent: &Progress, pending_units: i64, total_units: i64) -> Self {
+        Progress {
+            objc: unsafe {
+                ShareId::from_ptr(msg_send![class!(NSProgress), progressWithTotalUnitCount:total_units
+                    parent:&*parent.objc
+                    pendingUnitCount:pending_units
+                ])
+            }
+        }
+    }
+
+    /// Adds `child` to this progress (e.g, one handed back by a system API), counting it for
+    /// `pending_units` of this one's units.
+    pub fn add_child(&self, child: &Progress, pending_units: i64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, addChild:&*child.objc withPendingUnitCount:pending_units];
+        }
+    }
+
+    /// Sets the total number of units of work. A negative number makes this indeterminate.
+    pub fn set_total_units(&self, units: i64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTotalUnitCount:units];
+        }
+    }
+
+    /// Returns the total number of units of work.
+    pub fn total_units(&self) -> i64 {
+        unsafe { msg_send![&*self.objc, totalUnitCount] }
+    }
+
+    /// Sets the number of units of work that are done.
+    pub fn set_completed_units(&self, units: i64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setCompletedUnitCount:units];
+        }
+    }
+
+    /// Returns the number of units of work that are done.
+    pub fn completed_units(&self) -> i64 {
+        unsafe { msg_send![&*self.objc, completedUnitCount] }
+    }
+
+    /// Returns how much of the work is done, from `0.0` to `1.0` - including partial progress
+    /// from any children.
+    pub fn fraction_completed(&self) -> f64 {
+        unsafe { msg_send![&*self.objc, fractionCompleted] }
+    }
+
+    /// Returns whether the total (or completed) units aren't known.
+    pub fn is_indeterminate(&self) -> bool {
+        let indeterminate: BOOL = unsafe { msg_send![&*self.objc, isIndeterminate] };
+        indeterminate == YES
+    }
+
+    /// Returns whether all of the work is done.
+    pub fn is_finished(&self) -> bool {
+        let finished: BOOL = unsafe { msg_send![&*self.objc, isFinished] };
+        finished == YES
+    }
+
+    /// Sets the description shown for this progress (e.g, "Copying 3 files"). If you don't set
+    /// one, a default is generated from the units.
+    pub fn set_localized_description(&self, description: &str) {
+        let description = NSString::new(description);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setLocalizedDescription:description];
+        }
+    }
+
+    /// Returns the description of this progress.
+    pub fn localized_description(&self) -> String {
+        let description: id = unsafe { msg_send![&*self.objc, localizedDescription] };
+
+        match description == nil {
+            true => String::new(),
+            false => NSString::wrap(description).to_string()
+        }
+    }
+
+    /// Sets the more specific description shown for this progress (e.g, "2 of 3 - 1.2 MB/s").
+    pub fn set_localized_additional_description(&self, description: &str) {
+        let description = NSString::new(description);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setLocalizedAdditionalDescription:description];
+        }
+    }
+
+    /// Returns the more specific description of this progress.
+    pub fn localized_additional_description(&self) -> String {
+        let description: id = unsafe { msg_send![&*self.objc, localizedAdditionalDescription] };
+
+        match description == nil {
+            true => String::new(),
+            false => NSString::wrap(description).to_string()
+        }
+    }
+
+    /// Sets whether the work can be cancelled. UI showing this progress uses it to decide whether
+    /// to offer a cancel button.
+    pub fn set_cancellable(&self, cancellable: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setCancellable:match cancellable {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Cancels the work. This cancels any children too, and calls the cancellation handlers.
+    pub fn cancel(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, cancel];
+        }
+    }
+
+    /// Returns whether the work has been cancelled. Long-running work should check this
+    /// periodically, if it doesn't use a cancellation handler.
+    pub fn is_cancelled(&self) -> bool {
+        let cancelled: BOOL = unsafe { msg_send![&*self.objc, isCancelled] };
+        cancelled == YES
+    }
+
+    /// Sets a handler to be called when the work is cancelled. This can be called on any thread.
+    pub fn set_cancellation_handler<F: Fn() + Send + Sync + 'static>(&self, handler: F) {
+        let handler = ConcreteBlock::new(move || handler());
+        let handler = handler.copy();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setCancellationHandler:&*handler];
+        }
+    }
+
+    /// Sets whether the work can be paused.
+    pub fn set_pausable(&self, pausable: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setPausable:match pausable {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Pauses the work. This pauses any children too, and calls the pausing handlers.
+    pub fn pause(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, pause];
+        }
+    }
+
+    /// Resumes paused work. This resumes any children too, and calls the resuming handlers.
+    pub fn resume(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, resume];
+        }
+    }
+
+    /// Returns whether the work is paused.
+    pub fn is_paused(&self) -> bool {
+        let paused: BOOL = unsafe { msg_send![&*self.objc, isPaused] };
+        paused == YES
+    }
+
+    /// Sets a handler to be called when the work is paused. This can be called on any thread.
+    pub fn set_pausing_handler<F: Fn() + Send + Sync + 'static>(&self, handler: F) {
+        let handler = ConcreteBlock::new(move || handler());
+        let handler = handler.copy();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setPausingHandler:&*handler];
+        }
+    }
+
+    /// Sets a handler to be called when the work is resumed. This can be called on any thread.
+    pub fn set_resuming_handler<F: Fn() + Send + Sync + 'static>(&self, handler: F) {
+        let handler = ConcreteBlock::new(move || handler());
+        let handler = handler.copy();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setResumingHandler:&*handler];
+        }
+    }
+
+    /// Calls `handler` (on the main thread) with `fraction_completed()` now, and whenever it
+    /// changes after that, for as long as the returned token is held.
+    pub fn observe_fraction_completed<F: Fn(f64) + 'static>(&self, handler: F) -> ObservationToken {
+        let progress = self.clone();
+
+        observe(&*self.objc as *const Object as id, "fractionCompleted", &[ObservingOption::Initial], move |_| {
+            handler(progress.fraction_completed());
+        })
+    }
+}
+
+impl fmt::Debug for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Progress")
+            .field("completed_units", &self.completed_units())
+            .field("total_units", &self.total_units())
+            .finish()
+    }
+}
@@ -6,6 +6,7 @@ use objc::{class, msg_send, sel, sel_impl};
 use objc_id::ShareId;
 
 use crate::foundation::{id, YES, NSString, NSUInteger};
+use crate::geometry::Size;
 use crate::utils::CGSize;
 
 /// Describes the quality of the thumbnail you expect back from the 
@@ -69,7 +70,7 @@ impl From<NSUInteger> for ThumbnailQuality {
 
 #[derive(Clone, Debug)]
 pub struct ThumbnailConfig {
-    pub size: (CGFloat, CGFloat),
+    pub size: Size,
     pub scale: CGFloat,
     pub minimum_dimension: CGFloat,
     pub icon_mode: bool,
@@ -79,7 +80,7 @@ pub struct ThumbnailConfig {
 impl Default for ThumbnailConfig {
     fn default() -> Self {
         ThumbnailConfig {
-            size: (44., 44.),
+            size: Size::new(44., 44.),
             
             // #TODO: Should query the current screen size maybe? 2x is fairly safe
             // for most moderns Macs right now.
@@ -107,7 +108,7 @@ impl ThumbnailConfig {
         }
 
         unsafe {
-            let size = CGSize::new(self.size.0, self.size.1);
+            let size = CGSize::new(self.size.width, self.size.height);
             // @TODO: Check nil here, or other bad conversion
             let from_url: id = msg_send![class!(NSURL), fileURLWithPath:file.into_inner()];
 
@@ -0,0 +1,114 @@
+//! Wraps `NSScreen`, which describes the displays attached to the system - their size, the area
+//! not covered by the menu bar and Dock, scale factor, and so on.
+//!
+//! ```rust,no_run
+//! use cacao::screen::Screen;
+//!
+//! for screen in Screen::all() {
+//!     let frame = screen.visible_frame();
+//!     println!("{:?}: {}x{} @ {}x", screen.name(), frame.width, frame.height, screen.backing_scale_factor());
+//! }
+//! ```
+//!
+//! Note that screen frames are in the global screen coordinate space, where the origin is the
+//! bottom-left corner of the main screen.
+
+use core_graphics::base::CGFloat;
+use core_graphics::geometry::CGRect;
+
+use objc::runtime::{Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, NO, BOOL, NSArray, NSString};
+use crate::geometry::{EdgeInsets, Rect};
+
+/// A display attached to the system. This is cheap to clone; clones refer to the same screen.
+#[derive(Clone, Debug)]
+pub struct Screen {
+    /// The underlying `NSScreen`.
+    pub objc: ShareId<Object>
+}
+
+impl Screen {
+    /// Wraps an existing `NSScreen`.
+    pub(crate) fn with(screen: id) -> Self {
+        Screen {
+            objc: unsafe { ShareId::from_ptr(screen) }
+        }
+    }
+
+    /// Returns the screen containing the window that currently has keyboard focus. Returns `None`
+    /// if no screens are available (e.g, in a headless session).
+    pub fn main() -> Option<Self> {
+        let screen: id = unsafe { msg_send![class!(NSScreen), mainScreen] };
+
+        match screen == nil {
+            true => None,
+            false => Some(Screen::with(screen))
+        }
+    }
+
+    /// Returns all attached screens. The first screen is the one containing the menu bar.
+    pub fn all() -> Vec<Self> {
+        let screens: id = unsafe { msg_send![class!(NSScreen), screens] };
+
+        if screens == nil {
+            return vec![];
+        }
+
+        NSArray::wrap(screens).map(|screen| Screen::with(screen))
+    }
+
+    /// Returns whether the screen responds to the given selector; used for APIs that only exist
+    /// on newer versions of macOS.
+    fn responds_to(&self, selector: Sel) -> bool {
+        let responds: BOOL = unsafe { msg_send![&*self.objc, respondsToSelector:selector] };
+        responds != NO
+    }
+
+    /// Returns the full dimensions of the screen.
+    pub fn frame(&self) -> Rect {
+        let frame: CGRect = unsafe { msg_send![&*self.objc, frame] };
+        frame.into()
+    }
+
+    /// Returns the dimensions of the screen, minus the menu bar and Dock. This is generally the
+    /// area you want to position windows within.
+    pub fn visible_frame(&self) -> Rect {
+        let frame: CGRect = unsafe { msg_send![&*self.objc, visibleFrame] };
+        frame.into()
+    }
+
+    /// Returns the backing scale (e.g, `1.0` for non retina, `2.0` for retina) of this screen.
+    pub fn backing_scale_factor(&self) -> f64 {
+        let scale: CGFloat = unsafe { msg_send![&*self.objc, backingScaleFactor] };
+        scale as f64
+    }
+
+    /// Returns the localized name of the screen (e.g, `Built-in Retina Display`). Available on
+    /// macOS 10.15+; returns `None` on older versions.
+    pub fn name(&self) -> Option<String> {
+        if !self.responds_to(sel!(localizedName)) {
+            return None;
+        }
+
+        let name: id = unsafe { msg_send![&*self.objc, localizedName] };
+
+        match name == nil {
+            true => None,
+            false => Some(NSString::wrap(name).to_string())
+        }
+    }
+
+    /// Returns the area obscured by hardware (e.g, the camera housing on notched displays).
+    /// Available on macOS 12+; on older versions, or displays without a notch, this returns
+    /// zeroed insets.
+    pub fn safe_area_insets(&self) -> EdgeInsets {
+        if !self.responds_to(sel!(safeAreaInsets)) {
+            return EdgeInsets::default();
+        }
+
+        unsafe { msg_send![&*self.objc, safeAreaInsets] }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::foundation::NSInteger;
+
+/// How scrollers are drawn.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ScrollerStyle {
+    /// Scrollers that are always visible, and take up space beside the content.
+    Legacy,
+
+    /// Scrollers that float over the content, and only show while scrolling.
+    Overlay
+}
+
+impl From<ScrollerStyle> for NSInteger {
+    fn from(style: ScrollerStyle) -> Self {
+        match style {
+            ScrollerStyle::Legacy => 0,
+            ScrollerStyle::Overlay => 1
+        }
+    }
+}
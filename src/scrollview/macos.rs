@@ -9,15 +9,17 @@
 
 use std::sync::Once;
 
-use objc::declare::ClassDecl;
+use core_graphics::base::CGFloat;
+use core_graphics::geometry::CGRect;
+
 use objc::runtime::{Class, Object, Sel, BOOL};
-use objc::{class, sel, sel_impl};
+use objc::{class, msg_send, sel, sel_impl};
 use objc_id::Id;
 
 use crate::foundation::{id, YES, NO, NSUInteger};
 use crate::dragdrop::DragInfo;
 use crate::scrollview::{SCROLLVIEW_DELEGATE_PTR, ScrollViewDelegate};
-use crate::utils::load;
+use crate::utils::{load_or_register_class, load_or_register_class_for};
 
 /// Enforces normalcy, or: a needlessly cruel method in terms of the name. You get the idea though.
 extern fn enforce_normalcy(_: &Object, _: Sel) -> BOOL {
@@ -26,7 +28,7 @@ extern fn enforce_normalcy(_: &Object, _: Sel) -> BOOL {
 
 /// Called when a drag/drop operation has entered this view.
 extern fn dragging_entered<T: ScrollViewDelegate>(this: &mut Object, _: Sel, info: id) -> NSUInteger {
-    let view = load::<T>(this, SCROLLVIEW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, SCROLLVIEW_DELEGATE_PTR, 0);
     view.dragging_entered(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     }).into()
@@ -34,7 +36,7 @@ extern fn dragging_entered<T: ScrollViewDelegate>(this: &mut Object, _: Sel, inf
 
 /// Called when a drag/drop operation has entered this view.
 extern fn prepare_for_drag_operation<T: ScrollViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
-    let view = load::<T>(this, SCROLLVIEW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, SCROLLVIEW_DELEGATE_PTR, NO);
     
     match view.prepare_for_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -46,7 +48,7 @@ extern fn prepare_for_drag_operation<T: ScrollViewDelegate>(this: &mut Object, _
 
 /// Called when a drag/drop operation has entered this view.
 extern fn perform_drag_operation<T: ScrollViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
-    let view = load::<T>(this, SCROLLVIEW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, SCROLLVIEW_DELEGATE_PTR, NO);
         
     match view.perform_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -58,7 +60,7 @@ extern fn perform_drag_operation<T: ScrollViewDelegate>(this: &mut Object, _: Se
 
 /// Called when a drag/drop operation has entered this view.
 extern fn conclude_drag_operation<T: ScrollViewDelegate>(this: &mut Object, _: Sel, info: id) {
-    let view = load::<T>(this, SCROLLVIEW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, SCROLLVIEW_DELEGATE_PTR);
     
     view.conclude_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -67,13 +69,46 @@ extern fn conclude_drag_operation<T: ScrollViewDelegate>(this: &mut Object, _: S
 
 /// Called when a drag/drop operation has entered this view.
 extern fn dragging_exited<T: ScrollViewDelegate>(this: &mut Object, _: Sel, info: id) {
-    let view = load::<T>(this, SCROLLVIEW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, SCROLLVIEW_DELEGATE_PTR);
         
     view.dragging_exited(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     });
 }
 
+/// Called whenever the clip view scrolls - by the user, or programmatically. We pass this on to
+/// `NSScrollView` first, so that the scrollers are updated before the delegate hears about it.
+extern fn reflect_scrolled_clip_view<T: ScrollViewDelegate>(this: &mut Object, _: Sel, clip_view: id) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSScrollView)), reflectScrolledClipView:clip_view];
+    }
+
+    let view = load_or_return!(T, this, SCROLLVIEW_DELEGATE_PTR);
+    let bounds: CGRect = unsafe { msg_send![clip_view, bounds] };
+    view.did_scroll(bounds.origin.into());
+}
+
+/// Called (via `NSScrollViewWillStartLiveScrollNotification`) when the user starts scrolling.
+extern fn will_start_live_scroll<T: ScrollViewDelegate>(this: &mut Object, _: Sel, _notification: id) {
+    let view = load_or_return!(T, this, SCROLLVIEW_DELEGATE_PTR);
+    view.will_start_live_scroll();
+}
+
+/// Called (via `NSScrollViewDidEndLiveScrollNotification`) when the user stops scrolling, once any
+/// momentum has run out.
+extern fn did_end_live_scroll<T: ScrollViewDelegate>(this: &mut Object, _: Sel, _notification: id) {
+    let view = load_or_return!(T, this, SCROLLVIEW_DELEGATE_PTR);
+    view.did_end_live_scroll();
+}
+
+/// Called (via `NSScrollViewDidEndLiveMagnifyNotification`) when the user has finished magnifying
+/// (e.g, pinching).
+extern fn did_end_live_magnify<T: ScrollViewDelegate>(this: &mut Object, _: Sel, _notification: id) {
+    let view = load_or_return!(T, this, SCROLLVIEW_DELEGATE_PTR);
+    let level: CGFloat = unsafe { msg_send![this, magnification] };
+    view.did_magnify(level as f64);
+}
+
 /// Injects an `NSScrollView` subclass. 
 pub(crate) fn register_scrollview_class() -> *const Class {
     static mut VIEW_CLASS: *const Class = 0 as *const Class;
@@ -81,8 +116,7 @@ pub(crate) fn register_scrollview_class() -> *const Class {
 
     INIT.call_once(|| unsafe {
         let superclass = class!(NSScrollView);
-        let mut decl = ClassDecl::new("RSTScrollView", superclass).unwrap();
-        VIEW_CLASS = decl.register();
+        VIEW_CLASS = load_or_register_class(superclass, "RSTScrollView", |_| {});
     });
 
     unsafe { VIEW_CLASS }
@@ -90,31 +124,28 @@ pub(crate) fn register_scrollview_class() -> *const Class {
 
 /// Injects an `NSView` subclass, with some callback and pointer ivars for what we
 /// need to do.
-pub(crate) fn register_scrollview_class_with_delegate<T: ScrollViewDelegate>() -> *const Class {
-    static mut VIEW_CLASS: *const Class = 0 as *const Class;
-    static INIT: Once = Once::new();
-
-    INIT.call_once(|| unsafe {
-        let superclass = class!(NSScrollView);
-        let mut decl = ClassDecl::new("RSTScrollViewWithDelegate", superclass).unwrap();
-
-        // A pointer to the "view controller" on the Rust side. It's expected that this doesn't
-        // move.
-        decl.add_ivar::<usize>(SCROLLVIEW_DELEGATE_PTR);
-        
-        decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
-
-        // Drag and drop operations (e.g, accepting files)
-        decl.add_method(sel!(draggingEntered:), dragging_entered::<T> as extern fn (&mut Object, _, _) -> NSUInteger);
-        decl.add_method(sel!(prepareForDragOperation:), prepare_for_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
-        decl.add_method(sel!(performDragOperation:), perform_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
-        decl.add_method(sel!(concludeDragOperation:), conclude_drag_operation::<T> as extern fn (&mut Object, _, _));
-        decl.add_method(sel!(draggingExited:), dragging_exited::<T> as extern fn (&mut Object, _, _));
-        
-        VIEW_CLASS = decl.register();
-    });
-
+pub(crate) fn register_scrollview_class_with_delegate<T: ScrollViewDelegate + 'static>() -> *const Class {
     unsafe {
-        VIEW_CLASS
+        let superclass = class!(NSScrollView);
+        load_or_register_class_for::<T, _>(superclass, "RSTScrollViewWithDelegate", |decl| {
+            // A pointer to the "view controller" on the Rust side. It's expected that this doesn't
+            // move.
+            decl.add_ivar::<usize>(SCROLLVIEW_DELEGATE_PTR);
+
+            decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
+
+            // Drag and drop operations (e.g, accepting files)
+            decl.add_method(sel!(draggingEntered:), dragging_entered::<T> as extern fn (&mut Object, _, _) -> NSUInteger);
+            decl.add_method(sel!(prepareForDragOperation:), prepare_for_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
+            decl.add_method(sel!(performDragOperation:), perform_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
+            decl.add_method(sel!(concludeDragOperation:), conclude_drag_operation::<T> as extern fn (&mut Object, _, _));
+            decl.add_method(sel!(draggingExited:), dragging_exited::<T> as extern fn (&mut Object, _, _));
+
+            // Scrolling
+            decl.add_method(sel!(reflectScrolledClipView:), reflect_scrolled_clip_view::<T> as extern fn (&mut Object, _, _));
+            decl.add_method(sel!(rstWillStartLiveScroll:), will_start_live_scroll::<T> as extern fn (&mut Object, _, _));
+            decl.add_method(sel!(rstDidEndLiveScroll:), did_end_live_scroll::<T> as extern fn (&mut Object, _, _));
+            decl.add_method(sel!(rstDidEndLiveMagnify:), did_end_live_magnify::<T> as extern fn (&mut Object, _, _));
+        })
     }
 }
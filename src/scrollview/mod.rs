@@ -41,14 +41,20 @@
 //!
 //! For more information on Autolayout, view the module or check out the examples folder.
 
+use core_graphics::base::CGFloat;
+use core_graphics::geometry::{CGPoint, CGRect};
+
 use objc_id::ShareId;
-use objc::runtime::{Class, Object};
-use objc::{msg_send, sel, sel_impl};
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::{id, nil, YES, NO, NSArray, NSString};
+use crate::dispatch::{assert_main_thread, MainThreadHandle};
+use crate::foundation::{id, nil, YES, NO, BOOL, NSArray, NSInteger, NSString};
 use crate::color::Color;
+use crate::geometry::{EdgeInsets, Point, Rect};
 use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
 use crate::pasteboard::PasteboardType;
+use crate::utils::clear_ptr;
 
 #[cfg(target_os = "macos")]
 mod macos;
@@ -62,11 +68,21 @@ mod ios;
 #[cfg(target_os = "ios")]
 use ios::{register_view_class, register_view_class_with_delegate};
 
+mod enums;
+pub use enums::ScrollerStyle;
+
 mod traits;
 pub use traits::ScrollViewDelegate;
 
 pub(crate) static SCROLLVIEW_DELEGATE_PTR: &str = "rstScrollViewDelegatePtr";
 
+#[cfg(target_os = "macos")]
+static LIVE_SCROLL_NOTIFICATIONS: [(&str, &str); 3] = [
+    ("NSScrollViewWillStartLiveScrollNotification", "rstWillStartLiveScroll:"),
+    ("NSScrollViewDidEndLiveScrollNotification", "rstDidEndLiveScroll:"),
+    ("NSScrollViewDidEndLiveMagnifyNotification", "rstDidEndLiveMagnify:")
+];
+
 /// A helper method for instantiating view classes and applying default settings to them.
 fn allocate_view(registration_fn: fn() -> *const Class) -> id { 
     unsafe {
@@ -129,6 +145,8 @@ impl Default for ScrollView {
 impl ScrollView {
     /// Returns a default `View`, suitable for 
     pub fn new() -> Self {
+        assert_main_thread("ScrollView::new");
+
         let view = allocate_view(register_scrollview_class);
 
         ScrollView {
@@ -146,10 +164,34 @@ impl ScrollView {
     }
 }
 
+impl ScrollView {
+    /// Builds a `ScrollView` around `objc`, with no delegate.
+    fn wrap(objc: ShareId<Object>) -> Self {
+        let view = &*objc;
+
+        ScrollView {
+            delegate: None,
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            objc: objc
+        }
+    }
+}
+
+impl_objc_access!(ScrollView<T>, SCROLLVIEW_DELEGATE_PTR);
+
 impl<T> ScrollView<T> where T: ScrollViewDelegate + 'static {
     /// Initializes a new View with a given `ViewDelegate`. This enables you to respond to events
     /// and customize the view as a module, similar to class-based systems.
     pub fn with(delegate: T) -> ScrollView<T> {
+        assert_main_thread("ScrollView::with");
+
         let mut delegate = Box::new(delegate);
         
         let view = allocate_view(register_scrollview_class_with_delegate::<T>);
@@ -158,6 +200,20 @@ impl<T> ScrollView<T> where T: ScrollViewDelegate + 'static {
             //let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints:NO];
             let ptr: *const T = &*delegate;
             (&mut *view).set_ivar(SCROLLVIEW_DELEGATE_PTR, ptr as usize);
+
+            // Live scrolling (and magnifying) is only reported via notifications, which the view observes itself.
+            #[cfg(target_os = "macos")]
+            {
+                let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+
+                for (name, selector) in LIVE_SCROLL_NOTIFICATIONS.iter() {
+                    let name = NSString::new(name);
+                    let _: () = msg_send![center, addObserver:view
+                        selector:Sel::register(selector)
+                        name:name
+                        object:view];
+                }
+            }
         };
 
         let mut view = ScrollView {
@@ -199,6 +255,209 @@ impl<T> ScrollView<T> {
         }
     }
 
+    /// Returns a handle to this scroll view that can be sent to other threads, and used to update it
+    /// from there; see `MainThreadHandle`.
+    pub fn handle(&self) -> ScrollViewHandle {
+        MainThreadHandle::new(self.clone_as_handle())
+    }
+
+    /// Scrolls so that `point` of the content is at the top-left of what's visible.
+    pub fn scroll_to(&self, point: Point) {
+        let point: CGPoint = point.into();
+
+        unsafe {
+            #[cfg(target_os = "macos")]
+            {
+                let clip_view: id = msg_send![&*self.objc, contentView];
+                let _: () = msg_send![clip_view, scrollToPoint:point];
+                let _: () = msg_send![&*self.objc, reflectScrolledClipView:clip_view];
+            }
+
+            #[cfg(target_os = "ios")]
+            let _: () = msg_send![&*self.objc, setContentOffset:point animated:NO];
+        }
+    }
+
+    /// Scrolls the minimum amount needed to make `rect` (in the content's coordinates) visible.
+    pub fn scroll_to_visible(&self, rect: Rect) {
+        let rect: CGRect = rect.into();
+
+        unsafe {
+            #[cfg(target_os = "macos")]
+            {
+                let document: id = msg_send![&*self.objc, documentView];
+                let _: BOOL = msg_send![document, scrollRectToVisible:rect];
+            }
+
+            #[cfg(target_os = "ios")]
+            let _: () = msg_send![&*self.objc, scrollRectToVisible:rect animated:NO];
+        }
+    }
+
+    /// Returns the offset of the top-left of what's visible, from the top-left of the content.
+    pub fn content_offset(&self) -> Point {
+        #[cfg(target_os = "macos")]
+        let offset: CGPoint = unsafe {
+            let clip_view: id = msg_send![&*self.objc, contentView];
+            let bounds: CGRect = msg_send![clip_view, bounds];
+            bounds.origin
+        };
+
+        #[cfg(target_os = "ios")]
+        let offset: CGPoint = unsafe { msg_send![&*self.objc, contentOffset] };
+
+        offset.into()
+    }
+
+    /// Sets how far the content is inset from each edge - e.g, to keep it clear of a toolbar that
+    /// floats over the top. On macOS, this turns off any automatic adjustment.
+    pub fn set_content_insets(&self, insets: EdgeInsets) {
+        unsafe {
+            #[cfg(target_os = "macos")]
+            {
+                let _: () = msg_send![&*self.objc, setAutomaticallyAdjustsContentInsets:NO];
+                let _: () = msg_send![&*self.objc, setContentInsets:insets];
+            }
+
+            #[cfg(target_os = "ios")]
+            let _: () = msg_send![&*self.objc, setContentInset:insets];
+        }
+    }
+
+    /// Sets whether the vertical scroller (scroll indicator, on iOS) is shown.
+    pub fn set_has_vertical_scroller(&self, has_scroller: bool) {
+        let has_scroller = match has_scroller {
+            true => YES,
+            false => NO
+        };
+
+        unsafe {
+            #[cfg(target_os = "macos")]
+            let _: () = msg_send![&*self.objc, setHasVerticalScroller:has_scroller];
+
+            #[cfg(target_os = "ios")]
+            let _: () = msg_send![&*self.objc, setShowsVerticalScrollIndicator:has_scroller];
+        }
+    }
+
+    /// Sets whether the horizontal scroller (scroll indicator, on iOS) is shown.
+    pub fn set_has_horizontal_scroller(&self, has_scroller: bool) {
+        let has_scroller = match has_scroller {
+            true => YES,
+            false => NO
+        };
+
+        unsafe {
+            #[cfg(target_os = "macos")]
+            let _: () = msg_send![&*self.objc, setHasHorizontalScroller:has_scroller];
+
+            #[cfg(target_os = "ios")]
+            let _: () = msg_send![&*self.objc, setShowsHorizontalScrollIndicator:has_scroller];
+        }
+    }
+
+    /// Sets whether the scrollers hide when the content fits, and so there's nothing to scroll.
+    #[cfg(target_os = "macos")]
+    pub fn set_autohides_scrollers(&self, autohides: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAutohidesScrollers:match autohides {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets how the scrollers are drawn. By default, this follows the user's preference (from
+    /// System Preferences).
+    #[cfg(target_os = "macos")]
+    pub fn set_scroller_style(&self, style: ScrollerStyle) {
+        let style: NSInteger = style.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setScrollerStyle:style];
+        }
+    }
+
+    /// Sets whether the user can magnify (zoom) the content, e.g by pinching on a trackpad. Off by
+    /// default.
+    ///
+    /// On iOS, zooming is instead enabled by giving `set_magnification_range()` a range.
+    #[cfg(target_os = "macos")]
+    pub fn set_allows_magnification(&self, allows: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAllowsMagnification:match allows {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets how far the content can be magnified, where `1.0` is its actual size - e.g,
+    /// `(0.25, 4.0)` allows zooming out to a quarter, or in to four times, the size.
+    pub fn set_magnification_range(&self, min: f64, max: f64) {
+        unsafe {
+            #[cfg(target_os = "macos")]
+            {
+                let _: () = msg_send![&*self.objc, setMinMagnification:min as CGFloat];
+                let _: () = msg_send![&*self.objc, setMaxMagnification:max as CGFloat];
+            }
+
+            #[cfg(target_os = "ios")]
+            {
+                let _: () = msg_send![&*self.objc, setMinimumZoomScale:min as CGFloat];
+                let _: () = msg_send![&*self.objc, setMaximumZoomScale:max as CGFloat];
+            }
+        }
+    }
+
+    /// Magnifies the content to `level` (clamped to the range set with
+    /// `set_magnification_range()`). If `centered_at` is given, that point of the content stays
+    /// put on screen; otherwise, the center of what's visible does.
+    pub fn set_magnification(&self, level: f64, centered_at: Option<Point>) {
+        let level = level as CGFloat;
+
+        unsafe {
+            #[cfg(target_os = "macos")]
+            match centered_at {
+                Some(point) => {
+                    let point: CGPoint = point.into();
+                    let _: () = msg_send![&*self.objc, setMagnification:level centeredAtPoint:point];
+                },
+
+                None => {
+                    let _: () = msg_send![&*self.objc, setMagnification:level];
+                }
+            }
+
+            #[cfg(target_os = "ios")]
+            match centered_at {
+                Some(point) => {
+                    let bounds: CGRect = msg_send![&*self.objc, bounds];
+                    let width = bounds.size.width / level;
+                    let height = bounds.size.height / level;
+                    let rect: CGRect = Rect::new(point.y - height / 2., point.x - width / 2., width, height).into();
+
+                    let _: () = msg_send![&*self.objc, zoomToRect:rect animated:NO];
+                },
+
+                None => {
+                    let _: () = msg_send![&*self.objc, setZoomScale:level animated:NO];
+                }
+            }
+        }
+    }
+
+    /// Returns the current magnification, where `1.0` is the content's actual size.
+    pub fn magnification(&self) -> f64 {
+        #[cfg(target_os = "macos")]
+        let level: CGFloat = unsafe { msg_send![&*self.objc, magnification] };
+
+        #[cfg(target_os = "ios")]
+        let level: CGFloat = unsafe { msg_send![&*self.objc, zoomScale] };
+
+        level as f64
+    }
+
     /// Call this to set the background color for the backing layer.
     pub fn set_background_color(&self, color: Color) {
         let bg = color.into_platform_specific_color();
@@ -231,9 +490,18 @@ impl<T> Drop for ScrollView<T> {
     /// this has a superview (i.e, it's in the heirarchy) on the AppKit side. If it does, we go
     /// ahead and remove it - this is intended to match the semantics of how Rust handles things).
     ///
-    /// There are, thankfully, no delegates we need to break here.
+    /// The delegate pointer on the Objective-C side is cleared too, so any callbacks that arrive
+    /// after this (e.g, if something else is still holding on to the view) are ignored.
     fn drop(&mut self) {
         if self.delegate.is_some() {
+            clear_ptr(&self.objc, SCROLLVIEW_DELEGATE_PTR);
+
+            #[cfg(target_os = "macos")]
+            unsafe {
+                let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+                let _: () = msg_send![center, removeObserver:&*self.objc];
+            }
+
             unsafe {
                 let superview: id = msg_send![&*self.objc, superview];
                 if superview != nil {
@@ -243,3 +511,6 @@ impl<T> Drop for ScrollView<T> {
         }
     }
 }
+
+/// A `ScrollView` handle that can be sent to other threads. Updates are applied on the main thread.
+pub type ScrollViewHandle = MainThreadHandle<ScrollView>;
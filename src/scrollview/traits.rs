@@ -1,4 +1,5 @@
 use crate::dragdrop::{DragInfo, DragOperation};
+use crate::geometry::Point;
 use crate::scrollview::ScrollView;
 
 pub trait ScrollViewDelegate {
@@ -19,6 +20,21 @@ pub trait ScrollViewDelegate {
     /// Called when this has been removed from the view heirarchy.
     fn did_disappear(&self, _animated: bool) {}
 
+    /// Called whenever the visible content moves - by the user scrolling, or by you (e.g, via
+    /// `ScrollView::scroll_to()`). `offset` is the offset of the top-left of what's visible, from
+    /// the top-left of the content.
+    fn did_scroll(&self, _offset: Point) {}
+
+    /// Called when the user starts scrolling (e.g, puts two fingers on the trackpad).
+    fn will_start_live_scroll(&self) {}
+
+    /// Called when the user has finished scrolling, including any momentum scrolling afterwards.
+    fn did_end_live_scroll(&self) {}
+
+    /// Called when the user has finished magnifying the content (e.g, pinching on a trackpad), with
+    /// the magnification it ended at. Only on macOS, for now.
+    fn did_magnify(&self, _magnification: f64) {}
+
     /// Invoked when the dragged image enters destination bounds or frame; returns dragging operation to perform.
     fn dragging_entered(&self, _info: DragInfo) -> DragOperation { DragOperation::None }
     
@@ -0,0 +1,39 @@
+//! Handles presenting a `UIActivityViewController`.
+
+use block::ConcreteBlock;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, BOOL, YES, NSArray, NSString};
+use crate::sharing::ShareHandler;
+
+/// Creates a `UIActivityViewController` for the given items, and presents it from the view
+/// controller that owns `view`'s window. On iPad, it's shown as a popover anchored to `view`.
+pub(crate) fn present(items: NSArray, view: &Object, handler: ShareHandler) -> ShareId<Object> {
+    let block = ConcreteBlock::new(move |activity_type: id, completed: BOOL, _: id, _: id| {
+        (handler.0)(match completed == YES && activity_type != nil {
+            true => Some(NSString::wrap(activity_type).to_string()),
+            false => None
+        });
+    });
+    let block = block.copy();
+
+    unsafe {
+        let alloc: id = msg_send![class!(UIActivityViewController), alloc];
+        let controller: id = msg_send![alloc, initWithActivityItems:items.into_inner() applicationActivities:nil];
+        let _: () = msg_send![controller, setCompletionWithItemsHandler:block];
+
+        let popover: id = msg_send![controller, popoverPresentationController];
+        if popover != nil {
+            let _: () = msg_send![popover, setSourceView:view];
+        }
+
+        let window: id = msg_send![view, window];
+        let root: id = msg_send![window, rootViewController];
+        let _: () = msg_send![root, presentViewController:controller animated:YES completion:nil];
+
+        ShareId::from_retained_ptr(controller)
+    }
+}
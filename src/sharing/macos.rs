@@ -0,0 +1,59 @@
+//! Registers the delegate class used for `NSSharingServicePicker`, and handles presenting it.
+
+use std::sync::Once;
+
+use core_graphics::geometry::CGRect;
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, NSArray, NSString, NSUInteger};
+use crate::sharing::{SHARE_HANDLER_PTR, ShareHandler};
+use crate::utils::load_or_register_class;
+
+/// Corresponds to `NSRectEdgeMinY`, which (in a flipped view) shows the picker below the view.
+const PREFERRED_EDGE: NSUInteger = 1;
+
+/// Called when the user has chosen a sharing service (or dismissed the picker, in which case
+/// `service` is `nil`).
+extern fn did_choose_sharing_service(this: &Object, _: Sel, _: id, service: id) {
+    let handler = load_or_return!(ShareHandler, this, SHARE_HANDLER_PTR);
+
+    (handler.0)(match service == nil {
+        true => None,
+        false => Some(NSString::wrap(unsafe { msg_send![service, title] }).to_string())
+    });
+}
+
+/// Registers an `NSObject` subclass that acts as an `NSSharingServicePickerDelegate`.
+pub(crate) fn register_share_delegate_class() -> *const Class {
+    static mut DELEGATE_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSObject);
+
+        DELEGATE_CLASS = load_or_register_class(superclass, "RSTSharingServicePickerDelegate", |decl| {
+            decl.add_ivar::<usize>(SHARE_HANDLER_PTR);
+            decl.add_method(sel!(sharingServicePicker:didChooseSharingService:), did_choose_sharing_service as extern fn(&Object, _, _, _));
+        });
+    });
+
+    unsafe { DELEGATE_CLASS }
+}
+
+/// Creates an `NSSharingServicePicker` for the given items, and shows it relative to `view`.
+/// Returns the picker, which the caller should retain while it's showing.
+pub(crate) fn present(items: NSArray, view: &Object, delegate: &Object) -> ShareId<Object> {
+    unsafe {
+        let alloc: id = msg_send![class!(NSSharingServicePicker), alloc];
+        let picker: id = msg_send![alloc, initWithItems:items.into_inner()];
+        let _: () = msg_send![picker, setDelegate:delegate];
+
+        let bounds: CGRect = msg_send![view, bounds];
+        let _: () = msg_send![picker, showRelativeToRect:bounds ofView:view preferredEdge:PREFERRED_EDGE];
+
+        ShareId::from_retained_ptr(picker)
+    }
+}
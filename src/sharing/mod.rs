@@ -0,0 +1,173 @@
+//! Implements a share sheet, which lets the user send content to other apps and services (Mail,
+//! Messages, AirDrop, and so on). On macOS this wraps `NSSharingServicePicker`; on iOS, it wraps
+//! `UIActivityViewController`.
+//!
+//! ```rust,no_run
+//! use cacao::button::Button;
+//! use cacao::sharing::{ShareItem, ShareSheet};
+//!
+//! # fn example(button: &Button) {
+//! let mut sheet = ShareSheet::new(vec![
+//!     ShareItem::Text("Check this out!".into()),
+//!     ShareItem::Url(url::Url::parse("https://example.com").unwrap())
+//! ]);
+//!
+//! sheet.show(button, |service| {
+//!     println!("Shared via: {:?}", service);
+//! });
+//! # }
+//! ```
+
+use std::fmt;
+use std::path::PathBuf;
+
+use objc::{class, msg_send, sel, sel_impl};
+use objc::runtime::Object;
+use objc_id::ShareId;
+use url::Url;
+
+use crate::foundation::{id, nil, NSArray, NSString};
+use crate::image::Image;
+use crate::layout::Layout;
+
+#[cfg(target_os = "macos")]
+mod macos;
+
+#[cfg(target_os = "ios")]
+mod ios;
+
+pub(crate) static SHARE_HANDLER_PTR: &str = "rstShareHandlerPtr";
+
+/// Holds the completion callback for a `ShareSheet`. This exists to give us a concrete (sized)
+/// type to point to from the Objective-C side.
+pub(crate) struct ShareHandler(pub Box<dyn Fn(Option<String>) + 'static>);
+
+/// Represents an item that can be shared.
+#[derive(Clone, Debug)]
+pub enum ShareItem {
+    /// Plain text.
+    Text(String),
+
+    /// A URL (e.g, a link to a web page).
+    Url(Url),
+
+    /// An image.
+    Image(Image),
+
+    /// A file on disk.
+    File(PathBuf)
+}
+
+impl From<&ShareItem> for id {
+    fn from(item: &ShareItem) -> Self {
+        match item {
+            ShareItem::Text(text) => NSString::new(text).into_inner(),
+
+            ShareItem::Url(url) => unsafe {
+                let url = NSString::new(url.as_str());
+                msg_send![class!(NSURL), URLWithString:url]
+            },
+
+            ShareItem::Image(image) => unsafe {
+                msg_send![&*image.0, self]
+            },
+
+            ShareItem::File(path) => unsafe {
+                let path = NSString::new(&path.to_string_lossy());
+                msg_send![class!(NSURL), fileURLWithPath:path]
+            }
+        }
+    }
+}
+
+/// A share sheet for a set of items. Keep this around while the sheet is showing, as it retains
+/// the underlying picker and your completion handler.
+pub struct ShareSheet {
+    /// The items to share.
+    pub items: Vec<ShareItem>,
+
+    /// The underlying picker (`NSSharingServicePicker` or `UIActivityViewController`), once shown.
+    pub objc: Option<ShareId<Object>>,
+
+    delegate: Option<ShareId<Object>>,
+    handler: Option<Box<ShareHandler>>
+}
+
+impl ShareSheet {
+    /// Creates a new share sheet for the given items.
+    pub fn new(items: Vec<ShareItem>) -> Self {
+        ShareSheet {
+            items: items,
+            objc: None,
+            delegate: None,
+            handler: None
+        }
+    }
+
+    /// Shows the share sheet relative to the given view (e.g, the button the user clicked).
+    /// `completion` is called with the name of the service the user chose, or `None` if they
+    /// dismissed the sheet.
+    ///
+    /// On macOS, the name is the service title (e.g, "Mail"); on iOS, it's the activity type
+    /// (e.g, `com.apple.UIKit.activity.Mail`).
+    #[cfg(target_os = "macos")]
+    pub fn show<L, F>(&mut self, view: &L, completion: F)
+    where
+        L: Layout,
+        F: Fn(Option<String>) + 'static
+    {
+        let items: NSArray = self.items.iter().map(|item| item.into()).collect::<Vec<id>>().into();
+        let handler = Box::new(ShareHandler(Box::new(completion)));
+        let backing_node = view.get_backing_node();
+
+        let delegate = unsafe {
+            let delegate: id = msg_send![macos::register_share_delegate_class(), new];
+            let ptr: *const ShareHandler = &*handler;
+            (&mut *delegate).set_ivar(SHARE_HANDLER_PTR, ptr as usize);
+            ShareId::from_retained_ptr(delegate)
+        };
+
+        self.objc = Some(macos::present(items, &backing_node, &delegate));
+        self.delegate = Some(delegate);
+        self.handler = Some(handler);
+    }
+
+    /// Shows the share sheet relative to the given view (e.g, the button the user clicked).
+    /// `completion` is called with the name of the service the user chose, or `None` if they
+    /// dismissed the sheet.
+    ///
+    /// On macOS, the name is the service title (e.g, "Mail"); on iOS, it's the activity type
+    /// (e.g, `com.apple.UIKit.activity.Mail`).
+    #[cfg(target_os = "ios")]
+    pub fn show<L, F>(&mut self, view: &L, completion: F)
+    where
+        L: Layout,
+        F: Fn(Option<String>) + 'static
+    {
+        let items: NSArray = self.items.iter().map(|item| item.into()).collect::<Vec<id>>().into();
+        let handler = ShareHandler(Box::new(completion));
+        let backing_node = view.get_backing_node();
+
+        self.objc = Some(ios::present(items, &backing_node, handler));
+    }
+}
+
+impl fmt::Debug for ShareSheet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShareSheet")
+            .field("items", &self.items)
+            .field("objc", &self.objc)
+            .finish()
+    }
+}
+
+impl Drop for ShareSheet {
+    /// Breaks the delegate link, if there is one, as the handler is going away.
+    fn drop(&mut self) {
+        if let (Some(picker), Some(_)) = (&self.objc, &self.delegate) {
+            unsafe {
+                let _: () = msg_send![&**picker, setDelegate:nil];
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! The iOS side of `Sound`, backed by `AVAudioPlayer`.
+
+use std::path::Path;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, YES, BOOL, NSData, NSInteger, NSString};
+
+/// `kSystemSoundID_Vibrate`.
+const SYSTEM_SOUND_VIBRATE: u32 = 4095;
+
+extern "C" {
+    fn AudioServicesPlayAlertSound(sound: u32);
+}
+
+/// A sound effect, loaded and ready to play.
+#[derive(Debug)]
+pub struct Sound {
+    /// The underlying `AVAudioPlayer`.
+    pub objc: ShareId<Object>
+}
+
+impl Sound {
+    /// Wraps a freshly-initialized `AVAudioPlayer`, which is `nil` if the audio couldn't be read.
+    /// The player's buffers are filled up front, so the first `play()` doesn't lag.
+    fn with_retained(player: id) -> Option<Self> {
+        match player == nil {
+            true => None,
+            false => Some(Sound {
+                objc: unsafe {
+                    let _: BOOL = msg_send![player, prepareToPlay];
+                    ShareId::from_retained_ptr(player)
+                }
+            })
+        }
+    }
+
+    /// Loads the audio file at `path`. Returns `None` if it can't be read.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let path = NSString::new(&path.as_ref().to_string_lossy());
+        let error: *mut id = std::ptr::null_mut();
+
+        Self::with_retained(unsafe {
+            let url: id = msg_send![class!(NSURL), fileURLWithPath:path];
+            let player: id = msg_send![class!(AVAudioPlayer), alloc];
+            msg_send![player, initWithContentsOfURL:url error:error]
+        })
+    }
+
+    /// Loads audio from `bytes` (e.g, a file embedded with `include_bytes!()`). Returns `None` if
+    /// it isn't in a format the system can play.
+    pub fn from_bytes(bytes: Vec<u8>) -> Option<Self> {
+        let data = NSData::new(bytes);
+        let error: *mut id = std::ptr::null_mut();
+
+        Self::with_retained(unsafe {
+            let player: id = msg_send![class!(AVAudioPlayer), alloc];
+            msg_send![player, initWithData:data.into_inner() error:error]
+        })
+    }
+
+    /// Looks up a sound file in the app bundle by name, including its extension (e.g,
+    /// `"click.caf"`). Returns `None` if there isn't one.
+    pub fn named(name: &str) -> Option<Self> {
+        let name = NSString::new(name);
+
+        let url: id = unsafe {
+            let bundle: id = msg_send![class!(NSBundle), mainBundle];
+            msg_send![bundle, URLForResource:name withExtension:nil]
+        };
+
+        if url == nil {
+            return None;
+        }
+
+        let error: *mut id = std::ptr::null_mut();
+
+        Self::with_retained(unsafe {
+            let player: id = msg_send![class!(AVAudioPlayer), alloc];
+            msg_send![player, initWithContentsOfURL:url error:error]
+        })
+    }
+
+    /// Alerts the user, e.g for a validation error. iOS has no general-purpose alert sound, so
+    /// this vibrates the device (on devices that can); it does nothing in the simulator, or on
+    /// iPad.
+    pub fn beep() {
+        unsafe {
+            AudioServicesPlayAlertSound(SYSTEM_SOUND_VIBRATE);
+        }
+    }
+
+    /// Starts playing from the beginning, or where it was paused. Returns `false` if it couldn't
+    /// start.
+    pub fn play(&self) -> bool {
+        let playing: BOOL = unsafe {
+            msg_send![&*self.objc, play]
+        };
+
+        playing == YES
+    }
+
+    /// Pauses playback, to be picked up again with `resume()`.
+    pub fn pause(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, pause];
+        }
+    }
+
+    /// Resumes playback after a `pause()`.
+    pub fn resume(&self) {
+        self.play();
+    }
+
+    /// Stops playback. The next `play()` starts from the beginning.
+    pub fn stop(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, stop];
+            let _: () = msg_send![&*self.objc, setCurrentTime:0.0f64];
+        }
+    }
+
+    /// Returns whether this is currently playing.
+    pub fn is_playing(&self) -> bool {
+        let playing: BOOL = unsafe {
+            msg_send![&*self.objc, isPlaying]
+        };
+
+        playing == YES
+    }
+
+    /// Sets whether playback starts over when it reaches the end, until `stop()` is called.
+    pub fn set_looping(&self, looping: bool) {
+        let loops: NSInteger = match looping {
+            true => -1,
+            false => 0
+        };
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setNumberOfLoops:loops];
+        }
+    }
+
+    /// Sets the volume, from `0.0` (silent) to `1.0` (full).
+    pub fn set_volume(&self, volume: f32) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setVolume:volume];
+        }
+    }
+
+    /// Returns the volume, from `0.0` (silent) to `1.0` (full).
+    pub fn volume(&self) -> f32 {
+        unsafe {
+            msg_send![&*self.objc, volume]
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! The macOS side of `Sound`, backed by `NSSound`.
+
+use std::path::Path;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, YES, NO, BOOL, NSData, NSString};
+
+extern "C" {
+    fn NSBeep();
+}
+
+/// The sounds that ship with macOS (the ones offered in the Sound preferences).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SystemSound {
+    Basso,
+    Blow,
+    Bottle,
+    Frog,
+    Funk,
+    Glass,
+    Hero,
+    Morse,
+    Ping,
+    Pop,
+    Purr,
+    Sosumi,
+    Submarine,
+    Tink
+}
+
+impl SystemSound {
+    /// The name `NSSound` knows this sound by.
+    fn name(&self) -> &'static str {
+        match self {
+            SystemSound::Basso => "Basso",
+            SystemSound::Blow => "Blow",
+            SystemSound::Bottle => "Bottle",
+            SystemSound::Frog => "Frog",
+            SystemSound::Funk => "Funk",
+            SystemSound::Glass => "Glass",
+            SystemSound::Hero => "Hero",
+            SystemSound::Morse => "Morse",
+            SystemSound::Ping => "Ping",
+            SystemSound::Pop => "Pop",
+            SystemSound::Purr => "Purr",
+            SystemSound::Sosumi => "Sosumi",
+            SystemSound::Submarine => "Submarine",
+            SystemSound::Tink => "Tink"
+        }
+    }
+}
+
+/// A sound effect, loaded and ready to play.
+#[derive(Debug)]
+pub struct Sound {
+    /// The underlying `NSSound`.
+    pub objc: ShareId<Object>
+}
+
+impl Sound {
+    /// Wraps a freshly-initialized `NSSound`, which is `nil` if the audio couldn't be read.
+    fn with_retained(sound: id) -> Option<Self> {
+        match sound == nil {
+            true => None,
+            false => Some(Sound {
+                objc: unsafe { ShareId::from_retained_ptr(sound) }
+            })
+        }
+    }
+
+    /// Loads the audio file at `path`. Returns `None` if it can't be read.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let path = NSString::new(&path.as_ref().to_string_lossy());
+
+        Self::with_retained(unsafe {
+            let url: id = msg_send![class!(NSURL), fileURLWithPath:path];
+            let sound: id = msg_send![class!(NSSound), alloc];
+            msg_send![sound, initWithContentsOfURL:url byReference:YES]
+        })
+    }
+
+    /// Loads audio from `bytes` (e.g, a file embedded with `include_bytes!()`). Returns `None` if
+    /// it isn't in a format the system can play.
+    pub fn from_bytes(bytes: Vec<u8>) -> Option<Self> {
+        let data = NSData::new(bytes);
+
+        Self::with_retained(unsafe {
+            let sound: id = msg_send![class!(NSSound), alloc];
+            msg_send![sound, initWithData:data.into_inner()]
+        })
+    }
+
+    /// Looks up a sound by name: first in the app bundle, then in the system's sound folders.
+    /// Returns `None` if there isn't one.
+    pub fn named(name: &str) -> Option<Self> {
+        let name = NSString::new(name);
+
+        // `soundNamed:` hands back a shared instance, so we copy it; otherwise, playing or
+        // changing the volume on one `Sound` would affect every other one with the same name.
+        Self::with_retained(unsafe {
+            let sound: id = msg_send![class!(NSSound), soundNamed:name];
+
+            match sound == nil {
+                true => nil,
+                false => msg_send![sound, copy]
+            }
+        })
+    }
+
+    /// Loads one of the sounds that ship with macOS.
+    pub fn system(sound: SystemSound) -> Self {
+        Self::named(sound.name()).expect("System sounds should always be available")
+    }
+
+    /// Plays the user's alert sound (the one chosen in the Sound preferences), e.g for a
+    /// validation error or a key press that does nothing.
+    pub fn beep() {
+        unsafe {
+            NSBeep();
+        }
+    }
+
+    /// Starts playing from the beginning, or where it was paused. Returns `false` if it's already
+    /// playing (or couldn't start).
+    pub fn play(&self) -> bool {
+        let playing: BOOL = unsafe {
+            msg_send![&*self.objc, play]
+        };
+
+        playing == YES
+    }
+
+    /// Pauses playback, to be picked up again with `resume()`.
+    pub fn pause(&self) {
+        unsafe {
+            let _: BOOL = msg_send![&*self.objc, pause];
+        }
+    }
+
+    /// Resumes playback after a `pause()`.
+    pub fn resume(&self) {
+        unsafe {
+            let _: BOOL = msg_send![&*self.objc, resume];
+        }
+    }
+
+    /// Stops playback. The next `play()` starts from the beginning.
+    pub fn stop(&self) {
+        unsafe {
+            let _: BOOL = msg_send![&*self.objc, stop];
+        }
+    }
+
+    /// Returns whether this is currently playing.
+    pub fn is_playing(&self) -> bool {
+        let playing: BOOL = unsafe {
+            msg_send![&*self.objc, isPlaying]
+        };
+
+        playing == YES
+    }
+
+    /// Sets whether playback starts over when it reaches the end, until `stop()` is called.
+    pub fn set_looping(&self, looping: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setLoops:match looping {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets the volume, from `0.0` (silent) to `1.0` (full).
+    pub fn set_volume(&self, volume: f32) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setVolume:volume];
+        }
+    }
+
+    /// Returns the volume, from `0.0` (silent) to `1.0` (full).
+    pub fn volume(&self) -> f32 {
+        unsafe {
+            msg_send![&*self.objc, volume]
+        }
+    }
+}
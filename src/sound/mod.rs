@@ -0,0 +1,28 @@
+//! Short sound effects, for audio feedback: `NSSound` on macOS, and `AVAudioPlayer` on iOS.
+//!
+//! This isn't meant for music or anything long-running - there's no streaming or mixing - just
+//! loading a clip (from a file, or bytes you've bundled in) and playing it.
+//!
+//! ```rust,no_run
+//! use cacao::sound::Sound;
+//!
+//! if let Some(sound) = Sound::from_file("/path/to/click.aiff") {
+//!     sound.set_volume(0.5);
+//!     sound.play();
+//! }
+//! ```
+//!
+//! On macOS, the sounds from System Preferences are also available, as `SystemSound`s. For a quick
+//! "that didn't work", there's `Sound::beep()`.
+
+#[cfg(target_os = "macos")]
+mod macos;
+
+#[cfg(target_os = "macos")]
+pub use macos::{Sound, SystemSound};
+
+#[cfg(target_os = "ios")]
+mod ios;
+
+#[cfg(target_os = "ios")]
+pub use ios::Sound;
@@ -0,0 +1,28 @@
+//! Enums used when configuring a `StatusItem`.
+
+use core_graphics::base::CGFloat;
+
+/// Represents the length (width) that a `StatusItem` should occupy in the system status bar.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum StatusItemLength {
+    /// The item will size itself to fit its contents (e.g, a title). This is what you want for
+    /// text-based items.
+    Variable,
+
+    /// The item will be sized to match the thickness of the status bar, making it square. This is
+    /// what you want for icon-based items.
+    Square,
+
+    /// The item will be a fixed width, in points.
+    Fixed(f64)
+}
+
+impl From<StatusItemLength> for CGFloat {
+    fn from(length: StatusItemLength) -> Self {
+        match length {
+            StatusItemLength::Variable => -1.,
+            StatusItemLength::Square => -2.,
+            StatusItemLength::Fixed(width) => width as CGFloat
+        }
+    }
+}
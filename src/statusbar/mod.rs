@@ -0,0 +1,281 @@
+//! Wraps `NSStatusItem`, which is what you'd use to put an item in the system status bar (the
+//! right-hand side of the menu bar). This is the core of menu-bar-only ("agent") applications.
+//!
+//! A `StatusItem` is added to the system status bar upon creation, and removed when it's dropped -
+//! so be sure to keep it around (e.g, on your `AppDelegate`) for as long as you want it visible.
+//!
+//! ```rust,no_run
+//! use cacao::statusbar::{StatusItem, StatusItemLength};
+//!
+//! let mut item = StatusItem::new(StatusItemLength::Variable);
+//! item.set_title("Hello");
+//! item.set_action(|| {
+//!     println!("Clicked!");
+//! });
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+use core_graphics::base::CGFloat;
+
+use objc_id::ShareId;
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::dispatch::assert_main_thread;
+use crate::foundation::{id, nil, YES, NO, NSString, NSUInteger};
+use crate::image::Image;
+use crate::invoker::TargetActionHandler;
+use crate::layout::{Layout, LayoutConstraint, LayoutAnchorX, LayoutAnchorY};
+use crate::macos::menu::Menu;
+
+mod enums;
+pub use enums::StatusItemLength;
+
+/// Corresponds to `NSEventMaskLeftMouseUp | NSEventMaskRightMouseUp`.
+const CLICK_EVENT_MASK: NSUInteger = (1 << 2) | (1 << 4);
+
+/// Corresponds to `NSEventTypeRightMouseUp`.
+const RIGHT_MOUSE_UP: NSUInteger = 4;
+
+/// Corresponds to `NSEventModifierFlagControl`.
+const CONTROL_KEY_MASK: NSUInteger = 1 << 18;
+
+/// Shorthand for the callbacks we store.
+type Callback = Arc<dyn Fn() + Send + Sync + 'static>;
+
+/// A wrapper for `NSStatusItem`. Holds (retains) the underlying item, along with any menu or
+/// callbacks you've attached, so they stay alive for as long as the item does.
+pub struct StatusItem {
+    /// A pointer to the underlying `NSStatusItem`.
+    pub objc: ShareId<Object>,
+
+    menu: Option<Menu>,
+    handler: Option<TargetActionHandler>,
+    click: Option<Callback>,
+    right_click: Option<Callback>,
+    view: Option<ShareId<Object>>
+}
+
+impl StatusItem {
+    /// Creates a new `StatusItem` with the specified length, and adds it to the system status bar.
+    pub fn new(length: StatusItemLength) -> Self {
+        assert_main_thread("StatusItem::new");
+
+        let length: CGFloat = length.into();
+
+        StatusItem {
+            objc: unsafe {
+                let status_bar: id = msg_send![class!(NSStatusBar), systemStatusBar];
+                let item: id = msg_send![status_bar, statusItemWithLength:length];
+                ShareId::from_ptr(item)
+            },
+
+            menu: None,
+            handler: None,
+            click: None,
+            right_click: None,
+            view: None
+        }
+    }
+
+    /// Returns the `NSStatusBarButton` that backs this item. This is where titles, images and
+    /// actions ultimately live.
+    fn button(&self) -> id {
+        unsafe {
+            msg_send![&*self.objc, button]
+        }
+    }
+
+    /// Sets the title displayed for this item.
+    pub fn set_title(&self, title: &str) {
+        let title = NSString::new(title);
+
+        unsafe {
+            let _: () = msg_send![self.button(), setTitle:title];
+        }
+    }
+
+    /// Sets the image displayed for this item. The image is displayed as-is; if you want it to
+    /// adapt to the menu bar appearance (light, dark, highlighted), use `set_template_image`
+    /// instead.
+    pub fn set_image(&self, image: &Image) {
+        unsafe {
+            let _: () = msg_send![&*image.0, setTemplate:NO];
+            let _: () = msg_send![self.button(), setImage:&*image.0];
+        }
+    }
+
+    /// Sets the image displayed for this item, marking it as a template image. Template images
+    /// are tinted by the system to match the menu bar, which is almost always what you want for
+    /// status bar icons.
+    pub fn set_template_image(&self, image: &Image) {
+        unsafe {
+            let _: () = msg_send![&*image.0, setTemplate:YES];
+            let _: () = msg_send![self.button(), setImage:&*image.0];
+        }
+    }
+
+    /// Sets the tooltip shown when the user hovers over this item.
+    pub fn set_tooltip(&self, tooltip: &str) {
+        let tooltip = NSString::new(tooltip);
+
+        unsafe {
+            let _: () = msg_send![self.button(), setToolTip:tooltip];
+        }
+    }
+
+    /// Sets the length of this item.
+    pub fn set_length(&self, length: StatusItemLength) {
+        let length: CGFloat = length.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setLength:length];
+        }
+    }
+
+    /// Sets whether this item is visible in the status bar.
+    pub fn set_visible(&self, visible: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setVisible:match visible {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Attaches a `Menu` to this item, which will be shown when the item is clicked. The menu is
+    /// retained for as long as this item is alive (or until it's replaced).
+    ///
+    /// Note that if a menu is set, AppKit will show it on click rather than firing your click
+    /// callbacks.
+    pub fn set_menu(&mut self, menu: Menu) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setMenu:&*menu.inner];
+        }
+
+        self.menu = Some(menu);
+    }
+
+    /// Removes any `Menu` attached to this item.
+    pub fn remove_menu(&mut self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setMenu:nil];
+        }
+
+        self.menu = None;
+    }
+
+    /// Attaches a callback for (left) click events on this item.
+    pub fn set_action<F: Fn() + Send + Sync + 'static>(&mut self, action: F) {
+        self.click = Some(Arc::new(action));
+        self.configure_handler();
+    }
+
+    /// Attaches a callback for right-click (or control-click) events on this item. If this is not
+    /// set, right-clicks will call through to the handler set in `set_action`.
+    pub fn set_right_click_action<F: Fn() + Send + Sync + 'static>(&mut self, action: F) {
+        self.right_click = Some(Arc::new(action));
+        self.configure_handler();
+    }
+
+    /// Wires up the underlying button to call through to our callbacks. Since the button can only
+    /// have one target/action, we inspect the current event to decide which one to fire.
+    fn configure_handler(&mut self) {
+        let click = self.click.clone();
+        let right_click = self.right_click.clone();
+        let button = self.button();
+
+        unsafe {
+            let _: () = msg_send![button, sendActionOn:CLICK_EVENT_MASK];
+        }
+
+        self.handler = Some(TargetActionHandler::new(unsafe { &*button }, move || {
+            let is_right_click = unsafe {
+                let app: id = msg_send![class!(NSApplication), sharedApplication];
+                let event: id = msg_send![app, currentEvent];
+
+                if event == nil {
+                    false
+                } else {
+                    let event_type: NSUInteger = msg_send![event, type];
+                    let modifiers: NSUInteger = msg_send![event, modifierFlags];
+                    event_type == RIGHT_MOUSE_UP || (modifiers & CONTROL_KEY_MASK) == CONTROL_KEY_MASK
+                }
+            };
+
+            match (is_right_click, &right_click, &click) {
+                (true, Some(handler), _) => handler(),
+                (_, _, Some(handler)) => handler(),
+                _ => {}
+            }
+        }));
+    }
+
+    /// Sets a fully custom view as the contents of this item. The view is added to the backing
+    /// status bar button and pinned to its edges, so you can draw whatever you'd like while still
+    /// getting the standard highlighting and click behavior.
+    pub fn set_view<L: Layout>(&mut self, view: &L) {
+        let backing_node = view.get_backing_node();
+        let button = self.button();
+
+        if let Some(old_view) = self.view.take() {
+            unsafe {
+                let _: () = msg_send![&*old_view, removeFromSuperview];
+            }
+        }
+
+        unsafe {
+            let _: () = msg_send![&*backing_node, setTranslatesAutoresizingMaskIntoConstraints:NO];
+            let _: () = msg_send![button, addSubview:&*backing_node];
+        }
+
+        let (top, leading, trailing, bottom) = unsafe {(
+            LayoutAnchorY::new(msg_send![button, topAnchor]),
+            LayoutAnchorX::new(msg_send![button, leadingAnchor]),
+            LayoutAnchorX::new(msg_send![button, trailingAnchor]),
+            LayoutAnchorY::new(msg_send![button, bottomAnchor])
+        )};
+
+        let (view_top, view_leading, view_trailing, view_bottom) = unsafe {(
+            LayoutAnchorY::new(msg_send![&*backing_node, topAnchor]),
+            LayoutAnchorX::new(msg_send![&*backing_node, leadingAnchor]),
+            LayoutAnchorX::new(msg_send![&*backing_node, trailingAnchor]),
+            LayoutAnchorY::new(msg_send![&*backing_node, bottomAnchor])
+        )};
+
+        LayoutConstraint::activate(&[
+            view_top.constraint_equal_to(&top),
+            view_leading.constraint_equal_to(&leading),
+            view_trailing.constraint_equal_to(&trailing),
+            view_bottom.constraint_equal_to(&bottom)
+        ]);
+
+        self.view = Some(backing_node);
+    }
+}
+
+impl fmt::Debug for StatusItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StatusItem")
+            .field("objc", &self.objc)
+            .field("menu", &self.menu)
+            .finish()
+    }
+}
+
+impl Drop for StatusItem {
+    /// Removes the item from the system status bar. The underlying item (and anything attached to
+    /// it) is released once this finishes.
+    fn drop(&mut self) {
+        unsafe {
+            let button = self.button();
+            let _: () = msg_send![button, setTarget:nil];
+            let _: () = msg_send![button, setAction:nil];
+
+            let status_bar: id = msg_send![class!(NSStatusBar), systemStatusBar];
+            let _: () = msg_send![status_bar, removeStatusItem:&*self.objc];
+        }
+    }
+}
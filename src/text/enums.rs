@@ -1,4 +1,7 @@
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::foundation::{NSInteger, NSUInteger};
 
 pub enum TextAlign {
@@ -55,4 +58,61 @@ impl Into<NSUInteger> for LineBreakMode {
     }
 }
 
+/// The semantic styles for text, which map to fonts that follow the user's preferred text size
+/// (Dynamic Type) on iOS. See `Font::preferred()`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TextStyle {
+    /// The largest title, e.g at the top of a scrolling screen.
+    LargeTitle,
+
+    /// A first-level title.
+    Title1,
+
+    /// A second-level title.
+    Title2,
+
+    /// A third-level title.
+    Title3,
+
+    /// A heading, e.g for a section.
+    Headline,
+
+    /// A subheading.
+    Subheadline,
+
+    /// Body text - the default for most content.
+    Body,
+
+    /// A callout, e.g a note set apart from body text.
+    Callout,
 
+    /// A footnote.
+    Footnote,
+
+    /// A standard caption.
+    Caption1,
+
+    /// An alternate (smaller) caption.
+    Caption2
+}
+
+impl TextStyle {
+    /// Returns the `UIFontTextStyle` (or `NSFontTextStyle`, which shares the same values) for
+    /// this style.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            TextStyle::LargeTitle => "UICTFontTextStyleTitle0",
+            TextStyle::Title1 => "UICTFontTextStyleTitle1",
+            TextStyle::Title2 => "UICTFontTextStyleTitle2",
+            TextStyle::Title3 => "UICTFontTextStyleTitle3",
+            TextStyle::Headline => "UICTFontTextStyleHeadline",
+            TextStyle::Subheadline => "UICTFontTextStyleSubhead",
+            TextStyle::Body => "UICTFontTextStyleBody",
+            TextStyle::Callout => "UICTFontTextStyleCallout",
+            TextStyle::Footnote => "UICTFontTextStyleFootnote",
+            TextStyle::Caption1 => "UICTFontTextStyleCaption1",
+            TextStyle::Caption2 => "UICTFontTextStyleCaption2"
+        }
+    }
+}
@@ -6,7 +6,35 @@ use objc_id::ShareId;
 use objc::runtime::{Class, Object};
 use objc::{class, msg_send, sel, sel_impl};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::foundation::{id, nil, YES, NO, NSArray, NSString};
+use crate::text::TextStyle;
+
+/// Describes a font, without loading it - for keeping in settings or theme files (with the
+/// `serde` feature, this can be serialized). Turn it into a `Font` with `Font::from_descriptor()`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FontDescriptor {
+    /// The system font, at a given size.
+    System {
+        /// The size, in points.
+        size: f64
+    },
+
+    /// The system font for a text style. See `Font::preferred()`.
+    Preferred(TextStyle),
+
+    /// A font by its PostScript or family name (e.g, `"Menlo-Regular"`), at a given size.
+    Named {
+        /// The font's name.
+        name: String,
+
+        /// The size, in points.
+        size: f64
+    }
+}
 
 #[derive(Debug)]
 pub struct Font {
@@ -33,4 +61,77 @@ impl Font {
             }
         }
     }
+
+    /// Returns the font with `name` (a PostScript or family name, e.g `"Menlo-Regular"`) at
+    /// `size`, or `None` if it isn't installed.
+    pub fn named(name: &str, size: CGFloat) -> Option<Self> {
+        let name = NSString::new(name);
+
+        #[cfg(target_os = "macos")]
+        let font: id = unsafe { msg_send![class!(NSFont), fontWithName:name size:size] };
+
+        #[cfg(target_os = "ios")]
+        let font: id = unsafe { msg_send![class!(UIFont), fontWithName:name size:size] };
+
+        match font == nil {
+            true => None,
+            false => Some(Font {
+                objc: unsafe { ShareId::from_ptr(font) }
+            })
+        }
+    }
+
+    /// Loads the font that `descriptor` describes. A named font that isn't installed falls back
+    /// to the system font, at the same size.
+    pub fn from_descriptor(descriptor: &FontDescriptor) -> Self {
+        match descriptor {
+            FontDescriptor::System { size } => Font::system(*size as CGFloat),
+            FontDescriptor::Preferred(style) => Font::preferred(*style),
+
+            FontDescriptor::Named { name, size } => {
+                Font::named(name, *size as CGFloat).unwrap_or_else(|| Font::system(*size as CGFloat))
+            }
+        }
+    }
+
+    /// Returns the system font for `style`, at the size the user prefers (Dynamic Type) on iOS.
+    /// The font is fixed at today's preference; to have a text widget follow later changes, use
+    /// its `set_adjusts_font_for_content_size_category()` as well.
+    ///
+    /// On macOS, there's no user preference; this is the system's size for the style. Requires
+    /// macOS 11.
+    pub fn preferred(style: TextStyle) -> Self {
+        let style = NSString::new(style.name());
+
+        Font {
+            objc: unsafe {
+                #[cfg(target_os = "macos")]
+                let font: id = {
+                    let options: id = msg_send![class!(NSDictionary), dictionary];
+                    msg_send![class!(NSFont), preferredFontForTextStyle:style options:options]
+                };
+
+                #[cfg(target_os = "ios")]
+                let font: id = msg_send![class!(UIFont), preferredFontForTextStyle:style];
+
+                ShareId::from_ptr(font)
+            }
+        }
+    }
+
+    /// Returns this font scaled for the user's preferred text size, in the way `style` would
+    /// be - so custom fonts can follow Dynamic Type too. As with `preferred()`, set
+    /// `set_adjusts_font_for_content_size_category()` on the widget to follow later changes.
+    #[cfg(target_os = "ios")]
+    pub fn scaled(&self, style: TextStyle) -> Self {
+        let style = NSString::new(style.name());
+
+        Font {
+            objc: unsafe {
+                let metrics: id = msg_send![class!(UIFontMetrics), metricsForTextStyle:style];
+                let font: id = msg_send![metrics, scaledFontForFont:&*self.objc];
+                ShareId::from_ptr(font)
+            }
+        }
+    }
 }
@@ -0,0 +1,105 @@
+//! A builder for `Label`s.
+
+use std::fmt;
+
+use crate::color::Color;
+use crate::text::{Font, Label, LineBreakMode, TextAlign};
+use crate::text::label::LabelDelegate;
+
+/// Configures a `Label` in one expression, rather than a string of setters in `did_load()`.
+/// Everything is optional; anything left unset keeps the system default.
+///
+/// ```rust,no_run
+/// use cacao::text::{Font, Label, TextAlign};
+///
+/// let title = Label::builder()
+///     .text("Hello, world")
+///     .font(Font::system(24.))
+///     .alignment(TextAlign::Center)
+///     .build();
+/// ```
+#[derive(Default)]
+pub struct LabelBuilder {
+    text: Option<String>,
+    font: Option<Font>,
+    alignment: Option<TextAlign>,
+    line_break_mode: Option<LineBreakMode>,
+    background_color: Option<Color>
+}
+
+impl LabelBuilder {
+    /// Sets the text.
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// Sets the font.
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the text alignment.
+    pub fn alignment(mut self, alignment: TextAlign) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Sets how lines are broken when the text doesn't fit.
+    pub fn line_break_mode(mut self, mode: LineBreakMode) -> Self {
+        self.line_break_mode = Some(mode);
+        self
+    }
+
+    /// Sets the background color.
+    pub fn background_color(mut self, color: Color) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+
+    /// Creates the `Label`.
+    pub fn build(self) -> Label {
+        self.configure(Label::new())
+    }
+
+    /// Creates the `Label`, with a delegate.
+    pub fn build_with<T: LabelDelegate + 'static>(self, delegate: T) -> Label<T> {
+        self.configure(Label::with(delegate))
+    }
+
+    /// Applies everything that's been set.
+    fn configure<T>(self, label: Label<T>) -> Label<T> {
+        if let Some(text) = self.text {
+            label.set_text(&text);
+        }
+
+        if let Some(font) = self.font {
+            label.set_font(&font);
+        }
+
+        if let Some(alignment) = self.alignment {
+            label.set_text_alignment(alignment);
+        }
+
+        if let Some(mode) = self.line_break_mode {
+            label.set_line_break_mode(mode);
+        }
+
+        if let Some(color) = self.background_color {
+            label.set_background_color(color);
+        }
+
+        label
+    }
+}
+
+impl fmt::Debug for LabelBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LabelBuilder")
+            .field("text", &self.text)
+            .field("font", &self.font)
+            .field("background_color", &self.background_color)
+            .finish()
+    }
+}
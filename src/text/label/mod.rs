@@ -44,10 +44,13 @@ use objc_id::ShareId;
 use objc::runtime::{Class, Object};
 use objc::{msg_send, sel, sel_impl};
 
+use crate::binding::{Binding, Observable};
+use crate::dispatch::{assert_main_thread, MainThreadHandle};
 use crate::foundation::{id, nil, YES, NO, NSArray, NSInteger, NSUInteger, NSString};
 use crate::color::Color;
 use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
 use crate::text::{Font, TextAlign, LineBreakMode};
+use crate::utils::clear_ptr;
 
 #[cfg(target_os = "macos")]
 mod macos;
@@ -67,6 +70,9 @@ use ios::{register_view_class, register_view_class_with_delegate};
 mod traits;
 pub use traits::LabelDelegate;
 
+mod builder;
+pub use builder::LabelBuilder;
+
 pub(crate) static LABEL_DELEGATE_PTR: &str = "rstLabelDelegatePtr";
 
 /// A helper method for instantiating view classes and applying default settings to them.
@@ -135,6 +141,8 @@ impl Default for Label {
 impl Label {
     /// Returns a default `Label`, suitable for 
     pub fn new() -> Self {
+        assert_main_thread("Label::new");
+
         let view = allocate_view(register_view_class);
 
         Label {
@@ -152,10 +160,34 @@ impl Label {
     }
 }
 
+impl Label {
+    /// Builds a `Label` around `objc`, with no delegate.
+    fn wrap(objc: ShareId<Object>) -> Self {
+        let view = &*objc;
+
+        Label {
+            delegate: None,
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            objc: objc
+        }
+    }
+}
+
+impl_objc_access!(Label<T>, LABEL_DELEGATE_PTR);
+
 impl<T> Label<T> where T: LabelDelegate + 'static {
     /// Initializes a new Label with a given `LabelDelegate`. This enables you to respond to events
     /// and customize the view as a module, similar to class-based systems.
     pub fn with(delegate: T) -> Label<T> {
+        assert_main_thread("Label::with");
+
         let delegate = Box::new(delegate);
         
         let label = allocate_view(register_view_class_with_delegate::<T>);
@@ -205,6 +237,12 @@ impl<T> Label<T> {
         }
     }
 
+    /// Returns a handle to this label that can be sent to other threads, and used to update it
+    /// from there; see `MainThreadHandle`.
+    pub fn handle(&self) -> LabelHandle {
+        MainThreadHandle::new(self.clone_as_handle())
+    }
+
     /// Call this to set the background color for the backing layer.
     pub fn set_background_color(&self, color: Color) {
         let bg = color.into_platform_specific_color();
@@ -238,6 +276,27 @@ impl<T> Label<T> {
         }
     }
 
+    /// Sets whether the font follows the user's preferred text size (Dynamic Type) as it
+    /// changes. This only works for fonts from `Font::preferred()` or `Font::scaled()`.
+    #[cfg(target_os = "ios")]
+    pub fn set_adjusts_font_for_content_size_category(&self, adjusts: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAdjustsFontForContentSizeCategory:match adjusts {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Keeps the text in sync with `text`, until the returned `Binding` is dropped. The value can
+    /// be changed from any thread; the label is updated on the main thread.
+    pub fn bind_text(&self, text: &Observable<String>) -> Binding<String> {
+        self.set_text(&text.get());
+
+        let handle = self.handle();
+        text.subscribe(move |value| handle.set_text(value))
+    }
+
     pub fn set_line_break_mode(&self, mode: LineBreakMode) {
         #[cfg(target_os = "macos")]
         unsafe {
@@ -248,6 +307,13 @@ impl<T> Label<T> {
     }
 }
 
+impl Label {
+    /// Returns a builder, for configuring a `Label` in one go; see `LabelBuilder`.
+    pub fn builder() -> LabelBuilder {
+        LabelBuilder::default()
+    }
+}
+
 impl<T> Layout for Label<T> {
     fn get_backing_node(&self) -> ShareId<Object> {
         self.objc.clone()
@@ -268,9 +334,12 @@ impl<T> Drop for Label<T> {
     /// this has a superview (i.e, it's in the heirarchy) on the AppKit side. If it does, we go
     /// ahead and remove it - this is intended to match the semantics of how Rust handles things).
     ///
-    /// There are, thankfully, no delegates we need to break here.
+    /// The delegate pointer on the Objective-C side is cleared too, so any callbacks that arrive
+    /// after this (e.g, if something else is still holding on to the view) are ignored.
     fn drop(&mut self) {
         if self.delegate.is_some() {
+            clear_ptr(&self.objc, LABEL_DELEGATE_PTR);
+
             unsafe {
                 let superview: id = msg_send![&*self.objc, superview];
                 if superview != nil {
@@ -280,3 +349,14 @@ impl<T> Drop for Label<T> {
         }
     }
 }
+
+/// A `Label` handle that can be sent to other threads. Updates are applied on the main thread.
+pub type LabelHandle = MainThreadHandle<Label>;
+
+impl LabelHandle {
+    /// Sets the text, on the main thread.
+    pub fn set_text(&self, text: &str) {
+        let text = text.to_string();
+        self.update(move |label| label.set_text(&text));
+    }
+}
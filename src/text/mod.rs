@@ -2,10 +2,10 @@
 //! with text.
 
 pub mod label;
-pub use label::Label;
+pub use label::{Label, LabelBuilder, LabelHandle};
 
 pub mod enums;
-pub use enums::{LineBreakMode, TextAlign};
+pub use enums::{LineBreakMode, TextAlign, TextStyle};
 
 pub mod font;
-pub use font::Font;
+pub use font::{Font, FontDescriptor};
@@ -0,0 +1,204 @@
+//! Timers, for debouncing input, polling, or otherwise doing something later without spawning a
+//! thread.
+//!
+//! Timers created via `Timer::once()` and `Timer::repeating()` are backed by `NSTimer`, and fire
+//! on the main run loop - so they're safe to touch UI from. If you'd rather run on a dispatch
+//! queue (e.g, for background work), use `Timer::once_on()` and `Timer::repeating_on()`.
+//!
+//! Either way, a timer is invalidated when it's dropped, so hold on to it for as long as you want
+//! it to fire.
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use cacao::timer::Timer;
+//!
+//! let timer = Timer::repeating(Duration::from_secs(5), || {
+//!     println!("Checking for updates...");
+//! });
+//!
+//! // Allow the system to coalesce this with other work, for better energy usage.
+//! timer.set_tolerance(Duration::from_secs(1));
+//! ```
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use block::ConcreteBlock;
+use dispatch::Queue;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::dispatch::MainThreadMarker;
+use crate::foundation::{id, YES, NO, BOOL, NSString};
+
+/// `NSRunLoopCommonModes`, so that timers keep firing while the user is e.g scrolling or
+/// resizing a window.
+static COMMON_MODES: &str = "kCFRunLoopCommonModes";
+
+/// The two backing implementations for a `Timer`.
+#[derive(Debug)]
+enum TimerKind {
+    /// An `NSTimer` scheduled on the main run loop.
+    RunLoop(ShareId<Object>),
+
+    /// A closure scheduled (and rescheduled, for repeating timers) on a dispatch queue, and a
+    /// flag to stop it.
+    Dispatch(Arc<AtomicBool>)
+}
+
+/// A one-shot or repeating timer. The timer stops when this is dropped (or `invalidate()` is
+/// called).
+#[derive(Debug)]
+pub struct Timer {
+    kind: TimerKind
+}
+
+impl Timer {
+    /// Schedules an `NSTimer` on the main run loop. `handler` isn't `Send`, so this has to be
+    /// called from the main thread - where it'll run - and panics otherwise, in release builds too.
+    fn scheduled<F: Fn() + 'static>(interval: Duration, repeats: bool, handler: F) -> Self {
+        if let Err(e) = MainThreadMarker::try_new("Timer::once/repeating") {
+            panic!("{}", e);
+        }
+
+        let block = ConcreteBlock::new(move |_timer: id| {
+            handler();
+        });
+        let block = block.copy();
+
+        let interval = interval.as_secs_f64();
+        let repeats = match repeats {
+            true => YES,
+            false => NO
+        };
+
+        Timer {
+            kind: TimerKind::RunLoop(unsafe {
+                let timer: id = msg_send![class!(NSTimer), timerWithTimeInterval:interval repeats:repeats block:block];
+                let run_loop: id = msg_send![class!(NSRunLoop), mainRunLoop];
+                let mode = NSString::new(COMMON_MODES);
+                let _: () = msg_send![run_loop, addTimer:timer forMode:mode];
+                ShareId::from_ptr(timer)
+            })
+        }
+    }
+
+    /// Runs `handler` once, on the main thread, after `delay`. This must be called from the main
+    /// thread; from elsewhere, use `once_on()` with `Queue::main()`.
+    pub fn once<F: Fn() + 'static>(delay: Duration, handler: F) -> Self {
+        Timer::scheduled(delay, false, handler)
+    }
+
+    /// Runs `handler` every `interval`, on the main thread, until the timer is invalidated. This
+    /// must be called from the main thread; from elsewhere, use `repeating_on()` with
+    /// `Queue::main()`.
+    pub fn repeating<F: Fn() + 'static>(interval: Duration, handler: F) -> Self {
+        Timer::scheduled(interval, true, handler)
+    }
+
+    /// Runs `handler` once, on the given dispatch queue, after `delay`.
+    pub fn once_on<F: Fn() + Send + Sync + 'static>(queue: &Queue, delay: Duration, handler: F) -> Self {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        schedule_on(queue.clone(), delay, false, cancelled.clone(), Arc::new(handler));
+
+        Timer {
+            kind: TimerKind::Dispatch(cancelled)
+        }
+    }
+
+    /// Runs `handler` every `interval`, on the given dispatch queue, until the timer is
+    /// invalidated. The next run is scheduled once the handler returns, so a slow handler
+    /// delays subsequent runs rather than piling them up.
+    pub fn repeating_on<F: Fn() + Send + Sync + 'static>(queue: &Queue, interval: Duration, handler: F) -> Self {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        schedule_on(queue.clone(), interval, true, cancelled.clone(), Arc::new(handler));
+
+        Timer {
+            kind: TimerKind::Dispatch(cancelled)
+        }
+    }
+
+    /// Sets how late the timer is allowed to fire, which lets the system coalesce timers and
+    /// save energy. Apple recommends at least 10% of the interval for repeating timers. This
+    /// only applies to main run loop timers; it's a no-op for dispatch timers.
+    pub fn set_tolerance(&self, tolerance: Duration) {
+        if let TimerKind::RunLoop(timer) = &self.kind {
+            let tolerance = tolerance.as_secs_f64();
+
+            unsafe {
+                let _: () = msg_send![&**timer, setTolerance:tolerance];
+            }
+        }
+    }
+
+    /// Fires a main run loop timer immediately. For repeating timers, this doesn't affect the
+    /// regular schedule. This is a no-op for dispatch timers.
+    pub fn fire(&self) {
+        if let TimerKind::RunLoop(timer) = &self.kind {
+            unsafe {
+                let _: () = msg_send![&**timer, fire];
+            }
+        }
+    }
+
+    /// Returns whether the timer will still fire. One-shot main run loop timers become invalid
+    /// after they fire.
+    pub fn is_valid(&self) -> bool {
+        match &self.kind {
+            TimerKind::RunLoop(timer) => {
+                let valid: BOOL = unsafe { msg_send![&**timer, isValid] };
+
+                match valid {
+                    YES => true,
+                    NO => false,
+                    _ => unreachable!()
+                }
+            },
+
+            TimerKind::Dispatch(cancelled) => !cancelled.load(Ordering::SeqCst)
+        }
+    }
+
+    /// Stops the timer. It won't fire again.
+    pub fn invalidate(&self) {
+        match &self.kind {
+            TimerKind::RunLoop(timer) => unsafe {
+                let _: () = msg_send![&**timer, invalidate];
+            },
+
+            TimerKind::Dispatch(cancelled) => cancelled.store(true, Ordering::SeqCst)
+        }
+    }
+}
+
+impl Drop for Timer {
+    /// Invalidates the timer, as nothing can stop it once the handle is gone.
+    fn drop(&mut self) {
+        self.invalidate();
+    }
+}
+
+/// Schedules `handler` on `queue` after `delay`, rescheduling after each run if `repeats` is set,
+/// until `cancelled` is flipped.
+fn schedule_on<F>(queue: Queue, delay: Duration, repeats: bool, cancelled: Arc<AtomicBool>, handler: Arc<F>)
+where
+    F: Fn() + Send + Sync + 'static
+{
+    let next = queue.clone();
+
+    queue.exec_after(delay, move || {
+        if cancelled.load(Ordering::SeqCst) {
+            return;
+        }
+
+        handler();
+
+        match repeats {
+            true => schedule_on(next, delay, repeats, cancelled, handler),
+            false => cancelled.store(true, Ordering::SeqCst)
+        }
+    });
+}
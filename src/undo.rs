@@ -0,0 +1,228 @@
+//! Wraps `NSUndoManager`, which records operations so that the user can undo and redo them. On
+//! macOS, windows come with one for free, and the standard Edit ▸ Undo/Redo menu items route to
+//! it via the responder chain - so registering your operations there is all you need to do.
+//!
+//! ```rust,no_run
+//! use cacao::undo::UndoManager;
+//! use cacao::view::View;
+//!
+//! let manager = UndoManager::new();
+//! let view = View::new();
+//!
+//! // Make some change, then register how to revert it...
+//! manager.register_undo(&view.objc, |manager| {
+//!     // ...revert the change here. Registering with `manager` again makes it redo-able.
+//! });
+//! manager.set_action_name("Change Color");
+//! ```
+
+use block::ConcreteBlock;
+
+use objc::{class, msg_send, sel, sel_impl};
+use objc::runtime::Object;
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, BOOL, YES, NO, NSString, NSUInteger};
+
+/// A wrapper for `NSUndoManager`. This is cheap to clone; clones refer to the same manager.
+#[derive(Clone, Debug)]
+pub struct UndoManager {
+    pub objc: ShareId<Object>
+}
+
+impl Default for UndoManager {
+    fn default() -> Self {
+        UndoManager::new()
+    }
+}
+
+impl UndoManager {
+    /// Creates a new, standalone `UndoManager`. If you're working with a window, you probably want
+    /// the one it provides (see `Window::undo_manager()`) instead.
+    pub fn new() -> Self {
+        UndoManager {
+            objc: unsafe {
+                let manager: id = msg_send![class!(NSUndoManager), new];
+                ShareId::from_retained_ptr(manager)
+            }
+        }
+    }
+
+    /// Wraps an existing `NSUndoManager`. Returns `None` if the pointer is `nil`.
+    pub(crate) fn with(manager: id) -> Option<Self> {
+        match manager == nil {
+            true => None,
+            false => Some(UndoManager {
+                objc: unsafe { ShareId::from_ptr(manager) }
+            })
+        }
+    }
+
+    /// Registers an operation to undo. When the user undoes, `handler` is called with this
+    /// manager; if you register the inverse operation from inside it, that becomes the redo.
+    ///
+    /// `target` is used to group operations by object (see `remove_all_actions_with_target`); it
+    /// isn't retained, and is typically the `objc` field of whatever control or view you're
+    /// working with.
+    pub fn register_undo<F>(&self, target: &Object, handler: F)
+    where
+        F: Fn(&UndoManager) + 'static
+    {
+        // The manager owns the block, so capturing a retained handle here would create a cycle.
+        // The block can only ever be invoked by the manager itself, so the raw pointer is fine.
+        let manager = &*self.objc as *const Object as usize;
+        let block = ConcreteBlock::new(move |_target: id| {
+            if let Some(manager) = UndoManager::with(manager as id) {
+                handler(&manager);
+            }
+        });
+        let block = block.copy();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, registerUndoWithTarget:target handler:block];
+        }
+    }
+
+    /// Sets the name of the most recently registered operation. This shows up in the Edit menu
+    /// (e.g, "Undo Change Color").
+    pub fn set_action_name(&self, name: &str) {
+        let name = NSString::new(name);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setActionName:name];
+        }
+    }
+
+    /// Returns the name of the operation that would be undone, if any.
+    pub fn undo_action_name(&self) -> String {
+        NSString::wrap(unsafe {
+            msg_send![&*self.objc, undoActionName]
+        }).to_string()
+    }
+
+    /// Returns the name of the operation that would be redone, if any.
+    pub fn redo_action_name(&self) -> String {
+        NSString::wrap(unsafe {
+            msg_send![&*self.objc, redoActionName]
+        }).to_string()
+    }
+
+    /// Begins a group; everything registered until `end_grouping()` is undone as one operation.
+    pub fn begin_grouping(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, beginUndoGrouping];
+        }
+    }
+
+    /// Ends a group started with `begin_grouping()`.
+    pub fn end_grouping(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, endUndoGrouping];
+        }
+    }
+
+    /// Shorthand for calling `handler` inside of a group, so everything it registers is undone
+    /// as one operation.
+    pub fn group<F: FnOnce(&UndoManager)>(&self, handler: F) {
+        self.begin_grouping();
+        handler(self);
+        self.end_grouping();
+    }
+
+    /// Undoes the last operation (or group).
+    pub fn undo(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, undo];
+        }
+    }
+
+    /// Redoes the last undone operation (or group).
+    pub fn redo(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, redo];
+        }
+    }
+
+    /// Returns whether there's anything to undo.
+    pub fn can_undo(&self) -> bool {
+        let result: BOOL = unsafe { msg_send![&*self.objc, canUndo] };
+
+        match result {
+            YES => true,
+            NO => false,
+            _ => unreachable!()
+        }
+    }
+
+    /// Returns whether there's anything to redo.
+    pub fn can_redo(&self) -> bool {
+        let result: BOOL = unsafe { msg_send![&*self.objc, canRedo] };
+
+        match result {
+            YES => true,
+            NO => false,
+            _ => unreachable!()
+        }
+    }
+
+    /// Returns whether the manager is currently undoing. Handy for knowing whether a change is
+    /// coming from the user or from an undo.
+    pub fn is_undoing(&self) -> bool {
+        let result: BOOL = unsafe { msg_send![&*self.objc, isUndoing] };
+
+        match result {
+            YES => true,
+            NO => false,
+            _ => unreachable!()
+        }
+    }
+
+    /// Returns whether the manager is currently redoing.
+    pub fn is_redoing(&self) -> bool {
+        let result: BOOL = unsafe { msg_send![&*self.objc, isRedoing] };
+
+        match result {
+            YES => true,
+            NO => false,
+            _ => unreachable!()
+        }
+    }
+
+    /// Sets the maximum number of operations (or groups) to keep. `0` means no limit.
+    pub fn set_levels_of_undo(&self, levels: usize) {
+        let levels = levels as NSUInteger;
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setLevelsOfUndo:levels];
+        }
+    }
+
+    /// Removes all operations registered with the given target.
+    pub fn remove_all_actions_with_target(&self, target: &Object) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, removeAllActionsWithTarget:target];
+        }
+    }
+
+    /// Clears both the undo and redo stacks.
+    pub fn remove_all_actions(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, removeAllActions];
+        }
+    }
+
+    /// Temporarily stops recording operations; useful when making changes that shouldn't be
+    /// undoable. Calls nest, and must be balanced with `enable_registration()`.
+    pub fn disable_registration(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, disableUndoRegistration];
+        }
+    }
+
+    /// Resumes recording operations after `disable_registration()`.
+    pub fn enable_registration(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, enableUndoRegistration];
+        }
+    }
+}
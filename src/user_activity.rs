@@ -1,9 +1,12 @@
 //! A module wrapping `NSUserActivity`.
 
+use std::fmt;
+
+use objc::{class, msg_send, sel, sel_impl};
 use objc::runtime::Object;
 use objc_id::ShareId;
 
-use crate::foundation::id;
+use crate::foundation::{id, nil, NSString};
 
 /// Represents an `NSUserActivity`, which acts as a lightweight method to capture the state of your
 /// app. 
@@ -12,10 +15,88 @@ pub struct UserActivity {
 }
 
 impl UserActivity {
+    /// Creates a new activity of the given type - by convention, a reverse-DNS string (e.g,
+    /// `com.example.app.editing`) that you've listed under `NSUserActivityTypes` in your
+    /// `Info.plist`.
+    pub fn new(activity_type: &str) -> Self {
+        let activity_type = NSString::new(activity_type);
+
+        UserActivity {
+            inner: unsafe {
+                let activity: id = msg_send![class!(NSUserActivity), alloc];
+                ShareId::from_retained_ptr(msg_send![activity, initWithActivityType:activity_type])
+            }
+        }
+    }
+
     /// An internal method for wrapping a system-provided activity.
     pub(crate) fn with_inner(object: id) -> Self {
         UserActivity {
             inner: unsafe { ShareId::from_ptr(object) }
         }
     }
+
+    /// Returns the type this activity was created with.
+    pub fn activity_type(&self) -> String {
+        NSString::wrap(unsafe {
+            msg_send![&*self.inner, activityType]
+        }).to_string()
+    }
+
+    /// Sets a user-visible title for this activity.
+    pub fn set_title(&self, title: &str) {
+        let title = NSString::new(title);
+
+        unsafe {
+            let _: () = msg_send![&*self.inner, setTitle:title];
+        }
+    }
+
+    /// Stores `value` under `key`, in the activity's `userInfo`. This is where you'd record what's
+    /// needed to restore a scene (e.g, the ID of the open document).
+    pub fn set_value(&self, key: &str, value: &str) {
+        let key = NSString::new(key);
+        let value = NSString::new(value);
+
+        unsafe {
+            let entries: id = msg_send![class!(NSDictionary), dictionaryWithObject:value forKey:key];
+            let _: () = msg_send![&*self.inner, addUserInfoEntriesFromDictionary:entries];
+        }
+    }
+
+    /// Returns the string stored under `key`, in the activity's `userInfo`, if there is one.
+    pub fn value(&self, key: &str) -> Option<String> {
+        let key = NSString::new(key);
+
+        let value: id = unsafe {
+            let user_info: id = msg_send![&*self.inner, userInfo];
+            if user_info == nil {
+                return None;
+            }
+
+            msg_send![user_info, objectForKey:key]
+        };
+
+        match value != nil && NSString::is(value) {
+            true => Some(NSString::wrap(value).to_string()),
+            false => None
+        }
+    }
+
+    /// Returns the activity with a reference that's autoreleased, for handing back to the system
+    /// from a callback.
+    pub(crate) fn into_autoreleased(self) -> id {
+        unsafe {
+            let activity: id = msg_send![&*self.inner, retain];
+            msg_send![activity, autorelease]
+        }
+    }
+}
+
+impl fmt::Debug for UserActivity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UserActivity")
+            .field("activity_type", &self.activity_type())
+            .finish()
+    }
 }
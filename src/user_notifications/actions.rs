@@ -0,0 +1,81 @@
+//! Wraps `UNNotificationAction` and `UNNotificationCategory`, which together let notifications
+//! carry action buttons (and text input). Register your categories once at launch via
+//! `NotificationCenter::set_categories()`, then tag notifications with a category identifier.
+
+use objc_id::Id;
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, NSArray, NSString, NSUInteger};
+use crate::user_notifications::enums::{NotificationActionOption, NotificationCategoryOption};
+
+/// A button (or text input) that's shown on a notification. The identifier is what you'll get
+/// back in `NotificationResponse` when the user picks it.
+#[derive(Debug)]
+pub struct NotificationAction(pub Id<Object>);
+
+impl NotificationAction {
+    /// Creates a new action button.
+    pub fn new(identifier: &str, title: &str, options: &[NotificationActionOption]) -> Self {
+        let identifier = NSString::new(identifier);
+        let title = NSString::new(title);
+        let options = options.iter().fold(0, |opts, option| opts | NSUInteger::from(option));
+
+        NotificationAction(unsafe {
+            Id::from_ptr(msg_send![class!(UNNotificationAction), actionWithIdentifier:identifier
+                title:title
+                options:options
+            ])
+        })
+    }
+
+    /// Creates a new action that, when picked, lets the user type a reply. The text is available
+    /// via `NotificationResponse::text`.
+    pub fn text_input(
+        identifier: &str,
+        title: &str,
+        button_title: &str,
+        placeholder: &str,
+        options: &[NotificationActionOption]
+    ) -> Self {
+        let identifier = NSString::new(identifier);
+        let title = NSString::new(title);
+        let button_title = NSString::new(button_title);
+        let placeholder = NSString::new(placeholder);
+        let options = options.iter().fold(0, |opts, option| opts | NSUInteger::from(option));
+
+        NotificationAction(unsafe {
+            Id::from_ptr(msg_send![class!(UNTextInputNotificationAction), actionWithIdentifier:identifier
+                title:title
+                options:options
+                textInputButtonTitle:button_title
+                textInputPlaceholder:placeholder
+            ])
+        })
+    }
+}
+
+/// A group of actions, under an identifier. Notifications with a matching category identifier
+/// (see `Notification::set_category()`) show these actions.
+#[derive(Debug)]
+pub struct NotificationCategory(pub Id<Object>);
+
+impl NotificationCategory {
+    /// Creates a new category with the given actions.
+    pub fn new(identifier: &str, actions: Vec<NotificationAction>, options: &[NotificationCategoryOption]) -> Self {
+        let identifier = NSString::new(identifier);
+        let actions: NSArray = actions.iter().map(|action| {
+            &*action.0 as *const Object as id
+        }).collect::<Vec<id>>().into();
+        let intents = NSArray::new(&[]);
+        let options = options.iter().fold(0, |opts, option| opts | NSUInteger::from(option));
+
+        NotificationCategory(unsafe {
+            Id::from_ptr(msg_send![class!(UNNotificationCategory), categoryWithIdentifier:identifier
+                actions:&*actions.0
+                intentIdentifiers:&*intents.0
+                options:options
+            ])
+        })
+    }
+}
@@ -0,0 +1,52 @@
+//! Injects an `NSObject` subclass that conforms to `UNUserNotificationCenterDelegate`, and loops
+//! calls back to a `UserNotificationDelegate`.
+
+
+use block::Block;
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, sel, sel_impl};
+
+use crate::foundation::{id, NSUInteger};
+use crate::user_notifications::{USER_NOTIFICATION_DELEGATE_PTR, UserNotificationDelegate};
+use crate::user_notifications::response::{NotificationResponse, ReceivedNotification};
+use crate::utils::load_or_register_class_for;
+
+/// Called when a notification is about to be presented while the app is in the foreground.
+extern fn will_present<T: UserNotificationDelegate>(this: &Object, _: Sel, _: id, notification: id, handler: id) {
+    let delegate = load_or_return!(T, this, USER_NOTIFICATION_DELEGATE_PTR);
+    let options = delegate.will_present(ReceivedNotification::new(notification))
+        .iter()
+        .fold(0, |opts, option| opts | NSUInteger::from(option));
+
+    let handler = handler as *mut Block<(NSUInteger,), ()>;
+
+    unsafe {
+        (*handler).call((options,));
+    }
+}
+
+/// Called when the user has responded to a notification.
+extern fn did_receive_response<T: UserNotificationDelegate>(this: &Object, _: Sel, _: id, response: id, handler: id) {
+    let delegate = load_or_return!(T, this, USER_NOTIFICATION_DELEGATE_PTR);
+    delegate.did_receive_response(NotificationResponse::new(response));
+
+    let handler = handler as *mut Block<(), ()>;
+
+    unsafe {
+        (*handler).call(());
+    }
+}
+
+/// Registers a `UNUserNotificationCenterDelegate` subclass for the given delegate type.
+pub(crate) fn register_user_notification_delegate_class<T: UserNotificationDelegate + 'static>() -> *const Class {
+    unsafe {
+        let superclass = class!(NSObject);
+        load_or_register_class_for::<T, _>(superclass, "RSTUserNotificationCenterDelegate", |decl| {
+            decl.add_ivar::<usize>(USER_NOTIFICATION_DELEGATE_PTR);
+
+            decl.add_method(sel!(userNotificationCenter:willPresentNotification:withCompletionHandler:), will_present::<T> as extern fn(&Object, _, _, id, id));
+            decl.add_method(sel!(userNotificationCenter:didReceiveNotificationResponse:withCompletionHandler:), did_receive_response::<T> as extern fn(&Object, _, _, id, id));
+        })
+    }
+}
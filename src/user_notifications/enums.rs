@@ -27,3 +27,73 @@ impl From<&NotificationAuthOption> for NSUInteger {
         }
     }
 }
+
+/// Options for a `NotificationAction`, which affect how the system treats it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NotificationActionOption {
+    /// The device must be unlocked before the action is performed (iOS).
+    AuthenticationRequired,
+
+    /// The action is displayed as destructive (e.g, in red).
+    Destructive,
+
+    /// Performing the action launches (or brings forward) your app.
+    Foreground
+}
+
+impl From<&NotificationActionOption> for NSUInteger {
+    fn from(option: &NotificationActionOption) -> Self {
+        match option {
+            NotificationActionOption::AuthenticationRequired => 1 << 0,
+            NotificationActionOption::Destructive => 1 << 1,
+            NotificationActionOption::Foreground => 1 << 2
+        }
+    }
+}
+
+/// Options for a `NotificationCategory`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NotificationCategoryOption {
+    /// Deliver a response (with `NotificationResponseAction::Dismiss`) when the user dismisses a
+    /// notification of this category. By default, dismissals aren't reported.
+    CustomDismissAction,
+
+    /// Allow notifications of this category to show in CarPlay (iOS).
+    AllowInCarPlay
+}
+
+impl From<&NotificationCategoryOption> for NSUInteger {
+    fn from(option: &NotificationCategoryOption) -> Self {
+        match option {
+            NotificationCategoryOption::CustomDismissAction => 1 << 0,
+            NotificationCategoryOption::AllowInCarPlay => 1 << 1
+        }
+    }
+}
+
+/// Controls how a notification is presented when it arrives while your app is in the foreground.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NotificationPresentationOption {
+    /// Update the app badge.
+    Badge,
+
+    /// Play the notification's sound.
+    Sound,
+
+    /// Show the notification as a banner.
+    Banner,
+
+    /// Show the notification in Notification Center.
+    List
+}
+
+impl From<&NotificationPresentationOption> for NSUInteger {
+    fn from(option: &NotificationPresentationOption) -> Self {
+        match option {
+            NotificationPresentationOption::Badge => 1 << 0,
+            NotificationPresentationOption::Sound => 1 << 1,
+            NotificationPresentationOption::List => 1 << 3,
+            NotificationPresentationOption::Banner => 1 << 4
+        }
+    }
+}
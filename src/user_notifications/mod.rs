@@ -6,17 +6,38 @@
 
 use block::ConcreteBlock;
 
+use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
 use uuid::Uuid;
 
-use crate::foundation::{id, nil, NSString, NSUInteger};
+use crate::foundation::{id, nil, NSArray, NSString, NSUInteger};
+
+pub mod actions;
+pub use actions::{NotificationAction, NotificationCategory};
+
+mod class;
+use class::register_user_notification_delegate_class;
 
 pub mod enums;
-pub use enums::NotificationAuthOption;
+pub use enums::{
+    NotificationAuthOption, NotificationActionOption,
+    NotificationCategoryOption, NotificationPresentationOption
+};
 
 pub mod notifications;
 pub use notifications::Notification;
 
+pub mod response;
+pub use response::{NotificationResponse, NotificationResponseAction, ReceivedNotification};
+
+pub mod traits;
+pub use traits::UserNotificationDelegate;
+
+pub mod triggers;
+pub use triggers::{CalendarComponents, NotificationTrigger};
+
+pub(crate) static USER_NOTIFICATION_DELEGATE_PTR: &str = "rstUserNotificationDelegatePtr";
+
 /// Acts as a central interface to the Notification Center on macOS.
 pub struct NotificationCenter;
 
@@ -44,6 +65,38 @@ impl NotificationCenter {
         }
     }
 
+    /// Registers the categories (and thus, the action buttons) your notifications can use. This
+    /// replaces any previously registered categories, so pass all of them at once - ideally, at
+    /// launch.
+    pub fn set_categories(categories: &[NotificationCategory]) {
+        let categories: NSArray = categories.iter().map(|category| {
+            &*category.0 as *const Object as id
+        }).collect::<Vec<id>>().into();
+
+        unsafe {
+            let set: id = msg_send![class!(NSSet), setWithArray:&*categories.0];
+            let center: id = msg_send![class!(UNUserNotificationCenter), currentNotificationCenter];
+            let _: () = msg_send![center, setNotificationCategories:set];
+        }
+    }
+
+    /// Sets the delegate that handles foreground presentation and user responses. The system
+    /// needs this for the lifetime of your app, so the delegate is intentionally leaked - call
+    /// this once, before your app finishes launching (e.g, in `will_finish_launching`), so that
+    /// responses which launched your app are delivered.
+    pub fn set_delegate<T: UserNotificationDelegate + 'static>(delegate: T) {
+        let delegate: &'static T = Box::leak(Box::new(delegate));
+
+        unsafe {
+            let objc: id = msg_send![register_user_notification_delegate_class::<T>(), new];
+            let ptr: *const T = delegate;
+            (&mut *objc).set_ivar(USER_NOTIFICATION_DELEGATE_PTR, ptr as usize);
+
+            let center: id = msg_send![class!(UNUserNotificationCenter), currentNotificationCenter];
+            let _: () = msg_send![center, setDelegate:objc];
+        }
+    }
+
     /// Queues up a `Notification` to be displayed to the user.
     pub fn notify(notification: Notification) {
         let uuidentifier = format!("{}", Uuid::new_v4());
@@ -56,6 +109,90 @@ impl NotificationCenter {
         }
     }
 
+    /// Schedules a `Notification` to be delivered when `trigger` fires. Scheduling with the same
+    /// identifier as a pending notification replaces it; you can also use the identifier to
+    /// remove it later via `remove_pending_notifications()`.
+    pub fn schedule(identifier: &str, notification: Notification, trigger: NotificationTrigger) {
+        let identifier = NSString::new(identifier);
+
+        unsafe {
+            let request: id = msg_send![class!(UNNotificationRequest), requestWithIdentifier:identifier
+                content:&*notification.0
+                trigger:trigger.to_objc()
+            ];
+
+            let center: id = msg_send![class!(UNUserNotificationCenter), currentNotificationCenter];
+            let _: () = msg_send![center, addNotificationRequest:request withCompletionHandler:nil];
+        }
+    }
+
+    /// Fetches the notifications that are scheduled but haven't been delivered yet. `handler` is
+    /// called on a background queue.
+    pub fn pending_notifications<F>(handler: F)
+    where
+        F: Fn(Vec<ReceivedNotification>) + Send + Sync + 'static
+    {
+        let block = ConcreteBlock::new(move |requests: id| {
+            let requests = NSArray::wrap(requests).map(|request| ReceivedNotification::from_request(request));
+            handler(requests);
+        });
+
+        unsafe {
+            let center: id = msg_send![class!(UNUserNotificationCenter), currentNotificationCenter];
+            let _: () = msg_send![center, getPendingNotificationRequestsWithCompletionHandler:block.copy()];
+        }
+    }
+
+    /// Fetches the notifications that have been delivered and are still showing in Notification
+    /// Center. `handler` is called on a background queue.
+    pub fn delivered_notifications<F>(handler: F)
+    where
+        F: Fn(Vec<ReceivedNotification>) + Send + Sync + 'static
+    {
+        let block = ConcreteBlock::new(move |notifications: id| {
+            let notifications = NSArray::wrap(notifications).map(|notification| ReceivedNotification::new(notification));
+            handler(notifications);
+        });
+
+        unsafe {
+            let center: id = msg_send![class!(UNUserNotificationCenter), currentNotificationCenter];
+            let _: () = msg_send![center, getDeliveredNotificationsWithCompletionHandler:block.copy()];
+        }
+    }
+
+    /// Removes the scheduled notifications with the given identifiers.
+    pub fn remove_pending_notifications(identifiers: &[&str]) {
+        let identifiers: NSArray = identifiers.iter().map(|identifier| {
+            NSString::new(identifier).into_inner()
+        }).collect::<Vec<id>>().into();
+
+        unsafe {
+            let center: id = msg_send![class!(UNUserNotificationCenter), currentNotificationCenter];
+            let _: () = msg_send![center, removePendingNotificationRequestsWithIdentifiers:&*identifiers.0];
+        }
+    }
+
+    /// Removes all scheduled notifications.
+    pub fn remove_all_pending_notifications() {
+        unsafe {
+            let center: id = msg_send![class!(UNUserNotificationCenter), currentNotificationCenter];
+            let _: () = msg_send![center, removeAllPendingNotificationRequests];
+        }
+    }
+
+    /// Removes the delivered notifications (e.g, in Notification Center) with the given
+    /// identifiers.
+    pub fn remove_delivered_notifications(identifiers: &[&str]) {
+        let identifiers: NSArray = identifiers.iter().map(|identifier| {
+            NSString::new(identifier).into_inner()
+        }).collect::<Vec<id>>().into();
+
+        unsafe {
+            let center: id = msg_send![class!(UNUserNotificationCenter), currentNotificationCenter];
+            let _: () = msg_send![center, removeDeliveredNotificationsWithIdentifiers:&*identifiers.0];
+        }
+    }
+
     /// Removes all notifications that have been delivered (e.g, in the notification center).
     pub fn remove_all_delivered_notifications() {
         unsafe {
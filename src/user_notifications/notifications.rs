@@ -1,11 +1,21 @@
 //! Acts as a (currently dumb) wrapper for `UNMutableNotificationContent`, which is what you mostly
 //! need to pass to the notification center for things to work.
 
+use std::collections::HashMap;
+use std::path::Path;
+
 use objc_id::Id;
 use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::{id, NSString};
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::foundation::{id, nil, NSDictionary, NSString};
+
+#[cfg(feature = "serde")]
+use crate::user_notifications::response::PAYLOAD_KEY;
 
 /// A wrapper for `UNMutableNotificationContent`. Retains the pointer from the Objective C side,
 /// and is ultimately dropped upon sending.
@@ -25,4 +35,92 @@ impl Notification {
             Id::from_ptr(content)
         })
     }
+
+    /// Sets the category identifier for this notification, which determines which actions
+    /// (buttons) it shows. See `NotificationCenter::set_categories()`.
+    pub fn set_category(&mut self, identifier: &str) {
+        let identifier = NSString::new(identifier);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setCategoryIdentifier:identifier];
+        }
+    }
+
+    /// Sets the thread identifier, which the system uses to group related notifications (e.g, all
+    /// messages in a conversation).
+    pub fn set_thread_identifier(&mut self, identifier: &str) {
+        let identifier = NSString::new(identifier);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setThreadIdentifier:identifier];
+        }
+    }
+
+    /// Sets custom data on this notification, which comes back to you in
+    /// `ReceivedNotification::user_info`. This replaces any previously set data, including a
+    /// payload set via `set_payload()`.
+    pub fn set_user_info(&mut self, user_info: HashMap<String, String>) {
+        let mut dictionary = NSDictionary::new();
+
+        for (key, value) in user_info.iter() {
+            dictionary.insert(NSString::new(key), NSString::new(value).into_inner());
+        }
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setUserInfo:&*dictionary.0];
+        }
+    }
+
+    /// Attaches a typed payload to this notification, which you can get back via
+    /// `ReceivedNotification::payload()`. It's stored as JSON in `userInfo`, alongside anything
+    /// set via `set_user_info()` - so call this after that, if you use both.
+    #[cfg(feature = "serde")]
+    pub fn set_payload<T: Serialize>(&mut self, payload: &T) -> Result<(), serde_json::Error> {
+        let json = serde_json::to_string(payload)?;
+
+        unsafe {
+            let existing: id = msg_send![&*self.0, userInfo];
+            let user_info: id = msg_send![class!(NSMutableDictionary), dictionaryWithDictionary:existing];
+            let key = NSString::new(PAYLOAD_KEY);
+            let json = NSString::new(&json);
+            let _: () = msg_send![user_info, setObject:json forKey:key];
+            let _: () = msg_send![&*self.0, setUserInfo:user_info];
+        }
+
+        Ok(())
+    }
+
+    /// Attaches a file (an image, audio clip, or video) to this notification, which the system
+    /// displays alongside it. The file must be a supported type, and the system moves it into its
+    /// own storage once the notification is scheduled - so pass a copy if you need to keep it.
+    ///
+    /// Returns an error if the file can't be attached (e.g, unsupported type, or too large).
+    pub fn add_attachment<P: AsRef<Path>>(&mut self, identifier: &str, path: P) -> Result<(), Error> {
+        let identifier = NSString::new(identifier);
+        let path = NSString::new(&path.as_ref().to_string_lossy());
+
+        unsafe {
+            let url: id = msg_send![class!(NSURL), fileURLWithPath:path];
+            let mut error: id = nil;
+            let attachment: id = msg_send![class!(UNNotificationAttachment), attachmentWithIdentifier:identifier
+                URL:url
+                options:nil
+                error:&mut error
+            ];
+
+            if attachment == nil {
+                return Err(Error::new(error));
+            }
+
+            let attachments: id = msg_send![&*self.0, attachments];
+            let attachments: id = match attachments == nil {
+                true => msg_send![class!(NSArray), arrayWithObject:attachment],
+                false => msg_send![attachments, arrayByAddingObject:attachment]
+            };
+
+            let _: () = msg_send![&*self.0, setAttachments:attachments];
+        }
+
+        Ok(())
+    }
 }
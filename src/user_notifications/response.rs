@@ -0,0 +1,158 @@
+//! Types handed to your `UserNotificationDelegate` when a notification arrives, or when the user
+//! acts on one.
+
+use std::collections::HashMap;
+
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, YES, NO, BOOL, NSArray, NSString};
+
+#[cfg(feature = "serde")]
+use serde::de::DeserializeOwned;
+
+/// The key that typed payloads are stored under in `userInfo`.
+pub(crate) static PAYLOAD_KEY: &str = "CacaoPayload";
+
+/// `UNNotificationDefaultActionIdentifier`.
+static DEFAULT_ACTION_IDENTIFIER: &str = "com.apple.UNNotificationDefaultActionIdentifier";
+
+/// `UNNotificationDismissActionIdentifier`.
+static DISMISS_ACTION_IDENTIFIER: &str = "com.apple.UNNotificationDismissActionIdentifier";
+
+/// Reads the string keys and values out of an `NSDictionary`. Anything that isn't a string is
+/// skipped.
+fn string_map(dictionary: id) -> HashMap<String, String> {
+    let mut map = HashMap::new();
+
+    if dictionary == nil {
+        return map;
+    }
+
+    let keys = NSArray::wrap(unsafe { msg_send![dictionary, allKeys] });
+
+    for key in keys.map(|key| key) {
+        let value: id = unsafe { msg_send![dictionary, objectForKey:key] };
+
+        if NSString::is(key) && NSString::is(value) {
+            map.insert(NSString::wrap(key).to_string(), NSString::wrap(value).to_string());
+        }
+    }
+
+    map
+}
+
+/// Information about a notification - either one that's been delivered, or (when listing pending
+/// notifications) one that's scheduled.
+#[derive(Clone, Debug)]
+pub struct ReceivedNotification {
+    /// The identifier of the request this notification was delivered for.
+    pub identifier: String,
+
+    /// The category identifier, if one was set (otherwise, an empty string).
+    pub category: String,
+
+    /// The thread identifier, if one was set (otherwise, an empty string).
+    pub thread: String,
+
+    /// The title.
+    pub title: String,
+
+    /// The body.
+    pub body: String,
+
+    /// Any string values stored in the notification's `userInfo`.
+    pub user_info: HashMap<String, String>
+}
+
+impl ReceivedNotification {
+    /// Pulls the relevant information out of a `UNNotification`.
+    pub(crate) fn new(notification: id) -> Self {
+        ReceivedNotification::from_request(unsafe {
+            msg_send![notification, request]
+        })
+    }
+
+    /// Pulls the relevant information out of a `UNNotificationRequest`. Pending notifications
+    /// haven't been delivered yet, but are described the same way.
+    pub(crate) fn from_request(request: id) -> Self {
+        unsafe {
+            let content: id = msg_send![request, content];
+
+            ReceivedNotification {
+                identifier: NSString::wrap(msg_send![request, identifier]).to_string(),
+                category: NSString::wrap(msg_send![content, categoryIdentifier]).to_string(),
+                thread: NSString::wrap(msg_send![content, threadIdentifier]).to_string(),
+                title: NSString::wrap(msg_send![content, title]).to_string(),
+                body: NSString::wrap(msg_send![content, body]).to_string(),
+                user_info: string_map(msg_send![content, userInfo])
+            }
+        }
+    }
+
+    /// Decodes the typed payload set via `Notification::set_payload()`. Returns `None` if there
+    /// isn't one, or if it doesn't deserialize into `T`.
+    #[cfg(feature = "serde")]
+    pub fn payload<T: DeserializeOwned>(&self) -> Option<T> {
+        self.user_info.get(PAYLOAD_KEY).and_then(|json| serde_json::from_str(json).ok())
+    }
+}
+
+/// Which action the user took on a notification.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NotificationResponseAction {
+    /// The user clicked (or tapped) the notification itself.
+    Default,
+
+    /// The user dismissed the notification. This is only reported for categories with
+    /// `NotificationCategoryOption::CustomDismissAction`.
+    Dismiss,
+
+    /// The user picked one of your `NotificationAction`s; this holds its identifier.
+    Custom(String)
+}
+
+impl From<&str> for NotificationResponseAction {
+    fn from(identifier: &str) -> Self {
+        match identifier {
+            i if i == DEFAULT_ACTION_IDENTIFIER => NotificationResponseAction::Default,
+            i if i == DISMISS_ACTION_IDENTIFIER => NotificationResponseAction::Dismiss,
+            i => NotificationResponseAction::Custom(i.to_string())
+        }
+    }
+}
+
+/// Describes how the user responded to a notification.
+#[derive(Clone, Debug)]
+pub struct NotificationResponse {
+    /// The action the user took.
+    pub action: NotificationResponseAction,
+
+    /// The notification that was acted on.
+    pub notification: ReceivedNotification,
+
+    /// If the action was a text input action, the text the user entered.
+    pub text: Option<String>
+}
+
+impl NotificationResponse {
+    /// Pulls the relevant information out of a `UNNotificationResponse`.
+    pub(crate) fn new(response: id) -> Self {
+        unsafe {
+            let action = NSString::wrap(msg_send![response, actionIdentifier]);
+            let notification: id = msg_send![response, notification];
+
+            let is_text_input: BOOL = msg_send![response, isKindOfClass:class!(UNTextInputNotificationResponse)];
+            let text = match is_text_input {
+                YES => Some(NSString::wrap(msg_send![response, userText]).to_string()),
+                NO => None,
+                _ => unreachable!()
+            };
+
+            NotificationResponse {
+                action: action.to_str().into(),
+                notification: ReceivedNotification::new(notification),
+                text: text
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! The delegate trait for handling notifications that arrive while your app is running, along
+//! with the user's responses to them.
+
+use crate::user_notifications::enums::NotificationPresentationOption;
+use crate::user_notifications::response::{NotificationResponse, ReceivedNotification};
+
+/// Implement this and pass it to `NotificationCenter::set_delegate()` to respond to notification
+/// events. These map to `UNUserNotificationCenterDelegate`.
+pub trait UserNotificationDelegate {
+    /// Fired when a notification arrives while your app is in the foreground. Return how (if at
+    /// all) it should be presented; the default returns nothing, which means the notification is
+    /// delivered silently.
+    fn will_present(&self, _notification: ReceivedNotification) -> Vec<NotificationPresentationOption> {
+        vec![]
+    }
+
+    /// Fired when the user acts on a notification - clicking it, picking one of its actions, or
+    /// replying via a text input action.
+    fn did_receive_response(&self, _response: NotificationResponse) {}
+}
@@ -0,0 +1,212 @@
+//! Triggers control when a scheduled notification is delivered. See
+//! `NotificationCenter::schedule()`.
+
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, YES, NO, NSInteger};
+
+#[cfg(target_os = "ios")]
+use objc::{Encode, Encoding};
+
+#[cfg(target_os = "ios")]
+use crate::foundation::NSString;
+
+/// A set of date components to match against for `NotificationTrigger::Calendar`. Any component
+/// left as `None` matches anything - e.g, setting only `hour` and `minute` fires every day at
+/// that time (if the trigger repeats).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CalendarComponents {
+    /// The year.
+    pub year: Option<i64>,
+
+    /// The month, from `1` to `12`.
+    pub month: Option<i64>,
+
+    /// The day of the month.
+    pub day: Option<i64>,
+
+    /// The day of the week, from `1` (Sunday) to `7` (Saturday).
+    pub weekday: Option<i64>,
+
+    /// The hour, from `0` to `23`.
+    pub hour: Option<i64>,
+
+    /// The minute.
+    pub minute: Option<i64>,
+
+    /// The second.
+    pub second: Option<i64>
+}
+
+impl CalendarComponents {
+    /// Builds an `NSDateComponents` instance from these components.
+    fn to_objc(&self) -> id {
+        unsafe {
+            let components: id = msg_send![class!(NSDateComponents), new];
+            let _: () = msg_send![components, autorelease];
+
+            if let Some(year) = self.year {
+                let _: () = msg_send![components, setYear:year as NSInteger];
+            }
+
+            if let Some(month) = self.month {
+                let _: () = msg_send![components, setMonth:month as NSInteger];
+            }
+
+            if let Some(day) = self.day {
+                let _: () = msg_send![components, setDay:day as NSInteger];
+            }
+
+            if let Some(weekday) = self.weekday {
+                let _: () = msg_send![components, setWeekday:weekday as NSInteger];
+            }
+
+            if let Some(hour) = self.hour {
+                let _: () = msg_send![components, setHour:hour as NSInteger];
+            }
+
+            if let Some(minute) = self.minute {
+                let _: () = msg_send![components, setMinute:minute as NSInteger];
+            }
+
+            if let Some(second) = self.second {
+                let _: () = msg_send![components, setSecond:second as NSInteger];
+            }
+
+            components
+        }
+    }
+}
+
+/// Describes when a notification should be delivered.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NotificationTrigger {
+    /// Deliver immediately.
+    Immediately,
+
+    /// Deliver after a number of seconds. Repeating triggers must be at least 60 seconds apart.
+    TimeInterval {
+        /// How long to wait, in seconds.
+        seconds: f64,
+
+        /// Whether to keep delivering at this interval.
+        repeats: bool
+    },
+
+    /// Deliver when the current date matches the given components.
+    Calendar {
+        /// The components to match.
+        components: CalendarComponents,
+
+        /// Whether to deliver every time the components match, or just the first time.
+        repeats: bool
+    },
+
+    /// Deliver when the device enters and/or exits a circular region. This requires location
+    /// authorization, which you'll need to request (via CoreLocation) yourself.
+    #[cfg(target_os = "ios")]
+    Location {
+        /// An identifier for the region.
+        identifier: String,
+
+        /// The latitude of the center of the region.
+        latitude: f64,
+
+        /// The longitude of the center of the region.
+        longitude: f64,
+
+        /// The radius of the region, in meters.
+        radius: f64,
+
+        /// Whether to deliver on entering the region.
+        on_entry: bool,
+
+        /// Whether to deliver on exiting the region.
+        on_exit: bool,
+
+        /// Whether to deliver every time, or just the first time.
+        repeats: bool
+    }
+}
+
+impl Default for NotificationTrigger {
+    fn default() -> Self {
+        NotificationTrigger::Immediately
+    }
+}
+
+/// `CLLocationCoordinate2D`.
+#[cfg(target_os = "ios")]
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+struct CLLocationCoordinate2D {
+    latitude: f64,
+    longitude: f64
+}
+
+#[cfg(target_os = "ios")]
+unsafe impl Encode for CLLocationCoordinate2D {
+    fn encode() -> Encoding {
+        let encoding = format!("{{CLLocationCoordinate2D={}{}}}",
+            f64::encode().as_str(),
+            f64::encode().as_str()
+        );
+
+        unsafe { Encoding::from_str(&encoding) }
+    }
+}
+
+impl NotificationTrigger {
+    /// Builds the appropriate `UNNotificationTrigger` subclass, or `nil` for `Immediately`.
+    pub(crate) fn to_objc(&self) -> id {
+        match self {
+            NotificationTrigger::Immediately => nil,
+
+            NotificationTrigger::TimeInterval { seconds, repeats } => unsafe {
+                msg_send![class!(UNTimeIntervalNotificationTrigger), triggerWithTimeInterval:*seconds
+                    repeats:match repeats {
+                        true => YES,
+                        false => NO
+                    }
+                ]
+            },
+
+            NotificationTrigger::Calendar { components, repeats } => unsafe {
+                msg_send![class!(UNCalendarNotificationTrigger), triggerWithDateMatchingComponents:components.to_objc()
+                    repeats:match repeats {
+                        true => YES,
+                        false => NO
+                    }
+                ]
+            },
+
+            #[cfg(target_os = "ios")]
+            NotificationTrigger::Location { identifier, latitude, longitude, radius, on_entry, on_exit, repeats } => unsafe {
+                let identifier = NSString::new(identifier);
+                let center = CLLocationCoordinate2D {
+                    latitude: *latitude,
+                    longitude: *longitude
+                };
+
+                let region: id = msg_send![class!(CLCircularRegion), alloc];
+                let region: id = msg_send![region, initWithCenter:center radius:*radius identifier:identifier];
+                let _: () = msg_send![region, autorelease];
+                let _: () = msg_send![region, setNotifyOnEntry:match on_entry {
+                    true => YES,
+                    false => NO
+                }];
+                let _: () = msg_send![region, setNotifyOnExit:match on_exit {
+                    true => YES,
+                    false => NO
+                }];
+
+                msg_send![class!(UNLocationNotificationTrigger), triggerWithRegion:region
+                    repeats:match repeats {
+                        true => YES,
+                        false => NO
+                    }
+                ]
+            }
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//! Uniform Type Identifiers, which is how the system describes the type of a file or piece of
+//! data (e.g, `public.png`, or `com.adobe.pdf`). These are used throughout the framework -
+//! open and save panels, the document picker, pasteboards and drag and drop.
+//!
+//! ```rust,no_run
+//! use cacao::uti::UTType;
+//!
+//! let png = UTType::from_extension("png").unwrap();
+//! assert_eq!(png, UTType::PNG);
+//! assert!(png.conforms_to(&UTType::IMAGE));
+//! assert_eq!(png.preferred_mime_type().as_deref(), Some("image/png"));
+//! ```
+//!
+//! Types are identified by string, so comparing and hashing them is cheap; anything that needs
+//! to ask the system (conformance, tags, descriptions) goes through CoreServices.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::path::Path;
+
+use core_foundation::base::TCFType;
+use core_foundation::string::{CFString, CFStringRef};
+
+use objc::{class, msg_send, sel, sel_impl};
+use objc::runtime::{Class, Object};
+
+use crate::foundation::{id, nil, BOOL, YES, NSArray, NSString};
+
+/// `kUTTagClassFilenameExtension`.
+static TAG_CLASS_FILENAME_EXTENSION: &str = "public.filename-extension";
+
+/// `kUTTagClassMIMEType`.
+static TAG_CLASS_MIME_TYPE: &str = "public.mime-type";
+
+extern "C" {
+    fn UTTypeCreatePreferredIdentifierForTag(tag_class: CFStringRef, tag: CFStringRef, conforming_to: CFStringRef) -> CFStringRef;
+    fn UTTypeCopyPreferredTagWithClass(uti: CFStringRef, tag_class: CFStringRef) -> CFStringRef;
+    fn UTTypeConformsTo(uti: CFStringRef, conforms_to: CFStringRef) -> u8;
+    fn UTTypeCopyDescription(uti: CFStringRef) -> CFStringRef;
+    fn UTTypeIsDynamic(uti: CFStringRef) -> u8;
+}
+
+/// Wraps a `CFStringRef` returned under the create rule, which may be null.
+fn string(string: CFStringRef) -> Option<String> {
+    match string.is_null() {
+        true => None,
+        false => Some(unsafe { CFString::wrap_under_create_rule(string) }.to_string())
+    }
+}
+
+/// A Uniform Type Identifier.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UTType(Cow<'static, str>);
+
+impl UTType {
+    /// The base type for all files and directories (`public.item`).
+    pub const ITEM: UTType = UTType(Cow::Borrowed("public.item"));
+
+    /// The base type for all document content (`public.content`).
+    pub const CONTENT: UTType = UTType(Cow::Borrowed("public.content"));
+
+    /// Any stream of bytes - files, pasteboard data, and so on (`public.data`).
+    pub const DATA: UTType = UTType(Cow::Borrowed("public.data"));
+
+    /// A directory (`public.folder`).
+    pub const FOLDER: UTType = UTType(Cow::Borrowed("public.folder"));
+
+    /// An application bundle (`com.apple.application-bundle`).
+    pub const APPLICATION_BUNDLE: UTType = UTType(Cow::Borrowed("com.apple.application-bundle"));
+
+    /// Any text (`public.text`).
+    pub const TEXT: UTType = UTType(Cow::Borrowed("public.text"));
+
+    /// Text without markup (`public.plain-text`).
+    pub const PLAIN_TEXT: UTType = UTType(Cow::Borrowed("public.plain-text"));
+
+    /// UTF-8 encoded plain text (`public.utf8-plain-text`).
+    pub const UTF8_PLAIN_TEXT: UTType = UTType(Cow::Borrowed("public.utf8-plain-text"));
+
+    /// UTF-8 encoded tab-separated text (`public.utf8-tab-separated-values-text`).
+    pub const TAB_SEPARATED_TEXT: UTType = UTType(Cow::Borrowed("public.utf8-tab-separated-values-text"));
+
+    /// Comma-separated text (`public.comma-separated-values-text`).
+    pub const COMMA_SEPARATED_TEXT: UTType = UTType(Cow::Borrowed("public.comma-separated-values-text"));
+
+    /// Rich Text Format (`public.rtf`).
+    pub const RTF: UTType = UTType(Cow::Borrowed("public.rtf"));
+
+    /// Rich Text Format with attachments, flattened (`com.apple.flat-rtfd`).
+    pub const FLAT_RTFD: UTType = UTType(Cow::Borrowed("com.apple.flat-rtfd"));
+
+    /// HTML (`public.html`).
+    pub const HTML: UTType = UTType(Cow::Borrowed("public.html"));
+
+    /// XML (`public.xml`).
+    pub const XML: UTType = UTType(Cow::Borrowed("public.xml"));
+
+    /// JSON (`public.json`).
+    pub const JSON: UTType = UTType(Cow::Borrowed("public.json"));
+
+    /// Source code of any language (`public.source-code`).
+    pub const SOURCE_CODE: UTType = UTType(Cow::Borrowed("public.source-code"));
+
+    /// A URL (`public.url`).
+    pub const URL: UTType = UTType(Cow::Borrowed("public.url"));
+
+    /// A `file://` URL (`public.file-url`).
+    pub const FILE_URL: UTType = UTType(Cow::Borrowed("public.file-url"));
+
+    /// Any image (`public.image`).
+    pub const IMAGE: UTType = UTType(Cow::Borrowed("public.image"));
+
+    /// A PNG image (`public.png`).
+    pub const PNG: UTType = UTType(Cow::Borrowed("public.png"));
+
+    /// A JPEG image (`public.jpeg`).
+    pub const JPEG: UTType = UTType(Cow::Borrowed("public.jpeg"));
+
+    /// A GIF image (`com.compuserve.gif`).
+    pub const GIF: UTType = UTType(Cow::Borrowed("com.compuserve.gif"));
+
+    /// A TIFF image (`public.tiff`).
+    pub const TIFF: UTType = UTType(Cow::Borrowed("public.tiff"));
+
+    /// A HEIC image (`public.heic`).
+    pub const HEIC: UTType = UTType(Cow::Borrowed("public.heic"));
+
+    /// An SVG image (`public.svg-image`).
+    pub const SVG: UTType = UTType(Cow::Borrowed("public.svg-image"));
+
+    /// A PDF document (`com.adobe.pdf`).
+    pub const PDF: UTType = UTType(Cow::Borrowed("com.adobe.pdf"));
+
+    /// Any audiovisual content (`public.audiovisual-content`).
+    pub const AUDIOVISUAL_CONTENT: UTType = UTType(Cow::Borrowed("public.audiovisual-content"));
+
+    /// Any video (`public.movie`).
+    pub const MOVIE: UTType = UTType(Cow::Borrowed("public.movie"));
+
+    /// Any audio (`public.audio`).
+    pub const AUDIO: UTType = UTType(Cow::Borrowed("public.audio"));
+
+    /// A zip archive (`public.zip-archive`).
+    pub const ZIP: UTType = UTType(Cow::Borrowed("public.zip-archive"));
+
+    /// Creates a type with the given identifier (e.g, `com.example.myapp.document`).
+    pub fn new(identifier: &str) -> Self {
+        UTType(Cow::Owned(identifier.to_string()))
+    }
+
+    /// Looks up the type for a tag (e.g, a file extension or MIME type), skipping dynamic
+    /// (`dyn.*`) types the system makes up for tags it doesn't know.
+    fn from_tag(tag_class: &str, tag: &str) -> Option<Self> {
+        let tag_class = CFString::new(tag_class);
+        let tag = CFString::new(tag);
+
+        let identifier = string(unsafe {
+            UTTypeCreatePreferredIdentifierForTag(tag_class.as_concrete_TypeRef(), tag.as_concrete_TypeRef(), std::ptr::null())
+        })?;
+
+        let uti = UTType::new(&identifier);
+
+        match uti.is_dynamic() {
+            true => None,
+            false => Some(uti)
+        }
+    }
+
+    /// Returns the type for a file extension (without the leading `.`), if the system knows it.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        UTType::from_tag(TAG_CLASS_FILENAME_EXTENSION, extension)
+    }
+
+    /// Returns the type for a MIME type (e.g, `image/png`), if the system knows it.
+    pub fn from_mime_type(mime_type: &str) -> Option<Self> {
+        UTType::from_tag(TAG_CLASS_MIME_TYPE, mime_type)
+    }
+
+    /// Returns the type of the file (or directory) at `path`, as the system sees it - e.g, to
+    /// work out which of your document types you've been asked to open. Returns `None` if the
+    /// file can't be read.
+    pub fn of_file<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let path = NSString::new(path.as_ref().to_str()?);
+        let key = NSString::new("NSURLTypeIdentifierKey");
+        let mut identifier: id = nil;
+
+        let found: BOOL = unsafe {
+            let url: id = msg_send![class!(NSURL), fileURLWithPath:path];
+            msg_send![url, getResourceValue:&mut identifier forKey:key error:nil]
+        };
+
+        match found == YES && identifier != nil {
+            true => Some(UTType::new(NSString::wrap(identifier).to_str())),
+            false => None
+        }
+    }
+
+    /// Returns the identifier (e.g, `public.png`).
+    pub fn identifier(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns this type's identifier as a `CFString`.
+    fn cf_identifier(&self) -> CFString {
+        CFString::new(&self.0)
+    }
+
+    /// Returns the preferred tag of the given class for this type.
+    fn preferred_tag(&self, tag_class: &str) -> Option<String> {
+        let tag_class = CFString::new(tag_class);
+
+        string(unsafe {
+            UTTypeCopyPreferredTagWithClass(self.cf_identifier().as_concrete_TypeRef(), tag_class.as_concrete_TypeRef())
+        })
+    }
+
+    /// Returns the preferred file extension for this type (e.g, `png`), if it has one.
+    pub fn preferred_extension(&self) -> Option<String> {
+        self.preferred_tag(TAG_CLASS_FILENAME_EXTENSION)
+    }
+
+    /// Returns the preferred MIME type for this type (e.g, `image/png`), if it has one.
+    pub fn preferred_mime_type(&self) -> Option<String> {
+        self.preferred_tag(TAG_CLASS_MIME_TYPE)
+    }
+
+    /// Returns whether this type is (or is a more specific kind of) `other` - e.g, `public.png`
+    /// conforms to `public.image`, which conforms to `public.data`.
+    pub fn conforms_to(&self, other: &UTType) -> bool {
+        unsafe {
+            UTTypeConformsTo(self.cf_identifier().as_concrete_TypeRef(), other.cf_identifier().as_concrete_TypeRef()) != 0
+        }
+    }
+
+    /// Returns a localized, user-facing description of this type (e.g, `PNG image`), if the
+    /// system has one.
+    pub fn localized_description(&self) -> Option<String> {
+        string(unsafe { UTTypeCopyDescription(self.cf_identifier().as_concrete_TypeRef()) })
+    }
+
+    /// Returns whether this is a dynamic type, which the system makes up for tags (e.g, file
+    /// extensions) that no declared type claims.
+    pub fn is_dynamic(&self) -> bool {
+        unsafe { UTTypeIsDynamic(self.cf_identifier().as_concrete_TypeRef()) != 0 }
+    }
+}
+
+/// Restricts an open or save panel to `types` (or, if `types` is empty, allows anything).
+///
+/// This uses `setAllowedContentTypes:` where the `UTType` class is available (macOS 11+), and
+/// falls back to the older, identifier-based `setAllowedFileTypes:` where it isn't.
+pub(crate) fn set_allowed_content_types(panel: &Object, types: &[UTType]) {
+    let class = Class::get("UTType");
+
+    unsafe {
+        let _: () = match (class, types.len()) {
+            (Some(_), 0) => msg_send![panel, setAllowedContentTypes:NSArray::new(&[]).into_inner()],
+            (None, 0) => msg_send![panel, setAllowedFileTypes:nil],
+
+            (Some(class), _) => {
+                let types: NSArray = types.iter().filter_map(|t| {
+                    let identifier = NSString::new(t.identifier());
+                    let content_type: id = msg_send![class, typeWithIdentifier:identifier];
+
+                    match content_type == nil {
+                        true => None,
+                        false => Some(content_type)
+                    }
+                }).collect::<Vec<id>>().into();
+
+                msg_send![panel, setAllowedContentTypes:types.into_inner()]
+            },
+
+            (None, _) => {
+                let types: NSArray = types.iter().map(|t| {
+                    NSString::new(t.identifier()).into_inner()
+                }).collect::<Vec<id>>().into();
+
+                msg_send![panel, setAllowedFileTypes:types.into_inner()]
+            }
+        };
+    }
+}
+
+impl From<&str> for UTType {
+    fn from(identifier: &str) -> Self {
+        UTType::new(identifier)
+    }
+}
+
+impl From<String> for UTType {
+    fn from(identifier: String) -> Self {
+        UTType(Cow::Owned(identifier))
+    }
+}
+
+impl AsRef<str> for UTType {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for UTType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
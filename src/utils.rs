@@ -2,14 +2,23 @@
 //! belong to. These are typically internal, and if you rely on them... well, don't be surprised if
 //! they go away one day.
 
+use std::any::TypeId;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+
 use core_graphics::base::CGFloat;
+use lazy_static::lazy_static;
 
 use objc::{class, msg_send, sel, sel_impl};
 
 use objc::{Encode, Encoding};
-use objc::runtime::Object;
+use objc::declare::ClassDecl;
+use objc::runtime::{Class, Object};
 use objc_id::ShareId;
 
+use crate::error::Error;
 use crate::foundation::{id, BOOL};
 
 /// A generic trait that's used throughout multiple different controls in this framework - acts as
@@ -34,12 +43,241 @@ pub trait Controller {
 /// 
 /// This is, like much in this framework, subject to revision pending more thorough testing and
 /// checking.
+///
+/// # Panics
+///
+/// Panics if `this` has no such ivar, or if the pointer hasn't been set (or has been cleared).
+/// Use `try_load()` if you'd rather handle that.
 pub fn load<'a, T>(this: &'a Object, ptr_name: &str) -> &'a T {
+    match try_load(this, ptr_name) {
+        Ok(obj) => obj,
+        Err(e) => { panic!("{}", e); }
+    }
+}
+
+/// Like `load()`, but returns an error - rather than panicking, or worse, dereferencing a null
+/// pointer - if `this` has no such ivar, or the pointer isn't set.
+pub fn try_load<'a, T>(this: &'a Object, ptr_name: &str) -> Result<&'a T, Error> {
+    if this.class().instance_variable(ptr_name).is_none() {
+        return Err(Error::Downcast(format!("{} has no {} ivar", this.class().name(), ptr_name)));
+    }
+
+    let ptr: usize = unsafe { *this.get_ivar(ptr_name) };
+
+    match ptr {
+        0 => Err(Error::Downcast(format!("{} on {} is not set", ptr_name, this.class().name()))),
+        ptr => Ok(unsafe { &*(ptr as *const T) })
+    }
+}
+
+/// Clears the pointer stored in `ptr_name` on `this`, which the owning Rust type does when it's
+/// dropped. The Objective-C object can outlive it (e.g, it's still in a view hierarchy, or has
+/// events in flight), and callbacks that arrive afterwards then find nothing to call - rather than
+/// a dangling pointer.
+pub(crate) fn clear_ptr(this: &Object, ptr_name: &str) {
+    if this.class().instance_variable(ptr_name).is_none() {
+        return;
+    }
+
+    let this = this as *const Object as *mut Object;
+
     unsafe {
-        let ptr: usize = *this.get_ivar(ptr_name);
-        let obj = ptr as *const T;
-        &*obj
+        (*this).set_ivar(ptr_name, 0usize);
+    }
+}
+
+/// Loads the delegate behind `$ptr` on `$this` (via `try_load()`), or returns `$default` (`()`,
+/// if not given) from the enclosing function if it's gone. Our `extern fn` callbacks use this,
+/// since the Objective-C side can outlive the Rust side - and panicking across the FFI boundary
+/// aborts the process.
+macro_rules! load_or_return {
+    ($ty:ty, $this:expr, $ptr:expr) => {
+        load_or_return!($ty, $this, $ptr, ())
+    };
+
+    ($ty:ty, $this:expr, $ptr:expr, $default:expr) => {
+        match $crate::utils::try_load::<$ty>($this, $ptr) {
+            Ok(delegate) => delegate,
+            Err(_) => { return $default; }
+        }
+    };
+}
+
+/// Implements the raw pointer escape hatches on one of our view types: `from_ptr()` and
+/// `from_retained_ptr()` (which build it around an existing object via its `wrap()`), and
+/// `as_objc()`/`into_objc()`.
+///
+/// For types whose `Drop` pulls the view out of its hierarchy when there's a delegate, pass the
+/// ivar the delegate pointer lives in: `into_objc()` clears it and lets the delegate go first, so
+/// the view you get back stays where it is.
+macro_rules! impl_objc_access {
+    (@from_ptr $ty:ident) => {
+        impl $ty {
+            /// Wraps an existing instance of the underlying class - e.g, one loaded from a nib, or
+            /// created by another framework - retaining it.
+            ///
+            /// # Safety
+            ///
+            /// `view` must be a valid, non-nil pointer to an instance of the underlying class, or a
+            /// subclass of it.
+            pub unsafe fn from_ptr(view: $crate::foundation::id) -> Self {
+                $ty::wrap(objc_id::ShareId::from_ptr(view))
+            }
+
+            /// Like `from_ptr()`, but takes over a reference you already own (e.g, from `new`),
+            /// rather than retaining it again.
+            ///
+            /// # Safety
+            ///
+            /// As with `from_ptr()`, and the reference must be yours to give away.
+            pub unsafe fn from_retained_ptr(view: $crate::foundation::id) -> Self {
+                $ty::wrap(objc_id::ShareId::from_retained_ptr(view))
+            }
+        }
+    };
+
+    (@as_objc) => {
+        /// Returns the underlying Objective-C object, for handing to other frameworks (or
+        /// Swift/Objective-C code). It isn't retained; it stays valid for as long as this (or
+        /// whatever it's been added to) keeps it alive.
+        pub fn as_objc(&self) -> $crate::foundation::id {
+            &*self.objc as *const objc::runtime::Object as $crate::foundation::id
+        }
+    };
+
+    ($ty:ident) => {
+        impl_objc_access!(@from_ptr $ty);
+
+        impl $ty {
+            impl_objc_access!(@as_objc);
+
+            /// Consumes this, returning the underlying Objective-C object with a reference that
+            /// you own (and are responsible for releasing). Any action is detached, just as if
+            /// this had been dropped.
+            pub fn into_objc(self) -> $crate::foundation::id {
+                unsafe { msg_send![&*self.objc, retain] }
+            }
+        }
+    };
+
+    ($ty:ident<T>) => {
+        impl_objc_access!(@from_ptr $ty);
+
+        impl<T> $ty<T> {
+            impl_objc_access!(@as_objc);
+
+            /// Consumes this, returning the underlying Objective-C object with a reference that
+            /// you own (and are responsible for releasing). If there's a delegate, it's detached
+            /// and goes away with this; hold on to this and use `as_objc()` instead if the
+            /// delegate needs to keep working.
+            pub fn into_objc(self) -> $crate::foundation::id {
+                unsafe { msg_send![&*self.objc, retain] }
+            }
+        }
+    };
+
+    ($ty:ident<T>, $ptr:expr) => {
+        impl_objc_access!(@from_ptr $ty);
+
+        impl<T> $ty<T> {
+            impl_objc_access!(@as_objc);
+
+            /// Consumes this, returning the underlying Objective-C object with a reference that
+            /// you own (and are responsible for releasing). If there's a delegate, it's detached
+            /// and goes away with this; hold on to this and use `as_objc()` instead if the
+            /// delegate needs to keep working.
+            pub fn into_objc(mut self) -> $crate::foundation::id {
+                if let Some(delegate) = self.delegate.take() {
+                    $crate::utils::clear_ptr(&self.objc, $ptr);
+                    drop(delegate);
+                }
+
+                unsafe { msg_send![&*self.objc, retain] }
+            }
+        }
+    };
+}
+
+/// Returns the Objective-C class named `name`. If it doesn't exist yet, it's declared as a
+/// subclass of `superclass`, handed to `config` to add ivars and methods, and registered.
+///
+/// Several of our classes share a name across modules (e.g, `RSTView` on iOS), so looking up an
+/// existing class first - rather than assuming we're the first to declare it - matters.
+pub fn try_load_or_register_class<F>(superclass: &Class, name: &str, config: F) -> Result<&'static Class, Error>
+where
+    F: FnOnce(&mut ClassDecl)
+{
+    let class = match Class::get(name) {
+        Some(class) => Ok(class),
+
+        None => match ClassDecl::new(name, superclass) {
+            Some(mut decl) => {
+                config(&mut decl);
+                Ok(decl.register())
+            },
+
+            // Someone else registered it between the lookup and now; theirs will do.
+            None => Class::get(name).ok_or_else(|| Error::ClassRegistration(name.to_string()))
+        }
+    };
+
+    // Headless, everything we register is swapped for a stand-in; see `crate::headless`.
+    #[cfg(feature = "headless")]
+    let class = class.map(crate::headless::stand_in_class);
+
+    class
+}
+
+/// Like `try_load_or_register_class()`, for the (internal) registration functions that have
+/// nowhere to report an error to.
+///
+/// # Panics
+///
+/// Panics if the Objective-C runtime refuses to declare the class.
+pub(crate) fn load_or_register_class<F>(superclass: &Class, name: &str, config: F) -> *const Class
+where
+    F: FnOnce(&mut ClassDecl)
+{
+    match try_load_or_register_class(superclass, name, config) {
+        Ok(class) => class,
+        Err(e) => { panic!("{}", e); }
+    }
+}
+
+lazy_static! {
+    /// Classes registered through `load_or_register_class_for()`, keyed by base name and the
+    /// `TypeId` they were registered for. These are `*const Class`es, stored as `usize` so they
+    /// can live in a static.
+    static ref TYPED_CLASSES: Mutex<HashMap<(&'static str, TypeId), usize>> = Mutex::new(HashMap::new());
+}
+
+/// Like `load_or_register_class()`, for classes whose methods are generic over a Rust type `T`
+/// (typically a delegate).
+///
+/// A `static` inside a generic function is shared by every instantiation of it, so registering
+/// these behind a single `Once` means the first `T` wins - and every later `T` dispatches into
+/// the wrong code. Instead, each `T` gets its own class: `name`, suffixed with a hash of its
+/// `TypeId`.
+pub(crate) fn load_or_register_class_for<T, F>(superclass: &Class, name: &'static str, config: F) -> *const Class
+where
+    T: 'static,
+    F: FnOnce(&mut ClassDecl)
+{
+    let key = (name, TypeId::of::<T>());
+
+    // Held for the duration, so that two threads can't race to declare the same class.
+    let mut classes = TYPED_CLASSES.lock().unwrap();
+
+    if let Some(class) = classes.get(&key) {
+        return *class as *const Class;
     }
+
+    let mut hasher = DefaultHasher::new();
+    key.1.hash(&mut hasher);
+
+    let class = load_or_register_class(superclass, &format!("{}_{:016x}", name, hasher.finish()), config);
+    classes.insert(key, class as usize);
+    class
 }
 
 pub fn async_main_thread<F>(method: F)
@@ -88,6 +326,60 @@ unsafe impl Encode for CGSize {
     }
 }
 
+/// See `CGSize`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CGPoint {
+    pub x: CGFloat,
+    pub y: CGFloat,
+}
+
+impl CGPoint {
+    pub fn new(x: CGFloat, y: CGFloat) -> Self {
+        CGPoint { x, y }
+    }
+}
+
+unsafe impl Encode for CGPoint {
+    fn encode() -> Encoding {
+        let encoding = format!("{{CGPoint={}{}}}",
+            CGFloat::encode().as_str(),
+            CGFloat::encode().as_str()
+        );
+        
+        unsafe { Encoding::from_str(&encoding) }
+    }
+}
+
+/// See `CGSize`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CGRect {
+    pub origin: CGPoint,
+    pub size: CGSize,
+}
+
+impl CGRect {
+    pub fn new(origin: CGPoint, size: CGSize) -> Self {
+        CGRect { origin, size }
+    }
+    
+    pub fn zero() -> Self {
+        CGRect::default()
+    }
+}
+
+unsafe impl Encode for CGRect {
+    fn encode() -> Encoding {
+        let encoding = format!("{{CGRect={}{}}}",
+            CGPoint::encode().as_str(),
+            CGSize::encode().as_str()
+        );
+        
+        unsafe { Encoding::from_str(&encoding) }
+    }
+}
+
 /// A helper method for ensuring that Cocoa is running in multi-threaded mode.
 ///
 /// Why do we need this? According to Apple, if you're going to make use of standard POSIX threads,
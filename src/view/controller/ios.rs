@@ -1,13 +1,17 @@
-use std::sync::Once;
 use std::unreachable;
 
-use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel};
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::{BOOL};
+use crate::foundation::{id, nil, BOOL, NO, YES, NSInteger, NSUInteger};
 use crate::view::{VIEW_DELEGATE_PTR, ViewDelegate};
-use crate::utils::{load, as_bool};
+use crate::utils::{load_or_register_class_for, as_bool, CGSize};
+
+#[cfg(feature = "ios")]
+use crate::geometry::Size;
+
+#[cfg(feature = "ios")]
+use crate::ios::{ContentSizeCategory, InterfaceOrientation, ScreenEdge, SizeClass, StatusBarAnimation, StatusBarStyle};
 
 /// Called when the view controller receives a `viewWillAppear:` message.
 extern fn will_appear<T: ViewDelegate>(this: &mut Object, _: Sel, animated: BOOL) {
@@ -15,7 +19,7 @@ extern fn will_appear<T: ViewDelegate>(this: &mut Object, _: Sel, animated: BOOL
         let _: () = msg_send![super(this, class!(UIViewController)), viewWillAppear:animated];
     }
 
-    let controller = load::<T>(this, VIEW_DELEGATE_PTR);
+    let controller = load_or_return!(T, this, VIEW_DELEGATE_PTR);
     controller.will_appear(as_bool(animated));
 }
 
@@ -25,7 +29,7 @@ extern fn did_appear<T: ViewDelegate>(this: &mut Object, _: Sel, animated: BOOL)
         let _: () = msg_send![super(this, class!(UIViewController)), viewDidAppear:animated];
     }
 
-    let controller = load::<T>(this, VIEW_DELEGATE_PTR);
+    let controller = load_or_return!(T, this, VIEW_DELEGATE_PTR);
     controller.did_appear(as_bool(animated));
 }
 
@@ -35,7 +39,7 @@ extern fn will_disappear<T: ViewDelegate>(this: &mut Object, _: Sel, animated: B
         let _: () = msg_send![super(this, class!(UIViewController)), viewWillDisappear:animated];
     }
 
-    let controller = load::<T>(this, VIEW_DELEGATE_PTR);
+    let controller = load_or_return!(T, this, VIEW_DELEGATE_PTR);
     controller.will_disappear(as_bool(animated));
 }
 
@@ -45,28 +49,155 @@ extern fn did_disappear<T: ViewDelegate>(this: &mut Object, _: Sel, animated: BO
         let _: () = msg_send![super(this, class!(UIViewController)), viewDidDisappear:animated];
     }
 
-    let controller = load::<T>(this, VIEW_DELEGATE_PTR);
+    let controller = load_or_return!(T, this, VIEW_DELEGATE_PTR);
     controller.did_disappear(as_bool(animated));
 }
 
-/// Registers an `NSViewDelegate`.
-pub(crate) fn register_view_controller_class<T: ViewDelegate + 'static>() -> *const Class {
-    static mut VIEW_CLASS: *const Class = 0 as *const Class;
-    static INIT: Once = Once::new();
+/// Called when the view controller receives a `viewWillTransitionToSize:withTransitionCoordinator:`
+/// message.
+#[cfg(feature = "ios")]
+extern fn will_transition_to_size<T: ViewDelegate>(this: &mut Object, _: Sel, size: CGSize, coordinator: id) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(UIViewController)), viewWillTransitionToSize:size
+            withTransitionCoordinator:coordinator];
+    }
 
-    INIT.call_once(|| unsafe {
-        let superclass = class!(UIViewController);
-        let mut decl = ClassDecl::new("RSTViewController", superclass).unwrap();
+    let controller = load_or_return!(T, this, VIEW_DELEGATE_PTR);
+    controller.size_changed(Size::new(size.width as f64, size.height as f64));
+}
 
-        decl.add_ivar::<usize>(VIEW_DELEGATE_PTR);
+/// Returns the horizontal and vertical size classes of a `UITraitCollection`.
+#[cfg(feature = "ios")]
+pub(crate) fn size_classes(traits: id) -> (SizeClass, SizeClass) {
+    if traits == nil {
+        return (SizeClass::Unspecified, SizeClass::Unspecified);
+    }
 
-        decl.add_method(sel!(viewWillAppear:), will_appear::<T> as extern fn(&mut Object, _, BOOL));
-        decl.add_method(sel!(viewDidAppear:), did_appear::<T> as extern fn(&mut Object, _, BOOL));
-        decl.add_method(sel!(viewWillDisappear:), will_disappear::<T> as extern fn(&mut Object, _, BOOL));
-        decl.add_method(sel!(viewDidDisappear:), did_disappear::<T> as extern fn(&mut Object, _, BOOL));
+    unsafe {
+        let horizontal: NSInteger = msg_send![traits, horizontalSizeClass];
+        let vertical: NSInteger = msg_send![traits, verticalSizeClass];
+        (horizontal.into(), vertical.into())
+    }
+}
 
-        VIEW_CLASS = decl.register();
-    });
+/// Returns the `ContentSizeCategory` of a `UITraitCollection`.
+#[cfg(feature = "ios")]
+pub(crate) fn content_size_category(traits: id) -> ContentSizeCategory {
+    match traits == nil {
+        true => ContentSizeCategory::Unspecified,
+        false => ContentSizeCategory::from_category(unsafe { msg_send![traits, preferredContentSizeCategory] })
+    }
+}
+
+/// Called when the view controller receives a `traitCollectionDidChange:` message. This fires for
+/// every trait (e.g, appearance), so we only pass on changes to the ones the delegate hears about.
+#[cfg(feature = "ios")]
+extern fn trait_collection_did_change<T: ViewDelegate>(this: &mut Object, _: Sel, previous: id) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(UIViewController)), traitCollectionDidChange:previous];
+    }
+
+    let controller = load_or_return!(T, this, VIEW_DELEGATE_PTR);
+    let current: id = unsafe { msg_send![this, traitCollection] };
+
+    let (horizontal, vertical) = size_classes(current);
+    if size_classes(previous) != (horizontal, vertical) {
+        controller.trait_collection_changed(horizontal, vertical);
+    }
+
+    let category = content_size_category(current);
+    if content_size_category(previous) != category {
+        controller.content_size_category_changed(category);
+    }
+}
 
-    unsafe { VIEW_CLASS }
+/// Returns the orientations the delegate supports, or UIKit's default if it has no preference.
+#[cfg(feature = "ios")]
+extern fn supported_orientations<T: ViewDelegate>(this: &Object, _: Sel) -> NSUInteger {
+    let orientations = match crate::utils::try_load::<T>(this, VIEW_DELEGATE_PTR) {
+        Ok(controller) => controller.supported_orientations(),
+        Err(_) => None
+    };
+
+    match orientations {
+        Some(orientations) => InterfaceOrientation::mask(&orientations),
+        None => unsafe { msg_send![super(this, class!(UIViewController)), supportedInterfaceOrientations] }
+    }
+}
+
+/// Returns the status bar style the delegate wants.
+#[cfg(feature = "ios")]
+extern fn preferred_status_bar_style<T: ViewDelegate>(this: &Object, _: Sel) -> NSInteger {
+    let controller = load_or_return!(T, this, VIEW_DELEGATE_PTR, StatusBarStyle::Default.into());
+    controller.preferred_status_bar_style().into()
+}
+
+/// Returns whether the delegate wants the status bar hidden.
+#[cfg(feature = "ios")]
+extern fn prefers_status_bar_hidden<T: ViewDelegate>(this: &Object, _: Sel) -> BOOL {
+    let controller = load_or_return!(T, this, VIEW_DELEGATE_PTR, NO);
+
+    match controller.prefers_status_bar_hidden() {
+        true => YES,
+        false => NO
+    }
+}
+
+/// Returns how the delegate wants status bar visibility changes animated.
+#[cfg(feature = "ios")]
+extern fn status_bar_update_animation<T: ViewDelegate>(this: &Object, _: Sel) -> NSInteger {
+    let controller = load_or_return!(T, this, VIEW_DELEGATE_PTR, StatusBarAnimation::Fade.into());
+    controller.status_bar_update_animation().into()
+}
+
+/// Returns whether the delegate wants the home indicator to hide itself.
+#[cfg(feature = "ios")]
+extern fn prefers_home_indicator_auto_hidden<T: ViewDelegate>(this: &Object, _: Sel) -> BOOL {
+    let controller = load_or_return!(T, this, VIEW_DELEGATE_PTR, NO);
+
+    match controller.prefers_home_indicator_auto_hidden() {
+        true => YES,
+        false => NO
+    }
+}
+
+/// Returns the screen edges where the delegate wants its gestures to take priority.
+#[cfg(feature = "ios")]
+extern fn edges_deferring_system_gestures<T: ViewDelegate>(this: &Object, _: Sel) -> NSUInteger {
+    let controller = load_or_return!(T, this, VIEW_DELEGATE_PTR, 0);
+    ScreenEdge::mask(&controller.edges_deferring_system_gestures())
+}
+
+/// Registers an `NSViewDelegate`.
+pub(crate) fn register_view_controller_class<T: ViewDelegate + 'static>() -> *const Class {
+    unsafe {
+        let superclass = class!(UIViewController);
+        load_or_register_class_for::<T, _>(superclass, "RSTViewController", |decl| {
+            decl.add_ivar::<usize>(VIEW_DELEGATE_PTR);
+
+            decl.add_method(sel!(viewWillAppear:), will_appear::<T> as extern fn(&mut Object, _, BOOL));
+            decl.add_method(sel!(viewDidAppear:), did_appear::<T> as extern fn(&mut Object, _, BOOL));
+            decl.add_method(sel!(viewWillDisappear:), will_disappear::<T> as extern fn(&mut Object, _, BOOL));
+            decl.add_method(sel!(viewDidDisappear:), did_disappear::<T> as extern fn(&mut Object, _, BOOL));
+
+            #[cfg(feature = "ios")]
+            {
+                decl.add_method(
+                    sel!(viewWillTransitionToSize:withTransitionCoordinator:),
+                    will_transition_to_size::<T> as extern fn(&mut Object, _, CGSize, _)
+                );
+                decl.add_method(sel!(traitCollectionDidChange:), trait_collection_did_change::<T> as extern fn(&mut Object, _, _));
+                decl.add_method(sel!(supportedInterfaceOrientations), supported_orientations::<T> as extern fn(&Object, _) -> NSUInteger);
+
+                decl.add_method(sel!(preferredStatusBarStyle), preferred_status_bar_style::<T> as extern fn(&Object, _) -> NSInteger);
+                decl.add_method(sel!(prefersStatusBarHidden), prefers_status_bar_hidden::<T> as extern fn(&Object, _) -> BOOL);
+                decl.add_method(sel!(preferredStatusBarUpdateAnimation), status_bar_update_animation::<T> as extern fn(&Object, _) -> NSInteger);
+                decl.add_method(sel!(prefersHomeIndicatorAutoHidden), prefers_home_indicator_auto_hidden::<T> as extern fn(&Object, _) -> BOOL);
+                decl.add_method(
+                    sel!(preferredScreenEdgesDeferringSystemGestures),
+                    edges_deferring_system_gestures::<T> as extern fn(&Object, _) -> NSUInteger
+                );
+            }
+        })
+    }
 }
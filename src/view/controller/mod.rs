@@ -1,11 +1,17 @@
+#[cfg(feature = "ios")]
+use block::ConcreteBlock;
+
+#[cfg(feature = "ios")]
+use crate::ios::SizeClass;
+
 use objc_id::ShareId;
 use objc::runtime::Object;
-use objc::{msg_send, sel, sel_impl};
+use objc::{class, msg_send, sel, sel_impl};
 
 use crate::foundation::id;
 use crate::layout::{Layout};
 use crate::view::{VIEW_DELEGATE_PTR, View, ViewDelegate};
-use crate::utils::Controller;
+use crate::utils::{clear_ptr, Controller};
 
 #[cfg(target_os = "macos")]
 mod macos;
@@ -54,8 +60,71 @@ impl<T> ViewController<T> where T: ViewDelegate + 'static {
     }
 }
 
+#[cfg(feature = "ios")]
+impl<T> ViewController<T> {
+    /// Asks UIKit to check `ViewDelegate::preferred_status_bar_style()` and
+    /// `prefers_status_bar_hidden()` again. If `animated`, the change is animated per
+    /// `status_bar_update_animation()`.
+    pub fn set_needs_status_bar_appearance_update(&self, animated: bool) {
+        let controller = &*self.objc as *const Object as id;
+
+        match animated {
+            true => {
+                let animations = ConcreteBlock::new(move || unsafe {
+                    let _: () = msg_send![controller, setNeedsStatusBarAppearanceUpdate];
+                });
+                let animations = animations.copy();
+
+                unsafe {
+                    let _: () = msg_send![class!(UIView), animateWithDuration:0.25 animations:&*animations];
+                }
+            },
+
+            false => unsafe {
+                let _: () = msg_send![controller, setNeedsStatusBarAppearanceUpdate];
+            }
+        }
+    }
+
+    /// Returns the current horizontal and vertical size classes. These are `Unspecified` until
+    /// this is on screen; `ViewDelegate::trait_collection_changed()` is called once they're known.
+    pub fn size_classes(&self) -> (SizeClass, SizeClass) {
+        ios::size_classes(unsafe { msg_send![&*self.objc, traitCollection] })
+    }
+
+    /// Asks UIKit to check `ViewDelegate::supported_orientations()` again, rotating the interface
+    /// if the current orientation is no longer supported. Requires iOS 16.
+    pub fn set_needs_orientation_update(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setNeedsUpdateOfSupportedInterfaceOrientations];
+        }
+    }
+
+    /// Asks UIKit to check `ViewDelegate::prefers_home_indicator_auto_hidden()` again.
+    pub fn set_needs_home_indicator_update(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setNeedsUpdateOfHomeIndicatorAutoHidden];
+        }
+    }
+
+    /// Asks UIKit to check `ViewDelegate::edges_deferring_system_gestures()` again.
+    pub fn set_needs_deferred_edges_update(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setNeedsUpdateOfScreenEdgesDeferringSystemGestures];
+        }
+    }
+}
+
 impl<T> Controller for ViewController<T> {
     fn get_backing_node(&self) -> ShareId<Object> {
         self.objc.clone()
     }
 }
+
+impl<T> Drop for ViewController<T> {
+    /// The window (or a parent controller) can keep the `NSViewController` alive past this, so
+    /// break its link to the delegate, which is going away with `view`.
+    fn drop(&mut self) {
+        clear_ptr(&self.objc, VIEW_DELEGATE_PTR);
+    }
+}
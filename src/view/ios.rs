@@ -1,14 +1,24 @@
 use std::sync::Once;
 
-use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel, BOOL};
-use objc::{class, sel, sel_impl};
+use objc::{class, msg_send, sel, sel_impl};
 use objc_id::Id;
 
 use crate::foundation::{id, YES, NO, NSUInteger};
 use crate::dragdrop::DragInfo;
 use crate::view::{VIEW_DELEGATE_PTR, ViewDelegate};
-use crate::utils::load;
+use crate::utils::{load_or_register_class, load_or_register_class_for};
+
+/// Called when this view lays out its subviews. `UIView` does the actual layout first, so that
+/// the delegate sees final frames.
+extern fn layout_subviews<T: ViewDelegate>(this: &mut Object, _: Sel) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(UIView)), layoutSubviews];
+    }
+
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR);
+    view.layout_subviews();
+}
 
 /// Injects an `NSView` subclass. This is used for the default views that don't use delegates - we
 /// have separate classes here since we don't want to waste cycles on methods that will never be
@@ -19,8 +29,7 @@ pub(crate) fn register_view_class() -> *const Class {
 
     INIT.call_once(|| unsafe {
         let superclass = class!(UIView);
-        let mut decl = ClassDecl::new("RSTView", superclass).unwrap();
-        VIEW_CLASS = decl.register();
+        VIEW_CLASS = load_or_register_class(superclass, "RSTView", |_| {});
     });
 
     unsafe { VIEW_CLASS }
@@ -28,18 +37,13 @@ pub(crate) fn register_view_class() -> *const Class {
 
 /// Injects an `NSView` subclass, with some callback and pointer ivars for what we
 /// need to do.
-pub(crate) fn register_view_class_with_delegate<T: ViewDelegate>() -> *const Class {
-    static mut VIEW_CLASS: *const Class = 0 as *const Class;
-    static INIT: Once = Once::new();
-
-    INIT.call_once(|| unsafe {
+pub(crate) fn register_view_class_with_delegate<T: ViewDelegate + 'static>() -> *const Class {
+    unsafe {
         let superclass = class!(UIView);
-        let mut decl = ClassDecl::new("RSTViewWithDelegate", superclass).unwrap();
-        decl.add_ivar::<usize>(VIEW_DELEGATE_PTR);
-        VIEW_CLASS = decl.register();
-    });
+        load_or_register_class_for::<T, _>(superclass, "RSTViewWithDelegate", |decl| {
+            decl.add_ivar::<usize>(VIEW_DELEGATE_PTR);
 
-    unsafe {
-        VIEW_CLASS
+            decl.add_method(sel!(layoutSubviews), layout_subviews::<T> as extern fn(&mut Object, _));
+        })
     }
 }
@@ -11,22 +11,124 @@ use std::sync::Once;
 
 use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel, BOOL};
-use objc::{class, sel, sel_impl};
+use objc::{class, msg_send, sel, sel_impl};
 use objc_id::Id;
 
-use crate::foundation::{id, YES, NO, NSUInteger};
+use crate::appearance::Appearance;
+use crate::foundation::{id, YES, NO, NSInteger, NSUInteger};
 use crate::dragdrop::DragInfo;
+use crate::events::Command;
 use crate::view::{VIEW_DELEGATE_PTR, ViewDelegate};
-use crate::utils::load;
+use crate::utils::{load_or_register_class, load_or_register_class_for};
 
 /// Enforces normalcy, or: a needlessly cruel method in terms of the name. You get the idea though.
 extern fn enforce_normalcy(_: &Object, _: Sel) -> BOOL {
     return YES;
 }
 
+/// Called when the effective appearance of this view changes.
+extern fn did_change_effective_appearance<T: ViewDelegate>(this: &mut Object, _: Sel) {
+    let appearance: id = unsafe { msg_send![this, effectiveAppearance] };
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR);
+    view.appearance_changed(Appearance::from_objc(appearance));
+}
+
+/// Called when this view lays out its subviews. `NSView` does the actual layout first, so that
+/// the delegate sees final frames.
+extern fn layout<T: ViewDelegate>(this: &mut Object, _: Sel) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSView)), layout];
+    }
+
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR);
+    view.layout_subviews();
+}
+
+/// Called when the pressure of a click changes, on a Force Touch trackpad.
+extern fn pressure_change_with_event<T: ViewDelegate>(this: &mut Object, _: Sel, event: id) {
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR);
+
+    let (stage, pressure) = unsafe {
+        let stage: NSInteger = msg_send![event, stage];
+        let pressure: f32 = msg_send![event, pressure];
+        (stage, pressure)
+    };
+
+    view.pressure_changed(stage as isize, pressure);
+}
+
+/// The selectors for the commands a delegate can take over (see
+/// `ViewDelegate::supported_commands()`).
+const ROUTED_COMMANDS: [&str; 6] = ["copy:", "cut:", "paste:", "delete:", "selectAll:", "performFindPanelAction:"];
+
+/// Called to ask whether this view can become the first responder.
+extern fn accepts_first_responder<T: ViewDelegate>(this: &Object, _: Sel) -> BOOL {
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR, NO);
+
+    match view.accepts_first_responder() {
+        true => YES,
+        false => NO
+    }
+}
+
+/// AppKit finds the target for an action by asking each responder whether it responds to the
+/// selector. The class implements every routed command, so for those, the delegate decides;
+/// anything else goes to `NSView`.
+extern fn responds_to_selector<T: ViewDelegate>(this: &Object, _: Sel, selector: Sel) -> BOOL {
+    if ROUTED_COMMANDS.contains(&selector.name()) {
+        let view = load_or_return!(T, this, VIEW_DELEGATE_PTR, NO);
+
+        return match view.supported_commands().contains(&Command::from_selector(selector)) {
+            true => YES,
+            false => NO
+        };
+    }
+
+    unsafe {
+        msg_send![super(this, class!(NSView)), respondsToSelector:selector]
+    }
+}
+
+/// Called when one of the routed commands is sent to this view.
+extern fn perform_command<T: ViewDelegate>(this: &Object, cmd: Sel, _sender: id) {
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR);
+    view.perform_command(Command::from_selector(cmd));
+}
+
+/// Called when a menu item or toolbar item sending an action this view responds to is validated.
+extern fn validate_item<T: ViewDelegate>(this: &Object, _: Sel, item: id) -> BOOL {
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR, YES);
+    let action: Sel = unsafe { msg_send![item, action] };
+
+    match view.validate(Command::from_selector(action)) {
+        true => YES,
+        false => NO
+    }
+}
+
+/// Called when the window this view is in starts being resized by the user.
+extern fn will_start_live_resize<T: ViewDelegate>(this: &mut Object, _: Sel) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSView)), viewWillStartLiveResize];
+    }
+
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR);
+    view.will_start_live_resize();
+}
+
+/// Called when the user has finished resizing the window this view is in.
+extern fn did_end_live_resize<T: ViewDelegate>(this: &mut Object, _: Sel) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSView)), viewDidEndLiveResize];
+    }
+
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR);
+    view.did_end_live_resize();
+}
+
 /// Called when a drag/drop operation has entered this view.
 extern fn dragging_entered<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> NSUInteger {
-    let view = load::<T>(this, VIEW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR, 0);
     view.dragging_entered(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     }).into()
@@ -34,7 +136,7 @@ extern fn dragging_entered<T: ViewDelegate>(this: &mut Object, _: Sel, info: id)
 
 /// Called when a drag/drop operation has entered this view.
 extern fn prepare_for_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
-    let view = load::<T>(this, VIEW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR, NO);
     
     match view.prepare_for_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -46,7 +148,7 @@ extern fn prepare_for_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel,
 
 /// Called when a drag/drop operation has entered this view.
 extern fn perform_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
-    let view = load::<T>(this, VIEW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR, NO);
         
     match view.perform_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -58,7 +160,7 @@ extern fn perform_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, inf
 
 /// Called when a drag/drop operation has entered this view.
 extern fn conclude_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) {
-    let view = load::<T>(this, VIEW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR);
     
     view.conclude_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -67,7 +169,7 @@ extern fn conclude_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, in
 
 /// Called when a drag/drop operation has entered this view.
 extern fn dragging_exited<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) {
-    let view = load::<T>(this, VIEW_DELEGATE_PTR);
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR);
         
     view.dragging_exited(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -83,11 +185,9 @@ pub(crate) fn register_view_class() -> *const Class {
 
     INIT.call_once(|| unsafe {
         let superclass = class!(NSView);
-        let mut decl = ClassDecl::new("RSTView", superclass).unwrap();
-
-        decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
-    
-        VIEW_CLASS = decl.register();
+        VIEW_CLASS = load_or_register_class(superclass, "RSTView", |decl| {
+            decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
+        });
     });
 
     unsafe { VIEW_CLASS }
@@ -95,31 +195,48 @@ pub(crate) fn register_view_class() -> *const Class {
 
 /// Injects an `NSView` subclass, with some callback and pointer ivars for what we
 /// need to do.
-pub(crate) fn register_view_class_with_delegate<T: ViewDelegate>() -> *const Class {
-    static mut VIEW_CLASS: *const Class = 0 as *const Class;
-    static INIT: Once = Once::new();
-
-    INIT.call_once(|| unsafe {
-        let superclass = class!(NSView);
-        let mut decl = ClassDecl::new("RSTViewWithDelegate", superclass).unwrap();
-
-        // A pointer to the "view controller" on the Rust side. It's expected that this doesn't
-        // move.
-        decl.add_ivar::<usize>(VIEW_DELEGATE_PTR);
-        
-        decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
-
-        // Drag and drop operations (e.g, accepting files)
-        decl.add_method(sel!(draggingEntered:), dragging_entered::<T> as extern fn (&mut Object, _, _) -> NSUInteger);
-        decl.add_method(sel!(prepareForDragOperation:), prepare_for_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
-        decl.add_method(sel!(performDragOperation:), perform_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
-        decl.add_method(sel!(concludeDragOperation:), conclude_drag_operation::<T> as extern fn (&mut Object, _, _));
-        decl.add_method(sel!(draggingExited:), dragging_exited::<T> as extern fn (&mut Object, _, _));
-        
-        VIEW_CLASS = decl.register();
-    });
-
+pub(crate) fn register_view_class_with_delegate<T: ViewDelegate + 'static>() -> *const Class {
     unsafe {
-        VIEW_CLASS
+        let superclass = class!(NSView);
+        load_or_register_class_for::<T, _>(superclass, "RSTViewWithDelegate", |decl| add_delegate_methods::<T>(decl))
     }
 }
+
+/// Adds the delegate ivar and the `ViewDelegate` method overrides to a view class being declared.
+/// Shared with the other view classes that have delegates (e.g, the text input view).
+pub(crate) unsafe fn add_delegate_methods<T: ViewDelegate + 'static>(decl: &mut ClassDecl) {
+    // A pointer to the "view controller" on the Rust side. It's expected that this doesn't
+    // move.
+    decl.add_ivar::<usize>(VIEW_DELEGATE_PTR);
+
+    decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
+
+    decl.add_method(sel!(viewDidChangeEffectiveAppearance), did_change_effective_appearance::<T> as extern fn(&mut Object, _));
+
+    // Layout and live resizing
+    decl.add_method(sel!(layout), layout::<T> as extern fn(&mut Object, _));
+    decl.add_method(sel!(viewWillStartLiveResize), will_start_live_resize::<T> as extern fn(&mut Object, _));
+    decl.add_method(sel!(viewDidEndLiveResize), did_end_live_resize::<T> as extern fn(&mut Object, _));
+    decl.add_method(sel!(pressureChangeWithEvent:), pressure_change_with_event::<T> as extern fn(&mut Object, _, _));
+
+    // Responder chain
+    decl.add_method(sel!(acceptsFirstResponder), accepts_first_responder::<T> as extern fn(&Object, _) -> BOOL);
+    decl.add_method(sel!(respondsToSelector:), responds_to_selector::<T> as extern fn(&Object, _, Sel) -> BOOL);
+    decl.add_method(sel!(copy:), perform_command::<T> as extern fn(&Object, _, _));
+    decl.add_method(sel!(cut:), perform_command::<T> as extern fn(&Object, _, _));
+    decl.add_method(sel!(paste:), perform_command::<T> as extern fn(&Object, _, _));
+    decl.add_method(sel!(delete:), perform_command::<T> as extern fn(&Object, _, _));
+    decl.add_method(sel!(selectAll:), perform_command::<T> as extern fn(&Object, _, _));
+    decl.add_method(sel!(performFindPanelAction:), perform_command::<T> as extern fn(&Object, _, _));
+
+    // Validation
+    decl.add_method(sel!(validateUserInterfaceItem:), validate_item::<T> as extern fn(&Object, _, _) -> BOOL);
+    decl.add_method(sel!(validateToolbarItem:), validate_item::<T> as extern fn(&Object, _, _) -> BOOL);
+
+    // Drag and drop operations (e.g, accepting files)
+    decl.add_method(sel!(draggingEntered:), dragging_entered::<T> as extern fn (&mut Object, _, _) -> NSUInteger);
+    decl.add_method(sel!(prepareForDragOperation:), prepare_for_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
+    decl.add_method(sel!(performDragOperation:), perform_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
+    decl.add_method(sel!(concludeDragOperation:), conclude_drag_operation::<T> as extern fn (&mut Object, _, _));
+    decl.add_method(sel!(draggingExited:), dragging_exited::<T> as extern fn (&mut Object, _, _));
+}
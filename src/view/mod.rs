@@ -45,10 +45,21 @@ use objc_id::ShareId;
 use objc::runtime::{Class, Object};
 use objc::{msg_send, sel, sel_impl};
 
+#[cfg(target_os = "macos")]
+use objc::class;
+
+use crate::dispatch::{assert_main_thread, MainThreadHandle};
 use crate::foundation::{id, nil, YES, NO, NSArray, NSString};
 use crate::color::Color;
 use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
-use crate::pasteboard::PasteboardType;
+use crate::utils::clear_ptr;
+use crate::uti::UTType;
+
+#[cfg(target_os = "macos")]
+use crate::events::PressureBehavior;
+
+#[cfg(target_os = "macos")]
+use crate::foundation::NSInteger;
 
 #[cfg(target_os = "macos")]
 mod macos;
@@ -56,6 +67,12 @@ mod macos;
 #[cfg(target_os = "macos")]
 use macos::{register_view_class, register_view_class_with_delegate};
 
+#[cfg(target_os = "macos")]
+mod text_input;
+
+#[cfg(target_os = "macos")]
+pub use text_input::TextInputDelegate;
+
 #[cfg(target_os = "ios")]
 mod ios;
 
@@ -65,6 +82,10 @@ use ios::{register_view_class, register_view_class_with_delegate};
 mod controller;
 pub use controller::ViewController;
 
+/// Re-exported here, so that you can name it - e.g, to hand back a `Box<dyn Controller>` from a
+/// `PageViewDataSource`.
+pub use crate::utils::Controller;
+
 mod traits;
 pub use traits::ViewDelegate;
 
@@ -128,6 +149,8 @@ impl Default for View {
 impl View {
     /// Returns a default `View`, suitable for 
     pub fn new() -> Self {
+        assert_main_thread("View::new");
+
         let view = allocate_view(register_view_class);
 
         View {
@@ -145,13 +168,42 @@ impl View {
     }
 }
 
+impl View {
+    /// Builds a `View` around `objc`, with no delegate.
+    fn wrap(objc: ShareId<Object>) -> Self {
+        let view = &*objc;
+
+        View {
+            delegate: None,
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            objc: objc
+        }
+    }
+}
+
+impl_objc_access!(View<T>, VIEW_DELEGATE_PTR);
+
 impl<T> View<T> where T: ViewDelegate + 'static {
     /// Initializes a new View with a given `ViewDelegate`. This enables you to respond to events
     /// and customize the view as a module, similar to class-based systems.
     pub fn with(delegate: T) -> View<T> {
+        View::with_registration(delegate, register_view_class_with_delegate::<T>)
+    }
+
+    /// Builds a `View` from `registration_fn`'s class, wired up to `delegate`.
+    fn with_registration(delegate: T, registration_fn: fn() -> *const Class) -> View<T> {
+        assert_main_thread("View::with");
+
         let mut delegate = Box::new(delegate);
         
-        let view = allocate_view(register_view_class_with_delegate::<T>);
+        let view = allocate_view(registration_fn);
         unsafe {
             //let view: id = msg_send![register_view_class_with_delegate::<T>(), new];
             //let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints:NO];
@@ -198,6 +250,12 @@ impl<T> View<T> {
         }
     }
 
+    /// Returns a handle to this view that can be sent to other threads, and used to update it
+    /// from there; see `MainThreadHandle`.
+    pub fn handle(&self) -> ViewHandle {
+        MainThreadHandle::new(self.clone_as_handle())
+    }
+
     /// Call this to set the background color for the backing layer.
     pub fn set_background_color(&self, color: Color) {
         let bg = color.into_platform_specific_color();
@@ -209,19 +267,32 @@ impl<T> View<T> {
         }
     }
 
-    /// Register this view for drag and drop operations.
-    pub fn register_for_dragged_types(&self, types: &[PasteboardType]) {
+    /// Register this view for drag and drop operations. Takes `UTType`s, or anything that
+    /// converts to one (e.g, `PasteboardType`).
+    pub fn register_for_dragged_types<U: Clone + Into<UTType>>(&self, types: &[U]) {
         unsafe {
-            let types: NSArray = types.into_iter().map(|t| {
-                // This clone probably doesn't need to be here, but it should also be cheap as
-                // this is just an enum... and this is not an oft called method.
-                let x: NSString = t.clone().into();
-                x.into_inner()
+            let types: NSArray = types.iter().map(|t| {
+                let uti: UTType = t.clone().into();
+                NSString::new(uti.identifier()).into_inner()
             }).collect::<Vec<id>>().into();
 
             let _: () = msg_send![&*self.objc, registerForDraggedTypes:types.into_inner()];
         }
     }
+
+    /// Sets how a Force Touch trackpad responds to clicks on this view, and so which pressure
+    /// stages `ViewDelegate::pressure_changed()` hears about.
+    #[cfg(target_os = "macos")]
+    pub fn set_pressure_behavior(&self, behavior: PressureBehavior) {
+        let behavior: NSInteger = behavior.into();
+
+        unsafe {
+            let configuration: id = msg_send![class!(NSPressureConfiguration), alloc];
+            let configuration: id = msg_send![configuration, initWithPressureBehavior:behavior];
+            let _: () = msg_send![&*self.objc, setPressureConfiguration:configuration];
+            let _: () = msg_send![configuration, release];
+        }
+    }
 }
 
 impl<T> Layout for View<T> {
@@ -244,9 +315,12 @@ impl<T> Drop for View<T> {
     /// this has a superview (i.e, it's in the heirarchy) on the AppKit side. If it does, we go
     /// ahead and remove it - this is intended to match the semantics of how Rust handles things).
     ///
-    /// There are, thankfully, no delegates we need to break here.
+    /// The delegate pointer on the Objective-C side is cleared too, so any callbacks that arrive
+    /// after this (e.g, if something else is still holding on to the view) are ignored.
     fn drop(&mut self) {
         if self.delegate.is_some() {
+            clear_ptr(&self.objc, VIEW_DELEGATE_PTR);
+
             unsafe {
                 let superview: id = msg_send![&*self.objc, superview];
                 if superview != nil {
@@ -256,3 +330,13 @@ impl<T> Drop for View<T> {
         }
     }
 }
+
+/// A `View` handle that can be sent to other threads. Updates are applied on the main thread.
+pub type ViewHandle = MainThreadHandle<View>;
+
+impl ViewHandle {
+    /// Sets the background color, on the main thread.
+    pub fn set_background_color(&self, color: Color) {
+        self.update(move |view| view.set_background_color(color));
+    }
+}
@@ -0,0 +1,357 @@
+//! Opt-in text input support for custom views, via `NSTextInputClient`. This is what lets the
+//! system's input methods - CJK input, dead keys (e.g, Option-E, E for "é"), the emoji picker and
+//! so on - work in a custom editor or game text box, rather than just raw key presses.
+//!
+//! Input methods work by "marking" text: while the user composes a character, the provisional
+//! text is handed over with `set_marked_text()`, and you display it inline (usually underlined).
+//! Once they commit, the marked text is replaced via `insert_text()`. The input method positions
+//! its candidate window using `rect_for_range()`.
+//!
+//! All ranges are in UTF-16 code units (i.e, `NSString` indices), not bytes or `char`s.
+//!
+//! ```rust,no_run
+//! use std::cell::RefCell;
+//! use std::ops::Range;
+//!
+//! use cacao::geometry::Rect;
+//! use cacao::view::{View, ViewDelegate, TextInputDelegate};
+//!
+//! #[derive(Default)]
+//! struct Editor {
+//!     text: RefCell<String>,
+//!     marked: RefCell<Option<String>>
+//! }
+//!
+//! impl ViewDelegate for Editor {
+//!     fn accepts_first_responder(&self) -> bool { true }
+//! }
+//!
+//! impl TextInputDelegate for Editor {
+//!     fn insert_text(&self, text: &str, _replacement: Option<Range<usize>>) {
+//!         self.marked.replace(None);
+//!         self.text.borrow_mut().push_str(text);
+//!     }
+//!
+//!     fn set_marked_text(&self, text: &str, _selected: Range<usize>, _replacement: Option<Range<usize>>) {
+//!         self.marked.replace(Some(text.to_string()));
+//!     }
+//!
+//!     fn unmark_text(&self) {
+//!         self.marked.replace(None);
+//!     }
+//!
+//!     fn rect_for_range(&self, _range: Range<usize>) -> Rect {
+//!         // Wherever the caret is drawn.
+//!         Rect::new(0., 0., 1., 17.)
+//!     }
+//! }
+//!
+//! let editor = View::with_text_input(Editor::default());
+//! ```
+
+use std::ops::Range;
+
+use objc::declare::ClassDecl;
+use objc::runtime::{Class, Object, Protocol, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use objc::{Encode, Encoding};
+
+use crate::foundation::{id, nil, BOOL, NO, YES, NSRange, NSString, NSUInteger};
+use crate::geometry::{Point, Rect};
+use crate::utils::{load_or_register_class_for, CGPoint, CGRect, CGSize};
+use crate::view::{View, ViewDelegate, VIEW_DELEGATE_PTR};
+use crate::view::macos::add_delegate_methods;
+
+/// Receives text input for a `View` created with `View::with_text_input()`. The view also needs
+/// to be focused to receive anything, so return `true` from
+/// `ViewDelegate::accepts_first_responder()`.
+pub trait TextInputDelegate: ViewDelegate {
+    /// Called when the user has entered `text`, e.g by typing or committing a composed character.
+    /// It replaces `replacement` if given, otherwise the marked text (if there is any), otherwise
+    /// the selection. Any marked text is now gone.
+    fn insert_text(&self, text: &str, replacement: Option<Range<usize>>);
+
+    /// Called while an input method is composing: `text` is the provisional text, which replaces
+    /// `replacement` if given, otherwise the current marked text or selection. `selected` is the
+    /// selection within `text`. Passing empty text removes the marked text.
+    fn set_marked_text(&self, _text: &str, _selected: Range<usize>, _replacement: Option<Range<usize>>) {}
+
+    /// Called when the marked text should be committed as-is - e.g, the view lost focus while the
+    /// user was composing. Keep the text, but stop treating it as marked.
+    fn unmark_text(&self) {}
+
+    /// The range of the marked text, if there is any.
+    fn marked_range(&self) -> Option<Range<usize>> { None }
+
+    /// The range of the selection, or the caret position (as an empty range). `None` if there's
+    /// nothing selected, and no caret.
+    fn selected_range(&self) -> Option<Range<usize>> { None }
+
+    /// The text in `range`, which input methods use for context (e.g, to reconvert text). Return
+    /// `None` if you'd rather not say.
+    fn text_in_range(&self, _range: Range<usize>) -> Option<String> { None }
+
+    /// The rect enclosing the text in `range` (the first line of it, if it spans several), in
+    /// this view's coordinates. This is used to position the candidate window.
+    fn rect_for_range(&self, _range: Range<usize>) -> Rect { Rect::zero() }
+
+    /// The index of the character at `point`, in this view's coordinates, if there is one.
+    fn character_index_at(&self, _point: Point) -> Option<usize> { None }
+
+    /// Called for keys that issue commands rather than entering text - e.g, `"insertNewline:"`,
+    /// `"deleteBackward:"` or `"moveLeft:"`. Return `true` if you handled it; otherwise it's sent
+    /// up the responder chain (and beeps, if nothing takes it).
+    fn do_command(&self, _selector: &str) -> bool { false }
+}
+
+/// An `NSRangePointer`, as handed to us for "actual range" out-parameters.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+struct NSRangePointer(*mut NSRange);
+
+unsafe impl Encode for NSRangePointer {
+    fn encode() -> Encoding {
+        let encoding = format!("^{}", NSRange::encode().as_str());
+        unsafe { Encoding::from_str(&encoding) }
+    }
+}
+
+impl NSRangePointer {
+    /// Writes `range` through the pointer, if there is one.
+    fn set(&self, range: NSRange) {
+        if !self.0.is_null() {
+            unsafe { *self.0 = range; }
+        }
+    }
+}
+
+/// Pulls a Rust string out of the `NSString` or `NSAttributedString` that input methods hand over.
+fn input_string(string: id) -> String {
+    unsafe {
+        let attributed: BOOL = msg_send![string, isKindOfClass:class!(NSAttributedString)];
+
+        let string: id = match attributed {
+            YES => msg_send![string, string],
+            _ => string
+        };
+
+        NSString::wrap(string).to_string()
+    }
+}
+
+/// Forwards key presses to the input context, which turns them into text (or commands).
+extern fn key_down(this: &mut Object, _: Sel, event: id) {
+    unsafe {
+        let context: id = msg_send![this, inputContext];
+
+        let handled: BOOL = match context == nil {
+            true => NO,
+            false => msg_send![context, handleEvent:event]
+        };
+
+        if handled == NO {
+            let _: () = msg_send![super(this, class!(NSView)), keyDown:event];
+        }
+    }
+}
+
+/// Called when text has been entered.
+extern fn insert_text<T: TextInputDelegate>(this: &Object, _: Sel, string: id, replacement: NSRange) {
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR);
+    view.insert_text(&input_string(string), replacement.to_range());
+}
+
+/// Called while an input method is composing.
+extern fn set_marked_text<T: TextInputDelegate>(this: &Object, _: Sel, string: id, selected: NSRange, replacement: NSRange) {
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR);
+    let selected = selected.to_range().unwrap_or(0..0);
+    view.set_marked_text(&input_string(string), selected, replacement.to_range());
+}
+
+/// Called when marked text should be committed as-is.
+extern fn unmark_text<T: TextInputDelegate>(this: &Object, _: Sel) {
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR);
+    view.unmark_text();
+}
+
+/// Returns whether there's marked text.
+extern fn has_marked_text<T: TextInputDelegate>(this: &Object, _: Sel) -> BOOL {
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR, NO);
+
+    match view.marked_range() {
+        Some(_) => YES,
+        None => NO
+    }
+}
+
+/// Returns the range of the marked text.
+extern fn marked_range<T: TextInputDelegate>(this: &Object, _: Sel) -> NSRange {
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR, NSRange::not_found());
+    view.marked_range().into()
+}
+
+/// Returns the range of the selection.
+extern fn selected_range<T: TextInputDelegate>(this: &Object, _: Sel) -> NSRange {
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR, NSRange::not_found());
+    view.selected_range().into()
+}
+
+/// Returns the text in a range, for context.
+extern fn attributed_substring<T: TextInputDelegate>(this: &Object, _: Sel, range: NSRange, actual: NSRangePointer) -> id {
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR, nil);
+
+    let text = match range.to_range() {
+        Some(range) => view.text_in_range(range),
+        None => None
+    };
+
+    match text {
+        Some(text) => unsafe {
+            actual.set(range);
+            let text = NSString::new(&text);
+            let string: id = msg_send![class!(NSAttributedString), alloc];
+            let string: id = msg_send![string, initWithString:text];
+            msg_send![string, autorelease]
+        },
+
+        None => nil
+    }
+}
+
+/// We don't style marked text, so there's nothing to ask for.
+extern fn valid_attributes_for_marked_text(_: &Object, _: Sel) -> id {
+    unsafe { msg_send![class!(NSArray), array] }
+}
+
+/// Returns the rect (in screen coordinates) of a range of text, for the candidate window.
+extern fn first_rect_for_character_range<T: TextInputDelegate>(this: &Object, _: Sel, range: NSRange, actual: NSRangePointer) -> CGRect {
+    let zero = CGRect::zero();
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR, zero);
+
+    let rect = match range.to_range() {
+        Some(range) => view.rect_for_range(range),
+        None => return zero
+    };
+
+    actual.set(range);
+
+    unsafe {
+        let window: id = msg_send![this, window];
+        if window == nil {
+            return zero;
+        }
+
+        let rect = CGRect::new(CGPoint::new(rect.left, rect.top), CGSize::new(rect.width, rect.height));
+        let rect: CGRect = msg_send![this, convertRect:rect toView:nil];
+        msg_send![window, convertRectToScreen:rect]
+    }
+}
+
+/// Returns the index of the character at a point, given in screen coordinates.
+extern fn character_index_for_point<T: TextInputDelegate>(this: &Object, _: Sel, point: CGPoint) -> NSUInteger {
+    let view = load_or_return!(T, this, VIEW_DELEGATE_PTR, NSRange::NOT_FOUND);
+
+    let point: CGPoint = unsafe {
+        let window: id = msg_send![this, window];
+        if window == nil {
+            return NSRange::NOT_FOUND;
+        }
+
+        let point: CGPoint = msg_send![window, convertPointFromScreen:point];
+        msg_send![this, convertPoint:point fromView:nil]
+    };
+
+    match view.character_index_at(Point::new(point.x as f64, point.y as f64)) {
+        Some(index) => index as NSUInteger,
+        None => NSRange::NOT_FOUND
+    }
+}
+
+/// Called for keys that issue commands; anything the delegate doesn't handle carries on up the
+/// responder chain.
+extern fn do_command_by_selector<T: TextInputDelegate>(this: &Object, _: Sel, selector: Sel) {
+    if let Ok(view) = crate::utils::try_load::<T>(this, VIEW_DELEGATE_PTR) {
+        if view.do_command(selector.name()) {
+            return;
+        }
+    }
+
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSView)), doCommandBySelector:selector];
+    }
+}
+
+/// Injects an `NSView` subclass that conforms to `NSTextInputClient`, on top of everything the
+/// standard delegate view class does.
+pub(crate) fn register_text_input_view_class<T: TextInputDelegate + 'static>() -> *const Class {
+    unsafe {
+        let superclass = class!(NSView);
+        load_or_register_class_for::<T, _>(superclass, "RSTTextInputView", |decl: &mut ClassDecl| {
+            add_delegate_methods::<T>(decl);
+
+            // The view only gets an input context if it conforms to the protocol.
+            if let Some(protocol) = Protocol::get("NSTextInputClient") {
+                decl.add_protocol(protocol);
+            }
+
+            decl.add_method(sel!(keyDown:), key_down as extern fn(&mut Object, _, _));
+
+            decl.add_method(
+                sel!(insertText:replacementRange:),
+                insert_text::<T> as extern fn(&Object, _, _, NSRange)
+            );
+            decl.add_method(
+                sel!(setMarkedText:selectedRange:replacementRange:),
+                set_marked_text::<T> as extern fn(&Object, _, _, NSRange, NSRange)
+            );
+            decl.add_method(sel!(unmarkText), unmark_text::<T> as extern fn(&Object, _));
+            decl.add_method(sel!(hasMarkedText), has_marked_text::<T> as extern fn(&Object, _) -> BOOL);
+            decl.add_method(sel!(markedRange), marked_range::<T> as extern fn(&Object, _) -> NSRange);
+            decl.add_method(sel!(selectedRange), selected_range::<T> as extern fn(&Object, _) -> NSRange);
+            decl.add_method(
+                sel!(attributedSubstringForProposedRange:actualRange:),
+                attributed_substring::<T> as extern fn(&Object, _, NSRange, NSRangePointer) -> id
+            );
+            decl.add_method(sel!(validAttributesForMarkedText), valid_attributes_for_marked_text as extern fn(&Object, _) -> id);
+            decl.add_method(
+                sel!(firstRectForCharacterRange:actualRange:),
+                first_rect_for_character_range::<T> as extern fn(&Object, _, NSRange, NSRangePointer) -> CGRect
+            );
+            decl.add_method(
+                sel!(characterIndexForPoint:),
+                character_index_for_point::<T> as extern fn(&Object, _, CGPoint) -> NSUInteger
+            );
+            decl.add_method(sel!(doCommandBySelector:), do_command_by_selector::<T> as extern fn(&Object, _, Sel));
+        })
+    }
+}
+
+impl<T> View<T> where T: TextInputDelegate + 'static {
+    /// Like `View::with()`, but the view also takes text input through the system's input
+    /// methods, calling through to `delegate`. See the `TextInputDelegate` docs.
+    pub fn with_text_input(delegate: T) -> View<T> {
+        View::with_registration(delegate, register_text_input_view_class::<T>)
+    }
+}
+
+impl<T> View<T> {
+    /// Tells the input method to throw away any text it's composing - e.g, because the user
+    /// clicked elsewhere, moving the caret. Only meaningful for views created with
+    /// `View::with_text_input()`.
+    pub fn discard_marked_text(&self) {
+        unsafe {
+            let context: id = msg_send![&*self.objc, inputContext];
+            let _: () = msg_send![context, discardMarkedText];
+        }
+    }
+
+    /// Tells the input method that the text has moved on screen (e.g, it scrolled), so it should
+    /// ask `rect_for_range()` again. Only meaningful for views created with
+    /// `View::with_text_input()`.
+    pub fn invalidate_character_coordinates(&self) {
+        unsafe {
+            let context: id = msg_send![&*self.objc, inputContext];
+            let _: () = msg_send![context, invalidateCharacterCoordinates];
+        }
+    }
+}
@@ -1,5 +1,17 @@
 //! Various traits used for Views.
 
+#[cfg(feature = "macos")]
+use crate::appearance::Appearance;
+
+#[cfg(feature = "macos")]
+use crate::events::Command;
+
+#[cfg(feature = "ios")]
+use crate::geometry::Size;
+
+#[cfg(feature = "ios")]
+use crate::ios::{ContentSizeCategory, InterfaceOrientation, ScreenEdge, SizeClass, StatusBarAnimation, StatusBarStyle};
+
 use crate::dragdrop::{DragInfo, DragOperation};
 use crate::view::View;
 
@@ -21,6 +33,104 @@ pub trait ViewDelegate {
     /// Called when this has been removed from the view heirarchy.
     fn did_disappear(&self, _animated: bool) {}
 
+    /// Called when the effective appearance of the view changes (e.g, the user switched to dark
+    /// mode). If you're doing custom drawing, this is where you'd invalidate and redraw with the
+    /// correct palette.
+    #[cfg(feature = "macos")]
+    fn appearance_changed(&self, _appearance: Appearance) {}
+
+    /// Called after this view has laid out its subviews (e.g, because it was resized), with their
+    /// frames final. If you're positioning or drawing things by hand, this is where you'd
+    /// recompute geometry.
+    fn layout_subviews(&self) {}
+
+    /// Called when the user starts resizing the window this view is in. Expensive drawing can be
+    /// simplified until `did_end_live_resize()` is called.
+    #[cfg(feature = "macos")]
+    fn will_start_live_resize(&self) {}
+
+    /// Called when the user has finished resizing the window this view is in.
+    #[cfg(feature = "macos")]
+    fn did_end_live_resize(&self) {}
+
+    /// Whether this view can become the focused view (the first responder) - e.g, when clicked.
+    /// Views have to be focused to receive key events and commands.
+    #[cfg(feature = "macos")]
+    fn accepts_first_responder(&self) -> bool { false }
+
+    /// The standard commands this view handles - any of `Command::Copy`, `Cut`, `Paste`,
+    /// `Delete`, `SelectAll` and `Find`. While this view (or a subview) is focused, menu items,
+    /// toolbar items and keyboard shortcuts for these commands are routed to `perform_command()`,
+    /// just as they would be to a text field. Others carry on up the responder chain.
+    #[cfg(feature = "macos")]
+    fn supported_commands(&self) -> Vec<Command> { vec![] }
+
+    /// Called when one of the `supported_commands()` is sent to this view (e.g, the user chose
+    /// Edit > Copy).
+    #[cfg(feature = "macos")]
+    fn perform_command(&self, _command: Command) {}
+
+    /// Called when a menu item or toolbar item that sends `command` along the responder chain
+    /// reaches this view (i.e, it, or a subview, is focused), to ask whether it should be
+    /// enabled - e.g, `Command::Copy` only makes sense with something selected.
+    #[cfg(feature = "macos")]
+    fn validate(&self, _command: Command) -> bool { true }
+
+    /// Called as pressure changes during a click on a Force Touch trackpad. `stage` is `0` once
+    /// the click is released, `1` for a normal click, and `2` for a force click (or higher, with
+    /// `PressureBehavior::PrimaryAccelerator`); `pressure` runs from `0.0` to `1.0` within the
+    /// current stage. See `View::set_pressure_behavior()` for configuring the stages.
+    #[cfg(feature = "macos")]
+    fn pressure_changed(&self, _stage: isize, _pressure: f32) {}
+
+    /// Called when this is about to be resized to `size` - e.g, because the device rotated, or
+    /// the app was resized in Split View. The view still has its old size at this point.
+    #[cfg(feature = "ios")]
+    fn size_changed(&self, _size: Size) {}
+
+    /// Called when the horizontal or vertical size class changes (including when they're first
+    /// known, as this appears). This is where you'd switch between compact and regular layouts.
+    #[cfg(feature = "ios")]
+    fn trait_collection_changed(&self, _horizontal: SizeClass, _vertical: SizeClass) {}
+
+    /// Called when the user's preferred text size changes (including when it's first known, as this
+    /// appears). Text widgets can follow it by themselves (see
+    /// `Label::set_adjusts_font_for_content_size_category()`); this is for anything else, like
+    /// switching to a vertical layout at the Accessibility sizes.
+    #[cfg(feature = "ios")]
+    fn content_size_category_changed(&self, _category: ContentSizeCategory) {}
+
+    /// The orientations this supports, while it's the root (or presented fullscreen). Return
+    /// `None` for UIKit's default, which is all of them on iPad, and all but upside down on
+    /// iPhone. If this changes, call `ViewController::set_needs_orientation_update()`.
+    #[cfg(feature = "ios")]
+    fn supported_orientations(&self) -> Option<Vec<InterfaceOrientation>> { None }
+
+    /// The style of the status bar's content while this is showing. If this changes, call
+    /// `ViewController::set_needs_status_bar_appearance_update()`.
+    #[cfg(feature = "ios")]
+    fn preferred_status_bar_style(&self) -> StatusBarStyle { StatusBarStyle::Default }
+
+    /// Whether the status bar is hidden while this is showing - e.g, for fullscreen photos or
+    /// video. If this changes, call `ViewController::set_needs_status_bar_appearance_update()`.
+    #[cfg(feature = "ios")]
+    fn prefers_status_bar_hidden(&self) -> bool { false }
+
+    /// How the status bar animates when `prefers_status_bar_hidden()` changes.
+    #[cfg(feature = "ios")]
+    fn status_bar_update_animation(&self) -> StatusBarAnimation { StatusBarAnimation::Fade }
+
+    /// Whether the home indicator hides itself after a few seconds without touches. If this
+    /// changes, call `ViewController::set_needs_home_indicator_update()`.
+    #[cfg(feature = "ios")]
+    fn prefers_home_indicator_auto_hidden(&self) -> bool { false }
+
+    /// The screen edges where your gestures take priority over the system's: the user has to
+    /// swipe twice to trigger the system gesture. If this changes, call
+    /// `ViewController::set_needs_deferred_edges_update()`.
+    #[cfg(feature = "ios")]
+    fn edges_deferring_system_gestures(&self) -> Vec<ScreenEdge> { vec![] }
+
     /// Invoked when the dragged image enters destination bounds or frame; returns dragging operation to perform.
     fn dragging_entered(&self, _info: DragInfo) -> DragOperation { DragOperation::None }
     
@@ -0,0 +1,231 @@
+//! Sign-in flows that run in the system browser, rather than an embedded `WebView`: the user sees
+//! a browser they trust (with their saved passwords and existing sessions), and your app never
+//! sees their credentials.
+//!
+//! `WebAuthenticationSession` wraps `ASWebAuthenticationSession`, for OAuth-style flows: it loads
+//! the provider's login page, and hands you the URL the provider redirects back to (with the
+//! authorization code in it). On iOS there's also `SafariController`, which wraps
+//! `SFSafariViewController` for showing web content in-app.
+//!
+//! ```rust,no_run
+//! use cacao::web_authentication::WebAuthenticationSession;
+//! use url::Url;
+//!
+//! let login = Url::parse("https://auth.example.com/authorize?client_id=myapp").unwrap();
+//!
+//! let session = WebAuthenticationSession::new(&login, "myapp", |result| match result {
+//!     Ok(callback) => println!("Signed in: {}", callback),
+//!     Err(e) => eprintln!("Didn't sign in: {}", e)
+//! });
+//!
+//! // Keep `session` around until the callback has been called.
+//! session.start();
+//! ```
+
+use std::error;
+use std::fmt;
+use std::sync::Once;
+
+use block::ConcreteBlock;
+use url::Url;
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::error::Error;
+use crate::foundation::{id, nil, YES, NO, BOOL, NSString};
+use crate::utils::load_or_register_class;
+
+#[cfg(target_os = "ios")]
+use crate::foundation::NSArray;
+
+#[cfg(target_os = "ios")]
+mod safari;
+
+#[cfg(target_os = "ios")]
+pub use safari::SafariController;
+
+/// `ASWebAuthenticationSessionErrorDomain`.
+static ERROR_DOMAIN: &str = "com.apple.AuthenticationServices.WebAuthenticationSession";
+
+/// `ASWebAuthenticationSessionErrorCodeCanceledLogin`.
+const ERROR_CANCELED_LOGIN: usize = 1;
+
+/// Why a `WebAuthenticationSession` didn't complete.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WebAuthenticationError {
+    /// The user closed the browser (or the session was cancelled).
+    Cancelled,
+
+    /// The browser couldn't be shown, or the provider's page failed. Holds the underlying
+    /// error.
+    Failed(Error)
+}
+
+impl WebAuthenticationError {
+    /// Maps an `NSError` from the session.
+    fn new(error: id) -> Self {
+        let error = Error::new(error);
+
+        match &error {
+            Error::Cocoa { code, domain, .. } if *code == ERROR_CANCELED_LOGIN && domain == ERROR_DOMAIN => {
+                WebAuthenticationError::Cancelled
+            },
+
+            _ => WebAuthenticationError::Failed(error)
+        }
+    }
+}
+
+impl fmt::Display for WebAuthenticationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebAuthenticationError::Cancelled => write!(f, "The sign-in was cancelled."),
+            WebAuthenticationError::Failed(error) => write!(f, "{}", error)
+        }
+    }
+}
+
+impl error::Error for WebAuthenticationError {}
+
+/// Returns the window the browser sheet should be shown over: the key window.
+extern fn presentation_anchor(_: &Object, _: Sel, _: id) -> id {
+    unsafe {
+        #[cfg(target_os = "macos")]
+        {
+            let app: id = msg_send![class!(NSApplication), sharedApplication];
+            let window: id = msg_send![app, keyWindow];
+
+            match window == nil {
+                true => msg_send![app, mainWindow],
+                false => window
+            }
+        }
+
+        #[cfg(target_os = "ios")]
+        {
+            let app: id = msg_send![class!(UIApplication), sharedApplication];
+            let scenes: id = msg_send![app, connectedScenes];
+
+            let windows = NSArray::wrap(msg_send![scenes, allObjects]).map(|scene| {
+                let is_window_scene: BOOL = msg_send![scene, isKindOfClass:class!(UIWindowScene)];
+
+                match is_window_scene {
+                    YES => NSArray::wrap(msg_send![scene, windows]).map(|window| window),
+                    _ => vec![]
+                }
+            }).into_iter().flatten().collect::<Vec<id>>();
+
+            let key_window = windows.iter().copied().find(|&window| {
+                let is_key: BOOL = msg_send![window, isKeyWindow];
+                is_key == YES
+            });
+
+            key_window.or_else(|| windows.first().copied()).unwrap_or(nil)
+        }
+    }
+}
+
+/// Registers an `NSObject` subclass that acts as an
+/// `ASWebAuthenticationPresentationContextProviding`.
+fn register_presentation_context_provider_class() -> *const Class {
+    static mut PROVIDER_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSObject);
+        PROVIDER_CLASS = load_or_register_class(superclass, "RSTWebAuthenticationContextProvider", |decl| {
+            decl.add_method(sel!(presentationAnchorForWebAuthenticationSession:), presentation_anchor as extern fn(&Object, _, _) -> id);
+        });
+    });
+
+    unsafe { PROVIDER_CLASS }
+}
+
+/// Wraps an `ASWebAuthenticationSession`. Keep this around until your callback has been called;
+/// dropping it early cancels the session.
+#[derive(Debug)]
+pub struct WebAuthenticationSession {
+    /// The underlying `ASWebAuthenticationSession`.
+    pub objc: ShareId<Object>,
+
+    /// Supplies the window the browser is shown over.
+    provider: ShareId<Object>
+}
+
+impl WebAuthenticationSession {
+    /// Creates a session that loads `url`, and completes when the page redirects to a URL with
+    /// the given scheme (e.g, `myapp` for `myapp://callback?code=...`). `callback` is called on
+    /// the main thread with that URL, or with why the session didn't complete.
+    pub fn new<F>(url: &Url, callback_scheme: &str, callback: F) -> Self
+    where
+        F: Fn(Result<Url, WebAuthenticationError>) + 'static
+    {
+        let url = NSString::new(url.as_str());
+        let callback_scheme = NSString::new(callback_scheme);
+
+        let block = ConcreteBlock::new(move |callback_url: id, error: id| {
+            callback(match callback_url == nil {
+                true => Err(WebAuthenticationError::new(error)),
+
+                false => {
+                    let absolute = NSString::wrap(unsafe { msg_send![callback_url, absoluteString] });
+
+                    Url::parse(absolute.to_str()).map_err(|e| {
+                        WebAuthenticationError::Failed(Error::InvalidUrl(e.to_string()))
+                    })
+                }
+            });
+        });
+        let block = block.copy();
+
+        unsafe {
+            let url: id = msg_send![class!(NSURL), URLWithString:url];
+
+            let session: id = msg_send![class!(ASWebAuthenticationSession), alloc];
+            let session: id = msg_send![session, initWithURL:url callbackURLScheme:callback_scheme completionHandler:block];
+
+            let provider: id = msg_send![register_presentation_context_provider_class(), new];
+            let _: () = msg_send![session, setPresentationContextProvider:provider];
+
+            WebAuthenticationSession {
+                objc: ShareId::from_retained_ptr(session),
+                provider: ShareId::from_retained_ptr(provider)
+            }
+        }
+    }
+
+    /// Sets whether the browser should skip the user's existing cookies and website data (and
+    /// not keep any from this session). Ephemeral sessions don't ask the user for permission
+    /// to sign in first, but the user won't be signed in already. Call this before `start()`.
+    pub fn set_prefers_ephemeral_session(&self, ephemeral: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setPrefersEphemeralWebBrowserSession:match ephemeral {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Shows the browser and starts the session. Returns `false` if it couldn't be started (e.g,
+    /// it's already been started once).
+    pub fn start(&self) -> bool {
+        let started: BOOL = unsafe { msg_send![&*self.objc, start] };
+        started == YES
+    }
+
+    /// Cancels the session, dismissing the browser. The callback isn't called.
+    pub fn cancel(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, cancel];
+        }
+    }
+}
+
+impl Drop for WebAuthenticationSession {
+    /// Cancels the session, which would otherwise call back into a callback nobody's waiting on.
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
@@ -0,0 +1,99 @@
+//! Wraps `SFSafariViewController`, which shows a web page in-app with Safari's UI (and the user's
+//! Safari settings, autofill, and content blockers). Your app can't see or script the page; if you
+//! need that, use a `WebView` instead.
+
+use url::Url;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::color::Color;
+use crate::foundation::{id, nil, YES, NO, NSString};
+use crate::utils::Controller;
+
+/// An in-app Safari browser, showing a single page (which the user can navigate on from).
+#[derive(Debug)]
+pub struct SafariController {
+    /// The underlying `SFSafariViewController`.
+    pub objc: ShareId<Object>
+}
+
+impl SafariController {
+    /// Creates a browser that loads `url`, which must be `http` or `https`.
+    pub fn new(url: &Url) -> Self {
+        SafariController::with_reader_mode(url, false)
+    }
+
+    /// As with `new()`, but opens the page in Reader (if it supports it) when `reader` is
+    /// `true`.
+    pub fn with_reader_mode(url: &Url, reader: bool) -> Self {
+        let url = NSString::new(url.as_str());
+
+        SafariController {
+            objc: unsafe {
+                let url: id = msg_send![class!(NSURL), URLWithString:url];
+
+                let configuration: id = msg_send![class!(SFSafariViewControllerConfiguration), new];
+                let _: () = msg_send![configuration, setEntersReaderIfAvailable:match reader {
+                    true => YES,
+                    false => NO
+                }];
+
+                let controller: id = msg_send![class!(SFSafariViewController), alloc];
+                let controller: id = msg_send![controller, initWithURL:url configuration:configuration];
+                let _: () = msg_send![configuration, release];
+
+                ShareId::from_retained_ptr(controller)
+            }
+        }
+    }
+
+    /// Creates a browser for `url`, and presents it from `controller`. A shorthand for the common
+    /// case; keep the returned value if you want to dismiss it yourself.
+    pub fn present<VC: Controller>(url: &Url, controller: &VC) -> Self {
+        let safari = SafariController::new(url);
+        safari.present_from(controller);
+        safari
+    }
+
+    /// Sets the color of the navigation and tool bars.
+    pub fn set_bar_tint_color(&self, color: Color) {
+        let color = color.into_platform_specific_color();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setPreferredBarTintColor:color];
+        }
+    }
+
+    /// Sets the color of the buttons in the navigation and tool bars.
+    pub fn set_control_tint_color(&self, color: Color) {
+        let color = color.into_platform_specific_color();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setPreferredControlTintColor:color];
+        }
+    }
+
+    /// Presents this browser from `controller`. The user closes it with the Done button.
+    pub fn present_from<VC: Controller>(&self, controller: &VC) {
+        let backing_node = controller.get_backing_node();
+
+        unsafe {
+            let _: () = msg_send![&*backing_node, presentViewController:&*self.objc animated:YES completion:nil];
+        }
+    }
+
+    /// Dismisses this browser, if it's showing.
+    pub fn dismiss(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, dismissViewControllerAnimated:YES completion:nil];
+        }
+    }
+}
+
+impl Controller for SafariController {
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.objc.clone()
+    }
+}
@@ -7,7 +7,6 @@ use std::sync::Once;
 
 use block::Block;
 
-use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel};
 use objc::{class, msg_send, sel, sel_impl};
 
@@ -15,7 +14,7 @@ use crate::foundation::{id, nil, YES, NO, NSString, NSArray, NSInteger};
 use crate::webview::{WEBVIEW_DELEGATE_PTR, WebViewDelegate};
 use crate::webview::actions::{NavigationAction, NavigationResponse};//, OpenPanelParameters};
 //use crate::webview::enums::{NavigationPolicy, NavigationResponsePolicy};
-use crate::utils::load;
+use crate::utils::{load_or_register_class, load_or_register_class_for};
 
 /// Called when an `alert()` from the underlying `WKWebView` is fired. Will call over to your
 /// `WebViewController`, where you should handle the event.
@@ -44,7 +43,7 @@ extern fn alert<T: WebViewDelegate>(_: &Object, _: Sel, _: id, _: id, _: id, com
 
 /// Fires when a message has been passed from the underlying `WKWebView`.
 extern fn on_message<T: WebViewDelegate>(this: &Object, _: Sel, _: id, script_message: id) {
-    let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
+    let delegate = load_or_return!(T, this, WEBVIEW_DELEGATE_PTR);
 
     unsafe {
         let name = NSString::wrap(msg_send![script_message, name]);
@@ -55,7 +54,7 @@ extern fn on_message<T: WebViewDelegate>(this: &Object, _: Sel, _: id, script_me
 
 /// Fires when deciding a navigation policy - i.e, should something be allowed or not.
 extern fn decide_policy_for_action<T: WebViewDelegate>(this: &Object, _: Sel, _: id, action: id, handler: usize) {
-    let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
+    let delegate = load_or_return!(T, this, WEBVIEW_DELEGATE_PTR);
 
     let action = NavigationAction::new(action);
     
@@ -67,7 +66,7 @@ extern fn decide_policy_for_action<T: WebViewDelegate>(this: &Object, _: Sel, _:
 
 /// Fires when deciding a navigation policy - i.e, should something be allowed or not.
 extern fn decide_policy_for_response<T: WebViewDelegate>(this: &Object, _: Sel, _: id, response: id, handler: usize) {
-    let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
+    let delegate = load_or_return!(T, this, WEBVIEW_DELEGATE_PTR);
 
     let response = NavigationResponse::new(response);
 
@@ -79,7 +78,7 @@ extern fn decide_policy_for_response<T: WebViewDelegate>(this: &Object, _: Sel,
 
 /// Fires when deciding a navigation policy - i.e, should something be allowed or not.
 extern fn run_open_panel<T: WebViewDelegate>(this: &Object, _: Sel, _: id, params: id, _: id, handler: usize) {
-    let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
+    let delegate = load_or_return!(T, this, WEBVIEW_DELEGATE_PTR);
 
     delegate.run_open_panel(params.into(), move |urls| unsafe {
         let handler = handler as *const Block<(id,), c_void>;
@@ -104,7 +103,7 @@ extern fn run_open_panel<T: WebViewDelegate>(this: &Object, _: Sel, _: id, param
 /// API.
 #[cfg(feature = "webview-downloading")]
 extern fn handle_download<T: WebViewDelegate>(this: &Object, _: Sel, download: id, suggested_filename: id, handler: usize) {
-    let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
+    let delegate = load_or_return!(T, this, WEBVIEW_DELEGATE_PTR);
 
     let handler = handler as *const Block<(objc::runtime::BOOL, id), c_void>; 
     let filename = NSString::wrap(suggested_filename);
@@ -132,8 +131,7 @@ pub fn register_webview_class() -> *const Class {
 
     INIT.call_once(|| unsafe {
         let superclass = class!(WKWebView);
-        let decl = ClassDecl::new("RSTWebView", superclass).unwrap();
-        VIEW_CLASS = decl.register();
+        VIEW_CLASS = load_or_register_class(superclass, "RSTWebView", |_| {});
     });
 
     unsafe { VIEW_CLASS }
@@ -142,35 +140,28 @@ pub fn register_webview_class() -> *const Class {
 /// Registers an `NSViewController` that we effectively turn into a `WebViewController`. Acts as
 /// both a subclass of `NSViewController` and a delegate of the held `WKWebView` (for the various
 /// varieties of delegates needed there).
-pub fn register_webview_delegate_class<T: WebViewDelegate>() -> *const Class {
-    static mut VIEW_CLASS: *const Class = 0 as *const Class;
-    static INIT: Once = Once::new();
-
-    INIT.call_once(|| unsafe {
+pub fn register_webview_delegate_class<T: WebViewDelegate + 'static>() -> *const Class {
+    unsafe {
         let superclass = class!(NSObject);
-        let mut decl = ClassDecl::new("RSTWebViewDelegate", superclass).unwrap();
-
-        decl.add_ivar::<usize>(WEBVIEW_DELEGATE_PTR);
-
-        // WKNavigationDelegate
-        decl.add_method(sel!(webView:decidePolicyForNavigationAction:decisionHandler:), decide_policy_for_action::<T> as extern fn(&Object, _, _, id, usize));
-        decl.add_method(sel!(webView:decidePolicyForNavigationResponse:decisionHandler:), decide_policy_for_response::<T> as extern fn(&Object, _, _, id, usize));
-
-        // WKScriptMessageHandler
-        decl.add_method(sel!(userContentController:didReceiveScriptMessage:), on_message::<T> as extern fn(&Object, _, _, id));
- 
-        // WKUIDelegate
-        decl.add_method(sel!(webView:runJavaScriptAlertPanelWithMessage:initiatedByFrame:completionHandler:), alert::<T> as extern fn(&Object, _, _, id, _, _));
-        decl.add_method(sel!(webView:runOpenPanelWithParameters:initiatedByFrame:completionHandler:), run_open_panel::<T> as extern fn(&Object, _, _, id, _, usize));
-        
-        // WKDownloadDelegate is a private class on macOS that handles downloading (saving) files.
-        // It's absurd that this is still private in 2020. This probably couldn't get into the app
-        // store, so... screw it, feature-gate it.
-        #[cfg(feature = "webview-downloading")]
-        decl.add_method(sel!(_download:decideDestinationWithSuggestedFilename:completionHandler:), handle_download::<T> as extern fn(&Object, _, id, id, usize));
-
-        VIEW_CLASS = decl.register();
-    });
-
-    unsafe { VIEW_CLASS }
+        load_or_register_class_for::<T, _>(superclass, "RSTWebViewDelegate", |decl| {
+            decl.add_ivar::<usize>(WEBVIEW_DELEGATE_PTR);
+
+            // WKNavigationDelegate
+            decl.add_method(sel!(webView:decidePolicyForNavigationAction:decisionHandler:), decide_policy_for_action::<T> as extern fn(&Object, _, _, id, usize));
+            decl.add_method(sel!(webView:decidePolicyForNavigationResponse:decisionHandler:), decide_policy_for_response::<T> as extern fn(&Object, _, _, id, usize));
+
+            // WKScriptMessageHandler
+            decl.add_method(sel!(userContentController:didReceiveScriptMessage:), on_message::<T> as extern fn(&Object, _, _, id));
+
+            // WKUIDelegate
+            decl.add_method(sel!(webView:runJavaScriptAlertPanelWithMessage:initiatedByFrame:completionHandler:), alert::<T> as extern fn(&Object, _, _, id, _, _));
+            decl.add_method(sel!(webView:runOpenPanelWithParameters:initiatedByFrame:completionHandler:), run_open_panel::<T> as extern fn(&Object, _, _, id, _, usize));
+
+            // WKDownloadDelegate is a private class on macOS that handles downloading (saving) files.
+            // It's absurd that this is still private in 2020. This probably couldn't get into the app
+            // store, so... screw it, feature-gate it.
+            #[cfg(feature = "webview-downloading")]
+            decl.add_method(sel!(_download:decideDestinationWithSuggestedFilename:completionHandler:), handle_download::<T> as extern fn(&Object, _, id, id, usize));
+        })
+    }
 }
@@ -26,8 +26,6 @@ pub enum NavigationType {
 
 // For whatever reason, impl From<> below doesn't generate the reciprocal impl Into<> we need.
 // So I guess we'll do it ourselves.
-// 
-// This panic will be removed and is for testing purposes only right now.
 impl Into<NavigationType> for NSInteger {
     fn into(self) -> NavigationType {
         match self {
@@ -37,7 +35,9 @@ impl Into<NavigationType> for NSInteger {
             2 => NavigationType::BackForward,
             3 => NavigationType::Reload,
             4 => NavigationType::FormResubmitted,
-            _ => { panic!("Unsupported WKWebView NavigationType value found!"); }
+
+            // Newer WebKit versions may add types; treat those as `Other` rather than failing.
+            _ => NavigationType::Other
         }
     }
 }
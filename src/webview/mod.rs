@@ -19,9 +19,11 @@ use objc_id::ShareId;
 use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
 
+use crate::dispatch::{assert_main_thread, MainThreadHandle};
 use crate::foundation::{id, nil, YES, NO, NSString};
 use crate::geometry::Rect;
 use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
+use crate::utils::clear_ptr;
 
 mod actions;
 pub use actions::*;
@@ -123,6 +125,8 @@ impl Default for WebView {
 
 impl WebView {
     pub fn new(config: WebViewConfig) -> Self {
+        assert_main_thread("WebView::new");
+
         let view = allocate_webview(config, None);
 
         WebView {
@@ -141,10 +145,35 @@ impl WebView {
     }
 }
 
+impl WebView {
+    /// Builds a `WebView` around `objc`, with no delegate.
+    fn wrap(objc: ShareId<Object>) -> Self {
+        let view = &*objc;
+
+        WebView {
+            delegate: None,
+            objc_delegate: None,
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            objc: objc
+        }
+    }
+}
+
+impl_objc_access!(WebView<T>);
+
 impl<T> WebView<T> where T: WebViewDelegate + 'static {
     /// Initializes a new WebView with a given `WebViewDelegate`. This enables you to respond to events
     /// and customize the view as a module, similar to class-based systems.
     pub fn with(config: WebViewConfig, delegate: T) -> WebView<T> {
+        assert_main_thread("WebView::with");
+
         let delegate = Box::new(delegate);
 
         let objc_delegate = unsafe {
@@ -197,6 +226,12 @@ impl<T> WebView<T> {
         }
     }
 
+    /// Returns a handle to this web view that can be sent to other threads, and used to update it
+    /// from there; see `MainThreadHandle`.
+    pub fn handle(&self) -> WebViewHandle {
+        MainThreadHandle::new(self.clone_as_handle())
+    }
+
     /// Given a URL, instructs the WebView to load it.
     //  @TODO: Make this take Url instead? Fine for testing now I suppose.
     pub fn load_url(&self, url: &str) {
@@ -228,9 +263,15 @@ impl<T> std::fmt::Debug for WebView<T> {
 }
 
 impl<T> Drop for WebView<T> {
-    /// A bit of extra cleanup for delegate callback pointers.
+    /// A bit of extra cleanup for delegate callback pointers. Besides detaching the delegates, we
+    /// clear the pointer on our delegate object, in case the system still has messages queued up
+    /// for it.
     fn drop(&mut self) {
         if self.delegate.is_some() {
+            if let Some(objc_delegate) = &self.objc_delegate {
+                clear_ptr(objc_delegate, WEBVIEW_DELEGATE_PTR);
+            }
+
             unsafe {
                 let _: () = msg_send![&*self.objc, setNavigationDelegate:nil];
                 let _: () = msg_send![&*self.objc, setUIDelegate:nil];
@@ -238,3 +279,14 @@ impl<T> Drop for WebView<T> {
         }
     }
 }
+
+/// A `WebView` handle that can be sent to other threads. Updates are applied on the main thread.
+pub type WebViewHandle = MainThreadHandle<WebView>;
+
+impl WebViewHandle {
+    /// Loads `url`, on the main thread.
+    pub fn load_url(&self, url: &str) {
+        let url = url.to_string();
+        self.update(move |webview| webview.load_url(&url));
+    }
+}
@@ -11,12 +11,12 @@ use block::Block;
 
 use cocoa::foundation::{NSRect, NSPoint, NSSize, NSString, NSArray, NSInteger};
 
-use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel, BOOL};
 use objc::{class, msg_send, sel, sel_impl};
 
 use crate::foundation::{id, nil, YES, NO};
 use crate::webview::traits::WebViewController;
+use crate::utils::load_or_register_class;
 
 extern fn download_delegate(this: &Object, _: Sel) -> id {
     println!("YO!");
@@ -31,13 +31,12 @@ pub fn register_process_pool() -> *const Object {
 
     INIT.call_once(|| unsafe {
         let superclass = Class::get("WKProcessPool").unwrap();
-        let mut decl = ClassDecl::new("RSTWebViewProcessPool", superclass).unwrap();
+        let class = load_or_register_class(superclass, "RSTWebViewProcessPool", |decl| {
+            //decl.add_ivar::<id>(DOWNLOAD_DELEGATE_PTR);
+            decl.add_method(sel!(_downloadDelegate), download_delegate as extern fn(&Object, _) -> id);
+        });
 
-        //decl.add_ivar::<id>(DOWNLOAD_DELEGATE_PTR);
-        decl.add_method(sel!(_downloadDelegate), download_delegate as extern fn(&Object, _) -> id);
-
-        //PROCESS_POOL = decl.register();
-        PROCESS_POOL = msg_send![decl.register(), new];
+        PROCESS_POOL = msg_send![class, new];
     });
 
     unsafe { PROCESS_POOL }